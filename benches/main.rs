@@ -7,7 +7,7 @@ use test::{black_box, Bencher};
 use pyo3::prelude::*;
 use pyo3::types::{PyDict, PyString};
 
-use _pydantic_core::{validate_core_schema, SchemaValidator};
+use _pydantic_core::{validate_core_schema, PythonValidateOptions, SchemaValidator};
 
 fn build_schema_validator_with_globals(py: Python, code: &str, globals: Option<&Bound<'_, PyDict>>) -> SchemaValidator {
     let mut schema = py.eval_bound(code, globals, None).unwrap().extract().unwrap();
@@ -28,11 +28,19 @@ fn ints_json(bench: &mut Bencher) {
     Python::with_gil(|py| {
         let validator = build_schema_validator(py, "{'type': 'int'}");
 
-        let result = validator.validate_json(py, &json(py, "123"), None, None, None).unwrap();
+        let result = validator
+            .validate_json(py, &json(py, "123"), None, None, None, "last", None, false)
+            .unwrap();
         let result_int: i64 = result.extract(py).unwrap();
         assert_eq!(result_int, 123);
 
-        bench.iter(|| black_box(validator.validate_json(py, &json(py, "123"), None, None, None).unwrap()))
+        bench.iter(|| {
+            black_box(
+                validator
+                    .validate_json(py, &json(py, "123"), None, None, None, "last", None, false)
+                    .unwrap(),
+            )
+        })
     })
 }
 
@@ -42,12 +50,20 @@ fn ints_python(bench: &mut Bencher) {
         let validator = build_schema_validator(py, "{'type': 'int'}");
 
         let input = 123_i64.into_py(py).into_bound(py);
-        let result = validator.validate_python(py, &input, None, None, None, None).unwrap();
+        let result = validator
+            .validate_python_with(py, &input, PythonValidateOptions::default())
+            .unwrap();
         let result_int: i64 = result.extract(py).unwrap();
         assert_eq!(result_int, 123);
 
         let input = black_box(input);
-        bench.iter(|| black_box(validator.validate_python(py, &input, None, None, None, None).unwrap()))
+        bench.iter(|| {
+            black_box(
+                validator
+                    .validate_python_with(py, &input, PythonValidateOptions::default())
+                    .unwrap(),
+            )
+        })
     })
 }
 
@@ -60,7 +76,13 @@ fn list_int_json(bench: &mut Bencher) {
             (0..100).map(|x| x.to_string()).collect::<Vec<String>>().join(",")
         );
 
-        bench.iter(|| black_box(validator.validate_json(py, &json(py, &code), None, None, None).unwrap()))
+        bench.iter(|| {
+            black_box(
+                validator
+                    .validate_json(py, &json(py, &code), None, None, None, "last", None, false)
+                    .unwrap(),
+            )
+        })
     })
 }
 
@@ -81,7 +103,9 @@ fn list_int_python(bench: &mut Bencher) {
         let (validator, input) = list_int_input(py);
         let input = black_box(input.bind(py));
         bench.iter(|| {
-            let v = validator.validate_python(py, &input, None, None, None, None).unwrap();
+            let v = validator
+                .validate_python_with(py, &input, PythonValidateOptions::default())
+                .unwrap();
             black_box(v)
         })
     })
@@ -114,7 +138,7 @@ fn list_error_json(bench: &mut Bencher) {
                 .join(", ")
         );
 
-        match validator.validate_json(py, &json(py, &code), None, None, None) {
+        match validator.validate_json(py, &json(py, &code), None, None, None, "last", None, false) {
             Ok(_) => panic!("unexpectedly valid"),
             Err(e) => {
                 let v = e.value_bound(py);
@@ -126,7 +150,7 @@ fn list_error_json(bench: &mut Bencher) {
         };
 
         bench.iter(
-            || match validator.validate_json(py, &json(py, &code), None, None, None) {
+            || match validator.validate_json(py, &json(py, &code), None, None, None, "last", None, false) {
                 Ok(_) => panic!("unexpectedly valid"),
                 Err(e) => black_box(e),
             },
@@ -146,7 +170,7 @@ fn list_error_python_input(py: Python<'_>) -> (SchemaValidator, PyObject) {
 
     let input = py.eval_bound(&code, None, None).unwrap().extract().unwrap();
 
-    match validator.validate_python(py, &input, None, None, None, None) {
+    match validator.validate_python_with(py, &input, PythonValidateOptions::default()) {
         Ok(_) => panic!("unexpectedly valid"),
         Err(e) => {
             let v = e.value_bound(py);
@@ -166,7 +190,7 @@ fn list_error_python(bench: &mut Bencher) {
 
         let input = black_box(input.bind(py));
         bench.iter(|| {
-            let result = validator.validate_python(py, &input, None, None, None, None);
+            let result = validator.validate_python_with(py, &input, PythonValidateOptions::default());
 
             match result {
                 Ok(_) => panic!("unexpectedly valid"),
@@ -199,7 +223,13 @@ fn list_any_json(bench: &mut Bencher) {
             (0..100).map(|x| x.to_string()).collect::<Vec<String>>().join(",")
         );
 
-        bench.iter(|| black_box(validator.validate_json(py, &json(py, &code), None, None, None).unwrap()))
+        bench.iter(|| {
+            black_box(
+                validator
+                    .validate_json(py, &json(py, &code), None, None, None, "last", None, false)
+                    .unwrap(),
+            )
+        })
     })
 }
 
@@ -214,7 +244,9 @@ fn list_any_python(bench: &mut Bencher) {
         let input = py.eval_bound(&code, None, None).unwrap().to_object(py);
         let input = black_box(input.bind(py));
         bench.iter(|| {
-            let v = validator.validate_python(py, &input, None, None, None, None).unwrap();
+            let v = validator
+                .validate_python_with(py, &input, PythonValidateOptions::default())
+                .unwrap();
             black_box(v)
         })
     })
@@ -244,7 +276,13 @@ fn dict_json(bench: &mut Bencher) {
                 .join(", ")
         );
 
-        bench.iter(|| black_box(validator.validate_json(py, &json(py, &code), None, None, None).unwrap()))
+        bench.iter(|| {
+            black_box(
+                validator
+                    .validate_json(py, &json(py, &code), None, None, None, "last", None, false)
+                    .unwrap(),
+            )
+        })
     })
 }
 
@@ -266,7 +304,9 @@ fn dict_python(bench: &mut Bencher) {
         let input = py.eval_bound(&code, None, None).unwrap().to_object(py);
         let input = black_box(input.bind(py));
         bench.iter(|| {
-            let v = validator.validate_python(py, &input, None, None, None, None).unwrap();
+            let v = validator
+                .validate_python_with(py, &input, PythonValidateOptions::default())
+                .unwrap();
             black_box(v)
         })
     })
@@ -294,7 +334,7 @@ fn dict_value_error(bench: &mut Bencher) {
 
         let input = py.eval_bound(&code, None, None).unwrap().to_object(py).into_bound(py);
 
-        match validator.validate_python(py, &input, None, None, None, None) {
+        match validator.validate_python_with(py, &input, PythonValidateOptions::default()) {
             Ok(_) => panic!("unexpectedly valid"),
             Err(e) => {
                 let v = e.value_bound(py);
@@ -307,7 +347,7 @@ fn dict_value_error(bench: &mut Bencher) {
 
         let input = black_box(input);
         bench.iter(|| {
-            let result = validator.validate_python(py, &input, None, None, None, None);
+            let result = validator.validate_python_with(py, &input, PythonValidateOptions::default());
 
             match result {
                 Ok(_) => panic!("unexpectedly valid"),
@@ -342,7 +382,13 @@ fn typed_dict_json(bench: &mut Bencher) {
 
         let code = r#"{"a": 1, "b": 2, "c": 3, "d": 4, "e": 5, "f": 6, "g": 7, "h": 8, "i": 9, "j": 0}"#.to_string();
 
-        bench.iter(|| black_box(validator.validate_json(py, &json(py, &code), None, None, None).unwrap()))
+        bench.iter(|| {
+            black_box(
+                validator
+                    .validate_json(py, &json(py, &code), None, None, None, "last", None, false)
+                    .unwrap(),
+            )
+        })
     })
 }
 
@@ -373,7 +419,9 @@ fn typed_dict_python(bench: &mut Bencher) {
         let input = py.eval_bound(&code, None, None).unwrap().to_object(py);
         let input = black_box(input.bind(py));
         bench.iter(|| {
-            let v = validator.validate_python(py, &input, None, None, None, None).unwrap();
+            let v = validator
+                .validate_python_with(py, &input, PythonValidateOptions::default())
+                .unwrap();
             black_box(v)
         })
     })
@@ -413,7 +461,7 @@ fn typed_dict_deep_error(bench: &mut Bencher) {
         let input = py.eval_bound(code, None, None).unwrap().to_object(py);
         let input = black_box(input.bind(py));
 
-        match validator.validate_python(py, &input, None, None, None, None) {
+        match validator.validate_python_with(py, &input, PythonValidateOptions::default()) {
             Ok(_) => panic!("unexpectedly valid"),
             Err(e) => {
                 let v = e.value_bound(py);
@@ -425,7 +473,7 @@ fn typed_dict_deep_error(bench: &mut Bencher) {
         };
 
         bench.iter(|| {
-            let result = validator.validate_python(py, &input, None, None, None, None);
+            let result = validator.validate_python_with(py, &input, PythonValidateOptions::default());
 
             match result {
                 Ok(_) => panic!("unexpectedly valid"),
@@ -450,7 +498,11 @@ fn complete_model(bench: &mut Bencher) {
         let input = black_box(input);
 
         bench.iter(|| {
-            black_box(validator.validate_python(py, &input, None, None, None, None).unwrap());
+            black_box(
+                validator
+                    .validate_python_with(py, &input, PythonValidateOptions::default())
+                    .unwrap(),
+            );
         })
     })
 }
@@ -469,10 +521,16 @@ fn nested_model_using_definitions(bench: &mut Bencher) {
         let input = complete_schema.call_method0("input_data_valid").unwrap();
         let input = black_box(input);
 
-        validator.validate_python(py, &input, None, None, None, None).unwrap();
+        validator
+            .validate_python_with(py, &input, PythonValidateOptions::default())
+            .unwrap();
 
         bench.iter(|| {
-            black_box(validator.validate_python(py, &input, None, None, None, None).unwrap());
+            black_box(
+                validator
+                    .validate_python_with(py, &input, PythonValidateOptions::default())
+                    .unwrap(),
+            );
         })
     })
 }
@@ -491,10 +549,16 @@ fn nested_model_inlined(bench: &mut Bencher) {
         let input = complete_schema.call_method0("input_data_valid").unwrap();
         let input = black_box(input);
 
-        validator.validate_python(py, &input, None, None, None, None).unwrap();
+        validator
+            .validate_python_with(py, &input, PythonValidateOptions::default())
+            .unwrap();
 
         bench.iter(|| {
-            black_box(validator.validate_python(py, &input, None, None, None, None).unwrap());
+            black_box(
+                validator
+                    .validate_python_with(py, &input, PythonValidateOptions::default())
+                    .unwrap(),
+            );
         })
     })
 }
@@ -506,12 +570,20 @@ fn literal_ints_few_python(bench: &mut Bencher) {
 
         let input = 4_i64.into_py(py);
         let input = input.bind(py);
-        let result = validator.validate_python(py, &input, None, None, None, None).unwrap();
+        let result = validator
+            .validate_python_with(py, &input, PythonValidateOptions::default())
+            .unwrap();
         let result_int: i64 = result.extract(py).unwrap();
         assert_eq!(result_int, 4);
 
         let input = black_box(input);
-        bench.iter(|| black_box(validator.validate_python(py, &input, None, None, None, None).unwrap()))
+        bench.iter(|| {
+            black_box(
+                validator
+                    .validate_python_with(py, &input, PythonValidateOptions::default())
+                    .unwrap(),
+            )
+        })
     })
 }
 
@@ -523,12 +595,20 @@ fn literal_strings_few_small_python(bench: &mut Bencher) {
         let input = py.eval_bound("'4'", None, None).unwrap();
         let input = input.to_object(py).into_bound(py);
         let input_str: String = input.extract().unwrap();
-        let result = validator.validate_python(py, &input, None, None, None, None).unwrap();
+        let result = validator
+            .validate_python_with(py, &input, PythonValidateOptions::default())
+            .unwrap();
         let result_str: String = result.extract(py).unwrap();
         assert_eq!(result_str, input_str);
 
         let input = black_box(input);
-        bench.iter(|| black_box(validator.validate_python(py, &input, None, None, None, None).unwrap()))
+        bench.iter(|| {
+            black_box(
+                validator
+                    .validate_python_with(py, &input, PythonValidateOptions::default())
+                    .unwrap(),
+            )
+        })
     })
 }
 
@@ -543,12 +623,20 @@ fn literal_strings_few_large_python(bench: &mut Bencher) {
         let input = py.eval_bound("'a' * 25 + '4'", None, None).unwrap();
         let input = input.to_object(py).into_bound(py);
         let input_str: String = input.extract().unwrap();
-        let result = validator.validate_python(py, &input, None, None, None, None).unwrap();
+        let result = validator
+            .validate_python_with(py, &input, PythonValidateOptions::default())
+            .unwrap();
         let result_str: String = result.extract(py).unwrap();
         assert_eq!(result_str, input_str);
 
         let input = black_box(input);
-        bench.iter(|| black_box(validator.validate_python(py, &input, None, None, None, None).unwrap()))
+        bench.iter(|| {
+            black_box(
+                validator
+                    .validate_python_with(py, &input, PythonValidateOptions::default())
+                    .unwrap(),
+            )
+        })
     })
 }
 
@@ -579,11 +667,19 @@ class Foo(Enum):
 
         let input = py.eval_bound("Foo.v4", Some(&globals), None).unwrap();
         let input = input.to_object(py).into_bound(py);
-        let result = validator.validate_python(py, &input, None, None, None, None).unwrap();
+        let result = validator
+            .validate_python_with(py, &input, PythonValidateOptions::default())
+            .unwrap();
         assert!(input.eq(result).unwrap());
 
         let input = black_box(input);
-        bench.iter(|| black_box(validator.validate_python(py, &input, None, None, None, None).unwrap()))
+        bench.iter(|| {
+            black_box(
+                validator
+                    .validate_python_with(py, &input, PythonValidateOptions::default())
+                    .unwrap(),
+            )
+        })
     })
 }
 
@@ -593,12 +689,20 @@ fn literal_ints_many_python(bench: &mut Bencher) {
         let validator = build_schema_validator(py, "{'type': 'literal', 'expected': list(range(100))}");
 
         let input = 99_i64.into_py(py).into_bound(py);
-        let result = validator.validate_python(py, &input, None, None, None, None).unwrap();
+        let result = validator
+            .validate_python_with(py, &input, PythonValidateOptions::default())
+            .unwrap();
         let result_int: i64 = result.extract(py).unwrap();
         assert_eq!(result_int, 99);
 
         let input = black_box(input);
-        bench.iter(|| black_box(validator.validate_python(py, &input, None, None, None, None).unwrap()))
+        bench.iter(|| {
+            black_box(
+                validator
+                    .validate_python_with(py, &input, PythonValidateOptions::default())
+                    .unwrap(),
+            )
+        })
     })
 }
 
@@ -610,12 +714,20 @@ fn literal_strings_many_small_python(bench: &mut Bencher) {
         let input = py.eval_bound("'99'", None, None).unwrap();
         let input = input.to_object(py).into_bound(py);
         let input_str: String = input.extract().unwrap();
-        let result = validator.validate_python(py, &input, None, None, None, None).unwrap();
+        let result = validator
+            .validate_python_with(py, &input, PythonValidateOptions::default())
+            .unwrap();
         let result_str: String = result.extract(py).unwrap();
         assert_eq!(result_str, input_str);
 
         let input = black_box(input);
-        bench.iter(|| black_box(validator.validate_python(py, &input, None, None, None, None).unwrap()))
+        bench.iter(|| {
+            black_box(
+                validator
+                    .validate_python_with(py, &input, PythonValidateOptions::default())
+                    .unwrap(),
+            )
+        })
     })
 }
 
@@ -630,12 +742,20 @@ fn literal_strings_many_large_python(bench: &mut Bencher) {
         let input = py.eval_bound("'a' * 25 + '99'", None, None).unwrap();
         let input = input.to_object(py).into_bound(py);
         let input_str: String = input.extract().unwrap();
-        let result = validator.validate_python(py, &input, None, None, None, None).unwrap();
+        let result = validator
+            .validate_python_with(py, &input, PythonValidateOptions::default())
+            .unwrap();
         let result_str: String = result.extract(py).unwrap();
         assert_eq!(result_str, input_str);
 
         let input = black_box(input);
-        bench.iter(|| black_box(validator.validate_python(py, &input, None, None, None, None).unwrap()))
+        bench.iter(|| {
+            black_box(
+                validator
+                    .validate_python_with(py, &input, PythonValidateOptions::default())
+                    .unwrap(),
+            )
+        })
     })
 }
 
@@ -646,12 +766,20 @@ fn literal_ints_many_json(bench: &mut Bencher) {
 
         let input_json = py.eval_bound("'99'", None, None).unwrap();
         let input_json = input_json.to_object(py).into_bound(py);
-        let result = validator.validate_json(py, &input_json, None, None, None).unwrap();
+        let result = validator
+            .validate_json(py, &input_json, None, None, None, "last", None, false)
+            .unwrap();
         let result_int: i64 = result.extract(py).unwrap();
         assert_eq!(result_int, 99);
 
         let input_json = black_box(input_json);
-        bench.iter(|| black_box(validator.validate_json(py, &input_json, None, None, None).unwrap()))
+        bench.iter(|| {
+            black_box(
+                validator
+                    .validate_json(py, &input_json, None, None, None, "last", None, false)
+                    .unwrap(),
+            )
+        })
     })
 }
 
@@ -668,12 +796,20 @@ fn literal_strings_many_large_json(bench: &mut Bencher) {
         let input_json = py.eval_bound("'\"' + 'a' * 25 + '99' + '\"'", None, None).unwrap();
         let input_json = input_json.to_object(py).into_bound(py);
         let input_str: String = input.extract().unwrap();
-        let result = validator.validate_json(py, &input_json, None, None, None).unwrap();
+        let result = validator
+            .validate_json(py, &input_json, None, None, None, "last", None, false)
+            .unwrap();
         let result_str: String = result.extract(py).unwrap();
         assert_eq!(result_str, input_str);
 
         let input_json = black_box(input_json);
-        bench.iter(|| black_box(validator.validate_json(py, &input_json, None, None, None).unwrap()))
+        bench.iter(|| {
+            black_box(
+                validator
+                    .validate_json(py, &input_json, None, None, None, "last", None, false)
+                    .unwrap(),
+            )
+        })
     })
 }
 
@@ -706,12 +842,20 @@ class Foo(Enum):
             let input = py.eval_bound("'null'", None, None).unwrap();
             let input = input.to_object(py).into_bound(py);
             let input_str: String = input.extract().unwrap();
-            let result = validator.validate_python(py, &input, None, None, None, None).unwrap();
+            let result = validator
+                .validate_python_with(py, &input, PythonValidateOptions::default())
+                .unwrap();
             let result_str: String = result.extract(py).unwrap();
             assert_eq!(result_str, input_str);
 
             let input = black_box(input);
-            bench.iter(|| black_box(validator.validate_python(py, &input, None, None, None, None).unwrap()))
+            bench.iter(|| {
+                black_box(
+                    validator
+                        .validate_python_with(py, &input, PythonValidateOptions::default())
+                        .unwrap(),
+                )
+            })
         }
 
         // Int
@@ -719,34 +863,58 @@ class Foo(Enum):
             let input = py.eval_bound("-1", None, None).unwrap();
             let input = input.to_object(py).into_bound(py);
             let input_int: i64 = input.extract().unwrap();
-            let result = validator.validate_python(py, &input, None, None, None, None).unwrap();
+            let result = validator
+                .validate_python_with(py, &input, PythonValidateOptions::default())
+                .unwrap();
             let result_int: i64 = result.extract(py).unwrap();
             assert_eq!(result_int, input_int);
 
             let input = black_box(input);
-            bench.iter(|| black_box(validator.validate_python(py, &input, None, None, None, None).unwrap()))
+            bench.iter(|| {
+                black_box(
+                    validator
+                        .validate_python_with(py, &input, PythonValidateOptions::default())
+                        .unwrap(),
+                )
+            })
         }
 
         // None
         {
             let input = py.eval_bound("None", None, None).unwrap();
             let input = input.to_object(py).into_bound(py);
-            let result = validator.validate_python(py, &input, None, None, None, None).unwrap();
+            let result = validator
+                .validate_python_with(py, &input, PythonValidateOptions::default())
+                .unwrap();
             assert!(input.eq(result).unwrap());
 
             let input = black_box(input);
-            bench.iter(|| black_box(validator.validate_python(py, &input, None, None, None, None).unwrap()))
+            bench.iter(|| {
+                black_box(
+                    validator
+                        .validate_python_with(py, &input, PythonValidateOptions::default())
+                        .unwrap(),
+                )
+            })
         }
 
         // Enum
         {
             let input = py.eval_bound("Foo.v4", Some(&globals), None).unwrap();
             let input = input.to_object(py).into_bound(py);
-            let result = validator.validate_python(py, &input, None, None, None, None).unwrap();
+            let result = validator
+                .validate_python_with(py, &input, PythonValidateOptions::default())
+                .unwrap();
             assert!(input.eq(result).unwrap());
 
             let input = black_box(input);
-            bench.iter(|| black_box(validator.validate_python(py, &input, None, None, None, None).unwrap()))
+            bench.iter(|| {
+                black_box(
+                    validator
+                        .validate_python_with(py, &input, PythonValidateOptions::default())
+                        .unwrap(),
+                )
+            })
         }
     })
 }
@@ -5,15 +5,19 @@ from typing import Any as _Any
 
 from ._pydantic_core import (
     ArgsKwargs,
+    JsonRaw,
     MultiHostUrl,
+    ParsedJson,
     PydanticCustomError,
     PydanticKnownError,
+    PydanticMultiError,
     PydanticOmit,
     PydanticSerializationError,
     PydanticSerializationUnexpectedValue,
     PydanticUndefined,
     PydanticUndefinedType,
     PydanticUseDefault,
+    RecursionGuard,
     SchemaError,
     SchemaSerializer,
     SchemaValidator,
@@ -21,10 +25,15 @@ from ._pydantic_core import (
     TzInfo,
     Url,
     ValidationError,
+    ValidationReport,
     __version__,
+    clear_schema_types,
     from_json,
+    register_schema_type,
+    to_cbor,
     to_json,
     to_jsonable_python,
+    unregister_schema_type,
     validate_core_schema,
 )
 from .core_schema import CoreConfig, CoreSchema, CoreSchemaType, ErrorType
@@ -50,6 +59,10 @@ __all__ = [
     'Url',
     'MultiHostUrl',
     'ArgsKwargs',
+    'ParsedJson',
+    'JsonRaw',
+    'ValidationReport',
+    'RecursionGuard',
     'PydanticUndefined',
     'PydanticUndefinedType',
     'SchemaError',
@@ -58,6 +71,7 @@ __all__ = [
     'ValidationError',
     'PydanticCustomError',
     'PydanticKnownError',
+    'PydanticMultiError',
     'PydanticOmit',
     'PydanticUseDefault',
     'PydanticSerializationError',
@@ -66,7 +80,11 @@ __all__ = [
     'to_json',
     'from_json',
     'to_jsonable_python',
+    'to_cbor',
     'validate_core_schema',
+    'register_schema_type',
+    'unregister_schema_type',
+    'clear_schema_types',
 ]
 
 
@@ -89,6 +107,10 @@ class ErrorDetails(_TypedDict):
     Values which are required to render the error message, and could hence be useful in rendering custom error messages.
     Also useful for passing custom error data forward.
     """
+    meta: _NotRequired[dict[str, _Any]]
+    """
+    The `metadata` of a [`PydanticCustomError`][pydantic_core.PydanticCustomError], passed through unchanged.
+    """
 
 
 class InitErrorDetails(_TypedDict):
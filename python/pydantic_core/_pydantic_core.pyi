@@ -1,5 +1,5 @@
 import datetime
-from typing import Any, Callable, Generic, Literal, TypeVar, final
+from typing import Any, Callable, Generic, Iterable, Iterator, Literal, Mapping, TypeVar, final
 
 from _typeshed import SupportsAllComparisons
 from typing_extensions import LiteralString, Self, TypeAlias
@@ -13,6 +13,7 @@ __all__ = [
     'build_info',
     '_recursion_limit',
     'ArgsKwargs',
+    'RecursionGuard',
     'SchemaValidator',
     'SchemaSerializer',
     'Url',
@@ -21,6 +22,7 @@ __all__ = [
     'ValidationError',
     'PydanticCustomError',
     'PydanticKnownError',
+    'PydanticMultiError',
     'PydanticOmit',
     'PydanticUseDefault',
     'PydanticSerializationError',
@@ -31,9 +33,13 @@ __all__ = [
     'to_json',
     'from_json',
     'to_jsonable_python',
+    'to_cbor',
     'list_all_errors',
     'TzInfo',
     'validate_core_schema',
+    'register_schema_type',
+    'unregister_schema_type',
+    'clear_schema_types',
 ]
 __version__: str
 build_profile: str
@@ -78,11 +84,42 @@ class SchemaValidator:
             schema: The [`CoreSchema`][pydantic_core.core_schema.CoreSchema] to use for validation.
             config: Optionally a [`CoreConfig`][pydantic_core.core_schema.CoreConfig] to configure validation.
         """
+    @staticmethod
+    def from_json_schema(schema: dict[str, Any] | bool, config: CoreConfig | None = None) -> SchemaValidator:
+        """
+        Create a new SchemaValidator from a [JSON Schema](https://json-schema.org/) document.
+
+        Only a subset of JSON Schema is supported: `type`, `properties`, `required`, `items`, `enum`,
+        `minimum`, `maximum`, `minLength`, `maxLength` and `pattern`. Annotations like `title` and `description`
+        are ignored, any other keyword raises a `SchemaError`. Keywords which only apply to some types, like
+        `properties` or `minLength`, must be used along with `type`.
+
+        Since JSON Schema doesn't coerce values, validation is strict, and objects allow additional properties.
+
+        Arguments:
+            schema: The JSON Schema document, as parsed from JSON.
+            config: Optionally a [`CoreConfig`][pydantic_core.core_schema.CoreConfig] to configure validation.
+
+        Raises:
+            SchemaError: If the JSON Schema uses unsupported keywords or is invalid.
+        """
     @property
     def title(self) -> str:
         """
         The title of the schema, as used in the heading of [`ValidationError.__str__()`][pydantic_core.ValidationError].
         """
+    def json_schema(self) -> dict[str, Any]:
+        """
+        Generate an approximate [JSON Schema](https://json-schema.org/) document describing the values
+        this validator accepts, built from the validator itself rather than the core schema.
+
+        Types, constraints like `ge`, `min_length` and `pattern`, object properties, required fields and enums are
+        included, recursive definitions are added under `$defs`. Validators without a JSON Schema equivalent,
+        e.g. plain and wrap function validators, accept any value and have a `description` explaining why.
+
+        Returns:
+            The JSON Schema as a dict, values like defaults are included as python objects.
+        """
     def validate_python(
         self,
         input: Any,
@@ -91,6 +128,17 @@ class SchemaValidator:
         from_attributes: bool | None = None,
         context: Any | None = None,
         self_instance: Any | None = None,
+        cache_shared: bool = False,
+        now: datetime.datetime | None = None,
+        require_all_fields: bool = False,
+        with_hash: bool = False,
+        profile: bool = False,
+        on_error: Literal['raise', 'collect'] = 'raise',
+        recursion_guard: RecursionGuard | None = None,
+        report_union_variant: bool = False,
+        report_exactness: bool = False,
+        report_aliases: bool = False,
+        canonicalize: bool | Literal['sets'] = False,
     ) -> Any:
         """
         Validate a Python object against the schema and return the validated object.
@@ -99,19 +147,127 @@ class SchemaValidator:
             input: The Python object to validate.
             strict: Whether to validate the object in strict mode.
                 If `None`, the value of [`CoreConfig.strict`][pydantic_core.core_schema.CoreConfig] is used.
+                `True` or `False` overrides the `strict` setting of every validator in the schema, including the
+                choice made by [`lax_or_strict_schema`][pydantic_core.core_schema.lax_or_strict_schema], so
+                `strict=True` disables all lax coercion for this call.
             from_attributes: Whether to validate objects as inputs to models by extracting attributes.
                 If `None`, the value of [`CoreConfig.from_attributes`][pydantic_core.core_schema.CoreConfig] is used.
             context: The context to use for validation, this is passed to functional validators as
                 [`info.context`][pydantic_core.core_schema.ValidationInfo.context].
             self_instance: An instance of a model set attributes on from validation, this is used when running
                 validation from the `__init__` method of a model.
+            cache_shared: Whether to validate an object referenced multiple times within the input only once,
+                reusing the result for later references. This applies to schemas referenced via
+                [`definition_reference_schema`][pydantic_core.core_schema.definition_reference_schema], and changes
+                behaviour if validators have side effects, since they're only called once per object.
+            now: A datetime to use instead of the current time when checking `now_op` constraints on dates and
+                datetimes, e.g. to make validation deterministic in tests. Naive datetimes are assumed to be in the
+                constraint's `now_utc_offset`.
+            require_all_fields: Whether every field of models and typed dicts must be present in the input, fields
+                that are absent raise a `missing` error instead of using their default. Fields explicitly set to
+                `None` are validated as usual.
+            with_hash: Whether to also compute a hash of the validated object's content, e.g. to use it as a cache
+                key. The hash is deterministic across processes and doesn't depend on the order of dicts and sets,
+                models and other objects are hashed via the python structures they serialize to.
+            profile: Whether to record how many times each validator ran and its cumulative time, for debugging
+                performance. The profile is a dict of `{name: {'calls': int, 'time': float}}` keyed by validator name,
+                times are in seconds measured with a monotonic clock and include time spent in nested validators.
+            on_error: `'collect'` to return a best-effort value instead of raising, where invalid items of lists,
+                tuples, dicts, typed dicts and models and missing fields are replaced with `None`.
+                Items within unions and function validators aren't replaced, so a failing union or function
+                validator is replaced as a whole, and errors within a `with_default` schema with `on_error` set
+                are handled by its `on_error` instead. Can't be combined with `with_hash`, `profile`,
+                `report_union_variant`, `report_exactness` or `report_aliases`.
+            recursion_guard: A [`RecursionGuard`][pydantic_core.RecursionGuard] to reuse for cyclic reference
+                detection instead of creating a new one, it's reset before validation.
+            report_union_variant: Whether to also return which variant of the schema matched, the index of the choice
+                for a `union` schema or the tag for a `tagged-union` schema. Only allowed if the schema is a union.
+            report_exactness: Whether to also return how exactly the input matched the schema as a whole: `'exact'`
+                if no conversion was needed, `'strict'` if the input was converted in a way allowed in strict mode
+                (e.g. a subclass) and `'lax'` if any part of the input was only valid in lax mode.
+            report_aliases: Whether to also return the input key each field of a model or typed dict was found by,
+                a dict of `{field_name: alias}` where the alias is a `str`, a list for an alias path, or `None` if the
+                field wasn't in the input and its default was used. With `case_insensitive` this is the key as it
+                appears in the input. Models record their aliases after those nested within their fields, so an
+                enclosing model wins, otherwise the last model or typed dict validated is reported, e.g. the last item
+                of a list of models. `None` is returned if the schema has no model or typed dict.
+            canonicalize: Whether to sort the keys of all dicts in the validated object, so equal inputs produce
+                identical structures, e.g. for content-addressed caching. Sets have no deterministic order, so
+                `'sets'` also replaces sets with sorted lists and frozensets with sorted tuples. Only dicts, lists,
+                tuples and sets are rebuilt, models and other objects are unchanged. Keys or elements which can't be
+                ordered keep their order with a warning.
+
+        Raises:
+            ValidationError: If validation fails and `on_error` is `'raise'`.
+            Exception: Other error types maybe raised if internal errors occur.
+
+        Returns:
+            The validated object, or a [`ValidationReport`][pydantic_core.ValidationReport] holding the validated
+                object and the requested details if any of `with_hash`, `profile`, `report_union_variant`,
+                `report_exactness` or `report_aliases` is `True`.
+                If `on_error` is `'collect'`, a `ValidationReport` which unpacks as a tuple of the best-effort value
+                and a `ValidationError` holding all errors, or `None` if validation succeeded.
+        """
+    def validate_python_into(
+        self,
+        input: Any,
+        buffer: list[Any],
+        *,
+        strict: bool | None = None,
+        from_attributes: bool | None = None,
+        context: Any | None = None,
+    ) -> None:
+        """
+        Validate a Python object against a schema which produces a list, writing the validated items into
+        `buffer` rather than a new list.
+
+        The contents of `buffer` are replaced by the validated items once validation succeeds, the list itself is
+        kept so `buffer` may also be `input`. If validation fails, `buffer` is left empty.
+
+        Arguments:
+            input: The Python object to validate.
+            buffer: The list to write the validated items into.
+            strict: Whether to validate the object in strict mode.
+                If `None`, the value of [`CoreConfig.strict`][pydantic_core.core_schema.CoreConfig] is used.
+            from_attributes: Whether to validate objects as inputs to models by extracting attributes.
+                If `None`, the value of [`CoreConfig.from_attributes`][pydantic_core.core_schema.CoreConfig] is used.
+            context: The context to use for validation, this is passed to functional validators as
+                [`info.context`][pydantic_core.core_schema.ValidationInfo.context].
 
         Raises:
             ValidationError: If validation fails.
+            TypeError: If the validated object isn't a list.
             Exception: Other error types maybe raised if internal errors occur.
+        """
+    def validate_python_batch(
+        self,
+        inputs: Iterable[Any],
+        *,
+        strict: bool | None = None,
+        from_attributes: bool | None = None,
+        context: Any | None = None,
+    ) -> list[tuple[Any, ValidationError | None]]:
+        """
+        Validate each of an iterable of Python objects, reusing the validation state between them which is
+        cheaper than calling [`validate_python()`][pydantic_core.SchemaValidator.validate_python] for each.
+
+        An input which fails validation doesn't stop the batch, its `ValidationError` is returned instead.
+
+        Arguments:
+            inputs: The Python objects to validate.
+            strict: Whether to validate the objects in strict mode.
+                If `None`, the value of [`CoreConfig.strict`][pydantic_core.core_schema.CoreConfig] is used.
+            from_attributes: Whether to validate objects as inputs to models by extracting attributes.
+                If `None`, the value of [`CoreConfig.from_attributes`][pydantic_core.core_schema.CoreConfig] is used.
+            context: The context to use for validation, this is passed to functional validators as
+                [`info.context`][pydantic_core.core_schema.ValidationInfo.context].
+
+        Raises:
+            Exception: Other error types maybe raised if internal errors occur, which stops the batch.
 
         Returns:
-            The validated object.
+            A list with a `(value, error)` pair for each input, `error` is `None` if validation succeeded,
+                otherwise `value` is `None` and `error` is the `ValidationError`.
         """
     def isinstance_python(
         self,
@@ -138,6 +294,9 @@ class SchemaValidator:
         strict: bool | None = None,
         context: Any | None = None,
         self_instance: Any | None = None,
+        duplicate_keys: Literal['first', 'last', 'error', 'list'] = 'last',
+        cache_strings: bool | Literal['all', 'keys', 'none'] | None = None,
+        with_hash: bool = False,
     ) -> Any:
         """
         Validate JSON data directly against the schema and return the validated Python object.
@@ -155,11 +314,49 @@ class SchemaValidator:
             context: The context to use for validation, this is passed to functional validators as
                 [`info.context`][pydantic_core.core_schema.ValidationInfo.context].
             self_instance: An instance of a model set attributes on from validation.
+            duplicate_keys: How to handle objects with the same key more than once, as for `from_json`, `'last'`
+                (the default) and `'first'` keep the last or first value at the position of the first occurrence,
+                `'error'` makes the JSON invalid and `'list'` collects the values of a repeated key into an array.
+                Repeated keys are combined before validation, so dict schemas never see them.
+            cache_strings: Whether to cache strings created from the JSON data, as for `from_json`, turning caching off
+                avoids holding on to memory when validating many unique strings. If `None`, the value of
+                [`CoreConfig.cache_strings`][pydantic_core.core_schema.CoreConfig] is used.
+            with_hash: Whether to also compute a hash of the validated object's content, as for `validate_python`.
 
         Raises:
             ValidationError: If validation fails or if the JSON data is invalid.
             Exception: Other error types maybe raised if internal errors occur.
 
+        Returns:
+            The validated Python object, or a [`ValidationReport`][pydantic_core.ValidationReport] which unpacks as
+                a `(value, hash)` tuple if `with_hash` is `True`.
+        """
+    def validate_json_value(
+        self,
+        input: ParsedJson,
+        *,
+        strict: bool | None = None,
+        context: Any | None = None,
+        self_instance: Any | None = None,
+    ) -> Any:
+        """
+        Validate JSON data which has already been parsed into a [`ParsedJson`][pydantic_core.ParsedJson] instance.
+
+        This behaves exactly like `validate_json`, but allows the same parsed data to be validated multiple times
+        (e.g. against different schemas) without parsing the raw JSON again.
+
+        Arguments:
+            input: The parsed JSON data to validate.
+            strict: Whether to validate the object in strict mode.
+                If `None`, the value of [`CoreConfig.strict`][pydantic_core.core_schema.CoreConfig] is used.
+            context: The context to use for validation, this is passed to functional validators as
+                [`info.context`][pydantic_core.core_schema.ValidationInfo.context].
+            self_instance: An instance of a model set attributes on from validation.
+
+        Raises:
+            ValidationError: If validation fails.
+            Exception: Other error types maybe raised if internal errors occur.
+
         Returns:
             The validated Python object.
         """
@@ -184,6 +381,34 @@ class SchemaValidator:
         Returns:
             The validated Python object.
         """
+    def validate_call(
+        self,
+        args: tuple[Any, ...],
+        kwargs: dict[str, Any] | None = None,
+        *,
+        strict: bool | None = None,
+        context: Any | None = None,
+    ) -> ArgsKwargs:
+        """
+        Validate the arguments of a function call against an
+        [`arguments_schema`][pydantic_core.core_schema.arguments_schema] and return the bound arguments.
+
+        Arguments:
+            args: The positional arguments of the call.
+            kwargs: The keyword arguments of the call.
+            strict: Whether to validate the arguments in strict mode.
+                If `None`, the value of [`CoreConfig.strict`][pydantic_core.core_schema.CoreConfig] is used.
+            context: The context to use for validation, this is passed to functional validators as
+                [`info.context`][pydantic_core.core_schema.ValidationInfo.context].
+
+        Raises:
+            ValidationError: If validation fails, errors are located by parameter index or name.
+            TypeError: If the schema is not an arguments schema.
+
+        Returns:
+            The validated arguments with defaults applied, positional parameters are in `args` and
+            keyword-only parameters and `**kwargs` are in `kwargs`.
+        """
     def validate_assignment(
         self,
         obj: Any,
@@ -341,6 +566,56 @@ class SchemaSerializer:
         Returns:
            JSON bytes.
         """
+    def to_json_into(
+        self,
+        value: Any,
+        fp: Any,
+        *,
+        indent: int | None = None,
+        include: _IncEx = None,
+        exclude: _IncEx = None,
+        by_alias: bool = True,
+        exclude_unset: bool = False,
+        exclude_defaults: bool = False,
+        exclude_none: bool = False,
+        round_trip: bool = False,
+        warnings: bool | Literal['none', 'warn', 'error'] = True,
+        fallback: Callable[[Any], Any] | None = None,
+        serialize_as_any: bool = False,
+        context: Any | None = None,
+    ) -> None:
+        """
+        Serialize a Python object to JSON like [`to_json`][pydantic_core.SchemaSerializer.to_json], but write the
+        output incrementally to a binary file-like object rather than returning it, so the whole output is never
+        held in memory.
+
+        Output is buffered and passed to `fp.write()` in chunks, if serialization fails part of the output may
+        already have been written.
+
+        Arguments:
+            value: The Python object to serialize.
+            fp: A binary file-like object with a `write` method accepting `bytes`.
+            indent: If `None`, the JSON will be compact, otherwise it will be pretty-printed with the indent provided.
+            include: A set of fields to include, if `None` all fields are included.
+            exclude: A set of fields to exclude, if `None` no fields are excluded.
+            by_alias: Whether to use the alias names of fields.
+            exclude_unset: Whether to exclude fields that are not set,
+                e.g. are not included in `__pydantic_fields_set__`.
+            exclude_defaults: Whether to exclude fields that are equal to their default value.
+            exclude_none: Whether to exclude fields that have a value of `None`.
+            round_trip: Whether to enable serialization and validation round-trip support.
+            warnings: How to handle invalid fields. False/"none" ignores them, True/"warn" logs errors,
+                "error" raises a [`PydanticSerializationError`][pydantic_core.PydanticSerializationError].
+            fallback: A function to call when an unknown value is encountered,
+                if `None` a [`PydanticSerializationError`][pydantic_core.PydanticSerializationError] error is raised.
+            serialize_as_any: Whether to serialize fields with duck-typing serialization behavior.
+            context: The context to use for serialization, this is passed to functional serializers as
+                [`info.context`][pydantic_core.core_schema.SerializationInfo.context].
+
+        Raises:
+            PydanticSerializationError: If serialization fails and no `fallback` function is provided.
+            Exception: Any error raised by `fp.write()`.
+        """
 
 def to_json(
     value: Any,
@@ -396,6 +671,9 @@ def from_json(
     allow_inf_nan: bool = True,
     cache_strings: bool | Literal['all', 'keys', 'none'] = True,
     allow_partial: bool = False,
+    number_mode: Literal['number', 'string'] = 'number',
+    json5: bool = False,
+    duplicate_keys: Literal['first', 'last', 'error', 'list'] = 'last',
 ) -> Any:
     """
     Deserialize JSON data to a Python object.
@@ -410,6 +688,15 @@ def from_json(
             `all/True` means cache all strings, `keys` means cache only dict keys, `none/False` means no caching.
         allow_partial: Whether to allow partial deserialization, if `True` JSON data is returned if the end of the
             input is reached before the full object is deserialized, e.g. `["aa", "bb", "c` would return `['aa', 'bb']`.
+        number_mode: How to return JSON numbers, `number` converts them to `int` or `float`, `string` returns
+            every number as a `str` containing the exact digits from the input, e.g. `1.10` returns `'1.10'`.
+            `string` cannot be combined with `allow_partial`.
+        json5: Whether to allow `//` line comments, `/* */` block comments and trailing commas in objects and arrays,
+            positions in error messages still refer to the original input.
+        duplicate_keys: How to handle objects with the same key more than once, `last` and `first` keep the last
+            or first value at the position of the first occurrence, `error` raises a `ValueError` and `list` collects
+            the values of a repeated key into a list. `first` and `list` cannot be combined with `allow_partial` or
+            `number_mode='string'`.
 
     Raises:
         ValueError: If deserialization fails.
@@ -433,6 +720,9 @@ def to_jsonable_python(
     fallback: Callable[[Any], Any] | None = None,
     serialize_as_any: bool = False,
     context: Any | None = None,
+    keep_native: set[str] | None = None,
+    max_depth: int | None = None,
+    max_items: int | None = None,
 ) -> Any:
     """
     Serialize/marshal a Python object to a JSON-serializable Python object including transforming and filtering data.
@@ -457,6 +747,12 @@ def to_jsonable_python(
         serialize_as_any: Whether to serialize fields with duck-typing serialization behavior.
         context: The context to use for serialization, this is passed to functional serializers as
             [`info.context`][pydantic_core.core_schema.SerializationInfo.context].
+        keep_native: Names of types to leave as Python objects rather than converting, any of `'bytes'`,
+            `'datetime'`, `'date'`, `'time'`, `'timedelta'`, `'decimal'`, `'uuid'`, `'url'` and `'multi_host_url'`.
+        max_depth: How many collections deep to serialize, deeper lists, dicts, models etc. are replaced with
+            `'...(truncated)'`, if `None` there's no limit.
+        max_items: The maximum number of items to serialize from each collection, the remaining items of lists are
+            replaced with a single `'...(truncated)'` item and those of dicts with a `'...(truncated)'` key.
 
     Raises:
         PydanticSerializationError: If serialization fails and no `fallback` function is provided.
@@ -465,6 +761,58 @@ def to_jsonable_python(
         The serialized Python object.
     """
 
+def to_cbor(
+    value: Any,
+    *,
+    include: _IncEx = None,
+    exclude: _IncEx = None,
+    by_alias: bool = True,
+    exclude_none: bool = False,
+    round_trip: bool = False,
+    timedelta_mode: Literal['iso8601', 'float'] = 'iso8601',
+    inf_nan_mode: Literal['null', 'constants'] = 'constants',
+    serialize_unknown: bool = False,
+    fallback: Callable[[Any], Any] | None = None,
+    serialize_as_any: bool = False,
+    context: Any | None = None,
+    datetime_mode: Literal['iso8601', 'epoch'] = 'iso8601',
+) -> bytes:
+    """
+    Serialize a Python object to [CBOR](https://www.rfc-editor.org/rfc/rfc8949.html) including transforming and
+    filtering data.
+
+    Values are serialized as by [`to_json`][pydantic_core.to_json], except `bytes` are encoded as byte strings,
+    timezone aware datetimes as tag 0 date/time strings or tag 1 epoch timestamps, decimals as tag 4 decimal
+    fractions and integers which don't fit in 64 bits as tag 2 and 3 bignums. Naive datetimes don't identify an
+    instant, so they're encoded as plain text strings.
+
+    Args:
+        value: The Python object to serialize.
+        include: A set of fields to include, if `None` all fields are included.
+        exclude: A set of fields to exclude, if `None` no fields are excluded.
+        by_alias: Whether to use the alias names of fields.
+        exclude_none: Whether to exclude fields that have a value of `None`.
+        round_trip: Whether to enable serialization and validation round-trip support.
+        timedelta_mode: How to serialize `timedelta` objects, either `'iso8601'` or `'float'`.
+        inf_nan_mode: How to serialize `Infinity`, `-Infinity` and `NaN` values, either `'null'` or `'constants'`.
+        serialize_unknown: Attempt to serialize unknown types, `str(value)` will be used, if that fails
+            `"<Unserializable {value_type} object>"` will be used.
+        fallback: A function to call when an unknown value is encountered,
+            if `None` a [`PydanticSerializationError`][pydantic_core.PydanticSerializationError] error is raised.
+        serialize_as_any: Whether to serialize fields with duck-typing serialization behavior.
+        context: The context to use for serialization, this is passed to functional serializers as
+            [`info.context`][pydantic_core.core_schema.SerializationInfo.context].
+        datetime_mode: How to encode timezone aware datetimes, either `'iso8601'` for tag 0 date/time strings or
+            `'epoch'` for tag 1 epoch timestamps, naive datetimes can't be encoded as epoch timestamps.
+
+    Raises:
+        PydanticSerializationError: If serialization fails and no `fallback` function is provided, or if a naive
+            datetime is serialized with `datetime_mode='epoch'`.
+
+    Returns:
+       CBOR bytes.
+    """
+
 class Url(SupportsAllComparisons):
     """
     A URL type, internal logic uses the [url rust crate](https://docs.rs/url/latest/url/) originally developed
@@ -598,6 +946,32 @@ class Url(SupportsAllComparisons):
         Returns:
             An instance of URL
         """
+    def with_scheme(self, scheme: str) -> Self:
+        """
+        Return a copy of the URL with a different scheme, the new URL is validated again.
+
+        Args:
+            scheme: The new scheme, e.g. `'https'`.
+
+        Raises:
+            ValueError: If `scheme` isn't a valid URL scheme.
+            ValidationError: If the resulting URL is invalid.
+        """
+    def with_path(self, path: str) -> Self:
+        """
+        Return a copy of the URL with a different path, the new URL is validated again.
+
+        Args:
+            path: The new path, characters which aren't allowed in a path are percent-encoded.
+        """
+    def with_query(self, params: str | Mapping[str, str] | Iterable[tuple[str, str]] | None, /) -> Self:
+        """
+        Return a copy of the URL with a different query, the new URL is validated again.
+
+        Args:
+            params: The new query, either as a string or as key-value pairs which are form-urlencoded,
+                `None` or empty pairs remove the query.
+        """
 
 class MultiHostUrl(SupportsAllComparisons):
     """
@@ -787,6 +1161,17 @@ class ValidationError(ValueError):
         Returns:
             A list of [`ErrorDetails`][pydantic_core.ErrorDetails] for each error in the validation error.
         """
+    def errors_tree(self) -> dict[str | int | None, Any]:
+        """
+        The error messages nested by location, e.g. `{'items': {0: {'name': ['Field required']}}}`.
+
+        Each location maps to a list of error messages, if a location has errors of its own as well as errors
+        in nested locations, its own messages are stored under the `None` key; errors with an empty location
+        are also stored under `None`.
+
+        Returns:
+            A nested dict of error messages.
+        """
     def json(
         self,
         *,
@@ -823,11 +1208,26 @@ class ValidationError(ValueError):
 @final
 class PydanticCustomError(ValueError):
     def __new__(
-        cls, error_type: LiteralString, message_template: LiteralString, context: dict[str, Any] | None = None
-    ) -> Self: ...
+        cls,
+        error_type: LiteralString,
+        message_template: LiteralString,
+        context: dict[str, Any] | None = None,
+        metadata: dict[str, Any] | None = None,
+    ) -> Self:
+        """
+        Arguments:
+            error_type: The error type.
+            message_template: The message template, with `{name}` placeholders filled from `context`.
+            context: The values used to render the message, included as `ctx` in the error details.
+            metadata: Arbitrary data such as codes for routing errors in a client, included unchanged as `meta` in
+                [`ValidationError.errors()`][pydantic_core.ValidationError.errors] and
+                [`ValidationError.json()`][pydantic_core.ValidationError.json].
+        """
     @property
     def context(self) -> dict[str, Any] | None: ...
     @property
+    def metadata(self) -> dict[str, Any] | None: ...
+    @property
     def type(self) -> str: ...
     @property
     def message_template(self) -> str: ...
@@ -844,6 +1244,24 @@ class PydanticKnownError(ValueError):
     def message_template(self) -> str: ...
     def message(self) -> str: ...
 
+@final
+class PydanticMultiError(ValueError):
+    def __new__(cls, errors: Iterable[tuple[tuple[str | int, ...], PydanticCustomError | PydanticKnownError]]) -> Self:
+        """
+        Several errors raised together by one validator, e.g. a function validator which finds several invalid
+        fields in one pass.
+
+        Arguments:
+            errors: `(loc, error)` pairs, where `loc` is the location of the error relative to the validator
+                (`()` for the validator's own location), it's prefixed with the validator's location in the
+                resulting [`ValidationError`][pydantic_core.ValidationError].
+        """
+    @property
+    def errors(self) -> list[tuple[tuple[str | int, ...], str]]:
+        """
+        The relative location and message of each error.
+        """
+
 @final
 class PydanticOmit(Exception):
     def __new__(cls) -> Self: ...
@@ -860,6 +1278,132 @@ class PydanticSerializationError(ValueError):
 class PydanticSerializationUnexpectedValue(ValueError):
     def __new__(cls, message: str | None = None) -> Self: ...
 
+@final
+class ParsedJson:
+    """
+    JSON data parsed once and held in its native form, use
+    [`SchemaValidator.validate_json_value`][pydantic_core.SchemaValidator.validate_json_value] to validate it.
+    """
+
+    def __new__(cls, data: str | bytes | bytearray, *, allow_inf_nan: bool = True) -> Self:
+        """
+        Arguments:
+            data: The JSON data to parse.
+            allow_inf_nan: Whether to allow `Infinity`, `-Infinity` and `NaN` values as `json.loads()` does by default.
+
+        Raises:
+            ValueError: If parsing fails.
+        """
+    def to_python(self) -> Any:
+        """
+        Convert the parsed JSON data to Python objects, e.g. for inspection.
+        """
+
+@final
+class JsonRaw:
+    """
+    The result of a [`json_raw_schema`][pydantic_core.core_schema.json_raw_schema], the validated value and the exact
+    bytes of the JSON it was validated from.
+    """
+
+    def __new__(cls, value: Any, raw: bytes | None = None) -> Self: ...
+    @property
+    def value(self) -> Any:
+        """
+        The validated value.
+        """
+    @property
+    def raw(self) -> bytes | None:
+        """
+        The raw JSON of the value, `None` unless the value was validated with
+        [`SchemaValidator.validate_json`][pydantic_core.SchemaValidator.validate_json].
+        """
+
+@final
+class ValidationReport:
+    """
+    Returned by [`SchemaValidator.validate_python`][pydantic_core.SchemaValidator.validate_python] when any of
+    `with_hash`, `profile`, `report_union_variant`, `report_exactness` or `report_aliases` is `True`, or when
+    `on_error` is `'collect'`, and by [`SchemaValidator.validate_json`][pydantic_core.SchemaValidator.validate_json]
+    when `with_hash` is `True`, details which weren't requested are `None`.
+
+    The report also behaves like a tuple of `value` followed by the requested details in the order of the
+    attributes below, e.g.:
+
+    ```py
+    from pydantic_core import SchemaValidator, core_schema
+
+    v = SchemaValidator(core_schema.int_schema())
+    value, hash = v.validate_python('1', with_hash=True)
+    assert value == 1
+    value, exactness, hash = v.validate_python('1', report_exactness=True, with_hash=True)
+    assert exactness == 'lax'
+    assert v.validate_python('1', on_error='collect') == (1, None)
+    ```
+    """
+
+    def __len__(self) -> int: ...
+    def __getitem__(self, index: int) -> Any: ...
+    def __iter__(self) -> Iterator[Any]: ...
+
+    @property
+    def value(self) -> Any:
+        """
+        The validated object.
+        """
+    @property
+    def union_variant(self) -> Any | None:
+        """
+        The index of the choice of a `union` schema or the tag of a `tagged-union` schema which matched.
+        """
+    @property
+    def exactness(self) -> Literal['exact', 'strict', 'lax'] | None:
+        """
+        How exactly the input matched the schema as a whole.
+        """
+    @property
+    def aliases(self) -> dict[str, str | list[str | int] | None] | None:
+        """
+        The input key each field of the last model or typed dict to finish validating was found by.
+        """
+    @property
+    def hash(self) -> int | None:
+        """
+        The hash of the validated object's content.
+        """
+    @property
+    def profile(self) -> dict[str, dict[str, Any]] | None:
+        """
+        The number of calls and cumulative time of each validator.
+        """
+    @property
+    def errors(self) -> ValidationError | None:
+        """
+        With `on_error='collect'`, a `ValidationError` holding all errors, or `None` if validation succeeded.
+        """
+
+@final
+class RecursionGuard:
+    """
+    State used to detect cyclic references during validation, which can be reused across calls to
+    [`SchemaValidator.validate_python`][pydantic_core.SchemaValidator.validate_python] to avoid allocating it
+    for every call, e.g. when validating a stream of many small items.
+
+    It can be used as a context manager, on exit any memory it holds is released.
+
+    ```py
+    from pydantic_core import RecursionGuard, SchemaValidator, core_schema
+
+    v = SchemaValidator(core_schema.int_schema())
+    with RecursionGuard() as guard:
+        assert [v.validate_python(x, recursion_guard=guard) for x in ['1', '2']] == [1, 2]
+    ```
+    """
+
+    def __new__(cls) -> Self: ...
+    def __enter__(self) -> Self: ...
+    def __exit__(self, *args: Any) -> None: ...
+
 @final
 class ArgsKwargs:
     def __new__(cls, args: tuple[Any, ...], kwargs: dict[str, Any] | None = None) -> Self: ...
@@ -897,3 +1441,40 @@ def validate_core_schema(schema: CoreSchema, *, strict: bool | None = None) -> C
     We may also remove this function altogether, do not rely on it being present if you are
     using pydantic-core directly.
     """
+
+def register_schema_type(schema_type: str, build: Callable[[dict[str, Any]], CoreSchema]) -> None:
+    """
+    Register a custom schema type, so schemas like `{'type': 'money', 'currency': 'EUR'}` can be used anywhere
+    within the schemas passed to [`SchemaValidator`][pydantic_core.SchemaValidator] and
+    [`SchemaSerializer`][pydantic_core.SchemaSerializer].
+
+    When a schema of a registered type is built, `build` is called with the schema and the schema it returns is
+    built in its place, this may itself be of a registered type. Registering a type again replaces its build
+    function for schemas built afterwards. Custom types aren't known to
+    [`validate_core_schema`][pydantic_core.validate_core_schema].
+
+    Arguments:
+        schema_type: The name used as the schema's `type`, this can't be the type of a built-in schema.
+        build: A function taking a schema of this type and returning the schema to use in its place.
+
+    Raises:
+        ValueError: If `schema_type` is the type of a built-in schema.
+        TypeError: If `build` isn't callable.
+    """
+
+def unregister_schema_type(schema_type: str) -> None:
+    """
+    Remove a custom schema type registered with [`register_schema_type`][pydantic_core.register_schema_type],
+    validators and serializers which have already been built aren't affected.
+
+    Arguments:
+        schema_type: The name of the registered schema type.
+
+    Raises:
+        KeyError: If `schema_type` isn't registered.
+    """
+
+def clear_schema_types() -> None:
+    """
+    Remove all custom schema types registered with [`register_schema_type`][pydantic_core.register_schema_type].
+    """
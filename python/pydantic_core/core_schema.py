@@ -42,6 +42,7 @@ else:
 
 
 ExtraBehavior = Literal['allow', 'forbid', 'ignore']
+CaseCollision = Literal['prefer_exact', 'error']
 
 
 class CoreConfig(TypedDict, total=False):
@@ -54,6 +55,8 @@ class CoreConfig(TypedDict, total=False):
         extra_fields_behavior: The behavior for handling extra fields.
         typed_dict_total: Whether the TypedDict should be considered total. Default is `True`.
         from_attributes: Whether to use attributes for models, dataclasses, and tagged union keys.
+        from_attributes_safe: Whether models only read attributes stored on the object itself, in its `__dict__` or
+            `__slots__`, when validating from attributes, so no properties or `__getattr__` are run. Default is `False`.
         loc_by_alias: Whether to use the used alias (or first alias for "field required" errors) instead of
             `field_names` to construct error `loc`s. Default is `True`.
         revalidate_instances: Whether instances of models and dataclasses should re-validate. Default is 'never'.
@@ -66,10 +69,24 @@ class CoreConfig(TypedDict, total=False):
         str_to_lower: Whether to convert string fields to lowercase.
         str_to_upper: Whether to convert string fields to uppercase.
         allow_inf_nan: Whether to allow infinity and NaN values for float fields. Default is `True`.
+        allow_bool: Whether lax mode accepts `True` and `False` as `1` and `0` for int, float and decimal fields.
+            By default int and float fields accept them while decimal fields don't.
+        json_coerce_strings: Whether lax mode parses JSON strings as bools, ints and floats, e.g. `"42"` as `42`.
+            Set it to `False` to only accept JSON's native types, this doesn't affect Python input. Default is `True`.
         ser_json_timedelta: The serialization option for `timedelta` values. Default is 'iso8601'.
         ser_json_bytes: The serialization option for `bytes` values. Default is 'utf8'.
+        val_json_bytes: How strings are decoded to `bytes` when validating JSON, set it to the same value as
+            `ser_json_bytes` so serialized `bytes` round-trip. Default is 'utf8'.
         ser_json_inf_nan: The serialization option for infinity and NaN values
             in float fields. Default is 'null'.
+        ser_json_large_int: The serialization option for integers too large to be represented exactly by
+            a JavaScript number. Default is 'number', 'string' emits such integers as strings.
+        ser_json_large_int_threshold: The absolute value above which integers are considered "large".
+            Default is `2**53 - 1` (`Number.MAX_SAFE_INTEGER`).
+        ser_json_enum: The serialization option for `Enum` members, either their `value` or their `name`.
+            Default is 'value'.
+        ser_max_depth: The maximum depth of recursive values when serializing, beyond which a
+            `PydanticSerializationError` is raised, as a backstop for cycles which can't be detected by object id.
         hide_input_in_errors: Whether to hide input data from `ValidationError` representation.
         validation_error_cause: Whether to add user-python excs to the __cause__ of a ValidationError.
             Requires exceptiongroup backport pre Python 3.11.
@@ -77,6 +94,10 @@ class CoreConfig(TypedDict, total=False):
         regex_engine: The regex engine to use for regex pattern validation. Default is 'rust-regex'. See `StringSchema`.
         cache_strings: Whether to cache strings. Default is `True`, `True` or `'all'` is required to cache strings
             during general validation since validators don't know if they're in a key or a value.
+        explain_strict: Whether to add an `explanation` to the error context when strict mode rejects an input
+            which lax mode would have accepted, applies to `int`, `float`, `bool`, `str` and `bytes`. Default is `False`.
+        max_errors: The maximum number of errors to collect while validating, once reached any further errors are
+            dropped and a single `too_many_errors` error records how many were omitted. By default there is no limit.
     """
 
     title: str
@@ -86,6 +107,8 @@ class CoreConfig(TypedDict, total=False):
     typed_dict_total: bool  # default: True
     # used for models, dataclasses, and tagged union keys
     from_attributes: bool
+    # only read attributes from the instance `__dict__` or `__slots__` with from_attributes, default False
+    from_attributes_safe: bool
     # whether to use the used alias (or first alias for "field required" errors) instead of field_names
     # to construct error `loc`s, default True
     loc_by_alias: bool
@@ -103,16 +126,27 @@ class CoreConfig(TypedDict, total=False):
     str_to_upper: bool
     # fields related to float fields only
     allow_inf_nan: bool  # default: True
+    # fields related to int, float and decimal fields
+    allow_bool: bool
+    # fields related to bool, int and float fields
+    json_coerce_strings: bool  # default: True
     # the config options are used to customise serialization to JSON
     ser_json_timedelta: Literal['iso8601', 'float']  # default: 'iso8601'
     ser_json_bytes: Literal['utf8', 'base64', 'hex']  # default: 'utf8'
+    val_json_bytes: Literal['utf8', 'base64', 'hex']  # default: 'utf8'
     ser_json_inf_nan: Literal['null', 'constants']  # default: 'null'
+    ser_json_large_int: Literal['number', 'string']  # default: 'number'
+    ser_json_large_int_threshold: int  # default: 2**53 - 1
+    ser_json_enum: Literal['value', 'name']  # default: 'value'
+    ser_max_depth: int
     # used to hide input data from ValidationError repr
     hide_input_in_errors: bool
     validation_error_cause: bool  # default: False
     coerce_numbers_to_str: bool  # default: False
     regex_engine: Literal['rust-regex', 'python-re']  # default: 'rust-regex'
     cache_strings: Union[bool, Literal['all', 'keys', 'none']]  # default: 'True'
+    explain_strict: bool  # default: False
+    max_errors: int  # default: no limit
 
 
 IncExCall: TypeAlias = 'set[int | str] | dict[int | str, IncExCall] | None'
@@ -374,7 +408,11 @@ class FormatSerSchema(TypedDict, total=False):
 
 def format_ser_schema(formatting_string: str, *, when_used: WhenUsed = 'json-unless-none') -> FormatSerSchema:
     """
-    Returns a schema for serialization using python's `format` method.
+    Returns a schema for serialization using python's `format` method, e.g. a `strftime` format for datetimes
+    or `'.2f'` for floats.
+
+    When used on an `int`, `float`, `date`, `time` or `datetime` schema, the formatting string is checked
+    against a sample value when the serializer is built, so invalid format specs raise a `SchemaError`.
 
     Args:
         formatting_string: String defining the format to use
@@ -422,6 +460,30 @@ def model_ser_schema(cls: Type[Any], schema: CoreSchema) -> ModelSerSchema:
     return ModelSerSchema(type='model', cls=cls, schema=schema)
 
 
+class ConstantSerSchema(TypedDict, total=False):
+    type: Required[Literal['constant']]
+    check: bool  # default: False
+
+
+def constant_ser_schema(*, check: bool | None = None) -> ConstantSerSchema:
+    """
+    Returns a schema for serialization of a `literal` schema with a single expected value as that value, without
+    reading the instance, e.g. to keep discriminator fields consistent:
+
+    ```py
+    from pydantic_core import SchemaSerializer, core_schema
+
+    schema = core_schema.literal_schema(['cat'], serialization=core_schema.constant_ser_schema())
+    s = SchemaSerializer(schema)
+    assert s.to_python('dog') == 'cat'
+    ```
+
+    Args:
+        check: Whether to warn if the value being serialized isn't the constant
+    """
+    return _dict_not_none(type='constant', check=check)
+
+
 SerSchema = Union[
     SimpleSerSchema,
     PlainSerializerFunctionSerSchema,
@@ -429,6 +491,7 @@ SerSchema = Union[
     FormatSerSchema,
     ToStringSerSchema,
     ModelSerSchema,
+    ConstantSerSchema,
 ]
 
 
@@ -511,16 +574,35 @@ def none_schema(*, ref: str | None = None, metadata: Any = None, serialization:
     return _dict_not_none(type='none', ref=ref, metadata=metadata, serialization=serialization)
 
 
+class StringParseOptions(TypedDict, total=False):
+    trim: bool  # strip leading and trailing whitespace, default: True for ints and floats, False for bools
+    allow_underscores: bool  # allow underscores between digits, e.g. '1_000', default: True
+    allow_sign_whitespace: bool  # allow whitespace after the sign, e.g. '- 1', default: False
+    thousands_separator: str  # grouping separator removed before parsing, e.g. ',' for '1,234', default: None
+    decimal_separator: str  # decimal mark, e.g. ',' for '1,5', default: '.'
+    strict_grouping: bool  # require thousands_separator to separate groups of three digits, default: False
+
+
 class BoolSchema(TypedDict, total=False):
     type: Required[Literal['bool']]
     strict: bool
+    explain_strict: bool  # default: False
+    string_parse_options: StringParseOptions
+    json_coerce_strings: bool  # whether lax mode parses JSON strings. default: True
     ref: str
     metadata: Any
     serialization: SerSchema
 
 
 def bool_schema(
-    strict: bool | None = None, ref: str | None = None, metadata: Any = None, serialization: SerSchema | None = None
+    strict: bool | None = None,
+    ref: str | None = None,
+    metadata: Any = None,
+    serialization: SerSchema | None = None,
+    *,
+    explain_strict: bool | None = None,
+    string_parse_options: StringParseOptions | None = None,
+    json_coerce_strings: bool | None = None,
 ) -> BoolSchema:
     """
     Returns a schema that matches a bool value, e.g.:
@@ -538,8 +620,21 @@ def bool_schema(
         ref: optional unique identifier of the schema, used to reference the schema in other places
         metadata: Any other information you want to include with the schema, not used by pydantic-core
         serialization: Custom serialization schema
+        explain_strict: Whether to add an `explanation` to strict mode errors for inputs lax mode would accept,
+            overrides `explain_strict` in the config
+        string_parse_options: How strings are parsed in lax mode, only `trim` applies to bools
+        json_coerce_strings: Whether JSON strings like `"true"` are accepted in lax mode, defaults to `True`
     """
-    return _dict_not_none(type='bool', strict=strict, ref=ref, metadata=metadata, serialization=serialization)
+    return _dict_not_none(
+        type='bool',
+        strict=strict,
+        explain_strict=explain_strict,
+        ref=ref,
+        metadata=metadata,
+        serialization=serialization,
+        string_parse_options=string_parse_options,
+        json_coerce_strings=json_coerce_strings,
+    )
 
 
 class IntSchema(TypedDict, total=False):
@@ -550,6 +645,11 @@ class IntSchema(TypedDict, total=False):
     lt: int
     gt: int
     strict: bool
+    explain_strict: bool  # default: False
+    allow_bool: bool  # whether lax mode accepts `True` and `False`. default: True
+    string_parse_options: StringParseOptions
+    json_coerce_strings: bool  # whether lax mode parses JSON strings. default: True
+    cache_validations: bool  # whether to remember the outputs of recent python int inputs. default: False
     ref: str
     metadata: Any
     serialization: SerSchema
@@ -563,6 +663,11 @@ def int_schema(
     lt: int | None = None,
     gt: int | None = None,
     strict: bool | None = None,
+    explain_strict: bool | None = None,
+    allow_bool: bool | None = None,
+    string_parse_options: StringParseOptions | None = None,
+    json_coerce_strings: bool | None = None,
+    cache_validations: bool | None = None,
     ref: str | None = None,
     metadata: Any = None,
     serialization: SerSchema | None = None,
@@ -585,6 +690,15 @@ def int_schema(
         lt: The value must be strictly less than this number
         gt: The value must be strictly greater than this number
         strict: Whether the value should be a int or a value that can be converted to a int
+        explain_strict: Whether to add an `explanation` to strict mode errors for inputs lax mode would accept,
+            overrides `explain_strict` in the config
+        allow_bool: Whether `True` and `False` are accepted as `1` and `0` in lax mode, defaults to `True`
+        string_parse_options: How strings are parsed in lax mode, whether whitespace is trimmed, whether
+            underscores between digits and whitespace after the sign are allowed, and the thousands and decimal
+            separators to accept
+        json_coerce_strings: Whether JSON strings like `"42"` are accepted in lax mode, defaults to `True`
+        cache_validations: Whether to remember the outputs of the most recent valid python ints, to speed up
+            validating the same values repeatedly, at most 256 values totalling 64KiB are remembered
         ref: optional unique identifier of the schema, used to reference the schema in other places
         metadata: Any other information you want to include with the schema, not used by pydantic-core
         serialization: Custom serialization schema
@@ -597,6 +711,11 @@ def int_schema(
         lt=lt,
         gt=gt,
         strict=strict,
+        explain_strict=explain_strict,
+        allow_bool=allow_bool,
+        string_parse_options=string_parse_options,
+        json_coerce_strings=json_coerce_strings,
+        cache_validations=cache_validations,
         ref=ref,
         metadata=metadata,
         serialization=serialization,
@@ -606,12 +725,18 @@ def int_schema(
 class FloatSchema(TypedDict, total=False):
     type: Required[Literal['float']]
     allow_inf_nan: bool  # whether 'NaN', '+inf', '-inf' should be forbidden. default: True
+    allow_int_to_float: bool  # whether strict mode accepts ints. default: True
+    allow_bool: bool  # whether lax mode accepts `True` and `False`. default: True
+    nan_as_none: bool  # whether NaN is converted to `None` in lax mode. default: False
     multiple_of: float
     le: float
     ge: float
     lt: float
     gt: float
     strict: bool
+    explain_strict: bool  # default: False
+    string_parse_options: StringParseOptions
+    json_coerce_strings: bool  # whether lax mode parses JSON strings. default: True
     ref: str
     metadata: Any
     serialization: SerSchema
@@ -620,12 +745,18 @@ class FloatSchema(TypedDict, total=False):
 def float_schema(
     *,
     allow_inf_nan: bool | None = None,
+    allow_int_to_float: bool | None = None,
+    allow_bool: bool | None = None,
+    nan_as_none: bool | None = None,
     multiple_of: float | None = None,
     le: float | None = None,
     ge: float | None = None,
     lt: float | None = None,
     gt: float | None = None,
     strict: bool | None = None,
+    explain_strict: bool | None = None,
+    string_parse_options: StringParseOptions | None = None,
+    json_coerce_strings: bool | None = None,
     ref: str | None = None,
     metadata: Any = None,
     serialization: SerSchema | None = None,
@@ -643,12 +774,23 @@ def float_schema(
 
     Args:
         allow_inf_nan: Whether to allow inf and nan values
+        allow_int_to_float: Whether integers are accepted in strict mode, defaults to `True`,
+            when `False` strict mode rejects integers (Python `int` or JSON integers) with a `float_type` error
+        allow_bool: Whether `True` and `False` are accepted as `1.0` and `0.0` in lax mode, defaults to `True`
+        nan_as_none: Whether a NaN input is converted to `None` in lax mode, e.g. for data exported by pandas
+            where NaN marks a missing value, usually used within a `nullable_schema`
         multiple_of: The value must be a multiple of this number
         le: The value must be less than or equal to this number
         ge: The value must be greater than or equal to this number
         lt: The value must be strictly less than this number
         gt: The value must be strictly greater than this number
         strict: Whether the value should be a float or a value that can be converted to a float
+        explain_strict: Whether to add an `explanation` to strict mode errors for inputs lax mode would accept,
+            overrides `explain_strict` in the config
+        string_parse_options: How strings are parsed in lax mode, whether whitespace is trimmed, whether
+            underscores between digits and whitespace after the sign are allowed, and the thousands and decimal
+            separators to accept
+        json_coerce_strings: Whether JSON strings like `"42"` are accepted in lax mode, defaults to `True`
         ref: optional unique identifier of the schema, used to reference the schema in other places
         metadata: Any other information you want to include with the schema, not used by pydantic-core
         serialization: Custom serialization schema
@@ -656,12 +798,18 @@ def float_schema(
     return _dict_not_none(
         type='float',
         allow_inf_nan=allow_inf_nan,
+        allow_int_to_float=allow_int_to_float,
+        allow_bool=allow_bool,
+        nan_as_none=nan_as_none,
         multiple_of=multiple_of,
         le=le,
         ge=ge,
         lt=lt,
         gt=gt,
         strict=strict,
+        explain_strict=explain_strict,
+        string_parse_options=string_parse_options,
+        json_coerce_strings=json_coerce_strings,
         ref=ref,
         metadata=metadata,
         serialization=serialization,
@@ -671,6 +819,7 @@ def float_schema(
 class DecimalSchema(TypedDict, total=False):
     type: Required[Literal['decimal']]
     allow_inf_nan: bool  # whether 'NaN', '+inf', '-inf' should be forbidden. default: False
+    allow_bool: bool  # whether lax mode accepts `True` and `False`. default: False
     multiple_of: Decimal
     le: Decimal
     ge: Decimal
@@ -687,6 +836,7 @@ class DecimalSchema(TypedDict, total=False):
 def decimal_schema(
     *,
     allow_inf_nan: bool = None,
+    allow_bool: bool | None = None,
     multiple_of: Decimal | None = None,
     le: Decimal | None = None,
     ge: Decimal | None = None,
@@ -713,6 +863,8 @@ def decimal_schema(
 
     Args:
         allow_inf_nan: Whether to allow inf and nan values
+        allow_bool: Whether `True` and `False` are accepted as `Decimal(1)` and `Decimal(0)` in lax mode,
+            defaults to `False`
         multiple_of: The value must be a multiple of this number
         le: The value must be less than or equal to this number
         ge: The value must be greater than or equal to this number
@@ -735,6 +887,7 @@ def decimal_schema(
         decimal_places=decimal_places,
         multiple_of=multiple_of,
         allow_inf_nan=allow_inf_nan,
+        allow_bool=allow_bool,
         strict=strict,
         ref=ref,
         metadata=metadata,
@@ -742,6 +895,47 @@ def decimal_schema(
     )
 
 
+class FractionSchema(TypedDict, total=False):
+    type: Required[Literal['fraction']]
+    strict: bool
+    ref: str
+    metadata: Any
+    serialization: SerSchema
+
+
+def fraction_schema(
+    *,
+    strict: bool | None = None,
+    ref: str | None = None,
+    metadata: Any = None,
+    serialization: SerSchema | None = None,
+) -> FractionSchema:
+    """
+    Returns a schema that matches a fraction value, e.g.:
+
+    ```py
+    from fractions import Fraction
+    from pydantic_core import SchemaValidator, core_schema
+
+    schema = core_schema.fraction_schema()
+    v = SchemaValidator(schema)
+    assert v.validate_python('3/4') == Fraction(3, 4)
+    assert v.validate_python([3, 4]) == Fraction(3, 4)
+    ```
+
+    In lax mode ints, strings accepted by `Fraction` like `'3/4'` and `[numerator, denominator]` pairs of ints
+    are converted to a `Fraction`, in strict mode only `Fraction` instances are accepted. Strings with an exponent
+    larger than 4300, like `'1e10000'`, are rejected.
+
+    Args:
+        strict: Whether the value should be a `Fraction` or a value that can be converted to a `Fraction`
+        ref: optional unique identifier of the schema, used to reference the schema in other places
+        metadata: Any other information you want to include with the schema, not used by pydantic-core
+        serialization: Custom serialization schema
+    """
+    return _dict_not_none(type='fraction', strict=strict, ref=ref, metadata=metadata, serialization=serialization)
+
+
 class StringSchema(TypedDict, total=False):
     type: Required[Literal['str']]
     pattern: str
@@ -750,9 +944,12 @@ class StringSchema(TypedDict, total=False):
     strip_whitespace: bool
     to_lower: bool
     to_upper: bool
+    unicode_normalize: Literal['NFC', 'NFD', 'NFKC', 'NFKD']
     regex_engine: Literal['rust-regex', 'python-re']  # default: 'rust-regex'
     strict: bool
+    explain_strict: bool  # default: False
     coerce_numbers_to_str: bool
+    cache_validations: bool  # whether to remember the outputs of recent python str inputs. default: False
     ref: str
     metadata: Any
     serialization: SerSchema
@@ -766,9 +963,12 @@ def str_schema(
     strip_whitespace: bool | None = None,
     to_lower: bool | None = None,
     to_upper: bool | None = None,
+    unicode_normalize: Literal['NFC', 'NFD', 'NFKC', 'NFKD'] | None = None,
     regex_engine: Literal['rust-regex', 'python-re'] | None = None,
     strict: bool | None = None,
+    explain_strict: bool | None = None,
     coerce_numbers_to_str: bool | None = None,
+    cache_validations: bool | None = None,
     ref: str | None = None,
     metadata: Any = None,
     serialization: SerSchema | None = None,
@@ -791,6 +991,8 @@ def str_schema(
         strip_whitespace: Whether to strip whitespace from the value
         to_lower: Whether to convert the value to lowercase
         to_upper: Whether to convert the value to uppercase
+        unicode_normalize: The Unicode normalization form to apply to the value, length constraints and `pattern`
+            are checked against the normalized value
         regex_engine: The regex engine to use for pattern validation. Default is 'rust-regex'.
             - `rust-regex` uses the [`regex`](https://docs.rs/regex) Rust
               crate, which is non-backtracking and therefore more DDoS
@@ -798,7 +1000,11 @@ def str_schema(
             - `python-re` use the [`re`](https://docs.python.org/3/library/re.html) module,
               which supports all regex features, but may be slower.
         strict: Whether the value should be a string or a value that can be converted to a string
+        explain_strict: Whether to add an `explanation` to strict mode errors for inputs lax mode would accept,
+            overrides `explain_strict` in the config
         coerce_numbers_to_str: Whether to enable coercion of any `Number` type to `str` (not applicable in `strict` mode).
+        cache_validations: Whether to remember the outputs of the most recent valid python strings, to speed up
+            validating the same values repeatedly, at most 256 values totalling 64KiB are remembered
         ref: optional unique identifier of the schema, used to reference the schema in other places
         metadata: Any other information you want to include with the schema, not used by pydantic-core
         serialization: Custom serialization schema
@@ -811,9 +1017,12 @@ def str_schema(
         strip_whitespace=strip_whitespace,
         to_lower=to_lower,
         to_upper=to_upper,
+        unicode_normalize=unicode_normalize,
         regex_engine=regex_engine,
         strict=strict,
+        explain_strict=explain_strict,
         coerce_numbers_to_str=coerce_numbers_to_str,
+        cache_validations=cache_validations,
         ref=ref,
         metadata=metadata,
         serialization=serialization,
@@ -824,7 +1033,9 @@ class BytesSchema(TypedDict, total=False):
     type: Required[Literal['bytes']]
     max_length: int
     min_length: int
+    max_decoded_length: int
     strict: bool
+    explain_strict: bool  # default: False
     ref: str
     metadata: Any
     serialization: SerSchema
@@ -834,7 +1045,9 @@ def bytes_schema(
     *,
     max_length: int | None = None,
     min_length: int | None = None,
+    max_decoded_length: int | None = None,
     strict: bool | None = None,
+    explain_strict: bool | None = None,
     ref: str | None = None,
     metadata: Any = None,
     serialization: SerSchema | None = None,
@@ -853,7 +1066,11 @@ def bytes_schema(
     Args:
         max_length: The value must be at most this length
         min_length: The value must be at least this length
+        max_decoded_length: Strings decoded according to `val_json_bytes` must be at most this length once decoded,
+            oversized strings are rejected based on their encoded length without being decoded
         strict: Whether the value should be a bytes or a value that can be converted to a bytes
+        explain_strict: Whether to add an `explanation` to strict mode errors for inputs lax mode would accept,
+            overrides `explain_strict` in the config
         ref: optional unique identifier of the schema, used to reference the schema in other places
         metadata: Any other information you want to include with the schema, not used by pydantic-core
         serialization: Custom serialization schema
@@ -862,7 +1079,9 @@ def bytes_schema(
         type='bytes',
         max_length=max_length,
         min_length=min_length,
+        max_decoded_length=max_decoded_length,
         strict=strict,
+        explain_strict=explain_strict,
         ref=ref,
         metadata=metadata,
         serialization=serialization,
@@ -946,6 +1165,7 @@ class TimeSchema(TypedDict, total=False):
     gt: time
     tz_constraint: Union[Literal['aware', 'naive'], int]
     microseconds_precision: Literal['truncate', 'error']
+    time_format: Literal['iso', '12h', 'any']  # default: 'iso'
     ref: str
     metadata: Any
     serialization: SerSchema
@@ -960,6 +1180,7 @@ def time_schema(
     gt: time | None = None,
     tz_constraint: Literal['aware', 'naive'] | int | None = None,
     microseconds_precision: Literal['truncate', 'error'] = 'truncate',
+    time_format: Literal['iso', '12h', 'any'] | None = None,
     ref: str | None = None,
     metadata: Any = None,
     serialization: SerSchema | None = None,
@@ -984,6 +1205,8 @@ def time_schema(
         gt: The value must be strictly greater than this time
         tz_constraint: The value must be timezone aware or naive, or an int to indicate required tz offset
         microseconds_precision: The behavior when seconds have more than 6 digits or microseconds is too large
+        time_format: Which string formats to accept, `'iso'` for ISO 8601 times like `14:30`, `'12h'` for 12-hour
+            times like `2:30 PM` or `'any'` for either, the format also applies to strings in strict mode
         ref: optional unique identifier of the schema, used to reference the schema in other places
         metadata: Any other information you want to include with the schema, not used by pydantic-core
         serialization: Custom serialization schema
@@ -997,6 +1220,7 @@ def time_schema(
         gt=gt,
         tz_constraint=tz_constraint,
         microseconds_precision=microseconds_precision,
+        time_format=time_format,
         ref=ref,
         metadata=metadata,
         serialization=serialization,
@@ -1012,10 +1236,16 @@ class DatetimeSchema(TypedDict, total=False):
     gt: datetime
     now_op: Literal['past', 'future']
     tz_constraint: Union[Literal['aware', 'naive'], int]
+    require_utc: bool  # default: False
+    coerce_to_utc: bool  # default: False
     # defaults to current local utc offset from `time.localtime().tm_gmtoff`
     # value is restricted to -86_400 < offset < 86_400 by bounds in generate_self_schema.py
     now_utc_offset: int
     microseconds_precision: Literal['truncate', 'error']  # default: 'truncate'
+    leap_second: Literal['error', 'clamp']  # default: 'error'
+    fold: Literal['error', 'earlier', 'later']
+    timespec: Literal['auto', 'seconds', 'milliseconds', 'microseconds']  # default: 'auto'
+    use_z: bool  # default: True
     ref: str
     metadata: Any
     serialization: SerSchema
@@ -1030,8 +1260,14 @@ def datetime_schema(
     gt: datetime | None = None,
     now_op: Literal['past', 'future'] | None = None,
     tz_constraint: Literal['aware', 'naive'] | int | None = None,
+    require_utc: bool | None = None,
+    coerce_to_utc: bool | None = None,
     now_utc_offset: int | None = None,
     microseconds_precision: Literal['truncate', 'error'] = 'truncate',
+    leap_second: Literal['error', 'clamp'] | None = None,
+    fold: Literal['error', 'earlier', 'later'] | None = None,
+    timespec: Literal['auto', 'seconds', 'milliseconds', 'microseconds'] | None = None,
+    use_z: bool | None = None,
     ref: str | None = None,
     metadata: Any = None,
     serialization: SerSchema | None = None,
@@ -1058,8 +1294,20 @@ def datetime_schema(
         now_op: The value must be in the past or future relative to the current datetime
         tz_constraint: The value must be timezone aware or naive, or an int to indicate required tz offset
             TODO: use of a tzinfo where offset changes based on the datetime is not yet supported
+        require_utc: The value must be timezone aware with a UTC offset of zero, can't be combined with `tz_constraint`
+        coerce_to_utc: Whether timezone aware values should be converted to UTC, naive values are rejected,
+            can't be combined with `tz_constraint`
         now_utc_offset: The value must be in the past or future relative to the current datetime with this utc offset
         microseconds_precision: The behavior when seconds have more than 6 digits or microseconds is too large
+        leap_second: How strings with a leap second like `23:59:60` are handled, 'error' rejects them and 'clamp'
+            validates them as the last microsecond of the previous second, `23:59:59.999999`, defaults to 'error'
+        fold: How datetimes with a `tzinfo` whose UTC offset depends on `fold` are handled, i.e. wall times repeated
+            or skipped by a DST transition, 'error' rejects them, 'earlier' and 'later' set `fold` to 0 and 1
+            respectively, by default they're kept unchanged
+        timespec: How much of the time to include when serializing to JSON, like `timespec` in
+            `datetime.isoformat()`, 'auto' includes microseconds only if they're not zero, 'milliseconds' truncates
+            microseconds, defaults to 'auto'
+        use_z: Whether to serialize a UTC offset of zero as `Z` rather than `+00:00`, defaults to `True`
         ref: optional unique identifier of the schema, used to reference the schema in other places
         metadata: Any other information you want to include with the schema, not used by pydantic-core
         serialization: Custom serialization schema
@@ -1073,8 +1321,14 @@ def datetime_schema(
         gt=gt,
         now_op=now_op,
         tz_constraint=tz_constraint,
+        require_utc=require_utc,
+        coerce_to_utc=coerce_to_utc,
         now_utc_offset=now_utc_offset,
         microseconds_precision=microseconds_precision,
+        leap_second=leap_second,
+        fold=fold,
+        timespec=timespec,
+        use_z=use_z,
         ref=ref,
         metadata=metadata,
         serialization=serialization,
@@ -1089,6 +1343,7 @@ class TimedeltaSchema(TypedDict, total=False):
     lt: timedelta
     gt: timedelta
     microseconds_precision: Literal['truncate', 'error']
+    duration_format: Literal['speedate', 'iso8601', 'any']
     ref: str
     metadata: Any
     serialization: SerSchema
@@ -1102,6 +1357,7 @@ def timedelta_schema(
     lt: timedelta | None = None,
     gt: timedelta | None = None,
     microseconds_precision: Literal['truncate', 'error'] = 'truncate',
+    duration_format: Literal['speedate', 'iso8601', 'any'] | None = None,
     ref: str | None = None,
     metadata: Any = None,
     serialization: SerSchema | None = None,
@@ -1125,6 +1381,10 @@ def timedelta_schema(
         lt: The value must be strictly less than this timedelta
         gt: The value must be strictly greater than this timedelta
         microseconds_precision: The behavior when seconds have more than 6 digits or microseconds is too large
+        duration_format: Which string formats are accepted, `'speedate'` (the default) accepts speedate's formats
+            and converts ISO 8601 years and months to 365 and 30 days, `'iso8601'` only accepts ISO 8601
+            durations without years or months, `'any'` accepts both, rejecting ISO 8601 durations with
+            years or months
         ref: optional unique identifier of the schema, used to reference the schema in other places
         metadata: Any other information you want to include with the schema, not used by pydantic-core
         serialization: Custom serialization schema
@@ -1137,6 +1397,7 @@ def timedelta_schema(
         lt=lt,
         gt=gt,
         microseconds_precision=microseconds_precision,
+        duration_format=duration_format,
         ref=ref,
         metadata=metadata,
         serialization=serialization,
@@ -1146,13 +1407,21 @@ def timedelta_schema(
 class LiteralSchema(TypedDict, total=False):
     type: Required[Literal['literal']]
     expected: Required[List[Any]]
+    int_float_equivalence: bool  # default: False
+    suggest: bool  # default: False
     ref: str
     metadata: Any
     serialization: SerSchema
 
 
 def literal_schema(
-    expected: list[Any], *, ref: str | None = None, metadata: Any = None, serialization: SerSchema | None = None
+    expected: list[Any],
+    *,
+    int_float_equivalence: bool | None = None,
+    suggest: bool | None = None,
+    ref: str | None = None,
+    metadata: Any = None,
+    serialization: SerSchema | None = None,
 ) -> LiteralSchema:
     """
     Returns a schema that matches a literal value, e.g.:
@@ -1165,13 +1434,28 @@ def literal_schema(
     assert v.validate_python('hello') == 'hello'
     ```
 
+    Float literals match float inputs with an equal value, `NaN` literals match any `NaN` input even though
+    `NaN != NaN`. Int inputs only match float literals if `int_float_equivalence` is `True`, bools never do.
+
     Args:
         expected: The value must be one of these values
+        int_float_equivalence: Whether an int input matches an equal float literal, e.g. `1` matches `1.0`,
+            defaults to `False`
+        suggest: Whether to include the expected string closest to a string input by edit distance in the error
+            context as `suggestion`, defaults to `False`
         ref: optional unique identifier of the schema, used to reference the schema in other places
         metadata: Any other information you want to include with the schema, not used by pydantic-core
         serialization: Custom serialization schema
     """
-    return _dict_not_none(type='literal', expected=expected, ref=ref, metadata=metadata, serialization=serialization)
+    return _dict_not_none(
+        type='literal',
+        expected=expected,
+        int_float_equivalence=int_float_equivalence,
+        suggest=suggest,
+        ref=ref,
+        metadata=metadata,
+        serialization=serialization,
+    )
 
 
 class EnumSchema(TypedDict, total=False):
@@ -1181,6 +1465,8 @@ class EnumSchema(TypedDict, total=False):
     sub_type: Literal['str', 'int', 'float']
     missing: Callable[[Any], Any]
     strict: bool
+    suggest: bool  # default: False
+    ser_json_enum: Literal['value', 'name']
     ref: str
     metadata: Any
     serialization: SerSchema
@@ -1193,6 +1479,8 @@ def enum_schema(
     sub_type: Literal['str', 'int', 'float'] | None = None,
     missing: Callable[[Any], Any] | None = None,
     strict: bool | None = None,
+    suggest: bool | None = None,
+    ser_json_enum: Literal['value', 'name'] | None = None,
     ref: str | None = None,
     metadata: Any = None,
     serialization: SerSchema | None = None,
@@ -1220,6 +1508,10 @@ def enum_schema(
         sub_type: The type of the enum, either 'str' or 'int' or None for plain enums
         missing: A function to use when the value is not found in the enum, from `_missing_`
         strict: Whether to use strict mode, defaults to False
+        suggest: Whether to include the string member value closest to a string input by edit distance in the
+            error context as `suggestion`, defaults to False
+        ser_json_enum: Whether members are serialized to JSON by their `value` or their `name`, overrides the
+            `ser_json_enum` config for this enum
         ref: optional unique identifier of the schema, used to reference the schema in other places
         metadata: Any other information you want to include with the schema, not used by pydantic-core
         serialization: Custom serialization schema
@@ -1231,6 +1523,8 @@ def enum_schema(
         sub_type=sub_type,
         missing=missing,
         strict=strict,
+        suggest=suggest,
+        ser_json_enum=ser_json_enum,
         ref=ref,
         metadata=metadata,
         serialization=serialization,
@@ -1381,6 +1675,40 @@ def uuid_schema(
     )
 
 
+class EmailSchema(TypedDict, total=False):
+    type: Required[Literal['email']]
+    strict: bool
+    ref: str
+    metadata: Any
+    serialization: SerSchema
+
+
+def email_schema(
+    *, strict: bool | None = None, ref: str | None = None, metadata: Any = None, serialization: SerSchema | None = None
+) -> EmailSchema:
+    """
+    Returns a schema that validates an email address, e.g.:
+
+    ```py
+    from pydantic_core import SchemaValidator, core_schema
+
+    schema = core_schema.email_schema()
+    v = SchemaValidator(schema)
+    assert v.validate_python('John.Doe@Example.COM') == 'John.Doe@example.com'
+    ```
+
+    The address is only checked structurally, no DNS lookups are made to check the domain exists.
+    The domain is lowercased in the returned address, the local part is left as is.
+
+    Args:
+        strict: Whether the value should be a string
+        ref: optional unique identifier of the schema, used to reference the schema in other places
+        metadata: Any other information you want to include with the schema, not used by pydantic-core
+        serialization: Custom serialization schema
+    """
+    return _dict_not_none(type='email', strict=strict, ref=ref, metadata=metadata, serialization=serialization)
+
+
 class IncExSeqSerSchema(TypedDict, total=False):
     type: Required[Literal['include-exclude-sequence']]
     include: Set[int]
@@ -1397,9 +1725,16 @@ IncExSeqOrElseSerSchema = Union[IncExSeqSerSchema, SerSchema]
 class ListSchema(TypedDict, total=False):
     type: Required[Literal['list']]
     items_schema: CoreSchema
+    prefix_items: List[CoreSchema]
     min_length: int
     max_length: int
+    unique_items: bool
+    contains: CoreSchema
+    min_contains: int
+    max_contains: int
     strict: bool
+    allow_subclass: bool
+    columnar_input: bool  # default: False
     ref: str
     metadata: Any
     serialization: IncExSeqOrElseSerSchema
@@ -1408,9 +1743,16 @@ class ListSchema(TypedDict, total=False):
 def list_schema(
     items_schema: CoreSchema | None = None,
     *,
+    prefix_items: list[CoreSchema] | None = None,
     min_length: int | None = None,
     max_length: int | None = None,
+    unique_items: bool | None = None,
+    contains: CoreSchema | None = None,
+    min_contains: int | None = None,
+    max_contains: int | None = None,
     strict: bool | None = None,
+    allow_subclass: bool | None = None,
+    columnar_input: bool | None = None,
     ref: str | None = None,
     metadata: Any = None,
     serialization: IncExSeqOrElseSerSchema | None = None,
@@ -1428,9 +1770,21 @@ def list_schema(
 
     Args:
         items_schema: The value must be a list of items that match this schema
+        prefix_items: Schemas for the first items of the list by position, items after these are validated with
+            `items_schema`, items in the prefix aren't required unless `min_length` is set
         min_length: The value must be a list with at least this many items
         max_length: The value must be a list with at most this many items
+        unique_items: The validated items must not be equal to each other, the error for a duplicate is located at
+            its index and includes the index of the first occurrence, unhashable items are compared by equality
+        contains: At least `min_contains` and at most `max_contains` of the validated items must be valid against
+            this schema, checked once all items are valid
+        min_contains: The minimum number of items valid against `contains`, defaults to 1
+        max_contains: The maximum number of items valid against `contains`
         strict: The value must be a list with exactly this many items
+        allow_subclass: Whether subclasses of `list` are accepted in strict mode, defaults to `True`
+        columnar_input: Whether a Python dict of equal length lists or tuples is accepted in lax mode, it's transposed
+            into a list of dicts before the items are validated, e.g. `{'a': [1, 2], 'b': [3, 4]}` is validated as
+            `[{'a': 1, 'b': 3}, {'a': 2, 'b': 4}]`, defaults to `False`
         ref: optional unique identifier of the schema, used to reference the schema in other places
         metadata: Any other information you want to include with the schema, not used by pydantic-core
         serialization: Custom serialization schema
@@ -1438,9 +1792,16 @@ def list_schema(
     return _dict_not_none(
         type='list',
         items_schema=items_schema,
+        prefix_items=prefix_items,
         min_length=min_length,
         max_length=max_length,
+        unique_items=unique_items,
+        contains=contains,
+        min_contains=min_contains,
+        max_contains=max_contains,
         strict=strict,
+        allow_subclass=allow_subclass,
+        columnar_input=columnar_input,
         ref=ref,
         metadata=metadata,
         serialization=serialization,
@@ -1547,7 +1908,9 @@ class TupleSchema(TypedDict, total=False):
     variadic_item_index: int
     min_length: int
     max_length: int
+    namedtuple_cls: Type[Any]
     strict: bool
+    allow_subclass: bool
     ref: str
     metadata: Any
     serialization: IncExSeqOrElseSerSchema
@@ -1559,7 +1922,9 @@ def tuple_schema(
     variadic_item_index: int | None = None,
     min_length: int | None = None,
     max_length: int | None = None,
+    namedtuple_cls: Type[Any] | None = None,
     strict: bool | None = None,
+    allow_subclass: bool | None = None,
     ref: str | None = None,
     metadata: Any = None,
     serialization: IncExSeqOrElseSerSchema | None = None,
@@ -1583,7 +1948,10 @@ def tuple_schema(
         variadic_item_index: The index of the schema in `items_schema` to be treated as variadic (following PEP 646)
         min_length: The value must be a tuple with at least this many items
         max_length: The value must be a tuple with at most this many items
+        namedtuple_cls: A `namedtuple` class to construct from the validated items, it must have as many fields as
+            `items_schema` has items, and can't be used with `variadic_item_index`
         strict: The value must be a tuple with exactly this many items
+        allow_subclass: Whether subclasses of `tuple` are accepted in strict mode, defaults to `True`
         ref: Optional unique identifier of the schema, used to reference the schema in other places
         metadata: Any other information you want to include with the schema, not used by pydantic-core
         serialization: Custom serialization schema
@@ -1594,6 +1962,59 @@ def tuple_schema(
         variadic_item_index=variadic_item_index,
         min_length=min_length,
         max_length=max_length,
+        namedtuple_cls=namedtuple_cls,
+        strict=strict,
+        allow_subclass=allow_subclass,
+        ref=ref,
+        metadata=metadata,
+        serialization=serialization,
+    )
+
+
+class IntervalSchema(TypedDict, total=False):
+    type: Required[Literal['interval']]
+    items_schema: Required[CoreSchema]
+    allow_equal: bool  # default: True
+    strict: bool
+    ref: str
+    metadata: Any
+    serialization: IncExSeqOrElseSerSchema
+
+
+def interval_schema(
+    items_schema: CoreSchema,
+    *,
+    allow_equal: bool | None = None,
+    strict: bool | None = None,
+    ref: str | None = None,
+    metadata: Any = None,
+    serialization: IncExSeqOrElseSerSchema | None = None,
+) -> IntervalSchema:
+    """
+    Returns a schema that matches an interval, either a `(lower, upper)` pair or a `range` with a step of 1
+    which covers its first to last item, and returns it as a `(lower, upper)` tuple, e.g.:
+
+    ```py
+    from pydantic_core import SchemaValidator, core_schema
+
+    schema = core_schema.interval_schema(core_schema.int_schema(ge=0))
+    v = SchemaValidator(schema)
+    assert v.validate_python([1, '5']) == (1, 5)
+    assert v.validate_python(range(2, 4)) == (2, 3)
+    ```
+
+    Args:
+        items_schema: The schema both endpoints of the interval must match
+        allow_equal: Whether the lower and upper endpoints may be equal, defaults to `True`
+        strict: Whether only tuples and `range`s are accepted, rather than any sequence
+        ref: Optional unique identifier of the schema, used to reference the schema in other places
+        metadata: Any other information you want to include with the schema, not used by pydantic-core
+        serialization: Custom serialization schema
+    """
+    return _dict_not_none(
+        type='interval',
+        items_schema=items_schema,
+        allow_equal=allow_equal,
         strict=strict,
         ref=ref,
         metadata=metadata,
@@ -1606,7 +2027,11 @@ class SetSchema(TypedDict, total=False):
     items_schema: CoreSchema
     min_length: int
     max_length: int
+    contains: CoreSchema
+    min_contains: int
+    max_contains: int
     strict: bool
+    allow_subclass: bool
     ref: str
     metadata: Any
     serialization: SerSchema
@@ -1617,7 +2042,11 @@ def set_schema(
     *,
     min_length: int | None = None,
     max_length: int | None = None,
+    contains: CoreSchema | None = None,
+    min_contains: int | None = None,
+    max_contains: int | None = None,
     strict: bool | None = None,
+    allow_subclass: bool | None = None,
     ref: str | None = None,
     metadata: Any = None,
     serialization: SerSchema | None = None,
@@ -1639,7 +2068,12 @@ def set_schema(
         items_schema: The value must be a set with items that match this schema
         min_length: The value must be a set with at least this many items
         max_length: The value must be a set with at most this many items
+        contains: At least `min_contains` and at most `max_contains` of the validated items must be valid against
+            this schema, checked once all items are valid
+        min_contains: The minimum number of items valid against `contains`, defaults to 1
+        max_contains: The maximum number of items valid against `contains`
         strict: The value must be a set with exactly this many items
+        allow_subclass: Whether subclasses of `set` are accepted in strict mode, defaults to `True`
         ref: optional unique identifier of the schema, used to reference the schema in other places
         metadata: Any other information you want to include with the schema, not used by pydantic-core
         serialization: Custom serialization schema
@@ -1649,7 +2083,11 @@ def set_schema(
         items_schema=items_schema,
         min_length=min_length,
         max_length=max_length,
+        contains=contains,
+        min_contains=min_contains,
+        max_contains=max_contains,
         strict=strict,
+        allow_subclass=allow_subclass,
         ref=ref,
         metadata=metadata,
         serialization=serialization,
@@ -1661,7 +2099,11 @@ class FrozenSetSchema(TypedDict, total=False):
     items_schema: CoreSchema
     min_length: int
     max_length: int
+    contains: CoreSchema
+    min_contains: int
+    max_contains: int
     strict: bool
+    allow_subclass: bool
     ref: str
     metadata: Any
     serialization: SerSchema
@@ -1672,7 +2114,11 @@ def frozenset_schema(
     *,
     min_length: int | None = None,
     max_length: int | None = None,
+    contains: CoreSchema | None = None,
+    min_contains: int | None = None,
+    max_contains: int | None = None,
     strict: bool | None = None,
+    allow_subclass: bool | None = None,
     ref: str | None = None,
     metadata: Any = None,
     serialization: SerSchema | None = None,
@@ -1694,7 +2140,12 @@ def frozenset_schema(
         items_schema: The value must be a frozenset with items that match this schema
         min_length: The value must be a frozenset with at least this many items
         max_length: The value must be a frozenset with at most this many items
+        contains: At least `min_contains` and at most `max_contains` of the validated items must be valid against
+            this schema, checked once all items are valid
+        min_contains: The minimum number of items valid against `contains`, defaults to 1
+        max_contains: The maximum number of items valid against `contains`
         strict: The value must be a frozenset with exactly this many items
+        allow_subclass: Whether subclasses of `frozenset` are accepted in strict mode, defaults to `True`
         ref: optional unique identifier of the schema, used to reference the schema in other places
         metadata: Any other information you want to include with the schema, not used by pydantic-core
         serialization: Custom serialization schema
@@ -1704,7 +2155,11 @@ def frozenset_schema(
         items_schema=items_schema,
         min_length=min_length,
         max_length=max_length,
+        contains=contains,
+        min_contains=min_contains,
+        max_contains=max_contains,
         strict=strict,
+        allow_subclass=allow_subclass,
         ref=ref,
         metadata=metadata,
         serialization=serialization,
@@ -1790,7 +2245,11 @@ class DictSchema(TypedDict, total=False):
     values_schema: CoreSchema  # default: AnySchema
     min_length: int
     max_length: int
+    mapping_type: Type[Any]
+    mapping_duplicate_keys: Literal['first', 'last']  # default: 'last'
     strict: bool
+    allow_subclass: bool
+    json_key_mode: Literal['str', 'error']  # default: 'str'
     ref: str
     metadata: Any
     serialization: IncExDictOrElseSerSchema
@@ -1802,7 +2261,11 @@ def dict_schema(
     *,
     min_length: int | None = None,
     max_length: int | None = None,
+    mapping_type: Type[Any] | None = None,
+    mapping_duplicate_keys: Literal['first', 'last'] | None = None,
     strict: bool | None = None,
+    allow_subclass: bool | None = None,
+    json_key_mode: Literal['str', 'error'] | None = None,
     ref: str | None = None,
     metadata: Any = None,
     serialization: SerSchema | None = None,
@@ -1825,7 +2288,15 @@ def dict_schema(
         values_schema: The value must be a dict with values that match this schema
         min_length: The value must be a dict with at least this many items
         max_length: The value must be a dict with at most this many items
+        mapping_type: A mapping class to construct from the validated dict, e.g. `collections.OrderedDict`,
+            it's called with the validated dict as its only argument
+        mapping_duplicate_keys: Whether to keep the first or last value when the input yields the same key more than
+            once, e.g. a multi-value mapping whose `items()` repeats keys, repeated keys in JSON objects are handled by
+            the `duplicate_keys` argument of `validate_json`
         strict: Whether the keys and values should be validated with strict mode
+        allow_subclass: Whether subclasses of `dict` are accepted in strict mode, defaults to `True`
+        json_key_mode: How keys which aren't strings are serialized to JSON, `'str'` (the default) converts them to
+            strings, `'error'` raises an error
         ref: optional unique identifier of the schema, used to reference the schema in other places
         metadata: Any other information you want to include with the schema, not used by pydantic-core
         serialization: Custom serialization schema
@@ -1836,7 +2307,11 @@ def dict_schema(
         values_schema=values_schema,
         min_length=min_length,
         max_length=max_length,
+        mapping_type=mapping_type,
+        mapping_duplicate_keys=mapping_duplicate_keys,
         strict=strict,
+        allow_subclass=allow_subclass,
+        json_key_mode=json_key_mode,
         ref=ref,
         metadata=metadata,
         serialization=serialization,
@@ -2346,6 +2821,7 @@ class NullableSchema(TypedDict, total=False):
     type: Required[Literal['nullable']]
     schema: Required[CoreSchema]
     strict: bool
+    empty_str_as_none: Union[bool, Literal['whitespace']]
     ref: str
     metadata: Any
     serialization: SerSchema
@@ -2355,6 +2831,7 @@ def nullable_schema(
     schema: CoreSchema,
     *,
     strict: bool | None = None,
+    empty_str_as_none: bool | Literal['whitespace'] | None = None,
     ref: str | None = None,
     metadata: Any = None,
     serialization: SerSchema | None = None,
@@ -2373,12 +2850,21 @@ def nullable_schema(
     Args:
         schema: The schema to wrap
         strict: Whether the underlying schema should be validated with strict mode
+        empty_str_as_none: Whether empty strings (or with `'whitespace'`, strings containing only whitespace) should
+            be returned as `None` without running the wrapped schema, e.g. for values from web forms.
+            Only applies in lax mode
         ref: optional unique identifier of the schema, used to reference the schema in other places
         metadata: Any other information you want to include with the schema, not used by pydantic-core
         serialization: Custom serialization schema
     """
     return _dict_not_none(
-        type='nullable', schema=schema, strict=strict, ref=ref, metadata=metadata, serialization=serialization
+        type='nullable',
+        schema=schema,
+        strict=strict,
+        empty_str_as_none=empty_str_as_none,
+        ref=ref,
+        metadata=metadata,
+        serialization=serialization,
     )
 
 
@@ -2455,11 +2941,13 @@ class TaggedUnionSchema(TypedDict, total=False):
     type: Required[Literal['tagged-union']]
     choices: Required[Dict[Hashable, CoreSchema]]
     discriminator: Required[Union[str, List[Union[str, int]], List[List[Union[str, int]]], Callable[[Any], Hashable]]]
+    fallback_tag: Any  # one of the keys of choices
     custom_error_type: str
     custom_error_message: str
     custom_error_context: Dict[str, Union[str, int, float]]
     strict: bool
     from_attributes: bool  # default: True
+    from_attributes_safe: bool  # default: False
     ref: str
     metadata: Any
     serialization: SerSchema
@@ -2469,11 +2957,13 @@ def tagged_union_schema(
     choices: Dict[Hashable, CoreSchema],
     discriminator: str | list[str | int] | list[list[str | int]] | Callable[[Any], Hashable],
     *,
+    fallback_tag: Hashable | None = None,
     custom_error_type: str | None = None,
     custom_error_message: str | None = None,
     custom_error_context: dict[str, int | str | float] | None = None,
     strict: bool | None = None,
     from_attributes: bool | None = None,
+    from_attributes_safe: bool | None = None,
     ref: str | None = None,
     metadata: Any = None,
     serialization: SerSchema | None = None,
@@ -2524,11 +3014,15 @@ def tagged_union_schema(
             * If `discriminator` is a list of lists, each inner list is a path, and the first path that exists is used
             * If `discriminator` is a callable, it should return the discriminator when called on the value to validate;
               the callable can return `None` to indicate that there is no matching discriminator present on the input
+        fallback_tag: The key of the choice to validate against when the discriminator value doesn't match any of
+            `choices`, instead of raising a `union_tag_invalid` error, it must be one of the keys of `choices`
         custom_error_type: The custom error type to use if the validation fails
         custom_error_message: The custom error message to use if the validation fails
         custom_error_context: The custom error context to use if the validation fails
         strict: Whether the underlying schemas should be validated with strict mode
         from_attributes: Whether to use the attributes of the object to retrieve the discriminator value
+        from_attributes_safe: Whether to only read the discriminator value if it's stored in the object's `__dict__`
+            or `__slots__`, without calling properties, other descriptors or `__getattr__`
         ref: optional unique identifier of the schema, used to reference the schema in other places
         metadata: Any other information you want to include with the schema, not used by pydantic-core
         serialization: Custom serialization schema
@@ -2537,11 +3031,13 @@ def tagged_union_schema(
         type='tagged-union',
         choices=choices,
         discriminator=discriminator,
+        fallback_tag=fallback_tag,
         custom_error_type=custom_error_type,
         custom_error_message=custom_error_message,
         custom_error_context=custom_error_context,
         strict=strict,
         from_attributes=from_attributes,
+        from_attributes_safe=from_attributes_safe,
         ref=ref,
         metadata=metadata,
         serialization=serialization,
@@ -2714,6 +3210,8 @@ class TypedDictField(TypedDict, total=False):
     validation_alias: Union[str, List[Union[str, int]], List[List[Union[str, int]]]]
     serialization_alias: str
     serialization_exclude: bool  # default: False
+    serialization_omit_if_none: bool
+    deprecated: Union[bool, str]  # default: False
     metadata: Any
 
 
@@ -2724,6 +3222,8 @@ def typed_dict_field(
     validation_alias: str | list[str | int] | list[list[str | int]] | None = None,
     serialization_alias: str | None = None,
     serialization_exclude: bool | None = None,
+    serialization_omit_if_none: bool | None = None,
+    deprecated: bool | str | None = None,
     metadata: Any = None,
 ) -> TypedDictField:
     """
@@ -2741,6 +3241,10 @@ def typed_dict_field(
         validation_alias: The alias(es) to use to find the field in the validation data
         serialization_alias: The alias to use as a key when serializing
         serialization_exclude: Whether to exclude the field when serializing
+        serialization_omit_if_none: Whether to omit the field when serializing if it's `None`, overriding
+            `exclude_none` for this field
+        deprecated: Whether to issue a `DeprecationWarning` when the field is present in the input, a string is used
+            as the warning message
         metadata: Any other information you want to include with the schema, not used by pydantic-core
     """
     return _dict_not_none(
@@ -2750,6 +3254,8 @@ def typed_dict_field(
         validation_alias=validation_alias,
         serialization_alias=serialization_alias,
         serialization_exclude=serialization_exclude,
+        serialization_omit_if_none=serialization_omit_if_none,
+        deprecated=deprecated,
         metadata=metadata,
     )
 
@@ -2764,6 +3270,8 @@ class TypedDictSchema(TypedDict, total=False):
     extra_behavior: ExtraBehavior
     total: bool  # default: True
     populate_by_name: bool  # replaces `allow_population_by_field_name` in pydantic v1
+    case_insensitive: bool  # default: False
+    case_collision: CaseCollision  # default: 'prefer_exact'
     ref: str
     metadata: Any
     serialization: SerSchema
@@ -2779,6 +3287,8 @@ def typed_dict_schema(
     extra_behavior: ExtraBehavior | None = None,
     total: bool | None = None,
     populate_by_name: bool | None = None,
+    case_insensitive: bool | None = None,
+    case_collision: CaseCollision | None = None,
     ref: str | None = None,
     metadata: Any = None,
     serialization: SerSchema | None = None,
@@ -2807,6 +3317,10 @@ def typed_dict_schema(
         extra_behavior: The extra behavior to use for the typed dict
         total: Whether the typed dict is total
         populate_by_name: Whether the typed dict should populate by name
+        case_insensitive: Whether fields are matched to input keys ignoring case, e.g. an alias `Content-Type`
+            matches the key `content-type`, alias paths are still matched exactly
+        case_collision: What to do when several input keys match a field ignoring case, `'prefer_exact'` uses the
+            key matching exactly if there is one and otherwise the first key, `'error'` raises a `case_collision` error
         serialization: Custom serialization schema
     """
     return _dict_not_none(
@@ -2818,6 +3332,8 @@ def typed_dict_schema(
         extra_behavior=extra_behavior,
         total=total,
         populate_by_name=populate_by_name,
+        case_insensitive=case_insensitive,
+        case_collision=case_collision,
         ref=ref,
         metadata=metadata,
         serialization=serialization,
@@ -2831,7 +3347,9 @@ class ModelField(TypedDict, total=False):
     validation_alias: Union[str, List[Union[str, int]], List[List[Union[str, int]]]]
     serialization_alias: str
     serialization_exclude: bool  # default: False
+    serialization_omit_if_none: bool
     frozen: bool
+    deprecated: Union[bool, str]  # default: False
     metadata: Any
 
 
@@ -2841,7 +3359,9 @@ def model_field(
     validation_alias: str | list[str | int] | list[list[str | int]] | None = None,
     serialization_alias: str | None = None,
     serialization_exclude: bool | None = None,
+    serialization_omit_if_none: bool | None = None,
     frozen: bool | None = None,
+    deprecated: bool | str | None = None,
     metadata: Any = None,
 ) -> ModelField:
     """
@@ -2858,7 +3378,11 @@ def model_field(
         validation_alias: The alias(es) to use to find the field in the validation data
         serialization_alias: The alias to use as a key when serializing
         serialization_exclude: Whether to exclude the field when serializing
+        serialization_omit_if_none: Whether to omit the field when serializing if it's `None`, overriding
+            `exclude_none` for this field
         frozen: Whether the field is frozen
+        deprecated: Whether to issue a `DeprecationWarning` when the field is present in the input, a string is used
+            as the warning message
         metadata: Any other information you want to include with the schema, not used by pydantic-core
     """
     return _dict_not_none(
@@ -2867,11 +3391,45 @@ def model_field(
         validation_alias=validation_alias,
         serialization_alias=serialization_alias,
         serialization_exclude=serialization_exclude,
+        serialization_omit_if_none=serialization_omit_if_none,
         frozen=frozen,
+        deprecated=deprecated,
         metadata=metadata,
     )
 
 
+class ConditionalRequiredIf(TypedDict, total=False):
+    field: Required[str]
+    equals: Required[Any]
+
+
+# `if` is a keyword so the functional syntax is required
+ConditionalRequired = TypedDict(
+    'ConditionalRequired',
+    {'if': 'Required[ConditionalRequiredIf]', 'then_required': 'Required[List[str]]'},
+    total=False,
+)
+
+
+def conditional_required(field: str, equals: Any, then_required: list[str]) -> ConditionalRequired:
+    """
+    Returns a rule for `model_fields_schema` requiring fields to be in the input when another field has a given
+    value, e.g.:
+
+    ```py
+    from pydantic_core import core_schema
+
+    rule = core_schema.conditional_required('status', 'rejected', ['reason'])
+    ```
+
+    Args:
+        field: The name of the field whose validated value is checked
+        equals: The value which makes the fields required
+        then_required: The names of the fields which must be in the input when `field` equals `equals`
+    """
+    return {'if': {'field': field, 'equals': equals}, 'then_required': then_required}
+
+
 class ModelFieldsSchema(TypedDict, total=False):
     type: Required[Literal['model-fields']]
     fields: Required[Dict[str, ModelField]]
@@ -2883,6 +3441,10 @@ class ModelFieldsSchema(TypedDict, total=False):
     extra_behavior: ExtraBehavior
     populate_by_name: bool  # replaces `allow_population_by_field_name` in pydantic v1
     from_attributes: bool
+    from_attributes_safe: bool
+    case_insensitive: bool  # default: False
+    case_collision: CaseCollision  # default: 'prefer_exact'
+    conditional_required: List[ConditionalRequired]
     ref: str
     metadata: Any
     serialization: SerSchema
@@ -2898,6 +3460,10 @@ def model_fields_schema(
     extra_behavior: ExtraBehavior | None = None,
     populate_by_name: bool | None = None,
     from_attributes: bool | None = None,
+    from_attributes_safe: bool | None = None,
+    case_insensitive: bool | None = None,
+    case_collision: CaseCollision | None = None,
+    conditional_required: list[ConditionalRequired] | None = None,
     ref: str | None = None,
     metadata: Any = None,
     serialization: SerSchema | None = None,
@@ -2927,6 +3493,15 @@ def model_fields_schema(
         extra_behavior: The extra behavior to use for the typed dict
         populate_by_name: Whether the typed dict should populate by name
         from_attributes: Whether the typed dict should be populated from attributes
+        from_attributes_safe: Whether to only read attributes stored in the object's `__dict__` or `__slots__` when
+            populating from attributes, without calling properties, other descriptors or `__getattr__`
+        case_insensitive: Whether fields are matched to input keys ignoring case, e.g. an alias `Content-Type`
+            matches the key `content-type`, alias paths are still matched exactly
+        case_collision: What to do when several input keys match a field ignoring case, `'prefer_exact'` uses the
+            key matching exactly if there is one and otherwise the first key, `'error'` raises a `case_collision` error
+        conditional_required: Rules requiring fields to be in the input when another field has a given value, see
+            `conditional_required`, fields with a default which aren't in the input raise a `missing` error,
+            only applies when validating, not on assignment
         serialization: Custom serialization schema
     """
     return _dict_not_none(
@@ -2939,6 +3514,10 @@ def model_fields_schema(
         extra_behavior=extra_behavior,
         populate_by_name=populate_by_name,
         from_attributes=from_attributes,
+        from_attributes_safe=from_attributes_safe,
+        case_insensitive=case_insensitive,
+        case_collision=case_collision,
+        conditional_required=conditional_required,
         ref=ref,
         metadata=metadata,
         serialization=serialization,
@@ -3010,7 +3589,8 @@ def model_schema(
     ```
 
     Args:
-        cls: The class to use for the model
+        cls: The class to use for the model, instances are created without calling `__init__`, classes using
+            `__slots__` without `__dict__` have each field set as an attribute
         schema: The schema to use for the model
         custom_init: Whether the model has a custom init method
         root_model: Whether the model is a `RootModel`
@@ -3054,6 +3634,7 @@ class DataclassField(TypedDict, total=False):
     validation_alias: Union[str, List[Union[str, int]], List[List[Union[str, int]]]]
     serialization_alias: str
     serialization_exclude: bool  # default: False
+    serialization_omit_if_none: bool
     metadata: Any
 
 
@@ -3067,6 +3648,7 @@ def dataclass_field(
     validation_alias: str | list[str | int] | list[list[str | int]] | None = None,
     serialization_alias: str | None = None,
     serialization_exclude: bool | None = None,
+    serialization_omit_if_none: bool | None = None,
     metadata: Any = None,
     frozen: bool | None = None,
 ) -> DataclassField:
@@ -3093,6 +3675,8 @@ def dataclass_field(
         validation_alias: The alias(es) to use to find the field in the validation data
         serialization_alias: The alias to use as a key when serializing
         serialization_exclude: Whether to exclude the field when serializing
+        serialization_omit_if_none: Whether to omit the field when serializing if it's `None`, overriding
+            `exclude_none` for this field
         metadata: Any other information you want to include with the schema, not used by pydantic-core
         frozen: Whether the field is frozen
     """
@@ -3106,6 +3690,7 @@ def dataclass_field(
         validation_alias=validation_alias,
         serialization_alias=serialization_alias,
         serialization_exclude=serialization_exclude,
+        serialization_omit_if_none=serialization_omit_if_none,
         metadata=metadata,
         frozen=frozen,
     )
@@ -3522,6 +4107,90 @@ def json_schema(
     return _dict_not_none(type='json', schema=schema, ref=ref, metadata=metadata, serialization=serialization)
 
 
+class JsonStringSchema(TypedDict, total=False):
+    type: Required[Literal['json-string']]
+    strict: bool
+    ref: str
+    metadata: Any
+    serialization: SerSchema
+
+
+def json_string_schema(
+    *,
+    strict: bool | None = None,
+    ref: str | None = None,
+    metadata: Any = None,
+    serialization: SerSchema | None = None,
+) -> JsonStringSchema:
+    """
+    Returns a schema that checks a string is well-formed JSON without parsing it, the original string is returned
+    unchanged, e.g.:
+
+    ```py
+    from pydantic_core import SchemaValidator, core_schema
+
+    schema = core_schema.json_string_schema()
+    v = SchemaValidator(schema)
+    assert v.validate_python('{"a": [1, 2]}') == '{"a": [1, 2]}'
+    ```
+
+    Unlike [`json_schema`][pydantic_core.core_schema.json_schema], the JSON isn't converted to Python objects.
+
+    Args:
+        strict: Whether the input should be a `str` rather than a value which can be converted to a string
+        ref: optional unique identifier of the schema, used to reference the schema in other places
+        metadata: Any other information you want to include with the schema, not used by pydantic-core
+        serialization: Custom serialization schema
+    """
+    return _dict_not_none(type='json-string', strict=strict, ref=ref, metadata=metadata, serialization=serialization)
+
+
+class JsonRawSchema(TypedDict, total=False):
+    type: Required[Literal['json-raw']]
+    schema: Required[CoreSchema]
+    ref: str
+    metadata: Any
+    serialization: SerSchema
+
+
+def json_raw_schema(
+    schema: CoreSchema,
+    *,
+    ref: str | None = None,
+    metadata: Any = None,
+    serialization: SerSchema | None = None,
+) -> JsonRawSchema:
+    """
+    Returns a schema that validates a value and keeps the raw JSON it was validated from, e.g. to verify a
+    signature of part of a JSON document. The result is a [`JsonRaw`][pydantic_core.JsonRaw] holding the validated
+    `value` and the exact `raw` bytes of the value in the JSON input, `raw` is `None` unless the value was
+    validated with `validate_json`:
+
+    ```py
+    from pydantic_core import SchemaValidator, core_schema
+
+    schema = core_schema.typed_dict_schema(
+        {
+            'payload': core_schema.typed_dict_field(
+                core_schema.json_raw_schema(core_schema.dict_schema(core_schema.str_schema()))
+            ),
+        }
+    )
+    v = SchemaValidator(schema)
+    payload = v.validate_json('{"payload": {"a": "b" }}')['payload']
+    assert payload.value == {'a': 'b'}
+    assert payload.raw == b'{"a": "b" }'
+    ```
+
+    Args:
+        schema: The schema to validate the value with
+        ref: optional unique identifier of the schema, used to reference the schema in other places
+        metadata: Any other information you want to include with the schema, not used by pydantic-core
+        serialization: Custom serialization schema
+    """
+    return _dict_not_none(type='json-raw', schema=schema, ref=ref, metadata=metadata, serialization=serialization)
+
+
 class UrlSchema(TypedDict, total=False):
     type: Required[Literal['url']]
     max_length: int
@@ -3530,6 +4199,9 @@ class UrlSchema(TypedDict, total=False):
     default_host: str
     default_port: int
     default_path: str
+    strip_default_port: int
+    lowercase_host: bool  # default False
+    strip_trailing_slash: bool  # default False
     strict: bool
     ref: str
     metadata: Any
@@ -3544,6 +4216,9 @@ def url_schema(
     default_host: str | None = None,
     default_port: int | None = None,
     default_path: str | None = None,
+    strip_default_port: int | None = None,
+    lowercase_host: bool | None = None,
+    strip_trailing_slash: bool | None = None,
     strict: bool | None = None,
     ref: str | None = None,
     metadata: Any = None,
@@ -3568,6 +4243,10 @@ def url_schema(
         default_host: The default host to use if the URL does not have a host
         default_port: The default port to use if the URL does not have a port
         default_path: The default path to use if the URL does not have a path
+        strip_default_port: A port to remove from the URL, e.g. `5432` for `postgres` URLs, independent of
+            `default_port`. The default ports of special schemes like `http` are always removed
+        lowercase_host: Whether to lowercase the host, hosts of special schemes like `http` are always lowercased
+        strip_trailing_slash: Whether to remove trailing slashes from the path, e.g. `/foo/` becomes `/foo`
         strict: Whether to use strict URL parsing
         ref: optional unique identifier of the schema, used to reference the schema in other places
         metadata: Any other information you want to include with the schema, not used by pydantic-core
@@ -3581,6 +4260,9 @@ def url_schema(
         default_host=default_host,
         default_port=default_port,
         default_path=default_path,
+        strip_default_port=strip_default_port,
+        lowercase_host=lowercase_host,
+        strip_trailing_slash=strip_trailing_slash,
         strict=strict,
         ref=ref,
         metadata=metadata,
@@ -3596,6 +4278,8 @@ class MultiHostUrlSchema(TypedDict, total=False):
     default_host: str
     default_port: int
     default_path: str
+    min_hosts: int
+    max_hosts: int
     strict: bool
     ref: str
     metadata: Any
@@ -3610,6 +4294,8 @@ def multi_host_url_schema(
     default_host: str | None = None,
     default_port: int | None = None,
     default_path: str | None = None,
+    min_hosts: int | None = None,
+    max_hosts: int | None = None,
     strict: bool | None = None,
     ref: str | None = None,
     metadata: Any = None,
@@ -3629,11 +4315,14 @@ def multi_host_url_schema(
 
     Args:
         max_length: The maximum length of the URL
-        allowed_schemes: The allowed URL schemes
+        allowed_schemes: The allowed URL schemes, all hosts of a multi-host URL share its single scheme so this
+            applies to every host
         host_required: Whether the URL must have a host
         default_host: The default host to use if the URL does not have a host
         default_port: The default port to use if the URL does not have a port
         default_path: The default path to use if the URL does not have a path
+        min_hosts: The minimum number of hosts the URL must have, after `default_host` is applied
+        max_hosts: The maximum number of hosts the URL may have
         strict: Whether to use strict URL parsing
         ref: optional unique identifier of the schema, used to reference the schema in other places
         metadata: Any other information you want to include with the schema, not used by pydantic-core
@@ -3647,6 +4336,8 @@ def multi_host_url_schema(
         default_host=default_host,
         default_port=default_port,
         default_path=default_path,
+        min_hosts=min_hosts,
+        max_hosts=max_hosts,
         strict=strict,
         ref=ref,
         metadata=metadata,
@@ -3735,6 +4426,7 @@ if not MYPY:
         IntSchema,
         FloatSchema,
         DecimalSchema,
+        FractionSchema,
         StringSchema,
         BytesSchema,
         DateSchema,
@@ -3748,6 +4440,7 @@ if not MYPY:
         CallableSchema,
         ListSchema,
         TupleSchema,
+        IntervalSchema,
         SetSchema,
         FrozenSetSchema,
         GeneratorSchema,
@@ -3772,11 +4465,14 @@ if not MYPY:
         CallSchema,
         CustomErrorSchema,
         JsonSchema,
+        JsonStringSchema,
+        JsonRawSchema,
         UrlSchema,
         MultiHostUrlSchema,
         DefinitionsSchema,
         DefinitionReferenceSchema,
         UuidSchema,
+        EmailSchema,
     ]
 elif False:
     CoreSchema: TypeAlias = Mapping[str, Any]
@@ -3790,6 +4486,7 @@ CoreSchemaType = Literal[
     'int',
     'float',
     'decimal',
+    'fraction',
     'str',
     'bytes',
     'date',
@@ -3803,6 +4500,7 @@ CoreSchemaType = Literal[
     'callable',
     'list',
     'tuple',
+    'interval',
     'set',
     'frozenset',
     'generator',
@@ -3827,11 +4525,14 @@ CoreSchemaType = Literal[
     'call',
     'custom-error',
     'json',
+    'json-string',
+    'json-raw',
     'url',
     'multi-host-url',
     'definitions',
     'definition-ref',
     'uuid',
+    'email',
 ]
 
 CoreSchemaFieldType = Literal['model-field', 'dataclass-field', 'typed-dict-field', 'computed-field']
@@ -3844,11 +4545,13 @@ ErrorType = Literal[
     'json_invalid',
     'json_type',
     'recursion_loop',
+    'too_many_errors',
     'missing',
     'frozen_field',
     'frozen_instance',
     'extra_forbidden',
     'invalid_key',
+    'case_collision',
     'get_attribute_error',
     'model_type',
     'model_attributes_type',
@@ -3861,10 +4564,14 @@ ErrorType = Literal[
     'less_than_equal',
     'multiple_of',
     'finite_number',
+    'interval_invalid',
+    'interval_not_comparable',
+    'interval_range_step',
     'too_short',
     'too_long',
     'iterable_type',
     'iteration_error',
+    'contains',
     'string_type',
     'string_sub_type',
     'string_unicode',
@@ -3875,6 +4582,8 @@ ErrorType = Literal[
     'dict_type',
     'mapping_type',
     'list_type',
+    'list_unique_items',
+    'list_column_length',
     'tuple_type',
     'set_type',
     'bool_type',
@@ -3888,6 +4597,7 @@ ErrorType = Literal[
     'bytes_type',
     'bytes_too_short',
     'bytes_too_long',
+    'bytes_invalid_encoding',
     'value_error',
     'assertion_error',
     'literal_error',
@@ -3905,6 +4615,7 @@ ErrorType = Literal[
     'datetime_from_date_parsing',
     'datetime_past',
     'datetime_future',
+    'datetime_ambiguous',
     'timezone_naive',
     'timezone_aware',
     'timezone_offset',
@@ -3922,20 +4633,27 @@ ErrorType = Literal[
     'missing_keyword_only_argument',
     'unexpected_positional_argument',
     'missing_positional_only_argument',
+    'positional_only_argument_as_keyword',
+    'keyword_only_argument_as_positional',
     'multiple_argument_values',
     'url_type',
     'url_parsing',
     'url_syntax_violation',
     'url_too_long',
     'url_scheme',
+    'url_too_few_hosts',
+    'url_too_many_hosts',
     'uuid_type',
     'uuid_parsing',
     'uuid_version',
+    'email_parsing',
     'decimal_type',
     'decimal_parsing',
     'decimal_max_digits',
     'decimal_max_places',
     'decimal_whole_digits',
+    'fraction_type',
+    'fraction_parsing',
 ]
 
 
@@ -14,6 +14,16 @@ pub struct ArgsKwargs {
 }
 
 impl ArgsKwargs {
+    pub fn new(args: &Bound<'_, PyTuple>, kwargs: Option<&Bound<'_, PyDict>>) -> Self {
+        Self {
+            args: args.clone().unbind(),
+            kwargs: match kwargs {
+                Some(d) if !d.is_empty() => Some(d.clone().unbind()),
+                _ => None,
+            },
+        }
+    }
+
     fn eq(&self, py: Python, other: &Self) -> PyResult<bool> {
         if self.args.bind(py).eq(other.args.bind(py))? {
             match (&self.kwargs, &other.kwargs) {
@@ -30,14 +40,8 @@ impl ArgsKwargs {
 #[pymethods]
 impl ArgsKwargs {
     #[new]
-    fn py_new(py: Python, args: &Bound<'_, PyTuple>, kwargs: Option<&Bound<'_, PyDict>>) -> Self {
-        Self {
-            args: args.into_py(py),
-            kwargs: match kwargs {
-                Some(d) if !d.is_empty() => Some(d.to_owned().unbind()),
-                _ => None,
-            },
-        }
+    fn py_new(args: &Bound<'_, PyTuple>, kwargs: Option<&Bound<'_, PyDict>>) -> Self {
+        Self::new(args, kwargs)
     }
 
     fn __richcmp__(&self, other: &Self, op: CompareOp, py: Python<'_>) -> PyObject {
@@ -1,8 +1,9 @@
 use std::error::Error;
 use std::fmt;
 
-use pyo3::exceptions::PyException;
+use pyo3::exceptions::{PyException, PyKeyError, PyTypeError, PyValueError};
 use pyo3::prelude::*;
+use pyo3::sync::GILOnceCell;
 use pyo3::types::{PyDict, PyList, PyString};
 use pyo3::{intern, FromPyObject, PyErrArguments};
 
@@ -45,6 +46,11 @@ pub fn is_strict(schema: &Bound<'_, PyDict>, config: Option<&Bound<'_, PyDict>>)
     Ok(schema_or_config_same(schema, config, intern!(py, "strict"))?.unwrap_or(false))
 }
 
+pub fn explain_strict(schema: &Bound<'_, PyDict>, config: Option<&Bound<'_, PyDict>>) -> PyResult<bool> {
+    let py = schema.py();
+    Ok(schema_or_config_same(schema, config, intern!(py, "explain_strict"))?.unwrap_or(false))
+}
+
 enum SchemaErrorEnum {
     Message(String),
     ValidationError(ValidationError),
@@ -164,6 +170,69 @@ macro_rules! py_schema_err {
 }
 pub(crate) use py_schema_err;
 
+/// Custom schema types registered with `register_schema_type`, mapping a `type` to the function which builds
+/// the schema to use in place of a schema of that type.
+static SCHEMA_TYPE_REGISTRY: GILOnceCell<Py<PyDict>> = GILOnceCell::new();
+
+fn schema_type_registry(py: Python<'_>) -> &Bound<'_, PyDict> {
+    SCHEMA_TYPE_REGISTRY
+        .get_or_init(py, || PyDict::new_bound(py).unbind())
+        .bind(py)
+}
+
+#[pyfunction]
+pub fn register_schema_type(py: Python, schema_type: &str, build: &Bound<'_, PyAny>) -> PyResult<()> {
+    if crate::validators::is_builtin_schema_type(schema_type) {
+        return Err(PyValueError::new_err(format!(
+            "`{schema_type}` is a built-in schema type and can't be registered"
+        )));
+    }
+    if !build.is_callable() {
+        return Err(PyTypeError::new_err("`build` must be callable"));
+    }
+    schema_type_registry(py).set_item(schema_type, build)
+}
+
+#[pyfunction]
+pub fn unregister_schema_type(py: Python, schema_type: &str) -> PyResult<()> {
+    let registry = schema_type_registry(py);
+    if !registry.contains(schema_type)? {
+        return Err(PyKeyError::new_err(format!(
+            "`{schema_type}` isn't a registered schema type"
+        )));
+    }
+    registry.del_item(schema_type)
+}
+
+#[pyfunction]
+pub fn clear_schema_types(py: Python) {
+    schema_type_registry(py).clear();
+}
+
+/// If `type_` is a registered custom schema type, call its build function with `schema` until the result is of a
+/// built-in type, and return that schema to be built instead.
+pub fn expand_schema_type<'py>(schema: &Bound<'py, PyDict>, type_: &str) -> PyResult<Option<Bound<'py, PyDict>>> {
+    let py = schema.py();
+    let registry = schema_type_registry(py);
+    if registry.is_empty() {
+        return Ok(None);
+    }
+    let mut schema = schema.clone();
+    let mut expanded_types: Vec<String> = Vec::new();
+    let mut type_ = type_.to_string();
+    while let Some(build) = registry.get_item(&type_)? {
+        if expanded_types.contains(&type_) {
+            return py_schema_err!("Custom schema type `{}` expands to itself", type_);
+        }
+        schema = build.call1((&schema,))?.downcast_into::<PyDict>().map_err(|_| {
+            py_schema_error_type!("Build function of custom schema type `{}` must return a dict", type_)
+        })?;
+        let new_type: Bound<'_, PyString> = schema.get_as_req(intern!(py, "type"))?;
+        expanded_types.push(std::mem::replace(&mut type_, new_type.to_str()?.to_string()));
+    }
+    Ok((!expanded_types.is_empty()).then_some(schema))
+}
+
 #[derive(Debug, Clone, Copy, Eq, PartialEq)]
 pub(crate) enum ExtraBehavior {
     Allow,
@@ -61,6 +61,10 @@ impl<T> DefinitionRef<T> {
         Weak::as_ptr(&self.value) as usize
     }
 
+    pub fn reference(&self) -> &str {
+        &self.reference
+    }
+
     pub fn get_or_init_name(&self, init: impl FnOnce(&T) -> String) -> &str {
         let Some(definition) = self.value.upgrade() else {
             return "...";
@@ -87,6 +87,39 @@ impl ValError {
             other => other,
         }
     }
+
+    /// With `explain` set, add an `explanation` to the context of errors raised because strict mode refused to
+    /// coerce the input, `lax_is_valid` is only called then and should report whether lax mode accepts the input.
+    pub fn explain_strict(
+        self,
+        py: Python,
+        expected: &str,
+        explain: bool,
+        lax_is_valid: impl FnOnce() -> bool,
+    ) -> Self {
+        if !explain || !lax_is_valid() {
+            return self;
+        }
+        match self {
+            Self::LineErrors(mut line_errors) => {
+                for line_error in &mut line_errors {
+                    let input_type = match line_error.input_value.type_name(py) {
+                        Ok(input_type) => input_type,
+                        Err(err) => return Self::InternalErr(err),
+                    };
+                    let explanation = format!(
+                        "Input of type `{input_type}` was provided but strict mode requires `{expected}`, \
+                        use lax mode or pass a value of type `{expected}`"
+                    );
+                    if let Err(err) = line_error.error_type.add_context_item(py, "explanation", explanation) {
+                        return Self::InternalErr(err);
+                    }
+                }
+                Self::LineErrors(line_errors)
+            }
+            other => other,
+        }
+    }
 }
 
 /// A `ValLineError` is a single error that occurred during validation which is converted to a `PyLineError`
@@ -154,6 +187,25 @@ pub enum InputValue {
     Json(JsonValue<'static>),
 }
 
+impl InputValue {
+    /// Name of the input's type, for python inputs this is the class name, for JSON inputs the JSON type.
+    pub fn type_name(&self, py: Python) -> PyResult<String> {
+        match self {
+            Self::Python(input) => input.bind(py).get_type().qualname(),
+            Self::Json(input) => Ok(match input {
+                JsonValue::Null => "null",
+                JsonValue::Bool(_) => "bool",
+                JsonValue::Int(_) | JsonValue::BigInt(_) => "int",
+                JsonValue::Float(_) => "float",
+                JsonValue::Str(_) => "string",
+                JsonValue::Array(_) => "array",
+                JsonValue::Object(_) => "object",
+            }
+            .to_string()),
+        }
+    }
+}
+
 impl ToPyObject for InputValue {
     fn to_object(&self, py: Python) -> PyObject {
         match self {
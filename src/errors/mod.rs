@@ -10,7 +10,9 @@ pub use self::line_error::{InputValue, ToErrorValue, ValError, ValLineError, Val
 pub use self::location::LocItem;
 pub use self::types::{list_all_errors, ErrorType, ErrorTypeDefaults, Number};
 pub use self::validation_exception::ValidationError;
-pub use self::value_exception::{PydanticCustomError, PydanticKnownError, PydanticOmit, PydanticUseDefault};
+pub use self::value_exception::{
+    PydanticCustomError, PydanticKnownError, PydanticMultiError, PydanticOmit, PydanticUseDefault,
+};
 
 pub fn py_err_string(py: Python, err: PyErr) -> String {
     let value = err.value_bound(py);
@@ -118,6 +118,14 @@ macro_rules! error_types {
                 }
             }
 
+            fn context_mut(&mut self) -> &mut Option<Py<PyDict>> {
+                match self {
+                    $(
+                        Self::$item { context, .. } => context,
+                    )+
+                }
+            }
+
             fn py_dict_update_ctx(&self, py: Python, dict: &Bound<'_, PyDict>) -> PyResult<bool> {
                 use pyo3::types::PyMapping;
                 match self {
@@ -171,12 +179,20 @@ error_types! {
     // recursion error
     RecursionLoop {},
     // ---------------------
+    // error limit, added when `max_errors` is exceeded
+    TooManyErrors {
+        omitted: {ctx_type: usize, ctx_fn: field_from_context},
+    },
+    // ---------------------
     // typed dict specific errors
     Missing {},
     FrozenField {},
     FrozenInstance {},
     ExtraForbidden {},
     InvalidKey {},
+    CaseCollision {
+        keys: {ctx_type: String, ctx_fn: field_from_context},
+    },
     GetAttributeError {
         error: {ctx_type: String, ctx_fn: field_from_context},
     },
@@ -215,6 +231,11 @@ error_types! {
         multiple_of: {ctx_type: Number, ctx_fn: field_from_context},
     },
     FiniteNumber {},
+    IntervalInvalid {
+        expected: {ctx_type: String, ctx_fn: field_from_context},
+    },
+    IntervalNotComparable {},
+    IntervalRangeStep {},
     // ---------------------
     // generic length errors - used for everything with a length except strings and bytes which need custom messages
     TooShort {
@@ -233,6 +254,12 @@ error_types! {
     IterationError {
         error: {ctx_type: String, ctx_fn: field_from_context},
     },
+    Contains {
+        field_type: {ctx_type: String, ctx_fn: field_from_context},
+        min_contains: {ctx_type: usize, ctx_fn: field_from_context},
+        max_contains: {ctx_type: Option<usize>, ctx_fn: field_from_context},
+        actual_contains: {ctx_type: usize, ctx_fn: field_from_context},
+    },
     // ---------------------
     // string errors
     StringType {},
@@ -261,6 +288,14 @@ error_types! {
     // ---------------------
     // list errors
     ListType {},
+    ListUniqueItems {
+        first_index: {ctx_type: usize, ctx_fn: field_from_context},
+        duplicate_index: {ctx_type: usize, ctx_fn: field_from_context},
+    },
+    ListColumnLength {
+        expected_length: {ctx_type: usize, ctx_fn: field_from_context},
+        actual_length: {ctx_type: usize, ctx_fn: field_from_context},
+    },
     // ---------------------
     // tuple errors
     TupleType {},
@@ -290,6 +325,10 @@ error_types! {
     BytesTooLong {
         max_length: {ctx_type: usize, ctx_fn: field_from_context},
     },
+    BytesInvalidEncoding {
+        encoding: {ctx_type: String, ctx_fn: field_from_context},
+        encoding_error: {ctx_type: String, ctx_fn: field_from_context},
+    },
     // ---------------------
     // python errors from functions
     ValueError {
@@ -303,6 +342,7 @@ error_types! {
         // context is a common field in all enums
         error_type: {ctx_type: String, ctx_fn: field_from_context},
         message_template: {ctx_type: String, ctx_fn: field_from_context},
+        metadata: {ctx_type: Option<Py<PyDict>>, ctx_fn: field_from_context},
     },
     // ---------------------
     // literals
@@ -341,6 +381,7 @@ error_types! {
     },
     DatetimePast {},
     DatetimeFuture {},
+    DatetimeAmbiguous {},
     // ---------------------
     // timezone errors
     TimezoneNaive {},
@@ -385,6 +426,12 @@ error_types! {
     MissingKeywordOnlyArgument {},
     UnexpectedPositionalArgument {},
     MissingPositionalOnlyArgument {},
+    PositionalOnlyArgumentAsKeyword {
+        parameter: {ctx_type: String, ctx_fn: field_from_context},
+    },
+    KeywordOnlyArgumentAsPositional {
+        parameter: {ctx_type: String, ctx_fn: field_from_context},
+    },
     MultipleArgumentValues {},
     // ---------------------
     // URL errors
@@ -402,6 +449,14 @@ error_types! {
     UrlScheme {
         expected_schemes: {ctx_type: String, ctx_fn: field_from_context},
     },
+    UrlTooFewHosts {
+        min_hosts: {ctx_type: usize, ctx_fn: field_from_context},
+        actual_hosts: {ctx_type: usize, ctx_fn: field_from_context},
+    },
+    UrlTooManyHosts {
+        max_hosts: {ctx_type: usize, ctx_fn: field_from_context},
+        actual_hosts: {ctx_type: usize, ctx_fn: field_from_context},
+    },
     // UUID errors,
     UuidType {},
     UuidParsing {
@@ -410,6 +465,10 @@ error_types! {
     UuidVersion {
         expected_version: {ctx_type: usize, ctx_fn: field_from_context},
     },
+    // Email errors
+    EmailParsing {
+        reason: {ctx_type: String, ctx_fn: field_from_context},
+    },
     // Decimal errors
     DecimalType {},
     DecimalParsing {},
@@ -422,6 +481,9 @@ error_types! {
     DecimalWholeDigits {
         whole_digits: {ctx_type: u64, ctx_fn: field_from_context},
     },
+    // Fraction errors
+    FractionType {},
+    FractionParsing {},
 }
 
 macro_rules! render {
@@ -462,6 +524,15 @@ impl ErrorType {
             error_type: custom_error.error_type(),
             message_template: custom_error.message_template(),
             context: custom_error.context(py),
+            metadata: custom_error.metadata(py),
+        }
+    }
+
+    /// Data attached to custom errors which is passed through to `ValidationError.errors()` unchanged
+    pub fn metadata(&self) -> Option<&Py<PyDict>> {
+        match self {
+            Self::CustomError { metadata, .. } => metadata.as_ref(),
+            _ => None,
         }
     }
 
@@ -471,11 +542,13 @@ impl ErrorType {
             Self::JsonInvalid {..} => "Invalid JSON: {error}",
             Self::JsonType {..} => "JSON input should be string, bytes or bytearray",
             Self::RecursionLoop {..} => "Recursion error - cyclic reference detected",
+            Self::TooManyErrors {..} => "{omitted} more error{expected_plural} omitted",
             Self::Missing {..} => "Field required",
             Self::FrozenField {..} => "Field is frozen",
             Self::FrozenInstance {..} => "Instance is frozen",
             Self::ExtraForbidden {..} => "Extra inputs are not permitted",
             Self::InvalidKey {..} => "Keys should be strings",
+            Self::CaseCollision {..} => "Keys {keys} only differ in case",
             Self::GetAttributeError {..} => "Error extracting attribute: {error}",
             Self::ModelType {..} => "Input should be a valid dictionary or instance of {class_name}",
             Self::ModelAttributesType {..} => "Input should be a valid dictionary or object to extract fields from",
@@ -488,10 +561,14 @@ impl ErrorType {
             Self::LessThanEqual {..} => "Input should be less than or equal to {le}",
             Self::MultipleOf {..} => "Input should be a multiple of {multiple_of}",
             Self::FiniteNumber {..} => "Input should be a finite number",
+            Self::IntervalInvalid {..} => "Interval start should be {expected} its end",
+            Self::IntervalNotComparable {..} => "Interval start and end should be comparable",
+            Self::IntervalRangeStep {..} => "Range inputs to an interval should have a step of 1",
             Self::TooShort {..} => "{field_type} should have at least {min_length} item{expected_plural} after validation, not {actual_length}",
             Self::TooLong {..} => "{field_type} should have at most {max_length} item{expected_plural} after validation, not {actual_length}",
             Self::IterableType {..} => "Input should be iterable",
             Self::IterationError {..} => "Error iterating over object, error: {error}",
+            Self::Contains {..} => "{field_type} should contain {expected} item{expected_plural} matching `contains`, not {actual_contains}",
             Self::StringType {..} => "Input should be a valid string",
             Self::StringSubType {..} => "Input should be a string, not an instance of a subclass of str",
             Self::StringUnicode {..} => "Input should be a valid string, unable to parse raw data as a unicode string",
@@ -502,6 +579,8 @@ impl ErrorType {
             Self::DictType {..} => "Input should be a valid dictionary",
             Self::MappingType {..} => "Input should be a valid mapping, error: {error}",
             Self::ListType {..} => "Input should be a valid list",
+            Self::ListUniqueItems {..} => "List should have unique items, item {duplicate_index} is a duplicate of item {first_index}",
+            Self::ListColumnLength {..} => "Column should have {expected_length} item{expected_plural} like the first column, not {actual_length}",
             Self::TupleType {..} => "Input should be a valid tuple",
             Self::SetType {..} => "Input should be a valid set",
             Self::BoolType {..} => "Input should be a valid boolean",
@@ -515,6 +594,7 @@ impl ErrorType {
             Self::BytesType {..} => "Input should be a valid bytes",
             Self::BytesTooShort {..} => "Data should have at least {min_length} byte{expected_plural}",
             Self::BytesTooLong {..} => "Data should have at most {max_length} byte{expected_plural}",
+            Self::BytesInvalidEncoding {..} => "Data should be valid {encoding}: {encoding_error}",
             Self::ValueError {..} => "Value error, {error}",
             Self::AssertionError {..} => "Assertion failed, {error}",
             Self::CustomError {..} => "",  // custom errors are handled separately
@@ -533,6 +613,7 @@ impl ErrorType {
             Self::DatetimeFromDateParsing {..} => "Input should be a valid datetime or date, {error}",
             Self::DatetimePast {..} => "Input should be in the past",
             Self::DatetimeFuture {..} => "Input should be in the future",
+            Self::DatetimeAmbiguous {..} => "Input should not be an ambiguous local time",
             Self::TimezoneNaive {..} => "Input should not have timezone info",
             Self::TimezoneAware {..} => "Input should have timezone info",
             Self::TimezoneOffset {..} => "Timezone offset of {tz_expected} required, got {tz_actual}",
@@ -550,20 +631,27 @@ impl ErrorType {
             Self::MissingKeywordOnlyArgument {..} => "Missing required keyword only argument",
             Self::UnexpectedPositionalArgument {..} => "Unexpected positional argument",
             Self::MissingPositionalOnlyArgument {..} => "Missing required positional only argument",
+            Self::PositionalOnlyArgumentAsKeyword {..} => "Positional only argument '{parameter}' passed as a keyword argument",
+            Self::KeywordOnlyArgumentAsPositional {..} => "Keyword only argument '{parameter}' passed as a positional argument",
             Self::MultipleArgumentValues {..} => "Got multiple values for argument",
             Self::UrlType {..} => "URL input should be a string or URL",
             Self::UrlParsing {..} => "Input should be a valid URL, {error}",
             Self::UrlSyntaxViolation {..} => "Input violated strict URL syntax rules, {error}",
             Self::UrlTooLong {..} => "URL should have at most {max_length} character{expected_plural}",
             Self::UrlScheme {..} => "URL scheme should be {expected_schemes}",
+            Self::UrlTooFewHosts {..} => "URL should have at least {min_hosts} host{expected_plural}, not {actual_hosts}",
+            Self::UrlTooManyHosts {..} => "URL should have at most {max_hosts} host{expected_plural}, not {actual_hosts}",
             Self::UuidType {..} => "UUID input should be a string, bytes or UUID object",
             Self::UuidParsing {..} => "Input should be a valid UUID, {error}",
             Self::UuidVersion {..} => "UUID version {expected_version} expected",
+            Self::EmailParsing {..} => "Value should be a valid email address, {reason}",
             Self::DecimalType {..} => "Decimal input should be an integer, float, string or Decimal object",
             Self::DecimalParsing {..} => "Input should be a valid decimal",
             Self::DecimalMaxDigits {..} => "Decimal input should have no more than {max_digits} digit{expected_plural} in total",
             Self::DecimalMaxPlaces {..} => "Decimal input should have no more than {decimal_places} decimal place{expected_plural}",
             Self::DecimalWholeDigits {..} => "Decimal input should have no more than {whole_digits} digit{expected_plural} before the decimal point",
+            Self::FractionType {..} => "Fraction input should be an integer, string, pair of integers or Fraction object",
+            Self::FractionParsing {..} => "Input should be a valid fraction",
         }
     }
 
@@ -617,6 +705,14 @@ impl ErrorType {
             Self::NoSuchAttribute { attribute, .. } => render!(tmpl, attribute),
             Self::JsonInvalid { error, .. } => render!(tmpl, error),
             Self::GetAttributeError { error, .. } => render!(tmpl, error),
+            Self::PositionalOnlyArgumentAsKeyword { parameter, .. } => render!(tmpl, parameter),
+            Self::KeywordOnlyArgumentAsPositional { parameter, .. } => render!(tmpl, parameter),
+            Self::TooManyErrors { omitted, .. } => {
+                let expected_plural = plural_s(*omitted);
+                to_string_render!(tmpl, omitted, expected_plural)
+            }
+            Self::CaseCollision { keys, .. } => render!(tmpl, keys),
+            Self::IntervalInvalid { expected, .. } => render!(tmpl, expected),
             Self::ModelType { class_name, .. } => render!(tmpl, class_name),
             Self::DataclassType { class_name, .. } => render!(tmpl, class_name),
             Self::DataclassExactType { class_name, .. } => render!(tmpl, class_name),
@@ -625,6 +721,19 @@ impl ErrorType {
             Self::LessThan { lt, .. } => to_string_render!(tmpl, lt),
             Self::LessThanEqual { le, .. } => to_string_render!(tmpl, le),
             Self::MultipleOf { multiple_of, .. } => to_string_render!(tmpl, multiple_of),
+            Self::ListUniqueItems {
+                first_index,
+                duplicate_index,
+                ..
+            } => to_string_render!(tmpl, first_index, duplicate_index),
+            Self::ListColumnLength {
+                expected_length,
+                actual_length,
+                ..
+            } => {
+                let expected_plural = plural_s(*expected_length);
+                to_string_render!(tmpl, expected_length, actual_length, expected_plural,)
+            }
             Self::TooShort {
                 field_type,
                 min_length,
@@ -645,6 +754,22 @@ impl ErrorType {
                 to_string_render!(tmpl, field_type, max_length, actual_length, expected_plural,)
             }
             Self::IterationError { error, .. } => render!(tmpl, error),
+            Self::Contains {
+                field_type,
+                min_contains,
+                max_contains,
+                actual_contains,
+                ..
+            } => {
+                let (expected, expected_count) = match max_contains {
+                    Some(max_contains) if actual_contains > max_contains => {
+                        (format!("at most {max_contains}"), *max_contains)
+                    }
+                    _ => (format!("at least {min_contains}"), *min_contains),
+                };
+                let expected_plural = plural_s(expected_count);
+                to_string_render!(tmpl, field_type, expected, actual_contains, expected_plural,)
+            }
             Self::StringTooShort { min_length, .. } => {
                 let expected_plural = plural_s(*min_length);
                 to_string_render!(tmpl, min_length, expected_plural)
@@ -664,6 +789,11 @@ impl ErrorType {
                 let expected_plural = plural_s(*max_length);
                 to_string_render!(tmpl, max_length, expected_plural)
             }
+            Self::BytesInvalidEncoding {
+                encoding,
+                encoding_error,
+                ..
+            } => render!(tmpl, encoding, encoding_error),
             Self::ValueError { error, .. } => {
                 let error = &error
                     .as_ref()
@@ -708,8 +838,25 @@ impl ErrorType {
                 to_string_render!(tmpl, max_length, expected_plural)
             }
             Self::UrlScheme { expected_schemes, .. } => render!(tmpl, expected_schemes),
+            Self::UrlTooFewHosts {
+                min_hosts,
+                actual_hosts,
+                ..
+            } => {
+                let expected_plural = plural_s(*min_hosts);
+                to_string_render!(tmpl, min_hosts, actual_hosts, expected_plural)
+            }
+            Self::UrlTooManyHosts {
+                max_hosts,
+                actual_hosts,
+                ..
+            } => {
+                let expected_plural = plural_s(*max_hosts);
+                to_string_render!(tmpl, max_hosts, actual_hosts, expected_plural)
+            }
             Self::UuidParsing { error, .. } => render!(tmpl, error),
             Self::UuidVersion { expected_version, .. } => to_string_render!(tmpl, expected_version),
+            Self::EmailParsing { reason, .. } => render!(tmpl, reason),
             Self::DecimalMaxDigits { max_digits, .. } => {
                 let expected_plural = plural_s(*max_digits);
                 to_string_render!(tmpl, max_digits, expected_plural)
@@ -726,16 +873,23 @@ impl ErrorType {
         }
     }
 
+    /// Add an extra item to the error context, alongside the values used to render the message.
+    pub fn add_context_item(&mut self, py: Python, key: &str, value: impl ToPyObject) -> PyResult<()> {
+        let context = self.context_mut().get_or_insert_with(|| PyDict::new_bound(py).unbind());
+        context.bind(py).set_item(key, value)
+    }
+
     pub fn py_dict(&self, py: Python) -> PyResult<Option<Py<PyDict>>> {
         let dict = PyDict::new_bound(py);
         let custom_ctx_used = self.py_dict_update_ctx(py, &dict)?;
 
         if let Self::CustomError { .. } = self {
             if custom_ctx_used {
-                // Custom error type and message are handled separately by the caller.
+                // Custom error type, message and metadata are handled separately by the caller.
                 // They are added to the root of the ErrorDetails.
                 dict.del_item("error_type")?;
                 dict.del_item("message_template")?;
+                dict.del_item("metadata")?;
                 Ok(Some(dict.into()))
             } else {
                 Ok(None)
@@ -7,7 +7,7 @@ use pyo3::ffi;
 use pyo3::intern;
 use pyo3::prelude::*;
 use pyo3::sync::GILOnceCell;
-use pyo3::types::{PyDict, PyList, PyString};
+use pyo3::types::{PyDict, PyList, PyString, PyTuple};
 use serde::ser::{Error, SerializeMap, SerializeSeq};
 use serde::{Serialize, Serializer};
 
@@ -313,6 +313,32 @@ impl ValidationError {
         }
     }
 
+    pub fn errors_tree<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyDict>> {
+        let tree = PyDict::new_bound(py);
+        for line_error in &self.line_errors {
+            let message = line_error.error_type.render_message(py, self.input_type)?;
+            let loc = line_error.location.to_object(py);
+            let loc = loc.downcast_bound::<PyTuple>(py)?;
+            let mut node = tree.clone();
+            let mut leaf_key = py.None().into_bound(py);
+            for (index, loc_item) in loc.iter().enumerate() {
+                if index + 1 == loc.len() {
+                    leaf_key = loc_item;
+                } else {
+                    node = errors_tree_child(&node, &loc_item)?;
+                }
+            }
+            match node.get_item(&leaf_key)? {
+                Some(existing) => match existing.downcast::<PyList>() {
+                    Ok(messages) => messages.append(message)?,
+                    Err(_) => errors_tree_messages(existing.downcast::<PyDict>()?)?.append(message)?,
+                },
+                None => node.set_item(&leaf_key, PyList::new_bound(py, [message]))?,
+            }
+        }
+        Ok(tree)
+    }
+
     #[pyo3(signature = (*, indent = None, include_url = true, include_context = true, include_input = true))]
     pub fn json<'py>(
         &self,
@@ -386,6 +412,38 @@ impl ValidationError {
     }
 }
 
+/// Get the subtree for `key` in `node`, creating it if needed, if `key` already holds a list of messages
+/// they're moved under the `None` key of the new subtree.
+fn errors_tree_child<'py>(node: &Bound<'py, PyDict>, key: &Bound<'py, PyAny>) -> PyResult<Bound<'py, PyDict>> {
+    let py = node.py();
+    let child = match node.get_item(key)? {
+        Some(existing) => match existing.downcast_into::<PyDict>() {
+            Ok(child) => return Ok(child),
+            Err(err) => {
+                let child = PyDict::new_bound(py);
+                child.set_item(py.None(), err.into_inner())?;
+                child
+            }
+        },
+        None => PyDict::new_bound(py),
+    };
+    node.set_item(key, &child)?;
+    Ok(child)
+}
+
+/// Get the list of messages for errors located at `node` itself, stored under the `None` key.
+fn errors_tree_messages<'py>(node: &Bound<'py, PyDict>) -> PyResult<Bound<'py, PyList>> {
+    let py = node.py();
+    match node.get_item(py.None())? {
+        Some(messages) => Ok(messages.downcast_into::<PyList>()?),
+        None => {
+            let messages = PyList::empty_bound(py);
+            node.set_item(py.None(), &messages)?;
+            Ok(messages)
+        }
+    }
+}
+
 // TODO: is_utf8_char_boundary, floor_char_boundary and ceil_char_boundary
 // with builtin methods once https://github.com/rust-lang/rust/issues/93743 is resolved
 // These are just copy pasted from the current implementation
@@ -538,6 +596,9 @@ impl PyLineError {
                 dict.set_item("ctx", context)?;
             }
         }
+        if let Some(metadata) = self.error_type.metadata() {
+            dict.set_item("meta", metadata)?;
+        }
         if let Some(url_prefix) = url_prefix {
             match self.error_type {
                 ErrorType::CustomError { .. } => {
@@ -656,10 +717,16 @@ impl<'py> Serialize for PyLineErrorSerializer<'py> {
         S: Serializer,
     {
         let py = self.py;
-        let size = 3 + [self.url_prefix.is_some(), self.include_context, self.include_input]
-            .into_iter()
-            .filter(|b| *b)
-            .count();
+        let metadata = self.line_error.error_type.metadata();
+        let size = 3 + [
+            self.url_prefix.is_some(),
+            self.include_context,
+            self.include_input,
+            metadata.is_some(),
+        ]
+        .into_iter()
+        .filter(|b| *b)
+        .count();
         let mut map = serializer.serialize_map(Some(size))?;
 
         map.serialize_entry("type", &self.line_error.error_type.type_string())?;
@@ -685,6 +752,9 @@ impl<'py> Serialize for PyLineErrorSerializer<'py> {
                 map.serialize_entry("ctx", &self.extra.serialize_infer(context.bind(py)))?;
             }
         }
+        if let Some(metadata) = metadata {
+            map.serialize_entry("meta", &self.extra.serialize_infer(metadata.bind(py)))?;
+        }
         if let Some(url_prefix) = self.url_prefix {
             map.serialize_entry("url", &self.line_error.get_error_url(url_prefix))?;
         }
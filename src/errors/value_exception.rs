@@ -1,12 +1,13 @@
-use pyo3::exceptions::{PyException, PyValueError};
+use pyo3::exceptions::{PyException, PyTypeError, PyValueError};
 use pyo3::prelude::*;
-use pyo3::types::{PyDict, PyString};
+use pyo3::types::{PyDict, PyList, PyString, PyTuple};
 
 use crate::input::InputType;
 use crate::tools::extract_i64;
 
 use super::line_error::ToErrorValue;
-use super::{ErrorType, ValError};
+use super::location::Location;
+use super::{ErrorType, ValError, ValLineError};
 
 #[pyclass(extends=PyException, module="pydantic_core._pydantic_core")]
 #[derive(Debug, Clone)]
@@ -60,16 +61,24 @@ pub struct PydanticCustomError {
     error_type: String,
     message_template: String,
     context: Option<Py<PyDict>>,
+    metadata: Option<Py<PyDict>>,
 }
 
 #[pymethods]
 impl PydanticCustomError {
     #[new]
-    pub fn py_new(error_type: String, message_template: String, context: Option<Bound<'_, PyDict>>) -> Self {
+    #[pyo3(signature = (error_type, message_template, context=None, metadata=None))]
+    pub fn py_new(
+        error_type: String,
+        message_template: String,
+        context: Option<Bound<'_, PyDict>>,
+        metadata: Option<Bound<'_, PyDict>>,
+    ) -> Self {
         Self {
             error_type,
             message_template,
             context: context.map(Bound::unbind),
+            metadata: metadata.map(Bound::unbind),
         }
     }
 
@@ -88,6 +97,11 @@ impl PydanticCustomError {
         self.context.as_ref().map(|c| c.clone_ref(py))
     }
 
+    #[getter]
+    pub fn metadata(&self, py: Python) -> Option<Py<PyDict>> {
+        self.metadata.as_ref().map(|m| m.clone_ref(py))
+    }
+
     pub fn message(&self, py: Python) -> PyResult<String> {
         Self::format_message(&self.message_template, self.context.as_ref().map(|c| c.bind(py)))
     }
@@ -107,12 +121,16 @@ impl PydanticCustomError {
 
 impl PydanticCustomError {
     pub fn into_val_error(self, input: impl ToErrorValue) -> ValError {
-        let error_type = ErrorType::CustomError {
+        ValError::new(self.into_error_type(), input)
+    }
+
+    fn into_error_type(self) -> ErrorType {
+        ErrorType::CustomError {
             error_type: self.error_type,
             message_template: self.message_template,
             context: self.context,
-        };
-        ValError::new(error_type, input)
+            metadata: self.metadata,
+        }
     }
 
     pub fn format_message(message_template: &str, context: Option<&Bound<'_, PyDict>>) -> PyResult<String> {
@@ -185,3 +203,83 @@ impl PydanticKnownError {
         ValError::new(self.error_type, input)
     }
 }
+
+/// Several errors raised together by one validator, each at a location relative to the validator.
+#[pyclass(extends=PyValueError, module="pydantic_core._pydantic_core")]
+#[derive(Clone)]
+pub struct PydanticMultiError {
+    errors: Vec<(Location, ErrorType)>,
+}
+
+#[pymethods]
+impl PydanticMultiError {
+    #[new]
+    pub fn py_new(errors: &Bound<'_, PyAny>) -> PyResult<Self> {
+        let errors = errors
+            .iter()?
+            .map(|item| {
+                let (loc, error): (Bound<'_, PyAny>, Bound<'_, PyAny>) = item?.extract()?;
+                let error_type = if let Ok(custom_error) = error.extract::<PydanticCustomError>() {
+                    custom_error.into_error_type()
+                } else if let Ok(known_error) = error.extract::<PydanticKnownError>() {
+                    known_error.error_type
+                } else {
+                    return Err(PyTypeError::new_err(
+                        "PydanticMultiError errors must be PydanticCustomError or PydanticKnownError instances",
+                    ));
+                };
+                Ok((Location::try_from(Some(&loc))?, error_type))
+            })
+            .collect::<PyResult<Vec<_>>>()?;
+        if errors.is_empty() {
+            return Err(PyValueError::new_err("PydanticMultiError requires at least one error"));
+        }
+        Ok(Self { errors })
+    }
+
+    /// The errors as `(loc, message)` pairs.
+    #[getter]
+    pub fn errors(&self, py: Python) -> PyResult<Py<PyList>> {
+        let errors = self
+            .errors
+            .iter()
+            .map(|(loc, error_type)| {
+                let message = error_type.render_message(py, InputType::Python)?;
+                Ok(PyTuple::new_bound(py, [loc.to_object(py), message.to_object(py)]))
+            })
+            .collect::<PyResult<Vec<_>>>()?;
+        Ok(PyList::new_bound(py, errors).unbind())
+    }
+
+    fn __str__(&self, py: Python) -> PyResult<String> {
+        let messages = self
+            .errors
+            .iter()
+            .map(|(loc, error_type)| {
+                let message = error_type.render_message(py, InputType::Python)?;
+                Ok(match loc {
+                    Location::Empty => message,
+                    Location::List(_) => format!("{}: {message}", loc.to_string().trim_end()),
+                })
+            })
+            .collect::<PyResult<Vec<_>>>()?;
+        Ok(messages.join(", "))
+    }
+
+    fn __repr__(&self, py: Python) -> PyResult<String> {
+        Ok(format!("PydanticMultiError({})", self.errors(py)?.bind(py).repr()?))
+    }
+}
+
+impl PydanticMultiError {
+    /// One line error for each error, with its location inside the validator's location.
+    pub fn into_val_error(self, input: impl ToErrorValue) -> ValError {
+        let input: &dyn ToErrorValue = &input;
+        ValError::LineErrors(
+            self.errors
+                .into_iter()
+                .map(|(loc, error_type)| ValLineError::new_with_full_loc(error_type, input, loc))
+                .collect(),
+        )
+    }
+}
@@ -3,7 +3,7 @@ use pyo3::prelude::*;
 
 use pyo3::exceptions::PyValueError;
 use pyo3::pyclass::CompareOp;
-use pyo3::types::{PyDate, PyDateTime, PyDelta, PyDeltaAccess, PyDict, PyTime, PyTzInfo};
+use pyo3::types::{PyDate, PyDateTime, PyDelta, PyDeltaAccess, PyDict, PyString, PyTime, PyTzInfo};
 use speedate::MicrosecondsPrecisionOverflowBehavior;
 use speedate::{Date, DateTime, Duration, ParseError, Time, TimeConfig};
 use std::borrow::Cow;
@@ -14,9 +14,10 @@ use std::hash::Hasher;
 use strum::EnumMessage;
 
 use super::Input;
+use crate::build_tools::py_schema_err;
 use crate::errors::ToErrorValue;
 use crate::errors::{ErrorType, ValError, ValResult};
-use crate::tools::py_err;
+use crate::tools::{py_err, SchemaDict};
 
 #[cfg_attr(debug_assertions, derive(Debug))]
 pub enum EitherDate<'a> {
@@ -299,27 +300,145 @@ pub fn bytes_as_date<'py>(input: &(impl Input<'py> + ?Sized), bytes: &[u8]) -> V
     }
 }
 
+/// Which string formats are accepted for times, set from the `time_format` key of time schemas.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum TimeFormat {
+    /// ISO 8601 times like `14:30:00`, the default
+    #[default]
+    Iso,
+    /// 12-hour times like `2:30 PM`
+    TwelveHour,
+    /// either of the above
+    Any,
+}
+
+impl TimeFormat {
+    pub fn from_schema(schema: &Bound<'_, PyDict>) -> PyResult<Self> {
+        let py = schema.py();
+        match schema.get_as::<Bound<'_, PyString>>(intern!(py, "time_format"))? {
+            Some(s) => match s.to_str()? {
+                "iso" => Ok(Self::Iso),
+                "12h" => Ok(Self::TwelveHour),
+                "any" => Ok(Self::Any),
+                s => py_schema_err!("Invalid time_format {:?}, should be 'iso', '12h' or 'any'", s),
+            },
+            None => Ok(Self::Iso),
+        }
+    }
+}
+
 pub fn bytes_as_time<'py>(
     input: &(impl Input<'py> + ?Sized),
     bytes: &[u8],
     microseconds_overflow_behavior: MicrosecondsPrecisionOverflowBehavior,
+    time_format: TimeFormat,
 ) -> ValResult<EitherTime<'py>> {
-    match Time::parse_bytes_with_config(
-        bytes,
-        &TimeConfig {
-            microseconds_precision_overflow_behavior: microseconds_overflow_behavior,
-            unix_timestamp_offset: Some(0),
-        },
-    ) {
-        Ok(date) => Ok(date.into()),
-        Err(err) => Err(ValError::new(
+    let parse_error = |error: &'static str| {
+        ValError::new(
             ErrorType::TimeParsing {
-                error: Cow::Borrowed(err.get_documentation().unwrap_or_default()),
+                error: Cow::Borrowed(error),
                 context: None,
             },
             input,
-        )),
+        )
+    };
+    if time_format != TimeFormat::TwelveHour {
+        let config = TimeConfig {
+            microseconds_precision_overflow_behavior: microseconds_overflow_behavior,
+            unix_timestamp_offset: Some(0),
+        };
+        match Time::parse_bytes_with_config(bytes, &config) {
+            Ok(time) => return Ok(time.into()),
+            // with `any`, only fall back to 12-hour parsing for inputs which look like 12-hour times
+            Err(err) if time_format == TimeFormat::Iso || !ends_with_am_pm(bytes) => {
+                return Err(parse_error(err.get_documentation().unwrap_or_default()))
+            }
+            Err(_) => (),
+        }
+    }
+    match parse_12_hour_time(bytes, microseconds_overflow_behavior) {
+        Some(time) => Ok(time.into()),
+        None => Err(parse_error("invalid 12-hour time, expected a time like `2:30 PM`")),
+    }
+}
+
+fn trim_ascii_whitespace(bytes: &[u8]) -> &[u8] {
+    let start = bytes
+        .iter()
+        .position(|b| !b.is_ascii_whitespace())
+        .unwrap_or(bytes.len());
+    let end = bytes
+        .iter()
+        .rposition(|b| !b.is_ascii_whitespace())
+        .map_or(start, |end| end + 1);
+    &bytes[start..end]
+}
+
+fn ends_with_am_pm(bytes: &[u8]) -> bool {
+    let bytes = trim_ascii_whitespace(bytes);
+    bytes.len() >= 2 && matches!(&bytes[bytes.len() - 2..], [b'a' | b'A' | b'p' | b'P', b'm' | b'M'])
+}
+
+fn parse_digits(digits: &[u8], min_len: usize, max_len: usize) -> Option<u32> {
+    if digits.len() < min_len || digits.len() > max_len || !digits.iter().all(u8::is_ascii_digit) {
+        return None;
     }
+    Some(digits.iter().fold(0, |acc, d| acc * 10 + u32::from(d - b'0')))
+}
+
+/// Parse a 12-hour time like `2:30 PM` or `11:05:30.5am`, the hour must be 1 to 12 and minutes and seconds
+/// are optional.
+fn parse_12_hour_time(
+    bytes: &[u8],
+    microseconds_overflow_behavior: MicrosecondsPrecisionOverflowBehavior,
+) -> Option<Time> {
+    let bytes = trim_ascii_whitespace(bytes);
+    if !ends_with_am_pm(bytes) {
+        return None;
+    }
+    let (body, suffix) = bytes.split_at(bytes.len() - 2);
+    let pm = suffix[0].eq_ignore_ascii_case(&b'p');
+    let mut parts = trim_ascii_whitespace(body).splitn(3, |b| *b == b':');
+
+    let hour = parse_digits(parts.next()?, 1, 2)?;
+    if !(1..=12).contains(&hour) {
+        return None;
+    }
+    let minute = parts.next().map_or(Some(0), |m| parse_digits(m, 2, 2))?;
+    let (second, microsecond) = match parts.next() {
+        Some(seconds) => {
+            let (second, fraction) = match seconds.iter().position(|b| *b == b'.') {
+                Some(dot) => (&seconds[..dot], Some(&seconds[dot + 1..])),
+                None => (seconds, None),
+            };
+            let microsecond = match fraction {
+                Some(fraction) if fraction.len() > 6 => match microseconds_overflow_behavior {
+                    MicrosecondsPrecisionOverflowBehavior::Truncate => parse_digits(&fraction[..6], 6, 6)?,
+                    MicrosecondsPrecisionOverflowBehavior::Error => return None,
+                },
+                Some(fraction) => parse_digits(fraction, 1, 6)? * 10u32.pow(6 - fraction.len() as u32),
+                None => 0,
+            };
+            (parse_digits(second, 2, 2)?, microsecond)
+        }
+        None => (0, 0),
+    };
+    if minute > 59 || second > 59 {
+        return None;
+    }
+    let hour = match (hour, pm) {
+        (12, false) => 0,
+        (12, true) => 12,
+        (hour, false) => hour,
+        (hour, true) => hour + 12,
+    };
+    Some(Time {
+        hour: hour as u8,
+        minute: minute as u8,
+        second: second as u8,
+        microsecond,
+        tz_offset: None,
+    })
 }
 
 pub fn bytes_as_datetime<'py>(
@@ -5,11 +5,12 @@ use pyo3::types::{PyDict, PyList};
 use pyo3::{intern, prelude::*};
 
 use crate::errors::{ErrorTypeDefaults, InputValue, LocItem, ValError, ValResult};
-use crate::lookup_key::{LookupKey, LookupPath};
+use crate::lookup_key::{AttributeAccess, LookupKey, LookupPath};
 use crate::tools::py_err;
 
-use super::datetime::{EitherDate, EitherDateTime, EitherTime, EitherTimedelta};
+use super::datetime::{EitherDate, EitherDateTime, EitherTime, EitherTimedelta, TimeFormat};
 use super::return_enums::{EitherBytes, EitherInt, EitherString};
+use super::shared::StringParseOptions;
 use super::{EitherFloat, GenericIterator, ValidationMatch};
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
@@ -73,16 +74,28 @@ pub trait Input<'py>: fmt::Debug + ToPyObject {
 
     fn validate_bytes<'a>(&'a self, strict: bool) -> ValMatch<EitherBytes<'a, 'py>>;
 
-    fn validate_bool(&self, strict: bool) -> ValMatch<bool>;
+    fn validate_bool(&self, strict: bool, parse_options: StringParseOptions) -> ValMatch<bool>;
 
-    fn validate_int(&self, strict: bool) -> ValMatch<EitherInt<'_>>;
+    fn validate_int(&self, strict: bool, parse_options: StringParseOptions) -> ValMatch<EitherInt<'_>>;
 
     fn exact_int(&self) -> ValResult<EitherInt<'_>> {
-        self.validate_int(true).and_then(|val_match| {
-            val_match
-                .require_exact()
-                .ok_or_else(|| ValError::new(ErrorTypeDefaults::IntType, self))
-        })
+        self.validate_int(true, StringParseOptions::default())
+            .and_then(|val_match| {
+                val_match
+                    .require_exact()
+                    .ok_or_else(|| ValError::new(ErrorTypeDefaults::IntType, self))
+            })
+    }
+
+    /// Extract a bool from the input, only allowing actual bools rather than values which can be parsed as one,
+    /// e.g. the strings of `validate_strings`
+    fn exact_bool(&self) -> ValResult<bool> {
+        self.validate_bool(true, StringParseOptions::default())
+            .and_then(|val_match| {
+                val_match
+                    .require_exact()
+                    .ok_or_else(|| ValError::new(ErrorTypeDefaults::BoolType, self))
+            })
     }
 
     /// Extract a String from the input, only allowing exact
@@ -95,7 +108,7 @@ pub trait Input<'py>: fmt::Debug + ToPyObject {
         })
     }
 
-    fn validate_float(&self, strict: bool) -> ValMatch<EitherFloat<'_>>;
+    fn validate_float(&self, strict: bool, parse_options: StringParseOptions) -> ValMatch<EitherFloat<'_>>;
 
     fn validate_decimal(&self, strict: bool, py: Python<'py>) -> ValResult<Bound<'py, PyAny>> {
         if strict {
@@ -127,7 +140,11 @@ pub trait Input<'py>: fmt::Debug + ToPyObject {
         self.strict_dict()
     }
 
-    fn validate_model_fields(&self, strict: bool, _from_attributes: bool) -> ValResult<Self::Dict<'_>> {
+    fn validate_model_fields(
+        &self,
+        strict: bool,
+        _from_attributes: Option<AttributeAccess>,
+    ) -> ValResult<Self::Dict<'_>> {
         self.validate_dict(strict)
     }
 
@@ -159,6 +176,7 @@ pub trait Input<'py>: fmt::Debug + ToPyObject {
         &self,
         strict: bool,
         microseconds_overflow_behavior: speedate::MicrosecondsPrecisionOverflowBehavior,
+        time_format: TimeFormat,
     ) -> ValMatch<EitherTime<'py>>;
 
     fn validate_datetime(
@@ -13,11 +13,11 @@ use crate::validators::decimal::create_decimal;
 
 use super::datetime::{
     bytes_as_date, bytes_as_datetime, bytes_as_time, bytes_as_timedelta, float_as_datetime, float_as_duration,
-    float_as_time, int_as_datetime, int_as_duration, int_as_time, EitherDate, EitherDateTime, EitherTime,
+    float_as_time, int_as_datetime, int_as_duration, int_as_time, EitherDate, EitherDateTime, EitherTime, TimeFormat,
 };
 use super::input_abstract::{ConsumeIterator, Never, ValMatch};
 use super::return_enums::ValidationMatch;
-use super::shared::{float_as_int, int_as_bool, str_as_bool, str_as_float, str_as_int};
+use super::shared::{float_as_int, int_as_bool, str_as_bool, str_as_float, str_as_int, StringParseOptions};
 use super::{
     Arguments, BorrowInput, EitherBytes, EitherFloat, EitherInt, EitherString, EitherTimedelta, GenericIterator, Input,
     KeywordArgs, PositionalArgs, ValidatedDict, ValidatedList, ValidatedSet, ValidatedTuple,
@@ -113,10 +113,12 @@ impl<'py, 'data> Input<'py> for JsonValue<'data> {
         }
     }
 
-    fn validate_bool(&self, strict: bool) -> ValResult<ValidationMatch<bool>> {
+    fn validate_bool(&self, strict: bool, parse_options: StringParseOptions) -> ValResult<ValidationMatch<bool>> {
         match self {
             JsonValue::Bool(b) => Ok(ValidationMatch::exact(*b)),
-            JsonValue::Str(s) if !strict => str_as_bool(self, s).map(ValidationMatch::lax),
+            JsonValue::Str(s) if !strict && parse_options.json_coerce_strings => {
+                str_as_bool(self, s, parse_options).map(ValidationMatch::lax)
+            }
             JsonValue::Int(int) if !strict => int_as_bool(self, *int).map(ValidationMatch::lax),
             JsonValue::Float(float) if !strict => match float_as_int(self, *float) {
                 Ok(int) => int
@@ -129,13 +131,19 @@ impl<'py, 'data> Input<'py> for JsonValue<'data> {
         }
     }
 
-    fn validate_int(&self, strict: bool) -> ValResult<ValidationMatch<EitherInt<'_>>> {
+    fn validate_int(
+        &self,
+        strict: bool,
+        parse_options: StringParseOptions,
+    ) -> ValResult<ValidationMatch<EitherInt<'_>>> {
         match self {
             JsonValue::Int(i) => Ok(ValidationMatch::exact(EitherInt::I64(*i))),
             JsonValue::BigInt(b) => Ok(ValidationMatch::exact(EitherInt::BigInt(b.clone()))),
             JsonValue::Bool(b) if !strict => Ok(ValidationMatch::lax(EitherInt::I64((*b).into()))),
             JsonValue::Float(f) if !strict => float_as_int(self, *f).map(ValidationMatch::lax),
-            JsonValue::Str(str) if !strict => str_as_int(self, str).map(ValidationMatch::lax),
+            JsonValue::Str(str) if !strict && parse_options.json_coerce_strings => {
+                str_as_int(self, str, parse_options).map(ValidationMatch::lax)
+            }
             _ => Err(ValError::new(ErrorTypeDefaults::IntType, self)),
         }
     }
@@ -147,12 +155,18 @@ impl<'py, 'data> Input<'py> for JsonValue<'data> {
         }
     }
 
-    fn validate_float(&self, strict: bool) -> ValResult<ValidationMatch<EitherFloat<'_>>> {
+    fn validate_float(
+        &self,
+        strict: bool,
+        parse_options: StringParseOptions,
+    ) -> ValResult<ValidationMatch<EitherFloat<'_>>> {
         match self {
             JsonValue::Float(f) => Ok(ValidationMatch::exact(EitherFloat::F64(*f))),
             JsonValue::Int(i) => Ok(ValidationMatch::strict(EitherFloat::F64(*i as f64))),
             JsonValue::Bool(b) if !strict => Ok(ValidationMatch::lax(EitherFloat::F64(if *b { 1.0 } else { 0.0 }))),
-            JsonValue::Str(str) if !strict => str_as_float(self, str).map(ValidationMatch::lax),
+            JsonValue::Str(str) if !strict && parse_options.json_coerce_strings => {
+                str_as_float(self, str, parse_options).map(ValidationMatch::lax)
+            }
             _ => Err(ValError::new(ErrorTypeDefaults::FloatType, self)),
         }
     }
@@ -241,11 +255,16 @@ impl<'py, 'data> Input<'py> for JsonValue<'data> {
         &self,
         strict: bool,
         microseconds_overflow_behavior: MicrosecondsPrecisionOverflowBehavior,
+        time_format: TimeFormat,
     ) -> ValResult<ValidationMatch<EitherTime<'py>>> {
         match self {
-            JsonValue::Str(v) => {
-                bytes_as_time(self, v.as_bytes(), microseconds_overflow_behavior).map(ValidationMatch::strict)
-            }
+            JsonValue::Str(v) => bytes_as_time(
+                self,
+                v.as_bytes(),
+                microseconds_overflow_behavior,
+                time_format,
+            )
+            .map(ValidationMatch::strict),
             JsonValue::Int(v) if !strict => int_as_time(self, *v, 0).map(ValidationMatch::lax),
             JsonValue::Float(v) if !strict => float_as_time(self, *v).map(ValidationMatch::lax),
             JsonValue::BigInt(_) if !strict => Err(ValError::new(
@@ -346,16 +365,24 @@ impl<'py> Input<'py> for str {
         Ok(ValidationMatch::strict(self.as_bytes().into()))
     }
 
-    fn validate_bool(&self, _strict: bool) -> ValResult<ValidationMatch<bool>> {
-        str_as_bool(self, self).map(ValidationMatch::lax)
+    fn validate_bool(&self, _strict: bool, parse_options: StringParseOptions) -> ValResult<ValidationMatch<bool>> {
+        str_as_bool(self, self, parse_options).map(ValidationMatch::lax)
     }
 
-    fn validate_int(&self, _strict: bool) -> ValResult<ValidationMatch<EitherInt<'_>>> {
-        str_as_int(self, self).map(ValidationMatch::lax)
+    fn validate_int(
+        &self,
+        _strict: bool,
+        parse_options: StringParseOptions,
+    ) -> ValResult<ValidationMatch<EitherInt<'_>>> {
+        str_as_int(self, self, parse_options).map(ValidationMatch::lax)
     }
 
-    fn validate_float(&self, _strict: bool) -> ValResult<ValidationMatch<EitherFloat<'_>>> {
-        str_as_float(self, self).map(ValidationMatch::lax)
+    fn validate_float(
+        &self,
+        _strict: bool,
+        parse_options: StringParseOptions,
+    ) -> ValResult<ValidationMatch<EitherFloat<'_>>> {
+        str_as_float(self, self, parse_options).map(ValidationMatch::lax)
     }
 
     fn strict_decimal(&self, py: Python<'py>) -> ValResult<Bound<'py, PyAny>> {
@@ -406,8 +433,15 @@ impl<'py> Input<'py> for str {
         &self,
         _strict: bool,
         microseconds_overflow_behavior: MicrosecondsPrecisionOverflowBehavior,
+        time_format: TimeFormat,
     ) -> ValResult<ValidationMatch<EitherTime<'py>>> {
-        bytes_as_time(self, self.as_bytes(), microseconds_overflow_behavior).map(ValidationMatch::lax)
+        bytes_as_time(
+            self,
+            self.as_bytes(),
+            microseconds_overflow_behavior,
+            time_format,
+        )
+        .map(ValidationMatch::lax)
     }
 
     fn validate_datetime(
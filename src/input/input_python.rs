@@ -3,6 +3,7 @@ use std::str::from_utf8;
 
 use pyo3::intern;
 use pyo3::prelude::*;
+use pyo3::sync::GILOnceCell;
 
 use pyo3::types::PyType;
 use pyo3::types::{
@@ -14,6 +15,7 @@ use pyo3::PyTypeCheck;
 use speedate::MicrosecondsPrecisionOverflowBehavior;
 
 use crate::errors::{ErrorType, ErrorTypeDefaults, InputValue, LocItem, ValError, ValResult};
+use crate::lookup_key::{py_get_type_attr, type_mro, AttributeAccess};
 use crate::tools::{extract_i64, safe_repr};
 use crate::validators::decimal::{create_decimal, get_decimal_type};
 use crate::validators::Exactness;
@@ -22,12 +24,13 @@ use crate::ArgsKwargs;
 use super::datetime::{
     bytes_as_date, bytes_as_datetime, bytes_as_time, bytes_as_timedelta, date_as_datetime, float_as_datetime,
     float_as_duration, float_as_time, int_as_datetime, int_as_duration, int_as_time, EitherDate, EitherDateTime,
-    EitherTime,
+    EitherTime, TimeFormat,
 };
 use super::input_abstract::ValMatch;
-use super::return_enums::{iterate_attributes, iterate_mapping_items, ValidationMatch};
+use super::return_enums::{iterate_attributes, iterate_dataclass_fields, iterate_mapping_items, ValidationMatch};
 use super::shared::{
     decimal_as_int, float_as_int, get_enum_meta_object, int_as_bool, str_as_bool, str_as_float, str_as_int,
+    StringParseOptions,
 };
 use super::Arguments;
 use super::ConsumeIterator;
@@ -162,7 +165,7 @@ impl<'py> Input<'py> for Bound<'py, PyAny> {
                         || self.is_instance(decimal_type).unwrap_or_default()
                 } {
                     Ok(self.str()?.into())
-                } else if let Some(enum_val) = maybe_as_enum(self) {
+                } else if let Some(enum_val) = maybe_as_enum(self).filter(PyAnyMethods::is_instance_of::<PyString>) {
                     Ok(enum_val.str()?.into())
                 } else {
                     break 'lax;
@@ -198,14 +201,14 @@ impl<'py> Input<'py> for Bound<'py, PyAny> {
         Err(ValError::new(ErrorTypeDefaults::BytesType, self))
     }
 
-    fn validate_bool(&self, strict: bool) -> ValResult<ValidationMatch<bool>> {
+    fn validate_bool(&self, strict: bool, parse_options: StringParseOptions) -> ValResult<ValidationMatch<bool>> {
         if let Ok(bool) = self.downcast::<PyBool>() {
             return Ok(ValidationMatch::exact(bool.is_true()));
         }
 
         if !strict {
             if let Some(cow_str) = maybe_as_string(self, ErrorTypeDefaults::BoolParsing)? {
-                return str_as_bool(self, &cow_str).map(ValidationMatch::lax);
+                return str_as_bool(self, &cow_str, parse_options).map(ValidationMatch::lax);
             } else if let Some(int) = extract_i64(self) {
                 return int_as_bool(self, int).map(ValidationMatch::lax);
             } else if let Ok(float) = self.extract::<f64>() {
@@ -221,7 +224,11 @@ impl<'py> Input<'py> for Bound<'py, PyAny> {
         Err(ValError::new(ErrorTypeDefaults::BoolType, self))
     }
 
-    fn validate_int(&self, strict: bool) -> ValResult<ValidationMatch<EitherInt<'_>>> {
+    fn validate_int(
+        &self,
+        strict: bool,
+        parse_options: StringParseOptions,
+    ) -> ValResult<ValidationMatch<EitherInt<'_>>> {
         if self.is_exact_instance_of::<PyInt>() {
             return Ok(ValidationMatch::exact(EitherInt::Py(self.clone())));
         } else if self.is_instance_of::<PyInt>() {
@@ -242,15 +249,17 @@ impl<'py> Input<'py> for Bound<'py, PyAny> {
         'lax: {
             if !strict {
                 return if let Some(cow_str) = maybe_as_string(self, ErrorTypeDefaults::IntParsing)? {
-                    str_as_int(self, &cow_str)
+                    str_as_int(self, &cow_str, parse_options)
                 } else if self.is_exact_instance_of::<PyFloat>() {
                     float_as_int(self, self.extract::<f64>()?)
                 } else if let Ok(decimal) = self.strict_decimal(self.py()) {
                     decimal_as_int(self, &decimal)
+                } else if let Some(index) = maybe_as_index(self) {
+                    Ok(EitherInt::Py(index))
                 } else if let Ok(float) = self.extract::<f64>() {
                     float_as_int(self, float)
-                } else if let Some(enum_val) = maybe_as_enum(self) {
-                    Ok(EitherInt::Py(enum_val))
+                } else if let Some(enum_val) = maybe_as_enum(self).filter(PyAnyMethods::is_instance_of::<PyInt>) {
+                    EitherInt::upcast(&enum_val)
                 } else {
                     break 'lax;
                 }
@@ -277,7 +286,11 @@ impl<'py> Input<'py> for Bound<'py, PyAny> {
         }
     }
 
-    fn validate_float(&self, strict: bool) -> ValResult<ValidationMatch<EitherFloat<'_>>> {
+    fn validate_float(
+        &self,
+        strict: bool,
+        parse_options: StringParseOptions,
+    ) -> ValResult<ValidationMatch<EitherFloat<'_>>> {
         if let Ok(float) = self.downcast_exact::<PyFloat>() {
             return Ok(ValidationMatch::exact(EitherFloat::Py(float.clone())));
         }
@@ -285,7 +298,7 @@ impl<'py> Input<'py> for Bound<'py, PyAny> {
         if !strict {
             if let Some(cow_str) = maybe_as_string(self, ErrorTypeDefaults::FloatParsing)? {
                 // checking for bytes and string is fast, so do this before isinstance(float)
-                return str_as_float(self, &cow_str).map(ValidationMatch::lax);
+                return str_as_float(self, &cow_str, parse_options).map(ValidationMatch::lax);
             }
         }
 
@@ -368,23 +381,33 @@ impl<'py> Input<'py> for Bound<'py, PyAny> {
     fn validate_model_fields<'a>(
         &'a self,
         strict: bool,
-        from_attributes: bool,
+        from_attributes: Option<AttributeAccess>,
     ) -> ValResult<GenericPyMapping<'a, 'py>> {
-        if from_attributes {
+        if let Some(access) = from_attributes {
             // if from_attributes, first try a dict, then mapping then from_attributes
             if let Ok(dict) = self.downcast::<PyDict>() {
                 return Ok(GenericPyMapping::Dict(dict));
             } else if !strict {
-                if let Ok(mapping) = self.downcast::<PyMapping>() {
+                if access == AttributeAccess::Safe {
+                    // `isinstance` checks against `Mapping` use `__class__` which could be spoofed, so check the
+                    // real type instead
+                    if is_mapping_type(&self.get_type())? {
+                        // SAFETY: `PyMapping` methods work on any object, they fail if it isn't a mapping
+                        let mapping = unsafe { self.downcast_unchecked::<PyMapping>() };
+                        return Ok(GenericPyMapping::Mapping(mapping));
+                    }
+                } else if let Ok(mapping) = self.downcast::<PyMapping>() {
                     return Ok(GenericPyMapping::Mapping(mapping));
                 }
             }
 
-            if from_attributes_applicable(self) {
-                Ok(GenericPyMapping::GetAttr(self.to_owned(), None))
+            if is_dataclass_instance(self, access) {
+                Ok(GenericPyMapping::Dataclass(self.to_owned(), access))
+            } else if from_attributes_applicable(self, access) {
+                Ok(GenericPyMapping::GetAttr(self.to_owned(), None, access))
             } else if let Ok((obj, kwargs)) = self.extract() {
-                if from_attributes_applicable(&obj) {
-                    Ok(GenericPyMapping::GetAttr(obj, Some(kwargs)))
+                if from_attributes_applicable(&obj, access) {
+                    Ok(GenericPyMapping::GetAttr(obj, Some(kwargs), access))
                 } else {
                     Err(ValError::new(ErrorTypeDefaults::ModelAttributesType, self))
                 }
@@ -492,6 +515,7 @@ impl<'py> Input<'py> for Bound<'py, PyAny> {
         &self,
         strict: bool,
         microseconds_overflow_behavior: MicrosecondsPrecisionOverflowBehavior,
+        time_format: TimeFormat,
     ) -> ValResult<ValidationMatch<EitherTime<'py>>> {
         if let Ok(time) = self.downcast_exact::<PyTime>() {
             return Ok(ValidationMatch::exact(time.clone().into()));
@@ -503,9 +527,9 @@ impl<'py> Input<'py> for Bound<'py, PyAny> {
             if !strict {
                 return if let Ok(py_str) = self.downcast::<PyString>() {
                     let str = py_string_str(py_str)?;
-                    bytes_as_time(self, str.as_bytes(), microseconds_overflow_behavior)
+                    bytes_as_time(self, str.as_bytes(), microseconds_overflow_behavior, time_format)
                 } else if let Ok(py_bytes) = self.downcast::<PyBytes>() {
-                    bytes_as_time(self, py_bytes.as_bytes(), microseconds_overflow_behavior)
+                    bytes_as_time(self, py_bytes.as_bytes(), microseconds_overflow_behavior, time_format)
                 } else if self.is_exact_instance_of::<PyBool>() {
                     Err(ValError::new(ErrorTypeDefaults::TimeType, self))
                 } else if let Some(int) = extract_i64(self) {
@@ -608,13 +632,53 @@ impl<'py> BorrowInput<'py> for Borrowed<'_, 'py, PyAny> {
     }
 }
 
+static MAPPING_ABC: GILOnceCell<Py<PyType>> = GILOnceCell::new();
+
+fn mapping_abc(py: Python<'_>) -> PyResult<&Bound<'_, PyType>> {
+    MAPPING_ABC
+        .get_or_try_init(py, || {
+            py.import_bound(intern!(py, "collections.abc"))?
+                .getattr(intern!(py, "Mapping"))?
+                .extract()
+        })
+        .map(|t| t.bind(py))
+}
+
+/// Whether `cls` is a `Mapping`, without the ABC machinery which would call `__subclasshook__` and look up
+/// `__mro__` on `cls`: either `Mapping` is in its real MRO or it's flagged as a mapping by `Mapping.register`
+fn is_mapping_type(cls: &Bound<'_, PyType>) -> PyResult<bool> {
+    if has_mapping_flag(cls) {
+        return Ok(true);
+    }
+    let mapping = mapping_abc(cls.py())?;
+    Ok(type_mro(cls)?.iter().any(|base| base.is(mapping)))
+}
+
+#[cfg(all(Py_3_10, not(Py_LIMITED_API), not(PyPy)))]
+fn has_mapping_flag(cls: &Bound<'_, PyType>) -> bool {
+    // SAFETY: `cls` is a valid type object
+    unsafe { pyo3::ffi::PyType_GetFlags(cls.as_type_ptr()) & pyo3::ffi::Py_TPFLAGS_MAPPING != 0 }
+}
+
+#[cfg(not(all(Py_3_10, not(Py_LIMITED_API), not(PyPy))))]
+fn has_mapping_flag(_cls: &Bound<'_, PyType>) -> bool {
+    false
+}
+
+/// Whether obj is an instance of a dataclass, checked on its type so a dataclass type itself isn't matched
+fn is_dataclass_instance(obj: &Bound<'_, PyAny>, access: AttributeAccess) -> bool {
+    matches!(
+        py_get_type_attr(&obj.get_type(), intern!(obj.py(), "__dataclass_fields__"), access),
+        Ok(Some(_))
+    )
+}
+
 /// Best effort check of whether it's likely to make sense to inspect obj for attributes and iterate over it
 /// with `obj.dir()`
-fn from_attributes_applicable(obj: &Bound<'_, PyAny>) -> bool {
-    let Some(module_name) = obj
-        .get_type()
-        .getattr(intern!(obj.py(), "__module__"))
+fn from_attributes_applicable(obj: &Bound<'_, PyAny>, access: AttributeAccess) -> bool {
+    let Some(module_name) = py_get_type_attr(&obj.get_type(), intern!(obj.py(), "__module__"), access)
         .ok()
+        .flatten()
         .and_then(|module_name| module_name.downcast_into::<PyString>().ok())
     else {
         return false;
@@ -641,7 +705,19 @@ fn maybe_as_string<'a>(v: &'a Bound<'_, PyAny>, unicode_error: ErrorType) -> Val
     }
 }
 
-/// Utility for extracting an enum value, if possible.
+/// Integers from other libraries, e.g. `numpy.int64`, aren't `int` subclasses but implement `__index__`,
+/// using it rather than `__float__` avoids losing precision on large values.
+fn maybe_as_index<'py>(v: &Bound<'py, PyAny>) -> Option<Bound<'py, PyAny>> {
+    let py = v.py();
+    if v.get_type().hasattr(intern!(py, "__index__")).unwrap_or(false) {
+        let index = v.call_method0(intern!(py, "__index__")).ok()?;
+        index.downcast_into::<PyInt>().ok().map(Bound::into_any)
+    } else {
+        None
+    }
+}
+
+/// Utility for extracting an enum value, if possible, callers only use values of their own type.
 fn maybe_as_enum<'py>(v: &Bound<'py, PyAny>) -> Option<Bound<'py, PyAny>> {
     let py = v.py();
     let enum_meta_object = get_enum_meta_object(py);
@@ -785,7 +861,9 @@ impl<'py> KeywordArgs<'py> for PyKwargs<'py> {
 pub enum GenericPyMapping<'a, 'py> {
     Dict(&'a Bound<'py, PyDict>),
     Mapping(&'a Bound<'py, PyMapping>),
-    GetAttr(Bound<'py, PyAny>, Option<Bound<'py, PyDict>>),
+    GetAttr(Bound<'py, PyAny>, Option<Bound<'py, PyDict>>, AttributeAccess),
+    /// a dataclass instance read via attributes like `GetAttr`, but iterated in the order of its declared fields
+    Dataclass(Bound<'py, PyAny>, AttributeAccess),
 }
 
 impl<'py> ValidatedDict<'py> for GenericPyMapping<'_, 'py> {
@@ -804,12 +882,13 @@ impl<'py> ValidatedDict<'py> for GenericPyMapping<'_, 'py> {
         match self {
             Self::Dict(dict) => key.py_get_dict_item(dict),
             Self::Mapping(mapping) => key.py_get_mapping_item(mapping),
-            Self::GetAttr(obj, dict) => key.py_get_attr(obj, dict.as_ref()),
+            Self::GetAttr(obj, dict, access) => key.py_get_attr(obj, dict.as_ref(), *access),
+            Self::Dataclass(obj, access) => key.py_get_attr(obj, None, *access),
         }
     }
 
     fn is_py_get_attr(&self) -> bool {
-        matches!(self, Self::GetAttr(..))
+        matches!(self, Self::GetAttr(..) | Self::Dataclass(..))
     }
 
     fn as_py_dict(&self) -> Option<&Bound<'py, PyDict>> {
@@ -826,7 +905,8 @@ impl<'py> ValidatedDict<'py> for GenericPyMapping<'_, 'py> {
         match self {
             Self::Dict(dict) => Ok(consumer.consume_iterator(dict.iter().map(Ok))),
             Self::Mapping(mapping) => Ok(consumer.consume_iterator(iterate_mapping_items(mapping)?)),
-            Self::GetAttr(obj, _) => Ok(consumer.consume_iterator(iterate_attributes(obj))),
+            Self::GetAttr(obj, ..) => Ok(consumer.consume_iterator(iterate_attributes(obj))),
+            Self::Dataclass(obj, access) => Ok(consumer.consume_iterator(iterate_dataclass_fields(obj, *access)?)),
         }
     }
 }
@@ -11,9 +11,10 @@ use crate::validators::decimal::create_decimal;
 
 use super::datetime::{
     bytes_as_date, bytes_as_datetime, bytes_as_time, bytes_as_timedelta, EitherDate, EitherDateTime, EitherTime,
+    TimeFormat,
 };
 use super::input_abstract::{Never, ValMatch};
-use super::shared::{str_as_bool, str_as_float, str_as_int};
+use super::shared::{str_as_bool, str_as_float, str_as_int, StringParseOptions};
 use super::{
     Arguments, BorrowInput, EitherBytes, EitherFloat, EitherInt, EitherString, EitherTimedelta, GenericIterator, Input,
     KeywordArgs, ValidatedDict, ValidationMatch,
@@ -112,23 +113,31 @@ impl<'py> Input<'py> for StringMapping<'py> {
         }
     }
 
-    fn validate_bool(&self, _strict: bool) -> ValResult<ValidationMatch<bool>> {
+    fn validate_bool(&self, _strict: bool, parse_options: StringParseOptions) -> ValResult<ValidationMatch<bool>> {
         match self {
-            Self::String(s) => str_as_bool(self, py_string_str(s)?).map(ValidationMatch::strict),
+            Self::String(s) => str_as_bool(self, py_string_str(s)?, parse_options).map(ValidationMatch::strict),
             Self::Mapping(_) => Err(ValError::new(ErrorTypeDefaults::BoolType, self)),
         }
     }
 
-    fn validate_int(&self, _strict: bool) -> ValResult<ValidationMatch<EitherInt<'_>>> {
+    fn validate_int(
+        &self,
+        _strict: bool,
+        parse_options: StringParseOptions,
+    ) -> ValResult<ValidationMatch<EitherInt<'_>>> {
         match self {
-            Self::String(s) => str_as_int(self, py_string_str(s)?).map(ValidationMatch::strict),
+            Self::String(s) => str_as_int(self, py_string_str(s)?, parse_options).map(ValidationMatch::strict),
             Self::Mapping(_) => Err(ValError::new(ErrorTypeDefaults::IntType, self)),
         }
     }
 
-    fn validate_float(&self, _strict: bool) -> ValResult<ValidationMatch<EitherFloat<'_>>> {
+    fn validate_float(
+        &self,
+        _strict: bool,
+        parse_options: StringParseOptions,
+    ) -> ValResult<ValidationMatch<EitherFloat<'_>>> {
         match self {
-            Self::String(s) => str_as_float(self, py_string_str(s)?).map(ValidationMatch::strict),
+            Self::String(s) => str_as_float(self, py_string_str(s)?, parse_options).map(ValidationMatch::strict),
             Self::Mapping(_) => Err(ValError::new(ErrorTypeDefaults::FloatType, self)),
         }
     }
@@ -186,10 +195,16 @@ impl<'py> Input<'py> for StringMapping<'py> {
         &self,
         _strict: bool,
         microseconds_overflow_behavior: MicrosecondsPrecisionOverflowBehavior,
+        time_format: TimeFormat,
     ) -> ValResult<ValidationMatch<EitherTime<'py>>> {
         match self {
-            Self::String(s) => bytes_as_time(self, py_string_str(s)?.as_bytes(), microseconds_overflow_behavior)
-                .map(ValidationMatch::strict),
+            Self::String(s) => bytes_as_time(
+                self,
+                py_string_str(s)?.as_bytes(),
+                microseconds_overflow_behavior,
+                time_format,
+            )
+            .map(ValidationMatch::strict),
             Self::Mapping(_) => Err(ValError::new(ErrorTypeDefaults::TimeType, self)),
         }
     }
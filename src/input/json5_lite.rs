@@ -0,0 +1,79 @@
+/// Blank out `//` line comments, `/* */` block comments and trailing commas in objects and arrays so the
+/// result can be parsed as strict JSON.
+///
+/// Removed bytes are replaced with spaces (newlines inside block comments are kept) so the output is the
+/// same length and on the same lines as the input, and positions in parsing errors stay accurate.
+/// Unterminated block comments are left untouched so they're reported by the parser.
+pub fn strip_json5_lite(json_bytes: &[u8]) -> Vec<u8> {
+    let mut output = json_bytes.to_vec();
+    let mut pending_comma: Option<usize> = None;
+    // commas directly after `[`, `{` or another comma aren't trailing commas and are left for the parser to reject
+    let mut after_separator = false;
+    let mut index = 0;
+    while let Some(&byte) = json_bytes.get(index) {
+        match byte {
+            b'"' => {
+                pending_comma = None;
+                after_separator = false;
+                index = string_end(json_bytes, index + 1);
+                continue;
+            }
+            b'/' if json_bytes.get(index + 1) == Some(&b'/') => {
+                let end = json_bytes[index..]
+                    .iter()
+                    .position(|b| *b == b'\n')
+                    .map_or(json_bytes.len(), |offset| index + offset);
+                output[index..end].fill(b' ');
+                index = end;
+                continue;
+            }
+            b'/' if json_bytes.get(index + 1) == Some(&b'*') => {
+                if let Some(offset) = json_bytes[index + 2..].windows(2).position(|w| w == b"*/") {
+                    let end = index + 2 + offset + 2;
+                    for b in &mut output[index..end] {
+                        if !matches!(*b, b'\n' | b'\r') {
+                            *b = b' ';
+                        }
+                    }
+                    index = end;
+                    continue;
+                }
+                pending_comma = None;
+                after_separator = false;
+            }
+            b' ' | b'\t' | b'\n' | b'\r' => (),
+            b',' => {
+                pending_comma = if after_separator { None } else { Some(index) };
+                after_separator = true;
+            }
+            b'[' | b'{' => {
+                pending_comma = None;
+                after_separator = true;
+            }
+            b'}' | b']' => {
+                if let Some(comma_index) = pending_comma.take() {
+                    output[comma_index] = b' ';
+                }
+                after_separator = false;
+            }
+            _ => {
+                pending_comma = None;
+                after_separator = false;
+            }
+        }
+        index += 1;
+    }
+    output
+}
+
+/// Index just after the closing quote of the string starting at `index`, or the end of the input.
+fn string_end(json_bytes: &[u8], mut index: usize) -> usize {
+    while let Some(&byte) = json_bytes.get(index) {
+        match byte {
+            b'\\' => index += 2,
+            b'"' => return index + 1,
+            _ => index += 1,
+        }
+    }
+    json_bytes.len()
+}
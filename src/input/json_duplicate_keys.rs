@@ -0,0 +1,137 @@
+use std::borrow::Cow;
+use std::sync::Arc;
+
+use ahash::{AHashMap, AHashSet};
+use jiter::{Jiter, JiterError, JiterErrorType, JiterResult, JsonErrorType, JsonValue, LazyIndexMap, Peek};
+use pyo3::exceptions::PyValueError;
+use pyo3::prelude::*;
+
+use super::json_str_numbers::RECURSION_LIMIT;
+
+/// How repeated keys within a JSON object are handled.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum JsonDuplicateKeys {
+    /// keep the value of the first occurrence
+    First,
+    /// keep the value of the last occurrence, at the position of the first
+    Last,
+    /// raise an error
+    Error,
+    /// collect the values of every occurrence into an array, keys which aren't repeated are unchanged
+    List,
+}
+
+impl JsonDuplicateKeys {
+    pub fn from_arg(value: &str) -> PyResult<Self> {
+        match value {
+            "first" => Ok(Self::First),
+            "last" => Ok(Self::Last),
+            "error" => Ok(Self::Error),
+            "list" => Ok(Self::List),
+            _ => Err(PyValueError::new_err(format!(
+                "Invalid `duplicate_keys` value '{value}', expected 'first', 'last', 'error' or 'list'"
+            ))),
+        }
+    }
+}
+
+/// Check that no object in the JSON data has the same key more than once, the error refers to the position of
+/// the repeated key.
+pub fn check_duplicate_keys(json_bytes: &[u8]) -> JiterResult<()> {
+    let mut jiter = Jiter::new(json_bytes).with_allow_inf_nan();
+    let peek = jiter.peek()?;
+    check_value(&mut jiter, peek, RECURSION_LIMIT)?;
+    jiter.finish()
+}
+
+fn check_value(jiter: &mut Jiter, peek: Peek, remaining_depth: u8) -> JiterResult<()> {
+    match peek {
+        Peek::Array => {
+            let remaining_depth = descend(jiter, remaining_depth)?;
+            let mut next = jiter.known_array()?;
+            while let Some(peek) = next {
+                check_value(jiter, peek, remaining_depth)?;
+                next = jiter.array_step()?;
+            }
+        }
+        Peek::Object => {
+            let remaining_depth = descend(jiter, remaining_depth)?;
+            let mut keys = AHashSet::new();
+            let mut next_key = jiter.known_object()?.map(ToOwned::to_owned);
+            while let Some(key) = next_key {
+                if let Some(key) = keys.replace(key) {
+                    // the same position jiter reports for duplicate keys, just after the key
+                    return Err(JiterError {
+                        error_type: JiterErrorType::JsonError(JsonErrorType::DuplicateKey(key)),
+                        index: jiter.current_index(),
+                    });
+                }
+                let peek = jiter.peek()?;
+                check_value(jiter, peek, remaining_depth)?;
+                next_key = jiter.next_key()?.map(ToOwned::to_owned);
+            }
+        }
+        _ => jiter.known_skip(peek)?,
+    }
+    Ok(())
+}
+
+fn descend(jiter: &Jiter, remaining_depth: u8) -> JiterResult<u8> {
+    remaining_depth.checked_sub(1).ok_or_else(|| JiterError {
+        error_type: JiterErrorType::JsonError(JsonErrorType::RecursionLimitExceeded),
+        index: jiter.current_index(),
+    })
+}
+
+/// Whether any object within `value` has the same key more than once.
+pub fn has_duplicate_keys(value: &JsonValue<'_>) -> bool {
+    match value {
+        JsonValue::Array(array) => array.iter().any(has_duplicate_keys),
+        JsonValue::Object(object) => {
+            let mut keys = AHashSet::with_capacity(object.len());
+            object
+                .iter()
+                .any(|(key, value)| !keys.insert(key) || has_duplicate_keys(value))
+        }
+        _ => false,
+    }
+}
+
+/// Rebuild `value` so no object has the same key more than once, combining repeated keys according to `mode`,
+/// which must not be `Error`.
+pub fn merge_duplicate_keys<'j>(value: &JsonValue<'j>, mode: JsonDuplicateKeys) -> JsonValue<'j> {
+    match value {
+        JsonValue::Array(array) => JsonValue::Array(Arc::new(
+            array.iter().map(|item| merge_duplicate_keys(item, mode)).collect(),
+        )),
+        JsonValue::Object(object) => {
+            let mut positions: AHashMap<&Cow<'j, str>, usize> = AHashMap::with_capacity(object.len());
+            let mut entries: Vec<(Cow<'j, str>, Vec<JsonValue<'j>>)> = Vec::with_capacity(object.len());
+            for (key, item) in object.iter() {
+                let item = merge_duplicate_keys(item, mode);
+                match positions.get(key) {
+                    Some(&position) => match mode {
+                        JsonDuplicateKeys::First | JsonDuplicateKeys::Error => (),
+                        JsonDuplicateKeys::Last => entries[position].1 = vec![item],
+                        JsonDuplicateKeys::List => entries[position].1.push(item),
+                    },
+                    None => {
+                        positions.insert(key, entries.len());
+                        entries.push((key.clone(), vec![item]));
+                    }
+                }
+            }
+            let mut merged = LazyIndexMap::new();
+            for (key, mut items) in entries {
+                let item = if items.len() == 1 {
+                    items.remove(0)
+                } else {
+                    JsonValue::Array(Arc::new(items.into()))
+                };
+                merged.insert(key, item);
+            }
+            JsonValue::Object(Arc::new(merged))
+        }
+        _ => value.clone(),
+    }
+}
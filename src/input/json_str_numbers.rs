@@ -0,0 +1,143 @@
+use jiter::{
+    cached_py_string, pystring_fast_new, Jiter, JiterError, JiterErrorType, JiterResult, JsonErrorType, Peek,
+    StringCacheMode,
+};
+use pyo3::exceptions::PyValueError;
+use pyo3::prelude::*;
+use pyo3::types::{PyDict, PyList, PyString};
+
+/// Same depth limit jiter uses for its own parsers.
+pub(super) const RECURSION_LIMIT: u8 = 200;
+
+/// Parse JSON into Python objects like `jiter::PythonParse`, but return every number token as a `str`
+/// containing the exact digits from the input rather than converting it to an `int` or `float`.
+pub fn parse_json_str_numbers<'py>(
+    py: Python<'py>,
+    json_bytes: &[u8],
+    allow_inf_nan: bool,
+    cache_mode: StringCacheMode,
+) -> PyResult<Bound<'py, PyAny>> {
+    let mut jiter = Jiter::new(json_bytes);
+    if allow_inf_nan {
+        jiter = jiter.with_allow_inf_nan();
+    }
+    let mut parser = StrNumberParser {
+        jiter,
+        cache_mode,
+        remaining_depth: RECURSION_LIMIT,
+    };
+    parser.parse(py).map_err(|e| match e {
+        ParseError::Json(e) => parser.py_error(&e),
+        ParseError::Py(e) => e,
+    })
+}
+
+/// Errors from the JSON itself are turned into a `ValueError` referring to their position once parsing stops.
+enum ParseError {
+    Json(JiterError),
+    Py(PyErr),
+}
+
+impl From<JiterError> for ParseError {
+    fn from(error: JiterError) -> Self {
+        Self::Json(error)
+    }
+}
+
+impl From<PyErr> for ParseError {
+    fn from(error: PyErr) -> Self {
+        Self::Py(error)
+    }
+}
+
+type ParseResult<T> = Result<T, ParseError>;
+
+struct StrNumberParser<'j> {
+    jiter: Jiter<'j>,
+    cache_mode: StringCacheMode,
+    remaining_depth: u8,
+}
+
+impl StrNumberParser<'_> {
+    fn parse<'py>(&mut self, py: Python<'py>) -> ParseResult<Bound<'py, PyAny>> {
+        let peek = self.jiter.peek()?;
+        let value = self.value(py, peek)?;
+        self.jiter.finish()?;
+        Ok(value)
+    }
+
+    fn value<'py>(&mut self, py: Python<'py>, peek: Peek) -> ParseResult<Bound<'py, PyAny>> {
+        match peek {
+            Peek::Null => {
+                self.jiter.known_null()?;
+                Ok(py.None().into_bound(py))
+            }
+            Peek::True | Peek::False => {
+                let b = self.jiter.known_bool(peek)?;
+                Ok(b.to_object(py).into_bound(py))
+            }
+            Peek::String => {
+                let s = self.jiter.known_str()?;
+                let py_str = match self.cache_mode {
+                    StringCacheMode::All => cached_py_string(py, s, false),
+                    StringCacheMode::Keys | StringCacheMode::None => pystring_fast_new(py, s, false),
+                };
+                Ok(py_str.into_any())
+            }
+            Peek::Array => {
+                self.descend()?;
+                let list = PyList::empty_bound(py);
+                let mut next = self.jiter.known_array()?;
+                while let Some(peek) = next {
+                    list.append(self.value(py, peek)?)?;
+                    next = self.jiter.array_step()?;
+                }
+                self.remaining_depth += 1;
+                Ok(list.into_any())
+            }
+            Peek::Object => {
+                self.descend()?;
+                let dict = PyDict::new_bound(py);
+                let cache_mode = self.cache_mode;
+                let mut next_key = self.jiter.known_object()?.map(|key| py_key(py, key, cache_mode));
+                while let Some(key) = next_key {
+                    let peek = self.jiter.peek()?;
+                    dict.set_item(key, self.value(py, peek)?)?;
+                    next_key = self.jiter.next_key()?.map(|key| py_key(py, key, cache_mode));
+                }
+                self.remaining_depth += 1;
+                Ok(dict.into_any())
+            }
+            _ => {
+                let number = self.jiter.next_number_bytes()?;
+                // jiter only returns bytes for valid number tokens, which are always ASCII
+                let number = std::str::from_utf8(number).map_err(|e| PyValueError::new_err(e.to_string()))?;
+                Ok(pystring_fast_new(py, number, true).into_any())
+            }
+        }
+    }
+
+    fn descend(&mut self) -> JiterResult<()> {
+        match self.remaining_depth.checked_sub(1) {
+            Some(depth) => {
+                self.remaining_depth = depth;
+                Ok(())
+            }
+            None => Err(JiterError {
+                error_type: JiterErrorType::JsonError(JsonErrorType::RecursionLimitExceeded),
+                index: self.jiter.current_index(),
+            }),
+        }
+    }
+
+    fn py_error(&self, error: &JiterError) -> PyErr {
+        PyValueError::new_err(error.description(&self.jiter))
+    }
+}
+
+fn py_key<'py>(py: Python<'py>, key: &str, cache_mode: StringCacheMode) -> Bound<'py, PyString> {
+    match cache_mode {
+        StringCacheMode::All | StringCacheMode::Keys => cached_py_string(py, key, false),
+        StringCacheMode::None => pystring_fast_new(py, key, false),
+    }
+}
@@ -7,13 +7,16 @@ mod input_abstract;
 mod input_json;
 mod input_python;
 mod input_string;
+mod json5_lite;
+mod json_duplicate_keys;
+mod json_str_numbers;
 mod return_enums;
 mod shared;
 
 pub use datetime::TzInfo;
 pub(crate) use datetime::{
     duration_as_pytimedelta, pydate_as_date, pydatetime_as_datetime, pytime_as_time, EitherDate, EitherDateTime,
-    EitherTime, EitherTimedelta,
+    EitherTime, EitherTimedelta, TimeFormat,
 };
 pub(crate) use input_abstract::{
     Arguments, BorrowInput, ConsumeIterator, Input, InputType, KeywordArgs, PositionalArgs, ValidatedDict,
@@ -21,10 +24,16 @@ pub(crate) use input_abstract::{
 };
 pub(crate) use input_python::{downcast_python_input, input_as_python_instance};
 pub(crate) use input_string::StringMapping;
+pub(crate) use json5_lite::strip_json5_lite;
+pub(crate) use json_duplicate_keys::{
+    check_duplicate_keys, has_duplicate_keys, merge_duplicate_keys, JsonDuplicateKeys,
+};
+pub(crate) use json_str_numbers::parse_json_str_numbers;
 pub(crate) use return_enums::{
-    no_validator_iter_to_vec, py_string_str, validate_iter_to_set, validate_iter_to_vec, EitherBytes, EitherFloat,
-    EitherInt, EitherString, GenericIterator, Int, MaxLengthCheck, ValidationMatch,
+    no_validator_iter_to_list, py_string_str, validate_iter_to_list, validate_iter_to_set, EitherBytes,
+    EitherFloat, EitherInt, EitherString, GenericIterator, Int, MaxLengthCheck, ValidationMatch,
 };
+pub(crate) use shared::StringParseOptions;
 
 // Defined here as it's not exported by pyo3
 pub fn py_error_on_minusone(py: Python<'_>, result: c_int) -> PyResult<()> {
@@ -1,27 +1,29 @@
 use std::borrow::Cow;
 use std::cmp::Ordering;
+use std::fmt;
 use std::ops::Rem;
 use std::str::FromStr;
 
 use jiter::{JsonArray, JsonValue, StringCacheMode};
 use num_bigint::BigInt;
 
-use pyo3::exceptions::PyTypeError;
+use pyo3::exceptions::{PyAttributeError, PyTypeError};
 use pyo3::ffi;
 use pyo3::intern;
 use pyo3::prelude::*;
 #[cfg(not(PyPy))]
 use pyo3::types::PyFunction;
-use pyo3::types::{PyBytes, PyFloat, PyFrozenSet, PyIterator, PyMapping, PySet, PyString};
+use pyo3::types::{PyBytes, PyDict, PyFloat, PyFrozenSet, PyIterator, PyMapping, PySet, PyString};
 
 use serde::{ser::Error, Serialize, Serializer};
 
 use crate::errors::{
     py_err_string, ErrorType, ErrorTypeDefaults, InputValue, ToErrorValue, ValError, ValLineError, ValResult,
 };
+use crate::lookup_key::{py_get_attrs, py_get_type_attr, AttributeAccess};
 use crate::py_gc::PyGcTraverse;
-use crate::tools::{extract_i64, new_py_string, py_err};
-use crate::validators::{CombinedValidator, Exactness, ValidationState, Validator};
+use crate::tools::{extract_i64, get_dataclass_field_marker, new_py_string, py_err};
+use crate::validators::{CombinedValidator, Exactness, ValidationState};
 
 use super::{py_error_on_minusone, BorrowInput, Input};
 
@@ -48,6 +50,10 @@ impl<T> ValidationMatch<T> {
         (self.1 == Exactness::Exact).then_some(self.0)
     }
 
+    pub fn exactness(&self) -> Exactness {
+        self.1
+    }
+
     pub fn unpack(self, state: &mut ValidationState) -> T {
         state.floor_exactness(self.1);
         self.0
@@ -116,27 +122,42 @@ macro_rules! any_next_error {
     };
 }
 
+/// Validate items into `output`, when collecting errors the positions in `output` of invalid items which were
+/// replaced with `None` are added to `placeholders`.
 #[allow(clippy::too_many_arguments)]
-pub(crate) fn validate_iter_to_vec<'py>(
+pub(crate) fn validate_iter_to_list<'py>(
     py: Python<'py>,
+    output: &mut Vec<PyObject>,
+    placeholders: &mut Vec<usize>,
     iter: impl Iterator<Item = PyResult<impl BorrowInput<'py>>>,
-    capacity: usize,
     mut max_length_check: MaxLengthCheck<'_, impl Input<'py> + ?Sized>,
+    prefix_validators: &[CombinedValidator],
     validator: &CombinedValidator,
     state: &mut ValidationState<'_, 'py>,
-) -> ValResult<Vec<PyObject>> {
-    let mut output: Vec<PyObject> = Vec::with_capacity(capacity);
+) -> ValResult<()> {
     let mut errors: Vec<ValLineError> = Vec::new();
+    let mut output_len = 0;
     for (index, item_result) in iter.enumerate() {
         let item = item_result.map_err(|e| any_next_error!(py, e, max_length_check.input, index))?;
-        match validator.validate(py, item.borrow_input(), state) {
+        let validator = prefix_validators.get(index).unwrap_or(validator);
+        let start = state.collected_count();
+        let result = validator.validate_profiled(py, item.borrow_input(), state);
+        let is_placeholder = state.is_collecting_errors() && matches!(result, Err(ValError::LineErrors(_)));
+        match state.collect_item(py, result, start, |err| err.with_outer_location(index)) {
             Ok(item) => {
                 max_length_check.incr()?;
                 output.push(item);
+                if is_placeholder {
+                    placeholders.push(output_len);
+                }
+                output_len += 1;
             }
             Err(ValError::LineErrors(line_errors)) => {
                 max_length_check.incr()?;
-                errors.extend(line_errors.into_iter().map(|err| err.with_outer_location(index)));
+                state.collect_errors(
+                    &mut errors,
+                    line_errors.into_iter().map(|err| err.with_outer_location(index)),
+                );
             }
             Err(ValError::Omit) => (),
             Err(err) => return Err(err),
@@ -144,7 +165,7 @@ pub(crate) fn validate_iter_to_vec<'py>(
     }
 
     if errors.is_empty() {
-        Ok(output)
+        Ok(())
     } else {
         Err(ValError::LineErrors(errors))
     }
@@ -194,7 +215,11 @@ pub(crate) fn validate_iter_to_set<'py>(
     let mut errors: Vec<ValLineError> = Vec::new();
     for (index, item_result) in iter.enumerate() {
         let item = item_result.map_err(|e| any_next_error!(py, e, input, index))?;
-        match validator.validate(py, item.borrow_input(), state) {
+        // invalid items are dropped rather than replaced with `None` when collecting errors, since `None` could
+        // collide with another item, so errors within items aren't collected either
+        let result =
+            state.without_collecting_errors(|state| validator.validate_profiled(py, item.borrow_input(), state));
+        match result {
             Ok(item) => {
                 set.build_add(item)?;
                 if let Some(max_length) = max_length {
@@ -215,7 +240,12 @@ pub(crate) fn validate_iter_to_set<'py>(
                 }
             }
             Err(ValError::LineErrors(line_errors)) => {
-                errors.extend(line_errors.into_iter().map(|err| err.with_outer_location(index)));
+                let line_errors = line_errors.into_iter().map(|err| err.with_outer_location(index));
+                if state.is_collecting_errors() {
+                    state.collect_dropped(line_errors);
+                } else {
+                    state.collect_errors(&mut errors, line_errors);
+                }
             }
             Err(ValError::Omit) => (),
             Err(err) => return Err(err),
@@ -229,33 +259,43 @@ pub(crate) fn validate_iter_to_set<'py>(
     }
 }
 
-pub(crate) fn no_validator_iter_to_vec<'py>(
+pub(crate) fn no_validator_iter_to_list<'py>(
     py: Python<'py>,
+    output: &mut Vec<PyObject>,
     input: &(impl Input<'py> + ?Sized),
     iter: impl Iterator<Item = PyResult<impl BorrowInput<'py>>>,
     mut max_length_check: MaxLengthCheck<'_, impl Input<'py> + ?Sized>,
-) -> ValResult<Vec<PyObject>> {
-    iter.enumerate()
-        .map(|(index, result)| {
-            let v = result.map_err(|e| any_next_error!(py, e, input, index))?;
-            max_length_check.incr()?;
-            Ok(v.borrow_input().to_object(py))
-        })
-        .collect()
+) -> ValResult<()> {
+    for (index, result) in iter.enumerate() {
+        let v = result.map_err(|e| any_next_error!(py, e, input, index))?;
+        max_length_check.incr()?;
+        output.push(v.borrow_input().to_object(py));
+    }
+    Ok(())
 }
 
+/// Iterate over the items of a mapping, `items()` is used if the mapping has it (so multi-value mappings yield
+/// every pair), otherwise we fall back to `keys()` (or iterating the mapping) and `__getitem__` for each key.
 pub(crate) fn iterate_mapping_items<'a, 'py>(
     mapping: &'a Bound<'py, PyMapping>,
 ) -> ValResult<impl Iterator<Item = ValResult<(Bound<'py, PyAny>, Bound<'py, PyAny>)>> + 'a> {
     let py = mapping.py();
     let input = mapping.as_any();
-    let iterator = mapping
-        .items()
+    let has_attr = |name: &Bound<'py, PyString>| input.hasattr(name).map_err(|e| mapping_err(e, py, input));
+    let has_items = has_attr(intern!(py, "items"))?;
+    let source = if has_items {
+        mapping.items().map(Bound::into_any)
+    } else if has_attr(intern!(py, "keys"))? {
+        mapping.keys().map(Bound::into_any)
+    } else {
+        Ok(input.clone())
+    };
+    let iterator = source
         .map_err(|e| mapping_err(e, py, input))?
         .iter()
         .map_err(|e| mapping_err(e, py, input))?
         .map(move |item| match item {
-            Ok(item) => item.extract().map_err(|_| {
+            Ok(item) if has_items => item.extract().map_err(|_| {
                 ValError::new(
                     ErrorType::MappingType {
                         error: MAPPING_TUPLE_ERROR.into(),
@@ -264,6 +304,10 @@ pub(crate) fn iterate_mapping_items<'a, 'py>(
                     input,
                 )
             }),
+            Ok(key) => {
+                let value = input.get_item(&key).map_err(|e| mapping_err(e, py, input))?;
+                Ok((key, value))
+            }
             Err(e) => Err(mapping_err(e, py, input)),
         });
     Ok(iterator)
@@ -281,6 +325,27 @@ fn mapping_err<'py>(err: PyErr, py: Python<'py>, input: &'py (impl Input<'py> +
 
 const MAPPING_TUPLE_ERROR: &str = "Mapping items must be tuples of (key, value) pairs";
 
+/// Iterate over the fields declared on a dataclass instance in definition order, like `dataclasses.fields()`,
+/// fields which aren't set on the instance (e.g. `init=False` without a default) are skipped
+pub(crate) fn iterate_dataclass_fields<'a, 'py>(
+    dataclass: &'a Bound<'py, PyAny>,
+    access: AttributeAccess,
+) -> ValResult<impl Iterator<Item = ValResult<(Bound<'py, PyAny>, Bound<'py, PyAny>)>> + 'a> {
+    let py = dataclass.py();
+    let fields = py_get_type_attr(&dataclass.get_type(), intern!(py, "__dataclass_fields__"), access)?
+        .ok_or_else(|| PyAttributeError::new_err("__dataclass_fields__"))?
+        .downcast_into::<PyDict>()?;
+    let field_type_marker = get_dataclass_field_marker(py)?;
+    let next = move |(field_name, field): (Bound<'py, PyAny>, Bound<'py, PyAny>)| -> ValResult<Option<_>> {
+        if !field.getattr(intern!(py, "_field_type"))?.is(&field_type_marker) {
+            return Ok(None);
+        }
+        let attr_name: Py<PyString> = field_name.downcast::<PyString>()?.clone().unbind();
+        Ok(py_get_attrs(dataclass, &attr_name, access)?.map(|value| (field_name, value)))
+    };
+    Ok(fields.into_iter().filter_map(move |field| next(field).transpose()))
+}
+
 /// Iterate over attributes of an object
 pub(crate) fn iterate_attributes<'a, 'py>(
     object: &'a Bound<'py, PyAny>,
@@ -683,6 +748,15 @@ impl PartialEq for Int {
     }
 }
 
+impl fmt::Display for Int {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Int::I64(i) => write!(f, "{i}"),
+            Int::Big(b) => write!(f, "{b}"),
+        }
+    }
+}
+
 impl<'a> Rem for &'a Int {
     type Output = Int;
 
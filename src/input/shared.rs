@@ -2,11 +2,14 @@ use std::borrow::Cow;
 
 use pyo3::prelude::*;
 use pyo3::sync::GILOnceCell;
+use pyo3::types::{PyDict, PyString};
 use pyo3::{intern, Py, PyAny, Python};
 
 use jiter::{JsonErrorType, NumberInt};
 
+use crate::build_tools::{py_schema_err, schema_or_config_same};
 use crate::errors::{ErrorTypeDefaults, ValError, ValResult};
+use crate::tools::SchemaDict;
 
 use super::{EitherFloat, EitherInt, Input};
 static ENUM_META_OBJECT: GILOnceCell<Py<PyAny>> = GILOnceCell::new();
@@ -22,7 +25,161 @@ pub fn get_enum_meta_object(py: Python) -> &Bound<'_, PyAny> {
         .bind(py)
 }
 
-pub fn str_as_bool<'py>(input: &(impl Input<'py> + ?Sized), str: &str) -> ValResult<bool> {
+/// How strings are parsed as bools, ints and floats, set from the `string_parse_options` key of
+/// bool, int and float schemas, and the `json_coerce_strings` schema or config key.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct StringParseOptions {
+    /// strip leading and trailing whitespace before parsing
+    pub trim: bool,
+    /// allow underscores between digits, e.g. `1_000`
+    pub allow_underscores: bool,
+    /// allow whitespace between the sign and the digits, e.g. `- 1`
+    pub allow_sign_whitespace: bool,
+    /// grouping separator which is removed from the integer part, e.g. `,` for `1,234`
+    pub thousands_separator: Option<char>,
+    /// character used as the decimal mark, e.g. `,` for `1,5`
+    pub decimal_separator: char,
+    /// require `thousands_separator` to separate groups of exactly three digits
+    pub strict_grouping: bool,
+    /// parse JSON strings at all in lax mode, when false only JSON's native types are accepted
+    pub json_coerce_strings: bool,
+}
+
+impl Default for StringParseOptions {
+    fn default() -> Self {
+        Self {
+            trim: true,
+            allow_underscores: true,
+            allow_sign_whitespace: false,
+            thousands_separator: None,
+            decimal_separator: '.',
+            strict_grouping: false,
+            json_coerce_strings: true,
+        }
+    }
+}
+
+impl StringParseOptions {
+    /// Options for bools, which unlike numbers aren't trimmed by default.
+    pub const BOOL_DEFAULT: Self = Self {
+        trim: false,
+        allow_underscores: true,
+        allow_sign_whitespace: false,
+        thousands_separator: None,
+        decimal_separator: '.',
+        strict_grouping: false,
+        json_coerce_strings: true,
+    };
+
+    pub fn from_schema(
+        schema: &Bound<'_, PyDict>,
+        config: Option<&Bound<'_, PyDict>>,
+        default: Self,
+    ) -> PyResult<Self> {
+        let py = schema.py();
+        let json_coerce_strings = schema_or_config_same(schema, config, intern!(py, "json_coerce_strings"))?
+            .unwrap_or(default.json_coerce_strings);
+        let Some(options) = schema.get_as::<Bound<'_, PyDict>>(intern!(py, "string_parse_options"))? else {
+            return Ok(Self {
+                json_coerce_strings,
+                ..default
+            });
+        };
+        let thousands_separator = get_separator(&options, intern!(py, "thousands_separator"))?;
+        let decimal_separator = get_separator(&options, intern!(py, "decimal_separator"))?;
+        let decimal_separator = decimal_separator.unwrap_or(default.decimal_separator);
+        if thousands_separator == Some(decimal_separator) {
+            return py_schema_err!("`thousands_separator` and `decimal_separator` must be different");
+        }
+        Ok(Self {
+            trim: options.get_as(intern!(py, "trim"))?.unwrap_or(default.trim),
+            allow_underscores: options
+                .get_as(intern!(py, "allow_underscores"))?
+                .unwrap_or(default.allow_underscores),
+            allow_sign_whitespace: options
+                .get_as(intern!(py, "allow_sign_whitespace"))?
+                .unwrap_or(default.allow_sign_whitespace),
+            thousands_separator,
+            decimal_separator,
+            strict_grouping: options
+                .get_as(intern!(py, "strict_grouping"))?
+                .unwrap_or(default.strict_grouping),
+            json_coerce_strings,
+        })
+    }
+
+    /// Apply `trim`, `allow_sign_whitespace` and the separators to a string which is about to be parsed
+    /// as a number, `None` means the string is invalid.
+    fn normalize_number(self, s: &str) -> Option<Cow<'_, str>> {
+        let s = if self.trim { s.trim() } else { s };
+        let mut s = Cow::Borrowed(s);
+        if self.allow_sign_whitespace {
+            if let Some(digits) = s.strip_prefix(['+', '-']) {
+                if digits.starts_with(char::is_whitespace) {
+                    s = Cow::Owned(format!("{}{}", &s[..1], digits.trim_start()));
+                }
+            }
+        }
+        let has_separators = self.thousands_separator.is_some_and(|sep| s.contains(sep))
+            || (self.decimal_separator != '.' && s.contains([self.decimal_separator, '.']));
+        if has_separators {
+            s = Cow::Owned(self.replace_separators(&s)?);
+        }
+        Some(s)
+    }
+
+    /// Remove thousands separators from the integer part and replace the decimal separator with `.`.
+    fn replace_separators(self, s: &str) -> Option<String> {
+        let (int_part, fraction) = match s.split_once(self.decimal_separator) {
+            Some((int_part, fraction)) => (int_part, Some(fraction)),
+            None => (s, None),
+        };
+        // a `.` which isn't the thousands separator is invalid if it isn't the decimal separator either
+        if self.decimal_separator != '.' && self.thousands_separator != Some('.') && s.contains('.') {
+            return None;
+        }
+        let mut output = String::with_capacity(s.len());
+        match self.thousands_separator {
+            Some(sep) => {
+                if self.strict_grouping && !valid_grouping(int_part.trim_start_matches(['+', '-']), sep) {
+                    return None;
+                }
+                output.extend(int_part.chars().filter(|c| *c != sep));
+            }
+            None => output.push_str(int_part),
+        }
+        if let Some(fraction) = fraction {
+            output.push('.');
+            output.push_str(fraction);
+        }
+        Some(output)
+    }
+}
+
+fn get_separator(options: &Bound<'_, PyDict>, key: &Bound<'_, PyString>) -> PyResult<Option<char>> {
+    let Some(separator) = options.get_as::<String>(key)? else {
+        return Ok(None);
+    };
+    let mut chars = separator.chars();
+    match (chars.next(), chars.next()) {
+        (Some(c), None) => Ok(Some(c)),
+        _ => py_schema_err!("`{}` must be a single character, got {:?}", key, separator),
+    }
+}
+
+/// Whether `sep` only separates groups of three digits, with one to three digits in the first group,
+/// e.g. `1,234,567` but not `1,23` or `1234,567`.
+fn valid_grouping(int_part: &str, sep: char) -> bool {
+    if !int_part.contains(sep) {
+        return true;
+    }
+    let mut groups = int_part.split(sep);
+    let first_len = groups.next().map_or(0, str::len);
+    (1..=3).contains(&first_len) && groups.all(|group| group.len() == 3)
+}
+
+pub fn str_as_bool<'py>(input: &(impl Input<'py> + ?Sized), str: &str, options: StringParseOptions) -> ValResult<bool> {
+    let str = if options.trim { str.trim() } else { str };
     if str == "0"
         || str.eq_ignore_ascii_case("f")
         || str.eq_ignore_ascii_case("n")
@@ -74,7 +231,11 @@ fn strip_underscores(s: &str) -> Option<String> {
 /// max length of the input is 4300 which is checked by jiter, see
 /// https://docs.python.org/3/whatsnew/3.11.html#other-cpython-implementation-changes and
 /// https://github.com/python/cpython/issues/95778 for more info in that length bound
-pub fn str_as_int<'py>(input: &(impl Input<'py> + ?Sized), str: &str) -> ValResult<EitherInt<'py>> {
+pub fn str_as_int<'py>(
+    input: &(impl Input<'py> + ?Sized),
+    str: &str,
+    options: StringParseOptions,
+) -> ValResult<EitherInt<'py>> {
     // we can't move `NumberInt::try_from` into its own function we fail fast if the string is too long
     match NumberInt::try_from(str.as_bytes()) {
         Ok(NumberInt::Int(i)) => return Ok(EitherInt::I64(i)),
@@ -86,7 +247,7 @@ pub fn str_as_int<'py>(input: &(impl Input<'py> + ?Sized), str: &str) -> ValResu
         }
     }
 
-    if let Some(cleaned_str) = clean_int_str(str) {
+    if let Some(cleaned_str) = clean_int_str(str, options) {
         match NumberInt::try_from(cleaned_str.as_ref().as_bytes()) {
             Ok(NumberInt::Int(i)) => Ok(EitherInt::I64(i)),
             Ok(NumberInt::BigInt(i)) => Ok(EitherInt::BigInt(i)),
@@ -98,22 +259,39 @@ pub fn str_as_int<'py>(input: &(impl Input<'py> + ?Sized), str: &str) -> ValResu
 }
 
 /// parse a float as a float
-pub fn str_as_float<'py>(input: &(impl Input<'py> + ?Sized), str: &str) -> ValResult<EitherFloat<'py>> {
-    match str.trim().parse() {
+pub fn str_as_float<'py>(
+    input: &(impl Input<'py> + ?Sized),
+    str: &str,
+    options: StringParseOptions,
+) -> ValResult<EitherFloat<'py>> {
+    let Some(str) = options.normalize_number(str) else {
+        return Err(ValError::new(ErrorTypeDefaults::FloatParsing, input));
+    };
+    match str.parse() {
         Ok(float) => Ok(EitherFloat::F64(float)),
-        Err(_) => match strip_underscores(str).and_then(|stripped| stripped.parse().ok()) {
+        Err(_) => match options
+            .allow_underscores
+            .then(|| strip_underscores(&str))
+            .flatten()
+            .and_then(|stripped| stripped.parse().ok())
+        {
             Some(float) => Ok(EitherFloat::F64(float)),
             None => Err(ValError::new(ErrorTypeDefaults::FloatParsing, input)),
         },
     }
 }
 
-fn clean_int_str(mut s: &str) -> Option<Cow<str>> {
+fn clean_int_str(s: &str, options: StringParseOptions) -> Option<Cow<str>> {
     let len_before = s.len();
 
-    // strip leading and trailing whitespace
-    s = s.trim();
+    // strip leading and trailing whitespace, and whitespace after the sign if allowed
+    match options.normalize_number(s)? {
+        Cow::Borrowed(s) => clean_int_digits(s, len_before, options.allow_underscores),
+        Cow::Owned(s) => clean_int_digits(&s, len_before, options.allow_underscores).map(|s| s.into_owned().into()),
+    }
+}
 
+fn clean_int_digits(mut s: &str, len_before: usize, allow_underscores: bool) -> Option<Cow<'_, str>> {
     // Check for and remove a leading unary plus and ensure the next character is not a unary minus. e.g.: '+-1'.
     if let Some(suffix) = s.strip_prefix('+') {
         if suffix.starts_with('-') {
@@ -135,7 +313,7 @@ fn clean_int_str(mut s: &str) -> Option<Cow<str>> {
     }
 
     // remove underscores
-    if let Some(str_stripped) = strip_underscores(s) {
+    if let Some(str_stripped) = allow_underscores.then(|| strip_underscores(s)).flatten() {
         Some(str_stripped.into())
     } else {
         match len_before == s.len() {
@@ -4,8 +4,8 @@ extern crate core;
 
 use std::sync::OnceLock;
 
-use jiter::{map_json_error, PartialMode, PythonParse, StringCacheMode};
-use pyo3::exceptions::PyTypeError;
+use jiter::{map_json_error, Jiter, JsonValue, PartialMode, PythonParse, StringCacheMode};
+use pyo3::exceptions::{PyTypeError, PyValueError};
 use pyo3::{prelude::*, sync::GILOnceCell};
 
 // parse this first to get access to the contained macro
@@ -28,17 +28,23 @@ mod validators;
 pub use self::input::TzInfo;
 pub use self::url::{PyMultiHostUrl, PyUrl};
 pub use argument_markers::{ArgsKwargs, PydanticUndefinedType};
-pub use build_tools::SchemaError;
+pub use build_tools::{clear_schema_types, register_schema_type, unregister_schema_type, SchemaError};
 pub use errors::{
-    list_all_errors, PydanticCustomError, PydanticKnownError, PydanticOmit, PydanticUseDefault, ValidationError,
+    list_all_errors, PydanticCustomError, PydanticKnownError, PydanticMultiError, PydanticOmit, PydanticUseDefault,
+    ValidationError,
 };
+pub use recursion_guard::PyRecursionGuard;
 pub use serializers::{
-    to_json, to_jsonable_python, PydanticSerializationError, PydanticSerializationUnexpectedValue, SchemaSerializer,
-    WarningsArg,
+    to_cbor, to_json, to_jsonable_python, PydanticSerializationError, PydanticSerializationUnexpectedValue,
+    SchemaSerializer, WarningsArg,
+};
+pub use validators::{
+    validate_core_schema, JsonRaw, ParsedJson, PySome, PythonValidateOptions, SchemaValidator, ValidationReport,
 };
-pub use validators::{validate_core_schema, PySome, SchemaValidator};
 
-use crate::input::Input;
+use crate::input::{
+    check_duplicate_keys, merge_duplicate_keys, parse_json_str_numbers, strip_json5_lite, Input, JsonDuplicateKeys,
+};
 
 #[derive(FromPyObject)]
 pub enum CacheStringsArg {
@@ -46,23 +52,76 @@ pub enum CacheStringsArg {
     Literal(StringCacheMode),
 }
 
-#[pyfunction(signature = (data, *, allow_inf_nan=true, cache_strings=CacheStringsArg::Bool(true), allow_partial=false))]
+impl From<CacheStringsArg> for StringCacheMode {
+    fn from(arg: CacheStringsArg) -> Self {
+        match arg {
+            CacheStringsArg::Bool(b) => b.into(),
+            CacheStringsArg::Literal(mode) => mode,
+        }
+    }
+}
+
+#[allow(clippy::too_many_arguments)]
+#[pyfunction(signature = (data, *, allow_inf_nan=true, cache_strings=CacheStringsArg::Bool(true), allow_partial=false, number_mode="number", json5=false, duplicate_keys="last"))]
 pub fn from_json<'py>(
     py: Python<'py>,
     data: &Bound<'_, PyAny>,
     allow_inf_nan: bool,
     cache_strings: CacheStringsArg,
     allow_partial: bool,
+    number_mode: &str,
+    json5: bool,
+    duplicate_keys: &str,
 ) -> PyResult<Bound<'py, PyAny>> {
     let v_match = data
         .validate_bytes(false)
         .map_err(|_| PyTypeError::new_err("Expected bytes, bytearray or str"))?;
     let json_either_bytes = v_match.into_inner();
-    let json_bytes = json_either_bytes.as_slice();
-    let cache_mode = match cache_strings {
-        CacheStringsArg::Bool(b) => b.into(),
-        CacheStringsArg::Literal(mode) => mode,
+    let stripped_bytes;
+    let json_bytes = if json5 {
+        stripped_bytes = strip_json5_lite(json_either_bytes.as_slice());
+        &stripped_bytes
+    } else {
+        json_either_bytes.as_slice()
     };
+    let duplicate_keys = JsonDuplicateKeys::from_arg(duplicate_keys)?;
+    let cache_mode: StringCacheMode = cache_strings.into();
+    match number_mode {
+        "number" => (),
+        "string" if allow_partial => {
+            return Err(PyValueError::new_err(
+                "`allow_partial` is not supported with `number_mode='string'`",
+            ));
+        }
+        "string" if matches!(duplicate_keys, JsonDuplicateKeys::First | JsonDuplicateKeys::List) => {
+            return Err(PyValueError::new_err(
+                "`duplicate_keys='first'` and `duplicate_keys='list'` are not supported with `number_mode='string'`",
+            ));
+        }
+        "string" => {
+            if duplicate_keys == JsonDuplicateKeys::Error {
+                check_duplicate_keys(json_bytes)
+                    .map_err(|e| PyValueError::new_err(e.description(&Jiter::new(json_bytes))))?;
+            }
+            return parse_json_str_numbers(py, json_bytes, allow_inf_nan, cache_mode);
+        }
+        _ => {
+            return Err(PyValueError::new_err(
+                "Invalid number mode, should be `'number'` or `'string'`",
+            ))
+        }
+    }
+    if matches!(duplicate_keys, JsonDuplicateKeys::First | JsonDuplicateKeys::List) {
+        if allow_partial {
+            return Err(PyValueError::new_err(
+                "`duplicate_keys='first'` and `duplicate_keys='list'` are not supported with `allow_partial`",
+            ));
+        }
+        let json_value = JsonValue::parse(json_bytes, allow_inf_nan).map_err(|e| map_json_error(json_bytes, &e))?;
+        return Ok(merge_duplicate_keys(&json_value, duplicate_keys)
+            .to_object(py)
+            .into_bound(py));
+    }
     let partial_mode = if allow_partial {
         PartialMode::On
     } else {
@@ -72,7 +131,7 @@ pub fn from_json<'py>(
         allow_inf_nan,
         cache_mode,
         partial_mode,
-        catch_duplicate_keys: false,
+        catch_duplicate_keys: duplicate_keys == JsonDuplicateKeys::Error,
         lossless_floats: false,
     };
     parse_builder
@@ -125,10 +184,15 @@ fn _pydantic_core(py: Python, m: &Bound<'_, PyModule>) -> PyResult<()> {
     m.add_class::<PydanticUndefinedType>()?;
     m.add_class::<PySome>()?;
     m.add_class::<SchemaValidator>()?;
+    m.add_class::<ParsedJson>()?;
+    m.add_class::<JsonRaw>()?;
+    m.add_class::<ValidationReport>()?;
+    m.add_class::<PyRecursionGuard>()?;
     m.add_class::<ValidationError>()?;
     m.add_class::<SchemaError>()?;
     m.add_class::<PydanticCustomError>()?;
     m.add_class::<PydanticKnownError>()?;
+    m.add_class::<PydanticMultiError>()?;
     m.add_class::<PydanticOmit>()?;
     m.add_class::<PydanticUseDefault>()?;
     m.add_class::<PydanticSerializationError>()?;
@@ -141,7 +205,11 @@ fn _pydantic_core(py: Python, m: &Bound<'_, PyModule>) -> PyResult<()> {
     m.add_function(wrap_pyfunction!(to_json, m)?)?;
     m.add_function(wrap_pyfunction!(from_json, m)?)?;
     m.add_function(wrap_pyfunction!(to_jsonable_python, m)?)?;
+    m.add_function(wrap_pyfunction!(to_cbor, m)?)?;
     m.add_function(wrap_pyfunction!(list_all_errors, m)?)?;
     m.add_function(wrap_pyfunction!(validate_core_schema, m)?)?;
+    m.add_function(wrap_pyfunction!(register_schema_type, m)?)?;
+    m.add_function(wrap_pyfunction!(unregister_schema_type, m)?)?;
+    m.add_function(wrap_pyfunction!(clear_schema_types, m)?)?;
     Ok(())
 }
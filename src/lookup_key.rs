@@ -1,16 +1,29 @@
 use core::slice::Iter;
 use std::fmt;
 
+use ahash::AHashMap;
 use pyo3::exceptions::{PyAttributeError, PyTypeError};
 use pyo3::prelude::*;
-use pyo3::types::{PyDict, PyList, PyMapping, PyString};
+use pyo3::sync::GILOnceCell;
+use pyo3::types::{PyDict, PyList, PyMapping, PyString, PyTuple, PyType};
+use pyo3::{ffi, intern};
 
 use jiter::{JsonObject, JsonValue};
 
 use crate::build_tools::py_schema_err;
 use crate::errors::{py_err_string, ErrorType, ToErrorValue, ValError, ValLineError, ValResult};
-use crate::input::StringMapping;
-use crate::tools::{extract_i64, py_err};
+use crate::input::{BorrowInput, ConsumeIterator, Input, StringMapping, ValidatedDict};
+use crate::tools::{extract_i64, py_err, SchemaDict};
+
+/// How attributes are read from objects when validating with `from_attributes`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AttributeAccess {
+    /// normal `getattr`, including properties, other descriptors and `__getattr__`
+    GetAttr,
+    /// only read values stored in the instance `__dict__` or `__slots__`, without running any python code
+    /// defined on the object, for validating untrusted objects
+    Safe,
+}
 
 /// Used for getting items from python dicts, python objects, or JSON objects, in different ways
 #[derive(Debug, Clone)]
@@ -195,8 +208,9 @@ impl LookupKey {
         &'s self,
         obj: &Bound<'py, PyAny>,
         kwargs: Option<&Bound<'py, PyDict>>,
+        access: AttributeAccess,
     ) -> ValResult<Option<(&'s LookupPath, Bound<'py, PyAny>)>> {
-        match self._py_get_attr(obj, kwargs) {
+        match self._py_get_attr(obj, kwargs, access) {
             Ok(v) => Ok(v),
             Err(err) => {
                 let error = py_err_string(obj.py(), err);
@@ -212,6 +226,7 @@ impl LookupKey {
         &'s self,
         obj: &Bound<'py, PyAny>,
         kwargs: Option<&Bound<'py, PyDict>>,
+        access: AttributeAccess,
     ) -> PyResult<Option<(&'s LookupPath, Bound<'py, PyAny>)>> {
         if let Some(dict) = kwargs {
             if let Ok(Some(item)) = self.py_get_dict_item(dict) {
@@ -220,7 +235,7 @@ impl LookupKey {
         }
 
         match self {
-            Self::Simple { py_key, path, .. } => match py_get_attrs(obj, py_key)? {
+            Self::Simple { py_key, path, .. } => match py_get_attrs(obj, py_key, access)? {
                 Some(value) => Ok(Some((path, value))),
                 None => Ok(None),
             },
@@ -230,9 +245,9 @@ impl LookupKey {
                 py_key2,
                 path2,
                 ..
-            } => match py_get_attrs(obj, py_key1)? {
+            } => match py_get_attrs(obj, py_key1, access)? {
                 Some(value) => Ok(Some((path1, value))),
-                None => match py_get_attrs(obj, py_key2)? {
+                None => match py_get_attrs(obj, py_key2, access)? {
                     Some(value) => Ok(Some((path2, value))),
                     None => Ok(None),
                 },
@@ -243,7 +258,7 @@ impl LookupKey {
                     // so we have to loop manually
                     let mut v = obj.clone();
                     for loc in path.iter() {
-                        v = match loc.py_get_attrs(&v) {
+                        v = match loc.py_get_attrs(&v, access) {
                             Ok(Some(v)) => v,
                             Ok(None) => {
                                 continue 'outer;
@@ -327,6 +342,139 @@ impl LookupKey {
     }
 }
 
+/// How fields looked up case-insensitively treat input keys which only differ in case,
+/// e.g. `Content-Type` and `content-type`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum CaseCollision {
+    /// use the key which matches exactly if there is one, otherwise the first key in the input
+    PreferExact,
+    /// raise a `case_collision` error
+    Error,
+}
+
+impl CaseCollision {
+    /// Read `case_insensitive` and `case_collision` from the schema, `None` if keys should be matched exactly.
+    pub fn from_schema(schema: &Bound<'_, PyDict>) -> PyResult<Option<Self>> {
+        let py = schema.py();
+        let collision = match schema.get_as::<Bound<'_, PyString>>(intern!(py, "case_collision"))? {
+            Some(collision) => match collision.to_str()? {
+                "prefer_exact" => Self::PreferExact,
+                "error" => Self::Error,
+                s => return py_schema_err!("Invalid case_collision value: {}", s),
+            },
+            None => Self::PreferExact,
+        };
+        match schema.get_as(intern!(py, "case_insensitive"))?.unwrap_or(false) {
+            true => Ok(Some(collision)),
+            false => Ok(None),
+        }
+    }
+}
+
+/// The string keys of an input mapping grouped by their lowercase form, built once per validation so each
+/// field can be looked up case-insensitively.
+pub(crate) struct CaseInsensitiveKeys {
+    keys: AHashMap<String, Vec<String>>,
+    collision: CaseCollision,
+}
+
+impl CaseInsensitiveKeys {
+    pub fn new<'py>(dict: &impl ValidatedDict<'py>, collision: CaseCollision) -> ValResult<Self> {
+        struct CollectKeys;
+
+        impl<'py, Key, Value> ConsumeIterator<ValResult<(Key, Value)>> for CollectKeys
+        where
+            Key: BorrowInput<'py>,
+        {
+            type Output = ValResult<AHashMap<String, Vec<String>>>;
+            fn consume_iterator(self, iterator: impl Iterator<Item = ValResult<(Key, Value)>>) -> Self::Output {
+                let mut keys: AHashMap<String, Vec<String>> = AHashMap::new();
+                for item_result in iterator {
+                    let (raw_key, _) = item_result?;
+                    // keys which aren't strings can't match a field
+                    let Ok(either_str) = raw_key.borrow_input().validate_str(true, false) else {
+                        continue;
+                    };
+                    let key = either_str.into_inner().as_cow()?.into_owned();
+                    keys.entry(key.to_lowercase()).or_default().push(key);
+                }
+                Ok(keys)
+            }
+        }
+
+        Ok(Self {
+            keys: dict.iterate(CollectKeys)??,
+            collision,
+        })
+    }
+
+    /// Like `ValidatedDict::get_item` but matching string keys case-insensitively, for alias paths only the
+    /// first item is matched case-insensitively.
+    pub fn get_item<'py, 'k, 'd, D: ValidatedDict<'py>>(
+        &self,
+        py: Python<'py>,
+        dict: &'d D,
+        lookup_key: &'k LookupKey,
+        input: impl ToErrorValue,
+    ) -> ValResult<Option<(&'k LookupPath, D::Item<'d>)>> {
+        let paths = match lookup_key {
+            LookupKey::Simple { path, .. } => vec![path],
+            LookupKey::Choice { path1, path2, .. } => vec![path1, path2],
+            LookupKey::PathChoices(paths) => paths.iter().collect(),
+        };
+        for path in paths {
+            let key = path.first_key();
+            let Some(matches) = self.keys.get(&key.to_lowercase()) else {
+                continue;
+            };
+            if matches.len() > 1 && self.collision == CaseCollision::Error {
+                let keys = matches.iter().map(|k| format!("'{k}'")).collect::<Vec<_>>().join(", ");
+                return Err(ValError::new(ErrorType::CaseCollision { keys, context: None }, input));
+            }
+            let actual_key = Self::preferred_key(matches, key);
+            let actual_lookup_key = match path.with_first_key(py, actual_key) {
+                Some(actual_path) => LookupKey::PathChoices(vec![actual_path]),
+                None => LookupKey::from_string(py, actual_key),
+            };
+            if let Some((_, value)) = dict.get_item(&actual_lookup_key)? {
+                return Ok(Some((path, value)));
+            }
+        }
+        Ok(None)
+    }
+
+    /// Like `LookupPath::alias_py` but with the first item replaced by the input key it matched, which can
+    /// differ in case from the alias.
+    pub fn alias_py(&self, py: Python, path: &LookupPath) -> PyObject {
+        let key = path.first_key();
+        let Some(matches) = self.keys.get(&key.to_lowercase()) else {
+            return path.alias_py(py);
+        };
+        let actual_key = Self::preferred_key(matches, key);
+        match path.with_first_key(py, actual_key) {
+            Some(actual_path) => actual_path.alias_py(py),
+            None => PyString::new_bound(py, actual_key).into_py(py),
+        }
+    }
+
+    /// the input key with exactly the case of `key` if there is one, otherwise the first of `matches`
+    fn preferred_key<'s>(matches: &'s [String], key: &str) -> &'s str {
+        match matches.iter().find(|k| *k == key) {
+            Some(exact) => exact,
+            None => &matches[0],
+        }
+    }
+
+    /// All input keys which match `key` ignoring case.
+    pub fn variants(&self, key: &str) -> impl Iterator<Item = &str> {
+        self.keys
+            .get(&key.to_lowercase())
+            .into_iter()
+            .flatten()
+            .map(String::as_str)
+    }
+}
+
 #[derive(Debug, Clone)]
 pub(crate) struct LookupPath(Vec<PathItem>);
 
@@ -386,6 +534,24 @@ impl LookupPath {
     pub fn first_key(&self) -> &str {
         self.0.first().unwrap().get_key()
     }
+
+    /// a copy of this path with its first item replaced by `key`, `None` if the path is only a single key
+    fn with_first_key(&self, py: Python, key: &str) -> Option<Self> {
+        if self.0.len() == 1 {
+            return None;
+        }
+        let mut items = self.0.clone();
+        items[0] = PathItem::S(key.to_string(), PyString::new_bound(py, key).into());
+        Some(Self(items))
+    }
+
+    /// the alias this path was built from, a `str` for a single key, otherwise a list of path items
+    pub fn alias_py(&self, py: Python) -> PyObject {
+        match self.0.as_slice() {
+            [PathItem::S(_, py_key)] => py_key.clone_ref(py).into_py(py),
+            items => PyList::new_bound(py, items).into_py(py),
+        }
+    }
 }
 
 #[derive(Debug, Clone)]
@@ -460,14 +626,18 @@ impl PathItem {
         }
     }
 
-    pub fn py_get_attrs<'py>(&self, obj: &Bound<'py, PyAny>) -> PyResult<Option<Bound<'py, PyAny>>> {
+    pub fn py_get_attrs<'py>(
+        &self,
+        obj: &Bound<'py, PyAny>,
+        access: AttributeAccess,
+    ) -> PyResult<Option<Bound<'py, PyAny>>> {
         match self {
             Self::S(_, py_key) => {
                 // if obj is a dict, we want to use get_item, not getattr
                 if obj.downcast::<PyDict>().is_ok() {
                     Ok(self.py_get_item(obj))
                 } else {
-                    py_get_attrs(obj, py_key)
+                    py_get_attrs(obj, py_key, access)
                 }
             }
             // int, we fall back to py_get_item - e.g. we want to use get_item for a list, tuple, dict, etc.
@@ -503,9 +673,18 @@ impl PathItem {
 
 /// wrapper around `getattr` that returns `Ok(None)` for attribute errors, but returns other errors
 /// We don't check `try_from_attributes` because that check was performed on the top level object before we got here
-fn py_get_attrs<'py>(obj: &Bound<'py, PyAny>, attr_name: &Py<PyString>) -> PyResult<Option<Bound<'py, PyAny>>> {
-    match obj.getattr(attr_name.extract::<&PyString>(obj.py())?) {
-        Ok(attr) => Ok(Some(attr)),
+pub(crate) fn py_get_attrs<'py>(
+    obj: &Bound<'py, PyAny>,
+    attr_name: &Py<PyString>,
+    access: AttributeAccess,
+) -> PyResult<Option<Bound<'py, PyAny>>> {
+    let attr_name = attr_name.bind(obj.py());
+    let result = match access {
+        AttributeAccess::GetAttr => obj.getattr(attr_name).map(Some),
+        AttributeAccess::Safe => py_get_stored_attr(obj, attr_name),
+    };
+    match result {
+        Ok(attr) => Ok(attr),
         Err(err) => {
             if err.get_type_bound(obj.py()).is_subclass_of::<PyAttributeError>()? {
                 Ok(None)
@@ -515,3 +694,121 @@ fn py_get_attrs<'py>(obj: &Bound<'py, PyAny>, attr_name: &Py<PyString>) -> PyRes
         }
     }
 }
+
+/// Get an attribute of the type `cls`, with `AttributeAccess::Safe` only values stored in the namespaces of its
+/// real MRO are returned, without calling descriptors or looking anything up on its metaclass
+pub(crate) fn py_get_type_attr<'py>(
+    cls: &Bound<'py, PyType>,
+    attr_name: &Bound<'py, PyString>,
+    access: AttributeAccess,
+) -> PyResult<Option<Bound<'py, PyAny>>> {
+    match access {
+        AttributeAccess::GetAttr => match cls.getattr(attr_name) {
+            Ok(attr) => Ok(Some(attr)),
+            Err(err) if err.is_instance_of::<PyAttributeError>(cls.py()) => Ok(None),
+            Err(err) => Err(err),
+        },
+        AttributeAccess::Safe => {
+            for base in type_mro(cls)?.iter() {
+                let base_dict = type_dict(base.downcast::<PyType>()?)?;
+                if base_dict.contains(attr_name)? {
+                    return base_dict.get_item(attr_name).map(Some);
+                }
+            }
+            Ok(None)
+        }
+    }
+}
+
+/// Get an attribute stored on `obj` itself, in a slot or the instance `__dict__`, mirroring the order
+/// of `object.__getattribute__` but without calling `__getattr__`, properties or other descriptors.
+fn py_get_stored_attr<'py>(
+    obj: &Bound<'py, PyAny>,
+    attr_name: &Bound<'py, PyString>,
+) -> PyResult<Option<Bound<'py, PyAny>>> {
+    let py = obj.py();
+    let obj_type = obj.get_type();
+    for cls in type_mro(&obj_type)?.iter() {
+        let cls_dict = type_dict(cls.downcast::<PyType>()?)?;
+        if !cls_dict.contains(attr_name)? {
+            continue;
+        }
+        let descriptor = cls_dict.get_item(attr_name)?;
+        let descriptor_type = descriptor.get_type();
+        if descriptor_type.is(member_descriptor_type(py)?) {
+            // slots are builtin `member_descriptor`s, reading them doesn't run any python code
+            return descriptor
+                .call_method1(intern!(py, "__get__"), (obj, &obj_type))
+                .map(Some);
+        } else if is_data_descriptor_type(&descriptor_type)? {
+            // data descriptors like properties take precedence over the instance `__dict__`
+            return Ok(None);
+        }
+        break;
+    }
+
+    // `PyObject_GenericGetDict` reads the instance dict directly, rather than via `__dict__` which could
+    // be overridden, it raises an `AttributeError` if the object has no `__dict__`
+    let instance_dict =
+        unsafe { Bound::from_owned_ptr_or_err(py, ffi::PyObject_GenericGetDict(obj.as_ptr(), std::ptr::null_mut())) }?;
+    instance_dict.downcast::<PyDict>()?.get_item(attr_name)
+}
+
+/// The `__mro__` and `__dict__` descriptors of `type` itself, which read a type's real MRO and namespace
+/// even if its metaclass overrides those attributes.
+static TYPE_DESCRIPTORS: GILOnceCell<(PyObject, PyObject)> = GILOnceCell::new();
+
+fn type_descriptors(py: Python<'_>) -> PyResult<&(PyObject, PyObject)> {
+    TYPE_DESCRIPTORS.get_or_try_init(py, || {
+        let type_dict = py.get_type_bound::<PyType>().getattr(intern!(py, "__dict__"))?;
+        Ok((
+            type_dict.get_item(intern!(py, "__mro__"))?.unbind(),
+            type_dict.get_item(intern!(py, "__dict__"))?.unbind(),
+        ))
+    })
+}
+
+/// The MRO of `cls` without looking up `__mro__` on it, which could run code from its metaclass
+pub(crate) fn type_mro<'py>(cls: &Bound<'py, PyType>) -> PyResult<Bound<'py, PyTuple>> {
+    let py = cls.py();
+    let (mro_descriptor, _) = type_descriptors(py)?;
+    let mro = mro_descriptor
+        .bind(py)
+        .call_method1(intern!(py, "__get__"), (cls, py.get_type_bound::<PyType>()))?;
+    Ok(mro.downcast_into::<PyTuple>()?)
+}
+
+/// The namespace of `cls` as a read-only `mappingproxy`, without looking up `__dict__` on it
+fn type_dict<'py>(cls: &Bound<'py, PyType>) -> PyResult<Bound<'py, PyMapping>> {
+    let py = cls.py();
+    let (_, dict_descriptor) = type_descriptors(py)?;
+    let dict = dict_descriptor
+        .bind(py)
+        .call_method1(intern!(py, "__get__"), (cls, py.get_type_bound::<PyType>()))?;
+    Ok(dict.downcast_into::<PyMapping>()?)
+}
+
+/// Whether instances of `descriptor_type` are data descriptors, i.e. define `__set__` or `__delete__`,
+/// checked in the namespaces of its real MRO rather than with `hasattr` which could run metaclass code
+fn is_data_descriptor_type(descriptor_type: &Bound<'_, PyType>) -> PyResult<bool> {
+    let py = descriptor_type.py();
+    for cls in type_mro(descriptor_type)?.iter() {
+        let cls_dict = type_dict(cls.downcast::<PyType>()?)?;
+        if cls_dict.contains(intern!(py, "__set__"))? || cls_dict.contains(intern!(py, "__delete__"))? {
+            return Ok(true);
+        }
+    }
+    Ok(false)
+}
+
+static MEMBER_DESCRIPTOR_TYPE: GILOnceCell<Py<PyType>> = GILOnceCell::new();
+
+fn member_descriptor_type(py: Python<'_>) -> PyResult<&Bound<'_, PyType>> {
+    MEMBER_DESCRIPTOR_TYPE
+        .get_or_try_init(py, || {
+            py.import_bound(intern!(py, "types"))?
+                .getattr(intern!(py, "MemberDescriptorType"))?
+                .extract()
+        })
+        .map(|t| t.bind(py))
+}
@@ -1,5 +1,9 @@
 use ahash::AHashSet;
 use std::mem::MaybeUninit;
+use std::sync::{Mutex, MutexGuard};
+
+use pyo3::exceptions::PyRuntimeError;
+use pyo3::prelude::*;
 
 type RecursionKey = (
     // Identifier for the input object, e.g. the id() of a Python dict
@@ -128,6 +132,55 @@ impl RecursionState {
     fn remove(&mut self, obj_id: usize, node_id: usize) {
         self.ids.remove(&(obj_id, node_id));
     }
+
+    /// Clear the state so it can be reused for another validation, keeping any allocated capacity.
+    pub fn reset(&mut self) {
+        self.ids.clear();
+        self.depth = 0;
+    }
+}
+
+/// A recursion state which can be shared across calls to `SchemaValidator.validate_python`, rather than
+/// allocating a new one for every call, e.g. when validating a stream of many small items.
+#[pyclass(name = "RecursionGuard", module = "pydantic_core._pydantic_core", frozen)]
+#[derive(Debug, Default)]
+pub struct PyRecursionGuard {
+    state: Mutex<RecursionState>,
+}
+
+impl PyRecursionGuard {
+    /// Lock the state for one validation, it's reset first so ids and depth can't leak from a previous call.
+    pub fn lock(&self) -> PyResult<MutexGuard<'_, RecursionState>> {
+        let mut state = self
+            .state
+            .try_lock()
+            .map_err(|_| PyRuntimeError::new_err("RecursionGuard is already in use by another validation"))?;
+        state.reset();
+        Ok(state)
+    }
+}
+
+#[pymethods]
+impl PyRecursionGuard {
+    #[new]
+    fn py_new() -> Self {
+        Self::default()
+    }
+
+    fn __enter__(slf: Py<Self>) -> Py<Self> {
+        slf
+    }
+
+    /// Release any memory held by the state when leaving the context.
+    #[pyo3(signature = (*_args))]
+    fn __exit__(&self, _args: &Bound<'_, PyAny>) -> PyResult<()> {
+        *self.lock()? = RecursionState::default();
+        Ok(())
+    }
+
+    fn __repr__(&self) -> &'static str {
+        "RecursionGuard()"
+    }
 }
 
 // trial and error suggests this is a good value, going higher causes array lookups to get significantly slower
@@ -201,6 +254,19 @@ impl RecursionStack {
             }
         }
     }
+
+    fn clear(&mut self) {
+        match self {
+            Self::Array { data, len } => {
+                for value in data.iter_mut().take(*len) {
+                    // Safety: dropping values within bounds, which are then no longer read
+                    unsafe { std::ptr::drop_in_place(value.as_mut_ptr()) };
+                }
+                *len = 0;
+            }
+            Self::Set(set) => set.clear(),
+        }
+    }
 }
 
 impl Drop for RecursionStack {
@@ -0,0 +1,230 @@
+use std::str::FromStr;
+
+use num_bigint::{BigInt, Sign};
+use pyo3::intern;
+use pyo3::prelude::*;
+use pyo3::types::{PyBool, PyBytes, PyDateTime, PyDict, PyFloat, PyInt, PyList, PyString, PyTuple};
+
+use crate::build_tools::py_schema_err;
+use crate::validators::decimal::get_decimal_type;
+
+use super::errors::PydanticSerializationError;
+use super::ob_type::ObType;
+
+/// Types kept as Python objects when serializing to CBOR so they can be encoded natively rather than as strings.
+pub(super) const CBOR_NATIVE: [ObType; 3] = [ObType::Bytes, ObType::Datetime, ObType::Decimal];
+
+// major types, see https://www.rfc-editor.org/rfc/rfc8949.html#section-3.1
+const MAJOR_UNSIGNED: u8 = 0;
+const MAJOR_NEGATIVE: u8 = 1;
+const MAJOR_BYTES: u8 = 2;
+const MAJOR_TEXT: u8 = 3;
+const MAJOR_ARRAY: u8 = 4;
+const MAJOR_MAP: u8 = 5;
+const MAJOR_TAG: u8 = 6;
+
+// tags, see https://www.rfc-editor.org/rfc/rfc8949.html#section-3.4
+const TAG_DATETIME_STRING: u64 = 0;
+const TAG_DATETIME_EPOCH: u64 = 1;
+const TAG_POSITIVE_BIGNUM: u64 = 2;
+const TAG_NEGATIVE_BIGNUM: u64 = 3;
+const TAG_DECIMAL_FRACTION: u64 = 4;
+
+// additional information values giving the size of the argument following the initial byte
+const ARGUMENT_U8: u8 = 24;
+const ARGUMENT_U16: u8 = 25;
+const ARGUMENT_U32: u8 = 26;
+const ARGUMENT_U64: u8 = 27;
+
+const FALSE: u8 = 0xf4;
+const TRUE: u8 = 0xf5;
+const NULL: u8 = 0xf6;
+const FLOAT64: u8 = 0xfb;
+
+/// How datetimes are encoded, the datetime tags identify an instant so naive datetimes can't be tagged.
+#[derive(Default, Debug, Clone, Copy, PartialEq, Eq)]
+pub(super) enum CborDatetimeMode {
+    /// tag 0 date/time strings, naive datetimes are encoded as plain text strings
+    #[default]
+    Iso8601,
+    /// tag 1 epoch timestamps, naive datetimes are an error
+    Epoch,
+}
+
+impl FromStr for CborDatetimeMode {
+    type Err = PyErr;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "iso8601" => Ok(Self::Iso8601),
+            "epoch" => Ok(Self::Epoch),
+            s => py_schema_err!(
+                "Invalid CborDatetimeMode serialization mode: `{}`, expected iso8601 or epoch",
+                s
+            ),
+        }
+    }
+}
+
+/// Encode a value which has already been serialized in JSON mode, keeping the `CBOR_NATIVE` types, as CBOR.
+pub(super) fn to_cbor_bytes(value: &Bound<'_, PyAny>, datetime_mode: CborDatetimeMode) -> PyResult<Vec<u8>> {
+    let mut encoder = CborEncoder {
+        bytes: Vec::new(),
+        datetime_mode,
+    };
+    encoder.encode(value)?;
+    Ok(encoder.bytes)
+}
+
+struct CborEncoder {
+    bytes: Vec<u8>,
+    datetime_mode: CborDatetimeMode,
+}
+
+impl CborEncoder {
+    fn encode(&mut self, value: &Bound<'_, PyAny>) -> PyResult<()> {
+        let py = value.py();
+        if value.is_none() {
+            self.bytes.push(NULL);
+        } else if let Ok(b) = value.downcast::<PyBool>() {
+            self.bytes.push(if b.is_true() { TRUE } else { FALSE });
+        } else if let Ok(i) = value.downcast::<PyInt>() {
+            self.encode_int(&i.extract()?);
+        } else if let Ok(f) = value.downcast::<PyFloat>() {
+            self.encode_float(f.value());
+        } else if let Ok(s) = value.downcast::<PyString>() {
+            self.encode_text(s.to_str()?);
+        } else if let Ok(b) = value.downcast::<PyBytes>() {
+            self.write_head(MAJOR_BYTES, b.as_bytes().len() as u64);
+            self.bytes.extend_from_slice(b.as_bytes());
+        } else if let Ok(dict) = value.downcast::<PyDict>() {
+            self.write_head(MAJOR_MAP, dict.len() as u64);
+            for (k, v) in dict {
+                self.encode(&k)?;
+                self.encode(&v)?;
+            }
+        } else if let Ok(list) = value.downcast::<PyList>() {
+            self.write_head(MAJOR_ARRAY, list.len() as u64);
+            for item in list {
+                self.encode(&item)?;
+            }
+        } else if let Ok(tuple) = value.downcast::<PyTuple>() {
+            self.write_head(MAJOR_ARRAY, tuple.len() as u64);
+            for item in tuple {
+                self.encode(&item)?;
+            }
+        } else if let Ok(dt) = value.downcast::<PyDateTime>() {
+            self.encode_datetime(dt)?;
+        } else if value.is_instance(get_decimal_type(py))? {
+            self.encode_decimal(value)?;
+        } else {
+            return Err(PydanticSerializationError::new_err(format!(
+                "Unable to serialize unknown type to CBOR: {}",
+                value.get_type().name()?
+            )));
+        }
+        Ok(())
+    }
+
+    fn encode_int(&mut self, int: &BigInt) {
+        let (sign, magnitude) = match int.sign() {
+            // -1 - n is encoded as n
+            Sign::Minus => (MAJOR_NEGATIVE, -int - 1),
+            _ => (MAJOR_UNSIGNED, int.clone()),
+        };
+        match u64::try_from(&magnitude) {
+            Ok(n) => self.write_head(sign, n),
+            Err(_) => {
+                let tag = if sign == MAJOR_NEGATIVE {
+                    TAG_NEGATIVE_BIGNUM
+                } else {
+                    TAG_POSITIVE_BIGNUM
+                };
+                self.write_head(MAJOR_TAG, tag);
+                let (_, bytes) = magnitude.to_bytes_be();
+                self.write_head(MAJOR_BYTES, bytes.len() as u64);
+                self.bytes.extend_from_slice(&bytes);
+            }
+        }
+    }
+
+    fn encode_float(&mut self, float: f64) {
+        self.bytes.push(FLOAT64);
+        self.bytes.extend_from_slice(&float.to_be_bytes());
+    }
+
+    fn encode_text(&mut self, text: &str) {
+        self.write_head(MAJOR_TEXT, text.len() as u64);
+        self.bytes.extend_from_slice(text.as_bytes());
+    }
+
+    fn encode_datetime(&mut self, dt: &Bound<'_, PyDateTime>) -> PyResult<()> {
+        let py = dt.py();
+        let aware = !dt.call_method0(intern!(py, "utcoffset"))?.is_none();
+        match self.datetime_mode {
+            CborDatetimeMode::Iso8601 => {
+                if aware {
+                    self.write_head(MAJOR_TAG, TAG_DATETIME_STRING);
+                }
+                let iso = dt.call_method0(intern!(py, "isoformat"))?;
+                self.encode_text(iso.downcast::<PyString>()?.to_str()?);
+            }
+            CborDatetimeMode::Epoch => {
+                if !aware {
+                    return Err(PydanticSerializationError::new_err(
+                        "Naive datetimes can't be serialized to CBOR as epoch timestamps".to_string(),
+                    ));
+                }
+                self.write_head(MAJOR_TAG, TAG_DATETIME_EPOCH);
+                let timestamp: f64 = dt.call_method0(intern!(py, "timestamp"))?.extract()?;
+                // whole seconds are encoded as integers, as recommended by the RFC
+                if timestamp.fract() == 0.0 {
+                    self.encode_int(&BigInt::from(timestamp as i64));
+                } else {
+                    self.encode_float(timestamp);
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Decimals are encoded as a decimal fraction `[exponent, mantissa]`, except NaN and infinity which
+    /// have no such representation and are encoded as floats.
+    fn encode_decimal(&mut self, decimal: &Bound<'_, PyAny>) -> PyResult<()> {
+        let py = decimal.py();
+        let (sign, digits, exponent): (u8, Vec<u8>, Bound<'_, PyAny>) =
+            decimal.call_method0(intern!(py, "as_tuple"))?.extract()?;
+        let Ok(exponent) = exponent.extract::<i64>() else {
+            self.encode_float(decimal.extract()?);
+            return Ok(());
+        };
+        let mantissa = digits
+            .iter()
+            .fold(BigInt::from(0), |acc, digit| acc * 10 + BigInt::from(*digit));
+        let mantissa = if sign == 1 { -mantissa } else { mantissa };
+        self.write_head(MAJOR_TAG, TAG_DECIMAL_FRACTION);
+        self.write_head(MAJOR_ARRAY, 2);
+        self.encode_int(&BigInt::from(exponent));
+        self.encode_int(&mantissa);
+        Ok(())
+    }
+
+    /// Write the initial byte of an item and its argument in the shortest form.
+    fn write_head(&mut self, major: u8, argument: u64) {
+        let major = major << 5;
+        if argument < u64::from(ARGUMENT_U8) {
+            self.bytes.push(major | argument as u8);
+        } else if let Ok(argument) = u8::try_from(argument) {
+            self.bytes.extend_from_slice(&[major | ARGUMENT_U8, argument]);
+        } else if let Ok(argument) = u16::try_from(argument) {
+            self.bytes.push(major | ARGUMENT_U16);
+            self.bytes.extend_from_slice(&argument.to_be_bytes());
+        } else if let Ok(argument) = u32::try_from(argument) {
+            self.bytes.push(major | ARGUMENT_U32);
+            self.bytes.extend_from_slice(&argument.to_be_bytes());
+        } else {
+            self.bytes.push(major | ARGUMENT_U64);
+            self.bytes.extend_from_slice(&argument.to_be_bytes());
+        }
+    }
+}
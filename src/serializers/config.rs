@@ -20,6 +20,10 @@ pub(crate) struct SerializationConfig {
     pub timedelta_mode: TimedeltaMode,
     pub bytes_mode: BytesMode,
     pub inf_nan_mode: InfNanMode,
+    pub enum_mode: EnumMode,
+    /// the maximum depth of recursive values, from the `ser_max_depth` config setting, as a backstop for cycles
+    /// which can't be detected by object id
+    pub max_depth: Option<usize>,
 }
 
 impl SerializationConfig {
@@ -27,10 +31,17 @@ impl SerializationConfig {
         let timedelta_mode = TimedeltaMode::from_config(config)?;
         let bytes_mode = BytesMode::from_config(config)?;
         let inf_nan_mode = InfNanMode::from_config(config)?;
+        let enum_mode = EnumMode::from_config(config)?;
+        let max_depth = match config {
+            Some(config) => config.get_as(intern!(config.py(), "ser_max_depth"))?,
+            None => None,
+        };
         Ok(Self {
             timedelta_mode,
             bytes_mode,
             inf_nan_mode,
+            enum_mode,
+            max_depth,
         })
     }
 
@@ -39,6 +50,8 @@ impl SerializationConfig {
             timedelta_mode: TimedeltaMode::from_str(timedelta_mode)?,
             bytes_mode: BytesMode::from_str(bytes_mode)?,
             inf_nan_mode: InfNanMode::from_str(inf_nan_mode)?,
+            enum_mode: EnumMode::default(),
+            max_depth: None,
         })
     }
 }
@@ -106,6 +119,20 @@ serialization_mode! {
     Constants => "constants",
 }
 
+serialization_mode! {
+    LargeIntMode,
+    "ser_json_large_int",
+    Number => "number",
+    String => "string",
+}
+
+serialization_mode! {
+    EnumMode,
+    "ser_json_enum",
+    Value => "value",
+    Name => "name",
+}
+
 impl TimedeltaMode {
     fn total_seconds<'py>(py_timedelta: &Bound<'py, PyDelta>) -> PyResult<Bound<'py, PyAny>> {
         py_timedelta.call_method0(intern!(py_timedelta.py(), "total_seconds"))
@@ -0,0 +1,168 @@
+use pyo3::exceptions::PyRecursionError;
+use pyo3::prelude::*;
+use pyo3::types::{
+    PyBool, PyByteArray, PyBytes, PyDict, PyFloat, PyFrozenSet, PyInt, PyList, PySet, PyString, PyTuple,
+};
+
+use super::extra::{DuckTypingSerMode, SerMode, SerializationState};
+use super::infer::infer_to_python;
+
+/// Same depth limit jiter uses when parsing, content hashes are computed on validated data so deeper
+/// structures are almost certainly recursive.
+const RECURSION_LIMIT: u16 = 200;
+
+/// Compute a deterministic hash of `value`'s content.
+///
+/// The value is first serialized to python structures (models, dataclasses etc. become dicts), any values
+/// which aren't dicts, sequences, sets or primitives are then serialized in JSON mode. The hash of dicts
+/// and sets doesn't depend on their iteration order, lists and tuples are hashed the same way.
+pub(crate) fn content_hash(py: Python, value: &Bound<'_, PyAny>) -> PyResult<u64> {
+    let state = SerializationState::new("iso8601", "utf8", "constants")?;
+    let extra = |mode| {
+        state.extra(
+            py,
+            mode,
+            true,
+            false,
+            false,
+            false,
+            None,
+            DuckTypingSerMode::SchemaBased,
+            None,
+        )
+    };
+    let python_extra = extra(&SerMode::Python);
+    let python_value = infer_to_python(value, None, None, &python_extra)?;
+
+    let json_extra = extra(&SerMode::Json);
+    let to_json = |v: &Bound<'_, PyAny>| infer_to_python(v, None, None, &json_extra);
+
+    let mut hasher = Fnv1a::default();
+    hash_value(python_value.bind(py), &to_json, &mut hasher, RECURSION_LIMIT)?;
+    state.final_check(py)?;
+    Ok(hasher.0)
+}
+
+fn hash_value(
+    value: &Bound<'_, PyAny>,
+    to_json: &impl Fn(&Bound<'_, PyAny>) -> PyResult<PyObject>,
+    hasher: &mut Fnv1a,
+    remaining_depth: u16,
+) -> PyResult<()> {
+    let Some(remaining_depth) = remaining_depth.checked_sub(1) else {
+        return Err(PyRecursionError::new_err(
+            "Maximum depth exceeded while computing content hash",
+        ));
+    };
+    let hash_child = |child: &Bound<'_, PyAny>| -> PyResult<u64> {
+        let mut child_hasher = Fnv1a::default();
+        hash_value(child, to_json, &mut child_hasher, remaining_depth)?;
+        Ok(child_hasher.0)
+    };
+
+    if value.is_none() {
+        hasher.write(b"n");
+    } else if let Ok(b) = value.downcast::<PyBool>() {
+        hasher.write(if b.is_true() { b"t" } else { b"f" });
+    } else if let Ok(i) = value.downcast::<PyInt>() {
+        hasher.write(b"i");
+        hasher.write_str(i.str()?.to_str()?);
+    } else if let Ok(f) = value.downcast::<PyFloat>() {
+        hasher.write(b"d");
+        hasher.write(&f.value().to_bits().to_le_bytes());
+    } else if let Ok(s) = value.downcast::<PyString>() {
+        hasher.write(b"s");
+        hasher.write_str(s.to_str()?);
+    } else if let Ok(b) = value.downcast::<PyBytes>() {
+        hasher.write(b"b");
+        hasher.write_bytes(b.as_bytes());
+    } else if let Ok(b) = value.downcast::<PyByteArray>() {
+        hasher.write(b"b");
+        hasher.write_bytes(&b.to_vec());
+    } else if let Ok(dict) = value.downcast::<PyDict>() {
+        let mut entries = dict
+            .iter()
+            .map(|(k, v)| Ok((hash_child(&k)?, hash_child(&v)?)))
+            .collect::<PyResult<Vec<_>>>()?;
+        entries.sort_unstable();
+        hasher.write(b"{");
+        for (k, v) in entries {
+            hasher.write_u64(k);
+            hasher.write_u64(v);
+        }
+        hasher.write(b"}");
+    } else if let Ok(list) = value.downcast::<PyList>() {
+        hash_sequence(list.iter(), hash_child, hasher)?;
+    } else if let Ok(tuple) = value.downcast::<PyTuple>() {
+        hash_sequence(tuple.iter(), hash_child, hasher)?;
+    } else if let Ok(set) = value.downcast::<PySet>() {
+        hash_set(set.iter(), hash_child, hasher)?;
+    } else if let Ok(frozenset) = value.downcast::<PyFrozenSet>() {
+        hash_set(frozenset.iter(), hash_child, hasher)?;
+    } else {
+        let json_value = to_json(value)?;
+        hash_value(json_value.bind(value.py()), to_json, hasher, remaining_depth)?;
+    }
+    Ok(())
+}
+
+fn hash_sequence<'py>(
+    items: impl Iterator<Item = Bound<'py, PyAny>>,
+    hash_child: impl Fn(&Bound<'py, PyAny>) -> PyResult<u64>,
+    hasher: &mut Fnv1a,
+) -> PyResult<()> {
+    hasher.write(b"[");
+    for item in items {
+        hasher.write_u64(hash_child(&item)?);
+    }
+    hasher.write(b"]");
+    Ok(())
+}
+
+fn hash_set<'py>(
+    items: impl Iterator<Item = Bound<'py, PyAny>>,
+    hash_child: impl Fn(&Bound<'py, PyAny>) -> PyResult<u64>,
+    hasher: &mut Fnv1a,
+) -> PyResult<()> {
+    let mut hashes = items.map(|item| hash_child(&item)).collect::<PyResult<Vec<_>>>()?;
+    hashes.sort_unstable();
+    hasher.write(b"<");
+    for hash in hashes {
+        hasher.write_u64(hash);
+    }
+    hasher.write(b">");
+    Ok(())
+}
+
+/// 64 bit FNV-1a, used rather than `ahash` or `DefaultHasher` since its output is stable across
+/// processes, platforms and rust versions.
+struct Fnv1a(u64);
+
+impl Default for Fnv1a {
+    fn default() -> Self {
+        Self(0xcbf2_9ce4_8422_2325)
+    }
+}
+
+impl Fnv1a {
+    fn write(&mut self, bytes: &[u8]) {
+        for byte in bytes {
+            self.0 ^= u64::from(*byte);
+            self.0 = self.0.wrapping_mul(0x0100_0000_01b3);
+        }
+    }
+
+    fn write_u64(&mut self, value: u64) {
+        self.write(&value.to_le_bytes());
+    }
+
+    /// Length prefixed so adjacent strings can't collide, e.g. `["ab", "c"]` and `["a", "bc"]`.
+    fn write_bytes(&mut self, bytes: &[u8]) {
+        self.write_u64(bytes.len() as u64);
+        self.write(bytes);
+    }
+
+    fn write_str(&mut self, s: &str) {
+        self.write_bytes(s.as_bytes());
+    }
+}
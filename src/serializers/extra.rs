@@ -1,16 +1,21 @@
+use std::borrow::Cow;
 use std::cell::RefCell;
+use std::collections::HashSet;
 use std::fmt;
+use std::str::FromStr;
+
+use ahash::RandomState;
 
 use pyo3::exceptions::{PyTypeError, PyValueError};
 use pyo3::intern;
 use pyo3::prelude::*;
-use pyo3::types::PyBool;
+use pyo3::types::{PyBool, PyDict, PyType};
 
 use serde::ser::Error;
 
 use super::config::SerializationConfig;
 use super::errors::{PydanticSerializationUnexpectedValue, UNEXPECTED_TYPE_SER_MARKER};
-use super::ob_type::ObTypeLookup;
+use super::ob_type::{ObType, ObTypeLookup};
 use crate::recursion_guard::ContainsRecursionState;
 use crate::recursion_guard::RecursionError;
 use crate::recursion_guard::RecursionGuard;
@@ -64,6 +69,113 @@ impl DuckTypingSerMode {
     }
 }
 
+/// Types which are left as python objects rather than converted when serializing in JSON mode,
+/// set via the `keep_native` argument to `to_jsonable_python`.
+#[derive(Clone, Copy, Default, PartialEq, Eq)]
+#[cfg_attr(debug_assertions, derive(Debug))]
+pub(crate) struct KeepNative(u64);
+
+impl KeepNative {
+    const ALLOWED: [ObType; 9] = [
+        ObType::Bytes,
+        ObType::Datetime,
+        ObType::Date,
+        ObType::Time,
+        ObType::Timedelta,
+        ObType::Decimal,
+        ObType::Uuid,
+        ObType::Url,
+        ObType::MultiHostUrl,
+    ];
+
+    pub fn from_names(names: Option<HashSet<String, RandomState>>) -> PyResult<Self> {
+        let mut keep_native = Self::default();
+        for name in names.iter().flatten() {
+            match ObType::from_str(name) {
+                Ok(ob_type) if Self::ALLOWED.contains(&ob_type) => keep_native.0 |= Self::bit(ob_type),
+                _ => {
+                    let allowed = Self::ALLOWED.map(|ob_type| format!("'{ob_type}'")).join(", ");
+                    return Err(PyValueError::new_err(format!(
+                        "Invalid keep_native type '{name}', expected one of {allowed}"
+                    )));
+                }
+            }
+        }
+        Ok(keep_native)
+    }
+
+    pub fn from_ob_types(ob_types: &[ObType]) -> Self {
+        Self(ob_types.iter().fold(0, |bits, ob_type| bits | Self::bit(*ob_type)))
+    }
+
+    pub fn contains(self, ob_type: ObType) -> bool {
+        self.0 & Self::bit(ob_type) != 0
+    }
+
+    fn bit(ob_type: ObType) -> u64 {
+        1 << (ob_type as u8)
+    }
+}
+
+/// Limits on the size of the output, set via the `max_depth` and `max_items` arguments to `to_jsonable_python`.
+#[derive(Clone, Copy, Default, PartialEq, Eq)]
+#[cfg_attr(debug_assertions, derive(Debug))]
+pub(crate) struct Truncate {
+    max_depth: Option<usize>,
+    max_items: Option<usize>,
+    // how many collections the value being serialized is nested within
+    depth: usize,
+}
+
+impl Truncate {
+    /// What deeper collections and the remaining items of longer collections are replaced with.
+    pub const MARKER: &'static str = "...(truncated)";
+
+    pub fn new(max_depth: Option<usize>, max_items: Option<usize>) -> Self {
+        Self {
+            max_depth,
+            max_items,
+            depth: 0,
+        }
+    }
+
+    pub fn is_set(self) -> bool {
+        self.max_depth.is_some() || self.max_items.is_some()
+    }
+
+    /// The limits for the items of a collection, or `None` if the collection is nested too deeply to be serialized.
+    pub fn enter(self) -> Option<Self> {
+        match self.max_depth {
+            Some(max_depth) if self.depth >= max_depth => None,
+            _ => Some(Self {
+                depth: self.depth + 1,
+                ..self
+            }),
+        }
+    }
+
+    /// How many items of a collection are serialized before the rest are replaced with `MARKER`.
+    pub fn max_items(self) -> usize {
+        self.max_items.unwrap_or(usize::MAX)
+    }
+
+    /// Cut a serialized dict short after `max_items` items, with `MARKER` as the last key, for serializers which
+    /// can't stop early, e.g. models whose computed fields are added last.
+    pub fn limit_dict(self, dict: Bound<'_, PyDict>) -> PyResult<Bound<'_, PyDict>> {
+        let max_items = self.max_items();
+        if dict.len() <= max_items {
+            return Ok(dict);
+        }
+        let py = dict.py();
+        let limited = PyDict::new_bound(py);
+        for (key, value) in dict.iter().take(max_items) {
+            limited.set_item(key, value)?;
+        }
+        limited.set_item(Self::MARKER, py.None())?;
+        Ok(limited)
+    }
+}
+
 impl SerializationState {
     pub fn new(timedelta_mode: &str, bytes_mode: &str, inf_nan_mode: &str) -> PyResult<Self> {
         let warnings = CollectWarnings::new(WarningsMode::None);
@@ -137,6 +249,8 @@ pub(crate) struct Extra<'a> {
     pub fallback: Option<&'a Bound<'a, PyAny>>,
     pub duck_typing_ser_mode: DuckTypingSerMode,
     pub context: Option<&'a Bound<'a, PyAny>>,
+    pub keep_native: KeepNative,
+    pub truncate: Truncate,
 }
 
 impl<'a> Extra<'a> {
@@ -175,6 +289,8 @@ impl<'a> Extra<'a> {
             fallback,
             duck_typing_ser_mode,
             context,
+            keep_native: KeepNative::default(),
+            truncate: Truncate::default(),
         }
     }
 
@@ -187,16 +303,50 @@ impl<'a> Extra<'a> {
         self: &'x mut &'y Self,
         value: &Bound<'_, PyAny>,
         def_ref_id: usize,
-    ) -> PyResult<RecursionGuard<'x, &'y Self>> {
-        RecursionGuard::new(self, value.as_ptr() as usize, def_ref_id).map_err(|e| match e {
-            RecursionError::Depth => PyValueError::new_err("Circular reference detected (depth exceeded)"),
-            RecursionError::Cyclic => PyValueError::new_err("Circular reference detected (id repeated)"),
-        })
+    ) -> PyResult<SerRecursionGuard<'x, 'y, 'a>> {
+        let obj_id = value.as_ptr() as usize;
+        let rec_guard = self.rec_guard;
+        let max_depth = self.config.max_depth;
+        let depth = rec_guard.path.borrow().len() + 1;
+        let guard = RecursionGuard::new(self, obj_id, def_ref_id).map_err(|e| match e {
+            RecursionError::Depth => {
+                PydanticSerializationError::new_err("Circular reference detected (depth exceeded)".to_string())
+            }
+            RecursionError::Cyclic => PydanticSerializationError::new_err(format!(
+                "Circular reference detected (id repeated), path: {}",
+                rec_guard.cycle_path(value, obj_id, def_ref_id)
+            )),
+        })?;
+        if let Some(max_depth) = max_depth {
+            if depth > max_depth {
+                return Err(PydanticSerializationError::new_err(format!(
+                    "Circular reference detected (depth exceeded), max_depth is {max_depth}"
+                )));
+            }
+        }
+        rec_guard
+            .path
+            .borrow_mut()
+            .push((obj_id, def_ref_id, value.get_type().unbind()));
+        Ok(SerRecursionGuard { guard })
     }
 
     pub fn serialize_infer<'py>(&'py self, value: &'py Bound<'py, PyAny>) -> super::infer::SerializeInfer<'py> {
         super::infer::SerializeInfer::new(value, None, None, self)
     }
+
+    /// The `Extra` to serialize the items of a collection with, which counts towards `max_depth`, or `None` if the
+    /// collection is nested too deeply and should be replaced with `Truncate::MARKER`.
+    pub fn nested(&self) -> Option<Cow<'_, Self>> {
+        if !self.truncate.is_set() {
+            return Some(Cow::Borrowed(self));
+        }
+        let truncate = self.truncate.enter()?;
+        Some(Cow::Owned(Self {
+            truncate,
+            ..self.clone()
+        }))
+    }
 }
 
 #[derive(Clone, Copy, PartialEq, Eq)]
@@ -235,6 +385,8 @@ pub(crate) struct ExtraOwned {
     pub fallback: Option<PyObject>,
     duck_typing_ser_mode: DuckTypingSerMode,
     pub context: Option<PyObject>,
+    keep_native: KeepNative,
+    truncate: Truncate,
 }
 
 impl ExtraOwned {
@@ -256,6 +408,8 @@ impl ExtraOwned {
             fallback: extra.fallback.map(|model| model.clone().into()),
             duck_typing_ser_mode: extra.duck_typing_ser_mode,
             context: extra.context.map(|model| model.clone().into()),
+            keep_native: extra.keep_native,
+            truncate: extra.truncate,
         }
     }
 
@@ -278,6 +432,8 @@ impl ExtraOwned {
             fallback: self.fallback.as_ref().map(|m| m.bind(py)),
             duck_typing_ser_mode: self.duck_typing_ser_mode,
             context: self.context.as_ref().map(|m| m.bind(py)),
+            keep_native: self.keep_native,
+            truncate: self.truncate,
         }
     }
 }
@@ -462,6 +618,48 @@ impl CollectWarnings {
 #[cfg_attr(debug_assertions, derive(Debug))]
 pub struct SerRecursionState {
     guard: RefCell<RecursionState>,
+    /// the object id, node id and type of the values currently being serialized under the recursion guard,
+    /// outermost first, used to describe cycles
+    path: RefCell<Vec<(usize, usize, Py<PyType>)>>,
+}
+
+impl SerRecursionState {
+    /// The types of the values from the first occurrence of `value` to where it repeats, e.g. `Foo -> list -> Foo`
+    fn cycle_path(&self, value: &Bound<'_, PyAny>, obj_id: usize, node_id: usize) -> String {
+        let py = value.py();
+        let path = self.path.borrow();
+        let start = path
+            .iter()
+            .position(|(id, node, _)| *id == obj_id && *node == node_id)
+            .unwrap_or(0);
+        path[start..]
+            .iter()
+            .map(|(_, _, ty)| ty.bind(py).clone())
+            .chain(std::iter::once(value.get_type()))
+            .map(|ty| match ty.qualname() {
+                Ok(name) => name,
+                Err(_) => "<unknown>".to_string(),
+            })
+            .collect::<Vec<_>>()
+            .join(" -> ")
+    }
+}
+
+/// The recursion guard of a value being serialized, which also tracks the value in `SerRecursionState.path`
+pub(crate) struct SerRecursionGuard<'x, 'y, 'a> {
+    guard: RecursionGuard<'x, &'y Extra<'a>>,
+}
+
+impl<'y, 'a> SerRecursionGuard<'_, 'y, 'a> {
+    pub fn state(&mut self) -> &mut &'y Extra<'a> {
+        self.guard.state()
+    }
+}
+
+impl Drop for SerRecursionGuard<'_, '_, '_> {
+    fn drop(&mut self) {
+        self.guard.state().rec_guard.path.borrow_mut().pop();
+    }
 }
 
 impl ContainsRecursionState for &'_ Extra<'_> {
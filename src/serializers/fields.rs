@@ -13,7 +13,7 @@ use crate::PydanticSerializationUnexpectedValue;
 
 use super::computed_fields::ComputedFields;
 use super::errors::py_err_se_err;
-use super::extra::Extra;
+use super::extra::{Extra, Truncate};
 use super::filter::SchemaFilter;
 use super::infer::{infer_json_key, infer_serialize, infer_to_python, SerializeInfer};
 use super::shared::PydanticSerializer;
@@ -28,6 +28,8 @@ pub(super) struct SerField {
     // None serializer means exclude
     pub serializer: Option<CombinedSerializer>,
     pub required: bool,
+    // overrides `exclude_none` for this field if set
+    pub omit_if_none: Option<bool>,
 }
 
 impl_py_gc_traverse!(SerField { serializer });
@@ -39,6 +41,7 @@ impl SerField {
         alias: Option<String>,
         serializer: Option<CombinedSerializer>,
         required: bool,
+        omit_if_none: Option<bool>,
     ) -> Self {
         let alias_py = alias
             .as_ref()
@@ -49,9 +52,14 @@ impl SerField {
             alias_py,
             serializer,
             required,
+            omit_if_none,
         }
     }
 
+    pub fn omit_none(&self, value: &Bound<'_, PyAny>, extra: &Extra) -> bool {
+        self.omit_if_none.unwrap_or(extra.exclude_none) && value.is_none()
+    }
+
     pub fn get_key_py<'py>(&'py self, py: Python<'py>, extra: &Extra) -> &Bound<'py, PyAny> {
         if extra.by_alias {
             if let Some(ref alias_py) = self.alias_py {
@@ -160,7 +168,11 @@ impl GeneralFieldsSerializer {
             let (key, value) = result?;
             let key_str = key_str(&key)?;
             let op_field = self.fields.get(key_str.as_ref());
-            if extra.exclude_none && value.is_none() {
+            let omit_none = match op_field {
+                Some(field) => field.omit_none(&value, &extra),
+                None => extra.exclude_none && value.is_none(),
+            };
+            if omit_none {
                 if let Some(field) = op_field {
                     if field.required {
                         used_req_fields += 1;
@@ -231,10 +243,15 @@ impl GeneralFieldsSerializer {
 
         for result in main_iter {
             let (key, value) = result.map_err(py_err_se_err)?;
-            if extra.exclude_none && value.is_none() {
+            let key_str = key_str(&key).map_err(py_err_se_err)?;
+            let op_field = self.fields.get(key_str.as_ref());
+            let omit_none = match op_field {
+                Some(field) => field.omit_none(&value, &extra),
+                None => extra.exclude_none && value.is_none(),
+            };
+            if omit_none {
                 continue;
             }
-            let key_str = key_str(&key).map_err(py_err_se_err)?;
             let field_extra = Extra {
                 field_name: Some(&key_str),
                 ..extra
@@ -242,7 +259,7 @@ impl GeneralFieldsSerializer {
 
             let filter = self.filter.key_filter(&key, include, exclude).map_err(py_err_se_err)?;
             if let Some((next_include, next_exclude)) = filter {
-                if let Some(field) = self.fields.get(key_str.as_ref()) {
+                if let Some(field) = op_field {
                     if let Some(ref serializer) = field.serializer {
                         if !exclude_default(&value, &field_extra, serializer).map_err(py_err_se_err)? {
                             let s = PydanticSerializer::new(
@@ -344,6 +361,11 @@ impl TypeSerializer for GeneralFieldsSerializer {
             extra.warnings.on_fallback_py(self.get_name(), value, extra)?;
             return infer_to_python(value, include, exclude, extra);
         };
+        // models and typed dicts count towards `max_depth` like dicts
+        let Some(extra) = extra.nested() else {
+            return Ok(Truncate::MARKER.into_py(py));
+        };
+        let extra = extra.as_ref();
 
         let output_dict =
             self.main_to_python(py, dict_items(&main_dict), include, exclude, Extra { model, ..*extra })?;
@@ -366,7 +388,7 @@ impl TypeSerializer for GeneralFieldsSerializer {
             }
         }
         self.add_computed_fields_python(model, &output_dict, include, exclude, extra)?;
-        Ok(output_dict.into_py(py))
+        Ok(extra.truncate.limit_dict(output_dict)?.into_py(py))
     }
 
     fn json_key<'a>(&self, key: &'a Bound<'_, PyAny>, extra: &Extra) -> PyResult<Cow<'a, str>> {
@@ -12,10 +12,10 @@ use crate::input::{EitherTimedelta, Int};
 use crate::tools::{extract_i64, py_err, safe_repr};
 use crate::url::{PyMultiHostUrl, PyUrl};
 
-use super::config::InfNanMode;
+use super::config::{EnumMode, InfNanMode};
 use super::errors::SERIALIZATION_ERR_MARKER;
 use super::errors::{py_err_se_err, PydanticSerializationError};
-use super::extra::{Extra, SerMode};
+use super::extra::{Extra, SerMode, Truncate};
 use super::filter::{AnyFilter, SchemaFilter};
 use super::ob_type::ObType;
 use super::shared::{any_dataclass_iter, PydanticSerializer, TypeSerializer};
@@ -56,14 +56,40 @@ pub(crate) fn infer_to_python_known(
     };
     let extra = guard.state();
 
+    // collections count towards `max_depth`, and are replaced entirely once it's reached
+    let truncate = extra.truncate;
+    let nested_extra;
+    let extra = match ob_type {
+        ObType::Tuple
+        | ObType::List
+        | ObType::Set
+        | ObType::Frozenset
+        | ObType::Dict
+        | ObType::Dataclass
+        | ObType::Generator => match extra.nested() {
+            Some(extra) => {
+                nested_extra = extra;
+                nested_extra.as_ref()
+            }
+            None => return Ok(Truncate::MARKER.into_py(py)),
+        },
+        _ => extra,
+    };
+    let max_items = extra.truncate.max_items();
+
     macro_rules! serialize_seq {
-        ($t:ty) => {
-            value
-                .downcast::<$t>()?
-                .iter()
-                .map(|v| infer_to_python(&v, None, None, extra))
-                .collect::<PyResult<Vec<PyObject>>>()?
-        };
+        ($t:ty) => {{
+            let py_seq = value.downcast::<$t>()?;
+            let mut items = Vec::with_capacity(py_seq.len());
+            for element in py_seq.iter() {
+                if items.len() == max_items {
+                    items.push(Truncate::MARKER.into_py(py));
+                    break;
+                }
+                items.push(infer_to_python(&element, None, None, extra)?);
+            }
+            items
+        }};
     }
 
     macro_rules! serialize_seq_filter {
@@ -76,6 +102,10 @@ pub(crate) fn infer_to_python_known(
             for (index, element) in py_seq.iter().enumerate() {
                 let op_next = filter.index_filter(index, include, exclude, len)?;
                 if let Some((next_include, next_exclude)) = op_next {
+                    if items.len() == max_items {
+                        items.push(Truncate::MARKER.into_py(py));
+                        break;
+                    }
                     items.push(infer_to_python(
                         &element,
                         next_include.as_ref(),
@@ -88,10 +118,11 @@ pub(crate) fn infer_to_python_known(
         }};
     }
 
+    let keep_native = extra.keep_native;
     let serialize_with_serializer = || {
         let py_serializer = value.getattr(intern!(py, "__pydantic_serializer__"))?;
         let serializer: PyRef<SchemaSerializer> = py_serializer.extract()?;
-        let extra = serializer.build_extra(
+        let mut extra = serializer.build_extra(
             py,
             extra.mode,
             extra.by_alias,
@@ -106,10 +137,13 @@ pub(crate) fn infer_to_python_known(
             extra.duck_typing_ser_mode,
             extra.context,
         );
+        extra.keep_native = keep_native;
+        extra.truncate = truncate;
         serializer.serializer.to_python(value, include, exclude, &extra)
     };
 
     let value = match extra.mode {
+        SerMode::Json if extra.keep_native.contains(ob_type) => value.into_py(py),
         SerMode::Json => match ob_type {
             // `bool` and `None` can't be subclasses, `ObType::Int`, `ObType::Float`, `ObType::Str` refer to exact types
             ObType::None | ObType::Bool | ObType::Int | ObType::Str => value.into_py(py),
@@ -166,7 +200,7 @@ pub(crate) fn infer_to_python_known(
                 })?
             }
             ObType::Datetime => {
-                let iso_dt = super::type_serializers::datetime_etc::datetime_to_string(value.downcast()?)?;
+                let iso_dt = super::type_serializers::datetime_etc::datetime_to_string(value.downcast()?, extra)?;
                 iso_dt.into_py(py)
             }
             ObType::Date => {
@@ -174,7 +208,7 @@ pub(crate) fn infer_to_python_known(
                 iso_date.into_py(py)
             }
             ObType::Time => {
-                let iso_time = super::type_serializers::datetime_etc::time_to_string(value.downcast()?)?;
+                let iso_time = super::type_serializers::datetime_etc::time_to_string(value.downcast()?, extra)?;
                 iso_time.into_py(py)
             }
             ObType::Timedelta => {
@@ -202,10 +236,13 @@ pub(crate) fn infer_to_python_known(
                     Ok(PyString::new_bound(py, &infer_json_key(&k, extra)?).into_any())
                 })?
             }
-            ObType::Enum => {
-                let v = value.getattr(intern!(py, "value"))?;
-                infer_to_python(&v, include, exclude, extra)?.into_py(py)
-            }
+            ObType::Enum => match extra.config.enum_mode {
+                EnumMode::Value => {
+                    let v = value.getattr(intern!(py, "value"))?;
+                    infer_to_python(&v, include, exclude, extra)?.into_py(py)
+                }
+                EnumMode::Name => value.getattr(intern!(py, "name"))?.into_py(py),
+            },
             ObType::Generator => {
                 let py_seq = value.downcast::<PyIterator>()?;
                 let mut items = Vec::new();
@@ -215,6 +252,10 @@ pub(crate) fn infer_to_python_known(
                     let element = r?;
                     let op_next = filter.index_filter(index, include, exclude, None)?;
                     if let Some((next_include, next_exclude)) = op_next {
+                        if items.len() == max_items {
+                            items.push(Truncate::MARKER.into_py(py));
+                            break;
+                        }
                         items.push(infer_to_python(
                             &element,
                             next_include.as_ref(),
@@ -437,7 +478,8 @@ pub(crate) fn infer_serialize_known<S: Serializer>(
         ObType::Frozenset => serialize_seq!(PyFrozenSet),
         ObType::Datetime => {
             let py_dt = value.downcast().map_err(py_err_se_err)?;
-            let iso_dt = super::type_serializers::datetime_etc::datetime_to_string(py_dt).map_err(py_err_se_err)?;
+            let iso_dt =
+                super::type_serializers::datetime_etc::datetime_to_string(py_dt, extra).map_err(py_err_se_err)?;
             serializer.serialize_str(&iso_dt)
         }
         ObType::Date => {
@@ -447,7 +489,8 @@ pub(crate) fn infer_serialize_known<S: Serializer>(
         }
         ObType::Time => {
             let py_time = value.downcast().map_err(py_err_se_err)?;
-            let iso_time = super::type_serializers::datetime_etc::time_to_string(py_time).map_err(py_err_se_err)?;
+            let iso_time =
+                super::type_serializers::datetime_etc::time_to_string(py_time, extra).map_err(py_err_se_err)?;
             serializer.serialize_str(&iso_time)
         }
         ObType::Timedelta => {
@@ -499,7 +542,11 @@ pub(crate) fn infer_serialize_known<S: Serializer>(
             serializer.serialize_str(&uuid)
         }
         ObType::Enum => {
-            let v = value.getattr(intern!(value.py(), "value")).map_err(py_err_se_err)?;
+            let attr = match extra.config.enum_mode {
+                EnumMode::Value => intern!(value.py(), "value"),
+                EnumMode::Name => intern!(value.py(), "name"),
+            };
+            let v = value.getattr(attr).map_err(py_err_se_err)?;
             infer_serialize(&v, serializer, include, exclude, extra)
         }
         ObType::Generator => {
@@ -616,7 +663,7 @@ pub(crate) fn infer_json_key_known<'a>(
                 .map(|cow| Cow::Owned(cow.into_owned()))
         }
         ObType::Datetime => {
-            let iso_dt = super::type_serializers::datetime_etc::datetime_to_string(key.downcast()?)?;
+            let iso_dt = super::type_serializers::datetime_etc::datetime_to_string(key.downcast()?, extra)?;
             Ok(Cow::Owned(iso_dt))
         }
         ObType::Date => {
@@ -624,7 +671,7 @@ pub(crate) fn infer_json_key_known<'a>(
             Ok(Cow::Owned(iso_date))
         }
         ObType::Time => {
-            let iso_time = super::type_serializers::datetime_etc::time_to_string(key.downcast()?)?;
+            let iso_time = super::type_serializers::datetime_etc::time_to_string(key.downcast()?, extra)?;
             Ok(Cow::Owned(iso_time))
         }
         ObType::Uuid => {
@@ -660,7 +707,11 @@ pub(crate) fn infer_json_key_known<'a>(
             Ok(Cow::Owned(key))
         }
         ObType::Enum => {
-            let k = key.getattr(intern!(key.py(), "value"))?;
+            let attr = match extra.config.enum_mode {
+                EnumMode::Value => intern!(key.py(), "value"),
+                EnumMode::Name => intern!(key.py(), "name"),
+            };
+            let k = key.getattr(attr)?;
             infer_json_key(&k, extra).map(|cow| Cow::Owned(cow.into_owned()))
         }
         ObType::Path => {
@@ -696,11 +747,16 @@ fn serialize_pairs_python<'py>(
 ) -> PyResult<PyObject> {
     let new_dict = PyDict::new_bound(py);
     let filter = AnyFilter::new();
+    let max_items = extra.truncate.max_items();
 
     for result in pairs_iter {
         let (k, v) = result?;
         let op_next = filter.key_filter(&k, include, exclude)?;
         if let Some((next_include, next_exclude)) = op_next {
+            if new_dict.len() == max_items {
+                new_dict.set_item(Truncate::MARKER, py.None())?;
+                break;
+            }
             let k = key_transform(k)?;
             let v = infer_to_python(&v, next_include.as_ref(), next_exclude.as_ref(), extra)?;
             new_dict.set_item(k, v)?;
@@ -1,6 +1,10 @@
+use std::collections::HashSet;
 use std::fmt::Debug;
+use std::str::FromStr;
 use std::sync::atomic::{AtomicUsize, Ordering};
 
+use ahash::RandomState;
+
 use pyo3::prelude::*;
 use pyo3::types::{PyBytes, PyDict};
 use pyo3::{PyTraverseError, PyVisit};
@@ -8,21 +12,27 @@ use pyo3::{PyTraverseError, PyVisit};
 use crate::definitions::{Definitions, DefinitionsBuilder};
 use crate::py_gc::PyGcTraverse;
 
+pub(crate) use config::BytesMode;
 use config::SerializationConfig;
+pub(crate) use content_hash::content_hash;
 pub use errors::{PydanticSerializationError, PydanticSerializationUnexpectedValue};
-use extra::{CollectWarnings, SerRecursionState, WarningsMode};
+use extra::{CollectWarnings, KeepNative, SerRecursionState, Truncate, WarningsMode};
 pub(crate) use extra::{DuckTypingSerMode, Extra, SerMode, SerializationState};
+use py_writer::PyFileWriter;
 pub use shared::CombinedSerializer;
-use shared::{to_json_bytes, BuildSerializer, TypeSerializer};
+use shared::{to_json_bytes, to_json_writer, BuildSerializer, TypeSerializer};
 
+mod cbor;
 mod computed_fields;
 mod config;
+mod content_hash;
 mod errors;
 mod extra;
 mod fields;
 mod filter;
 mod infer;
 mod ob_type;
+mod py_writer;
 pub mod ser;
 mod shared;
 mod type_serializers;
@@ -211,6 +221,59 @@ impl SchemaSerializer {
         Ok(py_bytes.into())
     }
 
+    #[allow(clippy::too_many_arguments)]
+    #[pyo3(signature = (value, fp, *, indent = None, include = None, exclude = None, by_alias = true,
+        exclude_unset = false, exclude_defaults = false, exclude_none = false, round_trip = false, warnings = WarningsArg::Bool(true),
+        fallback = None, serialize_as_any = false, context = None))]
+    pub fn to_json_into(
+        &self,
+        py: Python,
+        value: &Bound<'_, PyAny>,
+        fp: &Bound<'_, PyAny>,
+        indent: Option<usize>,
+        include: Option<&Bound<'_, PyAny>>,
+        exclude: Option<&Bound<'_, PyAny>>,
+        by_alias: bool,
+        exclude_unset: bool,
+        exclude_defaults: bool,
+        exclude_none: bool,
+        round_trip: bool,
+        warnings: WarningsArg,
+        fallback: Option<&Bound<'_, PyAny>>,
+        serialize_as_any: bool,
+        context: Option<&Bound<'_, PyAny>>,
+    ) -> PyResult<()> {
+        let warnings_mode = match warnings {
+            WarningsArg::Bool(b) => b.into(),
+            WarningsArg::Literal(mode) => mode,
+        };
+        let warnings = CollectWarnings::new(warnings_mode);
+        let rec_guard = SerRecursionState::default();
+        let duck_typing_ser_mode = DuckTypingSerMode::from_bool(serialize_as_any);
+        let extra = self.build_extra(
+            py,
+            &SerMode::Json,
+            by_alias,
+            &warnings,
+            exclude_unset,
+            exclude_defaults,
+            exclude_none,
+            round_trip,
+            &rec_guard,
+            false,
+            fallback,
+            duck_typing_ser_mode,
+            context,
+        );
+        let mut writer = PyFileWriter::new(fp);
+        if let Err(err) = to_json_writer(value, &self.serializer, include, exclude, &extra, indent, &mut writer) {
+            return Err(writer.take_error(err));
+        }
+        writer.finish()?;
+
+        warnings.final_check(py)
+    }
+
     pub fn __reduce__(slf: &Bound<Self>) -> PyResult<(PyObject, (PyObject, PyObject))> {
         // Enables support for `pickle` serialization.
         let py = slf.py();
@@ -284,7 +347,7 @@ pub fn to_json(
 #[pyfunction]
 #[pyo3(signature = (value, *, include = None, exclude = None, by_alias = true, exclude_none = false, round_trip = false,
     timedelta_mode = "iso8601", bytes_mode = "utf8", inf_nan_mode = "constants", serialize_unknown = false, fallback = None,
-    serialize_as_any = false, context = None))]
+    serialize_as_any = false, context = None, keep_native = None, max_depth = None, max_items = None))]
 pub fn to_jsonable_python(
     py: Python,
     value: &Bound<'_, PyAny>,
@@ -300,10 +363,13 @@ pub fn to_jsonable_python(
     fallback: Option<&Bound<'_, PyAny>>,
     serialize_as_any: bool,
     context: Option<&Bound<'_, PyAny>>,
+    keep_native: Option<HashSet<String, RandomState>>,
+    max_depth: Option<usize>,
+    max_items: Option<usize>,
 ) -> PyResult<PyObject> {
     let state = SerializationState::new(timedelta_mode, bytes_mode, inf_nan_mode)?;
     let duck_typing_ser_mode = DuckTypingSerMode::from_bool(serialize_as_any);
-    let extra = state.extra(
+    let mut extra = state.extra(
         py,
         &SerMode::Json,
         by_alias,
@@ -314,7 +380,51 @@ pub fn to_jsonable_python(
         duck_typing_ser_mode,
         context,
     );
+    extra.keep_native = KeepNative::from_names(keep_native)?;
+    extra.truncate = Truncate::new(max_depth, max_items);
     let v = infer::infer_to_python(value, include, exclude, &extra)?;
     state.final_check(py)?;
     Ok(v)
 }
+
+#[allow(clippy::too_many_arguments)]
+#[pyfunction]
+#[pyo3(signature = (value, *, include = None, exclude = None, by_alias = true, exclude_none = false, round_trip = false,
+    timedelta_mode = "iso8601", inf_nan_mode = "constants", serialize_unknown = false, fallback = None,
+    serialize_as_any = false, context = None, datetime_mode = "iso8601"))]
+pub fn to_cbor(
+    py: Python,
+    value: &Bound<'_, PyAny>,
+    include: Option<&Bound<'_, PyAny>>,
+    exclude: Option<&Bound<'_, PyAny>>,
+    by_alias: bool,
+    exclude_none: bool,
+    round_trip: bool,
+    timedelta_mode: &str,
+    inf_nan_mode: &str,
+    serialize_unknown: bool,
+    fallback: Option<&Bound<'_, PyAny>>,
+    serialize_as_any: bool,
+    context: Option<&Bound<'_, PyAny>>,
+    datetime_mode: &str,
+) -> PyResult<PyObject> {
+    let datetime_mode = cbor::CborDatetimeMode::from_str(datetime_mode)?;
+    let state = SerializationState::new(timedelta_mode, "utf8", inf_nan_mode)?;
+    let duck_typing_ser_mode = DuckTypingSerMode::from_bool(serialize_as_any);
+    let mut extra = state.extra(
+        py,
+        &SerMode::Json,
+        by_alias,
+        exclude_none,
+        round_trip,
+        serialize_unknown,
+        fallback,
+        duck_typing_ser_mode,
+        context,
+    );
+    extra.keep_native = KeepNative::from_ob_types(&cbor::CBOR_NATIVE);
+    let v = infer::infer_to_python(value, include, exclude, &extra)?;
+    state.final_check(py)?;
+    let bytes = cbor::to_cbor_bytes(v.bind(py), datetime_mode)?;
+    Ok(PyBytes::new_bound(py, &bytes).into())
+}
@@ -0,0 +1,66 @@
+use std::io;
+
+use pyo3::intern;
+use pyo3::prelude::*;
+use pyo3::types::PyBytes;
+
+/// Bytes are buffered and written to the file in chunks of roughly this size.
+const CHUNK_SIZE: usize = 64 * 1024;
+
+/// Writes serialized JSON to a Python binary file-like object via its `write` method, so the whole output
+/// never has to be held in memory.
+pub(crate) struct PyFileWriter<'a, 'py> {
+    file: &'a Bound<'py, PyAny>,
+    buffer: Vec<u8>,
+    // the original exception raised by `write`, if any, so it can be re-raised rather than
+    // hidden behind a serialization error
+    error: Option<PyErr>,
+}
+
+impl<'a, 'py> PyFileWriter<'a, 'py> {
+    pub fn new(file: &'a Bound<'py, PyAny>) -> Self {
+        Self {
+            file,
+            buffer: Vec::with_capacity(CHUNK_SIZE),
+            error: None,
+        }
+    }
+
+    /// Write any remaining buffered output, should be called once serialization has finished.
+    pub fn finish(mut self) -> PyResult<()> {
+        self.write_buffer()
+    }
+
+    /// If serialization failed because writing to the file failed, return that error instead.
+    pub fn take_error(&mut self, serialization_error: PyErr) -> PyErr {
+        self.error.take().unwrap_or(serialization_error)
+    }
+
+    fn write_buffer(&mut self) -> PyResult<()> {
+        if !self.buffer.is_empty() {
+            let py = self.file.py();
+            let bytes = PyBytes::new_bound(py, &self.buffer);
+            self.file.call_method1(intern!(py, "write"), (bytes,))?;
+            self.buffer.clear();
+        }
+        Ok(())
+    }
+}
+
+impl io::Write for PyFileWriter<'_, '_> {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        self.buffer.extend_from_slice(buf);
+        if self.buffer.len() >= CHUNK_SIZE {
+            self.flush()?;
+        }
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        self.write_buffer().map_err(|err| {
+            let io_error = io::Error::other(err.to_string());
+            self.error = Some(err);
+            io_error
+        })
+    }
+}
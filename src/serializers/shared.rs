@@ -3,7 +3,6 @@ use std::fmt::Debug;
 
 use pyo3::exceptions::PyTypeError;
 use pyo3::prelude::*;
-use pyo3::sync::GILOnceCell;
 use pyo3::types::{PyDict, PyString};
 use pyo3::{intern, PyTraverseError, PyVisit};
 
@@ -11,12 +10,13 @@ use enum_dispatch::enum_dispatch;
 use serde::Serialize;
 use serde_json::ser::PrettyFormatter;
 
+use crate::build_tools::expand_schema_type;
 use crate::build_tools::py_schema_err;
 use crate::build_tools::py_schema_error_type;
 use crate::definitions::DefinitionsBuilder;
 use crate::py_gc::PyGcTraverse;
 use crate::serializers::ser::PythonSerializer;
-use crate::tools::{py_err, SchemaDict};
+use crate::tools::{get_dataclass_field_marker, py_err, SchemaDict};
 
 use super::errors::se_err_py_err;
 use super::extra::Extra;
@@ -84,6 +84,8 @@ combined_serializer! {
         Function: super::type_serializers::function::FunctionPlainSerializer;
         FunctionWrap: super::type_serializers::function::FunctionWrapSerializer;
         Fields: super::fields::GeneralFieldsSerializer;
+        // `constant` is built from the main schema, like the function serializers
+        Constant: super::type_serializers::literal::ConstantSerializer;
     }
     // `find_only` is for type_serializers which are built directly via the `type` key and `find_serializer`
     // but aren't actually used for serialization, e.g. their `build` method must return another serializer
@@ -105,6 +107,10 @@ combined_serializer! {
         super::type_serializers::function::FunctionWrapSerializerBuilder;
         super::type_serializers::model::ModelFieldsBuilder;
         super::type_serializers::typed_dict::TypedDictBuilder;
+        super::type_serializers::string::EmailBuilder;
+        super::type_serializers::string::JsonStringBuilder;
+        super::type_serializers::tuple::IntervalBuilder;
+        super::type_serializers::format::FractionBuilder;
     }
     // `both` means the struct is added to both the `CombinedSerializer` enum and the match statement in
     // `find_serializer` so they can be used via a `type` str.
@@ -137,6 +143,7 @@ combined_serializer! {
         WithDefault: super::type_serializers::with_default::WithDefaultSerializer;
         Json: super::type_serializers::json::JsonSerializer;
         JsonOrPython: super::type_serializers::json_or_python::JsonOrPythonSerializer;
+        JsonRaw: super::type_serializers::json_raw::JsonRawSerializer;
         Union: super::type_serializers::union::UnionSerializer;
         Literal: super::type_serializers::literal::LiteralSerializer;
         Enum: super::type_serializers::enum_::EnumSerializer;
@@ -154,6 +161,11 @@ impl CombinedSerializer {
         let py = schema.py();
         let type_key = intern!(py, "type");
 
+        let type_: Bound<'_, PyString> = schema.get_as_req(type_key)?;
+        if let Some(expanded) = expand_schema_type(schema, type_.to_str()?)? {
+            return Self::_build(&expanded, config, definitions);
+        }
+
         if let Some(ser_schema) = schema.get_as::<Bound<'_, PyDict>>(intern!(py, "serialization"))? {
             let op_ser_type: Option<Bound<'_, PyString>> = ser_schema.get_as(type_key)?;
             match op_ser_type.as_ref().map(|py_str| py_str.to_str()).transpose()? {
@@ -179,6 +191,22 @@ impl CombinedSerializer {
                     )
                     .map_err(|err| py_schema_error_type!("Error building `function-wrap` serializer:\n  {}", err));
                 }
+                Some("format") => {
+                    // `format` is looked up like other serializers, but we also use the main schema `type`
+                    // to check the formatting string up front
+                    let serializer = Self::find_serializer("format", &ser_schema, config, definitions)?;
+                    if let CombinedSerializer::Format(format_serializer) = &serializer {
+                        format_serializer
+                            .check_formatting_string(py, type_.to_str()?)
+                            .map_err(|err| py_schema_error_type!("Error building `format` serializer:\n  {}", err))?;
+                    }
+                    return Ok(serializer);
+                }
+                Some("constant") => {
+                    // `constant` also uses the main `schema`, to find the single `expected` value of a literal
+                    return super::type_serializers::literal::ConstantSerializer::build(schema, &ser_schema)
+                        .map_err(|err| py_schema_error_type!("Error building `constant` serializer:\n  {}", err));
+                }
                 // applies to lists tuples and dicts, does not override the main schema `type`
                 Some("include-exclude-sequence" | "include-exclude-dict") => (),
                 // applies specifically to bytes, does not override the main schema `type`
@@ -193,7 +221,6 @@ impl CombinedSerializer {
             };
         }
 
-        let type_: Bound<'_, PyString> = schema.get_as_req(type_key)?;
         Self::find_serializer(type_.to_str()?, schema, config, definitions)
     }
 }
@@ -218,6 +245,7 @@ impl PyGcTraverse for CombinedSerializer {
             CombinedSerializer::Function(inner) => inner.py_gc_traverse(visit),
             CombinedSerializer::FunctionWrap(inner) => inner.py_gc_traverse(visit),
             CombinedSerializer::Fields(inner) => inner.py_gc_traverse(visit),
+            CombinedSerializer::Constant(inner) => inner.py_gc_traverse(visit),
             CombinedSerializer::None(inner) => inner.py_gc_traverse(visit),
             CombinedSerializer::Nullable(inner) => inner.py_gc_traverse(visit),
             CombinedSerializer::Int(inner) => inner.py_gc_traverse(visit),
@@ -245,6 +273,7 @@ impl PyGcTraverse for CombinedSerializer {
             CombinedSerializer::WithDefault(inner) => inner.py_gc_traverse(visit),
             CombinedSerializer::Json(inner) => inner.py_gc_traverse(visit),
             CombinedSerializer::JsonOrPython(inner) => inner.py_gc_traverse(visit),
+            CombinedSerializer::JsonRaw(inner) => inner.py_gc_traverse(visit),
             CombinedSerializer::Union(inner) => inner.py_gc_traverse(visit),
             CombinedSerializer::Literal(inner) => inner.py_gc_traverse(visit),
             CombinedSerializer::Enum(inner) => inner.py_gc_traverse(visit),
@@ -346,10 +375,23 @@ pub(crate) fn to_json_bytes(
     indent: Option<usize>,
     expected_json_size: usize,
 ) -> PyResult<Vec<u8>> {
+    let writer: Vec<u8> = Vec::with_capacity(expected_json_size);
+    to_json_writer(value, serializer, include, exclude, extra, indent, writer)
+}
+
+/// Serialize `value` as JSON into `writer`, returning the writer once serialization is complete.
+pub(crate) fn to_json_writer<W: std::io::Write>(
+    value: &Bound<'_, PyAny>,
+    serializer: &CombinedSerializer,
+    include: Option<&Bound<'_, PyAny>>,
+    exclude: Option<&Bound<'_, PyAny>>,
+    extra: &Extra,
+    indent: Option<usize>,
+    writer: W,
+) -> PyResult<W> {
     let serializer = PydanticSerializer::new(value, serializer, include, exclude, extra);
 
-    let writer: Vec<u8> = Vec::with_capacity(expected_json_size);
-    let bytes = match indent {
+    let writer = match indent {
         Some(indent) => {
             let indent = vec![b' '; indent];
             let formatter = PrettyFormatter::with_indent(&indent);
@@ -363,7 +405,7 @@ pub(crate) fn to_json_bytes(
             ser.into_inner()
         }
     };
-    Ok(bytes)
+    Ok(writer)
 }
 
 pub(super) fn any_dataclass_iter<'a, 'py>(
@@ -379,7 +421,7 @@ where
     let fields = dataclass
         .getattr(intern!(py, "__dataclass_fields__"))?
         .downcast_into::<PyDict>()?;
-    let field_type_marker = get_field_marker(py)?;
+    let field_type_marker = get_dataclass_field_marker(py)?;
 
     let next = move |(field_name, field): (Bound<'py, PyAny>, Bound<'py, PyAny>)| -> PyResult<Option<(Bound<'py, PyAny>, Bound<'py, PyAny>)>> {
         let field_type = field.getattr(intern!(py, "_field_type"))?;
@@ -393,13 +435,3 @@ where
 
     Ok((fields.iter().filter_map(move |field| next(field).transpose()), fields))
 }
-
-static DC_FIELD_MARKER: GILOnceCell<PyObject> = GILOnceCell::new();
-
-/// needed to match the logic from dataclasses.fields `tuple(f for f in fields.values() if f._field_type is _FIELD)`
-fn get_field_marker(py: Python<'_>) -> PyResult<Bound<'_, PyAny>> {
-    let field_type_marker_obj = DC_FIELD_MARKER.get_or_try_init(py, || {
-        py.import_bound("dataclasses")?.getattr("_FIELD").map(|f| f.into_py(py))
-    })?;
-    Ok(field_type_marker_obj.bind(py).clone())
-}
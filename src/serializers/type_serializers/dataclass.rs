@@ -14,7 +14,7 @@ use crate::tools::SchemaDict;
 use super::{
     infer_json_key, infer_json_key_known, infer_serialize, infer_to_python, py_err_se_err, BuildSerializer,
     CombinedSerializer, ComputedFields, Extra, FieldsMode, GeneralFieldsSerializer, ObType, SerCheck, SerField,
-    TypeSerializer,
+    Truncate, TypeSerializer,
 };
 
 pub struct DataclassArgsBuilder;
@@ -44,14 +44,18 @@ impl BuildSerializer for DataclassArgsBuilder {
             let key_py: Py<PyString> = PyString::new_bound(py, &name).into();
 
             if field_info.get_as(intern!(py, "serialization_exclude"))? == Some(true) {
-                fields.insert(name, SerField::new(py, key_py, None, None, true));
+                fields.insert(name, SerField::new(py, key_py, None, None, true, None));
             } else {
                 let schema = field_info.get_as_req(intern!(py, "schema"))?;
                 let serializer = CombinedSerializer::build(&schema, config, definitions)
                     .map_err(|e| py_schema_error_type!("Field `{}`:\n  {}", index, e))?;
 
                 let alias = field_info.get_as(intern!(py, "serialization_alias"))?;
-                fields.insert(name, SerField::new(py, key_py, alias, Some(serializer), true));
+                let omit_if_none = field_info.get_as(intern!(py, "serialization_omit_if_none"))?;
+                fields.insert(
+                    name,
+                    SerField::new(py, key_py, alias, Some(serializer), true, omit_if_none),
+                );
             }
         }
 
@@ -146,16 +150,23 @@ impl TypeSerializer for DataclassSerializer {
         if self.allow_value(value, &dc_extra)? {
             let py = value.py();
             if let CombinedSerializer::Fields(ref fields_serializer) = *self.serializer {
+                // dataclasses count towards `max_depth` like dicts
+                let Some(extra) = extra.nested() else {
+                    return Ok(Truncate::MARKER.into_py(py));
+                };
                 let output_dict = fields_serializer.main_to_python(
                     py,
                     known_dataclass_iter(&self.fields, value),
                     include,
                     exclude,
-                    dc_extra,
+                    Extra {
+                        truncate: extra.truncate,
+                        ..dc_extra
+                    },
                 )?;
 
-                fields_serializer.add_computed_fields_python(model, &output_dict, include, exclude, extra)?;
-                Ok(output_dict.into_py(py))
+                fields_serializer.add_computed_fields_python(model, &output_dict, include, exclude, &extra)?;
+                Ok(extra.truncate.limit_dict(output_dict)?.into_py(py))
             } else {
                 let inner_value = self.get_inner_value(value)?;
                 self.serializer.to_python(&inner_value, include, exclude, &dc_extra)
@@ -1,27 +1,47 @@
 use std::borrow::Cow;
 
+use pyo3::intern;
 use pyo3::prelude::*;
-use pyo3::types::{PyDate, PyDateTime, PyDict, PyTime};
+use pyo3::types::{PyDate, PyDateTime, PyDict, PyString, PyTime};
 
+use crate::build_tools::py_schema_err;
 use crate::definitions::DefinitionsBuilder;
 use crate::input::{pydate_as_date, pydatetime_as_datetime, pytime_as_time};
+use crate::tools::SchemaDict;
 use crate::PydanticSerializationUnexpectedValue;
 
 use super::{
     infer_json_key, infer_serialize, infer_to_python, py_err_se_err, BuildSerializer, CombinedSerializer, Extra,
-    SerMode, TypeSerializer,
+    ObType, SerMode, TypeSerializer,
 };
 
-pub(crate) fn datetime_to_string(py_dt: &Bound<'_, PyDateTime>) -> PyResult<String> {
-    pydatetime_as_datetime(py_dt).map(|dt| dt.to_string())
+pub(crate) fn datetime_to_string(py_dt: &Bound<'_, PyDateTime>, extra: &Extra) -> PyResult<String> {
+    let dt = pydatetime_as_datetime(py_dt)?;
+    check_offset_minutes(dt.time.tz_offset, "datetime", extra);
+    Ok(dt.to_string())
 }
 
 pub(crate) fn date_to_string(py_date: &Bound<'_, PyDate>) -> PyResult<String> {
     pydate_as_date(py_date).map(|dt| dt.to_string())
 }
 
-pub(crate) fn time_to_string(py_time: &Bound<'_, PyTime>) -> PyResult<String> {
-    pytime_as_time(py_time, None).map(|dt| dt.to_string())
+pub(crate) fn time_to_string(py_time: &Bound<'_, PyTime>, extra: &Extra) -> PyResult<String> {
+    let t = pytime_as_time(py_time, None)?;
+    check_offset_minutes(t.tz_offset, "time", extra);
+    Ok(t.to_string())
+}
+
+/// ISO 8601 offsets only have minute precision, so the seconds of an offset like `+00:19:32` (seen in
+/// some historical time zones) are dropped when serializing and the value won't round-trip, warn about it.
+fn check_offset_minutes(tz_offset: Option<i32>, type_name: &str, extra: &Extra) {
+    if let Some(tz_offset) = tz_offset {
+        if tz_offset % 60 != 0 {
+            extra.warnings.custom_warning(format!(
+                "`{type_name}` has a UTC offset of {tz_offset} seconds which isn't a whole number of minutes, \
+                 the seconds are dropped when serializing to JSON"
+            ));
+        }
+    }
 }
 
 fn downcast_date_reject_datetime<'a, 'py>(py_date: &'a Bound<'py, PyAny>) -> PyResult<&'a Bound<'py, PyDate>> {
@@ -37,7 +57,7 @@ fn downcast_date_reject_datetime<'a, 'py>(py_date: &'a Bound<'py, PyAny>) -> PyR
 }
 
 macro_rules! build_serializer {
-    ($struct_name:ident, $expected_type:literal, $downcast:path, $convert_func:ident $(, $json_check_func:ident)?) => {
+    ($struct_name:ident, $expected_type:literal, $ob_type:expr, $downcast:path, |$value:ident, $extra:ident| $convert:expr) => {
         #[derive(Debug, Clone)]
         pub struct $struct_name;
 
@@ -53,6 +73,10 @@ macro_rules! build_serializer {
             }
         }
 
+        build_serializer!(@type_serializer $struct_name, $ob_type, $downcast, |_serializer, $value, $extra| $convert);
+    };
+    // `$convert` converts `$value` to a string, it can refer to the serializer as `$serializer`
+    (@type_serializer $struct_name:ident, $ob_type:expr, $downcast:path, |$serializer:ident, $value:ident, $extra:ident| $convert:expr) => {
         impl_py_gc_traverse!($struct_name {});
 
         impl TypeSerializer for $struct_name {
@@ -66,8 +90,9 @@ macro_rules! build_serializer {
                 let py = value.py();
                 match $downcast(value) {
                     Ok(py_value) => match extra.mode {
-                        SerMode::Json => {
-                            let s = $convert_func(py_value)?;
+                        SerMode::Json if !extra.keep_native.contains($ob_type) => {
+                            let ($serializer, $value, $extra) = (self, py_value, extra);
+                            let s = $convert?;
                             Ok(s.into_py(py))
                         }
                         _ => Ok(value.into_py(py)),
@@ -81,7 +106,10 @@ macro_rules! build_serializer {
 
             fn json_key<'a>(&self, key: &'a Bound<'_, PyAny>, extra: &Extra) -> PyResult<Cow<'a, str>> {
                 match $downcast(key) {
-                    Ok(py_value) => Ok(Cow::Owned($convert_func(py_value)?)),
+                    Ok(py_value) => {
+                        let ($serializer, $value, $extra) = (self, py_value, extra);
+                        Ok(Cow::Owned($convert?))
+                    }
                     Err(_) => {
                         extra.warnings.on_fallback_py(self.get_name(), key, extra)?;
                         infer_json_key(key, extra)
@@ -99,7 +127,8 @@ macro_rules! build_serializer {
             ) -> Result<S::Ok, S::Error> {
                 match $downcast(value) {
                     Ok(py_value) => {
-                        let s = $convert_func(py_value).map_err(py_err_se_err)?;
+                        let ($serializer, $value, $extra) = (self, py_value, extra);
+                        let s = $convert.map_err(py_err_se_err)?;
                         serializer.serialize_str(&s)
                     }
                     Err(_) => {
@@ -118,11 +147,104 @@ macro_rules! build_serializer {
     };
 }
 
+/// How much of the time to include when serializing a datetime, like `timespec` in `datetime.isoformat()`
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum TimeSpec {
+    /// Microseconds are included only if they're not zero
+    #[default]
+    Auto,
+    Seconds,
+    Milliseconds,
+    Microseconds,
+}
+
+impl TimeSpec {
+    fn from_schema(schema: &Bound<'_, PyDict>) -> PyResult<Self> {
+        match schema.get_as::<Bound<'_, PyString>>(intern!(schema.py(), "timespec"))? {
+            Some(s) => match s.to_str()? {
+                "auto" => Ok(Self::Auto),
+                "seconds" => Ok(Self::Seconds),
+                "milliseconds" => Ok(Self::Milliseconds),
+                "microseconds" => Ok(Self::Microseconds),
+                s => py_schema_err!(
+                    "Invalid timespec {:?}, should be 'auto', 'seconds', 'milliseconds' or 'microseconds'",
+                    s
+                ),
+            },
+            None => Ok(Self::Auto),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct DatetimeSerializer {
+    timespec: TimeSpec,
+    use_z: bool,
+}
+
+impl BuildSerializer for DatetimeSerializer {
+    const EXPECTED_TYPE: &'static str = "datetime";
+
+    fn build(
+        schema: &Bound<'_, PyDict>,
+        _config: Option<&Bound<'_, PyDict>>,
+        _definitions: &mut DefinitionsBuilder<CombinedSerializer>,
+    ) -> PyResult<CombinedSerializer> {
+        Ok(Self {
+            timespec: TimeSpec::from_schema(schema)?,
+            use_z: schema.get_as(intern!(schema.py(), "use_z"))?.unwrap_or(true),
+        }
+        .into())
+    }
+}
+
+impl DatetimeSerializer {
+    fn to_iso_string(&self, py_dt: &Bound<'_, PyDateTime>, extra: &Extra) -> PyResult<String> {
+        if self.timespec == TimeSpec::Auto && self.use_z {
+            return datetime_to_string(py_dt, extra);
+        }
+        let dt = pydatetime_as_datetime(py_dt)?;
+        check_offset_minutes(dt.time.tz_offset, "datetime", extra);
+        let time = &dt.time;
+        let fraction = match self.timespec {
+            TimeSpec::Auto if time.microsecond == 0 => String::new(),
+            TimeSpec::Auto | TimeSpec::Microseconds => format!(".{:06}", time.microsecond),
+            TimeSpec::Milliseconds => format!(".{:03}", time.microsecond / 1000),
+            TimeSpec::Seconds => String::new(),
+        };
+        let offset = match time.tz_offset {
+            Some(0) if self.use_z => "Z".to_string(),
+            Some(tz_offset) => {
+                let sign = if tz_offset < 0 { '-' } else { '+' };
+                let total_minutes = tz_offset.unsigned_abs() / 60;
+                format!("{sign}{:02}:{:02}", total_minutes / 60, total_minutes % 60)
+            }
+            None => String::new(),
+        };
+        Ok(format!(
+            "{}T{:02}:{:02}:{:02}{fraction}{offset}",
+            dt.date, time.hour, time.minute, time.second
+        ))
+    }
+}
+
 build_serializer!(
-    DatetimeSerializer,
-    "datetime",
+    @type_serializer DatetimeSerializer,
+    ObType::Datetime,
     PyAnyMethods::downcast::<PyDateTime>,
-    datetime_to_string
+    |serializer, py_dt, extra| serializer.to_iso_string(py_dt, extra)
+);
+build_serializer!(
+    DateSerializer,
+    "date",
+    ObType::Date,
+    downcast_date_reject_datetime,
+    |py_date, _extra| date_to_string(py_date)
+);
+build_serializer!(
+    TimeSerializer,
+    "time",
+    ObType::Time,
+    PyAnyMethods::downcast::<PyTime>,
+    |py_time, extra| time_to_string(py_time, extra)
 );
-build_serializer!(DateSerializer, "date", downcast_date_reject_datetime, date_to_string);
-build_serializer!(TimeSerializer, "time", PyAnyMethods::downcast::<PyTime>, time_to_string);
@@ -2,25 +2,50 @@ use std::borrow::Cow;
 
 use pyo3::intern;
 use pyo3::prelude::*;
-use pyo3::types::PyDict;
+use pyo3::types::{PyDict, PyString};
 
 use serde::ser::SerializeMap;
 
+use crate::build_tools::py_schema_err;
 use crate::definitions::DefinitionsBuilder;
-use crate::tools::SchemaDict;
+use crate::tools::{safe_repr, SchemaDict};
 
 use super::any::AnySerializer;
 use super::{
-    infer_serialize, infer_to_python, py_err_se_err, BuildSerializer, CombinedSerializer, Extra, PydanticSerializer,
-    SchemaFilter, SerMode, TypeSerializer,
+    infer_serialize, infer_to_python, py_err_se_err, BuildSerializer, CombinedSerializer, Extra,
+    PydanticSerializationError, PydanticSerializer, SchemaFilter, SerMode, Truncate, TypeSerializer,
 };
 
+/// How keys which aren't strings are handled when serializing to JSON.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum JsonKeyMode {
+    /// Convert the key to a string, the default
+    Str,
+    /// Raise an error
+    Error,
+}
+
+impl JsonKeyMode {
+    fn from_py(schema: &Bound<'_, PyDict>) -> PyResult<Self> {
+        let py = schema.py();
+        match schema.get_as::<Bound<'_, PyString>>(intern!(py, "json_key_mode"))? {
+            Some(s) => match s.to_str()? {
+                "str" => Ok(Self::Str),
+                "error" => Ok(Self::Error),
+                s => py_schema_err!("Invalid json_key_mode {:?}, should be 'str' or 'error'", s),
+            },
+            None => Ok(Self::Str),
+        }
+    }
+}
+
 #[derive(Debug, Clone)]
 pub struct DictSerializer {
     key_serializer: Box<CombinedSerializer>,
     value_serializer: Box<CombinedSerializer>,
     // isize because we look up include exclude via `.hash()` which returns an isize
     filter: SchemaFilter<isize>,
+    json_key_mode: JsonKeyMode,
     name: String,
 }
 
@@ -59,12 +84,26 @@ impl BuildSerializer for DictSerializer {
             key_serializer: Box::new(key_serializer),
             value_serializer: Box::new(value_serializer),
             filter,
+            json_key_mode: JsonKeyMode::from_py(schema)?,
             name,
         }
         .into())
     }
 }
 
+impl DictSerializer {
+    /// Serialize a key of the dict being serialized to JSON.
+    fn serialize_json_key<'a>(&self, key: &'a Bound<'_, PyAny>, extra: &Extra) -> PyResult<Cow<'a, str>> {
+        if self.json_key_mode == JsonKeyMode::Error && !key.is_instance_of::<PyString>() {
+            return Err(PydanticSerializationError::new_err(format!(
+                "Dict key {} is not a string, keys must be strings when serializing to JSON with json_key_mode='error'",
+                safe_repr(key)
+            )));
+        }
+        self.key_serializer.json_key(key, extra)
+    }
+}
+
 impl_py_gc_traverse!(DictSerializer {
     key_serializer,
     value_serializer
@@ -81,14 +120,23 @@ impl TypeSerializer for DictSerializer {
         let py = value.py();
         match value.downcast::<PyDict>() {
             Ok(py_dict) => {
+                let Some(extra) = extra.nested() else {
+                    return Ok(Truncate::MARKER.into_py(py));
+                };
+                let extra = extra.as_ref();
                 let value_serializer = self.value_serializer.as_ref();
+                let max_items = extra.truncate.max_items();
 
                 let new_dict = PyDict::new_bound(py);
                 for (key, value) in py_dict.iter() {
                     let op_next = self.filter.key_filter(&key, include, exclude)?;
                     if let Some((next_include, next_exclude)) = op_next {
+                        if new_dict.len() == max_items {
+                            new_dict.set_item(Truncate::MARKER, py.None())?;
+                            break;
+                        }
                         let key = match extra.mode {
-                            SerMode::Json => self.key_serializer.json_key(&key, extra)?.into_py(py),
+                            SerMode::Json => self.serialize_json_key(&key, extra)?.into_py(py),
                             _ => self.key_serializer.to_python(&key, None, None, extra)?,
                         };
                         let value =
@@ -120,13 +168,12 @@ impl TypeSerializer for DictSerializer {
         match value.downcast::<PyDict>() {
             Ok(py_dict) => {
                 let mut map = serializer.serialize_map(Some(py_dict.len()))?;
-                let key_serializer = self.key_serializer.as_ref();
                 let value_serializer = self.value_serializer.as_ref();
 
                 for (key, value) in py_dict.iter() {
                     let op_next = self.filter.key_filter(&key, include, exclude).map_err(py_err_se_err)?;
                     if let Some((next_include, next_exclude)) = op_next {
-                        let key = key_serializer.json_key(&key, extra).map_err(py_err_se_err)?;
+                        let key = self.serialize_json_key(&key, extra).map_err(py_err_se_err)?;
                         let value_serialize = PydanticSerializer::new(
                             &value,
                             value_serializer,
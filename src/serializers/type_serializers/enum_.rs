@@ -1,11 +1,13 @@
 use std::borrow::Cow;
+use std::str::FromStr;
 
 use crate::build_tools::py_schema_err;
 use pyo3::intern;
 use pyo3::prelude::*;
-use pyo3::types::{PyDict, PyType};
+use pyo3::types::{PyDict, PyString, PyType};
 
 use crate::definitions::DefinitionsBuilder;
+use crate::serializers::config::EnumMode;
 use crate::serializers::errors::py_err_se_err;
 use crate::serializers::infer::{infer_json_key, infer_serialize, infer_to_python};
 use crate::tools::SchemaDict;
@@ -19,6 +21,8 @@ use super::{BuildSerializer, CombinedSerializer, Extra, TypeSerializer};
 pub struct EnumSerializer {
     class: Py<PyType>,
     serializer: Option<Box<CombinedSerializer>>,
+    /// overrides the `ser_json_enum` config for this enum
+    enum_mode: Option<EnumMode>,
 }
 
 impl BuildSerializer for EnumSerializer {
@@ -32,15 +36,20 @@ impl BuildSerializer for EnumSerializer {
         let sub_type: Option<String> = schema.get_as(intern!(schema.py(), "sub_type"))?;
 
         let serializer = match sub_type.as_deref() {
-            Some("int") => Some(Box::new(IntSerializer::new().into())),
+            Some("int") => Some(Box::new(IntSerializer::new(schema.py(), config)?.into())),
             Some("str") => Some(Box::new(StrSerializer::new().into())),
             Some("float") => Some(Box::new(FloatSerializer::new(schema.py(), config)?.into())),
             Some(_) => return py_schema_err!("`sub_type` must be one of: 'int', 'str', 'float' or None"),
             None => None,
         };
+        let enum_mode = schema
+            .get_as::<Bound<'_, PyString>>(intern!(schema.py(), "ser_json_enum"))?
+            .map(|mode| EnumMode::from_str(&mode.to_cow()?))
+            .transpose()?;
         Ok(Self {
             class: schema.get_as_req(intern!(schema.py(), "cls"))?,
             serializer,
+            enum_mode,
         }
         .into())
     }
@@ -48,6 +57,12 @@ impl BuildSerializer for EnumSerializer {
 
 impl_py_gc_traverse!(EnumSerializer { serializer });
 
+impl EnumSerializer {
+    fn enum_mode<'e>(&'e self, extra: &'e Extra) -> &'e EnumMode {
+        self.enum_mode.as_ref().unwrap_or(&extra.config.enum_mode)
+    }
+}
+
 impl TypeSerializer for EnumSerializer {
     fn to_python(
         &self,
@@ -58,8 +73,11 @@ impl TypeSerializer for EnumSerializer {
     ) -> PyResult<PyObject> {
         let py = value.py();
         if value.is_exact_instance(self.class.bind(py)) {
-            // if we're in JSON mode, we need to get the value attribute and serialize that
+            // if we're in JSON mode, we need to get the value (or name) attribute and serialize that
             if extra.mode.is_json() {
+                if *self.enum_mode(extra) == EnumMode::Name {
+                    return Ok(value.getattr(intern!(py, "name"))?.into_py(py));
+                }
                 let dot_value = value.getattr(intern!(py, "value"))?;
                 match self.serializer {
                     Some(ref s) => s.to_python(&dot_value, include, exclude, extra),
@@ -78,6 +96,9 @@ impl TypeSerializer for EnumSerializer {
     fn json_key<'a>(&self, key: &'a Bound<'_, PyAny>, extra: &Extra) -> PyResult<Cow<'a, str>> {
         let py = key.py();
         if key.is_exact_instance(self.class.bind(py)) {
+            if *self.enum_mode(extra) == EnumMode::Name {
+                return Ok(Cow::Owned(key.getattr(intern!(py, "name"))?.extract()?));
+            }
             let dot_value = key.getattr(intern!(py, "value"))?;
             let k = match self.serializer {
                 Some(ref s) => s.json_key(&dot_value, extra),
@@ -101,6 +122,11 @@ impl TypeSerializer for EnumSerializer {
         extra: &Extra,
     ) -> Result<S::Ok, S::Error> {
         if value.is_exact_instance(self.class.bind(value.py())) {
+            if *self.enum_mode(extra) == EnumMode::Name {
+                let name = value.getattr(intern!(value.py(), "name")).map_err(py_err_se_err)?;
+                let name = name.downcast::<PyString>().map_err(py_err_se_err)?;
+                return serializer.serialize_str(&name.to_cow().map_err(py_err_se_err)?);
+            }
             let dot_value = value.getattr(intern!(value.py(), "value")).map_err(py_err_se_err)?;
             match self.serializer {
                 Some(ref s) => s.serde_serialize(&dot_value, serializer, include, exclude, extra),
@@ -2,7 +2,7 @@ use std::borrow::Cow;
 
 use pyo3::intern;
 use pyo3::prelude::*;
-use pyo3::types::{PyDict, PyString};
+use pyo3::types::{PyDate, PyDateTime, PyDict, PyString, PyTime};
 
 use serde::ser::Error;
 
@@ -88,6 +88,23 @@ impl BuildSerializer for FormatSerializer {
 }
 
 impl FormatSerializer {
+    /// Check the formatting string against a sample value of the main schema `type` so invalid format specs
+    /// are reported when the serializer is built rather than on first use; types we can't sample are skipped.
+    pub fn check_formatting_string(&self, py: Python, schema_type: &str) -> PyResult<()> {
+        let sample: PyObject = match schema_type {
+            "int" => 0_i64.into_py(py),
+            "float" => 0_f64.into_py(py),
+            "date" => PyDate::new_bound(py, 2000, 1, 1)?.into_py(py),
+            "time" => PyTime::new_bound(py, 0, 0, 0, 0, None)?.into_py(py),
+            "datetime" => PyDateTime::new_bound(py, 2000, 1, 1, 0, 0, 0, 0, None)?.into_py(py),
+            _ => return Ok(()),
+        };
+        match self.call(sample.bind(py)) {
+            Ok(_) => Ok(()),
+            Err(e) => py_schema_err!("Invalid formatting string for `{}` schema: {}", schema_type, e),
+        }
+    }
+
     fn call(&self, value: &Bound<'_, PyAny>) -> Result<PyObject, String> {
         let py = value.py();
         self.format_func
@@ -183,6 +200,21 @@ impl BuildSerializer for ToStringSerializer {
 
 impl_py_gc_traverse!(ToStringSerializer {});
 
+/// Fractions are validated to `Fraction`, so are serialized as strings like `"3/4"` in JSON mode.
+pub struct FractionBuilder;
+
+impl BuildSerializer for FractionBuilder {
+    const EXPECTED_TYPE: &'static str = "fraction";
+
+    fn build(
+        schema: &Bound<'_, PyDict>,
+        config: Option<&Bound<'_, PyDict>>,
+        definitions: &mut DefinitionsBuilder<CombinedSerializer>,
+    ) -> PyResult<CombinedSerializer> {
+        ToStringSerializer::build(schema, config, definitions)
+    }
+}
+
 impl TypeSerializer for ToStringSerializer {
     fn to_python(
         &self,
@@ -14,7 +14,7 @@ use crate::tools::SchemaDict;
 use super::any::AnySerializer;
 use super::{
     infer_serialize, infer_to_python, py_err_se_err, BuildSerializer, CombinedSerializer, Extra, ExtraOwned,
-    PydanticSerializer, SchemaFilter, SerMode, TypeSerializer,
+    PydanticSerializer, SchemaFilter, SerMode, Truncate, TypeSerializer,
 };
 
 #[derive(Debug, Clone)]
@@ -59,7 +59,12 @@ impl TypeSerializer for GeneratorSerializer {
                 let py = value.py();
                 match extra.mode {
                     SerMode::Json => {
+                        let Some(extra) = extra.nested() else {
+                            return Ok(Truncate::MARKER.into_py(py));
+                        };
+                        let extra = extra.as_ref();
                         let item_serializer = self.item_serializer.as_ref();
+                        let max_items = extra.truncate.max_items();
 
                         let mut items = match value.len() {
                             Ok(len) => Vec::with_capacity(len),
@@ -69,6 +74,10 @@ impl TypeSerializer for GeneratorSerializer {
                             let element = iter_result?;
                             let op_next = self.filter.index_filter(index, include, exclude, None)?;
                             if let Some((next_include, next_exclude)) = op_next {
+                                if items.len() == max_items {
+                                    items.push(Truncate::MARKER.into_py(py));
+                                    break;
+                                }
                                 items.push(item_serializer.to_python(
                                     &element,
                                     next_include.as_ref(),
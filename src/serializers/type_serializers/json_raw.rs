@@ -0,0 +1,84 @@
+use std::borrow::Cow;
+
+use pyo3::intern;
+use pyo3::prelude::*;
+use pyo3::types::PyDict;
+
+use crate::definitions::DefinitionsBuilder;
+use crate::tools::SchemaDict;
+use crate::validators::JsonRaw;
+
+use super::{BuildSerializer, CombinedSerializer, Extra, TypeSerializer};
+
+/// Serializes the value of a `JsonRaw` returned by a `json-raw` validator, any other value is passed to the
+/// inner serializer unchanged.
+#[derive(Debug, Clone)]
+pub struct JsonRawSerializer {
+    serializer: Box<CombinedSerializer>,
+}
+
+impl BuildSerializer for JsonRawSerializer {
+    const EXPECTED_TYPE: &'static str = "json-raw";
+
+    fn build(
+        schema: &Bound<'_, PyDict>,
+        config: Option<&Bound<'_, PyDict>>,
+        definitions: &mut DefinitionsBuilder<CombinedSerializer>,
+    ) -> PyResult<CombinedSerializer> {
+        let sub_schema = schema.get_as_req(intern!(schema.py(), "schema"))?;
+        Ok(Self {
+            serializer: Box::new(CombinedSerializer::build(&sub_schema, config, definitions)?),
+        }
+        .into())
+    }
+}
+
+impl_py_gc_traverse!(JsonRawSerializer { serializer });
+
+fn unwrap_json_raw<'py>(value: &Bound<'py, PyAny>) -> Bound<'py, PyAny> {
+    match value.downcast::<JsonRaw>() {
+        Ok(json_raw) => json_raw.get().value().bind(value.py()).clone(),
+        Err(_) => value.clone(),
+    }
+}
+
+impl TypeSerializer for JsonRawSerializer {
+    fn to_python(
+        &self,
+        value: &Bound<'_, PyAny>,
+        include: Option<&Bound<'_, PyAny>>,
+        exclude: Option<&Bound<'_, PyAny>>,
+        extra: &Extra,
+    ) -> PyResult<PyObject> {
+        self.serializer
+            .to_python(&unwrap_json_raw(value), include, exclude, extra)
+    }
+
+    fn json_key<'a>(&self, key: &'a Bound<'_, PyAny>, extra: &Extra) -> PyResult<Cow<'a, str>> {
+        let key = match key.downcast::<JsonRaw>() {
+            Ok(json_raw) => json_raw.get().value().bind(key.py()),
+            Err(_) => key,
+        };
+        self.serializer.json_key(key, extra)
+    }
+
+    fn serde_serialize<S: serde::ser::Serializer>(
+        &self,
+        value: &Bound<'_, PyAny>,
+        serializer: S,
+        include: Option<&Bound<'_, PyAny>>,
+        exclude: Option<&Bound<'_, PyAny>>,
+        extra: &Extra,
+    ) -> Result<S::Ok, S::Error> {
+        self.serializer
+            .serde_serialize(&unwrap_json_raw(value), serializer, include, exclude, extra)
+    }
+
+    fn get_name(&self) -> &str {
+        Self::EXPECTED_TYPE
+    }
+
+    fn retry_with_lax_check(&self) -> bool {
+        self.serializer.retry_with_lax_check()
+    }
+}
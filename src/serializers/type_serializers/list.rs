@@ -12,11 +12,12 @@ use crate::tools::SchemaDict;
 use super::any::AnySerializer;
 use super::{
     infer_serialize, infer_to_python, py_err_se_err, BuildSerializer, CombinedSerializer, Extra, PydanticSerializer,
-    SchemaFilter, TypeSerializer,
+    SchemaFilter, Truncate, TypeSerializer,
 };
 
 #[derive(Debug, Clone)]
 pub struct ListSerializer {
+    prefix_serializers: Vec<CombinedSerializer>,
     item_serializer: Box<CombinedSerializer>,
     filter: SchemaFilter<usize>,
     name: String,
@@ -35,8 +36,26 @@ impl BuildSerializer for ListSerializer {
             Some(items_schema) => CombinedSerializer::build(&items_schema, config, definitions)?,
             None => AnySerializer::build(schema, config, definitions)?,
         };
-        let name = format!("{}[{}]", Self::EXPECTED_TYPE, item_serializer.get_name());
+        let prefix_serializers = match schema.get_as::<Bound<'_, PyList>>(intern!(py, "prefix_items"))? {
+            Some(prefix_items) => prefix_items
+                .iter()
+                .map(|item| CombinedSerializer::build(item.downcast()?, config, definitions))
+                .collect::<PyResult<Vec<_>>>()?,
+            None => Vec::new(),
+        };
+        let name = if prefix_serializers.is_empty() {
+            format!("{}[{}]", Self::EXPECTED_TYPE, item_serializer.get_name())
+        } else {
+            let prefix_names = prefix_serializers.iter().map(TypeSerializer::get_name);
+            format!(
+                "{}[{}, *{}]",
+                Self::EXPECTED_TYPE,
+                prefix_names.collect::<Vec<_>>().join(", "),
+                item_serializer.get_name()
+            )
+        };
         Ok(Self {
+            prefix_serializers,
             item_serializer: Box::new(item_serializer),
             filter: SchemaFilter::from_schema(schema)?,
             name,
@@ -45,7 +64,10 @@ impl BuildSerializer for ListSerializer {
     }
 }
 
-impl_py_gc_traverse!(ListSerializer { item_serializer });
+impl_py_gc_traverse!(ListSerializer {
+    prefix_serializers,
+    item_serializer
+});
 
 impl TypeSerializer for ListSerializer {
     fn to_python(
@@ -58,12 +80,22 @@ impl TypeSerializer for ListSerializer {
         match value.downcast::<PyList>() {
             Ok(py_list) => {
                 let py = value.py();
+                let Some(extra) = extra.nested() else {
+                    return Ok(Truncate::MARKER.into_py(py));
+                };
+                let extra = extra.as_ref();
                 let item_serializer = self.item_serializer.as_ref();
+                let max_items = extra.truncate.max_items();
 
                 let mut items = Vec::with_capacity(py_list.len());
                 for (index, element) in py_list.iter().enumerate() {
                     let op_next = self.filter.index_filter(index, include, exclude, value.len().ok())?;
                     if let Some((next_include, next_exclude)) = op_next {
+                        if items.len() == max_items {
+                            items.push(Truncate::MARKER.into_py(py));
+                            break;
+                        }
+                        let item_serializer = self.prefix_serializers.get(index).unwrap_or(item_serializer);
                         items.push(item_serializer.to_python(
                             &element,
                             next_include.as_ref(),
@@ -104,6 +136,7 @@ impl TypeSerializer for ListSerializer {
                         .index_filter(index, include, exclude, Some(py_list.len()))
                         .map_err(py_err_se_err)?;
                     if let Some((next_include, next_exclude)) = op_next {
+                        let item_serializer = self.prefix_serializers.get(index).unwrap_or(item_serializer);
                         let item_serialize = PydanticSerializer::new(
                             &element,
                             item_serializer,
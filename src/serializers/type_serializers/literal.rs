@@ -169,3 +169,99 @@ impl TypeSerializer for LiteralSerializer {
         &self.name
     }
 }
+
+/// Serializes a `literal` schema with a single expected value as that value without reading the instance, set
+/// with a `constant` serialization schema.
+#[derive(Debug, Clone)]
+pub struct ConstantSerializer {
+    constant: PyObject,
+    check: bool,
+    name: String,
+}
+
+impl ConstantSerializer {
+    pub fn build(schema: &Bound<'_, PyDict>, ser_schema: &Bound<'_, PyDict>) -> PyResult<CombinedSerializer> {
+        let py = schema.py();
+        let type_: String = schema.get_as_req(intern!(py, "type"))?;
+        let expected: Option<Bound<'_, PyList>> = schema.get_as(intern!(py, "expected"))?;
+        let constant = match expected {
+            Some(expected) if type_ == LiteralSerializer::EXPECTED_TYPE && expected.len() == 1 => {
+                expected.get_item(0)?
+            }
+            _ => return py_schema_err!("`constant` serialization requires a `literal` schema with one expected value"),
+        };
+        Ok(Self {
+            name: format!("constant[{}]", constant.repr()?),
+            constant: constant.unbind(),
+            check: ser_schema.get_as(intern!(py, "check"))?.unwrap_or(false),
+        }
+        .into())
+    }
+
+    /// Whether `value` isn't the constant, when unions are checking which variant to use or with `check` set.
+    fn mismatch(&self, value: &Bound<'_, PyAny>, extra: &Extra) -> PyResult<bool> {
+        if self.check || extra.check.enabled() {
+            Ok(!value.eq(&self.constant)?)
+        } else {
+            Ok(false)
+        }
+    }
+
+    fn on_mismatch(&self, value: &Bound<'_, PyAny>, extra: &Extra) -> PyResult<()> {
+        if extra.check.enabled() {
+            // let unions try another variant
+            extra.warnings.on_fallback_py(self.get_name(), value, extra)
+        } else {
+            extra.warnings.custom_warning(format!(
+                "Expected `{}` but got {} - serialized the constant instead",
+                self.name,
+                value.repr()?
+            ));
+            Ok(())
+        }
+    }
+}
+
+impl_py_gc_traverse!(ConstantSerializer { constant });
+
+impl TypeSerializer for ConstantSerializer {
+    fn to_python(
+        &self,
+        value: &Bound<'_, PyAny>,
+        _include: Option<&Bound<'_, PyAny>>,
+        _exclude: Option<&Bound<'_, PyAny>>,
+        extra: &Extra,
+    ) -> PyResult<PyObject> {
+        if self.mismatch(value, extra)? {
+            self.on_mismatch(value, extra)?;
+        }
+        infer_to_python(self.constant.bind(value.py()), None, None, extra)
+    }
+
+    fn json_key<'a>(&self, key: &'a Bound<'_, PyAny>, extra: &Extra) -> PyResult<Cow<'a, str>> {
+        if self.mismatch(key, extra)? {
+            self.on_mismatch(key, extra)?;
+        }
+        Ok(Cow::Owned(
+            infer_json_key(self.constant.bind(key.py()), extra)?.into_owned(),
+        ))
+    }
+
+    fn serde_serialize<S: serde::ser::Serializer>(
+        &self,
+        value: &Bound<'_, PyAny>,
+        serializer: S,
+        _include: Option<&Bound<'_, PyAny>>,
+        _exclude: Option<&Bound<'_, PyAny>>,
+        extra: &Extra,
+    ) -> Result<S::Ok, S::Error> {
+        if self.mismatch(value, extra).map_err(py_err_se_err)? {
+            self.on_mismatch(value, extra).map_err(py_err_se_err)?;
+        }
+        infer_serialize(self.constant.bind(value.py()), serializer, None, None, extra)
+    }
+
+    fn get_name(&self) -> &str {
+        &self.name
+    }
+}
@@ -12,6 +12,7 @@ pub mod function;
 pub mod generator;
 pub mod json;
 pub mod json_or_python;
+pub mod json_raw;
 pub mod list;
 pub mod literal;
 pub mod model;
@@ -31,7 +32,7 @@ pub mod with_default;
 use super::computed_fields::ComputedFields;
 use super::config::utf8_py_error;
 use super::errors::{py_err_se_err, PydanticSerializationError};
-use super::extra::{Extra, ExtraOwned, SerCheck, SerMode};
+use super::extra::{Extra, ExtraOwned, SerCheck, SerMode, Truncate};
 use super::fields::{FieldsMode, GeneralFieldsSerializer, SerField};
 use super::filter::{AnyFilter, SchemaFilter};
 use super::infer::{infer_json_key, infer_json_key_known, infer_serialize, infer_to_python};
@@ -17,6 +17,7 @@ use crate::definitions::DefinitionsBuilder;
 use crate::serializers::errors::PydanticSerializationUnexpectedValue;
 use crate::serializers::extra::DuckTypingSerMode;
 use crate::tools::SchemaDict;
+use crate::validators::{class_has_dict, get_model_dict};
 
 const ROOT_FIELD: &str = "root";
 
@@ -54,15 +55,19 @@ impl BuildSerializer for ModelFieldsBuilder {
             let key_py: Py<PyString> = key_py.into();
 
             if field_info.get_as(intern!(py, "serialization_exclude"))? == Some(true) {
-                fields.insert(key, SerField::new(py, key_py, None, None, true));
+                fields.insert(key, SerField::new(py, key_py, None, None, true, None));
             } else {
                 let alias: Option<String> = field_info.get_as(intern!(py, "serialization_alias"))?;
+                let omit_if_none = field_info.get_as(intern!(py, "serialization_omit_if_none"))?;
 
                 let schema = field_info.get_as_req(intern!(py, "schema"))?;
                 let serializer = CombinedSerializer::build(&schema, config, definitions)
                     .map_err(|e| py_schema_error_type!("Field `{}`:\n  {}", key, e))?;
 
-                fields.insert(key, SerField::new(py, key_py, alias, Some(serializer), true));
+                fields.insert(
+                    key,
+                    SerField::new(py, key_py, alias, Some(serializer), true, omit_if_none),
+                );
             }
         }
 
@@ -79,6 +84,8 @@ pub struct ModelSerializer {
     has_extra: bool,
     root_model: bool,
     name: String,
+    // `False` for classes using `__slots__` without `__dict__`, fields are then read from the slots
+    has_dict: bool,
 }
 
 impl BuildSerializer for ModelSerializer {
@@ -99,6 +106,7 @@ impl BuildSerializer for ModelSerializer {
         let serializer = Box::new(CombinedSerializer::build(&sub_schema, config.as_ref(), definitions)?);
         let root_model = schema.get_as(intern!(py, "root_model"))?.unwrap_or(false);
         let name = class.bind(py).getattr(intern!(py, "__name__"))?.extract()?;
+        let has_dict = class_has_dict(class.bind(py))?;
 
         Ok(Self {
             class,
@@ -106,6 +114,7 @@ impl BuildSerializer for ModelSerializer {
             has_extra: has_extra(schema, config.as_ref())?,
             root_model,
             name,
+            has_dict,
         }
         .into())
     }
@@ -123,13 +132,14 @@ impl ModelSerializer {
         match extra.check {
             SerCheck::Strict => Ok(value.get_type().is(class)),
             SerCheck::Lax => value.is_instance(class),
-            SerCheck::None => value.hasattr(intern!(value.py(), "__dict__")),
+            SerCheck::None if self.has_dict => value.hasattr(intern!(value.py(), "__dict__")),
+            SerCheck::None => value.hasattr(intern!(value.py(), "__pydantic_fields_set__")),
         }
     }
 
     fn get_inner_value<'py>(&self, model: &Bound<'py, PyAny>, extra: &Extra) -> PyResult<Bound<'py, PyAny>> {
         let py = model.py();
-        let mut attrs = model.getattr(intern!(py, "__dict__"))?.downcast_into::<PyDict>()?;
+        let mut attrs = get_model_dict(model, self.has_dict)?;
 
         if extra.exclude_unset {
             let fields_set = model
@@ -12,7 +12,7 @@ use crate::tools::SchemaDict;
 use super::any::AnySerializer;
 use super::{
     infer_serialize, infer_to_python, BuildSerializer, CombinedSerializer, Extra, PydanticSerializer, SerMode,
-    TypeSerializer,
+    Truncate, TypeSerializer,
 };
 
 macro_rules! build_serializer {
@@ -58,10 +58,19 @@ macro_rules! build_serializer {
                 let py = value.py();
                 match value.downcast::<$py_type>() {
                     Ok(py_set) => {
+                        let Some(extra) = extra.nested() else {
+                            return Ok(Truncate::MARKER.into_py(py));
+                        };
+                        let extra = extra.as_ref();
                         let item_serializer = self.item_serializer.as_ref();
+                        let max_items = extra.truncate.max_items();
 
                         let mut items = Vec::with_capacity(py_set.len());
                         for element in py_set.iter() {
+                            if items.len() == max_items {
+                                items.push(Truncate::MARKER.into_py(py));
+                                break;
+                            }
                             items.push(item_serializer.to_python(&element, include, exclude, extra)?);
                         }
                         match extra.mode {
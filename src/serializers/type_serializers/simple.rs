@@ -1,3 +1,4 @@
+use pyo3::intern;
 use pyo3::prelude::*;
 use pyo3::types::PyDict;
 
@@ -5,6 +6,9 @@ use std::borrow::Cow;
 
 use serde::Serialize;
 
+use crate::build_tools::py_schema_err;
+use crate::serializers::config::{FromConfig, LargeIntMode};
+use crate::tools::SchemaDict;
 use crate::PydanticSerializationUnexpectedValue;
 use crate::{definitions::DefinitionsBuilder, input::Int};
 
@@ -182,7 +186,129 @@ pub(crate) fn to_str_json_key<'a>(key: &'a Bound<'_, PyAny>) -> PyResult<Cow<'a,
     Ok(Cow::Owned(key.str()?.to_string_lossy().into_owned()))
 }
 
-build_simple_serializer!(IntSerializer, "int", Int, ObType::Int, to_str_json_key, true);
+/// The largest integer which can be represented exactly as an IEEE 754 double,
+/// i.e. `Number.MAX_SAFE_INTEGER` in JavaScript
+const MAX_SAFE_INTEGER: u64 = 9_007_199_254_740_991;
+
+#[derive(Debug, Clone)]
+pub struct IntSerializer {
+    large_int_mode: LargeIntMode,
+    large_int_threshold: u64,
+}
+
+impl IntSerializer {
+    pub fn new(py: Python, config: Option<&Bound<'_, PyDict>>) -> PyResult<Self> {
+        let large_int_mode = LargeIntMode::from_config(config)?;
+        let large_int_threshold = match config.get_as::<i64>(intern!(py, "ser_json_large_int_threshold"))? {
+            Some(threshold) if threshold < 0 => {
+                return py_schema_err!("`ser_json_large_int_threshold` must not be negative, got {}", threshold)
+            }
+            Some(threshold) => threshold.unsigned_abs(),
+            None => MAX_SAFE_INTEGER,
+        };
+        Ok(Self {
+            large_int_mode,
+            large_int_threshold,
+        })
+    }
+
+    /// Whether `int` should be emitted as a string rather than a number when serializing to JSON
+    fn stringify(&self, int: &Int) -> bool {
+        match (&self.large_int_mode, int) {
+            (LargeIntMode::Number, _) => false,
+            (LargeIntMode::String, Int::I64(i)) => i.unsigned_abs() > self.large_int_threshold,
+            (LargeIntMode::String, Int::Big(_)) => true,
+        }
+    }
+
+    fn int_to_json(&self, py: Python, value: &Bound<'_, PyAny>) -> PyResult<PyObject> {
+        let int = value.extract::<Int>()?;
+        if self.stringify(&int) {
+            Ok(int.to_string().into_py(py))
+        } else {
+            Ok(int.to_object(py))
+        }
+    }
+}
+
+impl BuildSerializer for IntSerializer {
+    const EXPECTED_TYPE: &'static str = "int";
+
+    fn build(
+        schema: &Bound<'_, PyDict>,
+        config: Option<&Bound<'_, PyDict>>,
+        _definitions: &mut DefinitionsBuilder<CombinedSerializer>,
+    ) -> PyResult<CombinedSerializer> {
+        Self::new(schema.py(), config).map(Into::into)
+    }
+}
+
+impl_py_gc_traverse!(IntSerializer {});
+
+impl TypeSerializer for IntSerializer {
+    fn to_python(
+        &self,
+        value: &Bound<'_, PyAny>,
+        include: Option<&Bound<'_, PyAny>>,
+        exclude: Option<&Bound<'_, PyAny>>,
+        extra: &Extra,
+    ) -> PyResult<PyObject> {
+        let py = value.py();
+        match extra.ob_type_lookup.is_type(value, ObType::Int) {
+            IsType::Exact => match extra.mode {
+                SerMode::Json if self.large_int_mode == LargeIntMode::String => self.int_to_json(py, value),
+                _ => Ok(value.into_py(py)),
+            },
+            IsType::Subclass => match extra.check {
+                SerCheck::Strict => Err(PydanticSerializationUnexpectedValue::new_err(None)),
+                SerCheck::Lax | SerCheck::None => match extra.mode {
+                    SerMode::Json => self.int_to_json(py, value),
+                    _ => infer_to_python(value, include, exclude, extra),
+                },
+            },
+            IsType::False => {
+                extra.warnings.on_fallback_py(self.get_name(), value, extra)?;
+                infer_to_python(value, include, exclude, extra)
+            }
+        }
+    }
+
+    fn json_key<'a>(&self, key: &'a Bound<'_, PyAny>, extra: &Extra) -> PyResult<Cow<'a, str>> {
+        match extra.ob_type_lookup.is_type(key, ObType::Int) {
+            IsType::Exact | IsType::Subclass => to_str_json_key(key),
+            IsType::False => {
+                extra.warnings.on_fallback_py(self.get_name(), key, extra)?;
+                infer_json_key(key, extra)
+            }
+        }
+    }
+
+    fn serde_serialize<S: serde::ser::Serializer>(
+        &self,
+        value: &Bound<'_, PyAny>,
+        serializer: S,
+        include: Option<&Bound<'_, PyAny>>,
+        exclude: Option<&Bound<'_, PyAny>>,
+        extra: &Extra,
+    ) -> Result<S::Ok, S::Error> {
+        match value.extract::<Int>() {
+            Ok(v) if self.stringify(&v) => serializer.serialize_str(&v.to_string()),
+            Ok(v) => v.serialize(serializer),
+            Err(_) => {
+                extra.warnings.on_fallback_ser::<S>(self.get_name(), value, extra)?;
+                infer_serialize(value, serializer, include, exclude, extra)
+            }
+        }
+    }
+
+    fn get_name(&self) -> &str {
+        Self::EXPECTED_TYPE
+    }
+
+    fn retry_with_lax_check(&self) -> bool {
+        true
+    }
+}
 
 pub(crate) fn bool_json_key<'a>(key: &'a Bound<'_, PyAny>) -> PyResult<Cow<'a, str>> {
     let v = if key.is_truthy().unwrap_or(false) {
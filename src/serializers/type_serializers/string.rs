@@ -33,6 +33,36 @@ impl BuildSerializer for StrSerializer {
 
 impl_py_gc_traverse!(StrSerializer {});
 
+/// Email addresses are validated to `str`, so are serialized the same way.
+pub struct EmailBuilder;
+
+impl BuildSerializer for EmailBuilder {
+    const EXPECTED_TYPE: &'static str = "email";
+
+    fn build(
+        schema: &Bound<'_, PyDict>,
+        config: Option<&Bound<'_, PyDict>>,
+        definitions: &mut DefinitionsBuilder<CombinedSerializer>,
+    ) -> PyResult<CombinedSerializer> {
+        StrSerializer::build(schema, config, definitions)
+    }
+}
+
+/// `json-string` values are validated to the original `str`, so are serialized the same way.
+pub struct JsonStringBuilder;
+
+impl BuildSerializer for JsonStringBuilder {
+    const EXPECTED_TYPE: &'static str = "json-string";
+
+    fn build(
+        schema: &Bound<'_, PyDict>,
+        config: Option<&Bound<'_, PyDict>>,
+        definitions: &mut DefinitionsBuilder<CombinedSerializer>,
+    ) -> PyResult<CombinedSerializer> {
+        StrSerializer::build(schema, config, definitions)
+    }
+}
+
 impl TypeSerializer for StrSerializer {
     fn to_python(
         &self,
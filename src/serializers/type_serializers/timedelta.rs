@@ -8,7 +8,7 @@ use crate::input::EitherTimedelta;
 use crate::serializers::config::{FromConfig, TimedeltaMode};
 
 use super::{
-    infer_json_key, infer_serialize, infer_to_python, BuildSerializer, CombinedSerializer, Extra, SerMode,
+    infer_json_key, infer_serialize, infer_to_python, BuildSerializer, CombinedSerializer, Extra, ObType, SerMode,
     TypeSerializer,
 };
 
@@ -41,7 +41,7 @@ impl TypeSerializer for TimeDeltaSerializer {
         extra: &Extra,
     ) -> PyResult<PyObject> {
         match extra.mode {
-            SerMode::Json => match EitherTimedelta::try_from(value) {
+            SerMode::Json if !extra.keep_native.contains(ObType::Timedelta) => match EitherTimedelta::try_from(value) {
                 Ok(either_timedelta) => self.timedelta_mode.either_delta_to_json(value.py(), &either_timedelta),
                 Err(_) => {
                     extra.warnings.on_fallback_py(self.get_name(), value, extra)?;
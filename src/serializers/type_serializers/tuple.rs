@@ -14,7 +14,7 @@ use crate::PydanticSerializationUnexpectedValue;
 
 use super::{
     infer_json_key, infer_serialize, infer_to_python, py_err_se_err, BuildSerializer, CombinedSerializer, Extra,
-    PydanticSerializer, SchemaFilter, SerMode, TypeSerializer,
+    PydanticSerializer, SchemaFilter, SerMode, Truncate, TypeSerializer,
 };
 
 #[derive(Debug, Clone)]
@@ -59,6 +59,26 @@ impl BuildSerializer for TupleSerializer {
 
 impl_py_gc_traverse!(TupleSerializer { serializers });
 
+/// Intervals are validated to a `(lower, upper)` tuple, so are serialized as a tuple of two items.
+pub struct IntervalBuilder;
+
+impl BuildSerializer for IntervalBuilder {
+    const EXPECTED_TYPE: &'static str = "interval";
+
+    fn build(
+        schema: &Bound<'_, PyDict>,
+        config: Option<&Bound<'_, PyDict>>,
+        definitions: &mut DefinitionsBuilder<CombinedSerializer>,
+    ) -> PyResult<CombinedSerializer> {
+        let py = schema.py();
+        let items_schema: Bound<'_, PyDict> = schema.get_as_req(intern!(py, "items_schema"))?;
+        let tuple_schema = PyDict::new_bound(py);
+        tuple_schema.set_item(intern!(py, "type"), intern!(py, "tuple"))?;
+        tuple_schema.set_item(intern!(py, "items_schema"), [&items_schema, &items_schema])?;
+        TupleSerializer::build(&tuple_schema, config, definitions)
+    }
+}
+
 impl TypeSerializer for TupleSerializer {
     fn to_python(
         &self,
@@ -70,16 +90,29 @@ impl TypeSerializer for TupleSerializer {
         match value.downcast::<PyTuple>() {
             Ok(py_tuple) => {
                 let py = value.py();
+                let Some(extra) = extra.nested() else {
+                    return Ok(Truncate::MARKER.into_py(py));
+                };
+                let extra = extra.as_ref();
+                let max_items = extra.truncate.max_items();
 
                 let n_items = py_tuple.len();
                 let mut items = Vec::with_capacity(n_items);
+                let mut truncated = false;
 
                 self.for_each_tuple_item_and_serializer(py_tuple, include, exclude, extra, |entry| {
+                    if items.len() == max_items {
+                        truncated = true;
+                        return Ok(());
+                    }
                     entry
                         .serializer
                         .to_python(&entry.item, entry.include.as_ref(), entry.exclude.as_ref(), extra)
                         .map(|item| items.push(item))
                 })??;
+                if truncated {
+                    items.push(Truncate::MARKER.into_py(py));
+                }
 
                 match extra.mode {
                     SerMode::Json => Ok(PyList::new_bound(py, items).into_py(py)),
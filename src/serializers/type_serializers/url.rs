@@ -8,12 +8,12 @@ use crate::definitions::DefinitionsBuilder;
 use crate::url::{PyMultiHostUrl, PyUrl};
 
 use super::{
-    infer_json_key, infer_serialize, infer_to_python, BuildSerializer, CombinedSerializer, Extra, SerMode,
+    infer_json_key, infer_serialize, infer_to_python, BuildSerializer, CombinedSerializer, Extra, ObType, SerMode,
     TypeSerializer,
 };
 
 macro_rules! build_serializer {
-    ($struct_name:ident, $expected_type:literal, $extract:ty) => {
+    ($struct_name:ident, $expected_type:literal, $ob_type:expr, $extract:ty) => {
         #[derive(Debug, Clone)]
         pub struct $struct_name;
 
@@ -42,7 +42,7 @@ macro_rules! build_serializer {
                 let py = value.py();
                 match value.extract::<$extract>() {
                     Ok(py_url) => match extra.mode {
-                        SerMode::Json => Ok(py_url.__str__().into_py(py)),
+                        SerMode::Json if !extra.keep_native.contains($ob_type) => Ok(py_url.__str__().into_py(py)),
                         _ => Ok(value.into_py(py)),
                     },
                     Err(_) => {
@@ -87,5 +87,10 @@ macro_rules! build_serializer {
         }
     };
 }
-build_serializer!(UrlSerializer, "url", PyUrl);
-build_serializer!(MultiHostUrlSerializer, "multi-host-url", PyMultiHostUrl);
+build_serializer!(UrlSerializer, "url", ObType::Url, PyUrl);
+build_serializer!(
+    MultiHostUrlSerializer,
+    "multi-host-url",
+    ObType::MultiHostUrl,
+    PyMultiHostUrl
+);
@@ -2,6 +2,7 @@ use std::borrow::Cow;
 
 use pyo3::exceptions::PyKeyError;
 use pyo3::prelude::*;
+use pyo3::sync::GILOnceCell;
 use pyo3::types::{PyDict, PyString};
 use pyo3::{intern, FromPyObject};
 
@@ -146,3 +147,13 @@ pub(crate) fn new_py_string<'py>(py: Python<'py>, s: &str, cache_str: StringCach
         pystring_fast_new(py, s, ascii_only)
     }
 }
+
+static DC_FIELD_MARKER: GILOnceCell<PyObject> = GILOnceCell::new();
+
+/// needed to match the logic from dataclasses.fields `tuple(f for f in fields.values() if f._field_type is _FIELD)`
+pub(crate) fn get_dataclass_field_marker(py: Python<'_>) -> PyResult<Bound<'_, PyAny>> {
+    let field_type_marker_obj = DC_FIELD_MARKER.get_or_try_init(py, || {
+        py.import_bound("dataclasses")?.getattr("_FIELD").map(|f| f.into_py(py))
+    })?;
+    Ok(field_type_marker_obj.bind(py).clone())
+}
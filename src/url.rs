@@ -7,12 +7,12 @@ use idna::punycode::decode_to_string;
 use pyo3::exceptions::PyValueError;
 use pyo3::pyclass::CompareOp;
 use pyo3::sync::GILOnceCell;
-use pyo3::types::{PyDict, PyType};
+use pyo3::types::{PyDict, PyString, PyType};
 use pyo3::{intern, prelude::*};
-use url::Url;
+use url::{Position, Url};
 
 use crate::tools::SchemaDict;
-use crate::SchemaValidator;
+use crate::{PythonValidateOptions, SchemaValidator};
 
 static SCHEMA_DEFINITION_URL: GILOnceCell<SchemaValidator> = GILOnceCell::new();
 
@@ -45,7 +45,7 @@ impl PyUrl {
     pub fn py_new(py: Python, url: &Bound<'_, PyAny>) -> PyResult<Self> {
         let schema_obj = SCHEMA_DEFINITION_URL
             .get_or_init(py, || build_schema_validator(py, "url"))
-            .validate_python(py, url, None, None, None, None)?;
+            .validate_python_with(py, url, PythonValidateOptions::default())?;
         schema_obj.extract(py)
     }
 
@@ -190,6 +190,53 @@ impl PyUrl {
         }
         cls.call1((url,))
     }
+
+    // the `with_*` methods return a new URL of the same class, which is validated again from its string
+    pub fn with_scheme<'py>(slf: &Bound<'py, Self>, scheme: &str) -> PyResult<Bound<'py, PyAny>> {
+        let mut chars = scheme.chars();
+        let valid = chars.next().is_some_and(|c| c.is_ascii_alphabetic())
+            && chars.all(|c| c.is_ascii_alphanumeric() || matches!(c, '+' | '-' | '.'));
+        if !valid {
+            return Err(PyValueError::new_err(format!("Invalid URL scheme: {scheme:?}")));
+        }
+        let url = &slf.get().lib_url;
+        let rest = &url[Position::AfterScheme..];
+        slf.get_type().call1((format!("{scheme}{rest}"),))
+    }
+
+    pub fn with_path<'py>(slf: &Bound<'py, Self>, path: &str) -> PyResult<Bound<'py, PyAny>> {
+        let mut url = slf.get().lib_url.clone();
+        url.set_path(path);
+        slf.get_type().call1((url.as_str(),))
+    }
+
+    #[pyo3(signature = (params, /))]
+    pub fn with_query<'py>(slf: &Bound<'py, Self>, params: Option<&Bound<'py, PyAny>>) -> PyResult<Bound<'py, PyAny>> {
+        let mut url = slf.get().lib_url.clone();
+        match params {
+            None => url.set_query(None),
+            Some(params) => {
+                if let Ok(query) = params.downcast::<PyString>() {
+                    url.set_query(Some(query.to_str()?));
+                } else {
+                    let items = match params.downcast::<PyDict>() {
+                        Ok(dict) => dict.items().into_any(),
+                        Err(_) => params.clone(),
+                    };
+                    let pairs = items
+                        .iter()?
+                        .map(|item| item?.extract::<(String, String)>())
+                        .collect::<PyResult<Vec<_>>>()?;
+                    if pairs.is_empty() {
+                        url.set_query(None);
+                    } else {
+                        url.query_pairs_mut().clear().extend_pairs(pairs);
+                    }
+                }
+            }
+        }
+        slf.get_type().call1((url.as_str(),))
+    }
 }
 
 #[pyclass(name = "MultiHostUrl", module = "pydantic_core._pydantic_core", subclass, frozen)]
@@ -215,6 +262,14 @@ impl PyMultiHostUrl {
     pub fn mut_lib_url(&mut self) -> &mut Url {
         &mut self.ref_url.lib_url
     }
+
+    /// The number of hosts, matching the length of `hosts()`
+    pub fn host_count(&self) -> usize {
+        match &self.extra_urls {
+            Some(extra_urls) => extra_urls.len() + 1,
+            None => usize::from(self.ref_url.lib_url.has_host()),
+        }
+    }
 }
 
 static SCHEMA_DEFINITION_MULTI_HOST_URL: GILOnceCell<SchemaValidator> = GILOnceCell::new();
@@ -225,7 +280,7 @@ impl PyMultiHostUrl {
     pub fn py_new(py: Python, url: &Bound<'_, PyAny>) -> PyResult<Self> {
         let schema_obj = SCHEMA_DEFINITION_MULTI_HOST_URL
             .get_or_init(py, || build_schema_validator(py, "multi-host-url"))
-            .validate_python(py, url, None, None, None, None)?;
+            .validate_python_with(py, url, PythonValidateOptions::default())?;
         schema_obj.extract(py)
     }
 
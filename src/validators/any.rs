@@ -38,6 +38,10 @@ impl Validator for AnyValidator {
         Ok(input.to_object(py))
     }
 
+    fn json_schema<'py>(&self, py: Python<'py>, _defs: &Bound<'py, PyDict>) -> PyResult<Bound<'py, PyDict>> {
+        Ok(PyDict::new_bound(py))
+    }
+
     fn get_name(&self) -> &str {
         Self::EXPECTED_TYPE
     }
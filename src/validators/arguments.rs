@@ -6,7 +6,7 @@ use ahash::AHashSet;
 
 use crate::build_tools::py_schema_err;
 use crate::build_tools::{schema_or_config_same, ExtraBehavior};
-use crate::errors::{ErrorTypeDefaults, ValError, ValLineError, ValResult};
+use crate::errors::{ErrorType, ErrorTypeDefaults, ValError, ValLineError, ValResult};
 use crate::input::{Arguments, BorrowInput, Input, KeywordArgs, PositionalArgs, ValidationMatch};
 use crate::lookup_key::LookupKey;
 
@@ -21,6 +21,8 @@ struct Parameter {
     name: String,
     kw_lookup_key: Option<LookupKey>,
     kwarg_key: Option<Py<PyString>>,
+    // only set for positional only parameters, used to detect them being passed by keyword
+    positional_only_key: Option<LookupKey>,
     validator: CombinedValidator,
 }
 
@@ -85,6 +87,11 @@ impl BuildValidator for ArgumentsValidator {
                 };
                 kwarg_key = Some(py_name.into_py(py));
             }
+            let positional_only_key = if mode == "positional_only" {
+                Some(LookupKey::from_string(py, &name))
+            } else {
+                None
+            };
 
             let schema = arg.get_as_req(intern!(py, "schema"))?;
 
@@ -113,6 +120,7 @@ impl BuildValidator for ArgumentsValidator {
                 name,
                 kw_lookup_key,
                 kwarg_key,
+                positional_only_key,
                 validator,
             });
         }
@@ -135,6 +143,31 @@ impl BuildValidator for ArgumentsValidator {
     }
 }
 
+impl ArgumentsValidator {
+    /// Move positional parameters which were passed by keyword, or filled from their default,
+    /// out of `kwargs` and into `args`, so the output is bound the same way python binds a call.
+    pub fn bind_args<'py>(
+        &self,
+        args: &Bound<'py, PyTuple>,
+        kwargs: &Bound<'py, PyDict>,
+    ) -> PyResult<Bound<'py, PyTuple>> {
+        let mut bound_args: Vec<Bound<'py, PyAny>> = args.iter().collect();
+        let positional_parameters = self.parameters.iter().filter(|parameter| parameter.positional);
+        for parameter in positional_parameters.skip(bound_args.len()) {
+            let Some(ref kwarg_key) = parameter.kwarg_key else {
+                break;
+            };
+            let kwarg_key = kwarg_key.bind(args.py());
+            let Some(value) = kwargs.get_item(kwarg_key)? else {
+                break;
+            };
+            kwargs.del_item(kwarg_key)?;
+            bound_args.push(value);
+        }
+        Ok(PyTuple::new_bound(args.py(), bound_args))
+    }
+}
+
 impl_py_gc_traverse!(Parameter { validator });
 
 impl_py_gc_traverse!(ArgumentsValidator {
@@ -156,6 +189,8 @@ impl Validator for ArgumentsValidator {
         let output_kwargs = PyDict::new_bound(py);
         let mut errors: Vec<ValLineError> = Vec::new();
         let mut used_kwargs: AHashSet<&str> = AHashSet::with_capacity(self.parameters.len());
+        // index of the next extra positional argument which hasn't been reported as an error
+        let mut next_extra_positional = self.positional_params_count;
 
         // go through arguments getting the value from args or kwargs and validating it
         for (index, parameter) in self.parameters.iter().enumerate() {
@@ -183,15 +218,23 @@ impl Validator for ArgumentsValidator {
                         parameter.name.clone(),
                     ));
                 }
-                (Some(pos_value), None) => match parameter.validator.validate(py, pos_value.borrow_input(), state) {
-                    Ok(value) => output_args.push(value),
-                    Err(ValError::LineErrors(line_errors)) => {
-                        errors.extend(line_errors.into_iter().map(|err| err.with_outer_location(index)));
+                (Some(pos_value), None) => {
+                    match parameter
+                        .validator
+                        .validate_profiled(py, pos_value.borrow_input(), state)
+                    {
+                        Ok(value) => output_args.push(value),
+                        Err(ValError::LineErrors(line_errors)) => {
+                            errors.extend(line_errors.into_iter().map(|err| err.with_outer_location(index)));
+                        }
+                        Err(err) => return Err(err),
                     }
-                    Err(err) => return Err(err),
-                },
+                }
                 (None, Some((lookup_path, kw_value))) => {
-                    match parameter.validator.validate(py, kw_value.borrow_input(), state) {
+                    match parameter
+                        .validator
+                        .validate_profiled(py, kw_value.borrow_input(), state)
+                    {
                         Ok(value) => output_kwargs.set_item(parameter.kwarg_key.as_ref().unwrap(), value)?,
                         Err(ValError::LineErrors(line_errors)) => {
                             errors.extend(
@@ -204,6 +247,37 @@ impl Validator for ArgumentsValidator {
                     }
                 }
                 (None, None) => {
+                    if let Some(ref positional_only_key) = parameter.positional_only_key {
+                        // a positional only parameter passed by keyword, unless `**kwargs` would collect it
+                        if let (Some(kwargs), None) = (args.kwargs(), &self.var_kwargs_validator) {
+                            if let Some((lookup_path, value)) = kwargs.get_item(positional_only_key)? {
+                                used_kwargs.insert(lookup_path.first_key());
+                                errors.push(ValLineError::new_with_loc(
+                                    ErrorType::PositionalOnlyArgumentAsKeyword {
+                                        parameter: parameter.name.clone(),
+                                        context: None,
+                                    },
+                                    value.borrow_input(),
+                                    parameter.name.clone(),
+                                ));
+                                continue;
+                            }
+                        }
+                    } else if !parameter.positional && self.var_args_validator.is_none() {
+                        // a keyword only parameter given as an extra positional argument
+                        if let Some(item) = args.args().and_then(|args| args.get_item(next_extra_positional)) {
+                            errors.push(ValLineError::new_with_loc(
+                                ErrorType::KeywordOnlyArgumentAsPositional {
+                                    parameter: parameter.name.clone(),
+                                    context: None,
+                                },
+                                item.borrow_input(),
+                                next_extra_positional,
+                            ));
+                            next_extra_positional += 1;
+                            continue;
+                        }
+                    }
                     if let Some(value) = parameter
                         .validator
                         .default_value(py, Some(parameter.name.as_str()), state)?
@@ -236,7 +310,7 @@ impl Validator for ArgumentsValidator {
             if len > self.positional_params_count {
                 if let Some(ref validator) = self.var_args_validator {
                     for (index, item) in args.iter().enumerate().skip(self.positional_params_count) {
-                        match validator.validate(py, item.borrow_input(), state) {
+                        match validator.validate_profiled(py, item.borrow_input(), state) {
                             Ok(value) => output_args.push(value),
                             Err(ValError::LineErrors(line_errors)) => {
                                 errors.extend(line_errors.into_iter().map(|err| err.with_outer_location(index)));
@@ -245,7 +319,8 @@ impl Validator for ArgumentsValidator {
                         }
                     }
                 } else {
-                    for (index, item) in args.iter().enumerate().skip(self.positional_params_count) {
+                    // some of these may already have been reported against keyword only parameters
+                    for (index, item) in args.iter().enumerate().skip(next_extra_positional) {
                         errors.push(ValLineError::new_with_loc(
                             ErrorTypeDefaults::UnexpectedPositionalArgument,
                             item,
@@ -279,7 +354,7 @@ impl Validator for ArgumentsValidator {
                     };
                     if !used_kwargs.contains(either_str.as_cow()?.as_ref()) {
                         match self.var_kwargs_validator {
-                            Some(ref validator) => match validator.validate(py, value.borrow_input(), state) {
+                            Some(ref validator) => match validator.validate_profiled(py, value.borrow_input(), state) {
                                 Ok(value) => {
                                     output_kwargs.set_item(either_str.as_py_string(py, state.cache_str()), value)?;
                                 }
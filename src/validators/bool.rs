@@ -1,15 +1,18 @@
 use pyo3::prelude::*;
 use pyo3::types::PyDict;
 
-use crate::build_tools::is_strict;
-use crate::errors::ValResult;
-use crate::input::Input;
+use crate::build_tools::{explain_strict, is_strict};
+use crate::errors::{ErrorType, ValError, ValResult};
+use crate::input::{Input, StringParseOptions};
 
+use super::json_schema::new_schema;
 use super::{BuildValidator, CombinedValidator, DefinitionsBuilder, ValidationState, Validator};
 
 #[derive(Debug, Clone)]
 pub struct BoolValidator {
     strict: bool,
+    explain_strict: bool,
+    parse_options: StringParseOptions,
 }
 
 impl BuildValidator for BoolValidator {
@@ -22,6 +25,8 @@ impl BuildValidator for BoolValidator {
     ) -> PyResult<CombinedValidator> {
         Ok(Self {
             strict: is_strict(schema, config)?,
+            explain_strict: explain_strict(schema, config)?,
+            parse_options: StringParseOptions::from_schema(schema, config, StringParseOptions::BOOL_DEFAULT)?,
         }
         .into())
     }
@@ -38,12 +43,36 @@ impl Validator for BoolValidator {
     ) -> ValResult<PyObject> {
         // TODO in theory this could be quicker if we used PyBool rather than going to a bool
         // and back again, might be worth profiling?
+        let strict = state.strict_or(self.strict);
         input
-            .validate_bool(state.strict_or(self.strict))
+            .validate_bool(strict, self.parse_options)
+            .map_err(|e| {
+                e.explain_strict(py, Self::EXPECTED_TYPE, strict && self.explain_strict, || {
+                    input.validate_bool(false, self.parse_options).is_ok()
+                })
+            })
             .map(|val_match| val_match.unpack(state).into_py(py))
     }
 
+    fn json_schema<'py>(&self, py: Python<'py>, _defs: &Bound<'py, PyDict>) -> PyResult<Bound<'py, PyDict>> {
+        new_schema(py, "boolean")
+    }
+
     fn get_name(&self) -> &str {
         Self::EXPECTED_TYPE
     }
 }
+
+/// Reject `True` and `False` (or JSON `true` and `false`) for numeric validators with `allow_bool` disabled,
+/// lax mode otherwise accepts them as `1` and `0`.
+pub(super) fn check_allow_bool<'py>(
+    input: &(impl Input<'py> + ?Sized),
+    allow_bool: bool,
+    error_type: ErrorType,
+) -> ValResult<()> {
+    if !allow_bool && input.exact_bool().is_ok() {
+        Err(ValError::new(error_type, input))
+    } else {
+        Ok(())
+    }
+}
@@ -1,18 +1,24 @@
+use base64::engine::general_purpose::{STANDARD, URL_SAFE};
+use base64::Engine;
 use pyo3::intern;
 use pyo3::prelude::*;
-use pyo3::types::PyDict;
+use pyo3::types::{PyDict, PyString};
 
-use crate::build_tools::is_strict;
+use crate::build_tools::{explain_strict, is_strict};
 use crate::errors::{ErrorType, ValError, ValResult};
-use crate::input::Input;
+use crate::input::{EitherBytes, Input};
+use crate::serializers::BytesMode;
 
 use crate::tools::SchemaDict;
 
+use super::json_schema::{set_optional, string_json_schema};
 use super::{BuildValidator, CombinedValidator, DefinitionsBuilder, ValidationState, Validator};
 
 #[derive(Debug, Clone)]
 pub struct BytesValidator {
     strict: bool,
+    explain_strict: bool,
+    json_bytes_mode: BytesMode,
 }
 
 impl BuildValidator for BytesValidator {
@@ -25,12 +31,15 @@ impl BuildValidator for BytesValidator {
     ) -> PyResult<CombinedValidator> {
         let py = schema.py();
         let use_constrained = schema.get_item(intern!(py, "max_length"))?.is_some()
-            || schema.get_item(intern!(py, "min_length"))?.is_some();
+            || schema.get_item(intern!(py, "min_length"))?.is_some()
+            || schema.get_item(intern!(py, "max_decoded_length"))?.is_some();
         if use_constrained {
             BytesConstrainedValidator::build(schema, config)
         } else {
             Ok(Self {
                 strict: is_strict(schema, config)?,
+                explain_strict: explain_strict(schema, config)?,
+                json_bytes_mode: json_bytes_mode(config)?,
             }
             .into())
         }
@@ -46,9 +55,20 @@ impl Validator for BytesValidator {
         input: &(impl Input<'py> + ?Sized),
         state: &mut ValidationState<'_, 'py>,
     ) -> ValResult<PyObject> {
-        input
-            .validate_bytes(state.strict_or(self.strict))
-            .map(|m| m.unpack(state).into_py(py))
+        let strict = state.strict_or(self.strict);
+        let either_bytes = input
+            .validate_bytes(strict)
+            .map_err(|e| {
+                e.explain_strict(py, Self::EXPECTED_TYPE, strict && self.explain_strict, || {
+                    input.validate_bytes(false).is_ok()
+                })
+            })?
+            .unpack(state);
+        Ok(decode_json_bytes(&self.json_bytes_mode, input, either_bytes)?.into_py(py))
+    }
+
+    fn json_schema<'py>(&self, py: Python<'py>, _defs: &Bound<'py, PyDict>) -> PyResult<Bound<'py, PyDict>> {
+        string_json_schema(py, "binary")
     }
 
     fn get_name(&self) -> &str {
@@ -59,8 +79,11 @@ impl Validator for BytesValidator {
 #[derive(Debug, Clone)]
 pub struct BytesConstrainedValidator {
     strict: bool,
+    explain_strict: bool,
     max_length: Option<usize>,
     min_length: Option<usize>,
+    max_decoded_length: Option<usize>,
+    json_bytes_mode: BytesMode,
 }
 
 impl_py_gc_traverse!(BytesConstrainedValidator {});
@@ -72,7 +95,35 @@ impl Validator for BytesConstrainedValidator {
         input: &(impl Input<'py> + ?Sized),
         state: &mut ValidationState<'_, 'py>,
     ) -> ValResult<PyObject> {
-        let either_bytes = input.validate_bytes(state.strict_or(self.strict))?.unpack(state);
+        let strict = state.strict_or(self.strict);
+        let either_bytes = input
+            .validate_bytes(strict)
+            .map_err(|e| {
+                e.explain_strict(py, BytesValidator::EXPECTED_TYPE, strict && self.explain_strict, || {
+                    input.validate_bytes(false).is_ok()
+                })
+            })?
+            .unpack(state);
+        let is_encoded = input.as_python().is_none();
+        // `max_decoded_length` only applies to strings which are decoded according to `val_json_bytes`
+        let max_length = match (self.max_length, self.max_decoded_length) {
+            (Some(max_length), Some(max_decoded_length)) if is_encoded => Some(max_length.min(max_decoded_length)),
+            (None, max_decoded_length) if is_encoded => max_decoded_length,
+            (max_length, _) => max_length,
+        };
+        if let Some(max_length) = max_length {
+            // reject oversized encoded strings before decoding them
+            if is_encoded && min_decoded_len(&self.json_bytes_mode, either_bytes.as_slice()) > max_length {
+                return Err(ValError::new(
+                    ErrorType::BytesTooLong {
+                        max_length,
+                        context: None,
+                    },
+                    input,
+                ));
+            }
+        }
+        let either_bytes = decode_json_bytes(&self.json_bytes_mode, input, either_bytes)?;
         let len = either_bytes.len()?;
 
         if let Some(min_length) = self.min_length {
@@ -86,7 +137,7 @@ impl Validator for BytesConstrainedValidator {
                 ));
             }
         }
-        if let Some(max_length) = self.max_length {
+        if let Some(max_length) = max_length {
             if len > max_length {
                 return Err(ValError::new(
                     ErrorType::BytesTooLong {
@@ -100,6 +151,13 @@ impl Validator for BytesConstrainedValidator {
         Ok(either_bytes.into_py(py))
     }
 
+    fn json_schema<'py>(&self, py: Python<'py>, _defs: &Bound<'py, PyDict>) -> PyResult<Bound<'py, PyDict>> {
+        let json_schema = string_json_schema(py, "binary")?;
+        set_optional(&json_schema, "minLength", self.min_length)?;
+        set_optional(&json_schema, "maxLength", self.max_length)?;
+        Ok(json_schema)
+    }
+
     fn get_name(&self) -> &str {
         "constrained-bytes"
     }
@@ -110,9 +168,85 @@ impl BytesConstrainedValidator {
         let py = schema.py();
         Ok(Self {
             strict: is_strict(schema, config)?,
+            explain_strict: explain_strict(schema, config)?,
             min_length: schema.get_as(intern!(py, "min_length"))?,
             max_length: schema.get_as(intern!(py, "max_length"))?,
+            max_decoded_length: schema.get_as(intern!(py, "max_decoded_length"))?,
+            json_bytes_mode: json_bytes_mode(config)?,
         }
         .into())
     }
 }
+
+fn json_bytes_mode(config: Option<&Bound<'_, PyDict>>) -> PyResult<BytesMode> {
+    match config {
+        Some(config) => match config.get_as::<Bound<'_, PyString>>(intern!(config.py(), "val_json_bytes"))? {
+            Some(mode) => mode.to_str()?.parse(),
+            None => Ok(BytesMode::default()),
+        },
+        None => Ok(BytesMode::default()),
+    }
+}
+
+/// Lower bound on the length of `bytes` once decoded, exact if they're validly encoded.
+fn min_decoded_len(mode: &BytesMode, bytes: &[u8]) -> usize {
+    match mode {
+        BytesMode::Utf8 => bytes.len(),
+        // every 4 chars decode to 3 bytes, padding carries no data
+        BytesMode::Base64 => {
+            let padding = bytes.iter().rev().take(2).take_while(|&&b| b == b'=').count();
+            (bytes.len() - padding) * 3 / 4
+        }
+        BytesMode::Hex => bytes.len() / 2,
+    }
+}
+
+/// Decode bytes which came from a string in JSON (or `validate_strings`) input according to `val_json_bytes`,
+/// so values serialized with the matching `ser_json_bytes` round-trip. Python inputs are never decoded.
+fn decode_json_bytes<'a, 'py>(
+    mode: &BytesMode,
+    input: &(impl Input<'py> + ?Sized),
+    either_bytes: EitherBytes<'a, 'py>,
+) -> ValResult<EitherBytes<'a, 'py>> {
+    if input.as_python().is_some() {
+        return Ok(either_bytes);
+    }
+    let bytes = either_bytes.as_slice();
+    let (encoding, decoded) = match mode {
+        BytesMode::Utf8 => return Ok(either_bytes),
+        BytesMode::Base64 => (
+            "base64",
+            URL_SAFE
+                .decode(bytes)
+                .or_else(|err| STANDARD.decode(bytes).map_err(|_| err.to_string())),
+        ),
+        BytesMode::Hex => ("hex", decode_hex(bytes)),
+    };
+    match decoded {
+        Ok(decoded) => Ok(decoded.into()),
+        Err(encoding_error) => Err(ValError::new(
+            ErrorType::BytesInvalidEncoding {
+                encoding: encoding.to_string(),
+                encoding_error,
+                context: None,
+            },
+            input,
+        )),
+    }
+}
+
+fn decode_hex(bytes: &[u8]) -> Result<Vec<u8>, String> {
+    if bytes.len() % 2 != 0 {
+        return Err("Odd number of digits".to_string());
+    }
+    bytes
+        .chunks(2)
+        .map(|pair| {
+            let digit = |b: u8| char::from(b).to_digit(16);
+            match (digit(pair[0]), digit(pair[1])) {
+                (Some(high), Some(low)) => Ok((high * 16 + low) as u8),
+                _ => Err(format!("Invalid hex digits {:?}", String::from_utf8_lossy(pair))),
+            }
+        })
+        .collect()
+}
@@ -0,0 +1,259 @@
+use std::sync::Mutex;
+use std::time::Instant;
+
+use ahash::AHashMap;
+use pyo3::intern;
+use pyo3::prelude::*;
+use pyo3::types::{PyDict, PyInt, PyString};
+
+use crate::errors::ValResult;
+use crate::input::Input;
+use crate::tools::SchemaDict;
+
+use super::{CombinedValidator, ValidationState, Validator};
+
+/// The maximum number of inputs remembered by each validator with `cache_validations` set.
+const CACHE_CAPACITY: usize = 256;
+/// The maximum total size in bytes of the inputs remembered by each validator, larger inputs aren't cached.
+const CACHE_MAX_BYTES: usize = 64 * 1024;
+
+/// Which exact python type of input is cached, only inputs of the validator's own type are cached since
+/// they're valid or invalid regardless of strictness and don't change the exactness of validation.
+#[derive(Debug, Clone, Copy)]
+pub enum CachedInput {
+    Str,
+    Int,
+}
+
+/// Wraps a scalar validator to remember the outputs of validating recent inputs, set with `cache_validations`.
+#[derive(Debug)]
+pub struct CachedValidator {
+    validator: Box<CombinedValidator>,
+    input_kind: CachedInput,
+    cache: Mutex<LruCache>,
+    /// name cache hits are recorded under when profiling
+    hit_name: String,
+}
+
+impl CachedValidator {
+    /// Wrap `validator` if `cache_validations` is set on the schema, otherwise return it unchanged.
+    pub fn wrap(
+        schema: &Bound<'_, PyDict>,
+        validator: CombinedValidator,
+        input_kind: CachedInput,
+    ) -> PyResult<CombinedValidator> {
+        let cache_validations = schema
+            .get_as(intern!(schema.py(), "cache_validations"))?
+            .unwrap_or(false);
+        if cache_validations {
+            Ok(Self {
+                hit_name: format!("{}:cache-hit", validator.get_name()),
+                validator: Box::new(validator),
+                input_kind,
+                cache: Mutex::new(LruCache::default()),
+            }
+            .into())
+        } else {
+            Ok(validator)
+        }
+    }
+
+    /// The input if it can be cached, with its python hash and size in bytes.
+    fn cache_key<'a, 'py>(&self, input: &'a (impl Input<'py> + ?Sized)) -> Option<CacheKey<'a, 'py>> {
+        let input = input.as_python()?;
+        let size = match self.input_kind {
+            CachedInput::Str if input.is_exact_instance_of::<PyString>() => {
+                input.downcast::<PyString>().ok()?.to_str().ok()?.len()
+            }
+            CachedInput::Int if input.is_exact_instance_of::<PyInt>() => match input.extract::<i64>() {
+                Ok(_) => 8,
+                Err(_) => {
+                    let bits: usize = input
+                        .call_method0(intern!(input.py(), "bit_length"))
+                        .ok()?
+                        .extract()
+                        .ok()?;
+                    bits / 8 + 1
+                }
+            },
+            _ => return None,
+        };
+        if size > CACHE_MAX_BYTES {
+            return None;
+        }
+        Some(CacheKey {
+            input,
+            hash: input.hash().ok()?,
+            size,
+        })
+    }
+}
+
+impl_py_gc_traverse!(CachedValidator { validator });
+
+impl Validator for CachedValidator {
+    fn validate<'py>(
+        &self,
+        py: Python<'py>,
+        input: &(impl Input<'py> + ?Sized),
+        state: &mut ValidationState<'_, 'py>,
+    ) -> ValResult<PyObject> {
+        // the wrapped validator isn't profiled separately, this validator is already recorded under its name
+        let Some(key) = self.cache_key(input) else {
+            return self.validator.validate(py, input, state);
+        };
+        let start = Instant::now();
+        let cached = self.cache.lock().ok().and_then(|mut cache| cache.get(&key));
+        if let Some(output) = cached {
+            if let Some(profile) = state.profile.as_deref_mut() {
+                profile.record(&self.hit_name, start.elapsed());
+            }
+            return Ok(output);
+        }
+        // only valid inputs are cached, invalid ones are validated again so errors refer to the input
+        let output = self.validator.validate(py, input, state)?;
+        if let Ok(mut cache) = self.cache.lock() {
+            cache.insert(&key, output.clone_ref(py));
+        }
+        Ok(output)
+    }
+
+    fn json_schema<'py>(&self, py: Python<'py>, defs: &Bound<'py, PyDict>) -> PyResult<Bound<'py, PyDict>> {
+        self.validator.json_schema(py, defs)
+    }
+
+    fn get_name(&self) -> &str {
+        self.validator.get_name()
+    }
+}
+
+/// An input looked up in the cache, compared by python equality, which for exact `str`s and `int`s doesn't run
+/// any user code.
+struct CacheKey<'a, 'py> {
+    input: &'a Bound<'py, PyAny>,
+    hash: isize,
+    size: usize,
+}
+
+/// A least recently used cache of validation outputs, entries are kept in a doubly linked list ordered by last use
+/// with their indexes looked up by the python hash of their input, so both `get` and `insert` are O(1).
+#[derive(Debug, Default)]
+struct LruCache {
+    indexes: AHashMap<isize, Vec<usize>>,
+    entries: Vec<LruEntry>,
+    /// slots of evicted entries which can be reused
+    free: Vec<usize>,
+    /// total size of the cached inputs, see `CACHE_MAX_BYTES`
+    size: usize,
+    /// the most recently used entry
+    head: Option<usize>,
+    /// the least recently used entry, evicted first
+    tail: Option<usize>,
+}
+
+#[derive(Debug)]
+struct LruEntry {
+    input: PyObject,
+    hash: isize,
+    size: usize,
+    output: PyObject,
+    prev: Option<usize>,
+    next: Option<usize>,
+}
+
+impl LruCache {
+    fn find(&self, key: &CacheKey) -> Option<usize> {
+        let py = key.input.py();
+        self.indexes.get(&key.hash)?.iter().copied().find(|&index| {
+            let input = self.entries[index].input.bind(py);
+            input.is(key.input) || input.eq(key.input).unwrap_or(false)
+        })
+    }
+
+    fn get(&mut self, key: &CacheKey) -> Option<PyObject> {
+        let index = self.find(key)?;
+        self.unlink(index);
+        self.push_front(index);
+        Some(self.entries[index].output.clone_ref(key.input.py()))
+    }
+
+    fn insert(&mut self, key: &CacheKey, output: PyObject) {
+        if let Some(index) = self.find(key) {
+            self.entries[index].output = output;
+            self.unlink(index);
+            self.push_front(index);
+            return;
+        }
+        while self.entries.len() - self.free.len() >= CACHE_CAPACITY || self.size + key.size > CACHE_MAX_BYTES {
+            if !self.evict(key.input.py()) {
+                break;
+            }
+        }
+        let entry = LruEntry {
+            input: key.input.clone().unbind(),
+            hash: key.hash,
+            size: key.size,
+            output,
+            prev: None,
+            next: None,
+        };
+        let index = match self.free.pop() {
+            Some(index) => {
+                self.entries[index] = entry;
+                index
+            }
+            None => {
+                self.entries.push(entry);
+                self.entries.len() - 1
+            }
+        };
+        self.size += key.size;
+        self.indexes.entry(key.hash).or_default().push(index);
+        self.push_front(index);
+    }
+
+    /// Remove the least recently used entry, returns `false` if the cache is empty.
+    fn evict(&mut self, py: Python) -> bool {
+        let Some(index) = self.tail else {
+            return false;
+        };
+        self.unlink(index);
+        let entry = &mut self.entries[index];
+        // don't keep the evicted objects alive until the slot is reused
+        entry.input = py.None();
+        entry.output = py.None();
+        let (hash, size) = (entry.hash, entry.size);
+        if let Some(indexes) = self.indexes.get_mut(&hash) {
+            indexes.retain(|&i| i != index);
+            if indexes.is_empty() {
+                self.indexes.remove(&hash);
+            }
+        }
+        self.size -= size;
+        self.free.push(index);
+        true
+    }
+    fn unlink(&mut self, index: usize) {
+        let LruEntry { prev, next, .. } = self.entries[index];
+        match prev {
+            Some(prev) => self.entries[prev].next = next,
+            None => self.head = next,
+        }
+        match next {
+            Some(next) => self.entries[next].prev = prev,
+            None => self.tail = prev,
+        }
+    }
+
+    fn push_front(&mut self, index: usize) {
+        self.entries[index].prev = None;
+        self.entries[index].next = self.head;
+        if let Some(head) = self.head {
+            self.entries[head].prev = Some(index);
+        }
+        self.head = Some(index);
+        if self.tail.is_none() {
+            self.tail = Some(index);
+        }
+    }
+}
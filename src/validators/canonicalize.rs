@@ -0,0 +1,98 @@
+use pyo3::exceptions::{PyRecursionError, PyTypeError, PyUserWarning, PyValueError};
+use pyo3::intern;
+use pyo3::prelude::*;
+use pyo3::types::{PyBool, PyDict, PyFrozenSet, PyList, PySet, PyTuple};
+
+/// Same depth limit as content hashes, validated data this deep is almost certainly recursive.
+const RECURSION_LIMIT: u16 = 200;
+
+/// Which containers `validate_python(..., canonicalize=...)` sorts.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Canonicalize {
+    #[default]
+    Off,
+    /// sort the keys of dicts, `canonicalize=True`
+    Dicts,
+    /// also replace sets with sorted lists and frozensets with sorted tuples, since sets have no deterministic
+    /// order, `canonicalize='sets'`
+    DictsAndSets,
+}
+
+impl<'py> FromPyObject<'py> for Canonicalize {
+    fn extract_bound(ob: &Bound<'py, PyAny>) -> PyResult<Self> {
+        if let Ok(b) = ob.downcast::<PyBool>() {
+            Ok(if b.is_true() { Self::Dicts } else { Self::Off })
+        } else if ob.extract::<String>().is_ok_and(|s| s == "sets") {
+            Ok(Self::DictsAndSets)
+        } else {
+            Err(PyValueError::new_err(format!(
+                "Invalid `canonicalize` value {}, expected a bool or 'sets'",
+                ob.repr()?
+            )))
+        }
+    }
+}
+
+/// Copy `value` with the keys of every dict within it sorted, and with `DictsAndSets` sets and frozensets replaced
+/// by lists and tuples of their sorted elements, so equal values have identical structures.
+///
+/// Only exact dicts, lists, tuples, sets and frozensets are rebuilt, other objects like models are kept as is.
+/// Containers whose keys or elements can't be ordered keep their order and a `UserWarning` is issued.
+pub fn canonicalize(value: &Bound<'_, PyAny>, mode: Canonicalize) -> PyResult<PyObject> {
+    match mode {
+        Canonicalize::Off => Ok(value.clone().unbind()),
+        _ => canonicalize_value(value, mode, RECURSION_LIMIT),
+    }
+}
+
+fn canonicalize_value(value: &Bound<'_, PyAny>, mode: Canonicalize, remaining_depth: u16) -> PyResult<PyObject> {
+    let py = value.py();
+    let Some(remaining_depth) = remaining_depth.checked_sub(1) else {
+        return Err(PyRecursionError::new_err("Maximum depth exceeded while canonicalizing"));
+    };
+    let child = |item: &Bound<'_, PyAny>| canonicalize_value(item, mode, remaining_depth);
+
+    if let Ok(dict) = value.downcast_exact::<PyDict>() {
+        let keys = sorted_or_warn(dict.keys().as_any(), "dict keys")?;
+        let output = PyDict::new_bound(py);
+        for key in keys.iter() {
+            // the key is always present since the dict was just iterated
+            if let Some(item) = dict.get_item(&key)? {
+                output.set_item(key, child(&item)?)?;
+            }
+        }
+        Ok(output.into())
+    } else if let Ok(list) = value.downcast_exact::<PyList>() {
+        let items = list.iter().map(|item| child(&item)).collect::<PyResult<Vec<_>>>()?;
+        Ok(PyList::new_bound(py, items).into())
+    } else if let Ok(tuple) = value.downcast_exact::<PyTuple>() {
+        let items = tuple.iter().map(|item| child(&item)).collect::<PyResult<Vec<_>>>()?;
+        Ok(PyTuple::new_bound(py, items).into())
+    } else if mode == Canonicalize::DictsAndSets && value.is_exact_instance_of::<PySet>() {
+        let items = sorted_or_warn(value, "set elements")?;
+        let items = items.iter().map(|item| child(&item)).collect::<PyResult<Vec<_>>>()?;
+        Ok(PyList::new_bound(py, items).into())
+    } else if mode == Canonicalize::DictsAndSets && value.is_exact_instance_of::<PyFrozenSet>() {
+        let items = sorted_or_warn(value, "frozenset elements")?;
+        // a tuple rather than a list so the output is still hashable
+        let items = items.iter().map(|item| child(&item)).collect::<PyResult<Vec<_>>>()?;
+        Ok(PyTuple::new_bound(py, items).into())
+    } else {
+        Ok(value.clone().unbind())
+    }
+}
+
+/// `sorted(items)`, or `list(items)` with a warning if the items can't be ordered.
+fn sorted_or_warn<'py>(items: &Bound<'py, PyAny>, what: &str) -> PyResult<Bound<'py, PyList>> {
+    let py = items.py();
+    let builtins = py.import_bound(intern!(py, "builtins"))?;
+    match builtins.getattr(intern!(py, "sorted"))?.call1((items,)) {
+        Ok(sorted) => Ok(sorted.downcast_into::<PyList>()?),
+        Err(err) if err.is_instance_of::<PyTypeError>(py) => {
+            let message = format!("Unable to sort {what} while canonicalizing, keeping their order: {err}");
+            PyErr::warn_bound(py, &py.get_type_bound::<PyUserWarning>(), &message, 1)?;
+            Ok(PyList::new_bound(py, items.iter()?.collect::<PyResult<Vec<_>>>()?))
+        }
+        Err(err) => Err(err),
+    }
+}
@@ -44,6 +44,7 @@ impl CustomError {
                 error_type,
                 schema.get_as_req::<String>(intern!(py, "custom_error_message"))?,
                 context,
+                None,
             );
             Ok(Some(Self::Custom(error)))
         }
@@ -95,10 +96,14 @@ impl Validator for CustomErrorValidator {
         state: &mut ValidationState<'_, 'py>,
     ) -> ValResult<PyObject> {
         self.validator
-            .validate(py, input, state)
+            .validate_profiled(py, input, state)
             .map_err(|_| self.custom_error.as_val_error(input))
     }
 
+    fn json_schema<'py>(&self, py: Python<'py>, defs: &Bound<'py, PyDict>) -> PyResult<Bound<'py, PyDict>> {
+        self.validator.json_schema(py, defs)
+    }
+
     fn get_name(&self) -> &str {
         &self.name
     }
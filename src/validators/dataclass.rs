@@ -15,6 +15,7 @@ use crate::lookup_key::LookupKey;
 use crate::tools::SchemaDict;
 use crate::validators::function::convert_err;
 
+use super::json_schema::{is_required, object_json_schema};
 use super::model::{create_class, force_setattr, Revalidate};
 use super::validation_state::Exactness;
 use super::{build_validator, BuildValidator, CombinedValidator, DefinitionsBuilder, ValidationState, Validator};
@@ -213,7 +214,8 @@ impl Validator for DataclassArgsValidator {
                     ));
                 }
                 // found a positional argument, validate it
-                (Some(pos_value), None) => match field.validator.validate(py, pos_value.borrow_input(), state) {
+                (Some(pos_value), None) => match field.validator.validate_profiled(py, pos_value.borrow_input(), state)
+                {
                     Ok(value) => set_item!(field, value),
                     Err(ValError::LineErrors(line_errors)) => {
                         errors.extend(line_errors.into_iter().map(|err| err.with_outer_location(index)));
@@ -221,7 +223,7 @@ impl Validator for DataclassArgsValidator {
                     Err(err) => return Err(err),
                 },
                 // found a keyword argument, validate it
-                (None, Some((lookup_path, kw_value))) => match field.validator.validate(py, kw_value, state) {
+                (None, Some((lookup_path, kw_value))) => match field.validator.validate_profiled(py, kw_value, state) {
                     Ok(value) => set_item!(field, value),
                     Err(ValError::LineErrors(line_errors)) => {
                         errors.extend(
@@ -300,7 +302,7 @@ impl Validator for DataclassArgsValidator {
                                     ExtraBehavior::Ignore => {}
                                     ExtraBehavior::Allow => {
                                         if let Some(ref validator) = self.extras_validator {
-                                            match validator.validate(py, value.borrow_input(), state) {
+                                            match validator.validate_profiled(py, value.borrow_input(), state) {
                                                 Ok(value) => {
                                                     output_dict.set_item(
                                                         either_str.as_py_string(py, state.cache_str()),
@@ -383,7 +385,7 @@ impl Validator for DataclassArgsValidator {
                 }
             }
 
-            match field.validator.validate(
+            match field.validator.validate_profiled(
                 py,
                 field_value,
                 &mut state.rebind_extra(|extra| extra.data = Some(data_dict.clone())),
@@ -418,6 +420,18 @@ impl Validator for DataclassArgsValidator {
         }
     }
 
+    fn json_schema<'py>(&self, py: Python<'py>, defs: &Bound<'py, PyDict>) -> PyResult<Bound<'py, PyDict>> {
+        // fields with `init=False` can't be set from the input
+        let fields = self
+            .fields
+            .iter()
+            .filter(|f| f.init)
+            .map(|f| (&f.py_name, &f.validator, is_required(&f.validator)));
+        let json_schema = object_json_schema(py, defs, fields, self.extra_behavior, self.extras_validator.as_deref())?;
+        json_schema.set_item(intern!(py, "title"), &self.dataclass_name)?;
+        Ok(json_schema)
+    }
+
     fn get_name(&self) -> &str {
         &self.validator_name
     }
@@ -509,7 +523,7 @@ impl Validator for DataclassValidator {
         if let Some(py_input) = input_as_python_instance(input, class) {
             if self.revalidate.should_revalidate(py_input, class) {
                 let input_dict = self.dataclass_to_dict(py_input)?;
-                let val_output = self.validator.validate(py, input_dict.as_any(), state)?;
+                let val_output = self.validator.validate_profiled(py, input_dict.as_any(), state)?;
                 let dc = create_class(self.class.bind(py))?;
                 self.set_dict_call(py, &dc, val_output, input)?;
                 Ok(dc.into())
@@ -525,7 +539,7 @@ impl Validator for DataclassValidator {
                 input,
             ))
         } else {
-            let val_output = self.validator.validate(py, input, state)?;
+            let val_output = self.validator.validate_profiled(py, input, state)?;
             state.floor_exactness(Exactness::Strict);
             let dc = create_class(self.class.bind(py))?;
             self.set_dict_call(py, &dc, val_output, input)?;
@@ -567,6 +581,10 @@ impl Validator for DataclassValidator {
         Ok(obj.to_object(py))
     }
 
+    fn json_schema<'py>(&self, py: Python<'py>, defs: &Bound<'py, PyDict>) -> PyResult<Bound<'py, PyDict>> {
+        self.validator.json_schema(py, defs)
+    }
+
     fn get_name(&self) -> &str {
         &self.name
     }
@@ -584,7 +602,7 @@ impl DataclassValidator {
         // we need to set `self_instance` to None for nested validators as we don't want to operate on the self_instance
         // instance anymore
         let state = &mut state.rebind_extra(|extra| extra.self_instance = None);
-        let val_output = self.validator.validate(py, input, state)?;
+        let val_output = self.validator.validate_profiled(py, input, state)?;
 
         self.set_dict_call(py, self_instance, val_output, input)?;
 
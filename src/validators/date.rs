@@ -11,6 +11,7 @@ use crate::input::{EitherDate, Input};
 use crate::tools::SchemaDict;
 use crate::validators::datetime::{NowConstraint, NowOp};
 
+use super::json_schema::string_json_schema;
 use super::Exactness;
 use super::{BuildValidator, CombinedValidator, DefinitionsBuilder, ValidationState, Validator};
 
@@ -80,10 +81,15 @@ impl Validator for DateValidator {
             check_constraint!(gt, GreaterThan);
 
             if let Some(ref today_constraint) = constraints.today {
-                let offset = today_constraint.utc_offset(py)?;
-                let today = Date::today(offset).map_err(|e| {
-                    py_schema_error_type!("Date::today() error: {}", e.get_documentation().unwrap_or("unknown"))
-                })?;
+                let today = match state.extra().now {
+                    Some(now_override) => today_constraint.now(py, Some(now_override))?.date,
+                    None => {
+                        let offset = today_constraint.utc_offset(py)?;
+                        Date::today(offset).map_err(|e| {
+                            py_schema_error_type!("Date::today() error: {}", e.get_documentation().unwrap_or("unknown"))
+                        })?
+                    }
+                };
                 // `if let Some(c)` to match behaviour of gt/lt/le/ge
                 if let Some(c) = raw_date.partial_cmp(&today) {
                     let date_compliant = today_constraint.op.compare(c);
@@ -100,6 +106,10 @@ impl Validator for DateValidator {
         Ok(date.try_into_py(py)?)
     }
 
+    fn json_schema<'py>(&self, py: Python<'py>, _defs: &Bound<'py, PyDict>) -> PyResult<Bound<'py, PyDict>> {
+        string_json_schema(py, "date")
+    }
+
     fn get_name(&self) -> &str {
         Self::EXPECTED_TYPE
     }
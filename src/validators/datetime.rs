@@ -1,8 +1,8 @@
 use pyo3::intern;
 use pyo3::prelude::*;
 use pyo3::sync::GILOnceCell;
-use pyo3::types::{PyDict, PyString};
-use speedate::{DateTime, Time};
+use pyo3::types::{IntoPyDict, PyDateTime, PyDict, PyString, PyTimeAccess, PyTzInfoAccess};
+use speedate::{DateTime, Time, TimeConfig};
 use std::cmp::Ordering;
 use strum::EnumMessage;
 
@@ -10,10 +10,11 @@ use crate::build_tools::{is_strict, py_schema_error_type};
 use crate::build_tools::{py_schema_err, schema_or_config_same};
 use crate::errors::ToErrorValue;
 use crate::errors::{py_err_string, ErrorType, ErrorTypeDefaults, ValError, ValResult};
-use crate::input::{EitherDateTime, Input};
+use crate::input::{pydatetime_as_datetime, EitherDateTime, Input};
 
 use crate::tools::SchemaDict;
 
+use super::json_schema::string_json_schema;
 use super::Exactness;
 use super::{BuildValidator, CombinedValidator, DefinitionsBuilder, ValidationState, Validator};
 
@@ -22,6 +23,132 @@ pub struct DateTimeValidator {
     strict: bool,
     constraints: Option<DateTimeConstraints>,
     microseconds_precision: speedate::MicrosecondsPrecisionOverflowBehavior,
+    coerce_to_utc: bool,
+    leap_second: LeapSecond,
+    fold: Option<FoldPolicy>,
+}
+
+/// How strings with a leap second are handled, speedate itself rejects a seconds value of 60.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum LeapSecond {
+    Error,
+    /// validate `23:59:60` as `23:59:59.999999`
+    Clamp,
+}
+
+impl LeapSecond {
+    fn from_py(schema: &Bound<'_, PyDict>) -> PyResult<Self> {
+        match schema.get_as::<Bound<'_, PyString>>(intern!(schema.py(), "leap_second"))? {
+            Some(s) => match s.to_str()? {
+                "error" => Ok(Self::Error),
+                "clamp" => Ok(Self::Clamp),
+                s => py_schema_err!("Invalid leap_second {:?}", s),
+            },
+            None => Ok(Self::Error),
+        }
+    }
+
+    /// With `Clamp`, parse a string which failed to parse because of its leap second again with the seconds
+    /// clamped, `Ok(None)` means the original error stands.
+    fn clamp<'py>(
+        self,
+        input: &(impl Input<'py> + ?Sized),
+        error: &ValError,
+        microseconds_precision: speedate::MicrosecondsPrecisionOverflowBehavior,
+    ) -> ValResult<Option<EitherDateTime<'py>>> {
+        let ValError::LineErrors(line_errors) = error else {
+            return Ok(None);
+        };
+        let is_parsing_error = line_errors
+            .iter()
+            .any(|line_error| matches!(line_error.error_type, ErrorType::DatetimeParsing { .. }));
+        if self != Self::Clamp || !is_parsing_error {
+            return Ok(None);
+        }
+        let Ok(either_str) = input.validate_str(false, false) else {
+            return Ok(None);
+        };
+        let Some(clamped) = clamp_leap_second(either_str.into_inner().as_cow()?.as_bytes()) else {
+            return Ok(None);
+        };
+        let config = TimeConfig {
+            microseconds_precision_overflow_behavior: microseconds_precision,
+            unix_timestamp_offset: Some(0),
+        };
+        Ok(DateTime::parse_bytes_with_config(&clamped, &config)
+            .ok()
+            .map(|mut datetime| {
+                datetime.time.microsecond = 999_999;
+                datetime.into()
+            }))
+    }
+}
+
+/// Replace the seconds of a `YYYY-MM-DDT23:59:60[.ffffff]...` datetime string with `59`, dropping any fraction,
+/// `None` if the string doesn't have a leap second, which can only be the last second of a day.
+fn clamp_leap_second(bytes: &[u8]) -> Option<Vec<u8>> {
+    if bytes.get(11..19) != Some(b"23:59:60") {
+        return None;
+    }
+    let mut end = 19;
+    if matches!(bytes.get(end), Some(b'.' | b',')) {
+        end += 1;
+        while bytes.get(end).is_some_and(u8::is_ascii_digit) {
+            end += 1;
+        }
+    }
+    Some([&bytes[..17], b"59", &bytes[end..]].concat())
+}
+
+/// How python datetimes whose UTC offset depends on `fold` are handled, i.e. wall times which are repeated or
+/// skipped by a DST transition, datetimes parsed from strings have fixed offsets so are never affected.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum FoldPolicy {
+    Error,
+    /// set `fold=0`
+    Earlier,
+    /// set `fold=1`
+    Later,
+}
+
+impl FoldPolicy {
+    fn from_py(schema: &Bound<'_, PyDict>) -> PyResult<Option<Self>> {
+        match schema.get_as::<Bound<'_, PyString>>(intern!(schema.py(), "fold"))? {
+            Some(s) => match s.to_str()? {
+                "error" => Ok(Some(Self::Error)),
+                "earlier" => Ok(Some(Self::Earlier)),
+                "later" => Ok(Some(Self::Later)),
+                s => py_schema_err!("Invalid fold {:?}", s),
+            },
+            None => Ok(None),
+        }
+    }
+
+    fn apply<'py>(
+        self,
+        datetime: EitherDateTime<'py>,
+        input: &(impl Input<'py> + ?Sized),
+    ) -> ValResult<EitherDateTime<'py>> {
+        let EitherDateTime::Py(py_dt) = &datetime else {
+            return Ok(datetime);
+        };
+        let py = py_dt.py();
+        if py_dt.get_tzinfo_bound().is_none() {
+            return Ok(datetime);
+        }
+        let fold = py_dt.get_fold();
+        let kwargs = [(intern!(py, "fold"), u8::from(!fold))].into_py_dict_bound(py);
+        let other = py_dt.call_method(intern!(py, "replace"), (), Some(&kwargs))?;
+        let utc_offset = py_dt.call_method0(intern!(py, "utcoffset"))?;
+        if utc_offset.eq(other.call_method0(intern!(py, "utcoffset"))?)? {
+            return Ok(datetime);
+        }
+        match (self, fold) {
+            (Self::Error, _) => Err(ValError::new(ErrorTypeDefaults::DatetimeAmbiguous, input)),
+            (Self::Earlier, false) | (Self::Later, true) => Ok(datetime),
+            (Self::Earlier, true) | (Self::Later, false) => Ok(EitherDateTime::Py(other.downcast_into()?)),
+        }
+    }
 }
 
 pub(crate) fn extract_microseconds_precision(
@@ -48,10 +175,18 @@ impl BuildValidator for DateTimeValidator {
         config: Option<&Bound<'_, PyDict>>,
         _definitions: &mut DefinitionsBuilder<CombinedValidator>,
     ) -> PyResult<CombinedValidator> {
+        let py = schema.py();
+        let coerce_to_utc = schema.get_as(intern!(py, "coerce_to_utc"))?.unwrap_or(false);
+        if coerce_to_utc && schema.contains(intern!(py, "tz_constraint"))? {
+            return py_schema_err!("`coerce_to_utc` cannot be combined with `tz_constraint`");
+        }
         Ok(Self {
             strict: is_strict(schema, config)?,
             constraints: DateTimeConstraints::from_py(schema)?,
             microseconds_precision: extract_microseconds_precision(schema, config)?,
+            coerce_to_utc,
+            leap_second: LeapSecond::from_py(schema)?,
+            fold: FoldPolicy::from_py(schema)?,
         }
         .into())
     }
@@ -69,26 +204,34 @@ impl Validator for DateTimeValidator {
         let strict = state.strict_or(self.strict);
         let datetime = match input.validate_datetime(strict, self.microseconds_precision) {
             Ok(val_match) => val_match.unpack(state),
-            // if the error was a parsing error, in lax mode we allow dates and add the time 00:00:00
-            Err(line_errors @ ValError::LineErrors(..)) if !strict => {
-                state.floor_exactness(Exactness::Lax);
-                datetime_from_date(input)?.ok_or(line_errors)?
+            Err(line_errors @ ValError::LineErrors(..)) => {
+                if let Some(datetime) = self
+                    .leap_second
+                    .clamp(input, &line_errors, self.microseconds_precision)?
+                {
+                    state.floor_exactness(Exactness::Lax);
+                    datetime
+                } else if !strict {
+                    // if the error was a parsing error, in lax mode we allow dates and add the time 00:00:00
+                    state.floor_exactness(Exactness::Lax);
+                    datetime_from_date(input)?.ok_or(line_errors)?
+                } else {
+                    return Err(line_errors);
+                }
             }
             Err(otherwise) => return Err(otherwise),
         };
+        let datetime = match self.fold {
+            Some(fold) => fold.apply(datetime, input)?,
+            None => datetime,
+        };
+        let datetime = if self.coerce_to_utc {
+            datetime_to_utc(py, datetime, input)?
+        } else {
+            datetime
+        };
         if let Some(constraints) = &self.constraints {
-            // if we get an error from as_speedate, it's probably because the input datetime was invalid
-            // specifically had an invalid tzinfo, hence here we return a validation error
-            let speedate_dt = match datetime.as_raw() {
-                Ok(dt) => dt,
-                Err(err) => {
-                    let error = py_err_string(py, err);
-                    return Err(ValError::new(
-                        ErrorType::DatetimeObjectInvalid { error, context: None },
-                        input,
-                    ));
-                }
-            };
+            let speedate_dt = datetime_as_raw(py, &datetime, input)?;
             macro_rules! check_constraint {
                 ($constraint:ident, $error:ident) => {
                     if let Some(constraint) = &constraints.$constraint {
@@ -111,10 +254,7 @@ impl Validator for DateTimeValidator {
             check_constraint!(gt, GreaterThan);
 
             if let Some(ref now_constraint) = constraints.now {
-                let offset = now_constraint.utc_offset(py)?;
-                let now = DateTime::now(offset).map_err(|e| {
-                    py_schema_error_type!("DateTime::now() error: {}", e.get_documentation().unwrap_or("unknown"))
-                })?;
+                let now = now_constraint.now(py, state.extra().now)?;
                 // `if let Some(c)` to match behaviour of gt/lt/le/ge
                 if let Some(c) = speedate_dt.partial_cmp(&now) {
                     let dt_compliant = now_constraint.op.compare(c);
@@ -135,11 +275,52 @@ impl Validator for DateTimeValidator {
         Ok(datetime.try_into_py(py)?)
     }
 
+    fn json_schema<'py>(&self, py: Python<'py>, _defs: &Bound<'py, PyDict>) -> PyResult<Bound<'py, PyDict>> {
+        string_json_schema(py, "date-time")
+    }
+
     fn get_name(&self) -> &str {
         Self::EXPECTED_TYPE
     }
 }
 
+/// if we get an error from as_speedate, it's probably because the input datetime was invalid
+/// specifically had an invalid tzinfo, hence here we return a validation error
+fn datetime_as_raw<'py>(
+    py: Python<'py>,
+    datetime: &EitherDateTime<'py>,
+    input: &(impl Input<'py> + ?Sized),
+) -> ValResult<DateTime> {
+    datetime.as_raw().map_err(|err| {
+        let error = py_err_string(py, err);
+        ValError::new(ErrorType::DatetimeObjectInvalid { error, context: None }, input)
+    })
+}
+
+/// Convert an aware datetime to UTC for `coerce_to_utc`, datetimes which are already in UTC are returned
+/// unchanged so Python inputs keep their original `tzinfo`, naive datetimes can't be converted and are rejected.
+fn datetime_to_utc<'py>(
+    py: Python<'py>,
+    datetime: EitherDateTime<'py>,
+    input: &(impl Input<'py> + ?Sized),
+) -> ValResult<EitherDateTime<'py>> {
+    let speedate_dt = datetime_as_raw(py, &datetime, input)?;
+    match speedate_dt.time.tz_offset {
+        None => Err(ValError::new(ErrorTypeDefaults::TimezoneAware, input)),
+        Some(0) => Ok(datetime),
+        Some(_) => match speedate_dt.in_timezone(0) {
+            Ok(utc_dt) => Ok(EitherDateTime::Raw(utc_dt)),
+            Err(err) => Err(ValError::new(
+                ErrorType::DatetimeObjectInvalid {
+                    error: err.get_documentation().unwrap_or_default().to_string(),
+                    context: None,
+                },
+                input,
+            )),
+        },
+    }
+}
+
 /// In lax mode, if the input is not a datetime, we try parsing the input as a date and add the "00:00:00" time.
 /// Ok(None) means that this is not relevant to datetimes (the input was not a date nor a string)
 fn datetime_from_date<'py>(input: &(impl Input<'py> + ?Sized)) -> Result<Option<EitherDateTime<'py>>, ValError> {
@@ -200,7 +381,15 @@ impl DateTimeConstraints {
             ge: py_datetime_as_datetime(schema, intern!(py, "ge"))?,
             gt: py_datetime_as_datetime(schema, intern!(py, "gt"))?,
             now: NowConstraint::from_py(schema)?,
-            tz: TZConstraint::from_py(schema)?,
+            tz: match schema.get_as(intern!(py, "require_utc"))? {
+                Some(true) => {
+                    if schema.contains(intern!(py, "tz_constraint"))? {
+                        return py_schema_err!("`require_utc` cannot be combined with `tz_constraint`");
+                    }
+                    Some(TZConstraint::Aware(Some(0)))
+                }
+                _ => TZConstraint::from_py(schema)?,
+            },
         };
         if c.le.is_some() || c.lt.is_some() || c.ge.is_some() || c.gt.is_some() || c.now.is_some() || c.tz.is_some() {
             Ok(Some(c))
@@ -266,6 +455,23 @@ impl NowConstraint {
         }
     }
 
+    /// The current time in the constraint's UTC offset, `now_override` is used instead of the real clock if set
+    /// (naive overrides are assumed to be in the constraint's UTC offset).
+    pub fn now(&self, py: Python, now_override: Option<&Bound<'_, PyDateTime>>) -> PyResult<DateTime> {
+        let offset = self.utc_offset(py)?;
+        let now = match now_override {
+            Some(now_override) => {
+                let now = pydatetime_as_datetime(now_override)?;
+                match now.time.tz_offset {
+                    Some(_) => now.in_timezone(offset),
+                    None => now.with_timezone_offset(Some(offset)),
+                }
+            }
+            None => DateTime::now(offset),
+        };
+        now.map_err(|e| py_schema_error_type!("DateTime::now() error: {}", e.get_documentation().unwrap_or("unknown")))
+    }
+
     pub fn from_py(schema: &Bound<'_, PyDict>) -> PyResult<Option<Self>> {
         let py = schema.py();
         match schema.get_as::<Bound<'_, PyString>>(intern!(py, "now_op"))? {
@@ -9,10 +9,11 @@ use crate::errors::ErrorType;
 use crate::errors::ValResult;
 use crate::errors::{ErrorTypeDefaults, Number};
 use crate::errors::{ToErrorValue, ValError};
-use crate::input::Input;
+use crate::input::{Input, StringParseOptions};
 use crate::tools::SchemaDict;
 
-use super::{BuildValidator, CombinedValidator, DefinitionsBuilder, ValidationState, Validator};
+use super::json_schema::{any_of_json_schema, new_schema, set_optional};
+use super::{BuildValidator, CombinedValidator, DefinitionsBuilder, Exactness, ValidationState, Validator};
 
 static DECIMAL_TYPE: GILOnceCell<Py<PyType>> = GILOnceCell::new();
 
@@ -33,6 +34,7 @@ pub fn get_decimal_type(py: Python) -> &Bound<'_, PyType> {
 pub struct DecimalValidator {
     strict: bool,
     allow_inf_nan: bool,
+    allow_bool: bool,
     check_digits: bool,
     multiple_of: Option<Py<PyAny>>,
     le: Option<Py<PyAny>>,
@@ -62,6 +64,7 @@ impl BuildValidator for DecimalValidator {
         Ok(Self {
             strict: is_strict(schema, config)?,
             allow_inf_nan,
+            allow_bool: schema_or_config_same(schema, config, intern!(py, "allow_bool"))?.unwrap_or(false),
             check_digits: decimal_places.is_some() || max_digits.is_some(),
             decimal_places,
             multiple_of: schema.get_as(intern!(py, "multiple_of"))?,
@@ -123,7 +126,21 @@ impl Validator for DecimalValidator {
         input: &(impl Input<'py> + ?Sized),
         state: &mut ValidationState<'_, 'py>,
     ) -> ValResult<PyObject> {
-        let decimal = input.validate_decimal(state.strict_or(self.strict), py)?;
+        let strict = state.strict_or(self.strict);
+        let decimal = match input.validate_bool(true, StringParseOptions::BOOL_DEFAULT) {
+            // bools are rejected by default, unlike by int and float validators
+            Ok(val_match) if self.allow_bool && !strict => {
+                state.floor_exactness(Exactness::Lax);
+                create_decimal(&u8::from(val_match.into_inner()).into_py(py).into_bound(py), input)?
+            }
+            _ => input.validate_decimal(strict, py)?,
+        };
+        // inputs accepted in strict mode are instances of `Decimal` (or its subclasses) and all JSON inputs
+        match input.as_python() {
+            Some(py_input) if py_input.is_exact_instance(get_decimal_type(py)) => {}
+            Some(py_input) if !py_input.is_instance(get_decimal_type(py))? => state.floor_exactness(Exactness::Lax),
+            _ => state.floor_exactness(Exactness::Strict),
+        }
 
         if !self.allow_inf_nan || self.check_digits {
             if !decimal.call_method0(intern!(py, "is_finite"))?.extract()? {
@@ -259,6 +276,17 @@ impl Validator for DecimalValidator {
         Ok(decimal.into())
     }
 
+    fn json_schema<'py>(&self, py: Python<'py>, _defs: &Bound<'py, PyDict>) -> PyResult<Bound<'py, PyDict>> {
+        let number = new_schema(py, "number")?;
+        let as_float = |value: &Option<Py<PyAny>>| value.as_ref().map(|v| v.extract::<f64>(py)).transpose();
+        set_optional(&number, "multipleOf", as_float(&self.multiple_of)?)?;
+        set_optional(&number, "maximum", as_float(&self.le)?)?;
+        set_optional(&number, "exclusiveMaximum", as_float(&self.lt)?)?;
+        set_optional(&number, "minimum", as_float(&self.ge)?)?;
+        set_optional(&number, "exclusiveMinimum", as_float(&self.gt)?)?;
+        any_of_json_schema(py, [number, new_schema(py, "string")?])
+    }
+
     fn get_name(&self) -> &str {
         Self::EXPECTED_TYPE
     }
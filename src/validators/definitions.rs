@@ -10,7 +10,9 @@ use crate::input::Input;
 use crate::recursion_guard::RecursionGuard;
 use crate::tools::SchemaDict;
 
-use super::{build_validator, BuildValidator, CombinedValidator, DefinitionsBuilder, ValidationState, Validator};
+use super::{
+    build_validator, BuildValidator, CombinedValidator, DefinitionsBuilder, Exactness, ValidationState, Validator,
+};
 
 #[derive(Debug, Clone)]
 pub struct DefinitionsValidatorBuilder;
@@ -77,14 +79,29 @@ impl Validator for DefinitionRefValidator {
     ) -> ValResult<PyObject> {
         self.definition.read(|validator| {
             let validator = validator.unwrap();
-            if let Some(id) = input.as_python().map(py_identity) {
-                // Python objects can be cyclic, so need recursion guard
-                let Ok(mut guard) = RecursionGuard::new(state, id, self.definition.id()) else {
-                    return Err(ValError::new(ErrorTypeDefaults::RecursionLoop, input));
+            if let Some(obj) = input.as_python() {
+                let id = py_identity(obj);
+                let Some(key) = state.shared_cache_key(id, self.definition.id()) else {
+                    return validate_guarded(py, validator, input, id, self.definition.id(), state);
                 };
-                validator.validate(py, input, guard.state())
+                if let Some(output) = state.shared_cache_get(py, &key) {
+                    return Ok(output);
+                }
+                // record the exactness of this validation alone so it can be reapplied on a cache hit
+                let outer_exactness = state.exactness;
+                if outer_exactness.is_some() {
+                    state.exactness = Some(Exactness::Exact);
+                }
+                let result = validate_guarded(py, validator, input, id, self.definition.id(), state);
+                let exactness = std::mem::replace(&mut state.exactness, outer_exactness);
+                let output = result?;
+                if let Some(exactness) = exactness {
+                    state.floor_exactness(exactness);
+                }
+                state.shared_cache_insert(key, obj, &output, exactness);
+                Ok(output)
             } else {
-                validator.validate(py, input, state)
+                validator.validate_profiled(py, input, state)
             }
         })
     }
@@ -106,11 +123,42 @@ impl Validator for DefinitionRefValidator {
         })
     }
 
+    fn json_schema<'py>(&self, py: Python<'py>, defs: &Bound<'py, PyDict>) -> PyResult<Bound<'py, PyDict>> {
+        let reference = self.definition.reference();
+        // the placeholder is inserted first so recursive references don't recurse forever
+        if !defs.contains(reference)? {
+            defs.set_item(reference, PyDict::new_bound(py))?;
+            let json_schema = self.definition.read(|validator| match validator {
+                Some(validator) => validator.json_schema(py, defs),
+                None => Ok(PyDict::new_bound(py)),
+            })?;
+            defs.set_item(reference, json_schema)?;
+        }
+        let json_schema = PyDict::new_bound(py);
+        json_schema.set_item(intern!(py, "$ref"), format!("#/$defs/{reference}"))?;
+        Ok(json_schema)
+    }
+
     fn get_name(&self) -> &str {
         self.definition.get_or_init_name(|v| v.get_name().into())
     }
 }
 
+fn validate_guarded<'py>(
+    py: Python<'py>,
+    validator: &CombinedValidator,
+    input: &(impl Input<'py> + ?Sized),
+    obj_id: usize,
+    node_id: usize,
+    state: &mut ValidationState<'_, 'py>,
+) -> ValResult<PyObject> {
+    // Python objects can be cyclic, so need recursion guard
+    let Ok(mut guard) = RecursionGuard::new(state, obj_id, node_id) else {
+        return Err(ValError::new(ErrorTypeDefaults::RecursionLoop, input));
+    };
+    validator.validate_profiled(py, input, guard.state())
+}
+
 fn py_identity(obj: &Bound<'_, PyAny>) -> usize {
     obj.as_ptr() as usize
 }
@@ -1,9 +1,10 @@
+use pyo3::exceptions::PyTypeError;
 use pyo3::intern;
 use pyo3::prelude::*;
-use pyo3::types::PyDict;
+use pyo3::types::{PyDict, PyString, PyType};
 
-use crate::build_tools::is_strict;
-use crate::errors::{LocItem, ValError, ValLineError, ValResult};
+use crate::build_tools::{is_strict, py_schema_err};
+use crate::errors::{ErrorTypeDefaults, LocItem, ValError, ValLineError, ValResult};
 use crate::input::BorrowInput;
 use crate::input::ConsumeIterator;
 use crate::input::{Input, ValidatedDict};
@@ -11,7 +12,8 @@ use crate::input::{Input, ValidatedDict};
 use crate::tools::SchemaDict;
 
 use super::any::AnyValidator;
-use super::list::length_check;
+use super::json_schema::{new_schema, set_optional};
+use super::list::{check_exact_type, length_check};
 use super::{build_validator, BuildValidator, CombinedValidator, DefinitionsBuilder, ValidationState, Validator};
 
 #[derive(Debug)]
@@ -21,9 +23,34 @@ pub struct DictValidator {
     value_validator: Box<CombinedValidator>,
     min_length: Option<usize>,
     max_length: Option<usize>,
+    mapping_type: Option<Py<PyType>>,
+    duplicate_keys: DuplicateKeys,
+    allow_subclass: bool,
     name: String,
 }
 
+/// Which value to keep when the input yields the same key more than once, e.g. multi-value mappings
+/// or JSON objects with repeated keys.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum DuplicateKeys {
+    First,
+    Last,
+}
+
+impl DuplicateKeys {
+    fn from_py(schema: &Bound<'_, PyDict>) -> PyResult<Self> {
+        let py = schema.py();
+        match schema.get_as::<Bound<'_, PyString>>(intern!(py, "mapping_duplicate_keys"))? {
+            Some(s) => match s.to_str()? {
+                "first" => Ok(Self::First),
+                "last" => Ok(Self::Last),
+                s => py_schema_err!("Invalid mapping_duplicate_keys {:?}", s),
+            },
+            None => Ok(Self::Last),
+        }
+    }
+}
+
 impl BuildValidator for DictValidator {
     const EXPECTED_TYPE: &'static str = "dict";
 
@@ -53,6 +80,9 @@ impl BuildValidator for DictValidator {
             value_validator,
             min_length: schema.get_as(intern!(py, "min_length"))?,
             max_length: schema.get_as(intern!(py, "max_length"))?,
+            mapping_type: schema.get_as(intern!(py, "mapping_type"))?,
+            duplicate_keys: DuplicateKeys::from_py(schema)?,
+            allow_subclass: schema.get_as(intern!(py, "allow_subclass"))?.unwrap_or(true),
             name,
         }
         .into())
@@ -61,7 +91,8 @@ impl BuildValidator for DictValidator {
 
 impl_py_gc_traverse!(DictValidator {
     key_validator,
-    value_validator
+    value_validator,
+    mapping_type
 });
 
 impl Validator for DictValidator {
@@ -72,16 +103,43 @@ impl Validator for DictValidator {
         state: &mut ValidationState<'_, 'py>,
     ) -> ValResult<PyObject> {
         let strict = state.strict_or(self.strict);
+        check_exact_type::<PyDict>(input, strict, self.allow_subclass, ErrorTypeDefaults::DictType)?;
         let dict = input.validate_dict(strict)?;
-        dict.iterate(ValidateToDict {
+        let output = dict.iterate(ValidateToDict {
             py,
             input,
             min_length: self.min_length,
             max_length: self.max_length,
             key_validator: &self.key_validator,
             value_validator: &self.value_validator,
+            duplicate_keys: self.duplicate_keys,
             state,
-        })?
+        })??;
+        match self.mapping_type {
+            Some(ref mapping_type) => {
+                let mapping_type = mapping_type.bind(py);
+                mapping_type.call1((output,)).map(Bound::unbind).map_err(|err| {
+                    let type_err = PyTypeError::new_err(format!(
+                        "Unable to construct `{}` from the validated dict: {err}",
+                        mapping_type.qualname().unwrap_or_else(|_| "mapping_type".to_string())
+                    ));
+                    type_err.set_cause(py, Some(err));
+                    type_err.into()
+                })
+            }
+            None => Ok(output),
+        }
+    }
+
+    fn json_schema<'py>(&self, py: Python<'py>, defs: &Bound<'py, PyDict>) -> PyResult<Bound<'py, PyDict>> {
+        let json_schema = new_schema(py, "object")?;
+        json_schema.set_item(
+            intern!(py, "additionalProperties"),
+            self.value_validator.json_schema(py, defs)?,
+        )?;
+        set_optional(&json_schema, "minProperties", self.min_length)?;
+        set_optional(&json_schema, "maxProperties", self.max_length)?;
+        Ok(json_schema)
     }
 
     fn get_name(&self) -> &str {
@@ -96,6 +154,7 @@ struct ValidateToDict<'a, 's, 'py, I: Input<'py> + ?Sized> {
     max_length: Option<usize>,
     key_validator: &'a CombinedValidator,
     value_validator: &'a CombinedValidator,
+    duplicate_keys: DuplicateKeys,
     state: &'a mut ValidationState<'s, 'py>,
 }
 
@@ -112,24 +171,44 @@ where
 
         for item_result in iterator {
             let (key, value) = item_result?;
-            let output_key = match self.key_validator.validate(self.py, key.borrow_input(), self.state) {
+            // invalid keys can't be replaced with `None`, so when collecting errors their entry is dropped instead
+            let key_result = self.state.without_collecting_errors(|state| {
+                self.key_validator.validate_profiled(self.py, key.borrow_input(), state)
+            });
+            let output_key = match key_result {
                 Ok(value) => Some(value),
                 Err(ValError::LineErrors(line_errors)) => {
-                    for err in line_errors {
-                        // these are added in reverse order so [key] is shunted along by the second call
-                        errors.push(err.with_outer_location("[key]").with_outer_location(key.clone()));
+                    // these are added in reverse order so [key] is shunted along by the second call
+                    let line_errors = line_errors
+                        .into_iter()
+                        .map(|err| err.with_outer_location("[key]").with_outer_location(key.clone()));
+                    if self.state.is_collecting_errors() {
+                        self.state.collect_dropped(line_errors);
+                    } else {
+                        self.state.collect_errors(&mut errors, line_errors);
                     }
                     None
                 }
                 Err(ValError::Omit) => continue,
                 Err(err) => return Err(err),
             };
-            let output_value = match self.value_validator.validate(self.py, value.borrow_input(), self.state) {
+            if self.duplicate_keys == DuplicateKeys::First {
+                if let Some(ref output_key) = output_key {
+                    if output.contains(output_key)? {
+                        continue;
+                    }
+                }
+            }
+            let start = self.state.collected_count();
+            let value_result = self
+                .value_validator
+                .validate_profiled(self.py, value.borrow_input(), self.state);
+            let locate = |err: ValLineError| err.with_outer_location(key.clone());
+            let output_value = match self.state.collect_item(self.py, value_result, start, locate) {
                 Ok(value) => Some(value),
                 Err(ValError::LineErrors(line_errors)) => {
-                    for err in line_errors {
-                        errors.push(err.with_outer_location(key.clone()));
-                    }
+                    let line_errors = line_errors.into_iter().map(|err| err.with_outer_location(key.clone()));
+                    self.state.collect_errors(&mut errors, line_errors);
                     None
                 }
                 Err(ValError::Omit) => continue,
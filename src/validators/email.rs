@@ -0,0 +1,130 @@
+use pyo3::prelude::*;
+use pyo3::types::PyDict;
+
+use crate::build_tools::is_strict;
+use crate::errors::{ErrorType, ValError, ValResult};
+use crate::input::Input;
+
+use super::json_schema::string_json_schema;
+use super::{BuildValidator, CombinedValidator, DefinitionsBuilder, ValidationState, Validator};
+
+// limits from RFC 5321
+const MAX_LOCAL_LENGTH: usize = 64;
+const MAX_DOMAIN_LENGTH: usize = 253;
+const MAX_LABEL_LENGTH: usize = 63;
+
+/// Structural validation of email addresses, no DNS lookups are performed so an address passing
+/// validation isn't necessarily deliverable.
+#[derive(Debug, Clone)]
+pub struct EmailValidator {
+    strict: bool,
+}
+
+impl BuildValidator for EmailValidator {
+    const EXPECTED_TYPE: &'static str = "email";
+
+    fn build(
+        schema: &Bound<'_, PyDict>,
+        config: Option<&Bound<'_, PyDict>>,
+        _definitions: &mut DefinitionsBuilder<CombinedValidator>,
+    ) -> PyResult<CombinedValidator> {
+        Ok(Self {
+            strict: is_strict(schema, config)?,
+        }
+        .into())
+    }
+}
+
+impl_py_gc_traverse!(EmailValidator {});
+
+impl Validator for EmailValidator {
+    fn validate<'py>(
+        &self,
+        py: Python<'py>,
+        input: &(impl Input<'py> + ?Sized),
+        state: &mut ValidationState<'_, 'py>,
+    ) -> ValResult<PyObject> {
+        let either_str = input.validate_str(state.strict_or(self.strict), false)?.unpack(state);
+        let cow = either_str.as_cow()?;
+        let normalized = normalize_email(cow.trim()).map_err(|reason| {
+            ValError::new(
+                ErrorType::EmailParsing {
+                    reason: reason.to_string(),
+                    context: None,
+                },
+                input,
+            )
+        })?;
+        Ok(state.maybe_cached_str(py, &normalized).into_py(py))
+    }
+
+    fn json_schema<'py>(&self, py: Python<'py>, _defs: &Bound<'py, PyDict>) -> PyResult<Bound<'py, PyDict>> {
+        string_json_schema(py, "email")
+    }
+
+    fn get_name(&self) -> &str {
+        Self::EXPECTED_TYPE
+    }
+}
+
+/// Check the structure of `email` and return it with the domain lowercased, the local part is
+/// left untouched since it may be case sensitive.
+fn normalize_email(email: &str) -> Result<String, &'static str> {
+    let Some((local, domain)) = email.rsplit_once('@') else {
+        return Err("an email address must have an @-sign");
+    };
+    check_local(local)?;
+    let domain = domain.to_lowercase();
+    check_domain(&domain)?;
+    Ok(format!("{local}@{domain}"))
+}
+
+fn check_local(local: &str) -> Result<(), &'static str> {
+    if local.is_empty() {
+        return Err("there must be something before the @-sign");
+    }
+    if local.len() > MAX_LOCAL_LENGTH {
+        return Err("the part before the @-sign is too long");
+    }
+    if local.starts_with('.') || local.ends_with('.') {
+        return Err("the part before the @-sign cannot start or end with a period");
+    }
+    if local.contains("..") {
+        return Err("the part before the @-sign cannot contain two periods in a row");
+    }
+    // "atext" characters from RFC 5322, plus non-ASCII characters as allowed by RFC 6531
+    let valid_char = |c: char| c.is_alphanumeric() || c == '.' || "!#$%&'*+-/=?^_`{|}~".contains(c);
+    if !local.chars().all(valid_char) {
+        return Err("the part before the @-sign contains invalid characters");
+    }
+    Ok(())
+}
+
+fn check_domain(domain: &str) -> Result<(), &'static str> {
+    if domain.is_empty() {
+        return Err("there must be something after the @-sign");
+    }
+    if domain.len() > MAX_DOMAIN_LENGTH {
+        return Err("the part after the @-sign is too long");
+    }
+    let mut labels = 0;
+    for label in domain.split('.') {
+        if label.is_empty() {
+            return Err("the part after the @-sign cannot contain empty labels");
+        }
+        if label.len() > MAX_LABEL_LENGTH {
+            return Err("the part after the @-sign contains a label which is too long");
+        }
+        if label.starts_with('-') || label.ends_with('-') {
+            return Err("the part after the @-sign cannot have labels starting or ending with a hyphen");
+        }
+        if !label.chars().all(|c| c.is_alphanumeric() || c == '-') {
+            return Err("the part after the @-sign contains invalid characters");
+        }
+        labels += 1;
+    }
+    if labels < 2 {
+        return Err("the part after the @-sign is not valid, it should have a period");
+    }
+    Ok(())
+}
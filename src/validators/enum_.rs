@@ -12,7 +12,7 @@ use crate::input::Input;
 use crate::tools::{safe_repr, SchemaDict};
 
 use super::is_instance::class_repr;
-use super::literal::{expected_repr_name, LiteralLookup};
+use super::literal::{expected_repr_name, suggestion_context, LiteralLookup};
 use super::{BuildValidator, CombinedValidator, DefinitionsBuilder, Exactness, ValidationState, Validator};
 
 #[derive(Debug, Clone)]
@@ -46,7 +46,7 @@ impl BuildValidator for BuildEnumValidator {
         let class: Bound<PyType> = schema.get_as_req(intern!(py, "cls"))?;
         let class_repr = class_repr(schema, &class)?;
 
-        let lookup = LiteralLookup::new(py, expected.into_iter())?;
+        let lookup = LiteralLookup::new(py, expected.into_iter(), true)?;
 
         macro_rules! build {
             ($vv:ty, $name_prefix:literal) => {
@@ -56,6 +56,7 @@ impl BuildValidator for BuildEnumValidator {
                     lookup,
                     missing: schema.get_as(intern!(py, "missing"))?,
                     expected_repr: expected_repr_name(repr_args, "").0,
+                    suggest: schema.get_as(intern!(py, "suggest"))?.unwrap_or(false),
                     strict: is_strict(schema, config)?,
                     class_repr: class_repr.clone(),
                     name: format!("{}[{class_repr}]", $name_prefix),
@@ -90,6 +91,7 @@ pub struct EnumValidator<T: EnumValidateValue> {
     lookup: LiteralLookup<PyObject>,
     missing: Option<PyObject>,
     expected_repr: String,
+    suggest: bool,
     strict: bool,
     class_repr: String,
     name: String,
@@ -121,15 +123,9 @@ impl<T: EnumValidateValue> Validator for EnumValidator<T> {
             return Ok(v);
         } else if let Some(ref missing) = self.missing {
             state.floor_exactness(Exactness::Lax);
-            let enum_value = missing.bind(py).call1((input.to_object(py),)).map_err(|_| {
-                ValError::new(
-                    ErrorType::Enum {
-                        expected: self.expected_repr.clone(),
-                        context: None,
-                    },
-                    input,
-                )
-            })?;
+            let Ok(enum_value) = missing.bind(py).call1((input.to_object(py),)) else {
+                return Err(self.enum_error(py, input)?);
+            };
             // check enum_value is an instance of the class like
             // https://github.com/python/cpython/blob/v3.12.2/Lib/enum.py#L1148
             if enum_value.is_instance(class)? {
@@ -143,18 +139,41 @@ impl<T: EnumValidateValue> Validator for EnumValidator<T> {
                 return Err(type_error.into());
             }
         }
-        Err(ValError::new(
+        Err(self.enum_error(py, input)?)
+    }
+
+    fn json_schema<'py>(&self, py: Python<'py>, _defs: &Bound<'py, PyDict>) -> PyResult<Bound<'py, PyDict>> {
+        let values = self
+            .lookup
+            .values
+            .iter()
+            .map(|member| member.getattr(py, intern!(py, "value")))
+            .collect::<PyResult<Vec<_>>>()?;
+        let json_schema = PyDict::new_bound(py);
+        json_schema.set_item(intern!(py, "enum"), values)?;
+        json_schema.set_item(intern!(py, "title"), self.class.bind(py).name()?)?;
+        Ok(json_schema)
+    }
+
+    fn get_name(&self) -> &str {
+        &self.name
+    }
+}
+
+impl<T: EnumValidateValue> EnumValidator<T> {
+    fn enum_error<'py>(&self, py: Python<'py>, input: &(impl Input<'py> + ?Sized)) -> PyResult<ValError> {
+        let context = match self.suggest {
+            true => suggestion_context(py, &self.lookup, input)?,
+            false => None,
+        };
+        Ok(ValError::new(
             ErrorType::Enum {
                 expected: self.expected_repr.clone(),
-                context: None,
+                context,
             },
             input,
         ))
     }
-
-    fn get_name(&self) -> &str {
-        &self.name
-    }
 }
 
 #[derive(Debug, Clone)]
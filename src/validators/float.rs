@@ -4,12 +4,14 @@ use pyo3::intern;
 use pyo3::prelude::*;
 use pyo3::types::PyDict;
 
-use crate::build_tools::{is_strict, schema_or_config_same};
+use crate::build_tools::{explain_strict, is_strict, schema_or_config_same};
 use crate::errors::{ErrorType, ErrorTypeDefaults, ValError, ValResult};
-use crate::input::Input;
+use crate::input::{Input, StringParseOptions};
 use crate::tools::SchemaDict;
 
-use super::{BuildValidator, CombinedValidator, DefinitionsBuilder, ValidationState, Validator};
+use super::bool::check_allow_bool;
+use super::json_schema::{new_schema, set_optional};
+use super::{BuildValidator, CombinedValidator, DefinitionsBuilder, Exactness, ValidationState, Validator};
 
 pub struct FloatBuilder;
 
@@ -31,17 +33,40 @@ impl BuildValidator for FloatBuilder {
         } else {
             Ok(FloatValidator {
                 strict: is_strict(schema, config)?,
+                explain_strict: explain_strict(schema, config)?,
+                parse_options: StringParseOptions::from_schema(schema, config, StringParseOptions::default())?,
                 allow_inf_nan: schema_or_config_same(schema, config, intern!(py, "allow_inf_nan"))?.unwrap_or(true),
+                allow_int_to_float: schema.get_as(intern!(py, "allow_int_to_float"))?.unwrap_or(true),
+                allow_bool: schema_or_config_same(schema, config, intern!(py, "allow_bool"))?.unwrap_or(true),
+                nan_as_none: schema.get_as(intern!(py, "nan_as_none"))?.unwrap_or(false),
             }
             .into())
         }
     }
 }
 
+/// In strict mode with `allow_int_to_float` disabled, integers are rejected rather than converted to floats.
+fn check_int_to_float<'py>(
+    input: &(impl Input<'py> + ?Sized),
+    strict: bool,
+    allow_int_to_float: bool,
+) -> ValResult<()> {
+    if strict && !allow_int_to_float && input.validate_int(true, StringParseOptions::default()).is_ok() {
+        Err(ValError::new(ErrorTypeDefaults::FloatType, input))
+    } else {
+        Ok(())
+    }
+}
+
 #[derive(Debug, Clone)]
 pub struct FloatValidator {
     strict: bool,
+    explain_strict: bool,
+    parse_options: StringParseOptions,
     allow_inf_nan: bool,
+    allow_int_to_float: bool,
+    allow_bool: bool,
+    nan_as_none: bool,
 }
 
 impl BuildValidator for FloatValidator {
@@ -55,7 +80,12 @@ impl BuildValidator for FloatValidator {
         let py = schema.py();
         Ok(Self {
             strict: is_strict(schema, config)?,
+            explain_strict: explain_strict(schema, config)?,
+            parse_options: StringParseOptions::from_schema(schema, config, StringParseOptions::default())?,
             allow_inf_nan: schema_or_config_same(schema, config, intern!(py, "allow_inf_nan"))?.unwrap_or(true),
+            allow_int_to_float: schema.get_as(intern!(py, "allow_int_to_float"))?.unwrap_or(true),
+            allow_bool: schema_or_config_same(schema, config, intern!(py, "allow_bool"))?.unwrap_or(true),
+            nan_as_none: schema.get_as(intern!(py, "nan_as_none"))?.unwrap_or(false),
         }
         .into())
     }
@@ -70,13 +100,33 @@ impl Validator for FloatValidator {
         input: &(impl Input<'py> + ?Sized),
         state: &mut ValidationState<'_, 'py>,
     ) -> ValResult<PyObject> {
-        let either_float = input.validate_float(state.strict_or(self.strict))?.unpack(state);
+        let strict = state.strict_or(self.strict);
+        check_int_to_float(input, strict, self.allow_int_to_float)?;
+        if !strict {
+            check_allow_bool(input, self.allow_bool, ErrorTypeDefaults::FloatType)?;
+        }
+        let either_float = input
+            .validate_float(strict, self.parse_options)
+            .map_err(|e| {
+                e.explain_strict(py, FloatValidator::EXPECTED_TYPE, strict && self.explain_strict, || {
+                    input.validate_float(false, self.parse_options).is_ok()
+                })
+            })?
+            .unpack(state);
+        if self.nan_as_none && !strict && either_float.as_f64().is_nan() {
+            state.floor_exactness(Exactness::Lax);
+            return Ok(py.None());
+        }
         if !self.allow_inf_nan && !either_float.as_f64().is_finite() {
             return Err(ValError::new(ErrorTypeDefaults::FiniteNumber, input));
         }
         Ok(either_float.into_py(py))
     }
 
+    fn json_schema<'py>(&self, py: Python<'py>, _defs: &Bound<'py, PyDict>) -> PyResult<Bound<'py, PyDict>> {
+        new_schema(py, "number")
+    }
+
     fn get_name(&self) -> &str {
         Self::EXPECTED_TYPE
     }
@@ -85,7 +135,12 @@ impl Validator for FloatValidator {
 #[derive(Debug, Clone)]
 pub struct ConstrainedFloatValidator {
     strict: bool,
+    explain_strict: bool,
+    parse_options: StringParseOptions,
     allow_inf_nan: bool,
+    allow_int_to_float: bool,
+    allow_bool: bool,
+    nan_as_none: bool,
     multiple_of: Option<f64>,
     le: Option<f64>,
     lt: Option<f64>,
@@ -102,7 +157,23 @@ impl Validator for ConstrainedFloatValidator {
         input: &(impl Input<'py> + ?Sized),
         state: &mut ValidationState<'_, 'py>,
     ) -> ValResult<PyObject> {
-        let either_float = input.validate_float(state.strict_or(self.strict))?.unpack(state);
+        let strict = state.strict_or(self.strict);
+        check_int_to_float(input, strict, self.allow_int_to_float)?;
+        if !strict {
+            check_allow_bool(input, self.allow_bool, ErrorTypeDefaults::FloatType)?;
+        }
+        let either_float = input
+            .validate_float(strict, self.parse_options)
+            .map_err(|e| {
+                e.explain_strict(py, FloatValidator::EXPECTED_TYPE, strict && self.explain_strict, || {
+                    input.validate_float(false, self.parse_options).is_ok()
+                })
+            })?
+            .unpack(state);
+        if self.nan_as_none && !strict && either_float.as_f64().is_nan() {
+            state.floor_exactness(Exactness::Lax);
+            return Ok(py.None());
+        }
         let float: f64 = either_float.as_f64();
         if !self.allow_inf_nan && !float.is_finite() {
             return Err(ValError::new(ErrorTypeDefaults::FiniteNumber, input));
@@ -167,6 +238,16 @@ impl Validator for ConstrainedFloatValidator {
         Ok(either_float.into_py(py))
     }
 
+    fn json_schema<'py>(&self, py: Python<'py>, _defs: &Bound<'py, PyDict>) -> PyResult<Bound<'py, PyDict>> {
+        let json_schema = new_schema(py, "number")?;
+        set_optional(&json_schema, "multipleOf", self.multiple_of)?;
+        set_optional(&json_schema, "maximum", self.le)?;
+        set_optional(&json_schema, "exclusiveMaximum", self.lt)?;
+        set_optional(&json_schema, "minimum", self.ge)?;
+        set_optional(&json_schema, "exclusiveMinimum", self.gt)?;
+        Ok(json_schema)
+    }
+
     fn get_name(&self) -> &str {
         "constrained-float"
     }
@@ -182,7 +263,12 @@ impl BuildValidator for ConstrainedFloatValidator {
         let py = schema.py();
         Ok(Self {
             strict: is_strict(schema, config)?,
+            explain_strict: explain_strict(schema, config)?,
+            parse_options: StringParseOptions::from_schema(schema, config, StringParseOptions::default())?,
             allow_inf_nan: schema_or_config_same(schema, config, intern!(py, "allow_inf_nan"))?.unwrap_or(true),
+            allow_int_to_float: schema.get_as(intern!(py, "allow_int_to_float"))?.unwrap_or(true),
+            allow_bool: schema_or_config_same(schema, config, intern!(py, "allow_bool"))?.unwrap_or(true),
+            nan_as_none: schema.get_as(intern!(py, "nan_as_none"))?.unwrap_or(false),
             multiple_of: schema.get_as(intern!(py, "multiple_of"))?,
             le: schema.get_as(intern!(py, "le"))?,
             lt: schema.get_as(intern!(py, "lt"))?,
@@ -192,3 +278,35 @@ impl BuildValidator for ConstrainedFloatValidator {
         .into())
     }
 }
+
+impl ConstrainedFloatValidator {
+    /// Combine the constraints of two validators applied one after the other into a single validator,
+    /// only possible if they have the same options and no constraint is set on both. Validators with
+    /// `nan_as_none` are never merged, since the `None` returned by the first would fail the second.
+    pub fn merge(&self, other: &Self) -> Option<Self> {
+        if self.strict != other.strict
+            || self.explain_strict != other.explain_strict
+            || self.parse_options != other.parse_options
+            || self.allow_inf_nan != other.allow_inf_nan
+            || self.allow_int_to_float != other.allow_int_to_float
+            || self.allow_bool != other.allow_bool
+            || self.nan_as_none
+            || other.nan_as_none
+            || (self.multiple_of.is_some() && other.multiple_of.is_some())
+            || (self.le.is_some() && other.le.is_some())
+            || (self.lt.is_some() && other.lt.is_some())
+            || (self.ge.is_some() && other.ge.is_some())
+            || (self.gt.is_some() && other.gt.is_some())
+        {
+            return None;
+        }
+        Some(Self {
+            multiple_of: self.multiple_of.or(other.multiple_of),
+            le: self.le.or(other.le),
+            lt: self.lt.or(other.lt),
+            ge: self.ge.or(other.ge),
+            gt: self.gt.or(other.gt),
+            ..self.clone()
+        })
+    }
+}
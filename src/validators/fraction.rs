@@ -0,0 +1,153 @@
+use pyo3::intern;
+use pyo3::prelude::*;
+use pyo3::sync::GILOnceCell;
+use pyo3::types::{PyDict, PyTuple, PyType};
+
+use crate::build_tools::is_strict;
+use crate::errors::{ErrorTypeDefaults, ValError, ValResult};
+use crate::input::{BorrowInput, ConsumeIterator, Input, StringParseOptions, ValidatedTuple};
+
+use super::json_schema::{any_of_json_schema, new_schema};
+use super::{BuildValidator, CombinedValidator, DefinitionsBuilder, Exactness, ValidationState, Validator};
+
+/// The largest exponent accepted in strings like `"1.5e10"`, `Fraction` computes `10 ** exponent` so a huge
+/// exponent would hang validation.
+const MAX_EXPONENT: u32 = 4300;
+
+static FRACTION_TYPE: GILOnceCell<Py<PyType>> = GILOnceCell::new();
+
+fn get_fraction_type(py: Python<'_>) -> PyResult<&Bound<'_, PyType>> {
+    FRACTION_TYPE
+        .get_or_try_init(py, || py.import_bound("fractions")?.getattr("Fraction")?.extract())
+        .map(|fraction_type| fraction_type.bind(py))
+}
+
+/// Validates `fractions.Fraction` instances, and in lax mode ints, strings like `"3/4"` and
+/// `[numerator, denominator]` pairs of ints.
+#[derive(Debug, Clone)]
+pub struct FractionValidator {
+    strict: bool,
+}
+
+impl BuildValidator for FractionValidator {
+    const EXPECTED_TYPE: &'static str = "fraction";
+
+    fn build(
+        schema: &Bound<'_, PyDict>,
+        config: Option<&Bound<'_, PyDict>>,
+        _definitions: &mut DefinitionsBuilder<CombinedValidator>,
+    ) -> PyResult<CombinedValidator> {
+        Ok(Self {
+            strict: is_strict(schema, config)?,
+        }
+        .into())
+    }
+}
+
+impl_py_gc_traverse!(FractionValidator {});
+
+impl Validator for FractionValidator {
+    fn validate<'py>(
+        &self,
+        py: Python<'py>,
+        input: &(impl Input<'py> + ?Sized),
+        state: &mut ValidationState<'_, 'py>,
+    ) -> ValResult<PyObject> {
+        let fraction_type = get_fraction_type(py)?;
+        if let Some(py_input) = input.as_python() {
+            if py_input.is_exact_instance(fraction_type) {
+                return Ok(py_input.clone().unbind());
+            } else if py_input.is_instance(fraction_type)? {
+                state.floor_exactness(Exactness::Strict);
+                return Ok(py_input.clone().unbind());
+            } else if state.strict_or(self.strict) {
+                return Err(ValError::new(ErrorTypeDefaults::FractionType, input));
+            }
+        }
+        state.floor_exactness(Exactness::Lax);
+
+        let args = if let Ok(int) = input.validate_int(true, StringParseOptions::default()) {
+            PyTuple::new_bound(py, [int.into_inner().into_py(py)])
+        } else if let Ok(either_str) = input.validate_str(true, false) {
+            let either_str = either_str.into_inner();
+            let fraction_str = either_str.as_cow()?;
+            if exponent_too_large(&fraction_str) {
+                return Err(ValError::new(ErrorTypeDefaults::FractionParsing, input));
+            }
+            PyTuple::new_bound(py, [fraction_str.to_object(py)])
+        } else if let Ok(collection) = input.validate_tuple(false) {
+            let (numerator, denominator) = collection
+                .into_inner()
+                .iterate(FractionPair { py })??
+                .ok_or_else(|| ValError::new(ErrorTypeDefaults::FractionParsing, input))?;
+            PyTuple::new_bound(py, [numerator, denominator])
+        } else {
+            return Err(ValError::new(ErrorTypeDefaults::FractionType, input));
+        };
+
+        // zero denominators and malformed strings are rejected by `Fraction` itself
+        fraction_type
+            .call1(args)
+            .map(Bound::unbind)
+            .map_err(|_| ValError::new(ErrorTypeDefaults::FractionParsing, input))
+    }
+
+    fn json_schema<'py>(&self, py: Python<'py>, _defs: &Bound<'py, PyDict>) -> PyResult<Bound<'py, PyDict>> {
+        let pair = new_schema(py, "array")?;
+        let integer = new_schema(py, "integer")?;
+        pair.set_item(intern!(py, "prefixItems"), [integer.copy()?, integer.copy()?])?;
+        pair.set_item(intern!(py, "items"), false)?;
+        pair.set_item(intern!(py, "minItems"), 2)?;
+        pair.set_item(intern!(py, "maxItems"), 2)?;
+        any_of_json_schema(py, [integer, new_schema(py, "string")?, pair])
+    }
+
+    fn get_name(&self) -> &str {
+        Self::EXPECTED_TYPE
+    }
+}
+
+/// Whether a string has an exponent larger than `MAX_EXPONENT` in either direction, other malformed exponents
+/// are left to `Fraction` to reject.
+fn exponent_too_large(s: &str) -> bool {
+    let Some((_, exponent)) = s.split_once(['e', 'E']) else {
+        return false;
+    };
+    let digits = exponent.trim_end().trim_start_matches(['+', '-']).replace('_', "");
+    if digits.is_empty() || !digits.bytes().all(|b| b.is_ascii_digit()) {
+        return false;
+    }
+    let significant = digits.trim_start_matches('0');
+    !significant.is_empty()
+        && significant
+            .parse::<u32>()
+            .map_or(true, |exponent| exponent > MAX_EXPONENT)
+}
+
+/// Collects the numerator and denominator of a `[numerator, denominator]` pair, `None` if the input isn't
+/// exactly two ints.
+struct FractionPair<'py> {
+    py: Python<'py>,
+}
+
+impl<'py, T> ConsumeIterator<PyResult<T>> for FractionPair<'py>
+where
+    T: BorrowInput<'py>,
+{
+    type Output = ValResult<Option<(PyObject, PyObject)>>;
+    fn consume_iterator(self, iterator: impl Iterator<Item = PyResult<T>>) -> Self::Output {
+        let mut items = Vec::with_capacity(2);
+        for item in iterator {
+            let item = item?;
+            let Ok(int) = item.borrow_input().validate_int(true, StringParseOptions::default()) else {
+                return Ok(None);
+            };
+            if items.len() == 2 {
+                return Ok(None);
+            }
+            items.push(int.into_inner().into_py(self.py));
+        }
+        let mut items = items.into_iter();
+        Ok(items.next().zip(items.next()))
+    }
+}
@@ -1,11 +1,13 @@
+use pyo3::intern;
 use pyo3::prelude::*;
 use pyo3::types::{PyDict, PyFrozenSet};
 
-use crate::errors::ValResult;
+use crate::errors::{ErrorTypeDefaults, ValResult};
 use crate::input::{validate_iter_to_set, BorrowInput, ConsumeIterator, Input, ValidatedSet};
 use crate::tools::SchemaDict;
 
-use super::list::min_length_check;
+use super::json_schema::{new_schema, set_optional};
+use super::list::{check_exact_type, min_length_check, ContainsCheck};
 use super::set::set_build;
 use super::validation_state::ValidationState;
 use super::{BuildValidator, CombinedValidator, DefinitionsBuilder, Validator};
@@ -16,6 +18,8 @@ pub struct FrozenSetValidator {
     item_validator: Box<CombinedValidator>,
     min_length: Option<usize>,
     max_length: Option<usize>,
+    contains: Option<ContainsCheck>,
+    allow_subclass: bool,
     name: String,
 }
 
@@ -24,7 +28,10 @@ impl BuildValidator for FrozenSetValidator {
     set_build!();
 }
 
-impl_py_gc_traverse!(FrozenSetValidator { item_validator });
+impl_py_gc_traverse!(FrozenSetValidator {
+    item_validator,
+    contains
+});
 
 impl Validator for FrozenSetValidator {
     fn validate<'py>(
@@ -33,7 +40,9 @@ impl Validator for FrozenSetValidator {
         input: &(impl Input<'py> + ?Sized),
         state: &mut ValidationState<'_, 'py>,
     ) -> ValResult<PyObject> {
-        let collection = input.validate_frozenset(state.strict_or(self.strict))?.unpack(state);
+        let strict = state.strict_or(self.strict);
+        check_exact_type::<PyFrozenSet>(input, strict, self.allow_subclass, ErrorTypeDefaults::FrozenSetType)?;
+        let collection = input.validate_frozenset(strict)?.unpack(state);
         let f_set = PyFrozenSet::empty_bound(py)?;
         collection.iterate(ValidateToFrozenSet {
             py,
@@ -44,9 +53,24 @@ impl Validator for FrozenSetValidator {
             state,
         })??;
         min_length_check!(input, "Frozenset", self.min_length, f_set);
+        if let Some(contains) = &self.contains {
+            contains.check(py, input, "Frozenset", f_set.iter(), state)?;
+        }
         Ok(f_set.into_py(py))
     }
 
+    fn json_schema<'py>(&self, py: Python<'py>, defs: &Bound<'py, PyDict>) -> PyResult<Bound<'py, PyDict>> {
+        let json_schema = new_schema(py, "array")?;
+        json_schema.set_item(intern!(py, "items"), self.item_validator.json_schema(py, defs)?)?;
+        json_schema.set_item(intern!(py, "uniqueItems"), true)?;
+        set_optional(&json_schema, "minItems", self.min_length)?;
+        set_optional(&json_schema, "maxItems", self.max_length)?;
+        if let Some(contains) = &self.contains {
+            contains.json_schema(py, defs, &json_schema)?;
+        }
+        Ok(json_schema)
+    }
+
     fn get_name(&self) -> &str {
         &self.name
     }
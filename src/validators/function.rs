@@ -6,8 +6,8 @@ use pyo3::types::{PyAny, PyDict, PyString};
 use pyo3::{intern, PyTraverseError, PyVisit};
 
 use crate::errors::{
-    ErrorType, PydanticCustomError, PydanticKnownError, PydanticOmit, ToErrorValue, ValError, ValResult,
-    ValidationError,
+    ErrorType, PydanticCustomError, PydanticKnownError, PydanticMultiError, PydanticOmit, ToErrorValue, ValError,
+    ValResult, ValidationError,
 };
 use crate::input::Input;
 use crate::py_gc::PyGcTraverse;
@@ -125,8 +125,9 @@ impl Validator for FunctionBeforeValidator {
         input: &(impl Input<'py> + ?Sized),
         state: &mut ValidationState<'_, 'py>,
     ) -> ValResult<PyObject> {
-        let validate = |v, s: &mut ValidationState<'_, 'py>| self.validator.validate(py, &v, s);
-        self._validate(validate, py, input, state)
+        let validate = |v, s: &mut ValidationState<'_, 'py>| self.validator.validate_profiled(py, &v, s);
+        // the function shouldn't be called with placeholder `None`s
+        state.without_collecting_errors(|state| self._validate(validate, py, input, state))
     }
     fn validate_assignment<'py>(
         &self,
@@ -142,6 +143,10 @@ impl Validator for FunctionBeforeValidator {
         self._validate(validate, py, obj, state)
     }
 
+    fn json_schema<'py>(&self, py: Python<'py>, defs: &Bound<'py, PyDict>) -> PyResult<Bound<'py, PyDict>> {
+        self.validator.json_schema(py, defs)
+    }
+
     fn get_name(&self) -> &str {
         &self.name
     }
@@ -191,8 +196,9 @@ impl Validator for FunctionAfterValidator {
         input: &(impl Input<'py> + ?Sized),
         state: &mut ValidationState<'_, 'py>,
     ) -> ValResult<PyObject> {
-        let validate = |v: &_, s: &mut ValidationState<'_, 'py>| self.validator.validate(py, v, s);
-        self._validate(validate, py, input, state)
+        let validate = |v: &_, s: &mut ValidationState<'_, 'py>| self.validator.validate_profiled(py, v, s);
+        // the function shouldn't be called with placeholder `None`s
+        state.without_collecting_errors(|state| self._validate(validate, py, input, state))
     }
     fn validate_assignment<'py>(
         &self,
@@ -208,6 +214,10 @@ impl Validator for FunctionAfterValidator {
         self._validate(validate, py, obj, state)
     }
 
+    fn json_schema<'py>(&self, py: Python<'py>, defs: &Bound<'py, PyDict>) -> PyResult<Bound<'py, PyDict>> {
+        self.validator.json_schema(py, defs)
+    }
+
     fn get_name(&self) -> &str {
         &self.name
     }
@@ -479,6 +489,8 @@ pub fn convert_err(py: Python<'_>, err: PyErr, input: impl ToErrorValue) -> ValE
             pydantic_value_error.into_val_error(input)
         } else if let Ok(pydantic_error_type) = error_value.extract::<PydanticKnownError>() {
             pydantic_error_type.into_val_error(input)
+        } else if let Ok(pydantic_multi_error) = error_value.extract::<PydanticMultiError>() {
+            pydantic_multi_error.into_val_error(input)
         } else if let Ok(validation_error) = err.value_bound(py).extract::<ValidationError>() {
             validation_error.into_val_error()
         } else {
@@ -3,16 +3,22 @@ use pyo3::intern;
 use pyo3::prelude::*;
 use pyo3::types::PyDict;
 
-use crate::build_tools::is_strict;
-use crate::errors::{ErrorType, ValError, ValResult};
-use crate::input::{Input, Int};
+use crate::build_tools::{explain_strict, is_strict, schema_or_config_same};
+use crate::errors::{ErrorType, ErrorTypeDefaults, ValError, ValResult};
+use crate::input::{Input, Int, StringParseOptions};
 use crate::tools::SchemaDict;
 
+use super::bool::check_allow_bool;
+use super::cached::{CachedInput, CachedValidator};
+use super::json_schema::{new_schema, set_optional};
 use super::{BuildValidator, CombinedValidator, DefinitionsBuilder, ValidationState, Validator};
 
 #[derive(Debug, Clone)]
 pub struct IntValidator {
     strict: bool,
+    explain_strict: bool,
+    parse_options: StringParseOptions,
+    allow_bool: bool,
 }
 
 impl BuildValidator for IntValidator {
@@ -29,14 +35,18 @@ impl BuildValidator for IntValidator {
             || schema.get_item(intern!(py, "lt"))?.is_some()
             || schema.get_item(intern!(py, "ge"))?.is_some()
             || schema.get_item(intern!(py, "gt"))?.is_some();
-        if use_constrained {
-            ConstrainedIntValidator::build(schema, config)
+        let validator = if use_constrained {
+            ConstrainedIntValidator::build(schema, config)?
         } else {
-            Ok(Self {
+            Self {
                 strict: is_strict(schema, config)?,
+                explain_strict: explain_strict(schema, config)?,
+                parse_options: StringParseOptions::from_schema(schema, config, StringParseOptions::default())?,
+                allow_bool: schema_or_config_same(schema, config, intern!(py, "allow_bool"))?.unwrap_or(true),
             }
-            .into())
-        }
+            .into()
+        };
+        CachedValidator::wrap(schema, validator, CachedInput::Int)
     }
 }
 
@@ -49,11 +59,24 @@ impl Validator for IntValidator {
         input: &(impl Input<'py> + ?Sized),
         state: &mut ValidationState<'_, 'py>,
     ) -> ValResult<PyObject> {
+        let strict = state.strict_or(self.strict);
+        if !strict {
+            check_allow_bool(input, self.allow_bool, ErrorTypeDefaults::IntType)?;
+        }
         input
-            .validate_int(state.strict_or(self.strict))
+            .validate_int(strict, self.parse_options)
+            .map_err(|e| {
+                e.explain_strict(py, Self::EXPECTED_TYPE, strict && self.explain_strict, || {
+                    input.validate_int(false, self.parse_options).is_ok()
+                })
+            })
             .map(|val_match| val_match.unpack(state).into_py(py))
     }
 
+    fn json_schema<'py>(&self, py: Python<'py>, _defs: &Bound<'py, PyDict>) -> PyResult<Bound<'py, PyDict>> {
+        new_schema(py, "integer")
+    }
+
     fn get_name(&self) -> &str {
         Self::EXPECTED_TYPE
     }
@@ -62,6 +85,9 @@ impl Validator for IntValidator {
 #[derive(Debug, Clone)]
 pub struct ConstrainedIntValidator {
     strict: bool,
+    explain_strict: bool,
+    parse_options: StringParseOptions,
+    allow_bool: bool,
     multiple_of: Option<Int>,
     le: Option<Int>,
     lt: Option<Int>,
@@ -78,7 +104,18 @@ impl Validator for ConstrainedIntValidator {
         input: &(impl Input<'py> + ?Sized),
         state: &mut ValidationState<'_, 'py>,
     ) -> ValResult<PyObject> {
-        let either_int = input.validate_int(state.strict_or(self.strict))?.unpack(state);
+        let strict = state.strict_or(self.strict);
+        if !strict {
+            check_allow_bool(input, self.allow_bool, ErrorTypeDefaults::IntType)?;
+        }
+        let either_int = input
+            .validate_int(strict, self.parse_options)
+            .map_err(|e| {
+                e.explain_strict(py, IntValidator::EXPECTED_TYPE, strict && self.explain_strict, || {
+                    input.validate_int(false, self.parse_options).is_ok()
+                })
+            })?
+            .unpack(state);
         let int_value = either_int.as_int()?;
 
         if let Some(ref multiple_of) = self.multiple_of {
@@ -139,6 +176,16 @@ impl Validator for ConstrainedIntValidator {
         Ok(either_int.into_py(py))
     }
 
+    fn json_schema<'py>(&self, py: Python<'py>, _defs: &Bound<'py, PyDict>) -> PyResult<Bound<'py, PyDict>> {
+        let json_schema = new_schema(py, "integer")?;
+        set_optional(&json_schema, "multipleOf", self.multiple_of.as_ref())?;
+        set_optional(&json_schema, "maximum", self.le.as_ref())?;
+        set_optional(&json_schema, "exclusiveMaximum", self.lt.as_ref())?;
+        set_optional(&json_schema, "minimum", self.ge.as_ref())?;
+        set_optional(&json_schema, "exclusiveMinimum", self.gt.as_ref())?;
+        Ok(json_schema)
+    }
+
     fn get_name(&self) -> &str {
         "constrained-int"
     }
@@ -149,6 +196,9 @@ impl ConstrainedIntValidator {
         let py = schema.py();
         Ok(Self {
             strict: is_strict(schema, config)?,
+            explain_strict: explain_strict(schema, config)?,
+            parse_options: StringParseOptions::from_schema(schema, config, StringParseOptions::default())?,
+            allow_bool: schema_or_config_same(schema, config, intern!(py, "allow_bool"))?.unwrap_or(true),
             multiple_of: schema.get_as(intern!(py, "multiple_of"))?,
             le: schema.get_as(intern!(py, "le"))?,
             lt: schema.get_as(intern!(py, "lt"))?,
@@ -157,4 +207,32 @@ impl ConstrainedIntValidator {
         }
         .into())
     }
+
+    /// Combine the constraints of two validators applied one after the other into a single validator,
+    /// only possible if they have the same options and no constraint is set on both.
+    pub fn merge(&self, other: &Self) -> Option<Self> {
+        if self.strict != other.strict
+            || self.explain_strict != other.explain_strict
+            || self.parse_options != other.parse_options
+            || self.allow_bool != other.allow_bool
+            || (self.multiple_of.is_some() && other.multiple_of.is_some())
+            || (self.le.is_some() && other.le.is_some())
+            || (self.lt.is_some() && other.lt.is_some())
+            || (self.ge.is_some() && other.ge.is_some())
+            || (self.gt.is_some() && other.gt.is_some())
+        {
+            return None;
+        }
+        Some(Self {
+            strict: self.strict,
+            explain_strict: self.explain_strict,
+            parse_options: self.parse_options,
+            allow_bool: self.allow_bool,
+            multiple_of: self.multiple_of.clone().or_else(|| other.multiple_of.clone()),
+            le: self.le.clone().or_else(|| other.le.clone()),
+            lt: self.lt.clone().or_else(|| other.lt.clone()),
+            ge: self.ge.clone().or_else(|| other.ge.clone()),
+            gt: self.gt.clone().or_else(|| other.gt.clone()),
+        })
+    }
 }
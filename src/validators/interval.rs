@@ -0,0 +1,215 @@
+use pyo3::exceptions::PyTypeError;
+use pyo3::intern;
+use pyo3::prelude::*;
+use pyo3::sync::GILOnceCell;
+use pyo3::types::{PyDict, PyTuple, PyType};
+
+use crate::build_tools::is_strict;
+use crate::errors::{ErrorType, ErrorTypeDefaults, ValError, ValLineError, ValResult};
+use crate::input::{BorrowInput, ConsumeIterator, Input, ValidatedTuple};
+use crate::tools::SchemaDict;
+
+use super::json_schema::new_schema;
+use super::{build_validator, BuildValidator, CombinedValidator, DefinitionsBuilder, ValidationState, Validator};
+
+static RANGE_TYPE: GILOnceCell<Py<PyType>> = GILOnceCell::new();
+
+fn get_range_type(py: Python<'_>) -> PyResult<&Bound<'_, PyType>> {
+    RANGE_TYPE
+        .get_or_try_init(py, || py.import_bound("builtins")?.getattr("range")?.extract())
+        .map(|range_type| range_type.bind(py))
+}
+
+#[derive(Debug)]
+pub struct IntervalValidator {
+    strict: bool,
+    validator: Box<CombinedValidator>,
+    allow_equal: bool,
+    name: String,
+}
+
+impl BuildValidator for IntervalValidator {
+    const EXPECTED_TYPE: &'static str = "interval";
+
+    fn build(
+        schema: &Bound<'_, PyDict>,
+        config: Option<&Bound<'_, PyDict>>,
+        definitions: &mut DefinitionsBuilder<CombinedValidator>,
+    ) -> PyResult<CombinedValidator> {
+        let py = schema.py();
+        let items_schema = schema.get_as_req(intern!(py, "items_schema"))?;
+        let validator = Box::new(build_validator(&items_schema, config, definitions)?);
+        let name = format!("{}[{}]", Self::EXPECTED_TYPE, validator.get_name());
+        Ok(Self {
+            strict: is_strict(schema, config)?,
+            validator,
+            allow_equal: schema.get_as(intern!(py, "allow_equal"))?.unwrap_or(true),
+            name,
+        }
+        .into())
+    }
+}
+
+impl_py_gc_traverse!(IntervalValidator { validator });
+
+impl Validator for IntervalValidator {
+    fn validate<'py>(
+        &self,
+        py: Python<'py>,
+        input: &(impl Input<'py> + ?Sized),
+        state: &mut ValidationState<'_, 'py>,
+    ) -> ValResult<PyObject> {
+        let (lower, upper) = match input.as_python() {
+            Some(range) if range.is_exact_instance(get_range_type(py)?) => {
+                if !range.getattr(intern!(py, "step"))?.eq(1)? {
+                    return Err(ValError::new(ErrorTypeDefaults::IntervalRangeStep, input));
+                }
+                // ranges exclude their stop, the interval ends at the last item in the range
+                let lower = range.getattr(intern!(py, "start"))?;
+                let upper = range
+                    .getattr(intern!(py, "stop"))?
+                    .call_method1(intern!(py, "__sub__"), (1,))?;
+                let lower = self.validator.validate_profiled(py, &lower, state);
+                let upper = self.validator.validate_profiled(py, &upper, state);
+                match (lower, upper) {
+                    (Ok(lower), Ok(upper)) => (lower, upper),
+                    (lower, upper) => {
+                        let mut errors = Vec::new();
+                        collect_errors(&mut errors, lower, 0)?;
+                        collect_errors(&mut errors, upper, 1)?;
+                        return Err(ValError::LineErrors(errors));
+                    }
+                }
+            }
+            _ => {
+                let strict = state.strict_or(self.strict);
+                let collection = input.validate_tuple(strict)?.unpack(state);
+                collection.iterate(ValidateEndpoints {
+                    py,
+                    input,
+                    validator: self,
+                    state,
+                })??
+            }
+        };
+
+        let valid = if self.allow_equal {
+            lower.bind(py).le(upper.bind(py))
+        } else {
+            lower.bind(py).lt(upper.bind(py))
+        };
+        let valid = match valid {
+            Ok(valid) => valid,
+            // e.g. the endpoints of an `any` interval are of types which can't be ordered
+            Err(err) if err.is_instance_of::<PyTypeError>(py) => {
+                return Err(ValError::new(ErrorTypeDefaults::IntervalNotComparable, input))
+            }
+            Err(err) => return Err(err.into()),
+        };
+        if valid {
+            Ok(PyTuple::new_bound(py, [lower, upper]).into_py(py))
+        } else {
+            let expected = if self.allow_equal {
+                "less than or equal to"
+            } else {
+                "less than"
+            };
+            Err(ValError::new(
+                ErrorType::IntervalInvalid {
+                    expected: expected.to_string(),
+                    context: None,
+                },
+                input,
+            ))
+        }
+    }
+
+    fn json_schema<'py>(&self, py: Python<'py>, defs: &Bound<'py, PyDict>) -> PyResult<Bound<'py, PyDict>> {
+        let json_schema = new_schema(py, "array")?;
+        let item_schema = self.validator.json_schema(py, defs)?;
+        json_schema.set_item(intern!(py, "prefixItems"), [item_schema.copy()?, item_schema])?;
+        json_schema.set_item(intern!(py, "items"), false)?;
+        json_schema.set_item(intern!(py, "minItems"), 2)?;
+        json_schema.set_item(intern!(py, "maxItems"), 2)?;
+        Ok(json_schema)
+    }
+
+    fn get_name(&self) -> &str {
+        &self.name
+    }
+}
+
+/// Add the errors from validating the endpoint at `index` to `errors`.
+fn collect_errors(errors: &mut Vec<ValLineError>, result: ValResult<PyObject>, index: usize) -> ValResult<()> {
+    match result {
+        Ok(_) => Ok(()),
+        Err(ValError::LineErrors(line_errors)) => {
+            errors.extend(line_errors.into_iter().map(|err| err.with_outer_location(index)));
+            Ok(())
+        }
+        Err(err) => Err(err),
+    }
+}
+
+struct ValidateEndpoints<'a, 's, 'py, I: Input<'py> + ?Sized> {
+    py: Python<'py>,
+    input: &'a I,
+    validator: &'a IntervalValidator,
+    state: &'a mut ValidationState<'s, 'py>,
+}
+
+impl<'py, T, I> ConsumeIterator<PyResult<T>> for ValidateEndpoints<'_, '_, 'py, I>
+where
+    T: BorrowInput<'py>,
+    I: Input<'py> + ?Sized,
+{
+    type Output = ValResult<(PyObject, PyObject)>;
+    fn consume_iterator(self, iterator: impl Iterator<Item = PyResult<T>>) -> ValResult<(PyObject, PyObject)> {
+        let mut endpoints = Vec::with_capacity(2);
+        let mut errors = Vec::new();
+        let mut actual_length = 0;
+        for item in iterator {
+            let item = item?;
+            // items past the upper bound are only counted for the error
+            if actual_length < 2 {
+                let result = self
+                    .validator
+                    .validator
+                    .validate_profiled(self.py, item.borrow_input(), self.state);
+                if let Ok(ref endpoint) = result {
+                    endpoints.push(endpoint.clone_ref(self.py));
+                }
+                collect_errors(&mut errors, result, actual_length)?;
+            }
+            actual_length += 1;
+        }
+
+        if actual_length < 2 {
+            errors.push(ValLineError::new(
+                ErrorType::TooShort {
+                    field_type: "Interval".to_string(),
+                    min_length: 2,
+                    actual_length,
+                    context: None,
+                },
+                self.input,
+            ));
+        } else if actual_length > 2 {
+            errors.push(ValLineError::new(
+                ErrorType::TooLong {
+                    field_type: "Interval".to_string(),
+                    max_length: 2,
+                    actual_length: Some(actual_length),
+                    context: None,
+                },
+                self.input,
+            ));
+        }
+
+        let mut endpoints = endpoints.into_iter();
+        match (endpoints.next(), endpoints.next()) {
+            (Some(lower), Some(upper)) if errors.is_empty() => Ok((lower, upper)),
+            _ => Err(ValError::LineErrors(errors)),
+        }
+    }
+}
@@ -1,13 +1,16 @@
+use pyo3::exceptions::PyTypeError;
 use pyo3::intern;
 use pyo3::prelude::*;
 use pyo3::types::PyDict;
 
-use jiter::{JsonValue, PartialMode, PythonParse};
+use jiter::{map_json_error, Jiter, JsonValue, PartialMode, PythonParse};
 
+use crate::build_tools::is_strict;
 use crate::errors::{ErrorType, ErrorTypeDefaults, ValError, ValLineError, ValResult};
 use crate::input::{EitherBytes, Input, InputType, ValidationMatch};
 use crate::tools::SchemaDict;
 
+use super::json_schema::new_schema;
 use super::{build_validator, BuildValidator, CombinedValidator, DefinitionsBuilder, ValidationState, Validator};
 
 #[derive(Debug)]
@@ -61,7 +64,7 @@ impl Validator for JsonValidator {
                 let mut json_state = state.rebind_extra(|e| {
                     e.input_type = InputType::Json;
                 });
-                validator.validate(py, &json_value, &mut json_state)
+                validator.validate_profiled(py, &json_value, &mut json_state)
             }
             None => {
                 let parse_builder = PythonParse {
@@ -79,11 +82,113 @@ impl Validator for JsonValidator {
         }
     }
 
+    fn json_schema<'py>(&self, py: Python<'py>, defs: &Bound<'py, PyDict>) -> PyResult<Bound<'py, PyDict>> {
+        let json_schema = new_schema(py, "string")?;
+        json_schema.set_item(intern!(py, "contentMediaType"), "application/json")?;
+        if let Some(validator) = &self.validator {
+            json_schema.set_item(intern!(py, "contentSchema"), validator.json_schema(py, defs)?)?;
+        }
+        Ok(json_schema)
+    }
+
     fn get_name(&self) -> &str {
         &self.name
     }
 }
 
+/// Checks a string is well-formed JSON without parsing it into a value, the string itself is returned.
+#[derive(Debug)]
+pub struct JsonStringValidator {
+    strict: bool,
+}
+
+impl BuildValidator for JsonStringValidator {
+    const EXPECTED_TYPE: &'static str = "json-string";
+
+    fn build(
+        schema: &Bound<'_, PyDict>,
+        config: Option<&Bound<'_, PyDict>>,
+        _definitions: &mut DefinitionsBuilder<CombinedValidator>,
+    ) -> PyResult<CombinedValidator> {
+        Ok(Self {
+            strict: is_strict(schema, config)?,
+        }
+        .into())
+    }
+}
+
+impl_py_gc_traverse!(JsonStringValidator {});
+
+impl Validator for JsonStringValidator {
+    fn validate<'py>(
+        &self,
+        py: Python<'py>,
+        input: &(impl Input<'py> + ?Sized),
+        state: &mut ValidationState<'_, 'py>,
+    ) -> ValResult<PyObject> {
+        let either_str = input.validate_str(state.strict_or(self.strict), false)?.unpack(state);
+        let json_bytes = either_str.as_cow()?;
+        let mut jiter = Jiter::new(json_bytes.as_bytes()).with_allow_inf_nan();
+        jiter.next_skip().and_then(|()| jiter.finish()).map_err(|e| {
+            ValError::new(
+                ErrorType::JsonInvalid {
+                    error: e.description(&jiter),
+                    context: None,
+                },
+                input,
+            )
+        })?;
+        Ok(either_str.as_py_string(py, state.cache_str()).into_py(py))
+    }
+
+    fn json_schema<'py>(&self, py: Python<'py>, _defs: &Bound<'py, PyDict>) -> PyResult<Bound<'py, PyDict>> {
+        let json_schema = new_schema(py, "string")?;
+        json_schema.set_item(intern!(py, "contentMediaType"), "application/json")?;
+        Ok(json_schema)
+    }
+
+    fn get_name(&self) -> &str {
+        Self::EXPECTED_TYPE
+    }
+}
+
+/// JSON data which has already been parsed, this can be validated with `SchemaValidator.validate_json_value`
+/// multiple times without re-parsing the raw JSON.
+#[pyclass(module = "pydantic_core._pydantic_core", frozen)]
+#[derive(Debug)]
+pub struct ParsedJson {
+    value: JsonValue<'static>,
+}
+
+impl ParsedJson {
+    pub fn value(&self) -> &JsonValue<'static> {
+        &self.value
+    }
+}
+
+#[pymethods]
+impl ParsedJson {
+    #[new]
+    #[pyo3(signature = (data, *, allow_inf_nan=true))]
+    fn py_new(data: &Bound<'_, PyAny>, allow_inf_nan: bool) -> PyResult<Self> {
+        let v_match = data
+            .validate_bytes(false)
+            .map_err(|_| PyTypeError::new_err("Expected bytes, bytearray or str"))?;
+        let json_either_bytes = v_match.into_inner();
+        let json_bytes = json_either_bytes.as_slice();
+        let value = JsonValue::parse_owned(json_bytes, allow_inf_nan).map_err(|e| map_json_error(json_bytes, &e))?;
+        Ok(Self { value })
+    }
+
+    fn to_python(&self, py: Python) -> PyObject {
+        self.value.to_object(py)
+    }
+
+    fn __repr__(&self, py: Python) -> PyResult<String> {
+        Ok(format!("ParsedJson({})", self.value.to_object(py).bind(py).repr()?))
+    }
+}
+
 pub fn validate_json_bytes<'a, 'py>(
     input: &'a (impl Input<'py> + ?Sized),
 ) -> ValResult<ValidationMatch<EitherBytes<'a, 'py>>> {
@@ -56,11 +56,15 @@ impl Validator for JsonOrPython {
         state: &mut ValidationState<'_, 'py>,
     ) -> ValResult<PyObject> {
         match state.extra().input_type {
-            InputType::Python => self.python.validate(py, input, state),
-            _ => self.json.validate(py, input, state),
+            InputType::Python => self.python.validate_profiled(py, input, state),
+            _ => self.json.validate_profiled(py, input, state),
         }
     }
 
+    fn json_schema<'py>(&self, py: Python<'py>, defs: &Bound<'py, PyDict>) -> PyResult<Bound<'py, PyDict>> {
+        self.json.json_schema(py, defs)
+    }
+
     fn get_name(&self) -> &str {
         &self.name
     }
@@ -0,0 +1,165 @@
+use std::cell::OnceCell;
+use std::ops::Range;
+
+use ahash::AHashMap;
+use jiter::{Jiter, JiterResult, JsonValue};
+use pyo3::exceptions::PyValueError;
+use pyo3::intern;
+use pyo3::prelude::*;
+use pyo3::types::{PyBytes, PyDict};
+
+use crate::definitions::DefinitionsBuilder;
+use crate::errors::ValResult;
+use crate::input::Input;
+use crate::tools::SchemaDict;
+
+use super::{build_validator, BuildValidator, CombinedValidator, ValidationState, Validator};
+
+/// The result of a `json-raw` validator, the validated value and the raw JSON it was validated from.
+#[pyclass(module = "pydantic_core._pydantic_core", get_all, frozen)]
+#[derive(Debug)]
+pub struct JsonRaw {
+    value: PyObject,
+    raw: Option<Py<PyBytes>>,
+}
+
+impl JsonRaw {
+    pub fn value(&self) -> &PyObject {
+        &self.value
+    }
+}
+
+#[pymethods]
+impl JsonRaw {
+    #[new]
+    #[pyo3(signature = (value, raw=None))]
+    fn py_new(value: PyObject, raw: Option<Py<PyBytes>>) -> Self {
+        Self { value, raw }
+    }
+
+    fn __repr__(&self, py: Python) -> PyResult<String> {
+        let raw = match self.raw {
+            Some(ref raw) => raw.bind(py).repr()?.to_string(),
+            None => "None".to_string(),
+        };
+        Ok(format!("JsonRaw(value={}, raw={raw})", self.value.bind(py).repr()?))
+    }
+}
+
+/// A JSON document being validated, used by `json-raw` validators to find the raw JSON of a value within it.
+pub trait JsonSource {
+    /// The raw JSON of the value at `address`, or `None` if it isn't part of the document.
+    fn raw_json(&self, address: usize) -> PyResult<Option<&[u8]>>;
+}
+
+pub struct JsonDocument<'v, 'd> {
+    data: &'d [u8],
+    root: &'v JsonValue<'d>,
+    // byte ranges of every value keyed by its address, only found once a `json-raw` validator needs them
+    spans: OnceCell<AHashMap<usize, Range<usize>>>,
+}
+
+impl<'v, 'd> JsonDocument<'v, 'd> {
+    pub fn new(data: &'d [u8], root: &'v JsonValue<'d>) -> Self {
+        Self {
+            data,
+            root,
+            spans: OnceCell::new(),
+        }
+    }
+}
+
+impl JsonSource for JsonDocument<'_, '_> {
+    fn raw_json(&self, address: usize) -> PyResult<Option<&[u8]>> {
+        let spans = match self.spans.get() {
+            Some(spans) => spans,
+            None => {
+                let mut spans = AHashMap::new();
+                let mut jiter = Jiter::new(self.data).with_allow_inf_nan();
+                find_spans(&mut jiter, self.root, &mut spans).map_err(|e| {
+                    PyValueError::new_err(format!(
+                        "Unable to find the raw JSON of values: {}",
+                        e.description(&jiter)
+                    ))
+                })?;
+                self.spans.get_or_init(|| spans)
+            }
+        };
+        Ok(spans.get(&address).map(|span| &self.data[span.clone()]))
+    }
+}
+
+/// Walk the JSON data alongside the value parsed from it, recording the byte range of each value.
+fn find_spans(jiter: &mut Jiter, value: &JsonValue<'_>, spans: &mut AHashMap<usize, Range<usize>>) -> JiterResult<()> {
+    let peek = jiter.peek()?;
+    let start = jiter.current_index();
+    match value {
+        JsonValue::Array(array) => {
+            jiter.known_array()?;
+            for item in array.iter() {
+                find_spans(jiter, item, spans)?;
+                jiter.array_step()?;
+            }
+        }
+        JsonValue::Object(object) => {
+            jiter.known_object()?;
+            for (_, item) in object.iter() {
+                find_spans(jiter, item, spans)?;
+                jiter.next_key()?;
+            }
+        }
+        _ => jiter.known_skip(peek)?,
+    }
+    spans.insert(value as *const JsonValue as usize, start..jiter.current_index());
+    Ok(())
+}
+
+#[derive(Debug)]
+pub struct JsonRawValidator {
+    validator: Box<CombinedValidator>,
+    name: String,
+}
+
+impl BuildValidator for JsonRawValidator {
+    const EXPECTED_TYPE: &'static str = "json-raw";
+
+    fn build(
+        schema: &Bound<'_, PyDict>,
+        config: Option<&Bound<'_, PyDict>>,
+        definitions: &mut DefinitionsBuilder<CombinedValidator>,
+    ) -> PyResult<CombinedValidator> {
+        let sub_schema = schema.get_as_req(intern!(schema.py(), "schema"))?;
+        let validator = Box::new(build_validator(&sub_schema, config, definitions)?);
+        let name = format!("{}[{}]", Self::EXPECTED_TYPE, validator.get_name());
+        Ok(Self { validator, name }.into())
+    }
+}
+
+impl_py_gc_traverse!(JsonRawValidator { validator });
+
+impl Validator for JsonRawValidator {
+    fn validate<'py>(
+        &self,
+        py: Python<'py>,
+        input: &(impl Input<'py> + ?Sized),
+        state: &mut ValidationState<'_, 'py>,
+    ) -> ValResult<PyObject> {
+        let value = self.validator.validate_profiled(py, input, state)?;
+        let address = std::ptr::addr_of!(*input).cast::<()>() as usize;
+        let raw = match state.json_source {
+            Some(source) => source
+                .raw_json(address)?
+                .map(|raw| PyBytes::new_bound(py, raw).unbind()),
+            None => None,
+        };
+        Ok(JsonRaw { value, raw }.into_py(py))
+    }
+
+    fn json_schema<'py>(&self, py: Python<'py>, defs: &Bound<'py, PyDict>) -> PyResult<Bound<'py, PyDict>> {
+        self.validator.json_schema(py, defs)
+    }
+
+    fn get_name(&self) -> &str {
+        &self.name
+    }
+}
@@ -0,0 +1,334 @@
+use pyo3::intern;
+use pyo3::prelude::*;
+use pyo3::types::{PyBool, PyDict, PyInt, PyList, PyString};
+
+use crate::build_tools::{py_schema_err, ExtraBehavior};
+
+use super::{CombinedValidator, Validator};
+
+/// JSON Schema keywords which can be translated to core schema.
+const SUPPORTED_KEYWORDS: &[&str] = &[
+    "type",
+    "properties",
+    "required",
+    "items",
+    "enum",
+    "minimum",
+    "maximum",
+    "minLength",
+    "maxLength",
+    "pattern",
+];
+
+/// Keywords which only apply to values of some types, JSON Schema ignores them for other types so they're only
+/// translated along with a `type`.
+const TYPE_KEYWORDS: &[&str] = &[
+    "properties",
+    "required",
+    "items",
+    "minimum",
+    "maximum",
+    "minLength",
+    "maxLength",
+    "pattern",
+];
+
+/// Keywords which only annotate a schema and have no effect on validation, these are ignored.
+const ANNOTATION_KEYWORDS: &[&str] = &["$schema", "$id", "$comment", "title", "description", "examples"];
+
+/// Translate a JSON Schema document into the equivalent core schema.
+///
+/// Only a subset of JSON Schema is supported, any other keywords raise a `SchemaError`. Since JSON Schema
+/// doesn't coerce values, the generated schemas are strict, objects allow additional properties.
+pub(crate) fn core_schema_from_json_schema<'py>(json_schema: &Bound<'py, PyAny>) -> PyResult<Bound<'py, PyDict>> {
+    translate(json_schema, "#")
+}
+
+fn translate<'py>(json_schema: &Bound<'py, PyAny>, path: &str) -> PyResult<Bound<'py, PyDict>> {
+    let py = json_schema.py();
+    if let Ok(b) = json_schema.downcast::<PyBool>() {
+        return if b.is_true() {
+            new_schema(py, "any")
+        } else {
+            py_schema_err!("Unsupported JSON Schema `false` at `{}`", path)
+        };
+    }
+    let Ok(json_schema) = json_schema.downcast::<PyDict>() else {
+        return py_schema_err!("Invalid JSON Schema at `{}`, expected an object or a boolean", path);
+    };
+
+    let mut unsupported = Vec::new();
+    for key in json_schema.keys() {
+        let key = key.str()?;
+        let key = key.to_str()?;
+        if !SUPPORTED_KEYWORDS.contains(&key) && !ANNOTATION_KEYWORDS.contains(&key) {
+            unsupported.push(format!("`{key}`"));
+        }
+    }
+    if !unsupported.is_empty() {
+        let plural = if unsupported.len() == 1 { "" } else { "s" };
+        return py_schema_err!(
+            "Unsupported JSON Schema keyword{} {} at `{}`",
+            plural,
+            unsupported.join(", "),
+            path
+        );
+    }
+
+    if let Some(enum_values) = json_schema.get_item(intern!(py, "enum"))? {
+        let Ok(enum_values) = enum_values.downcast::<PyList>() else {
+            return py_schema_err!("`enum` must be an array at `{}`", path);
+        };
+        let schema = new_schema(py, "literal")?;
+        schema.set_item(intern!(py, "expected"), enum_values)?;
+        return Ok(schema);
+    }
+
+    match json_schema.get_item(intern!(py, "type"))? {
+        None => {
+            let mut keywords = Vec::new();
+            for keyword in TYPE_KEYWORDS {
+                if json_schema.contains(keyword)? {
+                    keywords.push(format!("`{keyword}`"));
+                }
+            }
+            if keywords.is_empty() {
+                new_schema(py, "any")
+            } else {
+                py_schema_err!(
+                    "JSON Schema keyword{} {} at `{}` can only be used along with `type`",
+                    if keywords.len() == 1 { "" } else { "s" },
+                    keywords.join(", "),
+                    path
+                )
+            }
+        }
+        Some(type_) => {
+            if let Ok(type_) = type_.downcast::<PyString>() {
+                translate_type(json_schema, type_.to_str()?, path)
+            } else if let Ok(types) = type_.downcast::<PyList>() {
+                let choices = types
+                    .iter()
+                    .map(|t| match t.downcast::<PyString>() {
+                        Ok(t) => translate_type(json_schema, t.to_str()?, path),
+                        Err(_) => py_schema_err!("`type` must be a string or an array of strings at `{}`", path),
+                    })
+                    .collect::<PyResult<Vec<_>>>()?;
+                let schema = new_schema(py, "union")?;
+                schema.set_item(intern!(py, "choices"), choices)?;
+                Ok(schema)
+            } else {
+                py_schema_err!("`type` must be a string or an array of strings at `{}`", path)
+            }
+        }
+    }
+}
+
+/// Build the core schema for a single JSON Schema `type`, keywords which don't apply to the type are ignored
+/// as they are in JSON Schema.
+fn translate_type<'py>(json_schema: &Bound<'py, PyDict>, type_: &str, path: &str) -> PyResult<Bound<'py, PyDict>> {
+    let py = json_schema.py();
+    let copy_keywords = |schema: &Bound<'py, PyDict>, keywords: &[(&str, &str)]| -> PyResult<()> {
+        for (keyword, key) in keywords {
+            if let Some(value) = json_schema.get_item(keyword)? {
+                schema.set_item(key, value)?;
+            }
+        }
+        Ok(())
+    };
+
+    let schema = match type_ {
+        "string" => {
+            let schema = new_schema(py, "str")?;
+            copy_keywords(
+                &schema,
+                &[
+                    ("minLength", "min_length"),
+                    ("maxLength", "max_length"),
+                    ("pattern", "pattern"),
+                ],
+            )?;
+            schema
+        }
+        "integer" => {
+            let schema = new_schema(py, "int")?;
+            if let Some(minimum) = json_schema.get_item(intern!(py, "minimum"))? {
+                schema.set_item(intern!(py, "ge"), integer_bound(&minimum, true, path)?)?;
+            }
+            if let Some(maximum) = json_schema.get_item(intern!(py, "maximum"))? {
+                schema.set_item(intern!(py, "le"), integer_bound(&maximum, false, path)?)?;
+            }
+            schema
+        }
+        "number" => {
+            let schema = new_schema(py, "float")?;
+            copy_keywords(&schema, &[("minimum", "ge"), ("maximum", "le")])?;
+            schema
+        }
+        "boolean" => new_schema(py, "bool")?,
+        "null" => return new_schema(py, "none"),
+        "array" => {
+            let schema = new_schema(py, "list")?;
+            if let Some(items) = json_schema.get_item(intern!(py, "items"))? {
+                let items_schema = translate(&items, &format!("{path}/items"))?;
+                schema.set_item(intern!(py, "items_schema"), items_schema)?;
+            }
+            schema
+        }
+        "object" => translate_object(json_schema, path)?,
+        _ => return py_schema_err!("Unsupported JSON Schema type `{}` at `{}`", type_, path),
+    };
+    schema.set_item(intern!(py, "strict"), true)?;
+    Ok(schema)
+}
+
+/// The `minimum` or `maximum` of an integer as an int, rounded towards the allowed values if it's a float,
+/// e.g. `"minimum": 1.5` allows integers from 2.
+fn integer_bound<'py>(bound: &Bound<'py, PyAny>, round_up: bool, path: &str) -> PyResult<Bound<'py, PyAny>> {
+    if bound.is_instance_of::<PyInt>() && !bound.is_instance_of::<PyBool>() {
+        return Ok(bound.clone());
+    }
+    match bound.extract::<f64>() {
+        Ok(f) if f.is_finite() && !bound.is_instance_of::<PyBool>() => {
+            let rounded = if round_up { f.ceil() } else { f.floor() };
+            // `int(float)` is exact for any finite float, unlike casting to `i64`
+            bound.py().get_type_bound::<PyInt>().call1((rounded,))
+        }
+        _ => py_schema_err!("`minimum` and `maximum` must be numbers at `{}`", path),
+    }
+}
+
+fn translate_object<'py>(json_schema: &Bound<'py, PyDict>, path: &str) -> PyResult<Bound<'py, PyDict>> {
+    let py = json_schema.py();
+    let properties = json_schema.get_item(intern!(py, "properties"))?;
+    let required = json_schema.get_item(intern!(py, "required"))?;
+    if properties.is_none() && required.is_none() {
+        return new_schema(py, "dict");
+    }
+
+    let required: Vec<String> = match required {
+        Some(required) => match required.extract() {
+            Ok(required) => required,
+            Err(_) => return py_schema_err!("`required` must be an array of strings at `{}`", path),
+        },
+        None => Vec::new(),
+    };
+    let fields = PyDict::new_bound(py);
+    if let Some(properties) = properties {
+        let Ok(properties) = properties.downcast::<PyDict>() else {
+            return py_schema_err!("`properties` must be an object at `{}`", path);
+        };
+        for (name, property) in properties {
+            let name_str = name.str()?;
+            let name_str = name_str.to_str()?;
+            let property_path = format!("{path}/properties/{name_str}");
+            let field = new_schema(py, "typed-dict-field")?;
+            field.set_item(intern!(py, "schema"), translate(&property, &property_path)?)?;
+            field.set_item(intern!(py, "required"), required.iter().any(|r| r == name_str))?;
+            fields.set_item(name, field)?;
+        }
+    }
+    // required properties without a schema can have any value
+    for name in &required {
+        if !fields.contains(name)? {
+            let field = new_schema(py, "typed-dict-field")?;
+            field.set_item(intern!(py, "schema"), new_schema(py, "any")?)?;
+            fields.set_item(name, field)?;
+        }
+    }
+
+    let schema = new_schema(py, "typed-dict")?;
+    schema.set_item(intern!(py, "fields"), fields)?;
+    schema.set_item(intern!(py, "extra_behavior"), "allow")?;
+    Ok(schema)
+}
+
+/// Approximate JSON Schema of the values accepted by `validator`, definitions are added under `$defs`.
+pub(crate) fn validator_json_schema<'py>(
+    py: Python<'py>,
+    validator: &CombinedValidator,
+) -> PyResult<Bound<'py, PyDict>> {
+    let defs = PyDict::new_bound(py);
+    let json_schema = validator.json_schema(py, &defs)?;
+    if !defs.is_empty() {
+        json_schema.set_item(intern!(py, "$defs"), defs)?;
+    }
+    Ok(json_schema)
+}
+
+/// JSON Schema for validators without an equivalent, e.g. plain function validators, any value is accepted.
+pub(crate) fn placeholder_json_schema<'py>(py: Python<'py>, name: &str) -> PyResult<Bound<'py, PyDict>> {
+    let json_schema = PyDict::new_bound(py);
+    json_schema.set_item(
+        intern!(py, "description"),
+        format!("`{name}` has no JSON Schema representation"),
+    )?;
+    Ok(json_schema)
+}
+
+/// Set `key` on `json_schema` if `value` is set, for optional constraints.
+pub(crate) fn set_optional(json_schema: &Bound<'_, PyDict>, key: &str, value: Option<impl ToPyObject>) -> PyResult<()> {
+    match value {
+        Some(value) => json_schema.set_item(key, value),
+        None => Ok(()),
+    }
+}
+
+/// `{"type": "string", "format": <format>}`, for types which are represented as strings in JSON.
+pub(crate) fn string_json_schema<'py>(py: Python<'py>, format: &str) -> PyResult<Bound<'py, PyDict>> {
+    let json_schema = new_schema(py, "string")?;
+    json_schema.set_item(intern!(py, "format"), format)?;
+    Ok(json_schema)
+}
+
+/// `{"anyOf": <choices>}`, `choices` should be a sequence of JSON Schemas.
+pub(crate) fn any_of_json_schema(py: Python<'_>, choices: impl ToPyObject) -> PyResult<Bound<'_, PyDict>> {
+    let json_schema = PyDict::new_bound(py);
+    json_schema.set_item(intern!(py, "anyOf"), choices)?;
+    Ok(json_schema)
+}
+
+/// JSON Schema of an object with known properties, used for typed dicts, models and dataclasses, `fields`
+/// yields the name, validator and whether the field is required.
+pub(crate) fn object_json_schema<'a, 'py>(
+    py: Python<'py>,
+    defs: &Bound<'py, PyDict>,
+    fields: impl Iterator<Item = (&'a Py<PyString>, &'a CombinedValidator, bool)>,
+    extra_behavior: ExtraBehavior,
+    extras_validator: Option<&CombinedValidator>,
+) -> PyResult<Bound<'py, PyDict>> {
+    let properties = PyDict::new_bound(py);
+    let required = PyList::empty_bound(py);
+    for (name, validator, field_required) in fields {
+        properties.set_item(name, validator.json_schema(py, defs)?)?;
+        if field_required {
+            required.append(name)?;
+        }
+    }
+    let json_schema = new_schema(py, "object")?;
+    json_schema.set_item(intern!(py, "properties"), properties)?;
+    if !required.is_empty() {
+        json_schema.set_item(intern!(py, "required"), required)?;
+    }
+    match (extra_behavior, extras_validator) {
+        (ExtraBehavior::Forbid, _) => json_schema.set_item(intern!(py, "additionalProperties"), false)?,
+        (ExtraBehavior::Allow, Some(extras_validator)) => json_schema.set_item(
+            intern!(py, "additionalProperties"),
+            extras_validator.json_schema(py, defs)?,
+        )?,
+        _ => (),
+    }
+    Ok(json_schema)
+}
+
+/// Fields are required unless they have a default.
+pub(crate) fn is_required(validator: &CombinedValidator) -> bool {
+    !matches!(validator, CombinedValidator::WithDefault(validator) if validator.has_default())
+}
+
+/// Dict with just a `type`, used to start both core schemas and JSON Schemas, e.g. `{"type": "string"}`.
+pub(crate) fn new_schema<'py>(py: Python<'py>, type_: &str) -> PyResult<Bound<'py, PyDict>> {
+    let schema = PyDict::new_bound(py);
+    schema.set_item(intern!(py, "type"), type_)?;
+    Ok(schema)
+}
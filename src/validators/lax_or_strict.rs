@@ -63,18 +63,28 @@ impl Validator for LaxOrStrictValidator {
         state: &mut ValidationState<'_, 'py>,
     ) -> ValResult<PyObject> {
         if state.strict_or(self.strict) {
-            self.strict_validator.validate(py, input, state)
+            self.strict_validator.validate_profiled(py, input, state)
         } else {
             // horrible edge case: if doing smart union validation, we need to try the strict validator
             // anyway and prefer that if it succeeds
             if state.exactness.is_some() {
-                if let Ok(strict_result) = self.strict_validator.validate(py, input, state) {
+                let old_omitted_errors = state.omitted_errors;
+                if let Ok(strict_result) = self.strict_validator.validate_profiled(py, input, state) {
                     return Ok(strict_result);
                 }
-                // this is now known to be not strict
+                // this is now known to be not strict, errors omitted from the strict attempt are discarded with it
                 state.floor_exactness(Exactness::Lax);
+                state.omitted_errors = old_omitted_errors;
             }
-            self.lax_validator.validate(py, input, state)
+            self.lax_validator.validate_profiled(py, input, state)
+        }
+    }
+
+    fn json_schema<'py>(&self, py: Python<'py>, defs: &Bound<'py, PyDict>) -> PyResult<Bound<'py, PyDict>> {
+        if self.strict {
+            self.strict_validator.json_schema(py, defs)
+        } else {
+            self.lax_validator.json_schema(py, defs)
         }
     }
 
@@ -1,22 +1,36 @@
 use std::sync::OnceLock;
 
+use pyo3::exceptions::PyTypeError;
+use pyo3::intern;
 use pyo3::prelude::*;
-use pyo3::types::PyDict;
+use pyo3::types::{PyDict, PyList, PyTuple};
+use pyo3::PyTypeInfo;
 
-use crate::errors::ValResult;
+use crate::build_tools::py_schema_err;
+use crate::errors::{ErrorType, ErrorTypeDefaults, ValError, ValResult};
 use crate::input::{
-    no_validator_iter_to_vec, validate_iter_to_vec, BorrowInput, ConsumeIterator, Input, MaxLengthCheck, ValidatedList,
+    no_validator_iter_to_list, validate_iter_to_list, BorrowInput, ConsumeIterator, Input, MaxLengthCheck,
+    ValidatedList,
 };
 use crate::tools::SchemaDict;
 
-use super::{build_validator, BuildValidator, CombinedValidator, DefinitionsBuilder, ValidationState, Validator};
+use super::any::AnyValidator;
+use super::json_schema::{new_schema, set_optional};
+use super::{
+    build_validator, BuildValidator, CombinedValidator, DefinitionsBuilder, Exactness, ValidationState, Validator,
+};
 
 #[derive(Debug)]
 pub struct ListValidator {
     strict: bool,
+    prefix_validators: Vec<CombinedValidator>,
     item_validator: Option<Box<CombinedValidator>>,
     min_length: Option<usize>,
     max_length: Option<usize>,
+    unique_items: bool,
+    contains: Option<ContainsCheck>,
+    allow_subclass: bool,
+    columnar_input: bool,
     name: OnceLock<String>,
 }
 
@@ -93,6 +107,105 @@ macro_rules! min_length_check {
 }
 pub(crate) use min_length_check;
 
+/// The `contains` constraint of list and set schemas, like JSON Schema's `contains` the number of validated items
+/// which are valid against the `contains` schema must be between `min_contains` and `max_contains`.
+#[derive(Debug)]
+pub(crate) struct ContainsCheck {
+    validator: Box<CombinedValidator>,
+    min_contains: usize,
+    max_contains: Option<usize>,
+}
+
+impl ContainsCheck {
+    pub fn build(
+        schema: &Bound<'_, PyDict>,
+        config: Option<&Bound<'_, PyDict>>,
+        definitions: &mut DefinitionsBuilder<CombinedValidator>,
+    ) -> PyResult<Option<Self>> {
+        let py = schema.py();
+        let min_contains: Option<usize> = schema.get_as(intern!(py, "min_contains"))?;
+        let max_contains: Option<usize> = schema.get_as(intern!(py, "max_contains"))?;
+        match schema.get_item(intern!(py, "contains"))? {
+            Some(contains_schema) => Ok(Some(Self {
+                validator: Box::new(build_validator(&contains_schema, config, definitions)?),
+                min_contains: min_contains.unwrap_or(1),
+                max_contains,
+            })),
+            None if min_contains.is_some() || max_contains.is_some() => {
+                py_schema_err!("`min_contains` and `max_contains` can only be used with `contains`")
+            }
+            None => Ok(None),
+        }
+    }
+
+    /// Count the items valid against the `contains` schema, errors of other items are discarded.
+    pub fn check<'py>(
+        &self,
+        py: Python<'py>,
+        input: &(impl Input<'py> + ?Sized),
+        field_type: &str,
+        items: impl Iterator<Item = Bound<'py, PyAny>>,
+        state: &mut ValidationState<'_, 'py>,
+    ) -> ValResult<()> {
+        let old_exactness = state.exactness;
+        let old_omitted_errors = state.omitted_errors;
+        let mut actual_contains = 0;
+        for item in items {
+            match state.without_collecting_errors(|state| self.validator.validate_profiled(py, &item, state)) {
+                Ok(_) => actual_contains += 1,
+                Err(ValError::LineErrors(_)) => {}
+                Err(err) => return Err(err),
+            }
+            if self.max_contains.is_none() && actual_contains >= self.min_contains {
+                break;
+            }
+        }
+        state.exactness = old_exactness;
+        state.omitted_errors = old_omitted_errors;
+        if actual_contains < self.min_contains || self.max_contains.is_some_and(|max| actual_contains > max) {
+            return Err(ValError::new(
+                ErrorType::Contains {
+                    field_type: field_type.to_string(),
+                    min_contains: self.min_contains,
+                    max_contains: self.max_contains,
+                    actual_contains,
+                    context: None,
+                },
+                input,
+            ));
+        }
+        Ok(())
+    }
+
+    pub fn json_schema<'py>(
+        &self,
+        py: Python<'py>,
+        defs: &Bound<'py, PyDict>,
+        json_schema: &Bound<'py, PyDict>,
+    ) -> PyResult<()> {
+        json_schema.set_item(intern!(py, "contains"), self.validator.json_schema(py, defs)?)?;
+        if self.min_contains != 1 {
+            json_schema.set_item(intern!(py, "minContains"), self.min_contains)?;
+        }
+        set_optional(json_schema, "maxContains", self.max_contains)
+    }
+}
+
+/// In strict mode with `allow_subclass` disabled, python inputs must be exactly `T` rather than a subclass.
+pub(crate) fn check_exact_type<'py, T: PyTypeInfo>(
+    input: &(impl Input<'py> + ?Sized),
+    strict: bool,
+    allow_subclass: bool,
+    error_type: ErrorType,
+) -> ValResult<()> {
+    match input.as_python() {
+        Some(py_input) if strict && !allow_subclass && !py_input.is_exact_instance_of::<T>() => {
+            Err(ValError::new(error_type, input))
+        }
+        _ => Ok(()),
+    }
+}
+
 impl BuildValidator for ListValidator {
     const EXPECTED_TYPE: &'static str = "list";
 
@@ -102,19 +215,41 @@ impl BuildValidator for ListValidator {
         definitions: &mut DefinitionsBuilder<CombinedValidator>,
     ) -> PyResult<CombinedValidator> {
         let py = schema.py();
-        let item_validator = get_items_schema(schema, config, definitions)?.map(Box::new);
+        let prefix_validators = match schema.get_as::<Bound<'_, PyList>>(pyo3::intern!(py, "prefix_items"))? {
+            Some(prefix_items) => prefix_items
+                .iter()
+                .map(|item| build_validator(&item, config, definitions))
+                .collect::<PyResult<Vec<_>>>()?,
+            None => Vec::new(),
+        };
+        let mut item_validator = get_items_schema(schema, config, definitions)?.map(Box::new);
+        if item_validator.is_none() && !prefix_validators.is_empty() {
+            // items after the prefix still need a validator to be passed to
+            item_validator = Some(Box::new(AnyValidator::build(schema, config, definitions)?));
+        }
         Ok(Self {
             strict: crate::build_tools::is_strict(schema, config)?,
+            prefix_validators,
             item_validator,
             min_length: schema.get_as(pyo3::intern!(py, "min_length"))?,
             max_length: schema.get_as(pyo3::intern!(py, "max_length"))?,
+            unique_items: schema.get_as(pyo3::intern!(py, "unique_items"))?.unwrap_or(false),
+            contains: ContainsCheck::build(schema, config, definitions)?,
+            allow_subclass: schema.get_as(pyo3::intern!(py, "allow_subclass"))?.unwrap_or(true),
+            columnar_input: schema.get_as(pyo3::intern!(py, "columnar_input"))?.unwrap_or(false),
             name: OnceLock::new(),
         }
         .into())
     }
 }
 
-impl_py_gc_traverse!(ListValidator { item_validator });
+impl_py_gc_traverse!(ContainsCheck { validator });
+
+impl_py_gc_traverse!(ListValidator {
+    prefix_validators,
+    item_validator,
+    contains
+});
 
 impl Validator for ListValidator {
     fn validate<'py>(
@@ -123,37 +258,55 @@ impl Validator for ListValidator {
         input: &(impl Input<'py> + ?Sized),
         state: &mut ValidationState<'_, 'py>,
     ) -> ValResult<PyObject> {
-        let seq = input.validate_list(state.strict_or(self.strict))?.unpack(state);
+        let strict = state.strict_or(self.strict);
+        if let Some(rows) = self.columns_to_rows(py, input, strict)? {
+            state.floor_exactness(Exactness::Lax);
+            return Ok(self
+                .validate_items(py, input, rows.validate_list(false)?.into_inner(), state)?
+                .into_py(py));
+        }
+        check_exact_type::<PyList>(input, strict, self.allow_subclass, ErrorTypeDefaults::ListType)?;
+        let seq = input.validate_list(strict)?.unpack(state);
 
-        let actual_length = seq.len();
-        let output = match self.item_validator {
-            Some(ref v) => seq.iterate(ValidateToVec {
-                py,
-                input,
-                actual_length,
-                max_length: self.max_length,
-                field_type: "List",
-                item_validator: v,
-                state,
-            })??,
-            None => {
-                if let Some(py_list) = seq.as_py_list() {
-                    length_check!(input, "List", self.min_length, self.max_length, py_list);
-                    let list_copy = py_list.get_slice(0, usize::MAX);
-                    return Ok(list_copy.into_py(py));
+        if self.item_validator.is_none() {
+            // `prefix_validators` is only set along with `item_validator`
+            if let Some(py_list) = seq.as_py_list() {
+                length_check!(input, "List", self.min_length, self.max_length, py_list);
+                let list_copy = py_list.get_slice(0, usize::MAX);
+                if self.unique_items {
+                    check_unique_items(py, list_copy.iter().enumerate())?;
                 }
-
-                seq.iterate(ToVec {
-                    py,
-                    input,
-                    actual_length,
-                    max_length: self.max_length,
-                    field_type: "List",
-                })??
+                if let Some(contains) = &self.contains {
+                    contains.check(py, input, "List", list_copy.iter(), state)?;
+                }
+                return Ok(list_copy.into_py(py));
             }
-        };
-        min_length_check!(input, "List", self.min_length, output);
-        Ok(output.into_py(py))
+        }
+        Ok(self.validate_items(py, input, seq, state)?.into_py(py))
+    }
+
+    fn json_schema<'py>(&self, py: Python<'py>, defs: &Bound<'py, PyDict>) -> PyResult<Bound<'py, PyDict>> {
+        let json_schema = new_schema(py, "array")?;
+        if !self.prefix_validators.is_empty() {
+            let prefix_items = self
+                .prefix_validators
+                .iter()
+                .map(|v| v.json_schema(py, defs))
+                .collect::<PyResult<Vec<_>>>()?;
+            json_schema.set_item(intern!(py, "prefixItems"), prefix_items)?;
+        }
+        if let Some(item_validator) = &self.item_validator {
+            json_schema.set_item(intern!(py, "items"), item_validator.json_schema(py, defs)?)?;
+        }
+        set_optional(&json_schema, "minItems", self.min_length)?;
+        set_optional(&json_schema, "maxItems", self.max_length)?;
+        if self.unique_items {
+            json_schema.set_item(intern!(py, "uniqueItems"), true)?;
+        }
+        if let Some(contains) = &self.contains {
+            contains.json_schema(py, defs, &json_schema)?;
+        }
+        Ok(json_schema)
     }
 
     fn get_name(&self) -> &str {
@@ -165,23 +318,204 @@ impl Validator for ListValidator {
             Some(s) => s.as_str(),
             None => {
                 let name = self.item_validator.as_ref().map_or("any", |v| v.get_name());
-                if name == "..." {
+                if name == "..." || self.prefix_validators.iter().any(|v| v.get_name() == "...") {
                     // when inner name is not initialized yet, don't cache it here
                     "list[...]"
-                } else {
+                } else if self.prefix_validators.is_empty() {
                     self.name.get_or_init(|| format!("list[{name}]")).as_str()
+                } else {
+                    let prefix_names = self.prefix_validators.iter().map(Validator::get_name);
+                    let prefix = prefix_names.collect::<Vec<_>>().join(", ");
+                    self.name.get_or_init(|| format!("list[{prefix}, *{name}]")).as_str()
+                }
+            }
+        }
+    }
+}
+
+impl ListValidator {
+    /// With `columnar_input` in lax mode, transpose a python dict of equal length lists or tuples (columns) into a
+    /// list of dicts (rows), e.g. `{'a': [1, 2], 'b': [3, 4]}` becomes `[{'a': 1, 'b': 3}, {'a': 2, 'b': 4}]`.
+    fn columns_to_rows<'py>(
+        &self,
+        py: Python<'py>,
+        input: &(impl Input<'py> + ?Sized),
+        strict: bool,
+    ) -> ValResult<Option<Bound<'py, PyList>>> {
+        if !self.columnar_input || strict {
+            return Ok(None);
+        }
+        let Some(columns) = input
+            .as_python()
+            .and_then(|py_input| py_input.downcast::<PyDict>().ok())
+        else {
+            return Ok(None);
+        };
+        let mut rows: Vec<Bound<'py, PyDict>> = Vec::new();
+        let mut expected_length = None;
+        for (name, column) in columns {
+            let values = match column.downcast::<PyList>() {
+                Ok(list) => list.to_tuple(),
+                Err(_) => match column.downcast::<PyTuple>() {
+                    Ok(tuple) => tuple.clone(),
+                    Err(_) => return Err(ValError::new_with_loc(ErrorTypeDefaults::ListType, &column, name)),
+                },
+            };
+            match expected_length {
+                None => {
+                    expected_length = Some(values.len());
+                    rows = (0..values.len()).map(|_| PyDict::new_bound(py)).collect();
+                }
+                Some(expected_length) if values.len() != expected_length => {
+                    return Err(ValError::new_with_loc(
+                        ErrorType::ListColumnLength {
+                            expected_length,
+                            actual_length: values.len(),
+                            context: None,
+                        },
+                        &column,
+                        name,
+                    ));
+                }
+                Some(_) => {}
+            }
+            for (row, value) in rows.iter().zip(values.iter()) {
+                row.set_item(&name, value)?;
+            }
+        }
+        Ok(Some(PyList::new_bound(py, rows)))
+    }
+
+    fn validate_items<'py>(
+        &self,
+        py: Python<'py>,
+        input: &(impl Input<'py> + ?Sized),
+        seq: impl ValidatedList<'py>,
+        state: &mut ValidationState<'_, 'py>,
+    ) -> ValResult<Vec<PyObject>> {
+        let mut output = Vec::with_capacity(seq.len().unwrap_or(DEFAULT_CAPACITY));
+        let placeholders = self.validate_items_to(py, input, seq, state, &mut output)?;
+        min_length_check!(input, "List", self.min_length, output);
+        let items = || output.iter().map(|item| item.bind(py).clone());
+        self.check_items(py, input, items, &placeholders, state)?;
+        Ok(output)
+    }
+
+    /// Validate the items of `seq` into `output`, returning the positions of invalid items which were replaced
+    /// with `None` when collecting errors.
+    fn validate_items_to<'py>(
+        &self,
+        py: Python<'py>,
+        input: &(impl Input<'py> + ?Sized),
+        seq: impl ValidatedList<'py>,
+        state: &mut ValidationState<'_, 'py>,
+        output: &mut Vec<PyObject>,
+    ) -> ValResult<Vec<usize>> {
+        let actual_length = seq.len();
+        let mut placeholders = Vec::new();
+        match self.item_validator {
+            Some(ref v) => seq.iterate(ValidateToList {
+                py,
+                input,
+                output,
+                placeholders: &mut placeholders,
+                actual_length,
+                max_length: self.max_length,
+                field_type: "List",
+                prefix_validators: &self.prefix_validators,
+                item_validator: v,
+                state,
+            })?,
+            None => seq.iterate(ToList {
+                py,
+                input,
+                output,
+                actual_length,
+                max_length: self.max_length,
+                field_type: "List",
+            })?,
+        }?;
+        Ok(placeholders)
+    }
+
+    /// Check the `unique_items` and `contains` constraints, which apply to the validated items as a whole.
+    /// `None` placeholders of invalid items are skipped so they aren't reported as duplicates.
+    fn check_items<'py, T: Iterator<Item = Bound<'py, PyAny>>>(
+        &self,
+        py: Python<'py>,
+        input: &(impl Input<'py> + ?Sized),
+        items: impl Fn() -> T,
+        placeholders: &[usize],
+        state: &mut ValidationState<'_, 'py>,
+    ) -> ValResult<()> {
+        let valid_items = || {
+            items()
+                .enumerate()
+                .filter(|(index, _)| placeholders.binary_search(index).is_err())
+        };
+        if self.unique_items {
+            check_unique_items(py, valid_items())?;
+        }
+        if let Some(contains) = &self.contains {
+            contains.check(py, input, "List", valid_items().map(|(_, item)| item), state)?;
+        }
+        Ok(())
+    }
+}
+
+/// Check validated items are unique, hashable items are looked up in a dict so `1`, `1.0` and `True` are
+/// duplicates as they would be in a set, unhashable items are compared with each other by equality.
+fn check_unique_items<'py>(py: Python<'py>, items: impl Iterator<Item = (usize, Bound<'py, PyAny>)>) -> ValResult<()> {
+    let hashable_items = PyDict::new_bound(py);
+    let mut unhashable_items: Vec<(usize, Bound<'py, PyAny>)> = Vec::new();
+    for (index, item) in items {
+        let first_index = match item.hash() {
+            Ok(_) => match hashable_items.get_item(&item)? {
+                Some(first_index) => Some(first_index.extract::<usize>()?),
+                None => {
+                    hashable_items.set_item(&item, index)?;
+                    None
                 }
+            },
+            Err(err) if err.is_instance_of::<PyTypeError>(py) => {
+                let mut first_index = None;
+                for (other_index, other) in &unhashable_items {
+                    if other.eq(&item)? {
+                        first_index = Some(*other_index);
+                        break;
+                    }
+                }
+                if first_index.is_none() {
+                    unhashable_items.push((index, item.clone()));
+                }
+                first_index
             }
+            Err(err) => return Err(err.into()),
+        };
+        if let Some(first_index) = first_index {
+            return Err(ValError::new_with_loc(
+                ErrorType::ListUniqueItems {
+                    first_index,
+                    duplicate_index: index,
+                    context: None,
+                },
+                &item,
+                index,
+            ));
         }
     }
+    Ok(())
 }
 
-struct ValidateToVec<'a, 's, 'py, I: Input<'py> + ?Sized> {
+struct ValidateToList<'a, 's, 'py, I: Input<'py> + ?Sized> {
     py: Python<'py>,
     input: &'a I,
+    output: &'a mut Vec<PyObject>,
+    placeholders: &'a mut Vec<usize>,
     actual_length: Option<usize>,
     max_length: Option<usize>,
     field_type: &'static str,
+    prefix_validators: &'a [CombinedValidator],
     item_validator: &'a CombinedValidator,
     state: &'a mut ValidationState<'s, 'py>,
 }
@@ -189,40 +523,42 @@ struct ValidateToVec<'a, 's, 'py, I: Input<'py> + ?Sized> {
 // pretty arbitrary default capacity when creating vecs from iteration
 const DEFAULT_CAPACITY: usize = 10;
 
-impl<'py, T, I: Input<'py> + ?Sized> ConsumeIterator<PyResult<T>> for ValidateToVec<'_, '_, 'py, I>
+impl<'py, T, I: Input<'py> + ?Sized> ConsumeIterator<PyResult<T>> for ValidateToList<'_, '_, 'py, I>
 where
     T: BorrowInput<'py>,
 {
-    type Output = ValResult<Vec<PyObject>>;
-    fn consume_iterator(self, iterator: impl Iterator<Item = PyResult<T>>) -> ValResult<Vec<PyObject>> {
-        let capacity = self.actual_length.unwrap_or(DEFAULT_CAPACITY);
+    type Output = ValResult<()>;
+    fn consume_iterator(self, iterator: impl Iterator<Item = PyResult<T>>) -> ValResult<()> {
         let max_length_check = MaxLengthCheck::new(self.max_length, self.field_type, self.input, self.actual_length);
-        validate_iter_to_vec(
+        validate_iter_to_list(
             self.py,
+            self.output,
+            self.placeholders,
             iterator,
-            capacity,
             max_length_check,
+            self.prefix_validators,
             self.item_validator,
             self.state,
         )
     }
 }
 
-struct ToVec<'a, 'py, I: Input<'py> + ?Sized> {
+struct ToList<'a, 'py, I: Input<'py> + ?Sized> {
     py: Python<'py>,
     input: &'a I,
+    output: &'a mut Vec<PyObject>,
     actual_length: Option<usize>,
     max_length: Option<usize>,
     field_type: &'static str,
 }
 
-impl<'py, T, I: Input<'py> + ?Sized> ConsumeIterator<PyResult<T>> for ToVec<'_, 'py, I>
+impl<'py, T, I: Input<'py> + ?Sized> ConsumeIterator<PyResult<T>> for ToList<'_, 'py, I>
 where
     T: BorrowInput<'py>,
 {
-    type Output = ValResult<Vec<PyObject>>;
-    fn consume_iterator(self, iterator: impl Iterator<Item = PyResult<T>>) -> ValResult<Vec<PyObject>> {
+    type Output = ValResult<()>;
+    fn consume_iterator(self, iterator: impl Iterator<Item = PyResult<T>>) -> ValResult<()> {
         let max_length_check = MaxLengthCheck::new(self.max_length, self.field_type, self.input, self.actual_length);
-        no_validator_iter_to_vec(self.py, self.input, iterator, max_length_check)
+        no_validator_iter_to_list(self.py, self.output, self.input, iterator, max_length_check)
     }
 }
@@ -4,18 +4,18 @@ use core::fmt::Debug;
 use std::cmp::Ordering;
 
 use pyo3::prelude::*;
-use pyo3::types::{PyDict, PyInt, PyList};
+use pyo3::types::{PyDict, PyFloat, PyInt, PyList};
 use pyo3::{intern, PyTraverseError, PyVisit};
 
 use ahash::AHashMap;
 
 use crate::build_tools::{py_schema_err, py_schema_error_type};
 use crate::errors::{ErrorType, ValError, ValResult};
-use crate::input::{Input, ValidationMatch};
+use crate::input::{Input, StringParseOptions, ValidationMatch};
 use crate::py_gc::PyGcTraverse;
 use crate::tools::SchemaDict;
 
-use super::{BuildValidator, CombinedValidator, DefinitionsBuilder, ValidationState, Validator};
+use super::{BuildValidator, CombinedValidator, DefinitionsBuilder, Exactness, ValidationState, Validator};
 
 #[derive(Debug, Clone, Default)]
 struct BoolLiteral {
@@ -32,26 +32,36 @@ pub struct LiteralLookup<T: Debug> {
     expected_bool: Option<BoolLiteral>,
     expected_int: Option<AHashMap<i64, usize>>,
     expected_str: Option<AHashMap<String, usize>>,
+    // Floats are compared by value rather than hashed so `NaN` can match `NaN`, float literals are rare
+    // so a linear search is fine
+    expected_float: Option<Vec<(f64, usize)>>,
     // Catch all for hashable types like Enum and bytes (the latter only because it is seldom used)
     expected_py_dict: Option<Py<PyDict>>,
     // Catch all for unhashable types like list
     expected_py_list: Option<Py<PyList>>,
 
     pub values: Vec<T>,
+    /// Whether an int input matches an equal float literal, e.g. `1` matches `1.0`
+    int_float_equivalence: bool,
 }
 
 impl<T: Debug> LiteralLookup<T> {
-    pub fn new<'py>(py: Python<'py>, expected: impl Iterator<Item = (Bound<'py, PyAny>, T)>) -> PyResult<Self> {
+    pub fn new<'py>(
+        py: Python<'py>,
+        expected: impl Iterator<Item = (Bound<'py, PyAny>, T)>,
+        int_float_equivalence: bool,
+    ) -> PyResult<Self> {
         let mut expected_bool = BoolLiteral::default();
         let mut expected_int = AHashMap::new();
         let mut expected_str: AHashMap<String, usize> = AHashMap::new();
+        let mut expected_float = Vec::new();
         let expected_py_dict = PyDict::new_bound(py);
         let expected_py_list = PyList::empty_bound(py);
         let mut values = Vec::new();
         for (k, v) in expected {
             let id = values.len();
             values.push(v);
-            if let Ok(bool) = k.validate_bool(true) {
+            if let Ok(bool) = k.validate_bool(true, StringParseOptions::default()) {
                 if bool.into_inner() {
                     expected_bool.true_id = Some(id);
                 } else {
@@ -70,6 +80,10 @@ impl<T: Debug> LiteralLookup<T> {
                     .as_cow()
                     .map_err(|_| py_schema_error_type!("error extracting str {:?}", k))?;
                 expected_str.insert(str.to_string(), id);
+            } else if k.is_exact_instance_of::<PyFloat>() {
+                expected_float.push((k.extract::<f64>()?, id));
+                // also hashed so inputs like `Decimal` which compare equal to the float match it
+                expected_py_dict.set_item(&k, id)?;
             } else if expected_py_dict.set_item(&k, id).is_err() {
                 expected_py_list.append((&k, id))?;
             }
@@ -88,6 +102,10 @@ impl<T: Debug> LiteralLookup<T> {
                 true => None,
                 false => Some(expected_str),
             },
+            expected_float: match expected_float.is_empty() {
+                true => None,
+                false => Some(expected_float),
+            },
             expected_py_dict: match expected_py_dict.is_empty() {
                 true => None,
                 false => Some(expected_py_dict.into()),
@@ -97,16 +115,38 @@ impl<T: Debug> LiteralLookup<T> {
                 false => Some(expected_py_list.into()),
             },
             values,
+            int_float_equivalence,
+        })
+    }
+
+    fn find_float(&self, float: f64) -> Option<usize> {
+        self.expected_float.as_ref().and_then(|expected_floats| {
+            expected_floats
+                .iter()
+                .find(|(expected, _)| *expected == float || (expected.is_nan() && float.is_nan()))
+                .map(|(_, id)| *id)
         })
     }
 
+    /// Whether a match of the literal `id` from `expected_py_dict` counts, bools never match float literals and
+    /// ints only do with `int_float_equivalence`, even though they hash the same.
+    fn py_dict_match<'py>(&self, id: usize, input: &(impl Input<'py> + ?Sized)) -> bool {
+        let is_float = self
+            .expected_float
+            .as_ref()
+            .is_some_and(|expected_floats| expected_floats.iter().any(|(_, float_id)| *float_id == id));
+        !is_float
+            || !(input.validate_bool(true, StringParseOptions::default()).is_ok()
+                || (!self.int_float_equivalence && input.exact_int().is_ok()))
+    }
+
     pub fn validate<'a, 'py, I: Input<'py> + ?Sized>(
         &self,
         py: Python<'py>,
         input: &'a I,
     ) -> ValResult<Option<(&'a I, &T)>> {
         if let Some(expected_bool) = &self.expected_bool {
-            if let Ok(bool_value) = input.validate_bool(true) {
+            if let Ok(bool_value) = input.validate_bool(true, StringParseOptions::default()) {
                 if bool_value.into_inner() {
                     if let Some(true_value) = &expected_bool.true_id {
                         return Ok(Some((input, &self.values[*true_value])));
@@ -143,13 +183,24 @@ impl<T: Debug> LiteralLookup<T> {
                 }
             }
         }
+        if self.expected_float.is_some() && (self.int_float_equivalence || input.exact_int().is_err()) {
+            if let Ok(float_match) = input.validate_float(true, StringParseOptions::default()) {
+                if float_match.exactness() != Exactness::Lax {
+                    if let Some(id) = self.find_float(float_match.into_inner().as_f64()) {
+                        return Ok(Some((input, &self.values[id])));
+                    }
+                }
+            }
+        }
         if let Some(expected_py_dict) = &self.expected_py_dict {
             // We don't use ? to unpack the result of `get_item` in the next line because unhashable
             // inputs will produce a TypeError, which in this case we just want to treat equivalently
             // to a failed lookup
             if let Ok(Some(v)) = expected_py_dict.bind(py).get_item(input) {
                 let id: usize = v.extract().unwrap();
-                return Ok(Some((input, &self.values[id])));
+                if self.py_dict_match(id, input) {
+                    return Ok(Some((input, &self.values[id])));
+                }
             }
         };
         if let Some(expected_py_list) = &self.expected_py_list {
@@ -174,7 +225,7 @@ impl<T: Debug> LiteralLookup<T> {
         strict: bool,
     ) -> ValResult<Option<&T>> {
         if let Some(expected_ints) = &self.expected_int {
-            if let Ok(either_int) = input.validate_int(strict) {
+            if let Ok(either_int) = input.validate_int(strict, StringParseOptions::default()) {
                 let int = either_int.into_inner().into_i64(py)?;
                 if let Some(id) = expected_ints.get(&int) {
                     return Ok(Some(&self.values[*id]));
@@ -197,6 +248,30 @@ impl<T: Debug> LiteralLookup<T> {
         Ok(None)
     }
 
+    /// The expected string closest to a string input by Levenshtein distance, used by `suggest`. Nothing is
+    /// suggested for other inputs, when replacing every character would be as close, or when the input is more
+    /// than `SUGGESTION_MAX_LENGTH_RATIO` times longer than the longest expected string, which also avoids
+    /// computing distances to very long inputs.
+    pub fn closest_str<'py, I: Input<'py> + ?Sized>(&self, input: &I) -> Option<&str> {
+        let expected_strings = self.expected_str.as_ref()?;
+        let either_str = input.validate_str(true, false).ok()?.into_inner();
+        let input_str = either_str.as_cow().ok()?;
+        let longest_expected = expected_strings.keys().map(|s| s.chars().count()).max()?;
+        let max_input_len = longest_expected.saturating_mul(SUGGESTION_MAX_LENGTH_RATIO);
+        // only count up to the limit so very long inputs aren't iterated in full
+        let input_len = input_str.chars().take(max_input_len + 1).count();
+        if input_len > max_input_len {
+            return None;
+        }
+        expected_strings
+            .iter()
+            .map(|(expected, id)| (levenshtein(&input_str, expected), *id, expected))
+            .filter(|(distance, _, expected)| *distance < input_len.max(expected.chars().count()))
+            // lower ids first so ties are resolved by the order of the expected values
+            .min_by_key(|(distance, id, _)| (*distance, *id))
+            .map(|(_, _, expected)| expected.as_str())
+    }
+
     /// Used by float enums
     pub fn validate_float<'a, 'py, I: Input<'py> + ?Sized>(
         &self,
@@ -204,9 +279,12 @@ impl<T: Debug> LiteralLookup<T> {
         input: &'a I,
         strict: bool,
     ) -> ValResult<Option<&T>> {
-        if let Some(expected_py) = &self.expected_py_dict {
-            if let Ok(either_float) = input.validate_float(strict) {
-                let f = either_float.into_inner().as_f64();
+        if let Ok(either_float) = input.validate_float(strict, StringParseOptions::default()) {
+            let f = either_float.into_inner().as_f64();
+            if let Some(id) = self.find_float(f) {
+                return Ok(Some(&self.values[id]));
+            }
+            if let Some(expected_py) = &self.expected_py_dict {
                 let py_float = f.to_object(py);
                 if let Ok(Some(v)) = expected_py.bind(py).get_item(py_float.bind(py)) {
                     let id: usize = v.extract().unwrap();
@@ -230,6 +308,7 @@ impl<T: PyGcTraverse + Debug> PyGcTraverse for LiteralLookup<T> {
 pub struct LiteralValidator {
     lookup: LiteralLookup<PyObject>,
     expected_repr: String,
+    suggest: bool,
     name: String,
 }
 
@@ -251,10 +330,16 @@ impl BuildValidator for LiteralValidator {
             repr_args.push(item.repr()?.extract()?);
         }
         let (expected_repr, name) = expected_repr_name(repr_args, "literal");
-        let lookup = LiteralLookup::new(py, expected.into_iter().map(|v| (v.clone(), v.into())))?;
+        let int_float_equivalence = schema.get_as(intern!(py, "int_float_equivalence"))?.unwrap_or(false);
+        let lookup = LiteralLookup::new(
+            py,
+            expected.into_iter().map(|v| (v.clone(), v.into())),
+            int_float_equivalence,
+        )?;
         Ok(CombinedValidator::Literal(Self {
             lookup,
             expected_repr,
+            suggest: schema.get_as(intern!(py, "suggest"))?.unwrap_or(false),
             name,
         }))
     }
@@ -274,18 +359,62 @@ impl Validator for LiteralValidator {
             None => Err(ValError::new(
                 ErrorType::LiteralError {
                     expected: self.expected_repr.clone(),
-                    context: None,
+                    context: match self.suggest {
+                        true => suggestion_context(py, &self.lookup, input)?,
+                        false => None,
+                    },
                 },
                 input,
             )),
         }
     }
 
+    fn json_schema<'py>(&self, py: Python<'py>, _defs: &Bound<'py, PyDict>) -> PyResult<Bound<'py, PyDict>> {
+        let json_schema = PyDict::new_bound(py);
+        json_schema.set_item(intern!(py, "enum"), &self.lookup.values)?;
+        Ok(json_schema)
+    }
+
     fn get_name(&self) -> &str {
         &self.name
     }
 }
 
+/// How many times longer than the longest expected string an input can be and still get a suggestion.
+const SUGGESTION_MAX_LENGTH_RATIO: usize = 2;
+
+/// Error context holding the closest expected value to `input` as `suggestion`, if any.
+pub fn suggestion_context<'py, T: Debug, I: Input<'py> + ?Sized>(
+    py: Python<'py>,
+    lookup: &LiteralLookup<T>,
+    input: &I,
+) -> PyResult<Option<Py<PyDict>>> {
+    match lookup.closest_str(input) {
+        Some(suggestion) => {
+            let context = PyDict::new_bound(py);
+            context.set_item(intern!(py, "suggestion"), suggestion)?;
+            Ok(Some(context.unbind()))
+        }
+        None => Ok(None),
+    }
+}
+
+/// The number of single character insertions, deletions or substitutions needed to turn `a` into `b`.
+fn levenshtein(a: &str, b: &str) -> usize {
+    let b: Vec<char> = b.chars().collect();
+    let mut row: Vec<usize> = (0..=b.len()).collect();
+    for (i, a_char) in a.chars().enumerate() {
+        let mut diagonal = row[0];
+        row[0] = i + 1;
+        for (j, b_char) in b.iter().enumerate() {
+            let substitution = diagonal + usize::from(a_char != *b_char);
+            diagonal = row[j + 1];
+            row[j + 1] = substitution.min(row[j] + 1).min(diagonal + 1);
+        }
+    }
+    row[b.len()]
+}
+
 pub fn expected_repr_name(mut repr_args: Vec<String>, base_name: &'static str) -> (String, String) {
     let name = format!("{base_name}[{}]", repr_args.join(","));
     // unwrap is okay since we check the length in build at the top of this file
@@ -1,28 +1,36 @@
 use std::fmt::Debug;
+use std::time::Instant;
 
 use enum_dispatch::enum_dispatch;
 use jiter::StringCacheMode;
 
-use pyo3::exceptions::PyTypeError;
+use pyo3::exceptions::{PyTypeError, PyValueError};
 use pyo3::prelude::*;
 use pyo3::sync::GILOnceCell;
-use pyo3::types::{PyAny, PyDict, PyString, PyTuple, PyType};
+use pyo3::types::{PyAny, PyDateTime, PyDict, PyIterator, PyList, PyString, PyTuple, PyType};
 use pyo3::{intern, PyTraverseError, PyVisit};
 
-use crate::build_tools::{py_schema_err, py_schema_error_type, SchemaError};
+use crate::argument_markers::ArgsKwargs;
+use crate::build_tools::{expand_schema_type, py_schema_err, py_schema_error_type, SchemaError};
 use crate::definitions::{Definitions, DefinitionsBuilder};
-use crate::errors::{LocItem, ValError, ValResult, ValidationError};
-use crate::input::{Input, InputType, StringMapping};
+use crate::errors::{ErrorType, LocItem, ValError, ValLineError, ValResult, ValidationError};
+use crate::input::{
+    check_duplicate_keys, has_duplicate_keys, merge_duplicate_keys, Input, InputType, JsonDuplicateKeys, StringMapping,
+};
 use crate::py_gc::PyGcTraverse;
-use crate::recursion_guard::RecursionState;
+use crate::recursion_guard::{PyRecursionGuard, RecursionState};
+use crate::serializers::content_hash;
 use crate::tools::SchemaDict;
+use crate::CacheStringsArg;
 
 mod any;
 mod arguments;
 mod bool;
 mod bytes;
+mod cached;
 mod call;
 mod callable;
+mod canonicalize;
 mod chain;
 mod custom_error;
 mod dataclass;
@@ -31,16 +39,21 @@ mod datetime;
 pub(crate) mod decimal;
 mod definitions;
 mod dict;
+mod email;
 mod enum_;
 mod float;
+mod fraction;
 mod frozenset;
 mod function;
 mod generator;
 mod int;
+mod interval;
 mod is_instance;
 mod is_subclass;
 mod json;
 mod json_or_python;
+mod json_raw;
+mod json_schema;
 mod lax_or_strict;
 mod list;
 mod literal;
@@ -60,9 +73,93 @@ mod uuid;
 mod validation_state;
 mod with_default;
 
-pub use self::validation_state::{Exactness, ValidationState};
+pub use self::canonicalize::Canonicalize;
+pub use self::json::ParsedJson;
+pub use self::json_raw::JsonRaw;
+use self::json_raw::{JsonDocument, JsonSource};
+pub(crate) use self::model::{class_has_dict, get_model_dict};
+pub use self::validation_state::{Exactness, SharedCache, ValidationProfile, ValidationState};
 pub use with_default::DefaultType;
 
+/// Returned by `validate_python` when any of `with_hash`, `profile`, `report_union_variant`, `report_exactness`
+/// or `report_aliases` is set, or with `on_error='collect'`, and by `validate_json` with `with_hash`, the attributes
+/// which weren't requested are `None`.
+///
+/// It also behaves like a tuple of the value followed by the requested details in attribute order, so
+/// `value, hash = validator.validate_python(x, with_hash=True)` works.
+#[pyclass(module = "pydantic_core._pydantic_core", get_all, frozen)]
+#[derive(Debug)]
+pub struct ValidationReport {
+    value: PyObject,
+    union_variant: Option<PyObject>,
+    exactness: Option<&'static str>,
+    aliases: Option<PyObject>,
+    hash: Option<u64>,
+    profile: Option<Py<PyDict>>,
+    errors: Option<PyObject>,
+}
+
+impl ValidationReport {
+    fn as_tuple<'py>(&self, py: Python<'py>) -> Bound<'py, PyTuple> {
+        let mut items = vec![self.value.clone_ref(py)];
+        items.extend(self.union_variant.as_ref().map(|v| v.clone_ref(py)));
+        items.extend(self.exactness.map(|v| v.into_py(py)));
+        items.extend(self.aliases.as_ref().map(|v| v.clone_ref(py)));
+        items.extend(self.hash.map(|v| v.into_py(py)));
+        items.extend(self.profile.as_ref().map(|v| v.clone_ref(py).into_any()));
+        items.extend(self.errors.as_ref().map(|v| v.clone_ref(py)));
+        PyTuple::new_bound(py, items)
+    }
+}
+
+#[pymethods]
+impl ValidationReport {
+    fn __len__(&self, py: Python) -> usize {
+        self.as_tuple(py).len()
+    }
+
+    fn __getitem__<'py>(&self, py: Python<'py>, index: &Bound<'py, PyAny>) -> PyResult<Bound<'py, PyAny>> {
+        self.as_tuple(py).as_any().get_item(index)
+    }
+
+    fn __iter__<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyIterator>> {
+        self.as_tuple(py).as_any().iter()
+    }
+
+    fn __eq__(&self, py: Python, other: &Bound<'_, PyAny>) -> PyResult<PyObject> {
+        if let Ok(other) = other.downcast::<Self>() {
+            Ok(self.as_tuple(py).eq(other.get().as_tuple(py))?.into_py(py))
+        } else if other.is_instance_of::<PyTuple>() {
+            Ok(self.as_tuple(py).eq(other)?.into_py(py))
+        } else {
+            Ok(py.NotImplemented())
+        }
+    }
+
+    fn __repr__(&self, py: Python) -> PyResult<String> {
+        let mut fields = vec![format!("value={}", self.value.bind(py).repr()?)];
+        if let Some(ref union_variant) = self.union_variant {
+            fields.push(format!("union_variant={}", union_variant.bind(py).repr()?));
+        }
+        if let Some(exactness) = self.exactness {
+            fields.push(format!("exactness='{exactness}'"));
+        }
+        if let Some(ref aliases) = self.aliases {
+            fields.push(format!("aliases={}", aliases.bind(py).repr()?));
+        }
+        if let Some(hash) = self.hash {
+            fields.push(format!("hash={hash}"));
+        }
+        if let Some(ref profile) = self.profile {
+            fields.push(format!("profile={}", profile.bind(py).repr()?));
+        }
+        if let Some(ref errors) = self.errors {
+            fields.push(format!("errors={}", errors.bind(py).repr()?));
+        }
+        Ok(format!("ValidationReport({})", fields.join(", ")))
+    }
+}
+
 #[pyclass(module = "pydantic_core._pydantic_core", name = "Some")]
 pub struct PySome {
     #[pyo3(get)]
@@ -112,6 +209,7 @@ pub struct SchemaValidator {
     hide_input_in_errors: bool,
     validation_error_cause: bool,
     cache_str: StringCacheMode,
+    max_errors: Option<usize>,
 }
 
 #[pymethods]
@@ -140,6 +238,10 @@ impl SchemaValidator {
         let cache_str: StringCacheMode = config
             .get_as(intern!(py, "cache_strings"))?
             .unwrap_or(StringCacheMode::All);
+        let max_errors: Option<usize> = config.get_as(intern!(py, "max_errors"))?;
+        if max_errors == Some(0) {
+            return py_schema_err!("`max_errors` must be greater than 0");
+        }
         Ok(Self {
             validator,
             definitions,
@@ -149,9 +251,27 @@ impl SchemaValidator {
             hide_input_in_errors,
             validation_error_cause,
             cache_str,
+            max_errors,
         })
     }
 
+    /// Build a validator from a JSON Schema document, see `core_schema_from_json_schema` for what's supported.
+    #[staticmethod]
+    #[pyo3(signature = (schema, config=None))]
+    pub fn from_json_schema(
+        py: Python,
+        schema: &Bound<'_, PyAny>,
+        config: Option<&Bound<'_, PyDict>>,
+    ) -> PyResult<Self> {
+        let core_schema = json_schema::core_schema_from_json_schema(schema)?;
+        Self::py_new(py, &core_schema, config)
+    }
+
+    /// Approximate JSON Schema document for the values accepted by this validator.
+    pub fn json_schema<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyDict>> {
+        json_schema::validator_json_schema(py, &self.validator)
+    }
+
     pub fn __reduce__(slf: &Bound<Self>) -> PyResult<(PyObject, (PyObject, PyObject))> {
         // Enables support for `pickle` serialization.
         let py = slf.py();
@@ -160,7 +280,8 @@ impl SchemaValidator {
         Ok((cls, init_args))
     }
 
-    #[pyo3(signature = (input, *, strict=None, from_attributes=None, context=None, self_instance=None))]
+    #[allow(clippy::too_many_arguments)]
+    #[pyo3(signature = (input, *, strict=None, from_attributes=None, context=None, self_instance=None, cache_shared=false, now=None, require_all_fields=false, with_hash=false, profile=false, on_error="raise", recursion_guard=None, report_union_variant=false, report_exactness=false, report_aliases=false, canonicalize=Canonicalize::Off))]
     pub fn validate_python(
         &self,
         py: Python,
@@ -169,17 +290,45 @@ impl SchemaValidator {
         from_attributes: Option<bool>,
         context: Option<&Bound<'_, PyAny>>,
         self_instance: Option<&Bound<'_, PyAny>>,
+        cache_shared: bool,
+        now: Option<&Bound<'_, PyDateTime>>,
+        require_all_fields: bool,
+        with_hash: bool,
+        profile: bool,
+        on_error: &str,
+        recursion_guard: Option<&Bound<'_, PyRecursionGuard>>,
+        report_union_variant: bool,
+        report_exactness: bool,
+        report_aliases: bool,
+        canonicalize: Canonicalize,
     ) -> PyResult<PyObject> {
-        self._validate(
-            py,
-            input,
-            InputType::Python,
+        let collect_errors = match on_error {
+            "raise" => false,
+            "collect" => true,
+            _ => {
+                return Err(PyValueError::new_err(format!(
+                    "Invalid `on_error` value '{on_error}', expected 'raise' or 'collect'"
+                )))
+            }
+        };
+        let options = PythonValidateOptions {
             strict,
             from_attributes,
             context,
             self_instance,
-        )
-        .map_err(|e| self.prepare_validation_err(py, e, InputType::Python))
+            cache_shared,
+            now,
+            require_all_fields,
+            with_hash,
+            profile,
+            collect_errors,
+            recursion_guard,
+            report_union_variant,
+            report_exactness,
+            report_aliases,
+            canonicalize,
+        };
+        self.validate_python_with(py, input, options)
     }
 
     #[pyo3(signature = (input, *, strict=None, from_attributes=None, context=None, self_instance=None))]
@@ -196,10 +345,13 @@ impl SchemaValidator {
             py,
             input,
             InputType::Python,
-            strict,
-            from_attributes,
-            context,
-            self_instance,
+            ValidateOptions {
+                strict,
+                from_attributes,
+                context,
+                self_instance,
+                ..ValidateOptions::default()
+            },
         ) {
             Ok(_) => Ok(true),
             Err(ValError::InternalErr(err)) => Err(err),
@@ -209,7 +361,8 @@ impl SchemaValidator {
         }
     }
 
-    #[pyo3(signature = (input, *, strict=None, context=None, self_instance=None))]
+    #[allow(clippy::too_many_arguments)]
+    #[pyo3(signature = (input, *, strict=None, context=None, self_instance=None, duplicate_keys="last", cache_strings=None, with_hash=false))]
     pub fn validate_json(
         &self,
         py: Python,
@@ -217,7 +370,12 @@ impl SchemaValidator {
         strict: Option<bool>,
         context: Option<&Bound<'_, PyAny>>,
         self_instance: Option<&Bound<'_, PyAny>>,
+        duplicate_keys: &str,
+        cache_strings: Option<CacheStringsArg>,
+        with_hash: bool,
     ) -> PyResult<PyObject> {
+        let duplicate_keys = JsonDuplicateKeys::from_arg(duplicate_keys)?;
+        let cache_str = cache_strings.map(StringCacheMode::from);
         let r = match json::validate_json_bytes(input) {
             Ok(v_match) => self._validate_json(
                 py,
@@ -226,10 +384,50 @@ impl SchemaValidator {
                 strict,
                 context,
                 self_instance,
+                duplicate_keys,
+                cache_str,
             ),
             Err(err) => Err(err),
         };
-        r.map_err(|e| self.prepare_validation_err(py, e, InputType::Json))
+        let value = r.map_err(|e| self.prepare_validation_err(py, e, InputType::Json))?;
+        if !with_hash {
+            return Ok(value);
+        }
+        let hash = content_hash(py, value.bind(py))?;
+        let report = ValidationReport {
+            value,
+            union_variant: None,
+            exactness: None,
+            aliases: None,
+            hash: Some(hash),
+            profile: None,
+            errors: None,
+        };
+        Ok(report.into_py(py))
+    }
+
+    #[pyo3(signature = (input, *, strict=None, context=None, self_instance=None))]
+    pub fn validate_json_value(
+        &self,
+        py: Python,
+        input: &Bound<'_, ParsedJson>,
+        strict: Option<bool>,
+        context: Option<&Bound<'_, PyAny>>,
+        self_instance: Option<&Bound<'_, PyAny>>,
+    ) -> PyResult<PyObject> {
+        let json_value = input.get().value();
+        self._validate(
+            py,
+            json_value,
+            InputType::Json,
+            ValidateOptions {
+                strict,
+                context,
+                self_instance,
+                ..ValidateOptions::default()
+            },
+        )
+        .map_err(|e| self.prepare_validation_err(py, e, InputType::Json))
     }
 
     #[pyo3(signature = (input, *, strict=None, context=None))]
@@ -243,12 +441,124 @@ impl SchemaValidator {
         let t = InputType::String;
         let string_mapping = StringMapping::new_value(input).map_err(|e| self.prepare_validation_err(py, e, t))?;
 
-        match self._validate(py, &string_mapping, t, strict, None, context, None) {
+        match self._validate(
+            py,
+            &string_mapping,
+            t,
+            ValidateOptions {
+                strict,
+                context,
+                ..ValidateOptions::default()
+            },
+        ) {
             Ok(r) => Ok(r),
             Err(e) => Err(self.prepare_validation_err(py, e, t)),
         }
     }
 
+    #[pyo3(signature = (args, kwargs=None, *, strict=None, context=None))]
+    pub fn validate_call(
+        &self,
+        py: Python,
+        args: &Bound<'_, PyTuple>,
+        kwargs: Option<&Bound<'_, PyDict>>,
+        strict: Option<bool>,
+        context: Option<&Bound<'_, PyAny>>,
+    ) -> PyResult<ArgsKwargs> {
+        let CombinedValidator::Arguments(ref arguments_validator) = self.validator else {
+            return Err(PyTypeError::new_err(
+                "`validate_call` can only be used with an `arguments` schema",
+            ));
+        };
+        let input = Bound::new(py, ArgsKwargs::new(args, kwargs))?;
+        let output = self
+            ._validate(
+                py,
+                input.as_any(),
+                InputType::Python,
+                ValidateOptions {
+                    strict,
+                    context,
+                    ..ValidateOptions::default()
+                },
+            )
+            .map_err(|e| self.prepare_validation_err(py, e, InputType::Python))?;
+        let (output_args, output_kwargs): (Bound<'_, PyTuple>, Bound<'_, PyDict>) = output.extract(py)?;
+        let output_args = arguments_validator.bind_args(&output_args, &output_kwargs)?;
+        Ok(ArgsKwargs::new(&output_args, Some(&output_kwargs)))
+    }
+
+    #[pyo3(signature = (input, buffer, *, strict=None, from_attributes=None, context=None))]
+    pub fn validate_python_into(
+        &self,
+        py: Python,
+        input: &Bound<'_, PyAny>,
+        buffer: &Bound<'_, PyList>,
+        strict: Option<bool>,
+        from_attributes: Option<bool>,
+        context: Option<&Bound<'_, PyAny>>,
+    ) -> PyResult<()> {
+        let guard = &mut RecursionState::default();
+        let mut state = self.python_state(guard, strict, from_attributes, context);
+        // validate into a new list which is only swapped into the buffer on success, so the buffer can also be
+        // the input, and is never left partly written
+        let result = self
+            .validator
+            .validate_profiled(py, input, &mut state)
+            .map_err(|e| state.limit_errors(e, input))
+            .map_err(|e| self.prepare_validation_err(py, e, InputType::Python))
+            .and_then(|output| match output.downcast_bound::<PyList>(py) {
+                Ok(list) => Ok(list.clone()),
+                // other schemas can still produce a list, e.g. a list within a function validator
+                Err(_) => Err(PyTypeError::new_err(format!(
+                    "`validate_python_into` requires a schema which produces a list, got {}",
+                    output.bind(py).get_type().qualname()?
+                ))),
+            });
+        match result {
+            Ok(list) => buffer.set_slice(0, usize::MAX, &list),
+            Err(err) => {
+                // never leave the previous contents in the buffer as if they were the result
+                buffer.del_slice(0, usize::MAX)?;
+                Err(err)
+            }
+        }
+    }
+
+    #[pyo3(signature = (inputs, *, strict=None, from_attributes=None, context=None))]
+    pub fn validate_python_batch(
+        &self,
+        py: Python,
+        inputs: &Bound<'_, PyAny>,
+        strict: Option<bool>,
+        from_attributes: Option<bool>,
+        context: Option<&Bound<'_, PyAny>>,
+    ) -> PyResult<Py<PyList>> {
+        // the same state is used for every input, only what's specific to an input is reset
+        let guard = &mut RecursionState::default();
+        let mut state = self.python_state(guard, strict, from_attributes, context);
+        let results = PyList::empty_bound(py);
+        for input in inputs.iter()? {
+            let input = input?;
+            // validating holds the GIL throughout, so make sure long batches can still be interrupted
+            py.check_signals()?;
+            state.exactness = None;
+            state.omitted_errors = 0;
+            let result = match self.validator.validate_profiled(py, &input, &mut state) {
+                Ok(value) => (value, py.None()),
+                // validation errors are returned alongside the item, anything else aborts the batch
+                Err(e @ ValError::LineErrors(_)) => {
+                    let e = state.limit_errors(e, &input);
+                    let error = self.prepare_validation_err(py, e, InputType::Python).into_value(py);
+                    (py.None(), error.into_any())
+                }
+                Err(e) => return Err(self.prepare_validation_err(py, e, InputType::Python)),
+            };
+            results.append(result)?;
+        }
+        Ok(results.unbind())
+    }
+
     #[allow(clippy::too_many_arguments)]
     #[pyo3(signature = (obj, field_name, field_value, *, strict=None, from_attributes=None, context=None))]
     pub fn validate_assignment(
@@ -261,20 +571,11 @@ impl SchemaValidator {
         from_attributes: Option<bool>,
         context: Option<&Bound<'_, PyAny>>,
     ) -> PyResult<PyObject> {
-        let extra = Extra {
-            input_type: InputType::Python,
-            data: None,
-            strict,
-            from_attributes,
-            context,
-            self_instance: None,
-            cache_str: self.cache_str,
-        };
-
         let guard = &mut RecursionState::default();
-        let mut state = ValidationState::new(extra, guard);
+        let mut state = self.python_state(guard, strict, from_attributes, context);
         self.validator
             .validate_assignment(py, &obj, field_name, &field_value, &mut state)
+            .map_err(|e| state.limit_errors(e, &field_value))
             .map_err(|e| self.prepare_validation_err(py, e, InputType::Python))
     }
 
@@ -293,6 +594,8 @@ impl SchemaValidator {
             context,
             self_instance: None,
             cache_str: self.cache_str,
+            now: None,
+            require_all_fields: false,
         };
         let recursion_guard = &mut RecursionState::default();
         let mut state = ValidationState::new(extra, recursion_guard);
@@ -330,33 +633,221 @@ impl SchemaValidator {
     }
 }
 
+/// The keyword arguments of `validate_python`, for calling [`SchemaValidator::validate_python_with`] from Rust,
+/// the defaults match those of `validate_python`.
+#[derive(Default)]
+pub struct PythonValidateOptions<'a, 'py> {
+    pub strict: Option<bool>,
+    pub from_attributes: Option<bool>,
+    pub context: Option<&'a Bound<'py, PyAny>>,
+    pub self_instance: Option<&'a Bound<'py, PyAny>>,
+    pub cache_shared: bool,
+    pub now: Option<&'a Bound<'py, PyDateTime>>,
+    pub require_all_fields: bool,
+    pub with_hash: bool,
+    pub profile: bool,
+    /// `on_error='collect'`
+    pub collect_errors: bool,
+    pub recursion_guard: Option<&'a Bound<'py, PyRecursionGuard>>,
+    pub report_union_variant: bool,
+    pub report_exactness: bool,
+    pub report_aliases: bool,
+    pub canonicalize: Canonicalize,
+}
+
+/// Everything `_validate` sets up the validation state with, the outputs like `profile` are only recorded
+/// when they're set.
+#[derive(Default)]
+struct ValidateOptions<'a, 'py> {
+    strict: Option<bool>,
+    from_attributes: Option<bool>,
+    context: Option<&'a Bound<'py, PyAny>>,
+    self_instance: Option<&'a Bound<'py, PyAny>>,
+    cache_shared: bool,
+    now: Option<&'a Bound<'py, PyDateTime>>,
+    require_all_fields: bool,
+    profile: Option<&'a mut ValidationProfile>,
+    collected_errors: Option<&'a mut Vec<ValLineError>>,
+    recursion_state: Option<&'a mut RecursionState>,
+    union_variant: Option<&'a mut Option<PyObject>>,
+    exactness: Option<&'a mut Exactness>,
+    matched_aliases: Option<&'a mut Option<Py<PyDict>>>,
+    json_source: Option<&'a dyn JsonSource>,
+    cache_str: Option<StringCacheMode>,
+}
+
 impl SchemaValidator {
-    #[allow(clippy::too_many_arguments)]
+    /// State for validating python inputs outside `_validate`, used by `validate_python_into`,
+    /// `validate_python_batch` and `validate_assignment`.
+    fn python_state<'a, 'py>(
+        &self,
+        recursion_guard: &'a mut RecursionState,
+        strict: Option<bool>,
+        from_attributes: Option<bool>,
+        context: Option<&'a Bound<'py, PyAny>>,
+    ) -> ValidationState<'a, 'py> {
+        let extra = Extra::new(
+            strict,
+            from_attributes,
+            context,
+            None,
+            InputType::Python,
+            self.cache_str,
+        );
+        let mut state = ValidationState::new(extra, recursion_guard);
+        state.max_errors = self.max_errors;
+        state
+    }
+
+    /// Rust equivalent of `validate_python`, taking its keyword arguments as [`PythonValidateOptions`].
+    pub fn validate_python_with<'py>(
+        &self,
+        py: Python<'py>,
+        input: &Bound<'py, PyAny>,
+        options: PythonValidateOptions<'_, 'py>,
+    ) -> PyResult<PyObject> {
+        let PythonValidateOptions {
+            strict,
+            from_attributes,
+            context,
+            self_instance,
+            cache_shared,
+            now,
+            require_all_fields,
+            with_hash,
+            profile,
+            collect_errors,
+            recursion_guard,
+            report_union_variant,
+            report_exactness,
+            report_aliases,
+            canonicalize,
+        } = options;
+        if report_union_variant
+            && !matches!(
+                self.validator,
+                CombinedValidator::Union(_) | CombinedValidator::TaggedUnion(_)
+            )
+        {
+            return Err(PyTypeError::new_err(
+                "`report_union_variant` can only be used with a `union` or `tagged-union` schema",
+            ));
+        }
+        if collect_errors && (with_hash || profile || report_union_variant || report_exactness || report_aliases) {
+            return Err(PyValueError::new_err(
+                "`on_error='collect'` cannot be combined with `with_hash`, `profile`, `report_union_variant`, \
+                `report_exactness` or `report_aliases`",
+            ));
+        }
+        let mut profile = profile.then(ValidationProfile::default);
+        let mut collected_errors = collect_errors.then(Vec::new);
+        let mut recursion_state = recursion_guard.map(|guard| guard.get().lock()).transpose()?;
+        let mut union_variant = None;
+        let mut exactness = Exactness::Exact;
+        let mut matched_aliases = None;
+        let options = ValidateOptions {
+            strict,
+            from_attributes,
+            context,
+            self_instance,
+            cache_shared,
+            now,
+            require_all_fields,
+            profile: profile.as_mut(),
+            collected_errors: collected_errors.as_mut(),
+            recursion_state: recursion_state.as_deref_mut(),
+            union_variant: report_union_variant.then_some(&mut union_variant),
+            exactness: report_exactness.then_some(&mut exactness),
+            matched_aliases: report_aliases.then_some(&mut matched_aliases),
+            ..ValidateOptions::default()
+        };
+        let result = self._validate(py, input, InputType::Python, options);
+        let result = result.and_then(|value| Ok(canonicalize::canonicalize(value.bind(py), canonicalize)?));
+        if let Some(collected_errors) = collected_errors {
+            return self.best_effort_result(py, result, collected_errors);
+        }
+        let value = result.map_err(|e| self.prepare_validation_err(py, e, InputType::Python))?;
+        let hash = match with_hash {
+            true => Some(content_hash(py, value.bind(py))?),
+            false => None,
+        };
+        let profile = profile.map(|profile| profile.to_py(py)).transpose()?;
+        if !report_union_variant && !report_exactness && !report_aliases && hash.is_none() && profile.is_none() {
+            return Ok(value);
+        }
+        let report = ValidationReport {
+            value,
+            union_variant: report_union_variant.then(|| union_variant.unwrap_or_else(|| py.None())),
+            exactness: report_exactness.then(|| exactness.as_str()),
+            aliases: report_aliases.then(|| matched_aliases.map_or_else(|| py.None(), |aliases| aliases.into_py(py))),
+            hash,
+            profile: profile.map(Bound::unbind),
+            errors: None,
+        };
+        Ok(report.into_py(py))
+    }
+
     fn _validate<'py>(
         &self,
         py: Python<'py>,
         input: &(impl Input<'py> + ?Sized),
         input_type: InputType,
-        strict: Option<bool>,
-        from_attributes: Option<bool>,
-        context: Option<&Bound<'py, PyAny>>,
-        self_instance: Option<&Bound<'py, PyAny>>,
+        options: ValidateOptions<'_, 'py>,
     ) -> ValResult<PyObject> {
-        let mut recursion_guard = RecursionState::default();
-        let mut state = ValidationState::new(
-            Extra::new(
-                strict,
-                from_attributes,
-                context,
-                self_instance,
-                input_type,
-                self.cache_str,
-            ),
-            &mut recursion_guard,
+        let ValidateOptions {
+            strict,
+            from_attributes,
+            context,
+            self_instance,
+            cache_shared,
+            now,
+            require_all_fields,
+            profile,
+            collected_errors,
+            recursion_state,
+            union_variant,
+            exactness,
+            matched_aliases,
+            json_source,
+            cache_str,
+        } = options;
+        let mut default_recursion_state;
+        let recursion_guard = match recursion_state {
+            Some(recursion_state) => recursion_state,
+            None => {
+                default_recursion_state = RecursionState::default();
+                &mut default_recursion_state
+            }
+        };
+        let mut extra = Extra::new(
+            strict,
+            from_attributes,
+            context,
+            self_instance,
+            input_type,
+            cache_str.unwrap_or(self.cache_str),
         );
-        self.validator.validate(py, input, &mut state)
+        extra.now = now;
+        extra.require_all_fields = require_all_fields;
+        let mut state = ValidationState::new(extra, recursion_guard);
+        if cache_shared {
+            state.shared_cache = Some(SharedCache::default());
+        }
+        state.max_errors = self.max_errors;
+        state.profile = profile;
+        state.collected_errors = collected_errors;
+        state.union_variant = union_variant;
+        state.matched_aliases = matched_aliases;
+        state.json_source = json_source;
+        state.exactness = exactness.as_deref().copied();
+        let result = self.validator.validate_profiled(py, input, &mut state);
+        if let (Some(exactness), Some(state_exactness)) = (exactness, state.exactness) {
+            *exactness = state_exactness;
+        }
+        result.map_err(|e| state.limit_errors(e, input))
     }
 
+    #[allow(clippy::too_many_arguments)]
     fn _validate_json(
         &self,
         py: Python,
@@ -365,10 +856,73 @@ impl SchemaValidator {
         strict: Option<bool>,
         context: Option<&Bound<'_, PyAny>>,
         self_instance: Option<&Bound<'_, PyAny>>,
+        duplicate_keys: JsonDuplicateKeys,
+        cache_str: Option<StringCacheMode>,
     ) -> ValResult<PyObject> {
-        let json_value =
+        if duplicate_keys == JsonDuplicateKeys::Error {
+            check_duplicate_keys(json_data).map_err(|e| {
+                let error = e.description(&jiter::Jiter::new(json_data));
+                ValError::new(ErrorType::JsonInvalid { error, context: None }, input)
+            })?;
+        }
+        let mut json_value =
             jiter::JsonValue::parse(json_data, true).map_err(|e| json::map_json_err(input, e, json_data))?;
-        self._validate(py, &json_value, InputType::Json, strict, None, context, self_instance)
+        // the raw JSON of values can't be found once objects are merged, since they no longer match the data
+        let mut merged = false;
+        if duplicate_keys != JsonDuplicateKeys::Error && has_duplicate_keys(&json_value) {
+            json_value = merge_duplicate_keys(&json_value, duplicate_keys);
+            merged = true;
+        }
+        let json_document = JsonDocument::new(json_data, &json_value);
+        let json_source: Option<&dyn JsonSource> = if merged { None } else { Some(&json_document) };
+        self._validate(
+            py,
+            &json_value,
+            InputType::Json,
+            ValidateOptions {
+                strict,
+                context,
+                self_instance,
+                json_source,
+                cache_str,
+                ..ValidateOptions::default()
+            },
+        )
+    }
+
+    /// Build the `(value, errors)` result of `validate_python(..., on_error='collect')`, the value is `None`
+    /// if the input as a whole is invalid and `errors` is `None` if there were no errors.
+    fn best_effort_result(
+        &self,
+        py: Python,
+        result: ValResult<PyObject>,
+        mut collected_errors: Vec<ValLineError>,
+    ) -> PyResult<PyObject> {
+        let value = match result {
+            Ok(value) => value,
+            Err(ValError::LineErrors(line_errors)) => {
+                collected_errors.extend(line_errors);
+                py.None()
+            }
+            Err(err) => return Err(self.prepare_validation_err(py, err, InputType::Python)),
+        };
+        let errors = match collected_errors.is_empty() {
+            true => py.None(),
+            false => {
+                let error = ValError::LineErrors(collected_errors);
+                self.prepare_validation_err(py, error, InputType::Python).into_py(py)
+            }
+        };
+        let report = ValidationReport {
+            value,
+            union_variant: None,
+            exactness: None,
+            aliases: None,
+            hash: None,
+            profile: None,
+            errors: Some(errors),
+        };
+        Ok(report.into_py(py))
     }
 
     fn prepare_validation_err(&self, py: Python, error: ValError, input_type: InputType) -> PyErr {
@@ -407,7 +961,7 @@ impl<'py> SelfValidator<'py> {
             Extra::new(strict, None, None, None, InputType::Python, true.into()),
             &mut recursion_guard,
         );
-        match self.validator.validator.validate(py, schema, &mut state) {
+        match self.validator.validator.validate_profiled(py, schema, &mut state) {
             Ok(schema_obj) => Ok(schema_obj.into_bound(py)),
             Err(e) => Err(SchemaError::from_val_error(py, e)),
         }
@@ -435,6 +989,7 @@ impl<'py> SelfValidator<'py> {
             hide_input_in_errors: false,
             validation_error_cause: false,
             cache_str: true.into(),
+            max_errors: None,
         })
     }
 }
@@ -478,6 +1033,118 @@ macro_rules! validator_match {
             _ => return py_schema_err!(r#"Unknown schema type: "{}""#, $type),
         }
     };
+    // whether `$type` is the type of a built-in validator
+    (@is_builtin $type:ident, $($validator:path,)+) => {
+        matches!($type, $(<$validator>::EXPECTED_TYPE)|+)
+    };
+}
+
+// call `validator_match` with `args` followed by every built-in validator
+macro_rules! with_builtin_validators {
+    ($($args:tt)*) => {
+        validator_match!(
+            $($args)*
+            // typed dict e.g. heterogeneous dicts or simply a model
+            typed_dict::TypedDictValidator,
+            // unions
+            union::UnionValidator,
+            union::TaggedUnionValidator,
+            // nullables
+            nullable::NullableValidator,
+            // model classes
+            model::ModelValidator,
+            model_fields::ModelFieldsValidator,
+            // dataclasses
+            dataclass::DataclassArgsValidator,
+            dataclass::DataclassValidator,
+            // strings
+            string::StrValidator,
+            // integers
+            int::IntValidator,
+            // boolean
+            bool::BoolValidator,
+            // floats
+            float::FloatBuilder,
+            // decimals
+            decimal::DecimalValidator,
+            // fractions
+            fraction::FractionValidator,
+            // tuples
+            tuple::TupleValidator,
+            // intervals - pairs of ordered endpoints
+            interval::IntervalValidator,
+            // list/arrays
+            list::ListValidator,
+            // sets - unique lists
+            set::SetValidator,
+            // dicts/objects (recursive)
+            dict::DictValidator,
+            // None/null
+            none::NoneValidator,
+            // functions - before, after, plain & wrap
+            function::FunctionAfterValidator,
+            function::FunctionBeforeValidator,
+            function::FunctionPlainValidator,
+            function::FunctionWrapValidator,
+            // function call - validation around a function call
+            call::CallValidator,
+            // literals
+            literal::LiteralValidator,
+            // enums
+            enum_::BuildEnumValidator,
+            // any
+            any::AnyValidator,
+            // bytes
+            bytes::BytesValidator,
+            // dates
+            date::DateValidator,
+            // times
+            time::TimeValidator,
+            // datetimes
+            datetime::DateTimeValidator,
+            // frozensets
+            frozenset::FrozenSetValidator,
+            // timedelta
+            timedelta::TimeDeltaValidator,
+            // introspection types
+            is_instance::IsInstanceValidator,
+            is_subclass::IsSubclassValidator,
+            callable::CallableValidator,
+            // arguments
+            arguments::ArgumentsValidator,
+            // default value
+            with_default::WithDefaultValidator,
+            // chain validators
+            chain::ChainValidator,
+            // lax or strict
+            lax_or_strict::LaxOrStrictValidator,
+            // json or python
+            json_or_python::JsonOrPython,
+            // generator validators
+            generator::GeneratorValidator,
+            // custom error
+            custom_error::CustomErrorValidator,
+            // json data
+            json::JsonValidator,
+            json::JsonStringValidator,
+            json_raw::JsonRawValidator,
+            // url types
+            url::UrlValidator,
+            url::MultiHostUrlValidator,
+            // uuid types
+            uuid::UuidValidator,
+            // email addresses
+            email::EmailValidator,
+            // recursive (self-referencing) models
+            definitions::DefinitionRefValidator,
+            definitions::DefinitionsValidatorBuilder,
+        )
+    };
+}
+
+/// Whether `type_` is the `type` of a built-in schema, these can't be registered as custom schema types.
+pub fn is_builtin_schema_type(type_: &str) -> bool {
+    with_builtin_validators!(@is_builtin type_,)
 }
 
 pub fn build_validator(
@@ -488,98 +1155,10 @@ pub fn build_validator(
     let dict = schema.downcast::<PyDict>()?;
     let type_: Bound<'_, PyString> = dict.get_as_req(intern!(schema.py(), "type"))?;
     let type_ = type_.to_str()?;
-    validator_match!(
-        type_,
-        dict,
-        config,
-        definitions,
-        // typed dict e.g. heterogeneous dicts or simply a model
-        typed_dict::TypedDictValidator,
-        // unions
-        union::UnionValidator,
-        union::TaggedUnionValidator,
-        // nullables
-        nullable::NullableValidator,
-        // model classes
-        model::ModelValidator,
-        model_fields::ModelFieldsValidator,
-        // dataclasses
-        dataclass::DataclassArgsValidator,
-        dataclass::DataclassValidator,
-        // strings
-        string::StrValidator,
-        // integers
-        int::IntValidator,
-        // boolean
-        bool::BoolValidator,
-        // floats
-        float::FloatBuilder,
-        // decimals
-        decimal::DecimalValidator,
-        // tuples
-        tuple::TupleValidator,
-        // list/arrays
-        list::ListValidator,
-        // sets - unique lists
-        set::SetValidator,
-        // dicts/objects (recursive)
-        dict::DictValidator,
-        // None/null
-        none::NoneValidator,
-        // functions - before, after, plain & wrap
-        function::FunctionAfterValidator,
-        function::FunctionBeforeValidator,
-        function::FunctionPlainValidator,
-        function::FunctionWrapValidator,
-        // function call - validation around a function call
-        call::CallValidator,
-        // literals
-        literal::LiteralValidator,
-        // enums
-        enum_::BuildEnumValidator,
-        // any
-        any::AnyValidator,
-        // bytes
-        bytes::BytesValidator,
-        // dates
-        date::DateValidator,
-        // times
-        time::TimeValidator,
-        // datetimes
-        datetime::DateTimeValidator,
-        // frozensets
-        frozenset::FrozenSetValidator,
-        // timedelta
-        timedelta::TimeDeltaValidator,
-        // introspection types
-        is_instance::IsInstanceValidator,
-        is_subclass::IsSubclassValidator,
-        callable::CallableValidator,
-        // arguments
-        arguments::ArgumentsValidator,
-        // default value
-        with_default::WithDefaultValidator,
-        // chain validators
-        chain::ChainValidator,
-        // lax or strict
-        lax_or_strict::LaxOrStrictValidator,
-        // json or python
-        json_or_python::JsonOrPython,
-        // generator validators
-        generator::GeneratorValidator,
-        // custom error
-        custom_error::CustomErrorValidator,
-        // json data
-        json::JsonValidator,
-        // url types
-        url::UrlValidator,
-        url::MultiHostUrlValidator,
-        // uuid types
-        uuid::UuidValidator,
-        // recursive (self-referencing) models
-        definitions::DefinitionRefValidator,
-        definitions::DefinitionsValidatorBuilder,
-    )
+    if let Some(expanded) = expand_schema_type(dict, type_)? {
+        return build_validator(&expanded, config, definitions);
+    }
+    with_builtin_validators!(type_, dict, config, definitions,)
 }
 
 /// More (mostly immutable) data to pass between validators, should probably be class `Context`,
@@ -600,6 +1179,10 @@ pub struct Extra<'a, 'py> {
     self_instance: Option<&'a Bound<'py, PyAny>>,
     /// Whether to use a cache of short strings to accelerate python string construction
     cache_str: StringCacheMode,
+    /// Overrides the current time used by `now_op` constraints on dates and datetimes
+    pub(crate) now: Option<&'a Bound<'py, PyDateTime>>,
+    /// Whether absent fields of models and typed dicts are errors even when they have a default
+    pub require_all_fields: bool,
 }
 
 impl<'a, 'py> Extra<'a, 'py> {
@@ -619,6 +1202,8 @@ impl<'a, 'py> Extra<'a, 'py> {
             context,
             self_instance,
             cache_str,
+            now: None,
+            require_all_fields: false,
         }
     }
 }
@@ -633,6 +1218,8 @@ impl Extra<'_, '_> {
             context: self.context,
             self_instance: self.self_instance,
             cache_str: self.cache_str,
+            now: self.now,
+            require_all_fields: self.require_all_fields,
         }
     }
 }
@@ -659,6 +1246,8 @@ pub enum CombinedValidator {
     // integers
     Int(int::IntValidator),
     ConstrainedInt(int::ConstrainedIntValidator),
+    // remembers the results of a scalar validator
+    Cached(cached::CachedValidator),
     // booleans
     Bool(bool::BoolValidator),
     // floats
@@ -666,12 +1255,16 @@ pub enum CombinedValidator {
     ConstrainedFloat(float::ConstrainedFloatValidator),
     // decimals
     Decimal(decimal::DecimalValidator),
+    // fractions
+    Fraction(fraction::FractionValidator),
     // lists
     List(list::ListValidator),
     // sets - unique lists
     Set(set::SetValidator),
     // tuples
     Tuple(tuple::TupleValidator),
+    // intervals - pairs of ordered endpoints
+    Interval(interval::IntervalValidator),
     // dicts/objects (recursive)
     Dict(dict::DictValidator),
     // None/null
@@ -723,17 +1316,43 @@ pub enum CombinedValidator {
     CustomError(custom_error::CustomErrorValidator),
     // json data
     Json(json::JsonValidator),
+    JsonString(json::JsonStringValidator),
+    JsonRaw(json_raw::JsonRawValidator),
     // url types
     Url(url::UrlValidator),
     MultiHostUrl(url::MultiHostUrlValidator),
     // uuid types
     Uuid(uuid::UuidValidator),
+    // email addresses
+    Email(email::EmailValidator),
     // reference to definition, useful for recursive (self-referencing) models
     DefinitionRef(definitions::DefinitionRefValidator),
     // input dependent
     JsonOrPython(json_or_python::JsonOrPython),
 }
 
+impl CombinedValidator {
+    /// Validators call this rather than `Validator::validate` to validate with a nested validator, it records
+    /// call counts and timings when profiling is enabled and otherwise costs a single check.
+    #[inline]
+    pub fn validate_profiled<'py>(
+        &self,
+        py: Python<'py>,
+        input: &(impl Input<'py> + ?Sized),
+        state: &mut ValidationState<'_, 'py>,
+    ) -> ValResult<PyObject> {
+        if state.profile.is_none() {
+            return Validator::validate(self, py, input, state);
+        }
+        let start = Instant::now();
+        let result = Validator::validate(self, py, input, state);
+        if let Some(profile) = state.profile.as_deref_mut() {
+            profile.record(self.get_name(), start.elapsed());
+        }
+        result
+    }
+}
+
 /// This trait must be implemented by all validators, it allows various validators to be accessed consistently,
 /// validators defined in `build_validator` also need `EXPECTED_TYPE` as a const, but that can't be part of the trait
 #[enum_dispatch(CombinedValidator)]
@@ -770,6 +1389,12 @@ pub trait Validator: Send + Sync + Debug {
         Err(py_err.into())
     }
 
+    /// Build an approximate JSON Schema of the values accepted by this validator, used by
+    /// `SchemaValidator.json_schema`, schemas of definitions are collected in `defs`
+    fn json_schema<'py>(&self, py: Python<'py>, _defs: &Bound<'py, PyDict>) -> PyResult<Bound<'py, PyDict>> {
+        json_schema::placeholder_json_schema(py, self.get_name())
+    }
+
     /// `get_name` generally returns `Self::EXPECTED_TYPE` or some other clear identifier of the validator
     /// this is used in the error location in unions, and in the top level message in `ValidationError`
     fn get_name(&self) -> &str;
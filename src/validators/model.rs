@@ -1,6 +1,6 @@
 use std::ptr::null_mut;
 
-use pyo3::exceptions::PyTypeError;
+use pyo3::exceptions::{PyAttributeError, PyTypeError};
 use pyo3::ffi;
 use pyo3::types::{PyDict, PySet, PyString, PyTuple, PyType};
 use pyo3::{intern, prelude::*};
@@ -59,6 +59,8 @@ pub struct ModelValidator {
     frozen: bool,
     custom_init: bool,
     root_model: bool,
+    // `False` for classes using `__slots__` without `__dict__`, fields are then set as individual attributes
+    has_dict: bool,
     undefined: PyObject,
     name: String,
 }
@@ -79,6 +81,7 @@ impl BuildValidator for ModelValidator {
         let sub_schema = schema.get_as_req(intern!(py, "schema"))?;
         let validator = build_validator(&sub_schema, config.as_ref(), definitions)?;
         let name = class.getattr(intern!(py, "__name__"))?.extract()?;
+        let has_dict = class_has_dict(&class)?;
 
         Ok(Self {
             revalidate: Revalidate::from_str(
@@ -97,6 +100,7 @@ impl BuildValidator for ModelValidator {
             frozen: schema.get_as(intern!(py, "frozen"))?.unwrap_or(false),
             custom_init: schema.get_as(intern!(py, "custom_init"))?.unwrap_or(false),
             root_model: schema.get_as(intern!(py, "root_model"))?.unwrap_or(false),
+            has_dict,
             undefined: PydanticUndefinedType::new(py).to_object(py),
             // Get the class's `__name__`, not using `class.qualname()`
             name,
@@ -132,13 +136,13 @@ impl Validator for ModelValidator {
                     self.validate_construct(py, &inner_input, Some(&fields_set), state)
                 } else {
                     // get dict here so from_attributes logic doesn't apply
-                    let dict = py_input.getattr(intern!(py, DUNDER_DICT))?;
+                    let dict = get_model_dict(py_input, self.has_dict)?;
                     let model_extra = py_input.getattr(intern!(py, DUNDER_MODEL_EXTRA_KEY))?;
 
                     let inner_input = if PyAnyMethods::is_none(&model_extra) {
-                        dict
+                        dict.into_any()
                     } else {
-                        let full_model_dict = dict.downcast::<PyDict>()?.copy()?;
+                        let full_model_dict = dict.copy()?;
                         full_model_dict.update(model_extra.downcast()?)?;
                         full_model_dict.into_any()
                     };
@@ -175,13 +179,13 @@ impl Validator for ModelValidator {
                     field_name.to_string(),
                 ))
             } else {
-                let output = self.validator.validate(py, field_value, state)?;
+                let output = self.validator.validate_profiled(py, field_value, state)?;
 
                 force_setattr(py, model, intern!(py, ROOT_FIELD), output)?;
                 Ok(model.into_py(py))
             };
         }
-        let old_dict = model.getattr(intern!(py, DUNDER_DICT))?.downcast_into::<PyDict>()?;
+        let old_dict = get_model_dict(model, self.has_dict)?;
 
         let input_dict = old_dict.copy()?;
         if let Ok(old_extra) = model.getattr(intern!(py, DUNDER_MODEL_EXTRA_KEY))?.downcast::<PyDict>() {
@@ -206,7 +210,7 @@ impl Validator for ModelValidator {
             }
         }
 
-        force_setattr(py, model, intern!(py, DUNDER_DICT), validated_dict.to_object(py))?;
+        set_model_dict(model, validated_dict.as_any(), self.has_dict)?;
         force_setattr(
             py,
             model,
@@ -216,6 +220,10 @@ impl Validator for ModelValidator {
         Ok(model.into_py(py))
     }
 
+    fn json_schema<'py>(&self, py: Python<'py>, defs: &Bound<'py, PyDict>) -> PyResult<Bound<'py, PyDict>> {
+        self.validator.json_schema(py, defs)
+    }
+
     fn get_name(&self) -> &str {
         &self.name
     }
@@ -233,7 +241,7 @@ impl ModelValidator {
         // we need to set `self_instance` to None for nested validators as we don't want to operate on self_instance
         // anymore
         let state = &mut state.rebind_extra(|extra| extra.self_instance = None);
-        let output = self.validator.validate(py, input, state)?;
+        let output = self.validator.validate_profiled(py, input, state)?;
 
         if self.root_model {
             let fields_set = if input.to_object(py).is(&self.undefined) {
@@ -245,7 +253,7 @@ impl ModelValidator {
             force_setattr(py, self_instance, intern!(py, ROOT_FIELD), &output)?;
         } else {
             let (model_dict, model_extra, fields_set) = output.extract(py)?;
-            set_model_attrs(self_instance, &model_dict, &model_extra, &fields_set)?;
+            set_model_attrs(self_instance, &model_dict, &model_extra, &fields_set, self.has_dict)?;
         }
         self.call_post_init(py, self_instance.clone(), input, state.extra())
     }
@@ -271,7 +279,7 @@ impl ModelValidator {
             }
         }
 
-        let output = self.validator.validate(py, input, state)?;
+        let output = self.validator.validate_profiled(py, input, state)?;
 
         let instance = create_class(self.class.bind(py))?;
 
@@ -286,7 +294,7 @@ impl ModelValidator {
         } else {
             let (model_dict, model_extra, val_fields_set) = output.extract(py)?;
             let fields_set = existing_fields_set.unwrap_or(&val_fields_set);
-            set_model_attrs(&instance, &model_dict, &model_extra, fields_set)?;
+            set_model_attrs(&instance, &model_dict, &model_extra, fields_set, self.has_dict)?;
         }
         self.call_post_init(py, instance, input, state.extra())
     }
@@ -333,15 +341,76 @@ fn set_model_attrs(
     model_dict: &Bound<'_, PyAny>,
     model_extra: &Bound<'_, PyAny>,
     fields_set: &Bound<'_, PyAny>,
+    has_dict: bool,
 ) -> PyResult<()> {
     let py = instance.py();
-    force_setattr(py, instance, intern!(py, DUNDER_DICT), model_dict)?;
+    set_model_dict(instance, model_dict, has_dict)?;
     force_setattr(py, instance, intern!(py, DUNDER_MODEL_EXTRA_KEY), model_extra)?;
     force_setattr(py, instance, intern!(py, DUNDER_MODEL_PRIVATE_KEY), py.None())?;
     force_setattr(py, instance, intern!(py, DUNDER_FIELDS_SET_KEY), fields_set)?;
     Ok(())
 }
 
+/// Set the fields of a model, either by replacing `__dict__` or, for classes using `__slots__` without
+/// `__dict__`, by setting each field as an attribute.
+fn set_model_dict(instance: &Bound<'_, PyAny>, model_dict: &Bound<'_, PyAny>, has_dict: bool) -> PyResult<()> {
+    let py = instance.py();
+    if has_dict {
+        force_setattr(py, instance, intern!(py, DUNDER_DICT), model_dict)
+    } else {
+        for (field_name, value) in model_dict.downcast::<PyDict>()? {
+            force_setattr(py, instance, field_name, value)?;
+        }
+        Ok(())
+    }
+}
+
+/// Whether instances of a model class have a `__dict__`, `False` for classes using `__slots__` without `__dict__`.
+pub(crate) fn class_has_dict(class: &Bound<'_, PyType>) -> PyResult<bool> {
+    let py = class.py();
+    Ok(class.getattr(intern!(py, "__dictoffset__"))?.extract::<isize>()? != 0
+        || !class.hasattr(intern!(py, "__slots__"))?)
+}
+
+/// Get the fields of a model, for classes using `__slots__` without `__dict__` this is built from the slots
+/// which have been set, ignoring dunder slots like `__pydantic_fields_set__`.
+pub(crate) fn get_model_dict<'py>(instance: &Bound<'py, PyAny>, has_dict: bool) -> PyResult<Bound<'py, PyDict>> {
+    let py = instance.py();
+    if has_dict {
+        return Ok(instance.getattr(intern!(py, DUNDER_DICT))?.downcast_into::<PyDict>()?);
+    }
+    let model_dict = PyDict::new_bound(py);
+    let mro = instance.get_type().getattr(intern!(py, "__mro__"))?;
+    for base in mro.downcast::<PyTuple>()?.iter().rev() {
+        // only the slots declared on this class, not those inherited from its bases
+        let slots = base
+            .getattr(intern!(py, DUNDER_DICT))?
+            .call_method1(intern!(py, "get"), (intern!(py, "__slots__"),))?;
+        if PyAnyMethods::is_none(&slots) {
+            continue;
+        }
+        let slots = if slots.is_instance_of::<PyString>() {
+            PyTuple::new_bound(py, [slots]).into_any()
+        } else {
+            slots
+        };
+        for slot in slots.iter()? {
+            let slot = slot?;
+            let slot_name = slot.downcast::<PyString>()?;
+            if slot_name.to_str()?.starts_with("__") {
+                continue;
+            }
+            match instance.getattr(slot_name) {
+                Ok(value) => model_dict.set_item(slot_name, value)?,
+                // the slot hasn't been set
+                Err(err) if err.is_instance_of::<PyAttributeError>(py) => (),
+                Err(err) => return Err(err),
+            }
+        }
+    }
+    Ok(model_dict)
+}
+
 pub(super) fn force_setattr<N, V>(py: Python<'_>, obj: &Bound<'_, PyAny>, attr_name: N, value: V) -> PyResult<()>
 where
     N: ToPyObject,
@@ -1,7 +1,7 @@
-use pyo3::exceptions::PyKeyError;
+use pyo3::exceptions::{PyDeprecationWarning, PyKeyError};
 use pyo3::intern;
 use pyo3::prelude::*;
-use pyo3::types::{PyDict, PySet, PyString, PyType};
+use pyo3::types::{PyDict, PyList, PySet, PyString, PyType};
 
 use ahash::AHashSet;
 
@@ -11,9 +11,10 @@ use crate::errors::LocItem;
 use crate::errors::{ErrorType, ErrorTypeDefaults, ValError, ValLineError, ValResult};
 use crate::input::ConsumeIterator;
 use crate::input::{BorrowInput, Input, ValidatedDict, ValidationMatch};
-use crate::lookup_key::LookupKey;
+use crate::lookup_key::{AttributeAccess, CaseCollision, CaseInsensitiveKeys, LookupKey};
 use crate::tools::SchemaDict;
 
+use super::json_schema::{is_required, object_json_schema};
 use super::{build_validator, BuildValidator, CombinedValidator, DefinitionsBuilder, ValidationState, Validator};
 
 #[derive(Debug)]
@@ -23,10 +24,66 @@ struct Field {
     name_py: Py<PyString>,
     validator: CombinedValidator,
     frozen: bool,
+    deprecated: Option<String>,
 }
 
 impl_py_gc_traverse!(Field { validator });
 
+/// The warning message for a field with `deprecated` set, either the custom message or a generic one.
+pub(super) fn deprecated_message(field_info: &Bound<'_, PyDict>, field_name: &str) -> PyResult<Option<String>> {
+    let py = field_info.py();
+    match field_info.get_item(intern!(py, "deprecated"))? {
+        Some(deprecated) => {
+            if let Ok(message) = deprecated.downcast::<PyString>() {
+                Ok(Some(message.to_str()?.to_string()))
+            } else if deprecated.extract::<bool>()? {
+                Ok(Some(format!("Field `{field_name}` is deprecated")))
+            } else {
+                Ok(None)
+            }
+        }
+        None => Ok(None),
+    }
+}
+
+/// Issue a `DeprecationWarning` for a deprecated field found in the input.
+pub(super) fn warn_deprecated(py: Python, message: &str) -> PyResult<()> {
+    PyErr::warn_bound(py, &py.get_type_bound::<PyDeprecationWarning>(), message, 1)
+}
+
+/// Fields which are required when another field has a given value, set with `conditional_required`.
+#[derive(Debug)]
+struct ConditionalRequired {
+    /// index of the field whose value is checked
+    field: usize,
+    equals: PyObject,
+    /// indices of the fields which must be in the input when the field's value equals `equals`
+    then_required: Vec<usize>,
+}
+
+impl_py_gc_traverse!(ConditionalRequired { equals });
+
+impl ConditionalRequired {
+    fn build(rule: &Bound<'_, PyDict>, fields: &[Field]) -> PyResult<Self> {
+        let py = rule.py();
+        let field_index = |name: &str| match fields.iter().position(|f| f.name == name) {
+            Some(index) => Ok(index),
+            None => py_schema_err!("conditional_required: unknown field \"{}\"", name),
+        };
+        let condition: Bound<'_, PyDict> = rule.get_as_req(intern!(py, "if"))?;
+        let field: String = condition.get_as_req(intern!(py, "field"))?;
+        let then_required: Vec<String> = rule.get_as_req(intern!(py, "then_required"))?;
+        Ok(Self {
+            field: field_index(&field)?,
+            equals: condition.get_as_req(intern!(py, "equals"))?,
+            then_required: then_required
+                .iter()
+                .map(|name| field_index(name))
+                .collect::<PyResult<_>>()?,
+        })
+    }
+}
+
 #[derive(Debug)]
 pub struct ModelFieldsValidator {
     fields: Vec<Field>,
@@ -35,7 +92,10 @@ pub struct ModelFieldsValidator {
     extras_validator: Option<Box<CombinedValidator>>,
     strict: bool,
     from_attributes: bool,
+    attribute_access: AttributeAccess,
     loc_by_alias: bool,
+    case_collision: Option<CaseCollision>,
+    conditional_required: Vec<ConditionalRequired>,
 }
 
 impl BuildValidator for ModelFieldsValidator {
@@ -50,6 +110,10 @@ impl BuildValidator for ModelFieldsValidator {
         let strict = is_strict(schema, config)?;
 
         let from_attributes = schema_or_config_same(schema, config, intern!(py, "from_attributes"))?.unwrap_or(false);
+        let attribute_access = match schema_or_config_same(schema, config, intern!(py, "from_attributes_safe"))? {
+            Some(true) => AttributeAccess::Safe,
+            _ => AttributeAccess::GetAttr,
+        };
         let populate_by_name = schema_or_config_same(schema, config, intern!(py, "populate_by_name"))?.unwrap_or(false);
 
         let extra_behavior = ExtraBehavior::from_schema_or_config(py, schema, config, ExtraBehavior::Ignore)?;
@@ -86,15 +150,25 @@ impl BuildValidator for ModelFieldsValidator {
                 None => LookupKey::from_string(py, field_name),
             };
 
+            let deprecated = deprecated_message(field_info, field_name)?;
             fields.push(Field {
                 name: field_name.to_string(),
                 lookup_key,
                 name_py: field_name_py.into(),
                 validator,
                 frozen: field_info.get_as::<bool>(intern!(py, "frozen"))?.unwrap_or(false),
+                deprecated,
             });
         }
 
+        let conditional_required = match schema.get_as::<Bound<'_, PyList>>(intern!(py, "conditional_required"))? {
+            Some(rules) => rules
+                .iter()
+                .map(|rule| ConditionalRequired::build(rule.downcast()?, &fields))
+                .collect::<PyResult<_>>()?,
+            None => Vec::new(),
+        };
+
         Ok(Self {
             fields,
             model_name,
@@ -102,7 +176,10 @@ impl BuildValidator for ModelFieldsValidator {
             extras_validator,
             strict,
             from_attributes,
+            attribute_access,
             loc_by_alias: config.get_as(intern!(py, "loc_by_alias"))?.unwrap_or(true),
+            case_collision: CaseCollision::from_schema(schema)?,
+            conditional_required,
         }
         .into())
     }
@@ -110,7 +187,8 @@ impl BuildValidator for ModelFieldsValidator {
 
 impl_py_gc_traverse!(ModelFieldsValidator {
     fields,
-    extras_validator
+    extras_validator,
+    conditional_required
 });
 
 impl Validator for ModelFieldsValidator {
@@ -124,7 +202,7 @@ impl Validator for ModelFieldsValidator {
         let from_attributes = state.extra().from_attributes.unwrap_or(self.from_attributes);
 
         // we convert the DictType error to a ModelType error
-        let dict = match input.validate_model_fields(strict, from_attributes) {
+        let dict = match input.validate_model_fields(strict, from_attributes.then_some(self.attribute_access)) {
             Ok(d) => d,
             Err(ValError::LineErrors(errors)) => {
                 let errors: Vec<ValLineError> = errors
@@ -150,6 +228,14 @@ impl Validator for ModelFieldsValidator {
         let mut model_extra_dict_op: Option<Bound<PyDict>> = None;
         let mut errors: Vec<ValLineError> = Vec::with_capacity(self.fields.len());
         let mut fields_set_vec: Vec<Py<PyString>> = Vec::with_capacity(self.fields.len());
+        let matched_aliases = state.matched_aliases.is_some().then(|| PyDict::new_bound(py));
+        // which fields took their default value, so `conditional_required` can tell they weren't in the input
+        let mut defaulted = vec![false; self.fields.len()];
+
+        let case_insensitive_keys = match self.case_collision {
+            Some(collision) if !dict.is_py_get_attr() => Some(CaseInsensitiveKeys::new(&dict, collision)?),
+            _ => None,
+        };
 
         // we only care about which keys have been used if we're iterating over the object for extra after
         // the first pass
@@ -163,8 +249,12 @@ impl Validator for ModelFieldsValidator {
         {
             let state = &mut state.rebind_extra(|extra| extra.data = Some(model_dict.clone()));
 
-            for field in &self.fields {
-                let op_key_value = match dict.get_item(&field.lookup_key) {
+            for (index, field) in self.fields.iter().enumerate() {
+                let op_key_value = match case_insensitive_keys {
+                    Some(ref keys) => keys.get_item(py, &dict, &field.lookup_key, input),
+                    None => dict.get_item(&field.lookup_key),
+                };
+                let op_key_value = match op_key_value {
                     Ok(v) => v,
                     Err(ValError::LineErrors(line_errors)) => {
                         for err in line_errors {
@@ -179,11 +269,27 @@ impl Validator for ModelFieldsValidator {
                         // key is "used" whether or not validation passes, since we want to skip this key in
                         // extra logic either way
                         used_keys.insert(lookup_path.first_key());
+                        if let Some(ref keys) = case_insensitive_keys {
+                            used_keys.extend(keys.variants(lookup_path.first_key()));
+                        }
+                    }
+                    if let Some(ref message) = field.deprecated {
+                        warn_deprecated(py, message)?;
                     }
-                    match field.validator.validate(py, value.borrow_input(), state) {
+                    let start = state.collected_count();
+                    let result = field.validator.validate_profiled(py, value.borrow_input(), state);
+                    let locate = |err| lookup_path.apply_error_loc(err, self.loc_by_alias, &field.name);
+                    match state.collect_item(py, result, start, locate) {
                         Ok(value) => {
                             model_dict.set_item(&field.name_py, value)?;
                             fields_set_vec.push(field.name_py.clone_ref(py));
+                            if let Some(ref matched_aliases) = matched_aliases {
+                                let alias = match case_insensitive_keys {
+                                    Some(ref keys) => keys.alias_py(py, lookup_path),
+                                    None => lookup_path.alias_py(py),
+                                };
+                                matched_aliases.set_item(&field.name_py, alias)?;
+                            }
                         }
                         Err(ValError::Omit) => continue,
                         Err(ValError::LineErrors(line_errors)) => {
@@ -196,19 +302,29 @@ impl Validator for ModelFieldsValidator {
                     continue;
                 }
 
-                match field.validator.default_value(py, Some(field.name.as_str()), state) {
+                let default = if state.extra().require_all_fields {
+                    Ok(None)
+                } else {
+                    field.validator.default_value(py, Some(field.name.as_str()), state)
+                };
+                match default {
                     Ok(Some(value)) => {
                         // Default value exists, and passed validation if required
                         model_dict.set_item(&field.name_py, value)?;
+                        defaulted[index] = true;
+                        if let Some(ref matched_aliases) = matched_aliases {
+                            matched_aliases.set_item(&field.name_py, py.None())?;
+                        }
                     }
                     Ok(None) => {
-                        // This means there was no default value
-                        errors.push(field.lookup_key.error(
-                            ErrorTypeDefaults::Missing,
-                            input,
-                            self.loc_by_alias,
-                            &field.name,
-                        ));
+                        // This means there was no default value, or `require_all_fields` disabled it
+                        let error =
+                            field
+                                .lookup_key
+                                .error(ErrorTypeDefaults::Missing, input, self.loc_by_alias, &field.name);
+                        if let Some(none) = state.collect_missing(py, error, &mut errors) {
+                            model_dict.set_item(&field.name_py, none)?;
+                        }
                     }
                     Err(ValError::Omit) => continue,
                     Err(ValError::LineErrors(line_errors)) => {
@@ -225,6 +341,28 @@ impl Validator for ModelFieldsValidator {
             }
         }
 
+        for rule in &self.conditional_required {
+            let Some(value) = model_dict.get_item(&self.fields[rule.field].name_py)? else {
+                continue;
+            };
+            if !value.eq(&rule.equals)? {
+                continue;
+            }
+            for &index in &rule.then_required {
+                // fields missing without a default already have an error, and only report each field once
+                if std::mem::take(&mut defaulted[index]) {
+                    let field = &self.fields[index];
+                    let error =
+                        field
+                            .lookup_key
+                            .error(ErrorTypeDefaults::Missing, input, self.loc_by_alias, &field.name);
+                    if let Some(none) = state.collect_missing(py, error, &mut errors) {
+                        model_dict.set_item(&field.name_py, none)?;
+                    }
+                }
+            }
+        }
+
         if let Some(used_keys) = used_keys {
             struct ValidateToModelExtra<'a, 's, 'py> {
                 py: Python<'py>,
@@ -285,7 +423,7 @@ impl Validator for ModelFieldsValidator {
                             ExtraBehavior::Allow => {
                                 let py_key = either_str.as_py_string(self.py, self.state.cache_str());
                                 if let Some(validator) = self.extras_validator {
-                                    match validator.validate(self.py, value, self.state) {
+                                    match validator.validate_profiled(self.py, value, self.state) {
                                         Ok(value) => {
                                             model_extra_dict.set_item(&py_key, value)?;
                                             self.fields_set_vec.push(py_key.into());
@@ -333,6 +471,9 @@ impl Validator for ModelFieldsValidator {
             if matches!(self.extra_behavior, ExtraBehavior::Allow) && model_extra_dict_op.is_none() {
                 model_extra_dict_op = Some(PyDict::new_bound(py));
             };
+            if let Some(matched_aliases) = matched_aliases {
+                state.record_matched_aliases(matched_aliases);
+            }
 
             Ok((model_dict, model_extra_dict_op, fields_set).to_object(py))
         }
@@ -386,7 +527,7 @@ impl Validator for ModelFieldsValidator {
                     ));
                 }
 
-                prepare_result(field.validator.validate(py, field_value, state))?
+                prepare_result(field.validator.validate_profiled(py, field_value, state))?
             } else {
                 // Handle extra (unknown) field
                 // We partially use the extra_behavior for initialization / validation
@@ -395,7 +536,7 @@ impl Validator for ModelFieldsValidator {
                 // unless the user explicitly set extra_behavior to 'allow'
                 match self.extra_behavior {
                     ExtraBehavior::Allow => match self.extras_validator {
-                        Some(ref validator) => prepare_result(validator.validate(py, field_value, state))?,
+                        Some(ref validator) => prepare_result(validator.validate_profiled(py, field_value, state))?,
                         None => get_updated_dict(field_value.to_object(py))?,
                     },
                     ExtraBehavior::Forbid | ExtraBehavior::Ignore => {
@@ -432,6 +573,16 @@ impl Validator for ModelFieldsValidator {
         Ok((new_data.to_object(py), new_extra, fields_set.to_object(py)).to_object(py))
     }
 
+    fn json_schema<'py>(&self, py: Python<'py>, defs: &Bound<'py, PyDict>) -> PyResult<Bound<'py, PyDict>> {
+        let fields = self
+            .fields
+            .iter()
+            .map(|f| (&f.name_py, &f.validator, is_required(&f.validator)));
+        let json_schema = object_json_schema(py, defs, fields, self.extra_behavior, self.extras_validator.as_deref())?;
+        json_schema.set_item(intern!(py, "title"), &self.model_name)?;
+        Ok(json_schema)
+    }
+
     fn get_name(&self) -> &str {
         Self::EXPECTED_TYPE
     }
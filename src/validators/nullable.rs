@@ -1,20 +1,65 @@
 use pyo3::intern;
 use pyo3::prelude::*;
-use pyo3::types::PyDict;
+use pyo3::types::{PyDict, PyString};
 
+use crate::build_tools::{is_strict, py_schema_err};
 use crate::errors::ValResult;
 use crate::input::Input;
 use crate::tools::SchemaDict;
 
-use super::ValidationState;
+use super::json_schema::{any_of_json_schema, new_schema};
 use super::{build_validator, BuildValidator, CombinedValidator, DefinitionsBuilder, Validator};
+use super::{Exactness, ValidationState};
 
 #[derive(Debug)]
 pub struct NullableValidator {
     validator: Box<CombinedValidator>,
+    strict: bool,
+    empty_str_as_none: EmptyStrAsNone,
     name: String,
 }
 
+/// Which strings are treated as `None` in lax mode, e.g. for values from web forms.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum EmptyStrAsNone {
+    Off,
+    Empty,
+    Whitespace,
+}
+
+impl EmptyStrAsNone {
+    fn from_py(schema: &Bound<'_, PyDict>) -> PyResult<Self> {
+        let Some(value) = schema.get_item(intern!(schema.py(), "empty_str_as_none"))? else {
+            return Ok(Self::Off);
+        };
+        if let Ok(s) = value.downcast::<PyString>() {
+            match s.to_str()? {
+                "whitespace" => Ok(Self::Whitespace),
+                s => py_schema_err!("Invalid empty_str_as_none {:?}, should be a bool or 'whitespace'", s),
+            }
+        } else if value.extract::<bool>()? {
+            Ok(Self::Empty)
+        } else {
+            Ok(Self::Off)
+        }
+    }
+
+    fn matches<'py>(self, input: &(impl Input<'py> + ?Sized)) -> ValResult<bool> {
+        if self == Self::Off {
+            return Ok(false);
+        }
+        let Ok(either_str) = input.validate_str(true, false) else {
+            return Ok(false);
+        };
+        let either_str = either_str.into_inner();
+        let s = either_str.as_cow()?;
+        Ok(match self {
+            Self::Whitespace => s.trim().is_empty(),
+            _ => s.is_empty(),
+        })
+    }
+}
+
 impl BuildValidator for NullableValidator {
     const EXPECTED_TYPE: &'static str = "nullable";
 
@@ -23,10 +68,16 @@ impl BuildValidator for NullableValidator {
         config: Option<&Bound<'_, PyDict>>,
         definitions: &mut DefinitionsBuilder<CombinedValidator>,
     ) -> PyResult<CombinedValidator> {
-        let schema = schema.get_as_req(intern!(schema.py(), "schema"))?;
-        let validator = Box::new(build_validator(&schema, config, definitions)?);
+        let sub_schema = schema.get_as_req(intern!(schema.py(), "schema"))?;
+        let validator = Box::new(build_validator(&sub_schema, config, definitions)?);
         let name = format!("{}[{}]", Self::EXPECTED_TYPE, validator.get_name());
-        Ok(Self { validator, name }.into())
+        Ok(Self {
+            validator,
+            strict: is_strict(schema, config)?,
+            empty_str_as_none: EmptyStrAsNone::from_py(schema)?,
+            name,
+        }
+        .into())
     }
 }
 
@@ -39,12 +90,20 @@ impl Validator for NullableValidator {
         input: &(impl Input<'py> + ?Sized),
         state: &mut ValidationState<'_, 'py>,
     ) -> ValResult<PyObject> {
-        match input.is_none() {
-            true => Ok(py.None()),
-            false => self.validator.validate(py, input, state),
+        if input.is_none() {
+            Ok(py.None())
+        } else if !state.strict_or(self.strict) && self.empty_str_as_none.matches(input)? {
+            state.floor_exactness(Exactness::Lax);
+            Ok(py.None())
+        } else {
+            self.validator.validate_profiled(py, input, state)
         }
     }
 
+    fn json_schema<'py>(&self, py: Python<'py>, defs: &Bound<'py, PyDict>) -> PyResult<Bound<'py, PyDict>> {
+        any_of_json_schema(py, [self.validator.json_schema(py, defs)?, new_schema(py, "null")?])
+    }
+
     fn get_name(&self) -> &str {
         &self.name
     }
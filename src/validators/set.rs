@@ -1,11 +1,13 @@
+use pyo3::intern;
 use pyo3::prelude::*;
 use pyo3::types::{PyDict, PySet};
 
-use crate::errors::ValResult;
+use crate::errors::{ErrorTypeDefaults, ValResult};
 use crate::input::{validate_iter_to_set, BorrowInput, ConsumeIterator, Input, ValidatedSet};
 use crate::tools::SchemaDict;
 
-use super::list::min_length_check;
+use super::json_schema::{new_schema, set_optional};
+use super::list::{check_exact_type, min_length_check, ContainsCheck};
 use super::{BuildValidator, CombinedValidator, DefinitionsBuilder, ValidationState, Validator};
 
 #[derive(Debug)]
@@ -14,6 +16,8 @@ pub struct SetValidator {
     item_validator: Box<CombinedValidator>,
     min_length: Option<usize>,
     max_length: Option<usize>,
+    contains: Option<ContainsCheck>,
+    allow_subclass: bool,
     name: String,
 }
 
@@ -41,6 +45,10 @@ macro_rules! set_build {
                 item_validator,
                 min_length: schema.get_as(pyo3::intern!(py, "min_length"))?,
                 max_length,
+                contains: crate::validators::list::ContainsCheck::build(schema, config, definitions)?,
+                allow_subclass: schema
+                    .get_as(pyo3::intern!(py, "allow_subclass"))?
+                    .unwrap_or(true),
                 name,
             }
             .into())
@@ -54,7 +62,10 @@ impl BuildValidator for SetValidator {
     set_build!();
 }
 
-impl_py_gc_traverse!(SetValidator { item_validator });
+impl_py_gc_traverse!(SetValidator {
+    item_validator,
+    contains
+});
 
 impl Validator for SetValidator {
     fn validate<'py>(
@@ -63,7 +74,9 @@ impl Validator for SetValidator {
         input: &(impl Input<'py> + ?Sized),
         state: &mut ValidationState<'_, 'py>,
     ) -> ValResult<PyObject> {
-        let collection = input.validate_set(state.strict_or(self.strict))?.unpack(state);
+        let strict = state.strict_or(self.strict);
+        check_exact_type::<PySet>(input, strict, self.allow_subclass, ErrorTypeDefaults::SetType)?;
+        let collection = input.validate_set(strict)?.unpack(state);
         let set = PySet::empty_bound(py)?;
         collection.iterate(ValidateToSet {
             py,
@@ -74,9 +87,24 @@ impl Validator for SetValidator {
             state,
         })??;
         min_length_check!(input, "Set", self.min_length, set);
+        if let Some(contains) = &self.contains {
+            contains.check(py, input, "Set", set.iter(), state)?;
+        }
         Ok(set.into_py(py))
     }
 
+    fn json_schema<'py>(&self, py: Python<'py>, defs: &Bound<'py, PyDict>) -> PyResult<Bound<'py, PyDict>> {
+        let json_schema = new_schema(py, "array")?;
+        json_schema.set_item(intern!(py, "items"), self.item_validator.json_schema(py, defs)?)?;
+        json_schema.set_item(intern!(py, "uniqueItems"), true)?;
+        set_optional(&json_schema, "minItems", self.min_length)?;
+        set_optional(&json_schema, "maxItems", self.max_length)?;
+        if let Some(contains) = &self.contains {
+            contains.json_schema(py, defs, &json_schema)?;
+        }
+        Ok(json_schema)
+    }
+
     fn get_name(&self) -> &str {
         &self.name
     }
@@ -1,18 +1,28 @@
+use std::borrow::Cow;
+
 use pyo3::intern;
 use pyo3::prelude::*;
 use pyo3::types::{PyDict, PyString};
 use regex::Regex;
+use unicode_normalization::{
+    is_nfc_quick, is_nfd_quick, is_nfkc_quick, is_nfkd_quick, IsNormalized, UnicodeNormalization,
+};
 
-use crate::build_tools::{is_strict, py_schema_error_type, schema_or_config, schema_or_config_same};
+use crate::build_tools::{
+    explain_strict, is_strict, py_schema_err, py_schema_error_type, schema_or_config, schema_or_config_same,
+};
 use crate::errors::{ErrorType, ValError, ValResult};
 use crate::input::Input;
 use crate::tools::SchemaDict;
 
+use super::cached::{CachedInput, CachedValidator};
+use super::json_schema::{new_schema, set_optional};
 use super::{BuildValidator, CombinedValidator, DefinitionsBuilder, ValidationState, Validator};
 
 #[derive(Debug)]
 pub struct StrValidator {
     strict: bool,
+    explain_strict: bool,
     coerce_numbers_to_str: bool,
 }
 
@@ -26,15 +36,17 @@ impl BuildValidator for StrValidator {
     ) -> PyResult<CombinedValidator> {
         let con_str_validator = StrConstrainedValidator::build(schema, config)?;
 
-        if con_str_validator.has_constraints_set() {
-            Ok(con_str_validator.into())
+        let validator = if con_str_validator.has_constraints_set() {
+            con_str_validator.into()
         } else {
-            Ok(Self {
+            Self {
                 strict: con_str_validator.strict,
+                explain_strict: con_str_validator.explain_strict,
                 coerce_numbers_to_str: con_str_validator.coerce_numbers_to_str,
             }
-            .into())
-        }
+            .into()
+        };
+        CachedValidator::wrap(schema, validator, CachedInput::Str)
     }
 }
 
@@ -47,11 +59,21 @@ impl Validator for StrValidator {
         input: &(impl Input<'py> + ?Sized),
         state: &mut ValidationState<'_, 'py>,
     ) -> ValResult<PyObject> {
+        let strict = state.strict_or(self.strict);
         input
-            .validate_str(state.strict_or(self.strict), self.coerce_numbers_to_str)
+            .validate_str(strict, self.coerce_numbers_to_str)
+            .map_err(|e| {
+                e.explain_strict(py, Self::EXPECTED_TYPE, strict && self.explain_strict, || {
+                    input.validate_str(false, self.coerce_numbers_to_str).is_ok()
+                })
+            })
             .map(|val_match| val_match.unpack(state).as_py_string(py, state.cache_str()).into_py(py))
     }
 
+    fn json_schema<'py>(&self, py: Python<'py>, _defs: &Bound<'py, PyDict>) -> PyResult<Bound<'py, PyDict>> {
+        new_schema(py, "string")
+    }
+
     fn get_name(&self) -> &str {
         Self::EXPECTED_TYPE
     }
@@ -61,12 +83,14 @@ impl Validator for StrValidator {
 #[derive(Debug, Clone, Default)]
 pub struct StrConstrainedValidator {
     strict: bool,
+    explain_strict: bool,
     pattern: Option<Pattern>,
     max_length: Option<usize>,
     min_length: Option<usize>,
     strip_whitespace: bool,
     to_lower: bool,
     to_upper: bool,
+    unicode_normalize: Option<UnicodeForm>,
     coerce_numbers_to_str: bool,
 }
 
@@ -79,14 +103,39 @@ impl Validator for StrConstrainedValidator {
         input: &(impl Input<'py> + ?Sized),
         state: &mut ValidationState<'_, 'py>,
     ) -> ValResult<PyObject> {
+        let strict = state.strict_or(self.strict);
         let either_str = input
-            .validate_str(state.strict_or(self.strict), self.coerce_numbers_to_str)?
+            .validate_str(strict, self.coerce_numbers_to_str)
+            .map_err(|e| {
+                e.explain_strict(py, StrValidator::EXPECTED_TYPE, strict && self.explain_strict, || {
+                    input.validate_str(false, self.coerce_numbers_to_str).is_ok()
+                })
+            })?
             .unpack(state);
         let cow = either_str.as_cow()?;
+        if let Some(max_length) = self.max_length {
+            // a str has at least one char per 4 bytes of UTF-8, so a huge string (e.g. from JSON) can be rejected
+            // without counting its chars or converting it, unless stripping or normalizing could shorten it
+            if !self.strip_whitespace && self.unicode_normalize.is_none() && cow.len() / 4 > max_length {
+                return Err(ValError::new(
+                    ErrorType::StringTooLong {
+                        max_length,
+                        context: None,
+                    },
+                    input,
+                ));
+            }
+        }
         let mut str = cow.as_ref();
         if self.strip_whitespace {
             str = str.trim();
         }
+        // normalize before checking constraints so lengths and patterns apply to the returned string
+        let normalized;
+        if let Some(form) = self.unicode_normalize {
+            normalized = form.normalize(str);
+            str = normalized.as_ref();
+        }
 
         let str_len: Option<usize> = if self.min_length.is_some() | self.max_length.is_some() {
             Some(str.chars().count())
@@ -132,7 +181,7 @@ impl Validator for StrConstrainedValidator {
             state.maybe_cached_str(py, &str.to_lowercase())
         } else if self.to_upper {
             state.maybe_cached_str(py, &str.to_uppercase())
-        } else if self.strip_whitespace {
+        } else if self.strip_whitespace || self.unicode_normalize.is_some() {
             state.maybe_cached_str(py, str)
         } else {
             // we haven't modified the string, return the original as it might be a PyString
@@ -141,6 +190,14 @@ impl Validator for StrConstrainedValidator {
         Ok(py_string.into_py(py))
     }
 
+    fn json_schema<'py>(&self, py: Python<'py>, _defs: &Bound<'py, PyDict>) -> PyResult<Bound<'py, PyDict>> {
+        let json_schema = new_schema(py, "string")?;
+        set_optional(&json_schema, "minLength", self.min_length)?;
+        set_optional(&json_schema, "maxLength", self.max_length)?;
+        set_optional(&json_schema, "pattern", self.pattern.as_ref().map(|p| &p.pattern))?;
+        Ok(json_schema)
+    }
+
     fn get_name(&self) -> &str {
         "constrained-str"
     }
@@ -184,17 +241,24 @@ impl StrConstrainedValidator {
         let to_upper: bool =
             schema_or_config(schema, config, intern!(py, "to_upper"), intern!(py, "str_to_upper"))?.unwrap_or(false);
 
+        let unicode_normalize = schema
+            .get_as::<Bound<'_, PyString>>(intern!(py, "unicode_normalize"))?
+            .map(|form| UnicodeForm::from_str(form.to_str()?))
+            .transpose()?;
+
         let coerce_numbers_to_str: bool =
             schema_or_config_same(schema, config, intern!(py, "coerce_numbers_to_str"))?.unwrap_or(false);
 
         Ok(Self {
             strict: is_strict(schema, config)?,
+            explain_strict: explain_strict(schema, config)?,
             pattern,
             min_length,
             max_length,
             strip_whitespace,
             to_lower,
             to_upper,
+            unicode_normalize,
             coerce_numbers_to_str,
         })
     }
@@ -208,10 +272,53 @@ impl StrConstrainedValidator {
             || self.strip_whitespace
             || self.to_lower
             || self.to_upper
+            || self.unicode_normalize.is_some()
             || self.coerce_numbers_to_str
     }
 }
 
+#[derive(Debug, Clone, Copy)]
+enum UnicodeForm {
+    Nfc,
+    Nfd,
+    Nfkc,
+    Nfkd,
+}
+
+impl UnicodeForm {
+    fn from_str(s: &str) -> PyResult<Self> {
+        match s {
+            "NFC" => Ok(Self::Nfc),
+            "NFD" => Ok(Self::Nfd),
+            "NFKC" => Ok(Self::Nfkc),
+            "NFKD" => Ok(Self::Nfkd),
+            s => py_schema_err!(
+                "Invalid unicode_normalize form `{}`, expected `NFC`, `NFD`, `NFKC` or `NFKD`",
+                s
+            ),
+        }
+    }
+
+    fn normalize(self, s: &str) -> Cow<'_, str> {
+        // the quick check avoids allocating for strings which are already normalized, e.g. ASCII
+        let is_normalized = match self {
+            Self::Nfc => is_nfc_quick(s.chars()),
+            Self::Nfd => is_nfd_quick(s.chars()),
+            Self::Nfkc => is_nfkc_quick(s.chars()),
+            Self::Nfkd => is_nfkd_quick(s.chars()),
+        };
+        if is_normalized == IsNormalized::Yes {
+            return Cow::Borrowed(s);
+        }
+        match self {
+            Self::Nfc => s.nfc().collect::<String>().into(),
+            Self::Nfd => s.nfd().collect::<String>().into(),
+            Self::Nfkc => s.nfkc().collect::<String>().into(),
+            Self::Nfkd => s.nfkd().collect::<String>().into(),
+        }
+    }
+}
+
 #[derive(Debug, Clone)]
 struct Pattern {
     pattern: String,
@@ -6,11 +6,12 @@ use speedate::Time;
 
 use crate::build_tools::is_strict;
 use crate::errors::{ErrorType, ValError, ValResult};
-use crate::input::{EitherTime, Input};
+use crate::input::{EitherTime, Input, TimeFormat};
 use crate::tools::SchemaDict;
 
 use super::datetime::extract_microseconds_precision;
 use super::datetime::TZConstraint;
+use super::json_schema::string_json_schema;
 use super::{BuildValidator, CombinedValidator, DefinitionsBuilder, ValidationState, Validator};
 
 #[derive(Debug, Clone)]
@@ -18,6 +19,7 @@ pub struct TimeValidator {
     strict: bool,
     constraints: Option<TimeConstraints>,
     microseconds_precision: speedate::MicrosecondsPrecisionOverflowBehavior,
+    time_format: TimeFormat,
 }
 
 impl BuildValidator for TimeValidator {
@@ -32,6 +34,7 @@ impl BuildValidator for TimeValidator {
             strict: is_strict(schema, config)?,
             constraints: TimeConstraints::from_py(schema)?,
             microseconds_precision: extract_microseconds_precision(schema, config)?,
+            time_format: TimeFormat::from_schema(schema)?,
         };
         Ok(s.into())
     }
@@ -47,7 +50,11 @@ impl Validator for TimeValidator {
         state: &mut ValidationState<'_, 'py>,
     ) -> ValResult<PyObject> {
         let time = input
-            .validate_time(state.strict_or(self.strict), self.microseconds_precision)?
+            .validate_time(
+                state.strict_or(self.strict),
+                self.microseconds_precision,
+                self.time_format,
+            )?
             .unpack(state);
         if let Some(constraints) = &self.constraints {
             let raw_time = time.as_raw()?;
@@ -80,6 +87,10 @@ impl Validator for TimeValidator {
         Ok(time.try_into_py(py)?)
     }
 
+    fn json_schema<'py>(&self, py: Python<'py>, _defs: &Bound<'py, PyDict>) -> PyResult<Bound<'py, PyDict>> {
+        string_json_schema(py, "time")
+    }
+
     fn get_name(&self) -> &str {
         Self::EXPECTED_TYPE
     }
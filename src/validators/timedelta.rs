@@ -1,12 +1,15 @@
+use pyo3::intern;
 use pyo3::prelude::*;
-use pyo3::types::{PyDelta, PyDeltaAccess, PyDict};
+use pyo3::types::{PyDelta, PyDeltaAccess, PyDict, PyString};
 use speedate::Duration;
 
-use crate::build_tools::is_strict;
+use crate::build_tools::{is_strict, py_schema_err};
 use crate::errors::{ErrorType, ValError, ValResult};
 use crate::input::{duration_as_pytimedelta, EitherTimedelta, Input};
+use crate::tools::SchemaDict;
 
 use super::datetime::extract_microseconds_precision;
+use super::json_schema::string_json_schema;
 use super::{BuildValidator, CombinedValidator, DefinitionsBuilder, ValidationState, Validator};
 
 #[derive(Debug, Clone)]
@@ -14,6 +17,75 @@ pub struct TimeDeltaValidator {
     strict: bool,
     constraints: Option<TimedeltaConstraints>,
     microseconds_precision: speedate::MicrosecondsPrecisionOverflowBehavior,
+    duration_format: DurationFormat,
+}
+
+/// Which string formats are accepted for durations, speedate's parser also accepts ISO 8601 durations
+/// but converts years and months to 365 and 30 days, the other formats reject those as ambiguous.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum DurationFormat {
+    Speedate,
+    Iso8601,
+    Any,
+}
+
+impl DurationFormat {
+    fn from_py(schema: &Bound<'_, PyDict>) -> PyResult<Self> {
+        match schema.get_as::<Bound<'_, PyString>>(intern!(schema.py(), "duration_format"))? {
+            Some(s) => match s.to_str()? {
+                "speedate" => Ok(Self::Speedate),
+                "iso8601" => Ok(Self::Iso8601),
+                "any" => Ok(Self::Any),
+                s => py_schema_err!("Invalid duration_format {:?}", s),
+            },
+            None => Ok(Self::Speedate),
+        }
+    }
+
+    /// Check the format of string and bytes inputs, the actual parsing is still done by speedate.
+    fn check<'py>(self, input: &(impl Input<'py> + ?Sized)) -> ValResult<()> {
+        if self == Self::Speedate {
+            return Ok(());
+        }
+        let duration_check = if let Ok(either_str) = input.validate_str(true, false) {
+            iso8601_duration_check(&either_str.into_inner().as_cow()?)
+        } else if let Ok(either_bytes) = input.validate_bytes(true) {
+            // bytes which aren't valid UTF-8 are left for speedate to reject
+            match std::str::from_utf8(either_bytes.into_inner().as_slice()) {
+                Ok(s) => iso8601_duration_check(s),
+                Err(_) => return Ok(()),
+            }
+        } else {
+            return Ok(());
+        };
+        let error = match (duration_check, self) {
+            (Ok(true), _) | (Ok(false), Self::Any) => return Ok(()),
+            (Ok(false), _) => "expected an ISO 8601 duration",
+            (Err(error), _) => error,
+        };
+        Err(ValError::new(
+            ErrorType::TimeDeltaParsing {
+                error: error.into(),
+                context: None,
+            },
+            input,
+        ))
+    }
+}
+
+/// `Ok(true)` if `s` looks like an ISO 8601 duration, `Ok(false)` if it doesn't, or an error if it uses
+/// years or months which have no fixed length.
+fn iso8601_duration_check(s: &str) -> Result<bool, &'static str> {
+    let s = s.strip_prefix(['+', '-']).unwrap_or(s);
+    let Some(s) = s.strip_prefix('P') else {
+        return Ok(false);
+    };
+    let date_part = s.split('T').next().unwrap_or_default();
+    if date_part.contains(['Y', 'M']) {
+        Err("years and months are ambiguous in ISO 8601 durations, use weeks or days instead")
+    } else {
+        Ok(true)
+    }
 }
 
 #[derive(Debug, Clone)]
@@ -57,6 +129,7 @@ impl BuildValidator for TimeDeltaValidator {
                 || constraints.gt.is_some())
             .then_some(constraints),
             microseconds_precision: extract_microseconds_precision(schema, config)?,
+            duration_format: DurationFormat::from_py(schema)?,
         }
         .into())
     }
@@ -71,6 +144,7 @@ impl Validator for TimeDeltaValidator {
         input: &(impl Input<'py> + ?Sized),
         state: &mut ValidationState<'_, 'py>,
     ) -> ValResult<PyObject> {
+        self.duration_format.check(input)?;
         let timedelta = input
             .validate_timedelta(state.strict_or(self.strict), self.microseconds_precision)?
             .unpack(state);
@@ -103,6 +177,10 @@ impl Validator for TimeDeltaValidator {
         Ok(py_timedelta.into())
     }
 
+    fn json_schema<'py>(&self, py: Python<'py>, _defs: &Bound<'py, PyDict>) -> PyResult<Bound<'py, PyDict>> {
+        string_json_schema(py, "duration")
+    }
+
     fn get_name(&self) -> &str {
         Self::EXPECTED_TYPE
     }
@@ -1,14 +1,16 @@
 use pyo3::intern;
 use pyo3::prelude::*;
-use pyo3::types::{PyDict, PyList, PyTuple};
+use pyo3::types::{PyDict, PyList, PyTuple, PyType};
 use std::collections::VecDeque;
 
-use crate::build_tools::is_strict;
+use crate::build_tools::{is_strict, py_schema_err};
 use crate::errors::{py_err_string, ErrorType, ErrorTypeDefaults, ValError, ValLineError, ValResult};
 use crate::input::ConsumeIterator;
 use crate::input::{BorrowInput, Input, ValidatedTuple};
 use crate::tools::SchemaDict;
 
+use super::json_schema::{new_schema, set_optional};
+use super::list::check_exact_type;
 use super::{build_validator, BuildValidator, CombinedValidator, DefinitionsBuilder, ValidationState, Validator};
 
 #[derive(Debug)]
@@ -18,6 +20,8 @@ pub struct TupleValidator {
     variadic_item_index: Option<usize>,
     min_length: Option<usize>,
     max_length: Option<usize>,
+    namedtuple_cls: Option<Py<PyType>>,
+    allow_subclass: bool,
     name: String,
 }
 
@@ -43,19 +47,39 @@ impl BuildValidator for TupleValidator {
         }
         let name = format!("tuple[{}]", validator_names.join(", "));
 
+        let namedtuple_cls: Option<Bound<'_, PyType>> = schema.get_as(intern!(py, "namedtuple_cls"))?;
+        if let Some(ref cls) = namedtuple_cls {
+            if variadic_item_index.is_some() {
+                return py_schema_err!("`namedtuple_cls` can only be used with tuples without a variadic item");
+            }
+            let fields_count = cls.getattr(intern!(py, "_fields"))?.len()?;
+            if fields_count != validators.len() {
+                return py_schema_err!(
+                    "`namedtuple_cls` has {} fields but `items_schema` has {} items",
+                    fields_count,
+                    validators.len()
+                );
+            }
+        }
+
         Ok(Self {
             strict: is_strict(schema, config)?,
             validators,
             variadic_item_index,
             min_length: schema.get_as(intern!(py, "min_length"))?,
             max_length: schema.get_as(intern!(py, "max_length"))?,
+            namedtuple_cls: namedtuple_cls.map(Bound::unbind),
+            allow_subclass: schema.get_as(intern!(py, "allow_subclass"))?.unwrap_or(true),
             name,
         }
         .into())
     }
 }
 
-impl_py_gc_traverse!(TupleValidator { validators });
+impl_py_gc_traverse!(TupleValidator {
+    validators,
+    namedtuple_cls
+});
 
 impl TupleValidator {
     #[allow(clippy::too_many_arguments)]
@@ -73,14 +97,18 @@ impl TupleValidator {
         // Validate the head:
         for validator in item_validators {
             match collection_iter.next() {
-                Some((index, input_item)) => match validator.validate(py, input_item.borrow_input(), state) {
-                    Ok(item) => self.push_output_item(input, output, item, actual_length)?,
-                    Err(ValError::LineErrors(line_errors)) => {
-                        errors.extend(line_errors.into_iter().map(|err| err.with_outer_location(index)));
+                Some((index, input_item)) => {
+                    let start = state.collected_count();
+                    let result = validator.validate_profiled(py, input_item.borrow_input(), state);
+                    match state.collect_item(py, result, start, |err| err.with_outer_location(index)) {
+                        Ok(item) => self.push_output_item(input, output, item, actual_length)?,
+                        Err(ValError::LineErrors(line_errors)) => {
+                            errors.extend(line_errors.into_iter().map(|err| err.with_outer_location(index)));
+                        }
+                        Err(ValError::Omit) => (),
+                        Err(err) => return Err(err),
                     }
-                    Err(ValError::Omit) => (),
-                    Err(err) => return Err(err),
-                },
+                }
                 None => {
                     let index = collection_iter.next_calls() - 1;
                     if let Some(value) = validator.default_value(py, Some(index), state)? {
@@ -133,10 +161,15 @@ impl TupleValidator {
             let n_tail_validators = tail_validators.len();
             if n_tail_validators == 0 {
                 for (index, input_item) in collection_iter {
-                    match variable_validator.validate(py, input_item.borrow_input(), state) {
+                    let start = state.collected_count();
+                    let result = variable_validator.validate_profiled(py, input_item.borrow_input(), state);
+                    match state.collect_item(py, result, start, |err| err.with_outer_location(index)) {
                         Ok(item) => self.push_output_item(input, &mut output, item, actual_length)?,
                         Err(ValError::LineErrors(line_errors)) => {
-                            errors.extend(line_errors.into_iter().map(|err| err.with_outer_location(index)));
+                            state.collect_errors(
+                                errors,
+                                line_errors.into_iter().map(|err| err.with_outer_location(index)),
+                            );
                         }
                         Err(ValError::Omit) => (),
                         Err(err) => return Err(err),
@@ -160,10 +193,14 @@ impl TupleValidator {
                     let buffered_item = tail_buffer.pop_front().unwrap();
                     tail_buffer.push_back(input_item);
 
-                    match variable_validator.validate(py, buffered_item.borrow_input(), state) {
+                    let start = state.collected_count();
+                    let result = variable_validator.validate_profiled(py, buffered_item.borrow_input(), state);
+                    let locate = |err: ValLineError| err.with_outer_location(buffer_item_index);
+                    match state.collect_item(py, result, start, locate) {
                         Ok(item) => self.push_output_item(input, &mut output, item, actual_length)?,
                         Err(ValError::LineErrors(line_errors)) => {
-                            errors.extend(
+                            state.collect_errors(
+                                errors,
                                 line_errors
                                     .into_iter()
                                     .map(|err| err.with_outer_location(buffer_item_index)),
@@ -247,7 +284,9 @@ impl Validator for TupleValidator {
         input: &(impl Input<'py> + ?Sized),
         state: &mut ValidationState<'_, 'py>,
     ) -> ValResult<PyObject> {
-        let collection = input.validate_tuple(state.strict_or(self.strict))?.unpack(state);
+        let strict = state.strict_or(self.strict);
+        check_exact_type::<PyTuple>(input, strict, self.allow_subclass, ErrorTypeDefaults::TupleType)?;
+        let collection = input.validate_tuple(strict)?.unpack(state);
         let actual_length = collection.len();
 
         let mut errors: Vec<ValLineError> = Vec::new();
@@ -277,12 +316,42 @@ impl Validator for TupleValidator {
         }
 
         if errors.is_empty() {
-            Ok(PyTuple::new_bound(py, output).into_py(py))
+            let output = PyTuple::new_bound(py, output);
+            match self.namedtuple_cls {
+                Some(ref cls) => Ok(cls.bind(py).call1(output)?.unbind()),
+                None => Ok(output.into_py(py)),
+            }
         } else {
             Err(ValError::LineErrors(errors))
         }
     }
 
+    fn json_schema<'py>(&self, py: Python<'py>, defs: &Bound<'py, PyDict>) -> PyResult<Bound<'py, PyDict>> {
+        let json_schema = new_schema(py, "array")?;
+        let to_json_schemas = |validators: &[CombinedValidator]| {
+            validators
+                .iter()
+                .map(|v| v.json_schema(py, defs))
+                .collect::<PyResult<Vec<_>>>()
+        };
+        // items after the variadic item can't be described in JSON Schema so are omitted
+        let (prefix_validators, items) = match self.variadic_item_index {
+            Some(index) => (
+                &self.validators[..index],
+                self.validators[index].json_schema(py, defs)?.into_any(),
+            ),
+            None => (&self.validators[..], false.to_object(py).into_bound(py)),
+        };
+        if !prefix_validators.is_empty() {
+            json_schema.set_item(intern!(py, "prefixItems"), to_json_schemas(prefix_validators)?)?;
+        }
+        json_schema.set_item(intern!(py, "items"), items)?;
+        let fixed_length = self.variadic_item_index.is_none().then_some(self.validators.len());
+        set_optional(&json_schema, "minItems", self.min_length.or(fixed_length))?;
+        set_optional(&json_schema, "maxItems", self.max_length.or(fixed_length))?;
+        Ok(json_schema)
+    }
+
     fn get_name(&self) -> &str {
         &self.name
     }
@@ -12,9 +12,11 @@ use crate::input::BorrowInput;
 use crate::input::ConsumeIterator;
 use crate::input::ValidationMatch;
 use crate::input::{Input, ValidatedDict};
-use crate::lookup_key::LookupKey;
+use crate::lookup_key::{CaseCollision, CaseInsensitiveKeys, LookupKey};
 use crate::tools::SchemaDict;
 
+use super::json_schema::object_json_schema;
+use super::model_fields::{deprecated_message, warn_deprecated};
 use super::{build_validator, BuildValidator, CombinedValidator, DefinitionsBuilder, ValidationState, Validator};
 
 #[derive(Debug)]
@@ -24,6 +26,7 @@ struct TypedDictField {
     name_py: Py<PyString>,
     required: bool,
     validator: CombinedValidator,
+    deprecated: Option<String>,
 }
 
 impl_py_gc_traverse!(TypedDictField { validator });
@@ -35,6 +38,7 @@ pub struct TypedDictValidator {
     extras_validator: Option<Box<CombinedValidator>>,
     strict: bool,
     loc_by_alias: bool,
+    case_collision: Option<CaseCollision>,
 }
 
 impl BuildValidator for TypedDictValidator {
@@ -116,12 +120,14 @@ impl BuildValidator for TypedDictValidator {
                 None => LookupKey::from_string(py, field_name),
             };
 
+            let deprecated = deprecated_message(field_info, field_name)?;
             fields.push(TypedDictField {
                 name: field_name.to_string(),
                 lookup_key,
                 name_py: field_name_py.into(),
                 validator,
                 required,
+                deprecated,
             });
         }
 
@@ -131,6 +137,7 @@ impl BuildValidator for TypedDictValidator {
             extras_validator,
             strict,
             loc_by_alias: config.get_as(intern!(py, "loc_by_alias"))?.unwrap_or(true),
+            case_collision: CaseCollision::from_schema(schema)?,
         }
         .into())
     }
@@ -153,6 +160,12 @@ impl Validator for TypedDictValidator {
 
         let output_dict = PyDict::new_bound(py);
         let mut errors: Vec<ValLineError> = Vec::with_capacity(self.fields.len());
+        let matched_aliases = state.matched_aliases.is_some().then(|| PyDict::new_bound(py));
+
+        let case_insensitive_keys = match self.case_collision {
+            Some(collision) if !dict.is_py_get_attr() => Some(CaseInsensitiveKeys::new(&dict, collision)?),
+            _ => None,
+        };
 
         // we only care about which keys have been used if we're iterating over the object for extra after
         // the first pass
@@ -167,7 +180,11 @@ impl Validator for TypedDictValidator {
             let state = &mut state.rebind_extra(|extra| extra.data = Some(output_dict.clone()));
 
             for field in &self.fields {
-                let op_key_value = match dict.get_item(&field.lookup_key) {
+                let op_key_value = match case_insensitive_keys {
+                    Some(ref keys) => keys.get_item(py, &dict, &field.lookup_key, input),
+                    None => dict.get_item(&field.lookup_key),
+                };
+                let op_key_value = match op_key_value {
                     Ok(v) => v,
                     Err(ValError::LineErrors(line_errors)) => {
                         for err in line_errors {
@@ -182,10 +199,26 @@ impl Validator for TypedDictValidator {
                         // key is "used" whether or not validation passes, since we want to skip this key in
                         // extra logic either way
                         used_keys.insert(lookup_path.first_key());
+                        if let Some(ref keys) = case_insensitive_keys {
+                            used_keys.extend(keys.variants(lookup_path.first_key()));
+                        }
                     }
-                    match field.validator.validate(py, value.borrow_input(), state) {
+                    if let Some(ref message) = field.deprecated {
+                        warn_deprecated(py, message)?;
+                    }
+                    let start = state.collected_count();
+                    let result = field.validator.validate_profiled(py, value.borrow_input(), state);
+                    let locate = |err| lookup_path.apply_error_loc(err, self.loc_by_alias, &field.name);
+                    match state.collect_item(py, result, start, locate) {
                         Ok(value) => {
                             output_dict.set_item(&field.name_py, value)?;
+                            if let Some(ref matched_aliases) = matched_aliases {
+                                let alias = match case_insensitive_keys {
+                                    Some(ref keys) => keys.alias_py(py, lookup_path),
+                                    None => lookup_path.alias_py(py),
+                                };
+                                matched_aliases.set_item(&field.name_py, alias)?;
+                            }
                         }
                         Err(ValError::Omit) => continue,
                         Err(ValError::LineErrors(line_errors)) => {
@@ -198,20 +231,31 @@ impl Validator for TypedDictValidator {
                     continue;
                 }
 
-                match field.validator.default_value(py, Some(field.name.as_str()), state) {
+                let default = if state.extra().require_all_fields {
+                    Ok(None)
+                } else {
+                    field.validator.default_value(py, Some(field.name.as_str()), state)
+                };
+                match default {
                     Ok(Some(value)) => {
                         // Default value exists, and passed validation if required
                         output_dict.set_item(&field.name_py, value)?;
+                        if let Some(ref matched_aliases) = matched_aliases {
+                            matched_aliases.set_item(&field.name_py, py.None())?;
+                        }
                     }
                     Ok(None) => {
-                        // This means there was no default value
-                        if field.required {
-                            errors.push(field.lookup_key.error(
+                        // This means there was no default value, or `require_all_fields` disabled it
+                        if field.required || state.extra().require_all_fields {
+                            let error = field.lookup_key.error(
                                 ErrorTypeDefaults::Missing,
                                 input,
                                 self.loc_by_alias,
                                 &field.name,
-                            ));
+                            );
+                            if let Some(none) = state.collect_missing(py, error, &mut errors) {
+                                output_dict.set_item(&field.name_py, none)?;
+                            }
                         }
                     }
                     Err(ValError::Omit) => continue,
@@ -285,7 +329,7 @@ impl Validator for TypedDictValidator {
                             ExtraBehavior::Allow => {
                                 let py_key = either_str.as_py_string(self.py, self.state.cache_str());
                                 if let Some(validator) = self.extras_validator {
-                                    match validator.validate(self.py, value, self.state) {
+                                    match validator.validate_profiled(self.py, value, self.state) {
                                         Ok(value) => {
                                             self.output_dict.set_item(py_key, value)?;
                                         }
@@ -321,10 +365,18 @@ impl Validator for TypedDictValidator {
         if !errors.is_empty() {
             Err(ValError::LineErrors(errors))
         } else {
+            if let Some(matched_aliases) = matched_aliases {
+                state.record_matched_aliases(matched_aliases);
+            }
             Ok(output_dict.to_object(py))
         }
     }
 
+    fn json_schema<'py>(&self, py: Python<'py>, defs: &Bound<'py, PyDict>) -> PyResult<Bound<'py, PyDict>> {
+        let fields = self.fields.iter().map(|f| (&f.name_py, &f.validator, f.required));
+        object_json_schema(py, defs, fields, self.extra_behavior, self.extras_validator.as_deref())
+    }
+
     fn get_name(&self) -> &str {
         Self::EXPECTED_TYPE
     }
@@ -7,14 +7,15 @@ use pyo3::{intern, PyTraverseError, PyVisit};
 use smallvec::SmallVec;
 
 use crate::build_tools::py_schema_err;
-use crate::build_tools::{is_strict, schema_or_config};
+use crate::build_tools::{is_strict, schema_or_config, schema_or_config_same};
 use crate::errors::{ErrorType, ToErrorValue, ValError, ValLineError, ValResult};
 use crate::input::{BorrowInput, Input, ValidatedDict};
-use crate::lookup_key::LookupKey;
+use crate::lookup_key::{AttributeAccess, LookupKey};
 use crate::py_gc::PyGcTraverse;
 use crate::tools::SchemaDict;
 
 use super::custom_error::CustomError;
+use super::json_schema::any_of_json_schema;
 use super::literal::LiteralLookup;
 use super::{
     build_validator, BuildValidator, CombinedValidator, DefinitionsBuilder, Exactness, ValidationState, Validator,
@@ -101,26 +102,28 @@ impl BuildValidator for UnionValidator {
 }
 
 impl UnionValidator {
+    /// Returns the output along with the index of the choice which matched.
     fn validate_smart<'py>(
         &self,
         py: Python<'py>,
         input: &(impl Input<'py> + ?Sized),
         state: &mut ValidationState<'_, 'py>,
-    ) -> ValResult<PyObject> {
+    ) -> ValResult<(PyObject, usize)> {
         let old_exactness = state.exactness;
+        let old_omitted_errors = state.omitted_errors;
         let strict = state.strict_or(self.strict);
         let mut errors = MaybeErrors::new(self.custom_error.as_ref());
 
         let mut success = None;
 
-        for (choice, label) in &self.choices {
+        for (index, (choice, label)) in self.choices.iter().enumerate() {
             let state = &mut state.rebind_extra(|extra| {
                 if strict {
                     extra.strict = Some(strict);
                 }
             });
             state.exactness = Some(Exactness::Exact);
-            let result = choice.validate(py, input, state);
+            let result = choice.validate_profiled(py, input, state);
             match result {
                 Ok(new_success) => match state.exactness {
                     // exact match, return
@@ -128,7 +131,8 @@ impl UnionValidator {
                         return {
                             // exact match, return, restore any previous exactness
                             state.exactness = old_exactness;
-                            Ok(new_success)
+                            state.omitted_errors = old_omitted_errors;
+                            Ok((new_success, index))
                         };
                     }
                     _ => {
@@ -138,12 +142,12 @@ impl UnionValidator {
                         // if the new result has higher exactness than the current success, replace it
                         if success
                             .as_ref()
-                            .map_or(true, |(_, current_exactness)| *current_exactness < new_exactness)
+                            .map_or(true, |(_, _, current_exactness)| *current_exactness < new_exactness)
                         {
                             // TODO: is there a possible optimization here, where once there has
                             // been one success, we turn on strict mode, to avoid unnecessary
                             // coercions for further validation?
-                            success = Some((new_success, new_exactness));
+                            success = Some((new_success, index, new_exactness));
                         }
                     }
                 },
@@ -153,27 +157,31 @@ impl UnionValidator {
                         errors.push(choice, label.as_deref(), lines);
                     }
                 }
-                otherwise => return otherwise,
+                Err(err) => return Err(err),
             }
         }
         state.exactness = old_exactness;
 
-        if let Some((success, exactness)) = success {
+        if let Some((success, index, exactness)) = success {
             state.floor_exactness(exactness);
-            return Ok(success);
+            // errors omitted from failed choices are irrelevant once one choice succeeds
+            state.omitted_errors = old_omitted_errors;
+            return Ok((success, index));
         }
 
         // no matches, build errors
-        Err(errors.into_val_error(input))
+        Err(errors.into_val_error(input, state))
     }
 
+    /// Returns the output along with the index of the choice which matched.
     fn validate_left_to_right<'py>(
         &self,
         py: Python<'py>,
         input: &(impl Input<'py> + ?Sized),
         state: &mut ValidationState<'_, 'py>,
-    ) -> ValResult<PyObject> {
+    ) -> ValResult<(PyObject, usize)> {
         let mut errors = MaybeErrors::new(self.custom_error.as_ref());
+        let old_omitted_errors = state.omitted_errors;
 
         let mut rebound_state;
         let state = if state.strict_or(self.strict) {
@@ -183,14 +191,18 @@ impl UnionValidator {
             state
         };
 
-        for (validator, label) in &self.choices {
-            match validator.validate(py, input, state) {
+        for (index, (validator, label)) in self.choices.iter().enumerate() {
+            match validator.validate_profiled(py, input, state) {
                 Err(ValError::LineErrors(lines)) => errors.push(validator, label.as_deref(), lines),
-                otherwise => return otherwise,
+                Ok(output) => {
+                    state.omitted_errors = old_omitted_errors;
+                    return Ok((output, index));
+                }
+                Err(err) => return Err(err),
             };
         }
 
-        Err(errors.into_val_error(input))
+        Err(errors.into_val_error(input, state))
     }
 }
 
@@ -208,10 +220,22 @@ impl Validator for UnionValidator {
         input: &(impl Input<'py> + ?Sized),
         state: &mut ValidationState<'_, 'py>,
     ) -> ValResult<PyObject> {
-        match self.mode {
+        // choices must fail normally to be rejected, so errors are never collected within a union
+        let (output, index) = state.without_collecting_errors(|state| match self.mode {
             UnionMode::Smart => self.validate_smart(py, input, state),
             UnionMode::LeftToRight => self.validate_left_to_right(py, input, state),
-        }
+        })?;
+        state.record_union_variant(|| index.into_py(py));
+        Ok(output)
+    }
+
+    fn json_schema<'py>(&self, py: Python<'py>, defs: &Bound<'py, PyDict>) -> PyResult<Bound<'py, PyDict>> {
+        let choices = self
+            .choices
+            .iter()
+            .map(|(choice, _)| choice.json_schema(py, defs))
+            .collect::<PyResult<Vec<_>>>()?;
+        any_of_json_schema(py, choices)
     }
 
     fn get_name(&self) -> &str {
@@ -249,13 +273,14 @@ impl<'a> MaybeErrors<'a> {
         }
     }
 
-    fn into_val_error(self, input: impl ToErrorValue) -> ValError {
+    fn into_val_error(self, input: impl ToErrorValue, state: &mut ValidationState) -> ValError {
         match self {
             Self::Custom(custom_error) => custom_error.as_val_error(input),
-            Self::Errors(errors) => ValError::LineErrors(
-                errors
-                    .into_iter()
-                    .flat_map(
+            Self::Errors(errors) => {
+                let mut val_line_errors = Vec::new();
+                state.collect_errors(
+                    &mut val_line_errors,
+                    errors.into_iter().flat_map(
                         |ChoiceLineErrors {
                              choice,
                              label,
@@ -266,9 +291,10 @@ impl<'a> MaybeErrors<'a> {
                                 err.with_outer_location(case_label)
                             })
                         },
-                    )
-                    .collect(),
-            ),
+                    ),
+                );
+                ValError::LineErrors(val_line_errors)
+            }
         }
     }
 }
@@ -320,7 +346,9 @@ impl PyGcTraverse for Discriminator {
 pub struct TaggedUnionValidator {
     discriminator: Discriminator,
     lookup: LiteralLookup<CombinedValidator>,
+    fallback_tag: Option<PyObject>,
     from_attributes: bool,
+    attribute_access: AttributeAccess,
     strict: bool,
     custom_error: Option<CustomError>,
     tags_repr: String,
@@ -363,10 +391,21 @@ impl BuildValidator for TaggedUnionValidator {
             lookup_map.push((choice_key, validator));
         }
 
-        let lookup = LiteralLookup::new(py, lookup_map.into_iter())?;
+        let lookup = LiteralLookup::new(py, lookup_map.into_iter(), true)?;
+
+        let fallback_tag: Option<Bound<'_, PyAny>> = schema.get_as(intern!(py, "fallback_tag"))?;
+        if let Some(ref fallback_tag) = fallback_tag {
+            if !matches!(lookup.validate(py, fallback_tag), Ok(Some(_))) {
+                return py_schema_err!("`fallback_tag` {} is not one of the choices", fallback_tag.repr()?);
+            }
+        }
 
         let key = intern!(py, "from_attributes");
         let from_attributes = schema_or_config(schema, config, key, key)?.unwrap_or(true);
+        let attribute_access = match schema_or_config_same(schema, config, intern!(py, "from_attributes_safe"))? {
+            Some(true) => AttributeAccess::Safe,
+            _ => AttributeAccess::GetAttr,
+        };
 
         let descr = match discriminator {
             Discriminator::SelfSchema => "self-schema".to_string(),
@@ -376,7 +415,9 @@ impl BuildValidator for TaggedUnionValidator {
         Ok(Self {
             discriminator,
             lookup,
+            fallback_tag: fallback_tag.map(Bound::unbind),
             from_attributes,
+            attribute_access,
             strict: is_strict(schema, config)?,
             custom_error: CustomError::build(schema, config, definitions)?,
             tags_repr,
@@ -387,7 +428,11 @@ impl BuildValidator for TaggedUnionValidator {
     }
 }
 
-impl_py_gc_traverse!(TaggedUnionValidator { discriminator, lookup });
+impl_py_gc_traverse!(TaggedUnionValidator {
+    discriminator,
+    lookup,
+    fallback_tag
+});
 
 impl Validator for TaggedUnionValidator {
     fn validate<'py>(
@@ -399,7 +444,10 @@ impl Validator for TaggedUnionValidator {
         match &self.discriminator {
             Discriminator::LookupKey(lookup_key) => {
                 let from_attributes = state.extra().from_attributes.unwrap_or(self.from_attributes);
-                let dict = input.validate_model_fields(self.strict, from_attributes)?;
+                let dict = input.validate_model_fields(
+                    state.strict_or(self.strict),
+                    from_attributes.then_some(self.attribute_access),
+                )?;
                 // note this methods returns PyResult<Option<(data, data)>>, the outer Err is just for
                 // errors when getting attributes which should be "raised"
                 let tag = match dict.get_item(lookup_key)? {
@@ -422,6 +470,16 @@ impl Validator for TaggedUnionValidator {
         }
     }
 
+    fn json_schema<'py>(&self, py: Python<'py>, defs: &Bound<'py, PyDict>) -> PyResult<Bound<'py, PyDict>> {
+        let choices = self
+            .lookup
+            .values
+            .iter()
+            .map(|choice| choice.json_schema(py, defs))
+            .collect::<PyResult<Vec<_>>>()?;
+        any_of_json_schema(py, choices)
+    }
+
     fn get_name(&self) -> &str {
         &self.name
     }
@@ -465,10 +523,13 @@ impl TaggedUnionValidator {
         state: &mut ValidationState<'_, 'py>,
     ) -> ValResult<PyObject> {
         if let Ok(Some((tag, validator))) = self.lookup.validate(py, tag) {
-            return match validator.validate(py, input, state) {
-                Ok(res) => Ok(res),
-                Err(err) => Err(err.with_outer_location(tag)),
-            };
+            return self.call_validator(py, tag, validator, input, state);
+        }
+        // unknown tags are validated by the fallback choice if there is one
+        if let Some(ref fallback_tag) = self.fallback_tag {
+            if let Ok(Some((tag, validator))) = self.lookup.validate(py, fallback_tag.bind(py)) {
+                return self.call_validator(py, tag, validator, input, state);
+            }
         }
         match self.custom_error {
             Some(ref custom_error) => Err(custom_error.as_val_error(input)),
@@ -484,6 +545,23 @@ impl TaggedUnionValidator {
         }
     }
 
+    fn call_validator<'py>(
+        &self,
+        py: Python<'py>,
+        tag: &Bound<'py, PyAny>,
+        validator: &CombinedValidator,
+        input: &(impl Input<'py> + ?Sized),
+        state: &mut ValidationState<'_, 'py>,
+    ) -> ValResult<PyObject> {
+        match validator.validate_profiled(py, input, state) {
+            Ok(res) => {
+                state.record_union_variant(|| tag.to_object(py));
+                Ok(res)
+            }
+            Err(err) => Err(err.with_outer_location(tag)),
+        }
+    }
+
     fn tag_not_found<'py>(&self, input: &(impl Input<'py> + ?Sized)) -> ValError {
         match self.custom_error {
             Some(ref custom_error) => custom_error.as_val_error(input),
@@ -17,6 +17,7 @@ use crate::input::Input;
 use crate::tools::SchemaDict;
 use crate::url::{schema_is_special, PyMultiHostUrl, PyUrl};
 
+use super::json_schema::{set_optional, string_json_schema};
 use super::literal::expected_repr_name;
 use super::Exactness;
 use super::{BuildValidator, CombinedValidator, DefinitionsBuilder, ValidationState, Validator};
@@ -32,6 +33,9 @@ pub struct UrlValidator {
     default_host: Option<String>,
     default_port: Option<u16>,
     default_path: Option<String>,
+    strip_default_port: Option<u16>,
+    lowercase_host: bool,
+    strip_trailing_slash: bool,
     name: String,
 }
 
@@ -52,6 +56,11 @@ impl BuildValidator for UrlValidator {
             default_host: schema.get_as(intern!(schema.py(), "default_host"))?,
             default_port: schema.get_as(intern!(schema.py(), "default_port"))?,
             default_path: schema.get_as(intern!(schema.py(), "default_path"))?,
+            strip_default_port: schema.get_as(intern!(schema.py(), "strip_default_port"))?,
+            lowercase_host: schema.get_as(intern!(schema.py(), "lowercase_host"))?.unwrap_or(false),
+            strip_trailing_slash: schema
+                .get_as(intern!(schema.py(), "strip_trailing_slash"))?
+                .unwrap_or(false),
             allowed_schemes,
             name,
         }
@@ -89,7 +98,9 @@ impl Validator for UrlValidator {
             &self.default_host,
             self.default_port,
             &self.default_path,
-        ) {
+        )
+        .and_then(|()| self.normalize(&mut either_url))
+        {
             Ok(()) => {
                 // Lax rather than strict to preserve V2.4 semantic that str wins over url in union
                 state.floor_exactness(Exactness::Lax);
@@ -99,12 +110,49 @@ impl Validator for UrlValidator {
         }
     }
 
+    fn json_schema<'py>(&self, py: Python<'py>, _defs: &Bound<'py, PyDict>) -> PyResult<Bound<'py, PyDict>> {
+        let json_schema = string_json_schema(py, "uri")?;
+        json_schema.set_item(intern!(py, "minLength"), 1)?;
+        set_optional(&json_schema, "maxLength", self.max_length)?;
+        Ok(json_schema)
+    }
+
     fn get_name(&self) -> &str {
         &self.name
     }
 }
 
 impl UrlValidator {
+    /// apply `strip_default_port`, `lowercase_host` & `strip_trailing_slash`, the url is only copied if it changes
+    fn normalize(&self, url: &mut impl CopyFromPyUrl) -> Result<(), ErrorType> {
+        if let Some(strip_default_port) = self.strip_default_port {
+            // the url crate already removes the default port of special schemes like `http` when parsing
+            if url.url().port() == Some(strip_default_port) {
+                url.url_mut()
+                    .set_port(None)
+                    .map_err(|()| map_parse_err(ParseError::EmptyHost))?;
+            }
+        }
+        if self.lowercase_host {
+            // hosts of special schemes are always lowercase already
+            if let Some(host) = url.url().host_str().filter(|host| host.chars().any(char::is_uppercase)) {
+                let host = host.to_lowercase();
+                url.url_mut().set_host(Some(&host)).map_err(map_parse_err)?;
+            }
+        }
+        if self.strip_trailing_slash {
+            let path = url.url().path();
+            if path.len() > 1 && path.ends_with('/') {
+                let path = match path.trim_end_matches('/') {
+                    "" => "/".to_string(),
+                    trimmed => trimmed.to_string(),
+                };
+                url.url_mut().set_path(&path);
+            }
+        }
+        Ok(())
+    }
+
     fn get_url<'py>(&self, input: &(impl Input<'py> + ?Sized), strict: bool) -> ValResult<EitherUrl<'py>> {
         match input.validate_str(strict, false) {
             Ok(val_match) => {
@@ -192,6 +240,8 @@ pub struct MultiHostUrlValidator {
     default_host: Option<String>,
     default_port: Option<u16>,
     default_path: Option<String>,
+    min_hosts: Option<usize>,
+    max_hosts: Option<usize>,
     name: String,
 }
 
@@ -219,6 +269,8 @@ impl BuildValidator for MultiHostUrlValidator {
             default_host,
             default_port: schema.get_as(intern!(schema.py(), "default_port"))?,
             default_path: schema.get_as(intern!(schema.py(), "default_path"))?,
+            min_hosts: schema.get_as(intern!(schema.py(), "min_hosts"))?,
+            max_hosts: schema.get_as(intern!(schema.py(), "max_hosts"))?,
             name,
         }
         .into())
@@ -254,7 +306,9 @@ impl Validator for MultiHostUrlValidator {
             &self.default_host,
             self.default_port,
             &self.default_path,
-        ) {
+        )
+        .and_then(|()| self.check_host_count(&multi_url))
+        {
             Ok(()) => {
                 // Lax rather than strict to preserve V2.4 semantic that str wins over url in union
                 state.floor_exactness(Exactness::Lax);
@@ -264,12 +318,42 @@ impl Validator for MultiHostUrlValidator {
         }
     }
 
+    fn json_schema<'py>(&self, py: Python<'py>, _defs: &Bound<'py, PyDict>) -> PyResult<Bound<'py, PyDict>> {
+        let json_schema = string_json_schema(py, "multi-host-uri")?;
+        json_schema.set_item(intern!(py, "minLength"), 1)?;
+        set_optional(&json_schema, "maxLength", self.max_length)?;
+        Ok(json_schema)
+    }
+
     fn get_name(&self) -> &str {
         &self.name
     }
 }
 
 impl MultiHostUrlValidator {
+    fn check_host_count(&self, multi_url: &EitherMultiHostUrl) -> Result<(), ErrorType> {
+        let actual_hosts = multi_url.host_count();
+        if let Some(min_hosts) = self.min_hosts {
+            if actual_hosts < min_hosts {
+                return Err(ErrorType::UrlTooFewHosts {
+                    min_hosts,
+                    actual_hosts,
+                    context: None,
+                });
+            }
+        }
+        if let Some(max_hosts) = self.max_hosts {
+            if actual_hosts > max_hosts {
+                return Err(ErrorType::UrlTooManyHosts {
+                    max_hosts,
+                    actual_hosts,
+                    context: None,
+                });
+            }
+        }
+        Ok(())
+    }
+
     fn get_url<'py>(&self, input: &(impl Input<'py> + ?Sized), strict: bool) -> ValResult<EitherMultiHostUrl<'py>> {
         match input.validate_str(strict, false) {
             Ok(val_match) => {
@@ -331,6 +415,13 @@ impl EitherMultiHostUrl<'_> {
             EitherMultiHostUrl::Rust(rust_multi_url) => rust_multi_url.into_py(py),
         }
     }
+
+    fn host_count(&self) -> usize {
+        match self {
+            EitherMultiHostUrl::Py(py_multi_url) => py_multi_url.get().host_count(),
+            EitherMultiHostUrl::Rust(rust_multi_url) => rust_multi_url.host_count(),
+        }
+    }
 }
 
 impl CopyFromPyUrl for EitherMultiHostUrl<'_> {
@@ -540,10 +631,6 @@ fn check_sub_defaults(
     default_port: Option<u16>,
     default_path: &Option<String>,
 ) -> Result<(), ErrorType> {
-    let map_parse_err = |e: ParseError| ErrorType::UrlParsing {
-        error: e.to_string(),
-        context: None,
-    };
     if let Some(ref default_host) = default_host {
         if !url.url().has_host() {
             url.url_mut().set_host(Some(default_host)).map_err(map_parse_err)?;
@@ -570,6 +657,13 @@ fn check_sub_defaults(
     Ok(())
 }
 
+fn map_parse_err(e: ParseError) -> ErrorType {
+    ErrorType::UrlParsing {
+        error: e.to_string(),
+        context: None,
+    }
+}
+
 /// Abstraction to create a new Url only when necessary if the existing Url is a PyUrl
 /// and needs to be updated with new defaults
 trait CopyFromPyUrl {
@@ -15,6 +15,7 @@ use crate::input::InputType;
 use crate::input::ValidationMatch;
 use crate::tools::SchemaDict;
 
+use super::json_schema::string_json_schema;
 use super::model::create_class;
 use super::model::force_setattr;
 use super::{BuildValidator, CombinedValidator, DefinitionsBuilder, Exactness, ValidationState, Validator};
@@ -146,6 +147,10 @@ impl Validator for UuidValidator {
         }
     }
 
+    fn json_schema<'py>(&self, py: Python<'py>, _defs: &Bound<'py, PyDict>) -> PyResult<Bound<'py, PyDict>> {
+        string_json_schema(py, "uuid")
+    }
+
     fn get_name(&self) -> &str {
         Self::EXPECTED_TYPE
     }
@@ -1,11 +1,17 @@
+use std::time::Duration;
+
+use ahash::AHashMap;
+use pyo3::intern;
 use pyo3::prelude::*;
-use pyo3::types::PyString;
+use pyo3::types::{PyDict, PyString};
 
 use jiter::StringCacheMode;
 
+use crate::errors::{ErrorType, ToErrorValue, ValError, ValLineError, ValResult};
 use crate::recursion_guard::{ContainsRecursionState, RecursionState};
 use crate::tools::new_py_string;
 
+use super::json_raw::JsonSource;
 use super::Extra;
 
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Ord, PartialOrd, Hash)]
@@ -15,9 +21,96 @@ pub enum Exactness {
     Exact,
 }
 
+impl Exactness {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Lax => "lax",
+            Self::Strict => "strict",
+            Self::Exact => "exact",
+        }
+    }
+}
+
+/// Key for `SharedCache`, the same object validated by the same node can still give different results
+/// if `strict` or `from_attributes` have been changed further up the tree.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct SharedCacheKey {
+    obj_id: usize,
+    node_id: usize,
+    strict: Option<bool>,
+    from_attributes: Option<bool>,
+}
+
+#[derive(Debug)]
+pub struct SharedCacheEntry {
+    // held so the object can't be garbage collected and its id reused while the cache is alive
+    _input: PyObject,
+    output: PyObject,
+    exactness: Option<Exactness>,
+}
+
+/// Results of validating python objects that are referenced multiple times within the input,
+/// used when `validate_python` is called with `cache_shared=True`.
+pub type SharedCache = AHashMap<SharedCacheKey, SharedCacheEntry>;
+
+/// Number of calls and cumulative time of each validator, keyed by `get_name()`, used when `validate_python`
+/// is called with `profile=True`. Times include nested validators.
+#[derive(Debug, Default)]
+pub struct ValidationProfile {
+    entries: AHashMap<String, (usize, Duration)>,
+}
+
+impl ValidationProfile {
+    pub fn record(&mut self, name: &str, elapsed: Duration) {
+        match self.entries.get_mut(name) {
+            Some((calls, time)) => {
+                *calls += 1;
+                *time += elapsed;
+            }
+            None => {
+                self.entries.insert(name.to_owned(), (1, elapsed));
+            }
+        }
+    }
+
+    /// Convert to a dict of `{name: {'calls': int, 'time': float}}` ordered by name, times are in seconds.
+    pub fn to_py<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyDict>> {
+        let mut entries: Vec<_> = self.entries.iter().collect();
+        entries.sort_unstable_by_key(|(name, _)| name.as_str());
+        let dict = PyDict::new_bound(py);
+        for (name, (calls, time)) in entries {
+            let entry = PyDict::new_bound(py);
+            entry.set_item(intern!(py, "calls"), calls)?;
+            entry.set_item(intern!(py, "time"), time.as_secs_f64())?;
+            dict.set_item(name, entry)?;
+        }
+        Ok(dict)
+    }
+}
+
 pub struct ValidationState<'a, 'py> {
     pub recursion_guard: &'a mut RecursionState,
     pub exactness: Option<Exactness>,
+    pub shared_cache: Option<SharedCache>,
+    /// Maximum number of errors to collect, from the `max_errors` config setting
+    pub max_errors: Option<usize>,
+    /// Number of errors dropped because `max_errors` was reached
+    pub omitted_errors: usize,
+    /// Per validator call counts and timings, only recorded when profiling is enabled
+    pub profile: Option<&'a mut ValidationProfile>,
+    /// Errors of invalid items which have been replaced with `None`, only set when `validate_python`
+    /// is called with `on_error='collect'`. Validators which handle the errors of their inner validators
+    /// (unions, function validators, dict keys and `with_default` with `on_error`) unset it with
+    /// `without_collecting_errors`, so their inner items fail as a whole instead.
+    pub collected_errors: Option<&'a mut Vec<ValLineError>>,
+    /// The variant which matched in the outermost union, only set when `validate_python` is called with
+    /// `report_union_variant=True`
+    pub union_variant: Option<&'a mut Option<PyObject>>,
+    /// The input key each field of the last model or typed dict to finish validating was found by, only set when
+    /// `validate_python` is called with `report_aliases=True`
+    pub matched_aliases: Option<&'a mut Option<Py<PyDict>>>,
+    /// The JSON document being validated, only set by `validate_json`
+    pub json_source: Option<&'a dyn JsonSource>,
     // deliberately make Extra readonly
     extra: Extra<'a, 'py>,
 }
@@ -27,6 +120,14 @@ impl<'a, 'py> ValidationState<'a, 'py> {
         Self {
             recursion_guard, // Don't care about exactness unless doing union validation
             exactness: None,
+            shared_cache: None,
+            max_errors: None,
+            omitted_errors: 0,
+            profile: None,
+            collected_errors: None,
+            union_variant: None,
+            matched_aliases: None,
+            json_source: None,
             extra,
         }
     }
@@ -68,6 +169,183 @@ impl<'a, 'py> ValidationState<'a, 'py> {
         }
     }
 
+    /// Returns the key to use for `obj_id` and `node_id` in the shared cache, or `None` if the cache isn't enabled.
+    pub fn shared_cache_key(&self, obj_id: usize, node_id: usize) -> Option<SharedCacheKey> {
+        self.shared_cache.as_ref().map(|_| SharedCacheKey {
+            obj_id,
+            node_id,
+            strict: self.extra.strict,
+            from_attributes: self.extra.from_attributes,
+        })
+    }
+
+    /// Get a previously validated value from the shared cache, applying the exactness it was validated with.
+    pub fn shared_cache_get(&mut self, py: Python<'py>, key: &SharedCacheKey) -> Option<PyObject> {
+        let (output, exactness) = self
+            .shared_cache
+            .as_ref()?
+            .get(key)
+            .map(|entry| (entry.output.clone_ref(py), entry.exactness))?;
+        if let Some(exactness) = exactness {
+            self.floor_exactness(exactness);
+        }
+        Some(output)
+    }
+
+    pub fn shared_cache_insert(
+        &mut self,
+        key: SharedCacheKey,
+        input: &Bound<'py, PyAny>,
+        output: &PyObject,
+        exactness: Option<Exactness>,
+    ) {
+        if let Some(cache) = self.shared_cache.as_mut() {
+            let entry = SharedCacheEntry {
+                _input: input.clone().unbind(),
+                output: output.clone_ref(input.py()),
+                exactness,
+            };
+            cache.insert(key, entry);
+        }
+    }
+
+    /// Add `line_errors` to `errors`, once `errors` holds `max_errors` errors any more are dropped
+    /// and counted in `omitted_errors` instead.
+    pub fn collect_errors(
+        &mut self,
+        errors: &mut Vec<ValLineError>,
+        line_errors: impl IntoIterator<Item = ValLineError>,
+    ) {
+        match self.max_errors {
+            Some(max_errors) => {
+                for line_error in line_errors {
+                    if errors.len() < max_errors {
+                        errors.push(line_error);
+                    } else {
+                        self.omitted_errors += 1;
+                    }
+                }
+            }
+            None => errors.extend(line_errors),
+        }
+    }
+
+    /// Apply `max_errors` to the final error of validation, if any errors were dropped a `too_many_errors`
+    /// error is added to the end of the list.
+    pub fn limit_errors(&mut self, error: ValError, input: impl ToErrorValue) -> ValError {
+        let Some(max_errors) = self.max_errors else {
+            return error;
+        };
+        match error {
+            ValError::LineErrors(mut line_errors) => {
+                if line_errors.len() > max_errors {
+                    self.omitted_errors += line_errors.len() - max_errors;
+                    line_errors.truncate(max_errors);
+                }
+                if self.omitted_errors > 0 {
+                    let error_type = ErrorType::TooManyErrors {
+                        omitted: self.omitted_errors,
+                        context: None,
+                    };
+                    line_errors.push(ValLineError::new(error_type, input));
+                }
+                ValError::LineErrors(line_errors)
+            }
+            other => other,
+        }
+    }
+
+    /// Record the variant which matched in a union, unions record their variant after any unions nested
+    /// within their choices so the variant of the outermost union is kept.
+    pub fn record_union_variant(&mut self, variant: impl FnOnce() -> PyObject) {
+        if let Some(union_variant) = self.union_variant.as_mut() {
+            **union_variant = Some(variant());
+        }
+    }
+
+    /// Record the input key each field was found by, replacing any aliases recorded before. Models and typed dicts
+    /// record their aliases after any models nested within their fields, so those of an enclosing model win, but
+    /// for e.g. a list of models the last item's aliases are kept.
+    pub fn record_matched_aliases(&mut self, aliases: Bound<'_, PyDict>) {
+        if let Some(matched_aliases) = self.matched_aliases.as_mut() {
+            **matched_aliases = Some(aliases.unbind());
+        }
+    }
+
+    /// Number of errors collected so far, passed to `collect_item` as `start` before validating an item.
+    pub fn collected_count(&self) -> usize {
+        self.collected_errors.as_ref().map_or(0, |errors| errors.len())
+    }
+
+    /// When collecting errors, replace an invalid item with `None` and collect its errors, errors collected
+    /// since `start` (including those of invalid items nested within this one) are then located with `locate`.
+    ///
+    /// When not collecting errors `result` is returned unchanged.
+    pub fn collect_item(
+        &mut self,
+        py: Python<'py>,
+        result: ValResult<PyObject>,
+        start: usize,
+        locate: impl Fn(ValLineError) -> ValLineError,
+    ) -> ValResult<PyObject> {
+        let Some(collected_errors) = self.collected_errors.as_mut() else {
+            return result;
+        };
+        let value = match result {
+            Ok(value) => value,
+            Err(ValError::LineErrors(line_errors)) => {
+                collected_errors.extend(line_errors);
+                py.None()
+            }
+            Err(err) => return Err(err),
+        };
+        let item_errors = collected_errors.split_off(start);
+        collected_errors.extend(item_errors.into_iter().map(locate));
+        Ok(value)
+    }
+
+    /// Whether errors of invalid items are being collected, see `collected_errors`.
+    pub fn is_collecting_errors(&self) -> bool {
+        self.collected_errors.is_some()
+    }
+
+    /// When collecting errors, collect the errors of an invalid item which is dropped from its container rather
+    /// than replaced with `None`, i.e. dict keys and set items. Does nothing when not collecting errors.
+    pub fn collect_dropped(&mut self, line_errors: impl IntoIterator<Item = ValLineError>) {
+        if let Some(collected_errors) = self.collected_errors.as_mut() {
+            collected_errors.extend(line_errors);
+        }
+    }
+
+    /// When collecting errors, collect `error` and return `None` to use in place of the missing value,
+    /// otherwise push `error` onto `errors`.
+    pub fn collect_missing(
+        &mut self,
+        py: Python<'py>,
+        error: ValLineError,
+        errors: &mut Vec<ValLineError>,
+    ) -> Option<PyObject> {
+        match self.collected_errors.as_mut() {
+            Some(collected_errors) => {
+                collected_errors.push(error);
+                Some(py.None())
+            }
+            None => {
+                errors.push(error);
+                None
+            }
+        }
+    }
+
+    /// Run `f` without collecting errors, for validators which need to see the errors of their inner
+    /// validators, e.g. unions and function validators.
+    pub fn without_collecting_errors<R>(&mut self, f: impl FnOnce(&mut Self) -> R) -> R {
+        let collected_errors = self.collected_errors.take();
+        let result = f(self);
+        self.collected_errors = collected_errors;
+        result
+    }
+
     pub fn cache_str(&self) -> StringCacheMode {
         self.extra.cache_str
     }
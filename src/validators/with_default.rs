@@ -143,15 +143,31 @@ impl Validator for WithDefaultValidator {
         if input.to_object(py).is(&self.undefined) {
             Ok(self.default_value(py, None::<usize>, state)?.unwrap())
         } else {
-            match self.validator.validate(py, input, state) {
+            let old_omitted_errors = state.omitted_errors;
+            let result = match self.on_error {
+                OnError::Raise => self.validator.validate_profiled(py, input, state),
+                // errors have to reach `on_error` rather than being collected
+                _ => state.without_collecting_errors(|state| self.validator.validate_profiled(py, input, state)),
+            };
+            match result {
                 Ok(v) => Ok(v),
-                Err(e) => match e {
-                    ValError::UseDefault => Ok(self.default_value(py, None::<usize>, state)?.ok_or(e)?),
-                    e => match self.on_error {
-                        OnError::Raise => Err(e),
-                        OnError::Default => Ok(self.default_value(py, None::<usize>, state)?.ok_or(e)?),
-                        OnError::Omit => Err(ValError::Omit),
-                    },
+                Err(ValError::UseDefault) => {
+                    state.omitted_errors = old_omitted_errors;
+                    Ok(self
+                        .default_value(py, None::<usize>, state)?
+                        .ok_or(ValError::UseDefault)?)
+                }
+                Err(e) => match self.on_error {
+                    OnError::Raise => Err(e),
+                    // the errors are replaced by the default, so are any errors omitted from them
+                    OnError::Default => {
+                        state.omitted_errors = old_omitted_errors;
+                        Ok(self.default_value(py, None::<usize>, state)?.ok_or(e)?)
+                    }
+                    OnError::Omit => {
+                        state.omitted_errors = old_omitted_errors;
+                        Err(ValError::Omit)
+                    }
                 },
             }
         }
@@ -190,6 +206,14 @@ impl Validator for WithDefaultValidator {
         }
     }
 
+    fn json_schema<'py>(&self, py: Python<'py>, defs: &Bound<'py, PyDict>) -> PyResult<Bound<'py, PyDict>> {
+        let json_schema = self.validator.json_schema(py, defs)?;
+        if let DefaultType::Default(default) = &self.default {
+            json_schema.set_item(intern!(py, "default"), default)?;
+        }
+        Ok(json_schema)
+    }
+
     fn get_name(&self) -> &str {
         &self.name
     }
@@ -2,7 +2,7 @@ from datetime import date, datetime, time, timedelta, timezone
 
 import pytest
 
-from pydantic_core import SchemaSerializer, core_schema
+from pydantic_core import SchemaError, SchemaSerializer, core_schema
 
 
 def test_datetime():
@@ -58,6 +58,42 @@ def test_datetime_json(value, expected):
     assert v.to_json(value).decode() == f'"{expected}"'
 
 
+@pytest.mark.parametrize(
+    'timespec,use_z,value,expected',
+    [
+        ('auto', False, datetime(2022, 12, 2, 12, tzinfo=timezone.utc), '2022-12-02T12:00:00+00:00'),
+        ('auto', False, datetime(2022, 12, 2, 12, 0, 0, 5), '2022-12-02T12:00:00.000005'),
+        ('seconds', True, datetime(2022, 12, 2, 12, 13, 14, 123456, tzinfo=timezone.utc), '2022-12-02T12:13:14Z'),
+        ('milliseconds', True, datetime(2022, 12, 2, 12, 13, 14, 123999), '2022-12-02T12:13:14.123'),
+        ('milliseconds', True, datetime(2022, 12, 2, 12, 13, 14), '2022-12-02T12:13:14.000'),
+        ('microseconds', True, datetime(2022, 12, 2, 12, 13, 14), '2022-12-02T12:13:14.000000'),
+        (
+            'microseconds',
+            False,
+            datetime(2022, 12, 2, 12, 13, 14, tzinfo=tz(hours=-2, minutes=-30)),
+            '2022-12-02T12:13:14.000000-02:30',
+        ),
+    ],
+)
+def test_datetime_timespec_use_z(timespec, use_z, value, expected):
+    v = SchemaSerializer(core_schema.datetime_schema(timespec=timespec, use_z=use_z))
+    assert v.to_python(value, mode='json') == expected
+    assert v.to_json(value).decode() == f'"{expected}"'
+    assert v.to_python(value) is value
+
+
+def test_datetime_timespec_key():
+    s = core_schema.datetime_schema(timespec='seconds', use_z=False)
+    v = SchemaSerializer(core_schema.dict_schema(s, s))
+    value = datetime(2022, 12, 2, 12, 13, 14, 5, tzinfo=timezone.utc)
+    assert v.to_json({value: value}) == b'{"2022-12-02T12:13:14+00:00":"2022-12-02T12:13:14+00:00"}'
+
+
+def test_datetime_invalid_timespec():
+    with pytest.raises(SchemaError, match='Invalid timespec "hours"'):
+        SchemaSerializer({'type': 'datetime', 'timespec': 'hours'})
+
+
 def test_date():
     v = SchemaSerializer(core_schema.date_schema())
     assert v.to_python(date(2022, 12, 2)) == date(2022, 12, 2)
@@ -1,6 +1,6 @@
 import pytest
 
-from pydantic_core import SchemaSerializer, core_schema
+from pydantic_core import CoreConfig, PydanticSerializationError, SchemaSerializer, core_schema
 
 
 def test_branch_nullable():
@@ -57,6 +57,56 @@ def test_cyclic_recursion():
         s.to_json(v)
 
 
+def branch_schema():
+    return core_schema.definitions_schema(
+        core_schema.definition_reference_schema('Branch'),
+        [
+            core_schema.typed_dict_schema(
+                {
+                    'name': core_schema.typed_dict_field(core_schema.str_schema()),
+                    'sub_branch': core_schema.typed_dict_field(
+                        core_schema.nullable_schema(core_schema.definition_reference_schema('Branch'))
+                    ),
+                },
+                ref='Branch',
+            )
+        ],
+    )
+
+
+def test_cyclic_recursion_path():
+    s = SchemaSerializer(branch_schema())
+    root = {'name': 'root'}
+    root['sub_branch'] = {'name': 'child', 'sub_branch': root}
+    # the types from the first occurrence of the repeated object to the repeat
+    with pytest.raises(PydanticSerializationError, match=r'\(id repeated\), path: dict -> dict -> dict$'):
+        s.to_python(root)
+    with pytest.raises(PydanticSerializationError, match=r'\(id repeated\), path: dict -> dict -> dict$'):
+        s.to_json({'name': 'outer', 'sub_branch': root})
+
+    s = SchemaSerializer(core_schema.any_schema())
+    items = []
+    items.append({'items': items})
+    with pytest.raises(PydanticSerializationError, match=r'\(id repeated\), path: list -> dict -> list$'):
+        s.to_json(items)
+
+
+def test_max_depth():
+    s = SchemaSerializer(branch_schema(), CoreConfig(ser_max_depth=3))
+    value = None
+    for i in range(3):
+        value = {'name': str(i), 'sub_branch': value}
+    assert s.to_python(value)['sub_branch']['sub_branch'] == {'name': '0', 'sub_branch': None}
+
+    value = {'name': 'deeper', 'sub_branch': value}
+    with pytest.raises(PydanticSerializationError, match=r'\(depth exceeded\), max_depth is 3'):
+        s.to_python(value)
+    with pytest.raises(PydanticSerializationError, match=r'\(depth exceeded\), max_depth is 3'):
+        s.to_json(value)
+    # without the setting, only the built-in limit applies
+    assert SchemaSerializer(branch_schema()).to_json(value).startswith(b'{"name":"deeper"')
+
+
 def test_custom_ser():
     s = SchemaSerializer(
         core_schema.definitions_schema(
@@ -3,7 +3,13 @@ import json
 import pytest
 from dirty_equals import IsStrictDict
 
-from pydantic_core import SchemaError, SchemaSerializer, core_schema, validate_core_schema
+from pydantic_core import (
+    PydanticSerializationError,
+    SchemaError,
+    SchemaSerializer,
+    core_schema,
+    validate_core_schema,
+)
 
 
 def test_dict_str_int():
@@ -158,3 +164,26 @@ def test_include_error(include_value, error_msg):
         validate_core_schema(
             core_schema.dict_schema(serialization=core_schema.filter_dict_schema(include=include_value))
         )
+
+
+def test_json_key_mode_error():
+    s = SchemaSerializer(core_schema.dict_schema(core_schema.any_schema(), json_key_mode='error'))
+    assert s.to_python({1: 1}) == {1: 1}
+    assert s.to_json({'a': 1}) == b'{"a":1}'
+    assert s.to_python({'a': 1}, mode='json') == {'a': 1}
+
+    msg = "Dict key 1 is not a string, keys must be strings when serializing to JSON with json_key_mode='error'"
+    with pytest.raises(PydanticSerializationError, match=msg):
+        s.to_json({'a': 1, 1: 2})
+    with pytest.raises(PydanticSerializationError, match=msg):
+        s.to_python({1: 2}, mode='json')
+
+    s = SchemaSerializer(core_schema.dict_schema(core_schema.int_schema(), json_key_mode='str'))
+    assert s.to_json({1: 2}) == b'{"1":2}'
+
+
+def test_json_key_mode_invalid():
+    with pytest.raises(SchemaError, match="Input should be 'str' or 'error'"):
+        validate_core_schema(core_schema.dict_schema(json_key_mode='foobar'))
+    with pytest.raises(SchemaError, match="Invalid json_key_mode \"foobar\", should be 'str' or 'error'"):
+        SchemaSerializer(core_schema.dict_schema(json_key_mode='foobar'))
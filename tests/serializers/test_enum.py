@@ -2,7 +2,7 @@ from enum import Enum
 
 import pytest
 
-from pydantic_core import SchemaSerializer, core_schema
+from pydantic_core import SchemaError, SchemaSerializer, core_schema
 
 
 def test_plain_enum():
@@ -103,3 +103,66 @@ def test_int_dict_key():
         assert v.to_python({'x': 'x'}) == {'x': 'x'}
     with pytest.warns(UserWarning, match='Expected `enum` but got `str` - serialized value may not be as expected'):
         assert v.to_json({'x': 'x'}) == b'{"x":"x"}'
+
+
+def test_enum_name_mode():
+    class MyEnum(int, Enum):
+        a = 1
+        b = 2
+
+    v = SchemaSerializer(
+        core_schema.enum_schema(MyEnum, list(MyEnum.__members__.values()), sub_type='int'),
+        {'ser_json_enum': 'name'},
+    )
+
+    assert v.to_python(MyEnum.a) is MyEnum.a
+    assert v.to_python(MyEnum.a, mode='json') == 'a'
+    assert v.to_json(MyEnum.a) == b'"a"'
+
+
+def test_enum_name_mode_dict_key():
+    class MyEnum(Enum):
+        a = 1
+        b = 2
+
+    v = SchemaSerializer(
+        core_schema.dict_schema(
+            core_schema.enum_schema(MyEnum, list(MyEnum.__members__.values())),
+            core_schema.any_schema(),
+        ),
+        {'ser_json_enum': 'name'},
+    )
+
+    assert v.to_python({MyEnum.a: MyEnum.b}, mode='json') == {'a': 'b'}
+    assert v.to_json({MyEnum.a: MyEnum.b}) == b'{"a":"b"}'
+
+
+def test_enum_schema_mode():
+    class MyEnum(Enum):
+        a = 1
+        b = 2
+
+    members = list(MyEnum.__members__.values())
+    v = SchemaSerializer(
+        core_schema.dict_schema(
+            core_schema.enum_schema(MyEnum, members, ser_json_enum='name'),
+            core_schema.enum_schema(MyEnum, members),
+        )
+    )
+    assert v.to_python({MyEnum.a: MyEnum.b}, mode='json') == {'a': 2}
+    assert v.to_json({MyEnum.a: MyEnum.b}) == b'{"a":2}'
+
+    # the schema's mode takes precedence over the config
+    v = SchemaSerializer(core_schema.enum_schema(MyEnum, members, ser_json_enum='value'), {'ser_json_enum': 'name'})
+    assert v.to_python(MyEnum.a, mode='json') == 1
+    assert v.to_json(MyEnum.a) == b'1'
+
+
+def test_enum_invalid_mode():
+    class MyEnum(Enum):
+        a = 1
+
+    with pytest.raises(SchemaError, match='Invalid EnumMode serialization mode: `label`'):
+        SchemaSerializer(core_schema.enum_schema(MyEnum, [MyEnum.a]), {'ser_json_enum': 'label'})
+    with pytest.raises(SchemaError, match='Invalid EnumMode serialization mode: `label`'):
+        SchemaSerializer({'type': 'enum', 'cls': MyEnum, 'members': [MyEnum.a], 'ser_json_enum': 'label'})
@@ -1,11 +1,11 @@
 import json
 import re
-from datetime import date
+from datetime import date, datetime
 from uuid import UUID
 
 import pytest
 
-from pydantic_core import PydanticSerializationError, SchemaSerializer, core_schema
+from pydantic_core import PydanticSerializationError, SchemaError, SchemaSerializer, core_schema
 
 
 @pytest.mark.parametrize(
@@ -88,6 +88,36 @@ def test_format_error():
         s.to_json('x')
 
 
+@pytest.mark.parametrize(
+    'schema,value,formatting_string,expected_json',
+    [
+        (core_schema.float_schema(), 1.5, '.3f', b'"1.500"'),
+        (core_schema.int_schema(), 42, '05d', b'"00042"'),
+        (core_schema.date_schema(), date(2022, 11, 20), '%Y/%m/%d', b'"2022/11/20"'),
+        (core_schema.datetime_schema(), datetime(2022, 11, 20, 12, 30), '%Y/%m/%d %H:%M', b'"2022/11/20 12:30"'),
+    ],
+)
+def test_format_typed(schema, value, formatting_string, expected_json):
+    schema['serialization'] = core_schema.format_ser_schema(formatting_string)
+    s = SchemaSerializer(schema)
+    assert s.to_python(value) == value
+    assert s.to_json(value) == expected_json
+
+
+@pytest.mark.parametrize(
+    'schema,formatting_string',
+    [
+        (core_schema.float_schema(), '.2d'),
+        (core_schema.int_schema(), '.2s'),
+        (core_schema.float_schema(), 'foobar'),
+    ],
+)
+def test_format_invalid_at_build(schema, formatting_string):
+    schema['serialization'] = core_schema.format_ser_schema(formatting_string)
+    with pytest.raises(SchemaError, match=r'Invalid formatting string for `\w+` schema: Error calling `format'):
+        SchemaSerializer(schema)
+
+
 def test_dict_keys():
     s = SchemaSerializer(
         core_schema.dict_schema(core_schema.float_schema(serialization=core_schema.format_ser_schema('0.4f')))
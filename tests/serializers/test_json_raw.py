@@ -0,0 +1,19 @@
+from pydantic_core import JsonRaw, SchemaSerializer, SchemaValidator, core_schema
+
+
+def test_json_raw():
+    schema = core_schema.json_raw_schema(core_schema.list_schema(core_schema.int_schema()))
+    s = SchemaSerializer(schema)
+    json_raw = SchemaValidator(schema).validate_json('[1,  2]')
+    assert s.to_python(json_raw) == [1, 2]
+    assert s.to_json(json_raw) == b'[1,2]'
+    assert s.to_json(JsonRaw([3])) == b'[3]'
+    assert s.to_python([4], mode='json') == [4]
+
+
+def test_json_raw_keys():
+    schema = core_schema.dict_schema(core_schema.json_raw_schema(core_schema.int_schema()), core_schema.int_schema())
+    s = SchemaSerializer(schema)
+    json_raw = SchemaValidator(core_schema.json_raw_schema(core_schema.int_schema())).validate_json('1')
+    assert s.to_json({json_raw: 2}) == b'{"1":2}'
+    assert s.to_json({3: 4}) == b'{"3":4}'
@@ -447,3 +447,11 @@ def test_tuple_wrong_size_union():
 
     with pytest.warns(UserWarning, match='Unexpected extra items present in tuple'):
         s.to_json((1.0, 2.0, 3.0, 4.0))
+
+
+def test_list_prefix_items():
+    s = SchemaSerializer(core_schema.list_schema(core_schema.int_schema(), prefix_items=[core_schema.bytes_schema()]))
+    assert s.to_python([b'a', 1, 2]) == [b'a', 1, 2]
+    assert s.to_python([b'a', 1, 2], mode='json') == ['a', 1, 2]
+    assert s.to_json([b'a', 1, 2]) == b'["a",1,2]'
+    assert s.to_json([b'a']) == b'["a"]'
@@ -71,3 +71,68 @@ def test_bool_literal():
     assert s.to_python(False, mode='json') is False
     assert s.to_python(True) is True
     assert s.to_json(False) == b'false'
+
+
+def test_constant():
+    s = SchemaSerializer(core_schema.literal_schema(['cat'], serialization=core_schema.constant_ser_schema()))
+    assert s.to_python('cat') == 'cat'
+    assert s.to_python('dog') == 'cat'
+    assert s.to_python(None, mode='json') == 'cat'
+    assert s.to_json('dog') == b'"cat"'
+
+    s = SchemaSerializer(
+        core_schema.dict_schema(
+            core_schema.literal_schema([1], serialization=core_schema.constant_ser_schema()), core_schema.int_schema()
+        )
+    )
+    assert s.to_python({2: 3}, mode='json') == {'1': 3}
+
+
+def test_constant_check():
+    s = SchemaSerializer(
+        core_schema.literal_schema(['cat'], serialization=core_schema.constant_ser_schema(check=True))
+    )
+    assert s.to_python('cat') == 'cat'
+    with pytest.warns(UserWarning, match=r"Expected `constant\['cat'\]` but got 'dog' - serialized the constant"):
+        assert s.to_python('dog') == 'cat'
+    with pytest.warns(UserWarning, match=r"Expected `constant\['cat'\]` but got 'dog'"):
+        assert s.to_json('dog') == b'"cat"'
+
+
+def test_constant_union():
+    s = SchemaSerializer(
+        core_schema.union_schema(
+            [
+                core_schema.typed_dict_schema(
+                    {
+                        'kind': core_schema.typed_dict_field(
+                            core_schema.literal_schema(['a'], serialization=core_schema.constant_ser_schema())
+                        ),
+                        'x': core_schema.typed_dict_field(core_schema.int_schema()),
+                    }
+                ),
+                core_schema.typed_dict_schema(
+                    {
+                        'kind': core_schema.typed_dict_field(
+                            core_schema.literal_schema(['b'], serialization=core_schema.constant_ser_schema())
+                        ),
+                        'x': core_schema.typed_dict_field(core_schema.int_schema()),
+                    }
+                ),
+            ]
+        )
+    )
+    # the constant still picks the matching union variant
+    assert s.to_python({'kind': 'b', 'x': 1}) == {'kind': 'b', 'x': 1}
+
+
+@pytest.mark.parametrize(
+    'schema',
+    [
+        core_schema.literal_schema(['a', 'b'], serialization=core_schema.constant_ser_schema()),
+        core_schema.str_schema(serialization=core_schema.constant_ser_schema()),
+    ],
+)
+def test_constant_invalid(schema):
+    with pytest.raises(SchemaError, match='requires a `literal` schema with one expected value'):
+        SchemaSerializer(schema)
@@ -1,6 +1,9 @@
+import io
+import json
+
 import pytest
 
-from pydantic_core import SchemaError, core_schema, validate_core_schema
+from pydantic_core import PydanticSerializationError, SchemaError, SchemaSerializer, core_schema, validate_core_schema
 
 
 @pytest.mark.parametrize(
@@ -13,3 +16,48 @@ from pydantic_core import SchemaError, core_schema, validate_core_schema
 def test_invalid_ser_schema(ser_schema, msg):
     with pytest.raises(SchemaError, match=msg):
         validate_core_schema(core_schema.any_schema(serialization=ser_schema))
+
+
+def test_to_json_into():
+    s = SchemaSerializer(core_schema.dict_schema(core_schema.str_schema(), core_schema.bytes_schema()))
+    f = io.BytesIO()
+    assert s.to_json_into({'a': b'x', 'b': b'y'}, f) is None
+    assert f.getvalue() == b'{"a":"x","b":"y"}'
+
+    f = io.BytesIO()
+    s.to_json_into({'a': b'x'}, f, indent=2, exclude={'b'})
+    assert f.getvalue() == s.to_json({'a': b'x'}, indent=2) == b'{\n  "a": "x"\n}'
+
+
+def test_to_json_into_chunks():
+    class RecordingFile:
+        def __init__(self):
+            self.chunks = []
+
+        def write(self, data):
+            assert isinstance(data, bytes)
+            self.chunks.append(data)
+
+    s = SchemaSerializer(core_schema.list_schema(core_schema.str_schema()))
+    value = ['x' * 1000] * 500
+    f = RecordingFile()
+    s.to_json_into(value, f)
+    assert len(f.chunks) > 1
+    assert max(len(chunk) for chunk in f.chunks) < 100_000
+    assert json.loads(b''.join(f.chunks)) == value
+
+
+def test_to_json_into_errors():
+    class BrokenFile:
+        def write(self, data):
+            raise OSError('disk full')
+
+    s = SchemaSerializer(core_schema.list_schema(core_schema.str_schema()))
+    with pytest.raises(OSError, match='disk full'):
+        s.to_json_into(['x'], BrokenFile())
+    with pytest.raises(OSError, match='disk full'):
+        s.to_json_into(['x' * 1000] * 500, BrokenFile())
+
+    s = SchemaSerializer(core_schema.any_schema())
+    with pytest.raises(PydanticSerializationError, match='Unable to serialize unknown type'):
+        s.to_json_into(object(), io.BytesIO())
@@ -266,6 +266,32 @@ def test_exclude_none():
     assert s.to_json(BasicModel(foo=None, bar=b'more'), exclude_none=True) == b'{"bar":"more"}'
 
 
+def test_omit_if_none():
+    s = SchemaSerializer(
+        core_schema.model_schema(
+            BasicModel,
+            core_schema.model_fields_schema(
+                {
+                    'foo': core_schema.model_field(
+                        core_schema.nullable_schema(core_schema.int_schema()), serialization_omit_if_none=True
+                    ),
+                    'bar': core_schema.model_field(
+                        core_schema.nullable_schema(core_schema.int_schema()), serialization_omit_if_none=False
+                    ),
+                    'spam': core_schema.model_field(core_schema.nullable_schema(core_schema.int_schema())),
+                }
+            ),
+        )
+    )
+    m = BasicModel(foo=None, bar=None, spam=None)
+    assert s.to_python(m) == {'bar': None, 'spam': None}
+    assert s.to_python(m, exclude_none=True) == {'bar': None}
+    assert s.to_python(m, mode='json') == {'bar': None, 'spam': None}
+    assert s.to_json(m) == b'{"bar":null,"spam":null}'
+    assert s.to_json(m, exclude_none=True) == b'{"bar":null}'
+    assert s.to_python(BasicModel(foo=1, bar=2, spam=3)) == {'foo': 1, 'bar': 2, 'spam': 3}
+
+
 class FieldsSetModel:
     __slots__ = '__dict__', '__pydantic_fields_set__', '__pydantic_extra__', '__pydantic_private__'
 
@@ -306,6 +332,29 @@ def test_exclude_unset():
     assert s.to_python(m2, exclude_unset=True) == {'bar': 2, 'spam': 3}
 
 
+def test_slots_model():
+    class SlotsModel:
+        __slots__ = 'foo', 'bar', '__pydantic_fields_set__', '__pydantic_extra__', '__pydantic_private__'
+
+    schema = core_schema.model_schema(
+        SlotsModel,
+        core_schema.model_fields_schema(
+            {
+                'foo': core_schema.model_field(core_schema.int_schema()),
+                'bar': core_schema.model_field(core_schema.with_default_schema(core_schema.bytes_schema(), default=b'x')),
+            }
+        ),
+    )
+    m = SchemaValidator(schema).validate_python({'foo': 1})
+    assert not hasattr(m, '__dict__')
+
+    s = SchemaSerializer(schema)
+    assert s.to_python(m) == {'foo': 1, 'bar': b'x'}
+    assert s.to_python(m, exclude_unset=True) == {'foo': 1}
+    assert s.to_python(m, mode='json', exclude={'foo'}) == {'bar': 'x'}
+    assert json.loads(s.to_json(m)) == {'foo': 1, 'bar': 'x'}
+
+
 @pytest.mark.parametrize(
     'exclude,expected',
     [
@@ -594,6 +643,44 @@ def test_function_wrap_field_serializer_to_json():
     assert json.loads(s.to_json(Model(x=1000))) == {'x': '1_000'}
 
 
+@pytest.mark.parametrize(
+    'when_used,expected_python,expected_json',
+    [
+        ('always', {'x': '1_000', 'y': 'None'}, {'x': '1_000', 'y': 'None'}),
+        ('unless-none', {'x': '1_000', 'y': None}, {'x': '1_000', 'y': None}),
+        ('json', {'x': 1000, 'y': None}, {'x': '1_000', 'y': 'None'}),
+        ('json-unless-none', {'x': 1000, 'y': None}, {'x': '1_000', 'y': None}),
+    ],
+)
+def test_function_field_serializer_when_used(when_used, expected_python, expected_json):
+    @dataclasses.dataclass
+    class Model:
+        x: int
+        y: Any
+
+        def ser_field(self, v: Any, _) -> str:
+            return f'{v:_}' if isinstance(v, int) else str(v)
+
+    field_schema = core_schema.nullable_schema(
+        core_schema.int_schema(),
+        serialization=core_schema.plain_serializer_function_ser_schema(
+            Model.ser_field, is_field_serializer=True, info_arg=True, when_used=when_used
+        ),
+    )
+    s = SchemaSerializer(
+        core_schema.model_schema(
+            Model,
+            core_schema.model_fields_schema(
+                {'x': core_schema.model_field(field_schema), 'y': core_schema.model_field(field_schema)}
+            ),
+        )
+    )
+    m = Model(x=1000, y=None)
+    assert s.to_python(m) == expected_python
+    assert s.to_python(m, mode='json') == expected_json
+    assert json.loads(s.to_json(m)) == expected_json
+
+
 def test_property():
     @dataclasses.dataclass
     class Model:
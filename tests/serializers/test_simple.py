@@ -3,7 +3,7 @@ from enum import IntEnum
 
 import pytest
 
-from pydantic_core import SchemaSerializer, core_schema
+from pydantic_core import SchemaError, SchemaSerializer, core_schema
 
 try:
     import numpy
@@ -163,3 +163,38 @@ def test_float_inf_and_nan_serializers(value, expected_json, config):
 
     # Serialized JSON value respects the ser_json_inf_nan setting
     assert s.to_json(value).decode() == expected_json
+
+
+@pytest.mark.parametrize(
+    'value,expected_json,config',
+    [
+        # default value of ser_json_large_int
+        (2**53, b'9007199254740992', {}),
+        (int(_BIG_NUMBER_BYTES), _BIG_NUMBER_BYTES, {}),
+        # explicit values of ser_json_large_int
+        (2**53, b'9007199254740992', {'ser_json_large_int': 'number'}),
+        (2**53 - 1, b'9007199254740991', {'ser_json_large_int': 'string'}),
+        (-(2**53) + 1, b'-9007199254740991', {'ser_json_large_int': 'string'}),
+        (2**53, b'"9007199254740992"', {'ser_json_large_int': 'string'}),
+        (-(2**53), b'"-9007199254740992"', {'ser_json_large_int': 'string'}),
+        (int(_BIG_NUMBER_BYTES), b'"' + _BIG_NUMBER_BYTES + b'"', {'ser_json_large_int': 'string'}),
+        (IntSubClass(2**53), b'"9007199254740992"', {'ser_json_large_int': 'string'}),
+        # custom threshold
+        (1000, b'1000', {'ser_json_large_int': 'string', 'ser_json_large_int_threshold': 1000}),
+        (1001, b'"1001"', {'ser_json_large_int': 'string', 'ser_json_large_int_threshold': 1000}),
+    ],
+)
+def test_int_large_int_serializers(value, expected_json, config):
+    s = SchemaSerializer(core_schema.int_schema(), config)
+
+    assert s.to_python(value) == value
+    assert s.to_json(value) == expected_json
+    assert s.to_python(value, mode='json') == json.loads(expected_json)
+    # keys are always strings
+    s = SchemaSerializer(core_schema.dict_schema(core_schema.int_schema()), config)
+    assert s.to_json({value: 1}) == b'{"%d":1}' % value
+
+
+def test_int_large_int_negative_threshold():
+    with pytest.raises(SchemaError, match='`ser_json_large_int_threshold` must not be negative, got -1'):
+        SchemaSerializer(core_schema.int_schema(), {'ser_json_large_int': 'string', 'ser_json_large_int_threshold': -1})
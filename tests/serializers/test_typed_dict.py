@@ -149,6 +149,26 @@ def test_exclude_none():
     assert v.to_json({'foo': None, 'bar': b'more', 'c': None}, exclude_none=True) == b'{"bar":"more"}'
 
 
+def test_omit_if_none():
+    v = SchemaSerializer(
+        core_schema.typed_dict_schema(
+            {
+                'foo': core_schema.typed_dict_field(
+                    core_schema.nullable_schema(core_schema.int_schema()), serialization_omit_if_none=True
+                ),
+                'bar': core_schema.typed_dict_field(
+                    core_schema.nullable_schema(core_schema.int_schema()), serialization_omit_if_none=False
+                ),
+            },
+            extra_behavior='allow',
+        )
+    )
+    assert v.to_python({'foo': None, 'bar': None, 'c': None}) == {'bar': None, 'c': None}
+    assert v.to_python({'foo': None, 'bar': None, 'c': None}, exclude_none=True) == {'bar': None}
+    assert v.to_json({'foo': None, 'bar': None, 'c': None}, exclude_none=True) == b'{"bar":null}'
+    assert v.to_json({'foo': 1, 'bar': 2}) == b'{"foo":1,"bar":2}'
+
+
 def test_exclude_default():
     v = SchemaSerializer(
         core_schema.typed_dict_schema(
@@ -0,0 +1,96 @@
+from datetime import date, datetime, timedelta, timezone
+from decimal import Decimal
+
+import pytest
+
+from pydantic_core import PydanticSerializationError, SchemaSerializer, core_schema, to_cbor
+
+
+@pytest.mark.parametrize(
+    'value,expected',
+    [
+        (None, 'f6'),
+        (True, 'f5'),
+        (False, 'f4'),
+        (0, '00'),
+        (23, '17'),
+        (24, '1818'),
+        (1000, '1903e8'),
+        (1_000_000, '1a000f4240'),
+        (2**64 - 1, '1bffffffffffffffff'),
+        (2**64, 'c249010000000000000000'),
+        (-1, '20'),
+        (-1000, '3903e7'),
+        (-(2**64) - 1, 'c349010000000000000000'),
+        (1.5, 'fb3ff8000000000000'),
+        ('', '60'),
+        ('a', '6161'),
+        ('ü', '62c3bc'),
+        (b'\x01\x02', '420102'),
+        ([1, [2, 3]], '8201820203'),
+        ((1, 2), '820102'),
+        ({'a': 1, 'b': [2]}, 'a261610161628102'),
+        (Decimal('273.15'), 'c48221196ab3'),
+        (Decimal('-1.5'), 'c482202e'),
+        (Decimal('Infinity'), 'fb7ff0000000000000'),
+        (
+            datetime(2013, 3, 21, 20, 4, tzinfo=timezone.utc),
+            'c0' + '7819' + b'2013-03-21T20:04:00+00:00'.hex(),
+        ),
+        # naive datetimes aren't date/time strings as they have no offset
+        (datetime(2013, 3, 21, 20, 4), '73' + b'2013-03-21T20:04:00'.hex()),
+        # other types are serialized like JSON
+        (date(2013, 3, 21), '6a' + b'2013-03-21'.hex()),
+        ({1, 2}, '820102'),
+    ],
+    ids=repr,
+)
+def test_to_cbor(value, expected):
+    assert to_cbor(value).hex() == expected
+
+
+@pytest.mark.parametrize(
+    'value,expected',
+    [
+        (datetime(2013, 3, 21, 20, 4, tzinfo=timezone.utc), 'c11a514b67b0'),
+        (datetime(2013, 3, 21, 22, 4, tzinfo=timezone(timedelta(hours=2))), 'c11a514b67b0'),
+        (datetime(2013, 3, 21, 20, 4, 0, 500_000, tzinfo=timezone.utc), 'c1fb41d452d9ec200000'),
+        (datetime(1969, 12, 31, 23, 59, tzinfo=timezone.utc), 'c1383b'),
+    ],
+    ids=repr,
+)
+def test_to_cbor_datetime_epoch(value, expected):
+    assert to_cbor(value, datetime_mode='epoch').hex() == expected
+
+
+def test_to_cbor_datetime_epoch_naive():
+    with pytest.raises(PydanticSerializationError, match="Naive datetimes can't be serialized to CBOR"):
+        to_cbor(datetime(2013, 3, 21, 20, 4), datetime_mode='epoch')
+
+
+def test_to_cbor_model():
+    class Model:
+        __slots__ = '__dict__', '__pydantic_fields_set__', '__pydantic_extra__', '__pydantic_private__'
+
+    s = SchemaSerializer(
+        core_schema.model_schema(
+            Model,
+            core_schema.model_fields_schema(
+                {
+                    'a': core_schema.model_field(core_schema.bytes_schema()),
+                    'b': core_schema.model_field(core_schema.int_schema(), serialization_alias='B'),
+                }
+            ),
+        )
+    )
+    m = Model()
+    m.__dict__ = {'a': b'x', 'b': 1}
+    m.__pydantic_serializer__ = s
+    assert to_cbor(m).hex() == 'a261614178614201'
+    assert to_cbor(m, exclude={'a'}, by_alias=False).hex() == 'a1616201'
+
+
+def test_to_cbor_unknown():
+    with pytest.raises(PydanticSerializationError, match='Unable to serialize unknown type'):
+        to_cbor(object())
+    assert to_cbor(object(), fallback=lambda v: 'x').hex() == '6178'
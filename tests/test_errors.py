@@ -1,4 +1,5 @@
 import enum
+import json
 import os
 import pickle
 import re
@@ -15,7 +16,9 @@ from pydantic_core import (
     CoreConfig,
     PydanticCustomError,
     PydanticKnownError,
+    PydanticMultiError,
     PydanticOmit,
+    SchemaError,
     SchemaValidator,
     ValidationError,
     core_schema,
@@ -111,6 +114,58 @@ def test_pydantic_value_error_invalid_type():
         v.validate_python(42)
 
 
+def test_pydantic_multi_error():
+    e = PydanticMultiError(
+        [
+            (('a',), PydanticCustomError('my_error', 'bad {thing}', {'thing': 'a'})),
+            ((), PydanticKnownError('int_type')),
+        ]
+    )
+    assert e.errors == [(('a',), 'bad a'), ((), 'Input should be a valid integer')]
+    assert str(e) == 'a: bad a, Input should be a valid integer'
+    assert repr(e) == "PydanticMultiError([(('a',), 'bad a'), ((), 'Input should be a valid integer')])"
+
+
+def test_pydantic_multi_error_invalid():
+    with pytest.raises(ValueError, match='PydanticMultiError requires at least one error'):
+        PydanticMultiError([])
+    with pytest.raises(TypeError, match='must be PydanticCustomError or PydanticKnownError instances'):
+        PydanticMultiError([((), ValueError('x'))])
+    with pytest.raises(TypeError, match='Location must be a list or tuple of strings and ints'):
+        PydanticMultiError([('a', PydanticKnownError('int_type'))])
+
+
+def test_pydantic_multi_error_usage():
+    def f(input_value, handler):
+        value = handler(input_value)
+        errors = [
+            ((key,), PydanticCustomError('negative', 'Value should not be negative'))
+            for key, item in value.items()
+            if item < 0
+        ]
+        if len(value) > 2:
+            ctx = {'field_type': 'Dict', 'max_length': 2, 'actual_length': len(value)}
+            errors.append(((), PydanticKnownError('too_long', ctx)))
+        if errors:
+            raise PydanticMultiError(errors)
+        return value
+
+    inner = core_schema.no_info_wrap_validator_function(
+        f, core_schema.dict_schema(core_schema.str_schema(), core_schema.int_schema())
+    )
+    v = SchemaValidator(core_schema.typed_dict_schema({'scores': core_schema.typed_dict_field(inner)}))
+    assert v.validate_python({'scores': {'a': 1}}) == {'scores': {'a': 1}}
+
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python({'scores': {'a': -1, 'b': 2, 'c': -3}})
+    # relative locations are inside the validator's location
+    assert [(e['type'], e['loc'], e['input']) for e in exc_info.value.errors()] == [
+        ('negative', ('scores', 'a'), {'a': -1, 'b': 2, 'c': -3}),
+        ('negative', ('scores', 'c'), {'a': -1, 'b': 2, 'c': -3}),
+        ('too_long', ('scores',), {'a': -1, 'b': 2, 'c': -3}),
+    ]
+
+
 def test_validator_instance_plain():
     class CustomValidator:
         def __init__(self):
@@ -253,11 +308,44 @@ def test_pydantic_custom_error_type_raise_custom_no_ctx(ctx: Optional[dict]):
     ]
 
 
+@pytest.mark.parametrize('ctx', [None, {'val': 42}])
+def test_pydantic_custom_error_metadata(ctx: Optional[dict]):
+    metadata = {'code': 'E42', 'help_url': 'https://example.com/help'}
+
+    def f(input_value, info):
+        raise PydanticCustomError('my_error', 'my message', ctx, metadata)
+
+    v = SchemaValidator(
+        core_schema.typed_dict_schema(
+            {
+                'a': core_schema.typed_dict_field(
+                    core_schema.list_schema(
+                        core_schema.with_info_before_validator_function(f, core_schema.int_schema())
+                    ),
+                    validation_alias='A',
+                )
+            }
+        )
+    )
+    expect_ctx = {'ctx': ctx} if ctx is not None else {}
+
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python({'A': [4]})
+    assert exc_info.value.errors(include_url=False) == [
+        {'type': 'my_error', 'loc': ('A', 0), 'msg': 'my message', 'input': 4, **expect_ctx, 'meta': metadata}
+    ]
+    assert exc_info.value.errors(include_context=False)[0]['meta'] == metadata
+    assert json.loads(exc_info.value.json())[0]['meta'] == metadata
+    assert PydanticCustomError('my_error', 'my message', None, metadata).metadata == metadata
+
+
 all_errors = [
     ('no_such_attribute', "Object has no attribute 'wrong_name'", {'attribute': 'wrong_name'}),
     ('json_invalid', 'Invalid JSON: foobar', {'error': 'foobar'}),
     ('json_type', 'JSON input should be string, bytes or bytearray', None),
     ('recursion_loop', 'Recursion error - cyclic reference detected', None),
+    ('too_many_errors', '1 more error omitted', {'omitted': 1}),
+    ('too_many_errors', '3 more errors omitted', {'omitted': 3}),
     ('model_type', 'Input should be a valid dictionary or instance of Foobar', {'class_name': 'Foobar'}),
     ('model_attributes_type', 'Input should be a valid dictionary or object to extract fields from', None),
     ('dataclass_exact_type', 'Input should be an instance of Foobar', {'class_name': 'Foobar'}),
@@ -267,6 +355,7 @@ all_errors = [
     ('frozen_instance', 'Instance is frozen', None),
     ('extra_forbidden', 'Extra inputs are not permitted', None),
     ('invalid_key', 'Keys should be strings', None),
+    ('case_collision', "Keys 'A', 'a' only differ in case", {'keys': "'A', 'a'"}),
     ('get_attribute_error', 'Error extracting attribute: foo', {'error': 'foo'}),
     ('none_required', 'Input should be None', None),
     ('enum', 'Input should be foo', {'expected': 'foo'}),
@@ -277,6 +366,9 @@ all_errors = [
     ('less_than', 'Input should be less than 42.1', {'lt': 42.1}),
     ('less_than_equal', 'Input should be less than or equal to 42.1', {'le': 42.1}),
     ('finite_number', 'Input should be a finite number', None),
+    ('interval_invalid', 'Interval start should be less than its end', {'expected': 'less than'}),
+    ('interval_not_comparable', 'Interval start and end should be comparable', None),
+    ('interval_range_step', 'Range inputs to an interval should have a step of 1', None),
     (
         'too_short',
         'Foobar should have at least 42 items after validation, not 40',
@@ -299,7 +391,32 @@ all_errors = [
     ('mapping_type', 'Input should be a valid mapping, error: foobar', {'error': 'foobar'}),
     ('iterable_type', 'Input should be iterable', None),
     ('iteration_error', 'Error iterating over object, error: foobar', {'error': 'foobar'}),
+    (
+        'contains',
+        'List should contain at least 1 item matching `contains`, not 0',
+        {'field_type': 'List', 'min_contains': 1, 'max_contains': None, 'actual_contains': 0},
+    ),
+    (
+        'contains',
+        'Set should contain at most 2 items matching `contains`, not 3',
+        {'field_type': 'Set', 'min_contains': 1, 'max_contains': 2, 'actual_contains': 3},
+    ),
     ('list_type', 'Input should be a valid list', None),
+    (
+        'list_unique_items',
+        'List should have unique items, item 2 is a duplicate of item 0',
+        {'first_index': 0, 'duplicate_index': 2},
+    ),
+    (
+        'list_column_length',
+        'Column should have 2 items like the first column, not 3',
+        {'expected_length': 2, 'actual_length': 3},
+    ),
+    (
+        'list_column_length',
+        'Column should have 1 item like the first column, not 0',
+        {'expected_length': 1, 'actual_length': 0},
+    ),
     ('tuple_type', 'Input should be a valid tuple', None),
     ('set_type', 'Input should be a valid set', None),
     ('bool_type', 'Input should be a valid boolean', None),
@@ -320,6 +437,11 @@ all_errors = [
     ('bytes_too_short', 'Data should have at least 1 byte', {'min_length': 1}),
     ('bytes_too_long', 'Data should have at most 42 bytes', {'max_length': 42}),
     ('bytes_too_long', 'Data should have at most 1 byte', {'max_length': 1}),
+    (
+        'bytes_invalid_encoding',
+        'Data should be valid base64: Invalid padding',
+        {'encoding': 'base64', 'encoding_error': 'Invalid padding'},
+    ),
     ('value_error', 'Value error, foobar', {'error': ValueError('foobar')}),
     ('assertion_error', 'Assertion failed, foobar', {'error': AssertionError('foobar')}),
     ('literal_error', 'Input should be foo', {'expected': 'foo'}),
@@ -338,6 +460,7 @@ all_errors = [
     ('datetime_object_invalid', 'Invalid datetime object, got foobar', {'error': 'foobar'}),
     ('datetime_past', 'Input should be in the past', None),
     ('datetime_future', 'Input should be in the future', None),
+    ('datetime_ambiguous', 'Input should not be an ambiguous local time', None),
     ('timezone_naive', 'Input should not have timezone info', None),
     ('timezone_aware', 'Input should have timezone info', None),
     ('timezone_offset', 'Timezone offset of 0 required, got 60', {'tz_expected': 0, 'tz_actual': 60}),
@@ -359,6 +482,16 @@ all_errors = [
     ('missing_keyword_only_argument', 'Missing required keyword only argument', None),
     ('unexpected_positional_argument', 'Unexpected positional argument', None),
     ('missing_positional_only_argument', 'Missing required positional only argument', None),
+    (
+        'positional_only_argument_as_keyword',
+        "Positional only argument 'a' passed as a keyword argument",
+        {'parameter': 'a'},
+    ),
+    (
+        'keyword_only_argument_as_positional',
+        "Keyword only argument 'a' passed as a positional argument",
+        {'parameter': 'a'},
+    ),
     ('multiple_argument_values', 'Got multiple values for argument', None),
     ('url_type', 'URL input should be a string or URL', None),
     ('url_parsing', 'Input should be a valid URL, Foobar', {'error': 'Foobar'}),
@@ -366,9 +499,20 @@ all_errors = [
     ('url_too_long', 'URL should have at most 42 characters', {'max_length': 42}),
     ('url_too_long', 'URL should have at most 1 character', {'max_length': 1}),
     ('url_scheme', 'URL scheme should be "foo", "bar" or "spam"', {'expected_schemes': '"foo", "bar" or "spam"'}),
+    (
+        'url_too_few_hosts',
+        'URL should have at least 2 hosts, not 1',
+        {'min_hosts': 2, 'actual_hosts': 1},
+    ),
+    (
+        'url_too_many_hosts',
+        'URL should have at most 1 host, not 3',
+        {'max_hosts': 1, 'actual_hosts': 3},
+    ),
     ('uuid_type', 'UUID input should be a string, bytes or UUID object', None),
     ('uuid_parsing', 'Input should be a valid UUID, Foobar', {'error': 'Foobar'}),
     ('uuid_version', 'UUID version 42 expected', {'expected_version': 42}),
+    ('email_parsing', 'Value should be a valid email address, bad', {'reason': 'bad'}),
     ('decimal_type', 'Decimal input should be an integer, float, string or Decimal object', None),
     ('decimal_parsing', 'Input should be a valid decimal', None),
     ('decimal_max_digits', 'Decimal input should have no more than 42 digits in total', {'max_digits': 42}),
@@ -385,6 +529,8 @@ all_errors = [
         'Decimal input should have no more than 1 digit before the decimal point',
         {'whole_digits': 1},
     ),
+    ('fraction_type', 'Fraction input should be an integer, string, pair of integers or Fraction object', None),
+    ('fraction_parsing', 'Input should be a valid fraction', None),
 ]
 
 
@@ -1140,3 +1286,205 @@ def test_errors_include_url_envvar(env_var, env_var_value, expected_to_have_url)
     if 'PYDANTIC_ERRORS_OMIT_URL' in env:
         assert 'PYDANTIC_ERRORS_OMIT_URL is deprecated' in result.stdout
     assert ('https://errors.pydantic.dev' in result.stdout) == expected_to_have_url
+
+
+def test_max_errors():
+    v = SchemaValidator(core_schema.list_schema(core_schema.int_schema()), config=CoreConfig(max_errors=3))
+    assert v.validate_python([1, '2', 3]) == [1, 2, 3]
+
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python(['a'] * 10)
+    # insert_assert(exc_info.value.errors(include_url=False))
+    assert exc_info.value.errors(include_url=False) == [
+        {
+            'type': 'int_parsing',
+            'loc': (0,),
+            'msg': 'Input should be a valid integer, unable to parse string as an integer',
+            'input': 'a',
+        },
+        {
+            'type': 'int_parsing',
+            'loc': (1,),
+            'msg': 'Input should be a valid integer, unable to parse string as an integer',
+            'input': 'a',
+        },
+        {
+            'type': 'int_parsing',
+            'loc': (2,),
+            'msg': 'Input should be a valid integer, unable to parse string as an integer',
+            'input': 'a',
+        },
+        {
+            'type': 'too_many_errors',
+            'loc': (),
+            'msg': '7 more errors omitted',
+            'input': ['a'] * 10,
+            'ctx': {'omitted': 7},
+        },
+    ]
+    assert exc_info.value.error_count() == 4
+
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_json('[1, "a", 2, "b", 3, "c", "d"]')
+    assert [e['loc'] for e in exc_info.value.errors()] == [(1,), (3,), (5,), ()]
+    assert exc_info.value.errors()[-1]['msg'] == '1 more error omitted'
+
+
+def test_max_errors_nested():
+    v = SchemaValidator(
+        core_schema.dict_schema(core_schema.str_schema(), core_schema.list_schema(core_schema.int_schema())),
+        config=CoreConfig(max_errors=2),
+    )
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python({'a': ['x', 'y', 'z'], 'b': ['x'], 'c': [1]})
+    errors = exc_info.value.errors(include_url=False)
+    assert [e['loc'] for e in errors] == [('a', 0), ('a', 1), ()]
+    assert errors[-1]['ctx'] == {'omitted': 2}
+
+
+def test_max_errors_union():
+    v = SchemaValidator(
+        core_schema.union_schema(
+            [core_schema.list_schema(core_schema.int_schema()), core_schema.list_schema(core_schema.bool_schema())]
+        ),
+        config=CoreConfig(max_errors=2),
+    )
+    assert v.validate_python([1, True]) == [1, 1]
+
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python(['x', 'w', 'z'])
+    errors = exc_info.value.errors(include_url=False)
+    assert [e['loc'] for e in errors] == [('list[int]', 0), ('list[int]', 1), ()]
+    assert errors[-1]['ctx'] == {'omitted': 4}
+
+    # errors omitted while trying a choice that didn't match aren't counted once another choice succeeds
+    v = SchemaValidator(
+        core_schema.list_schema(
+            core_schema.union_schema(
+                [core_schema.list_schema(core_schema.int_schema()), core_schema.list_schema(core_schema.str_schema())]
+            )
+        ),
+        config=CoreConfig(max_errors=1),
+    )
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python([['a', 'b', 'c'], 'x'])
+    errors = exc_info.value.errors(include_url=False)
+    assert [e['loc'] for e in errors] == [(1, 'list[int]'), ()]
+    assert errors[-1]['ctx'] == {'omitted': 1}
+
+
+def test_max_errors_discarded():
+    # errors replaced by a default don't count towards those omitted
+    v = SchemaValidator(
+        core_schema.tuple_schema(
+            [
+                core_schema.with_default_schema(
+                    core_schema.list_schema(core_schema.int_schema()), default=[], on_error='default'
+                ),
+                core_schema.int_schema(),
+                core_schema.int_schema(),
+            ]
+        ),
+        config=CoreConfig(max_errors=1),
+    )
+    assert v.validate_python((['a'] * 5, 1, 2)) == ([], 1, 2)
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python((['a'] * 5, 'x', 'y'))
+    errors = exc_info.value.errors(include_url=False)
+    assert [e['type'] for e in errors] == ['int_parsing', 'too_many_errors']
+    assert errors[-1]['ctx'] == {'omitted': 1}
+
+    # the same applies to the strict attempt made by lax-or-strict schemas in smart unions
+    v = SchemaValidator(
+        core_schema.tuple_schema(
+            [
+                core_schema.union_schema(
+                    [
+                        core_schema.lax_or_strict_schema(
+                            lax_schema=core_schema.list_schema(core_schema.int_schema()),
+                            strict_schema=core_schema.list_schema(core_schema.int_schema(strict=True)),
+                        ),
+                        core_schema.str_schema(),
+                    ]
+                ),
+                core_schema.int_schema(),
+                core_schema.int_schema(),
+            ]
+        ),
+        config=CoreConfig(max_errors=1),
+    )
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python((['a', '1', '1', '1', '1'], 1, 2))
+    errors = exc_info.value.errors(include_url=False)
+    assert [e['type'] for e in errors] == ['int_parsing', 'too_many_errors']
+    assert errors[-1]['ctx'] == {'omitted': 1}
+
+
+def test_max_errors_invalid():
+    with pytest.raises(SchemaError, match='`max_errors` must be greater than 0'):
+        SchemaValidator(core_schema.int_schema(), config=CoreConfig(max_errors=0))
+
+
+def test_errors_tree():
+    v = SchemaValidator(
+        core_schema.typed_dict_schema(
+            {
+                'name': core_schema.typed_dict_field(core_schema.str_schema()),
+                'items': core_schema.typed_dict_field(
+                    core_schema.list_schema(
+                        core_schema.typed_dict_schema(
+                            {
+                                'id': core_schema.typed_dict_field(core_schema.int_schema()),
+                                'tags': core_schema.typed_dict_field(
+                                    core_schema.list_schema(core_schema.str_schema(max_length=3))
+                                ),
+                            }
+                        )
+                    )
+                ),
+            }
+        )
+    )
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python({'items': [{'id': 1, 'tags': ['ok']}, {'id': 'x', 'tags': ['a', 1, 'long']}]})
+    assert exc_info.value.errors_tree() == {
+        'name': ['Field required'],
+        'items': {
+            1: {
+                'id': ['Input should be a valid integer, unable to parse string as an integer'],
+                'tags': {
+                    1: ['Input should be a valid string'],
+                    2: ['String should have at most 3 characters'],
+                },
+            }
+        },
+    }
+
+
+def test_errors_tree_own_and_nested():
+    exc = ValidationError.from_exception_data(
+        'Foo',
+        [
+            {'type': 'missing', 'loc': ('a', 'b'), 'input': None},
+            {'type': 'assertion_error', 'loc': ('a',), 'input': None, 'ctx': {'error': 'first'}},
+            {'type': 'assertion_error', 'loc': ('a',), 'input': None, 'ctx': {'error': 'second'}},
+            {'type': 'missing', 'loc': ('a', 'b'), 'input': None},
+            {'type': 'assertion_error', 'loc': (), 'input': None, 'ctx': {'error': 'root'}},
+        ],
+    )
+    assert exc.errors_tree() == {
+        'a': {
+            'b': ['Field required', 'Field required'],
+            None: ['Assertion failed, first', 'Assertion failed, second'],
+        },
+        None: ['Assertion failed, root'],
+    }
+    # a location with errors of its own becomes a dict once nested errors are added
+    exc = ValidationError.from_exception_data(
+        'Foo',
+        [
+            {'type': 'missing', 'loc': ('a',), 'input': None},
+            {'type': 'missing', 'loc': ('a', 0), 'input': None},
+        ],
+    )
+    assert exc.errors_tree() == {'a': {None: ['Field required'], 0: ['Field required']}}
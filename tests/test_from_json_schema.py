@@ -0,0 +1,111 @@
+import pickle
+import re
+
+import pytest
+
+from pydantic_core import SchemaError, SchemaValidator, ValidationError
+
+USER_SCHEMA = {
+    '$schema': 'https://json-schema.org/draft/2020-12/schema',
+    'title': 'User',
+    'type': 'object',
+    'properties': {
+        'name': {'type': 'string', 'minLength': 1, 'maxLength': 10, 'pattern': '^[a-z]+$'},
+        'age': {'type': 'integer', 'minimum': 0, 'maximum': 150},
+        'score': {'type': 'number'},
+        'admin': {'type': 'boolean'},
+        'tags': {'type': 'array', 'items': {'type': 'string'}},
+        'role': {'enum': ['user', 'staff', None]},
+        'nickname': {'type': ['string', 'null']},
+    },
+    'required': ['name', 'age'],
+}
+
+
+def test_from_json_schema():
+    v = SchemaValidator.from_json_schema(USER_SCHEMA)
+    value = {'name': 'alice', 'age': 30, 'score': 1, 'admin': True, 'tags': ['a'], 'role': None, 'nickname': 'al'}
+    assert v.validate_python(value) == value
+    assert v.validate_json('{"name": "alice", "age": 30, "other": [1]}') == {'name': 'alice', 'age': 30, 'other': [1]}
+
+
+def test_from_json_schema_errors():
+    v = SchemaValidator.from_json_schema(USER_SCHEMA)
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python({'name': 'Alice', 'age': '30', 'tags': [1], 'role': 'admin', 'nickname': 1})
+    assert [(e['type'], e['loc']) for e in exc_info.value.errors()] == [
+        ('string_pattern_mismatch', ('name',)),
+        ('int_type', ('age',)),
+        ('string_type', ('tags', 0)),
+        ('literal_error', ('role',)),
+        ('string_type', ('nickname', 'str')),
+        ('none_required', ('nickname', 'none')),
+    ]
+
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python({'age': 151})
+    assert [(e['type'], e['loc']) for e in exc_info.value.errors()] == [
+        ('missing', ('name',)),
+        ('less_than_equal', ('age',)),
+    ]
+
+
+@pytest.mark.parametrize(
+    'json_schema,valid,invalid',
+    [
+        (True, 'anything', None),
+        ({}, [1, 'a'], None),
+        ({'type': 'object'}, {'a': 1}, [1]),
+        ({'type': 'array'}, [1, 'a'], (1,)),
+        ({'type': 'null'}, None, 0),
+        ({'type': 'number', 'minimum': 1.5}, 2, 1),
+        ({'required': ['a'], 'type': 'object'}, {'a': 1}, {}),
+        ({'type': 'integer', 'minimum': 1.5, 'maximum': 3.5}, 2, 1),
+        ({'type': 'integer', 'minimum': 1.5, 'maximum': 3.5}, 3, 4),
+        ({'enum': ['a', 1]}, 1, 'b'),
+    ],
+)
+def test_from_json_schema_types(json_schema, valid, invalid):
+    v = SchemaValidator.from_json_schema(json_schema)
+    assert v.validate_python(valid) == valid
+    if invalid is not None:
+        with pytest.raises(ValidationError):
+            v.validate_python(invalid)
+
+
+@pytest.mark.parametrize(
+    'json_schema,message',
+    [
+        ({'type': 'string', 'format': 'email'}, 'Unsupported JSON Schema keyword `format` at `#`'),
+        (
+            {'type': 'object', 'properties': {'a': {'oneOf': [], '$ref': '#/$defs/a'}}},
+            'Unsupported JSON Schema keywords `oneOf`, `$ref` at `#/properties/a`',
+        ),
+        ({'type': 'array', 'items': {'type': 'date'}}, 'Unsupported JSON Schema type `date` at `#/items`'),
+        ({'type': 1}, '`type` must be a string or an array of strings at `#`'),
+        ({'enum': 'a'}, '`enum` must be an array at `#`'),
+        ({'minLength': 1}, 'JSON Schema keyword `minLength` at `#` can only be used along with `type`'),
+        (
+            {'properties': {'a': {}}, 'required': ['a']},
+            'JSON Schema keywords `properties`, `required` at `#` can only be used along with `type`',
+        ),
+        ({'type': 'integer', 'minimum': 'a'}, '`minimum` and `maximum` must be numbers at `#`'),
+        (False, 'Unsupported JSON Schema `false` at `#`'),
+        ([], 'Invalid JSON Schema at `#`, expected an object or a boolean'),
+    ],
+)
+def test_from_json_schema_invalid(json_schema, message):
+    with pytest.raises(SchemaError, match=re.escape(message)):
+        SchemaValidator.from_json_schema(json_schema)
+
+
+def test_from_json_schema_config():
+    v = SchemaValidator.from_json_schema({'type': 'string'}, {'str_max_length': 2})
+    with pytest.raises(ValidationError, match='String should have at most 2 characters'):
+        v.validate_python('abc')
+
+
+def test_from_json_schema_pickle():
+    v = SchemaValidator.from_json_schema(USER_SCHEMA)
+    v2 = pickle.loads(pickle.dumps(v))
+    assert v2.validate_python({'name': 'bob', 'age': 1}) == {'name': 'bob', 'age': 1}
@@ -1,6 +1,8 @@
 import json
 import platform
 import re
+from datetime import date, datetime, timedelta
+from decimal import Decimal
 from typing import List
 
 import pytest
@@ -8,6 +10,7 @@ from dirty_equals import IsFloatNan, IsList
 
 import pydantic_core
 from pydantic_core import (
+    ParsedJson,
     PydanticSerializationError,
     SchemaSerializer,
     SchemaValidator,
@@ -286,16 +289,178 @@ def test_to_jsonable_python_schema_serializer():
     assert to_json(instance, by_alias=False) == b'{"my_foo":1,"my_inners":[{"my_foo":2,"my_inners":[]}]}'
 
 
+def test_to_jsonable_python_context():
+    class Inner:
+        def __init__(self, secret: str):
+            self.secret = secret
+
+    class Outer:
+        def __init__(self, inner: Inner):
+            self.inner = inner
+
+    def ser_secret(value, info):
+        if info.context and info.context.get('admin'):
+            return value
+        return '***'
+
+    inner_schema = core_schema.model_schema(
+        Inner,
+        core_schema.model_fields_schema(
+            {
+                'secret': core_schema.model_field(
+                    core_schema.str_schema(
+                        serialization=core_schema.plain_serializer_function_ser_schema(ser_secret, info_arg=True)
+                    )
+                )
+            }
+        ),
+    )
+    Inner.__pydantic_serializer__ = SchemaSerializer(inner_schema)
+    Outer.__pydantic_serializer__ = SchemaSerializer(
+        core_schema.model_schema(Outer, core_schema.model_fields_schema({'inner': core_schema.model_field(inner_schema)}))
+    )
+
+    instance = Outer(Inner('hunter2'))
+    assert to_jsonable_python(instance) == {'inner': {'secret': '***'}}
+    assert to_jsonable_python(instance, context={'admin': True}) == {'inner': {'secret': 'hunter2'}}
+    assert to_json(instance) == b'{"inner":{"secret":"***"}}'
+    assert to_json(instance, context={'admin': True}) == b'{"inner":{"secret":"hunter2"}}'
+
+
+def test_to_jsonable_python_keep_native():
+    value = {'when': datetime(2024, 1, 2, 3, 4), 'day': date(2024, 1, 2), 'price': Decimal('1.5'), 'data': b'x'}
+    assert to_jsonable_python(value) == {
+        'when': '2024-01-02T03:04:00',
+        'day': '2024-01-02',
+        'price': '1.5',
+        'data': 'x',
+    }
+    assert to_jsonable_python(value, keep_native={'datetime', 'decimal'}) == {
+        'when': datetime(2024, 1, 2, 3, 4),
+        'day': '2024-01-02',
+        'price': Decimal('1.5'),
+        'data': 'x',
+    }
+
+    with pytest.raises(ValueError, match="Invalid keep_native type 'list', expected one of 'bytes', 'datetime'"):
+        to_jsonable_python(value, keep_native={'list'})
+
+
+def test_to_jsonable_python_keep_native_model():
+    class Event:
+        def __init__(self, when: datetime, duration: timedelta):
+            self.when = when
+            self.duration = duration
+
+    Event.__pydantic_serializer__ = SchemaSerializer(
+        core_schema.model_schema(
+            Event,
+            core_schema.model_fields_schema(
+                {
+                    'when': core_schema.model_field(core_schema.datetime_schema()),
+                    'duration': core_schema.model_field(core_schema.timedelta_schema()),
+                }
+            ),
+        )
+    )
+    event = Event(datetime(2024, 1, 2), timedelta(hours=1))
+    assert to_jsonable_python([event], keep_native={'timedelta'}) == [
+        {'when': '2024-01-02T00:00:00', 'duration': timedelta(hours=1)}
+    ]
+
+
+def test_to_jsonable_python_truncate():
+    value = {'a': 1, 'b': [1, 2, {'c': (3, 4)}], 'd': {'e': {'f': 5}}}
+    assert to_jsonable_python(value, max_depth=2) == {
+        'a': 1,
+        'b': [1, 2, '...(truncated)'],
+        'd': {'e': '...(truncated)'},
+    }
+    assert to_jsonable_python(value, max_depth=0) == '...(truncated)'
+    assert to_jsonable_python(1, max_depth=0) == 1
+
+    assert to_jsonable_python(list(range(10)), max_items=3) == [0, 1, 2, '...(truncated)']
+    assert to_jsonable_python(list(range(3)), max_items=3) == [0, 1, 2]
+    assert to_jsonable_python((i for i in range(10)), max_items=2) == [0, 1, '...(truncated)']
+    assert to_jsonable_python(value, max_items=2, max_depth=1) == {
+        'a': 1,
+        'b': '...(truncated)',
+        '...(truncated)': None,
+    }
+
+
+def test_to_jsonable_python_truncate_model():
+    class Item:
+        def __init__(self, tags: list, child: 'Item | None' = None):
+            self.tags = tags
+            self.child = child
+
+    Item.__pydantic_serializer__ = SchemaSerializer(
+        core_schema.model_schema(
+            Item,
+            core_schema.model_fields_schema(
+                {
+                    'tags': core_schema.model_field(core_schema.list_schema(core_schema.str_schema())),
+                    'child': core_schema.model_field(core_schema.nullable_schema(core_schema.any_schema())),
+                }
+            ),
+        )
+    )
+    item = Item(['a', 'b', 'c'], Item(['d'], Item([])))
+    assert to_jsonable_python(item, max_depth=2, max_items=2) == {
+        'tags': ['a', 'b', '...(truncated)'],
+        'child': {'tags': '...(truncated)', 'child': '...(truncated)'},
+    }
+    assert to_jsonable_python([item], max_depth=1) == ['...(truncated)']
+
+
+def test_to_jsonable_python_truncate_typed_serializers():
+    calls = []
+
+    def ser(value):
+        calls.append(value)
+        return value
+
+    class Item:
+        def __init__(self, tags: dict):
+            self.tags = tags
+
+    tag_schema = core_schema.int_schema(serialization=core_schema.plain_serializer_function_ser_schema(ser))
+    Item.__pydantic_serializer__ = SchemaSerializer(
+        core_schema.model_schema(
+            Item,
+            core_schema.model_fields_schema(
+                {
+                    'tags': core_schema.model_field(
+                        core_schema.dict_schema(core_schema.str_schema(), core_schema.list_schema(tag_schema))
+                    ),
+                }
+            ),
+        )
+    )
+    item = Item({'a': [1, 2, 3], 'b': [4], 'c': []})
+    assert to_jsonable_python(item, max_items=2) == {
+        'tags': {'a': [1, 2, '...(truncated)'], 'b': [4], '...(truncated)': None}
+    }
+    # items past the limit aren't serialized at all
+    assert calls == [1, 2, 4]
+    calls.clear()
+    assert to_jsonable_python(item, max_depth=2) == {
+        'tags': {'a': '...(truncated)', 'b': '...(truncated)', 'c': '...(truncated)'}
+    }
+    assert calls == []
+
+
 def test_cycle_same():
     def fallback_func_passthrough(obj):
         return obj
 
     f = Foobar()
 
-    with pytest.raises(ValueError, match=r'Circular reference detected \(id repeated\)'):
+    with pytest.raises(PydanticSerializationError, match=r'Circular reference detected \(id repeated\)'):
         to_jsonable_python(f, fallback=fallback_func_passthrough)
 
-    with pytest.raises(ValueError, match=r'Circular reference detected \(id repeated\)'):
+    with pytest.raises(PydanticSerializationError, match=r'Circular reference detected \(id repeated\)'):
         to_json(f, fallback=fallback_func_passthrough)
 
 
@@ -309,10 +474,10 @@ def test_cycle_change():
 
     f = Foobar()
 
-    with pytest.raises(ValueError, match=r'Circular reference detected \(depth exceeded\)'):
+    with pytest.raises(PydanticSerializationError, match=r'Circular reference detected \(depth exceeded\)'):
         to_jsonable_python(f, fallback=fallback_func_change_id)
 
-    with pytest.raises(ValueError, match=r'Circular reference detected \(depth exceeded\)'):
+    with pytest.raises(PydanticSerializationError, match=r'Circular reference detected \(depth exceeded\)'):
         to_json(f, fallback=fallback_func_change_id)
 
 
@@ -376,3 +541,172 @@ def test_partial_parse():
     with pytest.raises(ValueError, match='EOF while parsing a string at line 1 column 15'):
         from_json(b'["aa", "bb", "c')
     assert from_json(b'["aa", "bb", "c', allow_partial=True) == ['aa', 'bb']
+
+
+def test_number_mode_string():
+    data = '{"a": 1, "b": [1.10, -0, 1e3, 12345678901234567890.123456789], "c": "1", "d": null, "e": true}'
+    assert from_json(data)['b'] == [1.1, 0, 1000.0, 12345678901234567890.123456789]
+    assert from_json(data, number_mode='string') == {
+        'a': '1',
+        'b': ['1.10', '-0', '1e3', '12345678901234567890.123456789'],
+        'c': '1',
+        'd': None,
+        'e': True,
+    }
+    assert from_json(b'3.14159265358979323846264338327950288', number_mode='string') == (
+        '3.14159265358979323846264338327950288'
+    )
+    assert from_json('[NaN, -Infinity]', number_mode='string') == ['NaN', '-Infinity']
+
+
+def test_number_mode_string_errors():
+    with pytest.raises(ValueError, match='expected value at line 1 column 2'):
+        from_json('[NaN]', allow_inf_nan=False, number_mode='string')
+    with pytest.raises(ValueError, match='trailing characters at line 1 column 4'):
+        from_json('[1]x', number_mode='string')
+    with pytest.raises(ValueError, match='recursion limit exceeded'):
+        from_json('[' * 500 + ']' * 500, number_mode='string')
+    with pytest.raises(ValueError, match="`allow_partial` is not supported with `number_mode='string'`"):
+        from_json('[1, 2', allow_partial=True, number_mode='string')
+    with pytest.raises(ValueError, match="Invalid number mode, should be `'number'` or `'string'`"):
+        from_json('1', number_mode='decimal')
+
+
+def test_json5():
+    data = """{
+    // line comment, with a comma,
+    "a": [1, 2, /* block, comment */ 3,],
+    "b": "// not a comment, /* nor this */",
+    /* multi
+       line */
+    "c": {"d": "x\\"",},
+}
+"""
+    assert from_json(data, json5=True) == {'a': [1, 2, 3], 'b': '// not a comment, /* nor this */', 'c': {'d': 'x"'}}
+    assert from_json(data.encode(), json5=True, number_mode='string')['a'] == ['1', '2', '3']
+    assert from_json('[1, 2,] // end', json5=True) == [1, 2]
+    with pytest.raises(ValueError, match='trailing comma at line 1 column 7'):
+        from_json('[1, 2,]')
+
+
+def test_json5_errors():
+    # positions still refer to the original input
+    with pytest.raises(ValueError, match='expected value at line 3 column 11'):
+        from_json('[\n  /* a\n  b */ 1, x]', json5=True)
+    with pytest.raises(ValueError, match='expected value at line 1 column 5'):
+        from_json('[1, /* unterminated', json5=True)
+    with pytest.raises(ValueError, match='expected value at line 1 column 2'):
+        from_json('[,]', json5=True)
+    with pytest.raises(ValueError, match='expected value at line 1 column 4'):
+        from_json('[1,,]', json5=True)
+
+
+def test_from_json_duplicate_keys():
+    data = '{"a": 1, "b": {"c": 2, "c": 3}, "a": 4}'
+    assert from_json(data) == {'a': 4, 'b': {'c': 3}}
+    assert from_json(data, duplicate_keys='last') == {'a': 4, 'b': {'c': 3}}
+    assert from_json(data, duplicate_keys='first') == {'a': 1, 'b': {'c': 2}}
+    assert from_json(data, duplicate_keys='list') == {'a': [1, 4], 'b': {'c': [2, 3]}}
+    assert list(from_json(data, duplicate_keys='list')) == ['a', 'b']
+    assert from_json('[{"a": 1}, {"a": 2}]', duplicate_keys='error') == [{'a': 1}, {'a': 2}]
+    with pytest.raises(ValueError, match='Detected duplicate key "c" at line 1'):
+        from_json(data, duplicate_keys='error')
+    with pytest.raises(ValueError, match='Detected duplicate key "a" at line 1'):
+        from_json('[{"a": 1.0, "a": 2}]', duplicate_keys='error', number_mode='string')
+
+
+def test_from_json_duplicate_keys_errors():
+    with pytest.raises(ValueError, match="`duplicate_keys='first'` and `duplicate_keys='list'` are not supported"):
+        from_json('{"a": 1', duplicate_keys='first', allow_partial=True)
+    with pytest.raises(ValueError, match="`duplicate_keys='first'` and `duplicate_keys='list'` are not supported"):
+        from_json('{"a": 1}', duplicate_keys='list', number_mode='string')
+    with pytest.raises(ValueError, match="Invalid `duplicate_keys` value 'merge'"):
+        from_json('{}', duplicate_keys='merge')
+
+
+def test_validate_json_duplicate_keys():
+    v = SchemaValidator(
+        core_schema.typed_dict_schema(
+            {
+                'a': core_schema.typed_dict_field(core_schema.any_schema()),
+                'b': core_schema.typed_dict_field(
+                    core_schema.dict_schema(core_schema.str_schema(), core_schema.any_schema())
+                ),
+            }
+        )
+    )
+    data = '{"a": 1, "b": {"c": 2, "c": 3}, "a": 4}'
+    assert v.validate_json(data) == from_json(data) == {'a': 4, 'b': {'c': 3}}
+    assert v.validate_json(data, duplicate_keys='first') == {'a': 1, 'b': {'c': 2}}
+    assert v.validate_json(data, duplicate_keys='last') == {'a': 4, 'b': {'c': 3}}
+    assert v.validate_json(data, duplicate_keys='list') == {'a': [1, 4], 'b': {'c': [2, 3]}}
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_json(data, duplicate_keys='error')
+    assert exc_info.value.errors(include_url=False) == [
+        {
+            'type': 'json_invalid',
+            'loc': (),
+            'msg': 'Invalid JSON: Detected duplicate key "c" at line 1 column 28',
+            'input': data,
+            'ctx': {'error': 'Detected duplicate key "c" at line 1 column 28'},
+        }
+    ]
+    with pytest.raises(ValueError, match="Invalid `duplicate_keys` value 'merge'"):
+        v.validate_json(data, duplicate_keys='merge')
+
+
+@pytest.mark.parametrize(
+    'cache_strings,cached', [(None, True), (True, True), ('all', True), (False, False), ('none', False)]
+)
+def test_validate_json_cache_strings(cache_strings, cached):
+    v = SchemaValidator(core_schema.list_schema(core_schema.str_schema()))
+    output = v.validate_json('["spam and eggs", "spam and eggs"]', cache_strings=cache_strings)
+    assert output == ['spam and eggs', 'spam and eggs']
+    assert (output[0] is output[1]) == cached
+
+
+def test_validate_json_cache_strings_overrides_config():
+    v = SchemaValidator(core_schema.list_schema(core_schema.str_schema()), {'cache_strings': False})
+    output = v.validate_json('["spam", "spam"]')
+    assert output[0] is not output[1]
+    output = v.validate_json('["spam", "spam"]', cache_strings=True)
+    assert output[0] is output[1]
+
+
+def test_validate_json_value():
+    parsed = ParsedJson('{"a": [1, "2", 3.0], "b": "2024-01-01"}')
+    assert repr(parsed) == "ParsedJson({'a': [1, '2', 3.0], 'b': '2024-01-01'})"
+    assert parsed.to_python() == {'a': [1, '2', 3.0], 'b': '2024-01-01'}
+
+    v = SchemaValidator(
+        core_schema.typed_dict_schema(
+            {
+                'a': core_schema.typed_dict_field(core_schema.list_schema(core_schema.int_schema())),
+                'b': core_schema.typed_dict_field(core_schema.date_schema()),
+            }
+        )
+    )
+    # the same parsed data can be validated repeatedly, and behaves like `validate_json`
+    assert v.validate_json_value(parsed) == {'a': [1, 2, 3], 'b': date(2024, 1, 1)}
+    assert v.validate_json_value(parsed) == v.validate_json(b'{"a": [1, "2", 3.0], "b": "2024-01-01"}')
+
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_json_value(parsed, strict=True)
+    assert exc_info.value.errors(include_url=False) == [
+        {'type': 'int_type', 'loc': ('a', 1), 'msg': 'Input should be a valid integer', 'input': '2'},
+        {'type': 'int_type', 'loc': ('a', 2), 'msg': 'Input should be a valid integer', 'input': 3.0},
+    ]
+    assert exc_info.value.title == 'typed-dict'
+
+
+def test_validate_json_value_invalid():
+    with pytest.raises(ValueError, match='EOF while parsing a list at line 1 column 2'):
+        ParsedJson('[1')
+    with pytest.raises(ValueError, match='expected value at line 1 column 1'):
+        ParsedJson('NaN', allow_inf_nan=False)
+    with pytest.raises(TypeError, match='Expected bytes, bytearray or str'):
+        ParsedJson(1)
+
+    v = SchemaValidator(core_schema.int_schema())
+    with pytest.raises(TypeError):
+        v.validate_json_value('1')
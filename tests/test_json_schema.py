@@ -0,0 +1,198 @@
+from enum import Enum
+
+import pytest
+
+from pydantic_core import SchemaValidator, core_schema
+
+
+@pytest.mark.parametrize(
+    'schema,expected',
+    [
+        (core_schema.any_schema(), {}),
+        (core_schema.none_schema(), {'type': 'null'}),
+        (core_schema.bool_schema(), {'type': 'boolean'}),
+        (core_schema.int_schema(), {'type': 'integer'}),
+        (
+            core_schema.int_schema(ge=1, lt=10, multiple_of=2),
+            {'type': 'integer', 'multipleOf': 2, 'exclusiveMaximum': 10, 'minimum': 1},
+        ),
+        (core_schema.float_schema(gt=0.5, le=1.5), {'type': 'number', 'maximum': 1.5, 'exclusiveMinimum': 0.5}),
+        (core_schema.str_schema(), {'type': 'string'}),
+        (
+            core_schema.str_schema(min_length=1, max_length=5, pattern='^a'),
+            {'type': 'string', 'minLength': 1, 'maxLength': 5, 'pattern': '^a'},
+        ),
+        (core_schema.bytes_schema(max_length=3), {'type': 'string', 'format': 'binary', 'maxLength': 3}),
+        (
+            core_schema.decimal_schema(ge=1),
+            {'anyOf': [{'type': 'number', 'minimum': 1.0}, {'type': 'string'}]},
+        ),
+        (core_schema.datetime_schema(), {'type': 'string', 'format': 'date-time'}),
+        (core_schema.date_schema(), {'type': 'string', 'format': 'date'}),
+        (core_schema.time_schema(), {'type': 'string', 'format': 'time'}),
+        (core_schema.timedelta_schema(), {'type': 'string', 'format': 'duration'}),
+        (core_schema.uuid_schema(), {'type': 'string', 'format': 'uuid'}),
+        (core_schema.email_schema(), {'type': 'string', 'format': 'email'}),
+        (core_schema.url_schema(max_length=99), {'type': 'string', 'format': 'uri', 'minLength': 1, 'maxLength': 99}),
+        (
+            core_schema.list_schema(core_schema.int_schema(), min_length=1, unique_items=True),
+            {'type': 'array', 'items': {'type': 'integer'}, 'minItems': 1, 'uniqueItems': True},
+        ),
+        (
+            core_schema.list_schema(core_schema.int_schema(), contains=core_schema.int_schema(gt=0), max_contains=2),
+            {
+                'type': 'array',
+                'items': {'type': 'integer'},
+                'contains': {'type': 'integer', 'exclusiveMinimum': 0},
+                'maxContains': 2,
+            },
+        ),
+        (
+            core_schema.set_schema(core_schema.str_schema()),
+            {'type': 'array', 'items': {'type': 'string'}, 'uniqueItems': True},
+        ),
+        (
+            core_schema.tuple_schema([core_schema.int_schema(), core_schema.str_schema()]),
+            {
+                'type': 'array',
+                'prefixItems': [{'type': 'integer'}, {'type': 'string'}],
+                'items': False,
+                'minItems': 2,
+                'maxItems': 2,
+            },
+        ),
+        (
+            core_schema.tuple_schema([core_schema.int_schema(), core_schema.str_schema()], variadic_item_index=1),
+            {'type': 'array', 'prefixItems': [{'type': 'integer'}], 'items': {'type': 'string'}},
+        ),
+        (
+            core_schema.dict_schema(core_schema.str_schema(), core_schema.int_schema(), max_length=3),
+            {'type': 'object', 'additionalProperties': {'type': 'integer'}, 'maxProperties': 3},
+        ),
+        (
+            core_schema.nullable_schema(core_schema.int_schema()),
+            {'anyOf': [{'type': 'integer'}, {'type': 'null'}]},
+        ),
+        (
+            core_schema.union_schema([core_schema.int_schema(), core_schema.str_schema()]),
+            {'anyOf': [{'type': 'integer'}, {'type': 'string'}]},
+        ),
+        (core_schema.literal_schema(['a', 1]), {'enum': ['a', 1]}),
+        (
+            core_schema.no_info_after_validator_function(lambda x: x, core_schema.int_schema()),
+            {'type': 'integer'},
+        ),
+        (
+            core_schema.json_schema(core_schema.int_schema()),
+            {'type': 'string', 'contentMediaType': 'application/json', 'contentSchema': {'type': 'integer'}},
+        ),
+    ],
+)
+def test_json_schema(schema, expected):
+    assert SchemaValidator(schema).json_schema() == expected
+
+
+def test_typed_dict():
+    v = SchemaValidator(
+        core_schema.typed_dict_schema(
+            {
+                'a': core_schema.typed_dict_field(core_schema.int_schema()),
+                'b': core_schema.typed_dict_field(core_schema.str_schema(), required=False),
+            },
+            extra_behavior='forbid',
+        )
+    )
+    assert v.json_schema() == {
+        'type': 'object',
+        'properties': {'a': {'type': 'integer'}, 'b': {'type': 'string'}},
+        'required': ['a'],
+        'additionalProperties': False,
+    }
+
+
+def test_model():
+    class MyModel:
+        pass
+
+    v = SchemaValidator(
+        core_schema.model_schema(
+            MyModel,
+            core_schema.model_fields_schema(
+                {
+                    'x': core_schema.model_field(core_schema.int_schema()),
+                    'y': core_schema.model_field(
+                        core_schema.with_default_schema(core_schema.str_schema(), default='y')
+                    ),
+                },
+                model_name='MyModel',
+            ),
+        )
+    )
+    assert v.json_schema() == {
+        'type': 'object',
+        'properties': {'x': {'type': 'integer'}, 'y': {'type': 'string', 'default': 'y'}},
+        'required': ['x'],
+        'title': 'MyModel',
+    }
+
+
+def test_enum():
+    class Color(Enum):
+        RED = 'red'
+        BLUE = 'blue'
+
+    v = SchemaValidator(core_schema.enum_schema(Color, list(Color.__members__.values())))
+    assert v.json_schema() == {'enum': ['red', 'blue'], 'title': 'Color'}
+
+
+def test_recursive():
+    v = SchemaValidator(
+        core_schema.definitions_schema(
+            core_schema.definition_reference_schema('Branch'),
+            [
+                core_schema.typed_dict_schema(
+                    {
+                        'name': core_schema.typed_dict_field(core_schema.str_schema()),
+                        'sub': core_schema.typed_dict_field(
+                            core_schema.nullable_schema(core_schema.definition_reference_schema('Branch'))
+                        ),
+                    },
+                    ref='Branch',
+                )
+            ],
+        )
+    )
+    assert v.json_schema() == {
+        '$ref': '#/$defs/Branch',
+        '$defs': {
+            'Branch': {
+                'type': 'object',
+                'properties': {
+                    'name': {'type': 'string'},
+                    'sub': {'anyOf': [{'$ref': '#/$defs/Branch'}, {'type': 'null'}]},
+                },
+                'required': ['name', 'sub'],
+            }
+        },
+    }
+
+
+def test_function_placeholder():
+    v = SchemaValidator(core_schema.no_info_plain_validator_function(lambda x: x))
+    json_schema = v.json_schema()
+    assert list(json_schema) == ['description']
+    assert json_schema['description'].startswith('`function-plain[')
+    assert json_schema['description'].endswith('` has no JSON Schema representation')
+
+
+def test_from_json_schema_round_trip():
+    json_schema = {
+        'type': 'object',
+        'properties': {
+            'name': {'type': 'string', 'minLength': 1, 'pattern': '^[a-z]+$'},
+            'ages': {'type': 'array', 'items': {'type': 'integer', 'minimum': 0, 'maximum': 150}},
+            'role': {'enum': ['user', 'staff']},
+        },
+        'required': ['name'],
+    }
+    assert SchemaValidator.from_json_schema(json_schema).json_schema() == json_schema
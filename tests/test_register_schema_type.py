@@ -0,0 +1,98 @@
+from decimal import Decimal
+
+import pytest
+
+from pydantic_core import (
+    SchemaError,
+    SchemaSerializer,
+    SchemaValidator,
+    ValidationError,
+    clear_schema_types,
+    core_schema,
+    register_schema_type,
+    unregister_schema_type,
+)
+
+
+def build_money(schema):
+    return core_schema.decimal_schema(ge=schema.get('min', 0), decimal_places=2)
+
+
+@pytest.fixture
+def schema_types():
+    yield
+    clear_schema_types()
+
+
+@pytest.fixture
+def money(schema_types):
+    register_schema_type('money', build_money)
+
+
+def test_custom_type(money):
+    v = SchemaValidator(core_schema.list_schema({'type': 'money'}))
+    assert v.validate_python(['1.50', 2]) == [Decimal('1.50'), Decimal(2)]
+    with pytest.raises(ValidationError, match='Input should be greater than or equal to 0'):
+        v.validate_python(['-1'])
+
+    v = SchemaValidator(
+        core_schema.typed_dict_schema({'price': core_schema.typed_dict_field({'type': 'money', 'min': 10})})
+    )
+    assert v.validate_python({'price': '10.5'}) == {'price': Decimal('10.5')}
+    with pytest.raises(ValidationError, match='Input should be greater than or equal to 10'):
+        v.validate_python({'price': 5})
+
+
+def test_custom_type_serializer(money):
+    s = SchemaSerializer(core_schema.list_schema({'type': 'money'}))
+    assert s.to_json([Decimal('1.50')]) == b'["1.50"]'
+
+
+def test_custom_type_chain(money):
+    register_schema_type('positive_money', lambda schema: {'type': 'money', 'min': Decimal('0.01')})
+    v = SchemaValidator({'type': 'positive_money'})
+    assert v.validate_python('0.01') == Decimal('0.01')
+    with pytest.raises(ValidationError, match='Input should be greater than or equal to 0.01'):
+        v.validate_python(0)
+
+
+def test_custom_type_cycle(schema_types):
+    register_schema_type('cycle_a', lambda schema: {'type': 'cycle_b'})
+    register_schema_type('cycle_b', lambda schema: {'type': 'cycle_a'})
+    with pytest.raises(SchemaError, match='Custom schema type `cycle_a` expands to itself'):
+        SchemaValidator({'type': 'cycle_a'})
+
+
+def test_custom_type_invalid(schema_types):
+    register_schema_type('not_a_dict', lambda schema: 123)
+    with pytest.raises(SchemaError, match='Build function of custom schema type `not_a_dict` must return a dict'):
+        SchemaValidator({'type': 'not_a_dict'})
+
+    with pytest.raises(SchemaError, match='Unknown schema type: "not_registered"'):
+        SchemaValidator({'type': 'not_registered'})
+
+
+def test_register_invalid(schema_types):
+    with pytest.raises(ValueError, match='`int` is a built-in schema type and can\'t be registered'):
+        register_schema_type('int', build_money)
+    with pytest.raises(TypeError, match='`build` must be callable'):
+        register_schema_type('money_2', 'not callable')
+
+
+def test_unregister(money):
+    v = SchemaValidator({'type': 'money'})
+    unregister_schema_type('money')
+    with pytest.raises(SchemaError, match='Unknown schema type: "money"'):
+        SchemaValidator({'type': 'money'})
+    # validators which have already been built still work
+    assert v.validate_python('1.5') == Decimal('1.5')
+    with pytest.raises(KeyError, match="`money` isn't a registered schema type"):
+        unregister_schema_type('money')
+
+
+def test_clear(money):
+    register_schema_type('money_2', build_money)
+    clear_schema_types()
+    for schema_type in ('money', 'money_2'):
+        with pytest.raises(SchemaError, match=f'Unknown schema type: "{schema_type}"'):
+            SchemaValidator({'type': schema_type})
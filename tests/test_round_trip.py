@@ -0,0 +1,147 @@
+import math
+from datetime import date, datetime, time, timedelta, timezone
+from decimal import Decimal
+
+import pytest
+
+from pydantic_core import SchemaSerializer, SchemaValidator, ValidationError, core_schema
+
+
+@pytest.mark.parametrize(
+    'schema,value',
+    [
+        (core_schema.set_schema(core_schema.int_schema()), {1, 2, 3}),
+        (core_schema.frozenset_schema(core_schema.str_schema()), frozenset({'a', 'b'})),
+        (core_schema.tuple_schema([core_schema.int_schema(), core_schema.str_schema()]), (1, 'a')),
+        (core_schema.tuple_schema([core_schema.int_schema()], variadic_item_index=0), (1, 2, 3)),
+        (core_schema.tuple_schema([]), ()),
+        (
+            core_schema.set_schema(core_schema.tuple_schema([core_schema.int_schema(), core_schema.int_schema()])),
+            {(1, 2), (3, 4)},
+        ),
+        (core_schema.bytes_schema(), b'hello'),
+        (core_schema.decimal_schema(), Decimal('1.10')),
+        (core_schema.decimal_schema(), Decimal('-1E+100')),
+        (core_schema.datetime_schema(), datetime(2022, 6, 8, 12, 13, 14, 15)),
+        (core_schema.datetime_schema(), datetime(2022, 6, 8, 12, 13, 14, tzinfo=timezone(timedelta(hours=-5)))),
+        (core_schema.date_schema(), date(2022, 6, 8)),
+        (core_schema.time_schema(), time(12, 13, 14, 15)),
+        (core_schema.timedelta_schema(), timedelta(days=-1, seconds=5, microseconds=6)),
+        (
+            core_schema.dict_schema(core_schema.datetime_schema(), core_schema.decimal_schema()),
+            {datetime(2022, 6, 8): Decimal('1.5')},
+        ),
+    ],
+)
+def test_round_trip(schema, value):
+    s = SchemaSerializer(schema)
+    v = SchemaValidator(schema)
+    assert v.validate_json(s.to_json(value)) == value
+    assert v.validate_python(s.to_python(value, mode='json')) == value
+
+
+@pytest.mark.parametrize(
+    'mode,value,expected_json',
+    [
+        ('utf8', b'hello', b'"hello"'),
+        ('base64', b'\x00\xff\xfe', b'"AP_-"'),
+        ('hex', b'\x00\xff\xfe', b'"00fffe"'),
+    ],
+)
+def test_bytes_round_trip(mode, value, expected_json):
+    config = core_schema.CoreConfig(ser_json_bytes=mode, val_json_bytes=mode)
+    s = SchemaSerializer(core_schema.bytes_schema(), config)
+    v = SchemaValidator(core_schema.bytes_schema(), config)
+    assert s.to_json(value) == expected_json
+    assert v.validate_json(expected_json) == value
+
+
+def test_bytes_base64_standard_alphabet():
+    v = SchemaValidator(core_schema.bytes_schema(), core_schema.CoreConfig(val_json_bytes='base64'))
+    assert v.validate_json('"AP/+"') == b'\x00\xff\xfe'
+    # python inputs are never decoded
+    assert v.validate_python('AP/+') == b'AP/+'
+
+
+def test_bytes_decode_constrained():
+    v = SchemaValidator(core_schema.bytes_schema(max_length=3), core_schema.CoreConfig(val_json_bytes='hex'))
+    assert v.validate_json('"00fffe"') == b'\x00\xff\xfe'
+    with pytest.raises(ValidationError, match='Data should have at most 3 bytes'):
+        v.validate_json('"00fffe00"')
+
+
+@pytest.mark.parametrize(
+    'mode,input_value,encoding_error',
+    [
+        ('base64', '"ab!c"', 'Invalid byte 33, offset 2.'),
+        ('hex', '"abc"', 'Odd number of digits'),
+        ('hex', '"zz"', 'Invalid hex digits "zz"'),
+    ],
+)
+def test_bytes_invalid_encoding(mode, input_value, encoding_error):
+    v = SchemaValidator(core_schema.bytes_schema(), core_schema.CoreConfig(val_json_bytes=mode))
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_json(input_value)
+    assert exc_info.value.errors(include_url=False) == [
+        {
+            'type': 'bytes_invalid_encoding',
+            'loc': (),
+            'msg': f'Data should be valid {mode}: {encoding_error}',
+            'input': input_value.strip('"'),
+            'ctx': {'encoding': mode, 'encoding_error': encoding_error},
+        }
+    ]
+
+
+def test_decimal_inf_nan_round_trip():
+    """
+    Non-finite decimals serialize as `"NaN"`, `"Infinity"` etc. which are only accepted back with `allow_inf_nan`.
+    """
+    s = SchemaSerializer(core_schema.decimal_schema())
+    assert s.to_json(Decimal('NaN')) == b'"NaN"'
+    assert s.to_json(Decimal('-Infinity')) == b'"-Infinity"'
+
+    with pytest.raises(ValidationError, match='Input should be a finite number'):
+        SchemaValidator(core_schema.decimal_schema()).validate_json('"NaN"')
+
+    v = SchemaValidator(core_schema.decimal_schema(allow_inf_nan=True))
+    assert v.validate_json(s.to_json(Decimal('NaN'))).is_nan()
+    assert v.validate_json(s.to_json(Decimal('-Infinity'))) == Decimal('-Infinity')
+
+
+def test_float_inf_nan_round_trip():
+    """
+    With the default `ser_json_inf_nan='null'` infinity and NaN can't round-trip, `'constants'` is required.
+    """
+    v = SchemaValidator(core_schema.float_schema())
+    assert SchemaSerializer(core_schema.float_schema()).to_json(math.inf) == b'null'
+
+    s = SchemaSerializer(core_schema.float_schema(), core_schema.CoreConfig(ser_json_inf_nan='constants'))
+    assert v.validate_json(s.to_json(-math.inf)) == -math.inf
+    assert math.isnan(v.validate_json(s.to_json(math.nan)))
+
+
+@pytest.mark.parametrize(
+    'schema,value,expected_json,type_name',
+    [
+        (
+            core_schema.datetime_schema(),
+            datetime(2022, 6, 8, 12, 13, 14, tzinfo=timezone(timedelta(minutes=19, seconds=32))),
+            b'"2022-06-08T12:13:14+00:19"',
+            'datetime',
+        ),
+        (
+            core_schema.time_schema(),
+            time(12, 13, 14, tzinfo=timezone(timedelta(minutes=19, seconds=32))),
+            b'"12:13:14+00:19"',
+            'time',
+        ),
+    ],
+)
+def test_sub_minute_offset_warning(schema, value, expected_json, type_name):
+    s = SchemaSerializer(schema)
+    msg = f'`{type_name}` has a UTC offset of 1172 seconds which isn\'t a whole number of minutes'
+    with pytest.warns(UserWarning, match=msg):
+        assert s.to_json(value) == expected_json
+    with pytest.warns(UserWarning, match=msg):
+        assert s.to_python(value, mode='json') == expected_json.decode().strip('"')
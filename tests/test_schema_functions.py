@@ -91,6 +91,11 @@ all_schema_functions = [
     (core_schema.list_schema, args(), {'type': 'list'}),
     (core_schema.list_schema, args({'type': 'int'}), {'type': 'list', 'items_schema': {'type': 'int'}}),
     (core_schema.tuple_schema, args([]), {'type': 'tuple', 'items_schema': []}),
+    (
+        core_schema.interval_schema,
+        args({'type': 'int'}, allow_equal=False),
+        {'type': 'interval', 'items_schema': {'type': 'int'}, 'allow_equal': False},
+    ),
     (
         core_schema.set_schema,
         args({'type': 'int'}, min_length=4),
@@ -201,6 +206,11 @@ all_schema_functions = [
         args({'foo': core_schema.model_field({'type': 'int'})}),
         {'type': 'model-fields', 'fields': {'foo': {'type': 'model-field', 'schema': {'type': 'int'}}}},
     ),
+    (
+        core_schema.conditional_required,
+        args('status', 'rejected', ['reason']),
+        {'if': {'field': 'status', 'equals': 'rejected'}, 'then_required': ['reason']},
+    ),
     (
         core_schema.model_schema,
         args(MyModel, {'type': 'int'}),
@@ -245,6 +255,8 @@ all_schema_functions = [
         },
     ),
     (core_schema.json_schema, args({'type': 'int'}), {'type': 'json', 'schema': {'type': 'int'}}),
+    (core_schema.json_string_schema, args(), {'type': 'json-string'}),
+    (core_schema.json_raw_schema, args({'type': 'int'}), {'type': 'json-raw', 'schema': {'type': 'int'}}),
     (core_schema.url_schema, args(), {'type': 'url'}),
     (core_schema.multi_host_url_schema, args(), {'type': 'multi-host-url'}),
     (
@@ -288,8 +300,10 @@ all_schema_functions = [
         {'type': 'dataclass', 'schema': {'type': 'int'}, 'fields': ['foobar'], 'cls': MyDataclass, 'slots': True},
     ),
     (core_schema.uuid_schema, args(), {'type': 'uuid'}),
+    (core_schema.email_schema, args(), {'type': 'email'}),
     (core_schema.decimal_schema, args(), {'type': 'decimal'}),
     (core_schema.decimal_schema, args(multiple_of=5, gt=1.2), {'type': 'decimal', 'multiple_of': 5, 'gt': 1.2}),
+    (core_schema.fraction_schema, args(), {'type': 'fraction'}),
 ]
 
 
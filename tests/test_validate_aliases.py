@@ -0,0 +1,105 @@
+import pytest
+
+from pydantic_core import SchemaValidator, ValidationError, core_schema
+
+
+class MyModel:
+    __slots__ = '__dict__', '__pydantic_fields_set__', '__pydantic_extra__', '__pydantic_private__'
+
+
+@pytest.fixture
+def typed_dict_schema():
+    return core_schema.typed_dict_schema(
+        {
+            'a': core_schema.typed_dict_field(core_schema.int_schema(), validation_alias='A'),
+            'b': core_schema.typed_dict_field(core_schema.int_schema(), validation_alias=[['b'], ['x', 'y']]),
+            'c': core_schema.typed_dict_field(core_schema.with_default_schema(core_schema.int_schema(), default=0)),
+        },
+        populate_by_name=True,
+    )
+
+
+def test_report_aliases_typed_dict(typed_dict_schema):
+    v = SchemaValidator(typed_dict_schema)
+    report = v.validate_python({'A': 1, 'b': 2, 'c': 3}, report_aliases=True)
+    assert (report.value, report.aliases) == (
+        {'a': 1, 'b': 2, 'c': 3},
+        {'a': 'A', 'b': 'b', 'c': 'c'},
+    )
+    report = v.validate_python({'a': 1, 'x': {'y': 2}}, report_aliases=True)
+    assert (report.value, report.aliases) == (
+        {'a': 1, 'b': 2, 'c': 0},
+        {'a': 'a', 'b': ['x', 'y'], 'c': None},
+    )
+
+
+def test_report_aliases_model():
+    v = SchemaValidator(
+        core_schema.model_schema(
+            MyModel,
+            core_schema.model_fields_schema(
+                {
+                    'a': core_schema.model_field(core_schema.int_schema(), validation_alias=['items', 1]),
+                    'b': core_schema.model_field(core_schema.int_schema()),
+                }
+            ),
+        )
+    )
+    report = v.validate_python({'items': [0, 1], 'b': 2}, report_aliases=True)
+    assert report.value.__dict__ == {'a': 1, 'b': 2}
+    assert report.aliases == {'a': ['items', 1], 'b': 'b'}
+
+
+def test_report_aliases_outermost():
+    inner = core_schema.typed_dict_schema(
+        {'x': core_schema.typed_dict_field(core_schema.int_schema(), validation_alias='X')}
+    )
+    v = SchemaValidator(
+        core_schema.typed_dict_schema({'inner': core_schema.typed_dict_field(inner, validation_alias='Inner')})
+    )
+    report = v.validate_python({'Inner': {'X': 1}}, report_aliases=True)
+    assert (report.value, report.aliases) == ({'inner': {'x': 1}}, {'inner': 'Inner'})
+
+
+def test_report_aliases_last_recorded():
+    item = core_schema.typed_dict_schema(
+        {'x': core_schema.typed_dict_field(core_schema.int_schema(), validation_alias=[['X'], ['x']])}
+    )
+    v = SchemaValidator(core_schema.list_schema(item))
+    report = v.validate_python([{'X': 1}, {'x': 2}], report_aliases=True)
+    assert (report.value, report.aliases) == ([{'x': 1}, {'x': 2}], {'x': 'x'})
+
+
+def test_report_aliases_case_insensitive():
+    v = SchemaValidator(
+        core_schema.typed_dict_schema(
+            {
+                'content_type': core_schema.typed_dict_field(core_schema.str_schema(), validation_alias='Content-Type'),
+                'page': core_schema.typed_dict_field(core_schema.int_schema(), validation_alias=['Query', 'page']),
+            },
+            case_insensitive=True,
+        )
+    )
+    report = v.validate_python({'content-type': 'text/html', 'QUERY': {'page': 1}}, report_aliases=True)
+    assert (report.value, report.aliases) == (
+        {'content_type': 'text/html', 'page': 1},
+        {'content_type': 'content-type', 'page': ['QUERY', 'page']},
+    )
+
+
+def test_report_aliases_no_fields():
+    v = SchemaValidator(core_schema.int_schema())
+    report = v.validate_python('1', report_aliases=True)
+    assert (report.value, report.aliases) == (1, None)
+
+
+def test_report_aliases_with_other_reports(typed_dict_schema):
+    v = SchemaValidator(typed_dict_schema)
+    report = v.validate_python({'a': '1', 'b': 2}, report_exactness=True, report_aliases=True)
+    assert (report.value, report.exactness, report.aliases) == (
+        {'a': 1, 'b': 2, 'c': 0},
+        'lax',
+        {'a': 'a', 'b': 'b', 'c': None},
+    )
+    with pytest.raises(ValidationError, match='Field required'):
+        v.validate_python({'a': 1}, report_aliases=True)
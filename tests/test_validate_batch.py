@@ -0,0 +1,70 @@
+import pytest
+
+from pydantic_core import CoreConfig, SchemaValidator, ValidationError, core_schema
+
+
+def test_batch():
+    v = SchemaValidator(core_schema.int_schema())
+    assert v.validate_python_batch([1, '2', 3.0]) == [(1, None), (2, None), (3, None)]
+    assert v.validate_python_batch(iter(['4', 5])) == [(4, None), (5, None)]
+    assert v.validate_python_batch([]) == []
+
+
+def test_batch_errors():
+    v = SchemaValidator(
+        core_schema.typed_dict_schema({'a': core_schema.typed_dict_field(core_schema.int_schema())}),
+    )
+    results = v.validate_python_batch([{'a': 1}, {'a': 'x'}, {}, {'a': '3'}])
+    assert results[0] == ({'a': 1}, None)
+    value, error = results[1]
+    assert value is None
+    assert isinstance(error, ValidationError)
+    assert [(e['type'], e['loc']) for e in error.errors()] == [('int_parsing', ('a',))]
+    value, error = results[2]
+    assert value is None
+    assert [(e['type'], e['loc']) for e in error.errors()] == [('missing', ('a',))]
+    assert results[3] == ({'a': 3}, None)
+
+
+def test_batch_error_values():
+    # an input which validates to a `ValidationError` isn't mistaken for a failure
+    exc = ValidationError.from_exception_data('Foo', [])
+    v = SchemaValidator(core_schema.any_schema())
+    assert v.validate_python_batch([exc, None]) == [(exc, None), (None, None)]
+
+
+def test_batch_strict_context():
+    def f(input_value, info):
+        return input_value * info.context['multiplier']
+
+    v = SchemaValidator(core_schema.with_info_after_validator_function(f, core_schema.int_schema()))
+    assert v.validate_python_batch([1, 2], context={'multiplier': 10}) == [(10, None), (20, None)]
+    results = v.validate_python_batch([1, '2'], strict=True, context={'multiplier': 10})
+    assert results[0] == (10, None)
+    assert isinstance(results[1][1], ValidationError)
+
+
+def test_batch_max_errors():
+    v = SchemaValidator(core_schema.list_schema(core_schema.int_schema()), CoreConfig(max_errors=1))
+    results = v.validate_python_batch([['a', 'b'], ['c', 'd', 'e']])
+    # omitted errors are counted separately for each input
+    assert [[e['type'] for e in error.errors()] for _, error in results] == [
+        ['int_parsing', 'too_many_errors'],
+        ['int_parsing', 'too_many_errors'],
+    ]
+    assert [error.errors()[1]['ctx'] for _, error in results] == [{'omitted': 1}, {'omitted': 2}]
+
+
+def test_batch_internal_error():
+    def f(input_value):
+        raise RuntimeError('boom')
+
+    v = SchemaValidator(core_schema.no_info_plain_validator_function(f))
+    with pytest.raises(RuntimeError, match='boom'):
+        v.validate_python_batch([1, 2])
+
+
+def test_batch_not_iterable():
+    v = SchemaValidator(core_schema.int_schema())
+    with pytest.raises(TypeError):
+        v.validate_python_batch(1)
@@ -0,0 +1,56 @@
+import pytest
+
+from pydantic_core import SchemaValidator, core_schema
+
+
+def test_canonicalize():
+    v = SchemaValidator(core_schema.any_schema())
+    value = v.validate_python({'b': [{'z': 1, 'y': 2}], 'a': ({'d': 3, 'c': 4},)}, canonicalize=True)
+    assert value == {'a': ({'c': 4, 'd': 3},), 'b': [{'y': 2, 'z': 1}]}
+    assert list(value) == ['a', 'b']
+    assert list(value['a'][0]) == ['c', 'd']
+    assert list(value['b'][0]) == ['y', 'z']
+
+
+def test_canonicalize_equal_inputs():
+    v = SchemaValidator(core_schema.dict_schema(core_schema.str_schema(), core_schema.int_schema()))
+    a = v.validate_python({'x': 1, 'y': '2'}, canonicalize=True)
+    b = v.validate_python({'y': 2, 'x': 1}, canonicalize=True)
+    assert repr(a) == repr(b) == "{'x': 1, 'y': 2}"
+    assert list(v.validate_python({'y': 2, 'x': 1})) == ['y', 'x']
+
+
+def test_canonicalize_input_unchanged():
+    v = SchemaValidator(core_schema.any_schema())
+    input_value = {'b': 1, 'a': 2}
+    assert list(v.validate_python(input_value, canonicalize=True)) == ['a', 'b']
+    assert list(input_value) == ['b', 'a']
+
+
+def test_canonicalize_sets():
+    v = SchemaValidator(core_schema.any_schema())
+    value = v.validate_python({'s': {3, 1, 2}, 'f': frozenset({'b', 'a'}), 'n': [{'y', 'x'}]}, canonicalize='sets')
+    assert value == {'f': ('a', 'b'), 'n': [['x', 'y']], 's': [1, 2, 3]}
+    assert v.validate_python({'s': {3, 1, 2}}, canonicalize=True) == {'s': {1, 2, 3}}
+
+
+def test_canonicalize_unorderable():
+    v = SchemaValidator(core_schema.any_schema())
+    with pytest.warns(UserWarning, match='Unable to sort dict keys while canonicalizing, keeping their order'):
+        value = v.validate_python({'b': {'z': 1, 'y': 2}, 1: 'x'}, canonicalize=True)
+    assert list(value) == ['b', 1]
+    # nested dicts are still sorted
+    assert list(value['b']) == ['y', 'z']
+
+
+def test_canonicalize_with_hash():
+    v = SchemaValidator(core_schema.any_schema())
+    report = v.validate_python({'b': 1, 'a': 2}, canonicalize=True, with_hash=True)
+    assert list(report.value) == ['a', 'b']
+    assert report.hash == v.validate_python({'a': 2, 'b': 1}, with_hash=True).hash
+
+
+def test_canonicalize_invalid():
+    v = SchemaValidator(core_schema.any_schema())
+    with pytest.raises(ValueError, match="Invalid `canonicalize` value 'dicts', expected a bool or 'sets'"):
+        v.validate_python({}, canonicalize='dicts')
@@ -0,0 +1,168 @@
+import re
+
+import pytest
+
+from pydantic_core import SchemaValidator, ValidationError, core_schema
+
+
+def test_collect_nested():
+    v = SchemaValidator(
+        core_schema.typed_dict_schema(
+            {
+                'a': core_schema.typed_dict_field(core_schema.list_schema(core_schema.int_schema())),
+                'b': core_schema.typed_dict_field(
+                    core_schema.dict_schema(core_schema.str_schema(), core_schema.int_schema())
+                ),
+                'c': core_schema.typed_dict_field(core_schema.str_schema()),
+                'd': core_schema.typed_dict_field(core_schema.int_schema()),
+            }
+        )
+    )
+    value, errors = v.validate_python({'a': [1, 'x', '3'], 'b': {'p': 1, 'q': 'y'}, 'c': 123}, on_error='collect')
+    assert value == {'a': [1, None, 3], 'b': {'p': 1, 'q': None}, 'c': None, 'd': None}
+    assert isinstance(errors, ValidationError)
+    assert [(e['type'], e['loc']) for e in errors.errors()] == [
+        ('int_parsing', ('a', 1)),
+        ('int_parsing', ('b', 'q')),
+        ('string_type', ('c',)),
+        ('missing', ('d',)),
+    ]
+
+
+def test_collect_valid():
+    v = SchemaValidator(core_schema.list_schema(core_schema.int_schema()))
+    assert v.validate_python([1, '2'], on_error='collect') == ([1, 2], None)
+
+
+def test_collect_top_level():
+    v = SchemaValidator(core_schema.list_schema(core_schema.int_schema()))
+    value, errors = v.validate_python('x', on_error='collect')
+    assert value is None
+    assert [e['type'] for e in errors.errors()] == ['list_type']
+
+
+def test_collect_tuple():
+    v = SchemaValidator(core_schema.tuple_schema([core_schema.int_schema(), core_schema.str_schema()]))
+    value, errors = v.validate_python(['x', 'y'], on_error='collect')
+    assert value == (None, 'y')
+    assert [e['loc'] for e in errors.errors()] == [(0,)]
+
+
+def test_collect_model():
+    class MyModel:
+        __slots__ = '__dict__', '__pydantic_fields_set__', '__pydantic_extra__', '__pydantic_private__'
+
+    v = SchemaValidator(
+        core_schema.model_schema(
+            MyModel,
+            core_schema.model_fields_schema(
+                {
+                    'x': core_schema.model_field(core_schema.list_schema(core_schema.int_schema())),
+                    'y': core_schema.model_field(core_schema.str_schema()),
+                }
+            ),
+        )
+    )
+    m, errors = v.validate_python({'x': [1, 'a']}, on_error='collect')
+    assert isinstance(m, MyModel)
+    assert m.__dict__ == {'x': [1, None], 'y': None}
+    assert [(e['type'], e['loc']) for e in errors.errors()] == [('int_parsing', ('x', 1)), ('missing', ('y',))]
+
+
+def test_collect_union_whole():
+    v = SchemaValidator(
+        core_schema.list_schema(
+            core_schema.union_schema([core_schema.list_schema(core_schema.int_schema()), core_schema.str_schema()])
+        )
+    )
+    value, errors = v.validate_python([[1, 'x'], 'a'], on_error='collect')
+    assert value == [None, 'a']
+    assert [e['loc'] for e in errors.errors()] == [(0, 'list[int]', 1), (0, 'str')]
+
+
+def test_collect_function_not_called_with_placeholder():
+    calls = []
+
+    def f(value):
+        calls.append(value)
+        return value
+
+    v = SchemaValidator(
+        core_schema.list_schema(
+            core_schema.no_info_after_validator_function(f, core_schema.list_schema(core_schema.int_schema()))
+        )
+    )
+    value, errors = v.validate_python([[1], [2, 'x']], on_error='collect')
+    assert value == [[1], None]
+    assert calls == [[1]]
+    assert [e['loc'] for e in errors.errors()] == [(1, 1)]
+
+
+def test_collect_list_unique_items():
+    v = SchemaValidator(core_schema.list_schema(core_schema.int_schema(), unique_items=True))
+    # the `None` placeholders of invalid items aren't duplicates of each other
+    value, errors = v.validate_python(['x', 'y', 1], on_error='collect')
+    assert value == [None, None, 1]
+    assert [(e['type'], e['loc']) for e in errors.errors()] == [('int_parsing', (0,)), ('int_parsing', (1,))]
+
+    value, errors = v.validate_python(['x', 1, '1'], on_error='collect')
+    assert value is None
+    assert [(e['type'], e['loc']) for e in errors.errors()] == [('int_parsing', (0,)), ('list_unique_items', (2,))]
+
+    v = SchemaValidator(
+        core_schema.list_schema(core_schema.nullable_schema(core_schema.int_schema()), unique_items=True)
+    )
+    value, errors = v.validate_python([None, 'x'], on_error='collect')
+    assert value == [None, None]
+    assert [e['loc'] for e in errors.errors()] == [(1,)]
+
+
+def test_collect_dict_keys():
+    v = SchemaValidator(core_schema.dict_schema(core_schema.int_schema(), core_schema.str_schema()))
+    # entries with invalid keys are dropped rather than the whole dict
+    value, errors = v.validate_python({'x': 'a', 1: 'b', 2: 3}, on_error='collect')
+    assert value == {1: 'b', 2: None}
+    assert [(e['type'], e['loc']) for e in errors.errors()] == [
+        ('int_parsing', ('x', '[key]')),
+        ('string_type', (2,)),
+    ]
+
+
+@pytest.mark.parametrize('schema', [core_schema.set_schema, core_schema.frozenset_schema])
+def test_collect_set_items(schema):
+    v = SchemaValidator(schema(core_schema.int_schema()))
+    value, errors = v.validate_python(['x', 1, '2'], on_error='collect')
+    assert value == {1, 2}
+    assert [(e['type'], e['loc']) for e in errors.errors()] == [('int_parsing', (0,))]
+
+    v = SchemaValidator(
+        core_schema.list_schema(schema(core_schema.tuple_schema([core_schema.int_schema(), core_schema.int_schema()])))
+    )
+    # invalid items are dropped as a whole, rather than including `None` placeholders
+    value, errors = v.validate_python([[(1, 'x'), (1, 2)]], on_error='collect')
+    assert value == [{(1, 2)}]
+    assert [e['loc'] for e in errors.errors()] == [(0, 0, 1)]
+
+
+def test_collect_with_default_on_error():
+    v = SchemaValidator(
+        core_schema.list_schema(
+            core_schema.with_default_schema(
+                core_schema.list_schema(core_schema.int_schema()), default=[], on_error='default'
+            )
+        )
+    )
+    # the default replaces the whole inner list, rather than its invalid item being collected
+    assert v.validate_python([[1], [2, 'x']], on_error='collect') == ([[1], []], None)
+
+
+def test_collect_invalid_options():
+    v = SchemaValidator(core_schema.int_schema())
+    with pytest.raises(ValueError, match=re.escape("Invalid `on_error` value 'ignore', expected 'raise' or 'collect'")):
+        v.validate_python(1, on_error='ignore')
+    with pytest.raises(
+        ValueError,
+        match='cannot be combined with `with_hash`, `profile`, `report_union_variant`, `report_exactness` or '
+        '`report_aliases`',
+    ):
+        v.validate_python(1, on_error='collect', profile=True)
@@ -0,0 +1,72 @@
+from decimal import Decimal
+
+import pytest
+
+from pydantic_core import SchemaValidator, ValidationError, core_schema
+
+
+class MyInt(int):
+    pass
+
+
+class MyStr(str):
+    pass
+
+
+@pytest.mark.parametrize(
+    'schema,input_value,expected',
+    [
+        (core_schema.int_schema(), 1, (1, 'exact')),
+        (core_schema.int_schema(), MyInt(1), (1, 'strict')),
+        (core_schema.int_schema(), True, (1, 'lax')),
+        (core_schema.int_schema(), '1', (1, 'lax')),
+        (core_schema.str_schema(), 'a', ('a', 'exact')),
+        (core_schema.str_schema(), MyStr('a'), ('a', 'strict')),
+        (core_schema.decimal_schema(), Decimal('1.5'), (Decimal('1.5'), 'exact')),
+        (core_schema.decimal_schema(), '1.5', (Decimal('1.5'), 'lax')),
+        (core_schema.nullable_schema(core_schema.int_schema(), empty_str_as_none=True), '', (None, 'lax')),
+        (core_schema.list_schema(core_schema.int_schema()), [1, 2], ([1, 2], 'exact')),
+        (core_schema.list_schema(core_schema.int_schema()), [1, '2'], ([1, 2], 'lax')),
+        (core_schema.list_schema(core_schema.int_schema()), (1, 2), ([1, 2], 'lax')),
+        (
+            core_schema.typed_dict_schema({'a': core_schema.typed_dict_field(core_schema.float_schema())}),
+            {'a': 1.5},
+            ({'a': 1.5}, 'exact'),
+        ),
+        (
+            core_schema.typed_dict_schema({'a': core_schema.typed_dict_field(core_schema.float_schema())}),
+            {'a': '1.5'},
+            ({'a': 1.5}, 'lax'),
+        ),
+    ],
+)
+def test_report_exactness(schema, input_value, expected):
+    v = SchemaValidator(schema)
+    report = v.validate_python(input_value, report_exactness=True)
+    assert (report.value, report.exactness) == expected
+
+
+def test_report_exactness_union():
+    v = SchemaValidator(core_schema.union_schema([core_schema.int_schema(), core_schema.str_schema()]))
+    report = v.validate_python('1', report_exactness=True)
+    assert (report.value, report.exactness) == ('1', 'exact')
+    report = v.validate_python(1.0, report_exactness=True)
+    assert (report.value, report.exactness) == (1, 'lax')
+    report = v.validate_python(1.0, report_union_variant=True, report_exactness=True)
+    assert (report.value, report.union_variant, report.exactness) == (1, 0, 'lax')
+
+
+def test_report_exactness_strict():
+    v = SchemaValidator(core_schema.int_schema())
+    report = v.validate_python(MyInt(1), strict=True, report_exactness=True)
+    assert (report.value, report.exactness) == (1, 'strict')
+    with pytest.raises(ValidationError, match='Input should be a valid integer'):
+        v.validate_python('1', strict=True, report_exactness=True)
+
+
+def test_report_exactness_with_hash():
+    v = SchemaValidator(core_schema.int_schema())
+    report = v.validate_python('1', report_exactness=True, with_hash=True)
+    assert report.value == 1
+    assert report.exactness == 'lax'
+    assert isinstance(report.hash, int)
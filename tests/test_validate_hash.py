@@ -0,0 +1,112 @@
+import os
+import subprocess
+import sys
+from datetime import date
+from decimal import Decimal
+
+import pytest
+
+from pydantic_core import SchemaSerializer, SchemaValidator, ValidationError, core_schema
+
+
+def test_with_hash():
+    v = SchemaValidator(core_schema.dict_schema(core_schema.str_schema(), core_schema.int_schema()))
+    report = v.validate_python({'a': '1', 'b': 2}, with_hash=True)
+    assert report.value == {'a': 1, 'b': 2}
+    h = report.hash
+    assert isinstance(h, int)
+    assert report.profile is None
+    assert repr(report) == f"ValidationReport(value={{'a': 1, 'b': 2}}, hash={h})"
+    assert v.validate_python({'b': 2, 'a': 1}, with_hash=True).hash == h
+    assert v.validate_python({'a': 1, 'b': 3}, with_hash=True).hash != h
+    assert v.validate_python({'a': 1}) == {'a': 1}
+
+
+def test_with_hash_unpack():
+    v = SchemaValidator(core_schema.dict_schema(core_schema.str_schema(), core_schema.int_schema()))
+    value, h = v.validate_python({'a': '1', 'b': 2}, with_hash=True)
+    assert value == {'a': 1, 'b': 2}
+    assert h == v.validate_python({'b': 2, 'a': 1}, with_hash=True).hash
+
+    json_value, json_h = v.validate_json('{"b": 2, "a": 1}', with_hash=True)
+    assert json_value == {'a': 1, 'b': 2}
+    assert json_h == h
+    assert v.validate_json('{"a": 1}') == {'a': 1}
+
+
+def test_with_hash_invalid():
+    v = SchemaValidator(core_schema.int_schema())
+    with pytest.raises(ValidationError, match='Input should be a valid integer'):
+        v.validate_python('x', with_hash=True)
+
+
+@pytest.mark.parametrize(
+    'schema,a,b',
+    [
+        (core_schema.set_schema(core_schema.str_schema()), {'a', 'b', 'c'}, ['c', 'a', 'b']),
+        (core_schema.frozenset_schema(core_schema.int_schema()), frozenset({1, 2}), [2, 1]),
+        (
+            core_schema.list_schema(core_schema.dict_schema(core_schema.str_schema(), core_schema.any_schema())),
+            [{'x': 1, 'y': [1, 2]}],
+            [{'y': [1, 2], 'x': 1}],
+        ),
+    ],
+)
+def test_with_hash_order_insensitive(schema, a, b):
+    v = SchemaValidator(schema)
+    assert v.validate_python(a, with_hash=True).hash == v.validate_python(b, with_hash=True).hash
+
+
+@pytest.mark.parametrize(
+    'a,b',
+    [
+        ([1, 2], [2, 1]),
+        (['ab', 'c'], ['a', 'bc']),
+        ([1], ['1']),
+        ([1], [1.0]),
+        ([True], [1]),
+        ([None], ['']),
+        ([b'a'], ['a']),
+        ([[1, 2]], [1, 2]),
+    ],
+)
+def test_with_hash_differs(a, b):
+    v = SchemaValidator(core_schema.list_schema(core_schema.any_schema()))
+    assert v.validate_python(a, with_hash=True).hash != v.validate_python(b, with_hash=True).hash
+
+
+def test_with_hash_model():
+    class MyModel:
+        __slots__ = '__dict__', '__pydantic_fields_set__', '__pydantic_extra__', '__pydantic_private__'
+
+    schema = core_schema.model_schema(
+        MyModel,
+        core_schema.model_fields_schema(
+            {
+                'when': core_schema.model_field(core_schema.date_schema()),
+                'price': core_schema.model_field(core_schema.decimal_schema()),
+            }
+        ),
+    )
+    MyModel.__pydantic_serializer__ = SchemaSerializer(schema)
+    v = SchemaValidator(schema)
+    report = v.validate_python({'when': '2024-01-02', 'price': '1.5'}, with_hash=True)
+    m, h = report.value, report.hash
+    assert isinstance(m, MyModel)
+    assert m.__dict__ == {'when': date(2024, 1, 2), 'price': Decimal('1.5')}
+    assert v.validate_python({'price': 1.5, 'when': date(2024, 1, 2)}, with_hash=True).hash == h
+    assert v.validate_python({'when': '2024-01-03', 'price': '1.5'}, with_hash=True).hash != h
+
+
+def test_with_hash_deterministic():
+    code = (
+        'from pydantic_core import SchemaValidator, core_schema\n'
+        'v = SchemaValidator(core_schema.set_schema(core_schema.str_schema()))\n'
+        "print(v.validate_python({'foo', 'bar', 'spam'}, with_hash=True).hash)\n"
+    )
+    hashes = set()
+    for seed in '1', '2':
+        env = {**os.environ, 'PYTHONHASHSEED': seed}
+        output = subprocess.run([sys.executable, '-c', code], env=env, capture_output=True, check=True, text=True)
+        hashes.add(output.stdout)
+    assert len(hashes) == 1
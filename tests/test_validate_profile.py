@@ -0,0 +1,78 @@
+import pytest
+
+from pydantic_core import SchemaValidator, ValidationError, ValidationReport, core_schema
+
+
+def test_profile():
+    v = SchemaValidator(
+        core_schema.typed_dict_schema(
+            {
+                'a': core_schema.typed_dict_field(core_schema.list_schema(core_schema.int_schema())),
+                'b': core_schema.typed_dict_field(core_schema.str_schema()),
+            }
+        )
+    )
+    report = v.validate_python({'a': [1, '2', 3], 'b': 'x'}, profile=True)
+    assert report.value == {'a': [1, 2, 3], 'b': 'x'}
+    assert report.hash is None
+    profile = report.profile
+    assert list(profile) == ['int', 'list[int]', 'str', 'typed-dict']
+    assert {name: entry['calls'] for name, entry in profile.items()} == {
+        'int': 3,
+        'list[int]': 1,
+        'str': 1,
+        'typed-dict': 1,
+    }
+    assert all(isinstance(entry['time'], float) and entry['time'] >= 0 for entry in profile.values())
+    # times include nested validators
+    assert profile['typed-dict']['time'] >= profile['list[int]']['time'] >= profile['int']['time']
+
+
+def test_profile_simple():
+    v = SchemaValidator(core_schema.int_schema())
+    assert v.validate_python('1') == 1
+    report = v.validate_python('1', profile=True)
+    assert report.value == 1
+    assert report.profile == {'int': {'calls': 1, 'time': pytest.approx(0, abs=1)}}
+    assert repr(report).startswith("ValidationReport(value=1, profile={'int': {'calls': 1, 'time': ")
+
+
+def test_profile_with_hash():
+    v = SchemaValidator(core_schema.int_schema())
+    report = v.validate_python(1, with_hash=True, profile=True)
+    assert report.value == 1
+    assert report.hash == v.validate_python(1, with_hash=True).hash
+    assert list(report.profile) == ['int']
+
+
+def test_report_unpacks_like_tuple():
+    v = SchemaValidator(core_schema.int_schema())
+    report = v.validate_python('1', with_hash=True, profile=True)
+    assert isinstance(report, ValidationReport)
+    value, h, profile = report
+    assert (value, h, profile) == (1, report.hash, report.profile)
+    assert len(report) == 3
+    assert report[0] == 1
+    assert report[-1] is report.profile
+    assert report[:2] == (1, report.hash)
+    with pytest.raises(IndexError):
+        report[3]
+    assert report == (1, report.hash, report.profile)
+    assert report != (1, report.hash)
+
+
+def test_report_collect():
+    v = SchemaValidator(core_schema.int_schema())
+    report = v.validate_python('x', on_error='collect')
+    assert isinstance(report, ValidationReport)
+    value, errors = report
+    assert value is None
+    assert errors is report.errors
+    assert errors.errors(include_url=False)[0]['type'] == 'int_parsing'
+    assert v.validate_python('1', on_error='collect') == (1, None)
+
+
+def test_profile_error():
+    v = SchemaValidator(core_schema.int_schema())
+    with pytest.raises(ValidationError, match='Input should be a valid integer'):
+        v.validate_python('x', profile=True)
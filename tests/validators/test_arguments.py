@@ -926,7 +926,7 @@ def test_function_types():
     assert foobar(1, 2, c='3') == (1, 2, 3)
     assert foobar(a=1, b='2', c=3) == (1, 2, 3)
 
-    with pytest.raises(ValidationError, match='Unexpected positional argument'):
+    with pytest.raises(ValidationError, match="Keyword only argument 'c' passed as a positional argument"):
         foobar(1, 2, 3)
 
     with pytest.raises(ValidationError) as exc_info:
@@ -985,12 +985,12 @@ def create_function(validate, config = None):
         foobar('1', b=2, c=3)
     assert exc_info.value.errors(include_url=False) == [
         {
-            'type': 'missing_positional_only_argument',
-            'loc': (1,),
-            'msg': 'Missing required positional only argument',
-            'input': ArgsKwargs(('1',), {'b': 2, 'c': 3}),
-        },
-        {'type': 'unexpected_keyword_argument', 'loc': ('b',), 'msg': 'Unexpected keyword argument', 'input': 2},
+            'type': 'positional_only_argument_as_keyword',
+            'loc': ('b',),
+            'msg': "Positional only argument 'b' passed as a keyword argument",
+            'input': 2,
+            'ctx': {'parameter': 'b'},
+        }
     ]
     # Allowing extras using the config
     foobar = m.create_function(validate, config={'title': 'func', 'extra_fields_behavior': 'allow'})
@@ -1103,3 +1103,130 @@ def test_error_display(pydantic_version):
         '[{"type":"missing_argument","loc":["b"],"msg":"Missing required argument",'
         '"input":"ArgsKwargs((), {\'a\': 1})"}]'
     )
+
+
+def test_validate_call():
+    v = SchemaValidator(
+        core_schema.arguments_schema(
+            [
+                core_schema.arguments_parameter('a', core_schema.int_schema(), mode='positional_only'),
+                core_schema.arguments_parameter(
+                    'b', core_schema.with_default_schema(core_schema.str_schema(), default='x')
+                ),
+                core_schema.arguments_parameter(
+                    'c', core_schema.with_default_schema(core_schema.bool_schema(), default=False), mode='keyword_only'
+                ),
+            ]
+        )
+    )
+    result = v.validate_call(('1',))
+    assert isinstance(result, ArgsKwargs)
+    assert result == ArgsKwargs((1, 'x'), {'c': False})
+    # `b` is given by keyword but bound positionally
+    assert v.validate_call((1,), {'b': 'y', 'c': 'true'}) == ArgsKwargs((1, 'y'), {'c': True})
+    assert v.validate_call((1, 'y')) == ArgsKwargs((1, 'y'), {'c': False})
+
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_call(('a',), {'c': 'wrong'})
+    # insert_assert(exc_info.value.errors(include_url=False))
+    assert exc_info.value.errors(include_url=False) == [
+        {
+            'type': 'int_parsing',
+            'loc': (0,),
+            'msg': 'Input should be a valid integer, unable to parse string as an integer',
+            'input': 'a',
+        },
+        {
+            'type': 'bool_parsing',
+            'loc': ('c',),
+            'msg': 'Input should be a valid boolean, unable to interpret input',
+            'input': 'wrong',
+        },
+    ]
+
+
+def test_validate_call_var_args():
+    v = SchemaValidator(
+        core_schema.arguments_schema(
+            [core_schema.arguments_parameter('a', core_schema.int_schema())],
+            var_args_schema=core_schema.int_schema(),
+            var_kwargs_schema=core_schema.str_schema(),
+        )
+    )
+    assert v.validate_call((1, '2', 3), {'d': 'x'}) == ArgsKwargs((1, 2, 3), {'d': 'x'})
+    assert v.validate_call((), {'a': 1}) == ArgsKwargs((1,))
+
+
+def test_validate_call_not_arguments():
+    v = SchemaValidator(core_schema.int_schema())
+    with pytest.raises(TypeError, match='`validate_call` can only be used with an `arguments` schema'):
+        v.validate_call((1,))
+
+
+def test_positional_only_as_keyword():
+    v = SchemaValidator(
+        core_schema.arguments_schema(
+            [
+                core_schema.arguments_parameter('a', core_schema.int_schema(), mode='positional_only'),
+                core_schema.arguments_parameter(
+                    'b', core_schema.with_default_schema(core_schema.int_schema(), default=2), mode='positional_only'
+                ),
+            ]
+        )
+    )
+    assert v.validate_python(ArgsKwargs((1,))) == ((1, 2), {})
+
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python(ArgsKwargs((), {'a': 1, 'b': 3}))
+    # insert_assert(exc_info.value.errors(include_url=False))
+    assert exc_info.value.errors(include_url=False) == [
+        {
+            'type': 'positional_only_argument_as_keyword',
+            'loc': ('a',),
+            'msg': "Positional only argument 'a' passed as a keyword argument",
+            'input': 1,
+            'ctx': {'parameter': 'a'},
+        },
+        {
+            'type': 'positional_only_argument_as_keyword',
+            'loc': ('b',),
+            'msg': "Positional only argument 'b' passed as a keyword argument",
+            'input': 3,
+            'ctx': {'parameter': 'b'},
+        },
+    ]
+
+    # with `**kwargs` the keyword is collected there instead, as python does
+    v = SchemaValidator(
+        core_schema.arguments_schema(
+            [core_schema.arguments_parameter('a', core_schema.int_schema(), mode='positional_only')],
+            var_kwargs_schema=core_schema.int_schema(),
+        )
+    )
+    assert v.validate_python(ArgsKwargs((1,), {'a': 2})) == ((1,), {'a': 2})
+
+
+def test_keyword_only_as_positional():
+    v = SchemaValidator(
+        core_schema.arguments_schema(
+            [
+                core_schema.arguments_parameter('a', core_schema.int_schema()),
+                core_schema.arguments_parameter('b', core_schema.int_schema(), mode='keyword_only'),
+            ]
+        )
+    )
+    assert v.validate_python(ArgsKwargs((1,), {'b': 2})) == ((1,), {'b': 2})
+
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python(ArgsKwargs((1, 2, 3)))
+    # insert_assert(exc_info.value.errors(include_url=False))
+    assert exc_info.value.errors(include_url=False) == [
+        {
+            'type': 'keyword_only_argument_as_positional',
+            'loc': (1,),
+            'msg': "Keyword only argument 'b' passed as a positional argument",
+            'input': 2,
+            'ctx': {'parameter': 'b'},
+        },
+        {'type': 'unexpected_positional_argument', 'loc': (2,), 'msg': 'Unexpected positional argument', 'input': 3},
+    ]
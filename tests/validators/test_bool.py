@@ -79,12 +79,12 @@ def test_bool_repr():
     v = SchemaValidator({'type': 'bool'})
     assert (
         plain_repr(v)
-        == 'SchemaValidator(title="bool",validator=Bool(BoolValidator{strict:false}),definitions=[],cache_strings=True)'
+        == 'SchemaValidator(title="bool",validator=Bool(BoolValidator{strict:false,explain_strict:false,parse_options:StringParseOptions{trim:false,allow_underscores:true,allow_sign_whitespace:false,thousands_separator:None,decimal_separator:\'.\',strict_grouping:false,json_coerce_strings:true}}),definitions=[],cache_strings=True)'
     )
     v = SchemaValidator({'type': 'bool', 'strict': True})
     assert (
         plain_repr(v)
-        == 'SchemaValidator(title="bool",validator=Bool(BoolValidator{strict:true}),definitions=[],cache_strings=True)'
+        == 'SchemaValidator(title="bool",validator=Bool(BoolValidator{strict:true,explain_strict:false,parse_options:StringParseOptions{trim:false,allow_underscores:true,allow_sign_whitespace:false,thousands_separator:None,decimal_separator:\'.\',strict_grouping:false,json_coerce_strings:true}}),definitions=[],cache_strings=True)'
     )
 
 
@@ -107,3 +107,38 @@ def test_validate_assignment_not_supported() -> None:
     v = SchemaValidator(core_schema.bool_schema())
     with pytest.raises(TypeError, match='validate_assignment is not supported for bool'):
         v.validate_assignment(False, 'foo', True)
+
+
+def test_explain_strict():
+    v = SchemaValidator(core_schema.bool_schema(), core_schema.CoreConfig(strict=True, explain_strict=True))
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_json('"true"')
+    assert exc_info.value.errors(include_url=False)[0]['ctx'] == {
+        'explanation': (
+            'Input of type `string` was provided but strict mode requires `bool`, '
+            'use lax mode or pass a value of type `bool`'
+        )
+    }
+
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_json('"maybe"')
+    assert 'ctx' not in exc_info.value.errors()[0]
+
+
+def test_string_parse_options_trim():
+    v = SchemaValidator(core_schema.bool_schema())
+    with pytest.raises(ValidationError, match='Input should be a valid boolean, unable to interpret input'):
+        v.validate_python(' true ')
+
+    v = SchemaValidator(core_schema.bool_schema(string_parse_options={'trim': True}))
+    assert v.validate_python(' true ') is True
+    assert v.validate_json('" off\\n"') is False
+
+
+def test_json_coerce_strings():
+    v = SchemaValidator(core_schema.bool_schema(), {'json_coerce_strings': False})
+    assert v.validate_json('true') is True
+    assert v.validate_json('1') is True
+    assert v.validate_python('true') is True
+    with pytest.raises(ValidationError, match=r'Input should be a valid boolean \[type=bool_type,'):
+        v.validate_json('"true"')
@@ -3,7 +3,7 @@ from typing import Any, Dict
 
 import pytest
 
-from pydantic_core import SchemaValidator, ValidationError
+from pydantic_core import SchemaValidator, ValidationError, core_schema
 
 from ..conftest import Err, PyAndJson
 
@@ -121,3 +121,42 @@ def test_length_ctx():
             'ctx': {'max_length': 3},
         }
     ]
+
+
+@pytest.mark.parametrize('mode,encoded', [('utf8', 'abc'), ('base64', 'YWJj'), ('hex', '616263')])
+def test_max_length_json_encoded(mode, encoded):
+    v = SchemaValidator({'type': 'bytes', 'max_length': 3}, {'val_json_bytes': mode})
+    assert v.validate_json(f'"{encoded}"') == b'abc'
+    with pytest.raises(ValidationError, match='Data should have at most 3 bytes'):
+        v.validate_json(f'"{encoded * 1000}"')
+
+
+@pytest.mark.parametrize(
+    'mode,valid,too_long',
+    [('utf8', 'abcd', 'abcde'), ('base64', 'YWJjZA==', 'YWJjZGU='), ('hex', '61626364', '6162636465')],
+)
+def test_max_decoded_length(mode, valid, too_long):
+    v = SchemaValidator(core_schema.bytes_schema(max_decoded_length=4), {'val_json_bytes': mode})
+    assert v.validate_json(f'"{valid}"') == b'abcd'
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_json(f'"{too_long}"')
+    assert exc_info.value.errors(include_url=False) == [
+        {
+            'type': 'bytes_too_long',
+            'loc': (),
+            'msg': 'Data should have at most 4 bytes',
+            'input': too_long,
+            'ctx': {'max_length': 4},
+        }
+    ]
+    # python bytes aren't decoded so aren't limited
+    assert v.validate_python(b'abcdefgh') == b'abcdefgh'
+
+
+def test_max_decoded_length_not_decoded():
+    v = SchemaValidator(core_schema.bytes_schema(max_length=100, max_decoded_length=3), {'val_json_bytes': 'base64'})
+    # oversized strings are rejected before they're decoded, so invalid base64 isn't reported
+    with pytest.raises(ValidationError, match='Data should have at most 3 bytes'):
+        v.validate_json('"!!!!!!!!"')
+    with pytest.raises(ValidationError, match='Data should have at most 100 bytes'):
+        v.validate_python(b'x' * 101)
@@ -110,3 +110,51 @@ def test_chain_one():
     )
     assert validator.validate_python('input') == 'input-1'
     assert validator.title == 'function-plain[<lambda>()]'
+
+
+def test_chain_merge_constraints():
+    validator = SchemaValidator(
+        core_schema.chain_schema([core_schema.int_schema(gt=0), core_schema.int_schema(lt=100, multiple_of=5)])
+    )
+    # the steps are merged into a single validator
+    assert validator.title == 'constrained-int'
+    assert validator.validate_python('50') == 50
+    for input_value, error in [(0, 'greater than 0'), (100, 'less than 100'), ('3', 'multiple of 5')]:
+        with pytest.raises(ValidationError, match=error):
+            validator.validate_python(input_value)
+
+    validator = SchemaValidator(
+        core_schema.chain_schema([core_schema.float_schema(ge=0), core_schema.float_schema(le=1)])
+    )
+    assert validator.title == 'constrained-float'
+    assert validator.validate_python(0.5) == 0.5
+    with pytest.raises(ValidationError, match='less than or equal to 1'):
+        validator.validate_python(1.5)
+
+
+@pytest.mark.parametrize(
+    'steps',
+    [
+        # the same constraint on both
+        [core_schema.int_schema(gt=0), core_schema.int_schema(gt=10)],
+        # different options
+        [core_schema.int_schema(gt=0), core_schema.int_schema(lt=100, strict=True)],
+        [core_schema.float_schema(gt=0), core_schema.float_schema(lt=100, allow_inf_nan=False)],
+        # `None` from the first step isn't a valid float for the second
+        [core_schema.float_schema(gt=0, nan_as_none=True), core_schema.float_schema(lt=100, nan_as_none=True)],
+        # not adjacent
+        [core_schema.int_schema(gt=0), core_schema.str_schema(), core_schema.int_schema(lt=100)],
+    ],
+)
+def test_chain_merge_constraints_not_merged(steps):
+    validator = SchemaValidator(core_schema.chain_schema(steps))
+    assert validator.title.startswith('chain[')
+
+
+def test_chain_nan_as_none():
+    validator = SchemaValidator(
+        core_schema.chain_schema([core_schema.float_schema(nan_as_none=True), core_schema.float_schema(le=1)])
+    )
+    assert validator.validate_python(0.5) == 0.5
+    with pytest.raises(ValidationError, match='Input should be a valid number'):
+        validator.validate_python(float('nan'))
@@ -290,6 +290,22 @@ def test_date_past_future_today():
     assert v.isinstance_python(today + timedelta(days=1)) is True
 
 
+def test_date_now_override():
+    v = SchemaValidator(core_schema.date_schema(now_op='past', now_utc_offset=0))
+    now = datetime(2020, 1, 1, 12, tzinfo=timezone.utc)
+    assert v.validate_python(date(2019, 12, 31), now=now) == date(2019, 12, 31)
+    with pytest.raises(ValidationError, match=r'Date should be in the past \[type=date_past,'):
+        v.validate_python(date(2020, 1, 1), now=now)
+    # the real clock is used when no override is given
+    assert v.validate_python(date(2020, 1, 1)) == date(2020, 1, 1)
+
+    # aware overrides are converted to the UTC offset of the constraint before taking the date
+    v = SchemaValidator(core_schema.date_schema(now_op='future', now_utc_offset=-6 * 3600))
+    assert v.validate_python(date(2020, 1, 2), now=datetime(2020, 1, 2, 3, tzinfo=timezone.utc)) == date(2020, 1, 2)
+    with pytest.raises(ValidationError, match=r'Date should be in the future \[type=date_future,'):
+        v.validate_python(date(2020, 1, 2), now=datetime(2020, 1, 2, 3))
+
+
 def test_offset_too_large():
     with pytest.raises(SchemaError, match=r'Input should be less than 86400 \[type=less_than,'):
         validate_core_schema(core_schema.date_schema(now_op='past', now_utc_offset=24 * 3600))
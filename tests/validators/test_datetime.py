@@ -345,6 +345,22 @@ def test_datetime_past_timezone():
     assert v.isinstance_python(soon_utc.astimezone(zoneinfo.ZoneInfo('America/Los_Angeles')).replace(tzinfo=None))
 
 
+def test_datetime_now_override():
+    v = SchemaValidator(core_schema.datetime_schema(now_utc_offset=0, now_op='past'))
+    now = datetime(2020, 1, 1, 12, tzinfo=timezone.utc)
+    assert v.validate_python('2020-01-01T11:59:59Z', now=now) == datetime(2020, 1, 1, 11, 59, 59, tzinfo=timezone.utc)
+    with pytest.raises(ValidationError, match=r'Input should be in the past \[type=datetime_past,'):
+        v.validate_python('2020-01-01T12:00:01Z', now=now)
+    # naive overrides are taken to be in the constraint's UTC offset
+    with pytest.raises(ValidationError, match=r'Input should be in the past \[type=datetime_past,'):
+        v.validate_python('2020-01-01T12:00:01Z', now=datetime(2020, 1, 1, 12))
+    # the real clock is used when no override is given
+    assert v.validate_python('2020-01-01T12:00:01Z') == datetime(2020, 1, 1, 12, 0, 1, tzinfo=timezone.utc)
+
+    with pytest.raises(TypeError):
+        v.validate_python('2020-01-01T12:00:01Z', now='2020-01-01T12:00:00Z')
+
+
 @pytest.mark.parametrize(
     'input_value,expected',
     [
@@ -495,6 +511,139 @@ def test_tz_constraint_wrong():
         validate_core_schema(core_schema.datetime_schema(tz_constraint='wrong'))
 
 
+def test_require_utc(py_and_json: PyAndJson):
+    v = py_and_json(core_schema.datetime_schema(require_utc=True))
+    assert v.validate_test('2022-06-08T12:13:14Z') == datetime(2022, 6, 8, 12, 13, 14, tzinfo=timezone.utc)
+
+    with pytest.raises(ValidationError, match='Timezone offset of 0 required, got 3600') as exc_info:
+        v.validate_test('2022-06-08T12:13:14+01:00')
+    assert exc_info.value.errors(include_url=False) == [
+        {
+            'type': 'timezone_offset',
+            'loc': (),
+            'msg': 'Timezone offset of 0 required, got 3600',
+            'input': '2022-06-08T12:13:14+01:00',
+            'ctx': {'tz_expected': 0, 'tz_actual': 3600},
+        }
+    ]
+
+    with pytest.raises(ValidationError, match=r'Input should have timezone info \[type=timezone_aware,'):
+        v.validate_test('2022-06-08T12:13:14')
+
+
+def test_require_utc_python():
+    v = SchemaValidator(core_schema.datetime_schema(require_utc=True))
+    value = datetime.now(tz=timezone.utc)
+    assert value is v.validate_python(value)
+
+    with pytest.raises(ValidationError, match='Timezone offset of 0 required, got -7200'):
+        v.validate_python(datetime.now(tz=timezone(timedelta(hours=-2))))
+    with pytest.raises(ValidationError, match=r'Input should have timezone info \[type=timezone_aware,'):
+        v.validate_python(datetime.now())
+
+
+def test_coerce_to_utc(py_and_json: PyAndJson):
+    v = py_and_json(core_schema.datetime_schema(coerce_to_utc=True))
+    output = v.validate_test('2022-06-08T12:13:14+01:30')
+    assert output == datetime(2022, 6, 8, 10, 43, 14, tzinfo=timezone.utc)
+    assert output.utcoffset() == timedelta(0)
+    assert v.validate_test('2022-06-08T12:13:14Z') == datetime(2022, 6, 8, 12, 13, 14, tzinfo=timezone.utc)
+
+    with pytest.raises(ValidationError, match=r'Input should have timezone info \[type=timezone_aware,'):
+        v.validate_test('2022-06-08T12:13:14')
+
+
+def test_coerce_to_utc_python():
+    v = SchemaValidator(
+        core_schema.datetime_schema(coerce_to_utc=True, require_utc=True, lt=datetime(2023, 1, 1, tzinfo=timezone.utc))
+    )
+    value = datetime(2022, 6, 8, 12, 13, 14, tzinfo=timezone.utc)
+    assert value is v.validate_python(value)
+
+    output = v.validate_python(datetime(2022, 1, 1, 1, 0, 0, tzinfo=timezone(timedelta(hours=5))))
+    assert output == datetime(2021, 12, 31, 20, 0, 0, tzinfo=timezone.utc)
+    assert output.utcoffset() == timedelta(0)
+
+
+@pytest.mark.parametrize('option', ['require_utc', 'coerce_to_utc'])
+def test_utc_options_with_tz_constraint(option):
+    with pytest.raises(SchemaError, match=f'`{option}` cannot be combined with `tz_constraint`'):
+        SchemaValidator(core_schema.datetime_schema(tz_constraint='aware', **{option: True}))
+
+
+@pytest.mark.parametrize(
+    'input_value,expected',
+    [
+        ('2016-12-31T23:59:60Z', datetime(2016, 12, 31, 23, 59, 59, 999999, tzinfo=timezone.utc)),
+        ('2016-12-31 23:59:60.5', datetime(2016, 12, 31, 23, 59, 59, 999999)),
+        ('2016-12-31T23:59:59Z', datetime(2016, 12, 31, 23, 59, 59, tzinfo=timezone.utc)),
+        ('2016-12-31T23:59:61Z', Err('[type=datetime_from_date_parsing,')),
+        ('2020-06-01T12:30:60Z', Err('[type=datetime_from_date_parsing,')),
+        ('2020-06-01T23:30:60Z', Err('[type=datetime_from_date_parsing,')),
+    ],
+)
+def test_leap_second_clamp(py_and_json: PyAndJson, input_value, expected):
+    v = py_and_json(core_schema.datetime_schema(leap_second='clamp'))
+    if isinstance(expected, Err):
+        with pytest.raises(ValidationError, match=re.escape(expected.message)):
+            v.validate_test(input_value)
+    else:
+        assert v.validate_test(input_value) == expected
+
+
+@pytest.mark.parametrize('leap_second', [None, 'error'])
+def test_leap_second_error(py_and_json: PyAndJson, leap_second):
+    v = py_and_json(core_schema.datetime_schema(leap_second=leap_second))
+    with pytest.raises(ValidationError, match=r'\[type=datetime_from_date_parsing,'):
+        v.validate_test('2016-12-31T23:59:60Z')
+
+
+def test_leap_second_clamp_strict():
+    v = SchemaValidator(core_schema.datetime_schema(leap_second='clamp', strict=True))
+    with pytest.raises(ValidationError, match=r'Input should be a valid datetime \[type=datetime_type,'):
+        v.validate_python('2016-12-31T23:59:60Z')
+    assert v.validate_json('"2016-12-31T23:59:60Z"') == datetime(2016, 12, 31, 23, 59, 59, 999999, tzinfo=timezone.utc)
+
+
+def test_fold():
+    tz = zoneinfo.ZoneInfo('Europe/London')
+    # 01:30 happens twice when clocks go back
+    ambiguous = datetime(2022, 10, 30, 1, 30, tzinfo=tz)
+    unambiguous = datetime(2022, 6, 8, 12, 13, 14, tzinfo=tz)
+
+    v = SchemaValidator(core_schema.datetime_schema(fold='error'))
+    assert v.validate_python(unambiguous) is unambiguous
+    assert v.validate_python('2022-10-30T01:30:00+01:00') == datetime(
+        2022, 10, 30, 1, 30, tzinfo=timezone(timedelta(hours=1))
+    )
+    with pytest.raises(ValidationError, match=r'ambiguous local time \[type=datetime_ambiguous,'):
+        v.validate_python(ambiguous)
+
+    v = SchemaValidator(core_schema.datetime_schema(fold='earlier'))
+    assert v.validate_python(ambiguous.replace(fold=1)).fold == 0
+    assert v.validate_python(unambiguous.replace(fold=1)).fold == 1
+
+    v = SchemaValidator(core_schema.datetime_schema(fold='later'))
+    output = v.validate_python(ambiguous)
+    assert output.fold == 1
+    assert output.utcoffset() == timedelta(0)
+
+    assert SchemaValidator(core_schema.datetime_schema()).validate_python(ambiguous) is ambiguous
+
+
+def test_fold_coerce_to_utc():
+    v = SchemaValidator(core_schema.datetime_schema(fold='later', coerce_to_utc=True))
+    output = v.validate_python(datetime(2022, 10, 30, 1, 30, tzinfo=zoneinfo.ZoneInfo('Europe/London')))
+    assert output.utcoffset() == timedelta(0)
+    assert output.astimezone(timezone.utc) == datetime(2022, 10, 30, 1, 30, tzinfo=timezone.utc)
+
+
+@pytest.mark.parametrize('option', ['leap_second', 'fold'])
+def test_invalid_leap_second_fold(option):
+    with pytest.raises(SchemaError):
+        SchemaValidator({'type': 'datetime', option: 'nope'})
+
+
 def test_tz_hash() -> None:
     v = SchemaValidator(core_schema.datetime_schema())
     lookup: Dict[datetime, str] = {}
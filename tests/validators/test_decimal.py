@@ -9,7 +9,7 @@ from typing import Any
 import pytest
 from dirty_equals import FunctionCheck, IsStr
 
-from pydantic_core import SchemaValidator, ValidationError
+from pydantic_core import SchemaValidator, ValidationError, core_schema
 
 from ..conftest import Err, PyAndJson, plain_repr
 
@@ -467,3 +467,20 @@ def test_validate_max_digits_and_decimal_places_edge_case() -> None:
     assert v.validate_python(Decimal('9999999999999999.999999999999999999')) == Decimal(
         '9999999999999999.999999999999999999'
     )
+
+
+def test_allow_bool(py_and_json: PyAndJson):
+    v = py_and_json(core_schema.decimal_schema())
+    with pytest.raises(ValidationError, match=r'Decimal input should be an integer, float, string or Decimal object'):
+        v.validate_test(True)
+
+    v = py_and_json(core_schema.decimal_schema(allow_bool=True))
+    assert v.validate_test(True) == Decimal(1)
+    assert v.validate_test(False) == Decimal(0)
+
+    v = py_and_json(core_schema.decimal_schema(), {'allow_bool': True})
+    assert v.validate_test(True) == Decimal(1)
+
+    v = py_and_json(core_schema.decimal_schema(allow_bool=True, strict=True))
+    with pytest.raises(ValidationError):
+        v.validate_test(True)
@@ -1,6 +1,6 @@
 import pytest
 
-from pydantic_core import SchemaError, SchemaValidator, core_schema, validate_core_schema
+from pydantic_core import SchemaError, SchemaValidator, ValidationError, core_schema, validate_core_schema
 
 from ..conftest import plain_repr
 
@@ -150,3 +150,69 @@ def test_definition_chain():
         ),
     )
     assert v.validate_python('1') == 1
+
+
+def test_cache_shared():
+    calls = []
+
+    def f(input_value):
+        calls.append(input_value)
+        return dict(input_value)
+
+    v = SchemaValidator(
+        core_schema.definitions_schema(
+            core_schema.list_schema(core_schema.definition_reference_schema('inner')),
+            [
+                core_schema.no_info_after_validator_function(
+                    f, core_schema.dict_schema(core_schema.str_schema(), core_schema.int_schema()), ref='inner'
+                )
+            ],
+        )
+    )
+    shared = {'a': '1'}
+    other = {'b': '2'}
+
+    output = v.validate_python([shared, other, shared, shared])
+    assert output == [{'a': 1}, {'b': 2}, {'a': 1}, {'a': 1}]
+    assert len(calls) == 4
+    assert output[0] is not output[2]
+
+    calls.clear()
+    output = v.validate_python([shared, other, shared, shared], cache_shared=True)
+    assert output == [{'a': 1}, {'b': 2}, {'a': 1}, {'a': 1}]
+    assert calls == [{'a': 1}, {'b': 2}]
+    assert output[0] is output[2] is output[3]
+
+    # the cache only lives for a single call
+    calls.clear()
+    v.validate_python([shared], cache_shared=True)
+    assert calls == [{'a': 1}]
+
+
+def test_cache_shared_errors():
+    v = SchemaValidator(
+        core_schema.definitions_schema(
+            core_schema.list_schema(core_schema.definition_reference_schema('inner')),
+            [core_schema.list_schema(core_schema.int_schema(), ref='inner')],
+        )
+    )
+    bad = ['x']
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python([bad, [1], bad], cache_shared=True)
+    # failures aren't cached, so each repeated input still reports its own errors
+    assert [e['loc'] for e in exc_info.value.errors()] == [(0, 0), (2, 0)]
+
+
+def test_cache_shared_union_exactness():
+    v = SchemaValidator(
+        core_schema.definitions_schema(
+            core_schema.list_schema(
+                core_schema.union_schema([core_schema.definition_reference_schema('inner'), core_schema.str_schema()])
+            ),
+            [core_schema.int_schema(ref='inner')],
+        )
+    )
+    value = '42'
+    # a cached result keeps the exactness it was validated with, so the exact `str` choice still wins
+    assert v.validate_python([value, value], cache_shared=True) == ['42', '42']
+    assert v.validate_python([42, 42], cache_shared=True) == [42, 42]
@@ -336,6 +336,49 @@ def test_recursion_branch():
     ]
 
 
+def test_recursion_guard_shared():
+    v = SchemaValidator(
+        core_schema.definitions_schema(
+            core_schema.definition_reference_schema('Branch'),
+            [
+                core_schema.typed_dict_schema(
+                    {
+                        'name': core_schema.typed_dict_field(core_schema.str_schema()),
+                        'branch': core_schema.typed_dict_field(
+                            core_schema.nullable_schema(core_schema.definition_reference_schema('Branch'))
+                        ),
+                    },
+                    ref='Branch',
+                )
+            ],
+        )
+    )
+    b = {'name': 'recursive'}
+    b['branch'] = b
+
+    with pydantic_core.RecursionGuard() as guard:
+        for _ in range(3):
+            with pytest.raises(ValidationError, match='Recursion error - cyclic reference detected'):
+                v.validate_python(b, recursion_guard=guard)
+            # the same item validated again must not be reported as cyclic
+            item = {'name': 'root', 'branch': {'name': 'b1', 'branch': None}}
+            assert v.validate_python(item, recursion_guard=guard) == item
+
+
+def test_recursion_guard_in_use():
+    guard = pydantic_core.RecursionGuard()
+    inner = SchemaValidator(core_schema.int_schema())
+
+    def f(value):
+        return inner.validate_python(value, recursion_guard=guard)
+
+    v = SchemaValidator(core_schema.no_info_plain_validator_function(f))
+    with pytest.raises(RuntimeError, match='RecursionGuard is already in use by another validation'):
+        v.validate_python(1, recursion_guard=guard)
+    assert v.validate_python(1) == 1
+    assert repr(guard) == 'RecursionGuard()'
+
+
 def test_recursion_branch_from_attributes():
     v = SchemaValidator(
         core_schema.definitions_schema(
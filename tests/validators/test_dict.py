@@ -1,12 +1,12 @@
 import re
-from collections import OrderedDict
+from collections import OrderedDict, defaultdict
 from collections.abc import Mapping
 from typing import Any, Dict
 
 import pytest
 from dirty_equals import HasRepr, IsStr
 
-from pydantic_core import SchemaValidator, ValidationError
+from pydantic_core import SchemaValidator, ValidationError, core_schema
 
 from ..conftest import Err, PyAndJson
 
@@ -196,6 +196,69 @@ def test_mapping_error_yield_1(mapping_items):
     ]
 
 
+def test_mapping_without_items():
+    class KeysMapping:
+        def __init__(self, d):
+            self._d = d
+
+        def keys(self):
+            return list(self._d)
+
+        def __getitem__(self, key):
+            return self._d[key]
+
+        def __iter__(self):
+            pytest.fail('unexpected call to __iter__')
+
+        def __len__(self):
+            return len(self._d)
+
+    Mapping.register(KeysMapping)
+
+    v = SchemaValidator(core_schema.dict_schema(core_schema.int_schema(), core_schema.int_schema()))
+    assert v.validate_python(KeysMapping({'1': 2, 3: '4'})) == {1: 2, 3: 4}
+
+
+class MultiMapping(Mapping):
+    def __init__(self, *pairs):
+        self._pairs = pairs
+
+    def items(self):
+        return list(self._pairs)
+
+    def __getitem__(self, key):
+        return next(v for k, v in self._pairs if k == key)
+
+    def __iter__(self):
+        return (k for k, _ in self._pairs)
+
+    def __len__(self):
+        return len(self._pairs)
+
+
+@pytest.mark.parametrize(
+    'mapping_duplicate_keys,expected',
+    [(None, {'a': 3, 'b': 2}), ('last', {'a': 3, 'b': 2}), ('first', {'a': 1, 'b': 2})],
+)
+def test_mapping_duplicate_keys(mapping_duplicate_keys, expected):
+    v = SchemaValidator(
+        core_schema.dict_schema(
+            core_schema.str_schema(), core_schema.int_schema(), mapping_duplicate_keys=mapping_duplicate_keys
+        )
+    )
+    assert v.validate_python(MultiMapping(('a', 1), ('b', 2), ('a', '3'))) == expected
+    # repeated JSON keys are combined by `validate_json(duplicate_keys=...)` before the dict sees them
+    assert v.validate_json('{"a": 1, "b": 2, "a": 3}') == {'a': 3, 'b': 2}
+    assert v.validate_json('{"a": 1, "b": 2, "a": 3}', duplicate_keys='first') == {'a': 1, 'b': 2}
+
+
+def test_mapping_duplicate_keys_first_skips_validation():
+    v = SchemaValidator(
+        core_schema.dict_schema(core_schema.str_schema(), core_schema.int_schema(), mapping_duplicate_keys='first')
+    )
+    assert v.validate_python(MultiMapping(('a', 1), ('a', 'x'))) == {'a': 1}
+
+
 @pytest.mark.parametrize(
     'kwargs,input_value,expected',
     [
@@ -235,3 +298,56 @@ def test_json_dict():
     assert exc_info.value.errors(include_url=False) == [
         {'type': 'dict_type', 'loc': (), 'msg': 'Input should be an object', 'input': 1}
     ]
+
+
+def test_mapping_type(py_and_json: PyAndJson):
+    v = py_and_json(
+        core_schema.dict_schema(core_schema.str_schema(), core_schema.int_schema(), mapping_type=OrderedDict)
+    )
+    output = v.validate_test({'b': '1', 'a': 2})
+    assert output == OrderedDict([('b', 1), ('a', 2)])
+    assert type(output) is OrderedDict
+    assert list(output) == ['b', 'a']
+
+    with pytest.raises(ValidationError, match=r'b\n  Input should be a valid integer'):
+        v.validate_test({'b': 'x'})
+
+
+def test_mapping_type_custom():
+    class MyMapping(Mapping):
+        def __init__(self, data):
+            self._data = data
+
+        def __getitem__(self, key):
+            return self._data[key]
+
+        def __iter__(self):
+            return iter(self._data)
+
+        def __len__(self):
+            return len(self._data)
+
+    v = SchemaValidator(core_schema.dict_schema(core_schema.int_schema(), mapping_type=MyMapping))
+    output = v.validate_python({'1': 'a'})
+    assert isinstance(output, MyMapping)
+    assert dict(output) == {1: 'a'}
+
+
+def test_mapping_type_invalid():
+    v = SchemaValidator(core_schema.dict_schema(mapping_type=defaultdict))
+    msg = r'^Unable to construct `defaultdict` from the validated dict: TypeError: '
+    with pytest.raises(TypeError, match=msg) as exc_info:
+        v.validate_python({'a': 1})
+    assert isinstance(exc_info.value.__cause__, TypeError)
+
+
+def test_allow_subclass():
+    v = SchemaValidator(core_schema.dict_schema(strict=True, allow_subclass=False))
+    assert v.validate_python({'a': 1}) == {'a': 1}
+    assert v.validate_json('{"a": 1}') == {'a': 1}
+    for input_value in OrderedDict(a=1), defaultdict(int, a=1):
+        with pytest.raises(ValidationError, match='Input should be a valid dictionary'):
+            v.validate_python(input_value)
+
+    v = SchemaValidator(core_schema.dict_schema(allow_subclass=False))
+    assert v.validate_python(OrderedDict(a=1)) == {'a': 1}
@@ -0,0 +1,86 @@
+import re
+
+import pytest
+
+from pydantic_core import SchemaSerializer, SchemaValidator, ValidationError, core_schema
+
+from ..conftest import Err, PyAndJson
+
+
+@pytest.mark.parametrize(
+    'input_value,expected',
+    [
+        ('john@example.com', 'john@example.com'),
+        ('John.Doe@Example.COM', 'John.Doe@example.com'),
+        ('  john@example.com ', 'john@example.com'),
+        ('first.last+tag@sub.example.co.uk', 'first.last+tag@sub.example.co.uk'),
+        ("o'neil!#$%&*/=?^_`{|}~-@example.com", "o'neil!#$%&*/=?^_`{|}~-@example.com"),
+        ('jörg@bücher.de', 'jörg@bücher.de'),
+        ('a@my-domain.io', 'a@my-domain.io'),
+        ('x' * 64 + '@example.com', 'x' * 64 + '@example.com'),
+        ('john.example.com', Err('Value should be a valid email address, an email address must have an @-sign')),
+        ('@example.com', Err('Value should be a valid email address, there must be something before the @-sign')),
+        ('john@', Err('Value should be a valid email address, there must be something after the @-sign')),
+        ('x' * 65 + '@example.com', Err('the part before the @-sign is too long')),
+        ('.john@example.com', Err('the part before the @-sign cannot start or end with a period')),
+        ('john.@example.com', Err('the part before the @-sign cannot start or end with a period')),
+        ('jo..hn@example.com', Err('the part before the @-sign cannot contain two periods in a row')),
+        ('jo hn@example.com', Err('the part before the @-sign contains invalid characters')),
+        ('a@b@example.com', Err('the part before the @-sign contains invalid characters')),
+        ('john@localhost', Err('the part after the @-sign is not valid, it should have a period')),
+        ('john@example..com', Err('the part after the @-sign cannot contain empty labels')),
+        ('john@example.com.', Err('the part after the @-sign cannot contain empty labels')),
+        ('john@-example.com', Err('the part after the @-sign cannot have labels starting or ending with a hyphen')),
+        ('john@example-.com', Err('the part after the @-sign cannot have labels starting or ending with a hyphen')),
+        ('john@exa_mple.com', Err('the part after the @-sign contains invalid characters')),
+        ('john@' + 'x' * 64 + '.com', Err('the part after the @-sign contains a label which is too long')),
+        ('john@' + 'x.' * 127 + 'com', Err('the part after the @-sign is too long')),
+    ],
+)
+def test_email(py_and_json: PyAndJson, input_value, expected):
+    v = py_and_json(core_schema.email_schema())
+    if isinstance(expected, Err):
+        with pytest.raises(ValidationError, match=re.escape(expected.message)):
+            v.validate_test(input_value)
+    else:
+        assert v.validate_test(input_value) == expected
+
+
+def test_email_error():
+    v = SchemaValidator(core_schema.email_schema())
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python('john@localhost')
+    assert exc_info.value.errors(include_url=False) == [
+        {
+            'type': 'email_parsing',
+            'loc': (),
+            'msg': (
+                'Value should be a valid email address, the part after the @-sign is not valid, it should have a period'
+            ),
+            'input': 'john@localhost',
+            'ctx': {'reason': 'the part after the @-sign is not valid, it should have a period'},
+        }
+    ]
+
+
+def test_email_type():
+    v = SchemaValidator(core_schema.email_schema())
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python(1)
+    assert exc_info.value.errors(include_url=False) == [
+        {'type': 'string_type', 'loc': (), 'msg': 'Input should be a valid string', 'input': 1}
+    ]
+
+
+def test_email_strict():
+    v = SchemaValidator(core_schema.email_schema(strict=True))
+    assert v.validate_python('john@example.com') == 'john@example.com'
+    with pytest.raises(ValidationError, match='Input should be a valid string'):
+        v.validate_python(b'john@example.com')
+    assert SchemaValidator(core_schema.email_schema()).validate_python(b'john@example.com') == 'john@example.com'
+
+
+def test_email_serialization():
+    v = SchemaValidator(core_schema.email_schema())
+    s = SchemaSerializer(core_schema.email_schema())
+    assert s.to_json(v.validate_python('John@Example.com')) == b'"John@example.com"'
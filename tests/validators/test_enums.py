@@ -344,3 +344,24 @@ def test_big_int():
 
     assert v.validate_python(ColorEnum.GREEN) is ColorEnum.GREEN
     assert v.validate_python(1 << 63) is ColorEnum.GREEN
+
+
+def test_suggest():
+    class Color(str, Enum):
+        RED = 'red'
+        GREEN = 'green'
+
+    v = SchemaValidator(core_schema.enum_schema(Color, list(Color.__members__.values()), sub_type='str', suggest=True))
+    assert v.validate_python('green') is Color.GREEN
+
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python('gren')
+    assert exc_info.value.errors(include_url=False) == [
+        {
+            'type': 'enum',
+            'loc': (),
+            'msg': "Input should be 'red' or 'green'",
+            'input': 'gren',
+            'ctx': {'expected': "'red' or 'green'", 'suggestion': 'green'},
+        }
+    ]
@@ -6,10 +6,15 @@ from typing import Any, Dict
 import pytest
 from dirty_equals import FunctionCheck, IsFloatNan, IsStr
 
-from pydantic_core import SchemaValidator, ValidationError, core_schema
+from pydantic_core import SchemaError, SchemaValidator, ValidationError, core_schema
 
 from ..conftest import Err, PyAndJson, plain_repr
 
+try:
+    import numpy
+except ImportError:
+    numpy = None
+
 f64_max = 1.7976931348623157e308
 
 
@@ -66,6 +71,40 @@ def test_float_strict(py_and_json: PyAndJson, input_value, expected):
         assert isinstance(output, float)
 
 
+@pytest.mark.parametrize(
+    'input_value,expected',
+    [
+        (42.0, 42.0),
+        (42.5, 42.5),
+        (42, Err('Input should be a valid number [type=float_type, input_value=42, input_type=int]')),
+        (0, Err('Input should be a valid number [type=float_type, input_value=0, input_type=int]')),
+        (2**64, Err('Input should be a valid number [type=float_type,')),
+    ],
+    ids=repr,
+)
+@pytest.mark.parametrize('constrained', [False, True])
+def test_float_strict_no_int(py_and_json: PyAndJson, input_value, expected, constrained):
+    schema = core_schema.float_schema(strict=True, allow_int_to_float=False, ge=0 if constrained else None)
+    v = py_and_json(schema)
+    if isinstance(expected, Err):
+        with pytest.raises(ValidationError, match=re.escape(expected.message)):
+            v.validate_test(input_value)
+    else:
+        output = v.validate_test(input_value)
+        assert output == expected
+        assert isinstance(output, float)
+
+
+def test_float_lax_no_int():
+    v = SchemaValidator(core_schema.float_schema(allow_int_to_float=False))
+    assert v.validate_python(42) == 42.0
+    assert v.validate_json('42') == 42.0
+    with pytest.raises(ValidationError, match=r'Input should be a valid number \[type=float_type,'):
+        v.validate_python(42, strict=True)
+    with pytest.raises(ValidationError, match=r'Input should be a valid number \[type=float_type,'):
+        v.validate_json('42', strict=True)
+
+
 @pytest.mark.parametrize(
     'kwargs,input_value,expected',
     [
@@ -183,12 +222,12 @@ def test_float_repr():
     v = SchemaValidator({'type': 'float'})
     assert (
         plain_repr(v)
-        == 'SchemaValidator(title="float",validator=Float(FloatValidator{strict:false,allow_inf_nan:true}),definitions=[],cache_strings=True)'
+        == 'SchemaValidator(title="float",validator=Float(FloatValidator{strict:false,explain_strict:false,parse_options:StringParseOptions{trim:true,allow_underscores:true,allow_sign_whitespace:false,thousands_separator:None,decimal_separator:\'.\',strict_grouping:false,json_coerce_strings:true},allow_inf_nan:true,allow_int_to_float:true,allow_bool:true,nan_as_none:false}),definitions=[],cache_strings=True)'
     )
     v = SchemaValidator({'type': 'float', 'strict': True})
     assert (
         plain_repr(v)
-        == 'SchemaValidator(title="float",validator=Float(FloatValidator{strict:true,allow_inf_nan:true}),definitions=[],cache_strings=True)'
+        == 'SchemaValidator(title="float",validator=Float(FloatValidator{strict:true,explain_strict:false,parse_options:StringParseOptions{trim:true,allow_underscores:true,allow_sign_whitespace:false,thousands_separator:None,decimal_separator:\'.\',strict_grouping:false,json_coerce_strings:true},allow_inf_nan:true,allow_int_to_float:true,allow_bool:true,nan_as_none:false}),definitions=[],cache_strings=True)'
     )
     v = SchemaValidator({'type': 'float', 'multiple_of': 7})
     assert plain_repr(v).startswith('SchemaValidator(title="constrained-float",validator=ConstrainedFloat(')
@@ -408,3 +447,130 @@ def test_allow_inf_nan_false_json() -> None:
     assert exc_info3.value.errors(include_url=False) == [
         {'type': 'finite_number', 'loc': (), 'msg': 'Input should be a finite number', 'input': float('-inf')}
     ]
+
+
+def test_float_from_dunder_float():
+    class FloatScalar:
+        def __init__(self, value: float):
+            self.value = value
+
+        def __float__(self) -> float:
+            return self.value
+
+    v = SchemaValidator(core_schema.float_schema())
+    assert v.validate_python(FloatScalar(1.5)) == 1.5
+    assert math.isnan(v.validate_python(FloatScalar(math.nan)))
+
+    v = SchemaValidator(core_schema.float_schema(allow_inf_nan=False))
+    with pytest.raises(ValidationError, match='Input should be a finite number'):
+        v.validate_python(FloatScalar(math.nan))
+
+
+@pytest.mark.skipif(numpy is None, reason='numpy is not installed')
+def test_float_numpy():
+    v = SchemaValidator(core_schema.float_schema())
+    assert v.validate_python(numpy.float32(1.5)) == 1.5
+    assert v.validate_python(numpy.int64(3)) == 3.0
+    assert math.isnan(v.validate_python(numpy.nan))
+    assert math.isnan(v.validate_python(numpy.float64('nan')))
+
+    v = SchemaValidator(core_schema.float_schema(allow_inf_nan=False))
+    with pytest.raises(ValidationError, match='Input should be a finite number'):
+        v.validate_python(numpy.float64('nan'))
+    with pytest.raises(ValidationError, match='Input should be a finite number'):
+        v.validate_python(numpy.float32('inf'))
+
+
+@pytest.mark.parametrize(
+    'string_parse_options,input_value,expected',
+    [
+        (None, ' 1.5 ', 1.5),
+        (None, ' 1_000.5 ', 1000.5),
+        (None, '- 1.5', Err('Input should be a valid number, unable to parse string as a number')),
+        ({'trim': False}, ' 1.5', Err('Input should be a valid number, unable to parse string as a number')),
+        ({'allow_underscores': False}, '1_000.5', Err('Input should be a valid number, unable to parse string')),
+        ({'allow_sign_whitespace': True}, '- 1.5', -1.5),
+    ],
+)
+def test_string_parse_options(py_and_json: PyAndJson, string_parse_options, input_value, expected):
+    v = py_and_json(core_schema.float_schema(string_parse_options=string_parse_options))
+    if isinstance(expected, Err):
+        with pytest.raises(ValidationError, match=re.escape(expected.message)):
+            v.validate_test(input_value)
+    else:
+        assert v.validate_test(input_value) == expected
+
+
+@pytest.mark.parametrize(
+    'string_parse_options,input_value,expected',
+    [
+        ({'thousands_separator': ','}, '1,234.5', 1234.5),
+        ({'thousands_separator': ','}, '-1,234,567', -1234567),
+        ({'thousands_separator': ','}, '12,34', 1234),
+        ({'thousands_separator': '.', 'decimal_separator': ','}, '1.234,56', 1234.56),
+        ({'thousands_separator': ' ', 'decimal_separator': ','}, '1 234,5', 1234.5),
+        ({'decimal_separator': ','}, '1,5', 1.5),
+        ({'decimal_separator': ','}, '1.5', Err('Input should be a valid number, unable to parse string as a number')),
+        ({'thousands_separator': ',', 'strict_grouping': True}, '1,234,567.5', 1234567.5),
+        ({'thousands_separator': ',', 'strict_grouping': True}, '1234.5', 1234.5),
+        ({'thousands_separator': ',', 'strict_grouping': True}, '12,34', Err('unable to parse string as a number')),
+        ({'thousands_separator': ',', 'strict_grouping': True}, '1234,567', Err('unable to parse string as a number')),
+    ],
+)
+def test_separators(py_and_json: PyAndJson, string_parse_options, input_value, expected):
+    v = py_and_json(core_schema.float_schema(string_parse_options=string_parse_options))
+    if isinstance(expected, Err):
+        with pytest.raises(ValidationError, match=re.escape(expected.message)):
+            v.validate_test(input_value)
+    else:
+        assert v.validate_test(input_value) == expected
+
+
+def test_separators_invalid():
+    with pytest.raises(SchemaError, match='`thousands_separator` and `decimal_separator` must be different'):
+        SchemaValidator(core_schema.float_schema(string_parse_options={'thousands_separator': '.'}))
+    with pytest.raises(SchemaError, match='`decimal_separator` must be a single character, got "::"'):
+        SchemaValidator(core_schema.float_schema(string_parse_options={'decimal_separator': '::'}))
+
+
+@pytest.mark.parametrize('constrained', [False, True])
+def test_nan_as_none(constrained):
+    v = SchemaValidator(core_schema.float_schema(nan_as_none=True, ge=0 if constrained else None))
+    assert v.validate_python(float('nan')) is None
+    assert v.validate_python('NaN') is None
+    assert v.validate_json('NaN') is None
+    assert v.validate_python(1.5) == 1.5
+    assert v.validate_python(float('inf')) == float('inf')
+
+
+def test_nan_as_none_nullable():
+    v = SchemaValidator(
+        core_schema.nullable_schema(core_schema.float_schema(nan_as_none=True, allow_inf_nan=False)),
+    )
+    assert v.validate_python(float('nan')) is None
+    assert v.validate_python(None) is None
+    with pytest.raises(ValidationError, match='Input should be a finite number'):
+        v.validate_python(float('inf'))
+    with pytest.raises(ValidationError, match='Input should be a finite number'):
+        v.validate_python(float('nan'), strict=True)
+
+    v = SchemaValidator(core_schema.nullable_schema(core_schema.float_schema(nan_as_none=True, strict=True)))
+    assert math.isnan(v.validate_python(float('nan')))
+
+
+@pytest.mark.parametrize('constrained', [False, True])
+def test_allow_bool(py_and_json: PyAndJson, constrained):
+    v = py_and_json(core_schema.float_schema(allow_bool=False, ge=0 if constrained else None))
+    assert v.validate_test(1) == 1.0
+    with pytest.raises(ValidationError, match=r'Input should be a valid number \[type=float_type,'):
+        v.validate_test(True)
+    assert py_and_json(core_schema.float_schema()).validate_test(True) == 1.0
+
+
+def test_json_coerce_strings():
+    v = SchemaValidator(core_schema.float_schema(json_coerce_strings=False))
+    assert v.validate_json('1.5') == 1.5
+    assert v.validate_json('1') == 1.0
+    assert v.validate_python('1.5') == 1.5
+    with pytest.raises(ValidationError, match=r'Input should be a valid number \[type=float_type,'):
+        v.validate_json('"1.5"')
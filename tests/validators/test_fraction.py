@@ -0,0 +1,70 @@
+import re
+from fractions import Fraction
+
+import pytest
+
+from pydantic_core import SchemaSerializer, SchemaValidator, ValidationError, core_schema
+
+from ..conftest import Err, PyAndJson
+
+
+@pytest.mark.parametrize(
+    'input_value,expected',
+    [
+        ('3/4', Fraction(3, 4)),
+        (' -3/4 ', Fraction(-3, 4)),
+        ('1.5', Fraction(3, 2)),
+        ('1.5e3', Fraction(1500)),
+        ('2E-2', Fraction(1, 50)),
+        ('3e0', Fraction(3)),
+        ('3e-000', Fraction(3)),
+        ('1e4300', Fraction(10**4300)),
+        ('1e10000000', Err('Input should be a valid fraction [type=fraction_parsing,')),
+        ('1e-10000000', Err('Input should be a valid fraction [type=fraction_parsing,')),
+        ('1e99999999999999999999', Err('Input should be a valid fraction [type=fraction_parsing,')),
+        (2, Fraction(2)),
+        ([3, 4], Fraction(3, 4)),
+        ([6, -4], Fraction(-3, 2)),
+        ('3/0', Err('Input should be a valid fraction [type=fraction_parsing,')),
+        ('three quarters', Err('Input should be a valid fraction [type=fraction_parsing,')),
+        ([3, 0], Err('Input should be a valid fraction [type=fraction_parsing,')),
+        ([3, 4, 5], Err('Input should be a valid fraction [type=fraction_parsing,')),
+        ([3, 'x'], Err('Input should be a valid fraction [type=fraction_parsing,')),
+        (1.5, Err('Fraction input should be an integer, string, pair of integers or Fraction object')),
+        ({}, Err('Fraction input should be an integer, string, pair of integers or Fraction object')),
+    ],
+)
+def test_fraction(py_and_json: PyAndJson, input_value, expected):
+    v = py_and_json(core_schema.fraction_schema())
+    if isinstance(expected, Err):
+        with pytest.raises(ValidationError, match=re.escape(expected.message)):
+            v.validate_test(input_value)
+    else:
+        output = v.validate_test(input_value)
+        assert output == expected
+        assert isinstance(output, Fraction)
+
+
+def test_fraction_strict():
+    v = SchemaValidator(core_schema.fraction_schema(strict=True))
+    f = Fraction(3, 4)
+    assert v.validate_python(f) is f
+    for input_value in ('3/4', 3, (3, 4)):
+        with pytest.raises(ValidationError, match=r'\[type=fraction_type,'):
+            v.validate_python(input_value)
+    assert v.validate_json('"3/4"') == f
+
+    v = SchemaValidator(core_schema.fraction_schema())
+    assert v.validate_python(f) is f
+    assert v.validate_python((3, 4)) == f
+    with pytest.raises(ValidationError, match=r'\[type=fraction_type,'):
+        v.validate_python(True)
+    with pytest.raises(ValidationError, match=r'\[type=fraction_type,'):
+        v.validate_python('3/4', strict=True)
+
+
+def test_fraction_serialization():
+    s = SchemaSerializer(core_schema.fraction_schema())
+    assert s.to_python(Fraction(3, 4)) == Fraction(3, 4)
+    assert s.to_python(Fraction(3, 4), mode='json') == '3/4'
+    assert s.to_json(Fraction(3, 4)) == b'"3/4"'
@@ -247,7 +247,7 @@ def test_repr():
         'SchemaValidator('
         'title="frozenset[any]",'
         'validator=FrozenSet(FrozenSetValidator{'
-        'strict:true,item_validator:Any(AnyValidator),min_length:Some(42),max_length:None,'
+        'strict:true,item_validator:Any(AnyValidator),min_length:Some(42),max_length:None,contains:None,allow_subclass:true,'
         'name:"frozenset[any]"'
         '}),'
         'definitions=[],'
@@ -296,3 +296,14 @@ def test_frozenset_from_dict_items(input_value, items_schema, expected):
     output = v.validate_python(input_value)
     assert isinstance(output, frozenset)
     assert output == expected
+
+
+def test_allow_subclass():
+    class MyFrozenSet(frozenset):
+        pass
+
+    v = SchemaValidator({'type': 'frozenset', 'strict': True, 'allow_subclass': False})
+    assert v.validate_python(frozenset({1, 2})) == {1, 2}
+    with pytest.raises(ValidationError, match='Input should be a valid frozenset'):
+        v.validate_python(MyFrozenSet({1, 2}))
+    assert SchemaValidator({'type': 'frozenset', 'strict': True}).validate_python(MyFrozenSet({1, 2})) == {1, 2}
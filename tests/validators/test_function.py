@@ -189,7 +189,7 @@ def test_function_wrap_repr():
 
     assert (
         v.validate_python('input value')
-        == 'ValidatorCallable(Str(StrValidator{strict:false,coerce_numbers_to_str:false}))'
+        == 'ValidatorCallable(Str(StrValidator{strict:false,explain_strict:false,coerce_numbers_to_str:false}))'
     )
 
 
@@ -201,7 +201,7 @@ def test_function_wrap_str():
 
     assert (
         v.validate_python('input value')
-        == 'ValidatorCallable(Str(StrValidator{strict:false,coerce_numbers_to_str:false}))'
+        == 'ValidatorCallable(Str(StrValidator{strict:false,explain_strict:false,coerce_numbers_to_str:false}))'
     )
 
 
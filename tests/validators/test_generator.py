@@ -173,6 +173,16 @@ def test_generator_too_long():
     ]
 
 
+
+def test_generator_too_long_any():
+    v = SchemaValidator({'type': 'generator', 'max_length': 2})
+
+    validating_iterator = v.validate_python(gen())
+    assert next(validating_iterator) == 1
+    assert next(validating_iterator) == 2
+    with pytest.raises(ValidationError, match='Generator should have at most 2 items after validation, not more'):
+        next(validating_iterator)
+
 def test_generator_too_short():
     v = SchemaValidator({'type': 'generator', 'items_schema': {'type': 'int'}, 'min_length': 4})
 
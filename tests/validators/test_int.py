@@ -10,6 +10,11 @@ from pydantic_core import SchemaValidator, ValidationError, core_schema
 
 from ..conftest import Err, PyAndJson, plain_repr
 
+try:
+    import numpy
+except ImportError:
+    numpy = None
+
 i64_max = 9_223_372_036_854_775_807
 
 
@@ -383,12 +388,12 @@ def test_int_repr():
     v = SchemaValidator({'type': 'int'})
     assert (
         plain_repr(v)
-        == 'SchemaValidator(title="int",validator=Int(IntValidator{strict:false}),definitions=[],cache_strings=True)'
+        == 'SchemaValidator(title="int",validator=Int(IntValidator{strict:false,explain_strict:false,parse_options:StringParseOptions{trim:true,allow_underscores:true,allow_sign_whitespace:false,thousands_separator:None,decimal_separator:\'.\',strict_grouping:false,json_coerce_strings:true},allow_bool:true}),definitions=[],cache_strings=True)'
     )
     v = SchemaValidator({'type': 'int', 'strict': True})
     assert (
         plain_repr(v)
-        == 'SchemaValidator(title="int",validator=Int(IntValidator{strict:true}),definitions=[],cache_strings=True)'
+        == 'SchemaValidator(title="int",validator=Int(IntValidator{strict:true,explain_strict:false,parse_options:StringParseOptions{trim:true,allow_underscores:true,allow_sign_whitespace:false,thousands_separator:None,decimal_separator:\'.\',strict_grouping:false,json_coerce_strings:true},allow_bool:true}),definitions=[],cache_strings=True)'
     )
     v = SchemaValidator({'type': 'int', 'multiple_of': 7})
     assert plain_repr(v).startswith('SchemaValidator(title="constrained-int",validator=ConstrainedInt(')
@@ -527,6 +532,24 @@ def test_int_subclass_plain_enum() -> None:
     assert type(v_lax) == int
 
 
+def test_plain_enum_value_type() -> None:
+    from enum import Enum
+
+    class PlainEnum(Enum):
+        ONE = 1
+        TWO = 'two'
+        HALF = 0.5
+
+    v = SchemaValidator(core_schema.int_schema())
+    assert v.validate_python(PlainEnum.ONE) == 1
+    # only values which are ints are used
+    for member in (PlainEnum.TWO, PlainEnum.HALF):
+        with pytest.raises(ValidationError, match='Input should be a valid integer'):
+            v.validate_python(member)
+    with pytest.raises(ValidationError, match='Input should be a valid integer'):
+        v.validate_python(PlainEnum.ONE, strict=True)
+
+
 def test_allow_inf_nan_true_json() -> None:
     v = SchemaValidator(core_schema.int_schema(), core_schema.CoreConfig(allow_inf_nan=True))
 
@@ -557,3 +580,202 @@ def test_json_big_int_key():
     assert v.validate_python({big_integer: 'x'}) == {big_integer: 'x'}
     assert v.validate_json('{"' + str(big_integer) + '": "x"}') == {big_integer: 'x'}
     assert v.validate_strings({str(big_integer): 'x'}) == {big_integer: 'x'}
+
+
+@pytest.mark.parametrize('schema', [core_schema.int_schema(), core_schema.int_schema(gt=0)])
+def test_explain_strict(schema):
+    v = SchemaValidator(schema, core_schema.CoreConfig(strict=True, explain_strict=True))
+    assert v.validate_python(42) == 42
+
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python('42')
+    # the error type and message are unchanged, only the context is extended
+    assert exc_info.value.errors(include_url=False) == [
+        {
+            'type': 'int_type',
+            'loc': (),
+            'msg': 'Input should be a valid integer',
+            'input': '42',
+            'ctx': {
+                'explanation': (
+                    'Input of type `str` was provided but strict mode requires `int`, '
+                    'use lax mode or pass a value of type `int`'
+                )
+            },
+        }
+    ]
+
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_json('"42"')
+    assert exc_info.value.errors(include_url=False)[0]['ctx'] == {
+        'explanation': (
+            'Input of type `string` was provided but strict mode requires `int`, '
+            'use lax mode or pass a value of type `int`'
+        )
+    }
+
+    # lax mode wouldn't have accepted this either, so there's nothing to explain
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python('foobar')
+    assert 'ctx' not in exc_info.value.errors()[0]
+
+
+def test_explain_strict_default():
+    v = SchemaValidator(core_schema.int_schema(strict=True))
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python('42')
+    assert 'ctx' not in exc_info.value.errors()[0]
+
+    v = SchemaValidator(core_schema.int_schema(), core_schema.CoreConfig(explain_strict=True))
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python('42', strict=True)
+    assert exc_info.value.errors()[0]['ctx']['explanation'].startswith('Input of type `str` was provided')
+
+
+def test_explain_strict_schema():
+    v = SchemaValidator(core_schema.int_schema(strict=True, explain_strict=True))
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python('42')
+    assert exc_info.value.errors()[0]['ctx']['explanation'].startswith('Input of type `str` was provided')
+
+    # the schema value overrides the config
+    v = SchemaValidator(
+        core_schema.int_schema(strict=True, explain_strict=False), core_schema.CoreConfig(explain_strict=True)
+    )
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python('42')
+    assert 'ctx' not in exc_info.value.errors()[0]
+
+
+class IndexInt:
+    """Mimics integer scalars from other libraries like `numpy.int64`, which aren't `int` subclasses."""
+
+    def __init__(self, value: int):
+        self.value = value
+
+    def __index__(self) -> int:
+        return self.value
+
+    def __float__(self) -> float:
+        return float(self.value)
+
+
+def test_int_from_index():
+    v = SchemaValidator(core_schema.int_schema())
+    big = 2**62 + 1
+    assert float(big) != big
+    # `__index__` is used before `__float__` so precision isn't lost
+    assert v.validate_python(IndexInt(big)) == big
+    assert type(v.validate_python(IndexInt(1))) is int
+    with pytest.raises(ValidationError, match='Input should be a valid integer'):
+        v.validate_python(IndexInt(1), strict=True)
+
+
+@pytest.mark.skipif(numpy is None, reason='numpy is not installed')
+def test_int_numpy():
+    v = SchemaValidator(core_schema.int_schema())
+    assert v.validate_python(numpy.int64(2**62 + 1)) == 2**62 + 1
+    assert v.validate_python(numpy.uint8(7)) == 7
+    assert v.validate_python(numpy.float64(3.0)) == 3
+    with pytest.raises(ValidationError, match='Input should be a finite number'):
+        v.validate_python(numpy.float64('nan'))
+
+
+@pytest.mark.parametrize(
+    'string_parse_options,input_value,expected',
+    [
+        (None, ' 1_000 ', 1000),
+        (None, '- 1', Err('Input should be a valid integer, unable to parse string as an integer')),
+        ({'trim': False}, ' 1', Err('Input should be a valid integer, unable to parse string as an integer')),
+        ({'trim': False}, '01', 1),
+        ({'allow_underscores': False}, '1_000', Err('Input should be a valid integer, unable to parse string')),
+        ({'allow_sign_whitespace': True}, '- 1', -1),
+        ({'allow_sign_whitespace': True}, ' +  012 ', 12),
+    ],
+)
+def test_string_parse_options(py_and_json: PyAndJson, string_parse_options, input_value, expected):
+    v = py_and_json(core_schema.int_schema(string_parse_options=string_parse_options))
+    if isinstance(expected, Err):
+        with pytest.raises(ValidationError, match=re.escape(expected.message)):
+            v.validate_test(input_value)
+    else:
+        assert v.validate_test(input_value) == expected
+
+
+@pytest.mark.parametrize(
+    'string_parse_options,input_value,expected',
+    [
+        ({'thousands_separator': ','}, '1,234', 1234),
+        ({'thousands_separator': '.', 'decimal_separator': ','}, '1.234.567', 1234567),
+        ({'thousands_separator': '.', 'decimal_separator': ','}, '1.234,00', 1234),
+        ({'thousands_separator': '.', 'decimal_separator': ','}, '1.234,5', Err('Input should be a valid integer')),
+        ({'thousands_separator': ',', 'strict_grouping': True}, '1,23', Err('Input should be a valid integer')),
+    ],
+)
+def test_separators(py_and_json: PyAndJson, string_parse_options, input_value, expected):
+    v = py_and_json(core_schema.int_schema(string_parse_options=string_parse_options))
+    if isinstance(expected, Err):
+        with pytest.raises(ValidationError, match=re.escape(expected.message)):
+            v.validate_test(input_value)
+    else:
+        assert v.validate_test(input_value) == expected
+
+
+@pytest.mark.parametrize('constrained', [False, True])
+def test_allow_bool(py_and_json: PyAndJson, constrained):
+    v = py_and_json(core_schema.int_schema(allow_bool=False, ge=0 if constrained else None))
+    assert v.validate_test(1) == 1
+    with pytest.raises(ValidationError, match=r'Input should be a valid integer \[type=int_type,'):
+        v.validate_test(True)
+
+    v = py_and_json(core_schema.int_schema(), {'allow_bool': False})
+    with pytest.raises(ValidationError, match=r'Input should be a valid integer \[type=int_type,'):
+        v.validate_test(False)
+
+
+@pytest.mark.parametrize('constrained', [False, True])
+def test_allow_bool_strings(constrained):
+    # strings like '1' and '0' could be parsed as bools, but they aren't bools
+    v = SchemaValidator(core_schema.int_schema(allow_bool=False, ge=0 if constrained else None))
+    assert v.validate_strings('1') == 1
+    assert v.validate_strings('0') == 0
+    assert v.validate_python('1') == 1
+    assert v.validate_json('"0"') == 0
+    v = SchemaValidator(
+        core_schema.dict_schema(core_schema.int_schema(allow_bool=False), core_schema.int_schema(allow_bool=False))
+    )
+    assert v.validate_json('{"1": 0}') == {1: 0}
+
+
+@pytest.mark.parametrize('constrained', [False, True])
+def test_json_coerce_strings(constrained):
+    v = SchemaValidator(core_schema.int_schema(json_coerce_strings=False, ge=0 if constrained else None))
+    assert v.validate_json('42') == 42
+    assert v.validate_python('42') == 42
+    with pytest.raises(ValidationError, match=r'Input should be a valid integer \[type=int_type,'):
+        v.validate_json('"42"')
+
+    v = SchemaValidator(core_schema.int_schema(), {'json_coerce_strings': False})
+    with pytest.raises(ValidationError, match=r'Input should be a valid integer \[type=int_type,'):
+        v.validate_json('"42"')
+    assert SchemaValidator(core_schema.int_schema()).validate_json('"42"') == 42
+
+
+@pytest.mark.parametrize('constrained', [False, True])
+def test_cache_validations(constrained):
+    v = SchemaValidator(core_schema.int_schema(lt=10 if constrained else None, cache_validations=True))
+    for _ in range(2):
+        assert v.validate_python(5) == 5
+        assert v.validate_python(5, strict=True) == 5
+        assert v.validate_python('5') == 5
+        with pytest.raises(ValidationError, match='Input should be a valid integer'):
+            v.validate_python('5', strict=True)
+    if constrained:
+        for _ in range(2):
+            with pytest.raises(ValidationError, match='Input should be less than 10'):
+                v.validate_python(12)
+    else:
+        assert v.validate_python(2**70) == 2**70
+        # large ints are cached too, the output of the first equal input is returned
+        first = v.validate_python(int('1' * 25))
+        assert v.validate_python(int('1' * 25)) is first
@@ -0,0 +1,148 @@
+import re
+from datetime import date
+
+import pytest
+
+from pydantic_core import SchemaSerializer, SchemaValidator, ValidationError, core_schema
+
+from ..conftest import Err, PyAndJson
+
+
+@pytest.mark.parametrize(
+    'input_value,expected',
+    [
+        ([1, 2], (1, 2)),
+        (['1', 2.0], (1, 2)),
+        ([3, 3], (3, 3)),
+        ([3, 2], Err('Interval start should be less than or equal to its end [type=interval_invalid,')),
+        ([1], Err('Interval should have at least 2 items after validation, not 1 [type=too_short,')),
+        ([1, 2, 3], Err('Interval should have at most 2 items after validation, not 3 [type=too_long,')),
+        (5, Err('[type=tuple_type, input_value=5, input_type=int]')),
+    ],
+)
+def test_interval(py_and_json: PyAndJson, input_value, expected):
+    v = py_and_json(core_schema.interval_schema(core_schema.int_schema()))
+    if isinstance(expected, Err):
+        with pytest.raises(ValidationError, match=re.escape(expected.message)):
+            v.validate_test(input_value)
+    else:
+        assert v.validate_test(input_value) == expected
+
+
+def test_interval_python_inputs():
+    v = SchemaValidator(core_schema.interval_schema(core_schema.int_schema()))
+    assert v.validate_python((1, 2)) == (1, 2)
+    # the stop of a range is excluded
+    assert v.validate_python(range(5, 10)) == (5, 9)
+    assert v.validate_python(range(5, 6)) == (5, 5)
+    with pytest.raises(ValidationError, match='Interval start should be less than or equal to its end'):
+        v.validate_python(range(5, 5))
+
+    v = SchemaValidator(core_schema.interval_schema(core_schema.int_schema(), strict=True))
+    assert v.validate_python((1, 2)) == (1, 2)
+    assert v.validate_python(range(1, 3)) == (1, 2)
+    with pytest.raises(ValidationError, match='Input should be a valid tuple'):
+        v.validate_python([1, 2])
+
+
+@pytest.mark.parametrize('input_value', [range(10, 5, -1), range(0, 10, 2), range(0, 10, -2)])
+def test_interval_range_step(input_value):
+    v = SchemaValidator(core_schema.interval_schema(core_schema.int_schema()))
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python(input_value)
+    assert exc_info.value.errors(include_url=False) == [
+        {
+            'type': 'interval_range_step',
+            'loc': (),
+            'msg': 'Range inputs to an interval should have a step of 1',
+            'input': input_value,
+        }
+    ]
+
+
+def test_interval_not_comparable():
+    v = SchemaValidator(core_schema.interval_schema(core_schema.any_schema()))
+    assert v.validate_python(('a', 'b')) == ('a', 'b')
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python((1, 'b'))
+    assert exc_info.value.errors(include_url=False) == [
+        {
+            'type': 'interval_not_comparable',
+            'loc': (),
+            'msg': 'Interval start and end should be comparable',
+            'input': (1, 'b'),
+        }
+    ]
+
+
+def test_interval_allow_equal():
+    v = SchemaValidator(core_schema.interval_schema(core_schema.float_schema(), allow_equal=False))
+    assert v.validate_python((1, 1.5)) == (1, 1.5)
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python((1, 1.0))
+    assert exc_info.value.errors(include_url=False) == [
+        {
+            'type': 'interval_invalid',
+            'loc': (),
+            'msg': 'Interval start should be less than its end',
+            'input': (1, 1.0),
+            'ctx': {'expected': 'less than'},
+        }
+    ]
+
+
+def test_interval_endpoint_errors():
+    v = SchemaValidator(core_schema.interval_schema(core_schema.int_schema(ge=0)))
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python(['x', -1])
+    assert exc_info.value.errors(include_url=False) == [
+        {
+            'type': 'int_parsing',
+            'loc': (0,),
+            'msg': 'Input should be a valid integer, unable to parse string as an integer',
+            'input': 'x',
+        },
+        {
+            'type': 'greater_than_equal',
+            'loc': (1,),
+            'msg': 'Input should be greater than or equal to 0',
+            'input': -1,
+            'ctx': {'ge': 0},
+        },
+    ]
+
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python(range(-1, 2))
+    assert exc_info.value.errors(include_url=False) == [
+        {
+            'type': 'greater_than_equal',
+            'loc': (0,),
+            'msg': 'Input should be greater than or equal to 0',
+            'input': -1,
+            'ctx': {'ge': 0},
+        }
+    ]
+
+
+def test_interval_dates():
+    v = SchemaValidator(core_schema.interval_schema(core_schema.date_schema()))
+    assert v.validate_json('["2024-01-01", "2024-02-01"]') == (date(2024, 1, 1), date(2024, 2, 1))
+    with pytest.raises(ValidationError, match='Interval start should be less than or equal to its end'):
+        v.validate_json('["2024-02-01", "2024-01-01"]')
+
+
+def test_interval_serialization():
+    s = SchemaSerializer(core_schema.interval_schema(core_schema.date_schema()))
+    assert s.to_python((date(2024, 1, 1), date(2024, 2, 1))) == (date(2024, 1, 1), date(2024, 2, 1))
+    assert s.to_json((date(2024, 1, 1), date(2024, 2, 1))) == b'["2024-01-01","2024-02-01"]'
+
+
+def test_interval_json_schema():
+    v = SchemaValidator(core_schema.interval_schema(core_schema.int_schema()))
+    assert v.json_schema() == {
+        'type': 'array',
+        'prefixItems': [{'type': 'integer'}, {'type': 'integer'}],
+        'items': False,
+        'minItems': 2,
+        'maxItems': 2,
+    }
@@ -177,3 +177,35 @@ def test_any_schema_no_schema():
     assert 'validator:None' in plain_repr(v)
     v = SchemaValidator(core_schema.json_schema(core_schema.int_schema()))
     assert 'validator:Some(' in plain_repr(v)
+
+
+@pytest.mark.parametrize(
+    'input_value,expected',
+    [
+        ('{"a": [1, 2.5, null]}', '{"a": [1, 2.5, null]}'),
+        (' "a" ', ' "a" '),
+        (b'[true]', '[true]'),
+        ('{"a": 1', Err('Invalid JSON: EOF while parsing an object at line 1 column 7 [type=json_invalid,')),
+        ('[1] 2', Err('Invalid JSON: trailing characters at line 1 column 5 [type=json_invalid,')),
+        ('', Err('Invalid JSON: EOF while parsing a value at line 1 column 0 [type=json_invalid,')),
+        (1, Err('Input should be a valid string [type=string_type,')),
+    ],
+)
+def test_json_string(input_value, expected):
+    v = SchemaValidator(core_schema.json_string_schema())
+    if isinstance(expected, Err):
+        with pytest.raises(ValidationError, match=re.escape(expected.message)):
+            v.validate_python(input_value)
+    else:
+        assert v.validate_python(input_value) == expected
+
+
+def test_json_string_unchanged():
+    v = SchemaValidator(core_schema.json_string_schema())
+    data = '{"b": 2,  "a": 1}'
+    assert v.validate_python(data) is data
+    assert v.validate_json('"{\\"a\\": 1}"') == '{"a": 1}'
+
+    v = SchemaValidator(core_schema.json_string_schema(strict=True))
+    with pytest.raises(ValidationError, match=r'\[type=string_type,'):
+        v.validate_python(b'[true]')
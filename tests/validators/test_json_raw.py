@@ -0,0 +1,68 @@
+import pytest
+
+from pydantic_core import JsonRaw, SchemaValidator, ValidationError
+from pydantic_core import core_schema as cs
+
+
+def raw_field_schema(schema):
+    return cs.typed_dict_schema(
+        {'id': cs.typed_dict_field(cs.int_schema()), 'payload': cs.typed_dict_field(cs.json_raw_schema(schema))}
+    )
+
+
+@pytest.mark.parametrize(
+    'payload,expected',
+    [
+        ('{"b": 2,  "a": [1, 2.50]}', {'b': 2, 'a': [1, 2.5]}),
+        ('{}', {}),
+        ('{"a": "\\u00e9", "b": {"c": []}}', {'a': 'é', 'b': {'c': []}}),
+    ],
+)
+def test_json_raw(payload, expected):
+    v = SchemaValidator(raw_field_schema(cs.dict_schema()))
+    output = v.validate_json(f'{{"id": 1, "payload": {payload} }}')
+    assert output['id'] == 1
+    json_raw = output['payload']
+    assert isinstance(json_raw, JsonRaw)
+    assert json_raw.value == expected
+    assert json_raw.raw == payload.encode()
+
+
+def test_json_raw_scalars():
+    v = SchemaValidator(cs.list_schema(cs.json_raw_schema(cs.float_schema())))
+    output = v.validate_json(b' [1.50, 2e3,\n -0.0 ] ')
+    assert [(r.value, r.raw) for r in output] == [(1.5, b'1.50'), (2000.0, b'2e3'), (-0.0, b'-0.0')]
+
+    v = SchemaValidator(cs.json_raw_schema(cs.str_schema()))
+    json_raw = v.validate_json(' "a\\nb" ')
+    assert json_raw.value == 'a\nb'
+    assert json_raw.raw == b'"a\\nb"'
+
+
+def test_json_raw_nested():
+    v = SchemaValidator(cs.json_raw_schema(cs.list_schema(cs.json_raw_schema(cs.int_schema()))))
+    json_raw = v.validate_json('[1, 2]')
+    assert json_raw.raw == b'[1, 2]'
+    assert [(r.value, r.raw) for r in json_raw.value] == [(1, b'1'), (2, b'2')]
+
+
+def test_json_raw_python():
+    v = SchemaValidator(raw_field_schema(cs.dict_schema()))
+    json_raw = v.validate_python({'id': 1, 'payload': {'a': 1}})['payload']
+    assert json_raw.value == {'a': 1}
+    assert json_raw.raw is None
+    assert repr(json_raw) == "JsonRaw(value={'a': 1}, raw=None)"
+
+
+def test_json_raw_invalid():
+    v = SchemaValidator(raw_field_schema(cs.dict_schema(values_schema=cs.int_schema())))
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_json('{"id": 1, "payload": {"a": "x"}}')
+    assert exc_info.value.errors(include_url=False) == [
+        {
+            'type': 'int_parsing',
+            'loc': ('payload', 'a'),
+            'msg': 'Input should be a valid integer, unable to parse string as an integer',
+            'input': 'x',
+        }
+    ]
@@ -7,7 +7,7 @@ from typing import Any, Dict, Iterator, List, Union
 import pytest
 from dirty_equals import Contains, HasRepr, IsInstance, IsList, IsStr
 
-from pydantic_core import SchemaValidator, ValidationError, core_schema
+from pydantic_core import SchemaError, SchemaValidator, ValidationError, core_schema
 
 from ..conftest import Err, PyAndJson, infinite_generator
 
@@ -502,3 +502,270 @@ def test_list_dict_items_input(testcase: ListInputTestCase) -> None:
         output = v.validate_python(testcase.input)
         assert output == testcase.output
         assert output is not testcase.input
+
+
+@pytest.mark.parametrize('items_schema', [None, core_schema.int_schema()])
+def test_unique_items(py_and_json: PyAndJson, items_schema):
+    v = py_and_json(core_schema.list_schema(items_schema, unique_items=True))
+    assert v.validate_test([1, 2, 3]) == [1, 2, 3]
+    assert v.validate_test([]) == []
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_test([1, 2, 3, 2, 1])
+    assert exc_info.value.errors(include_url=False) == [
+        {
+            'type': 'list_unique_items',
+            'loc': (3,),
+            'msg': 'List should have unique items, item 3 is a duplicate of item 1',
+            'input': 2,
+            'ctx': {'first_index': 1, 'duplicate_index': 3},
+        }
+    ]
+
+
+def test_unique_items_after_validation():
+    v = SchemaValidator(core_schema.list_schema(core_schema.int_schema(), unique_items=True))
+    with pytest.raises(ValidationError, match='item 1 is a duplicate of item 0'):
+        v.validate_python(['1', 1.0])
+    v = SchemaValidator(core_schema.list_schema(unique_items=True))
+    with pytest.raises(ValidationError, match='item 1 is a duplicate of item 0'):
+        v.validate_python((x for x in [1, True]))
+
+
+def test_unique_items_unhashable(py_and_json: PyAndJson):
+    v = py_and_json(core_schema.list_schema(core_schema.any_schema(), unique_items=True))
+    assert v.validate_test([[1], [2], {'a': 1}, {'a': 2}, 1]) == [[1], [2], {'a': 1}, {'a': 2}, 1]
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_test([[1], {'a': 1}, [2], {'a': 1}])
+    assert exc_info.value.errors(include_url=False) == [
+        {
+            'type': 'list_unique_items',
+            'loc': (3,),
+            'msg': 'List should have unique items, item 3 is a duplicate of item 1',
+            'input': {'a': 1},
+            'ctx': {'first_index': 1, 'duplicate_index': 3},
+        }
+    ]
+
+
+@pytest.mark.parametrize('items_schema', [None, core_schema.str_schema()])
+def test_contains(py_and_json: PyAndJson, items_schema):
+    v = py_and_json(core_schema.list_schema(items_schema, contains=core_schema.literal_schema(['admin'])))
+    assert v.validate_test(['user', 'admin']) == ['user', 'admin']
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_test(['user', 'guest'])
+    assert exc_info.value.errors(include_url=False) == [
+        {
+            'type': 'contains',
+            'loc': (),
+            'msg': 'List should contain at least 1 item matching `contains`, not 0',
+            'input': ['user', 'guest'],
+            'ctx': {'field_type': 'List', 'min_contains': 1, 'max_contains': None, 'actual_contains': 0},
+        }
+    ]
+
+
+def test_min_max_contains():
+    v = SchemaValidator(
+        core_schema.list_schema(
+            core_schema.int_schema(), contains=core_schema.int_schema(gt=10), min_contains=2, max_contains=3
+        )
+    )
+    # checked against the validated items
+    assert v.validate_python(['11', 12, 1]) == [11, 12, 1]
+    with pytest.raises(ValidationError, match='List should contain at least 2 items matching `contains`, not 1'):
+        v.validate_python([11, 1])
+    with pytest.raises(ValidationError, match='List should contain at most 3 items matching `contains`, not 4'):
+        v.validate_python([11, 12, 13, 14])
+    # items are validated before contains is checked
+    with pytest.raises(ValidationError, match='int_parsing'):
+        v.validate_python([11, 12, 'x'])
+
+    v = SchemaValidator(
+        core_schema.list_schema(contains=core_schema.str_schema(strict=True), min_contains=0, max_contains=0)
+    )
+    assert v.validate_python([1, 2]) == [1, 2]
+    with pytest.raises(ValidationError, match='List should contain at most 0 items matching `contains`, not 1'):
+        v.validate_python([1, 'a'])
+
+
+def test_contains_schema_error():
+    with pytest.raises(SchemaError, match='`min_contains` and `max_contains` can only be used with `contains`'):
+        SchemaValidator(core_schema.list_schema(min_contains=2))
+
+
+def test_allow_subclass():
+    class MyList(list):
+        pass
+
+    v = SchemaValidator(core_schema.list_schema(core_schema.int_schema(), strict=True, allow_subclass=False))
+    assert v.validate_python([1, 2]) == [1, 2]
+    assert v.validate_json('[1, 2]') == [1, 2]
+    with pytest.raises(ValidationError, match='Input should be a valid list'):
+        v.validate_python(MyList([1, 2]))
+
+    # subclasses are still accepted in lax mode or with allow_subclass
+    v = SchemaValidator(core_schema.list_schema(core_schema.int_schema(), allow_subclass=False))
+    assert v.validate_python(MyList([1, 2])) == [1, 2]
+    with pytest.raises(ValidationError, match='Input should be a valid list'):
+        v.validate_python(MyList([1, 2]), strict=True)
+    v = SchemaValidator(core_schema.list_schema(core_schema.int_schema(), strict=True))
+    assert v.validate_python(MyList([1, 2])) == [1, 2]
+
+
+def test_validate_python_into():
+    v = SchemaValidator(core_schema.list_schema(core_schema.int_schema(), max_length=5))
+    buffer = [10, 20, 30]
+    buffer_id = id(buffer)
+    assert v.validate_python_into(['1', 2], buffer) is None
+    assert buffer == [1, 2]
+    v.validate_python_into((1, 2, 3, '4'), buffer)
+    assert buffer == [1, 2, 3, 4]
+    v.validate_python_into(buffer, buffer)
+    assert buffer == [1, 2, 3, 4]
+    assert id(buffer) == buffer_id
+
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python_into([1, 'x', 3], buffer)
+    assert exc_info.value.errors(include_url=False) == [
+        {
+            'type': 'int_parsing',
+            'loc': (1,),
+            'msg': 'Input should be a valid integer, unable to parse string as an integer',
+            'input': 'x',
+        }
+    ]
+    # no partial results, or previous contents, are left in the buffer
+    assert buffer == []
+
+    buffer = [1]
+    with pytest.raises(ValidationError, match='List should have at most 5 items after validation'):
+        v.validate_python_into(range(10), buffer)
+    assert buffer == []
+
+
+def test_validate_python_into_same_list():
+    v = SchemaValidator(core_schema.list_schema(core_schema.int_schema(), unique_items=True))
+    # the input is read in full before the buffer is overwritten
+    buffer = ['3', 2, '1']
+    v.validate_python_into(buffer, buffer)
+    assert buffer == [3, 2, 1]
+
+    buffer = ['1', 'x', '3']
+    with pytest.raises(ValidationError, match='Input should be a valid integer'):
+        v.validate_python_into(buffer, buffer)
+    assert buffer == []
+
+    buffer = ['1', 2, 1]
+    with pytest.raises(ValidationError, match='List should have unique items'):
+        v.validate_python_into(buffer, buffer)
+    assert buffer == []
+
+
+def test_validate_python_into_any_items():
+    v = SchemaValidator(core_schema.list_schema(min_length=1))
+    buffer = [None] * 3
+    v.validate_python_into([1, 'a'], buffer)
+    assert buffer == [1, 'a']
+    with pytest.raises(ValidationError, match='List should have at least 1 item after validation'):
+        v.validate_python_into([], buffer)
+
+
+def test_validate_python_into_nested_list():
+    v = SchemaValidator(
+        core_schema.no_info_after_validator_function(sorted, core_schema.list_schema(core_schema.int_schema()))
+    )
+    buffer = [10, 20, 30]
+    v.validate_python_into(['3', 1], buffer)
+    assert buffer == [1, 3]
+    with pytest.raises(ValidationError, match='Input should be a valid integer'):
+        v.validate_python_into(['x'], buffer)
+    assert buffer == []
+
+
+def test_validate_python_into_not_list():
+    v = SchemaValidator(core_schema.int_schema())
+    buffer = [1]
+    with pytest.raises(TypeError, match='`validate_python_into` requires a schema which produces a list, got int'):
+        v.validate_python_into(1, buffer)
+    assert buffer == []
+
+
+def test_prefix_items(py_and_json: PyAndJson):
+    v = py_and_json(
+        core_schema.list_schema(
+            core_schema.float_schema(), prefix_items=[core_schema.str_schema(), core_schema.int_schema()]
+        )
+    )
+    assert v.validate_test(['a', '1', '1.5', 2]) == ['a', 1, 1.5, 2.0]
+    assert v.validate_test(['a', 1]) == ['a', 1]
+    assert v.validate_test(['a']) == ['a']
+    assert v.validate_test([]) == []
+
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_test([1, 'x', 'y'])
+    assert [(e['loc'], e['type']) for e in exc_info.value.errors()] == [
+        ((0,), 'string_type'),
+        ((1,), 'int_parsing'),
+        ((2,), 'float_parsing'),
+    ]
+
+
+def test_prefix_items_any_items():
+    v = SchemaValidator(core_schema.list_schema(prefix_items=[core_schema.int_schema()], min_length=1))
+    assert v.title == 'list[int, *any]'
+    assert v.validate_python(['1', 'x', b'y']) == [1, 'x', b'y']
+    with pytest.raises(ValidationError, match='List should have at least 1 item after validation'):
+        v.validate_python([])
+
+
+def test_columnar_input():
+    row_schema = core_schema.typed_dict_schema(
+        {
+            'name': core_schema.typed_dict_field(core_schema.str_schema()),
+            'age': core_schema.typed_dict_field(core_schema.int_schema()),
+        }
+    )
+    v = SchemaValidator(core_schema.list_schema(row_schema, columnar_input=True))
+    assert v.validate_python({'name': ['a', 'b'], 'age': (1, '2')}) == [
+        {'name': 'a', 'age': 1},
+        {'name': 'b', 'age': 2},
+    ]
+    assert v.validate_python({}) == []
+    # rows are still accepted
+    assert v.validate_python([{'name': 'a', 'age': 1}]) == [{'name': 'a', 'age': 1}]
+
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python({'name': ['a', 'b'], 'age': [1, 2, 3]})
+    assert exc_info.value.errors(include_url=False) == [
+        {
+            'type': 'list_column_length',
+            'loc': ('age',),
+            'msg': 'Column should have 2 items like the first column, not 3',
+            'input': [1, 2, 3],
+            'ctx': {'expected_length': 2, 'actual_length': 3},
+        }
+    ]
+
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python({'name': ['a', 'b'], 'age': 1})
+    assert exc_info.value.errors(include_url=False) == [
+        {'type': 'list_type', 'loc': ('age',), 'msg': 'Input should be a valid list', 'input': 1}
+    ]
+
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python({'name': ['a', 'b'], 'age': [1, 'x']})
+    assert exc_info.value.errors(include_url=False) == [
+        {
+            'type': 'int_parsing',
+            'loc': (1, 'age'),
+            'msg': 'Input should be a valid integer, unable to parse string as an integer',
+            'input': 'x',
+        }
+    ]
+
+    with pytest.raises(ValidationError, match=r'\[type=list_type,'):
+        v.validate_python({'name': ['a'], 'age': [1]}, strict=True)
+
+    v = SchemaValidator(core_schema.list_schema(row_schema))
+    with pytest.raises(ValidationError, match=r'\[type=list_type,'):
+        v.validate_python({'name': ['a'], 'age': [1]})
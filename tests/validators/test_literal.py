@@ -1,4 +1,5 @@
 import re
+from decimal import Decimal
 from enum import Enum
 from typing import Any, Callable, List
 
@@ -389,3 +390,66 @@ def test_big_int():
     m = r'Input should be 18446744073709551617 or 340282366920938463463374607431768211457 \[type=literal_error'
     with pytest.raises(ValidationError, match=m):
         v.validate_python(37)
+
+
+def test_float_literal():
+    v = SchemaValidator(core_schema.literal_schema([1.0, 2.5, float('nan')]))
+    assert v.validate_python(1.0) == 1.0
+    assert v.validate_json('2.5') == 2.5
+    nan = v.validate_python(float('nan'))
+    assert nan != nan
+    for input_value in (1, True, '1.0'):
+        with pytest.raises(ValidationError, match=r'Input should be 1\.0, 2\.5 or nan \[type=literal_error'):
+            v.validate_python(input_value)
+    with pytest.raises(ValidationError, match='literal_error'):
+        v.validate_json('1')
+
+
+def test_float_literal_decimal():
+    v = SchemaValidator(core_schema.literal_schema([1.0, 2.5]))
+    assert v.validate_python(Decimal('2.5')) == 2.5
+    assert v.validate_python(Decimal('1')) == 1.0
+    with pytest.raises(ValidationError, match='literal_error'):
+        v.validate_python(Decimal('3'))
+
+
+def test_float_literal_int_float_equivalence():
+    v = SchemaValidator(core_schema.literal_schema([1.0, 'a'], int_float_equivalence=True))
+    assert v.validate_python(1) == 1.0
+    assert v.validate_json('1') == 1.0
+    with pytest.raises(ValidationError, match='literal_error'):
+        v.validate_python(True)
+    with pytest.raises(ValidationError, match='literal_error'):
+        v.validate_python(2)
+
+
+def test_suggest():
+    v = SchemaValidator(core_schema.literal_schema(['apple', 'banana', 'cherry', 42], suggest=True))
+    assert v.validate_python('banana') == 'banana'
+
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python('bananna')
+    assert exc_info.value.errors(include_url=False) == [
+        {
+            'type': 'literal_error',
+            'loc': (),
+            'msg': "Input should be 'apple', 'banana', 'cherry' or 42",
+            'input': 'bananna',
+            'ctx': {'expected': "'apple', 'banana', 'cherry' or 42", 'suggestion': 'banana'},
+        }
+    ]
+
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_json('"chery"')
+    assert exc_info.value.errors(include_url=False)[0]['ctx']['suggestion'] == 'cherry'
+
+    # nothing close enough, much longer than any expected value, or not a string
+    for input_value in ('xyz', 'banana' * 3, 'b' * 1_000_000, 43):
+        with pytest.raises(ValidationError) as exc_info:
+            v.validate_python(input_value)
+        assert 'suggestion' not in exc_info.value.errors(include_url=False)[0]['ctx']
+
+    v = SchemaValidator(core_schema.literal_schema(['apple', 'banana']))
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python('bananna')
+    assert exc_info.value.errors(include_url=False)[0]['ctx'] == {'expected': "'apple' or 'banana'"}
@@ -44,6 +44,47 @@ def test_model_class():
     assert m2.__dict__ == {'field_a': 'test', 'field_b': 12}
 
 
+
+def test_model_class_slots():
+    class MyModel:
+        __slots__ = 'field_a', 'field_b', '__pydantic_fields_set__', '__pydantic_extra__', '__pydantic_private__'
+
+    v = SchemaValidator(
+        core_schema.model_schema(
+            MyModel,
+            core_schema.model_fields_schema(
+                {
+                    'field_a': core_schema.model_field(core_schema.str_schema()),
+                    'field_b': core_schema.model_field(
+                        core_schema.with_default_schema(core_schema.int_schema(), default=1)
+                    ),
+                }
+            ),
+            revalidate_instances='always',
+        )
+    )
+    m = v.validate_python({'field_a': 'test'})
+    assert isinstance(m, MyModel)
+    assert not hasattr(m, '__dict__')
+    assert m.field_a == 'test'
+    assert m.field_b == 1
+    assert m.__pydantic_extra__ is None
+    assert m.__pydantic_fields_set__ == {'field_a'}
+
+    m2 = v.validate_python(m)
+    assert m2 is not m
+    assert m2.field_a == 'test'
+    assert m2.field_b == 1
+    assert m2.__pydantic_fields_set__ == {'field_a'}
+
+    v.validate_assignment(m, 'field_b', '2')
+    assert m.field_b == 2
+    assert m.field_a == 'test'
+    assert m.__pydantic_fields_set__ == {'field_a', 'field_b'}
+
+    with pytest.raises(ValidationError, match='Input should be a valid integer'):
+        v.validate_assignment(m, 'field_b', 'x')
+
 def test_model_class_extra():
     class MyModel:
         # this is not required, but it avoids `__pydantic_fields_set__` being included in `__dict__`
@@ -1,9 +1,10 @@
 import math
 import re
 import sys
-from dataclasses import dataclass
+import warnings
+from dataclasses import dataclass, field
 from datetime import datetime
-from typing import Any, Dict, List, Mapping, Union
+from typing import Any, ClassVar, Dict, List, Mapping, Union
 
 import pytest
 from dirty_equals import FunctionCheck, HasRepr, IsStr
@@ -1213,6 +1214,34 @@ def test_from_attributes_extra_forbid() -> None:
     assert v.validate_python(Source()) == ({'a': 1}, None, {'a'})
 
 
+def test_from_attributes_dataclass_fields() -> None:
+    @dataclass
+    class Source:
+        a: int
+        c: str = 'ham'
+        b: int = field(init=False)
+        d: ClassVar[int] = 4
+
+        @property
+        def e(self):
+            return 'wrong'
+
+    v = SchemaValidator(
+        core_schema.model_fields_schema(
+            {'a': core_schema.model_field(core_schema.int_schema())}, from_attributes=True, extra_behavior='allow'
+        )
+    )
+    # like other objects read via attributes, the remaining fields aren't extras
+    assert v.validate_python(Source(a=1)) == ({'a': 1}, {}, {'a'})
+
+    v = SchemaValidator(
+        core_schema.model_fields_schema(
+            {'a': core_schema.model_field(core_schema.int_schema())}, from_attributes=True, extra_behavior='forbid'
+        )
+    )
+    assert v.validate_python(Source(a=1)) == ({'a': 1}, None, {'a'})
+
+
 def foobar():
     pass
 
@@ -1781,3 +1810,269 @@ def test_extra_behavior_ignore(config: Union[core_schema.CoreConfig, None], sche
         }
     ]
     assert 'not_f' not in m
+
+
+def test_deprecated_field(py_and_json: PyAndJson):
+    v = py_and_json(
+        core_schema.model_fields_schema(
+            {
+                'a': core_schema.model_field(core_schema.int_schema()),
+                'b': core_schema.model_field(
+                    core_schema.with_default_schema(core_schema.int_schema(), default=0), deprecated=True
+                ),
+                'c': core_schema.model_field(
+                    core_schema.with_default_schema(core_schema.int_schema(), default=0),
+                    deprecated='`c` is going away, use `a`',
+                ),
+            }
+        )
+    )
+    with warnings.catch_warnings():
+        warnings.simplefilter('error')
+        assert v.validate_test({'a': 1}) == ({'a': 1, 'b': 0, 'c': 0}, None, {'a'})
+
+    with pytest.warns(DeprecationWarning, match='Field `b` is deprecated'):
+        assert v.validate_test({'a': 1, 'b': 2}) == ({'a': 1, 'b': 2, 'c': 0}, None, {'a', 'b'})
+
+    with pytest.warns(DeprecationWarning, match='`c` is going away, use `a`'):
+        assert v.validate_test({'a': 1, 'c': 3}) == ({'a': 1, 'b': 0, 'c': 3}, None, {'a', 'c'})
+
+
+def test_deprecated_field_as_error():
+    v = SchemaValidator(
+        core_schema.model_fields_schema({'a': core_schema.model_field(core_schema.int_schema(), deprecated=True)})
+    )
+    with warnings.catch_warnings():
+        warnings.simplefilter('error')
+        with pytest.raises(DeprecationWarning, match='Field `a` is deprecated'):
+            v.validate_python({'a': 1})
+
+
+@pytest.mark.parametrize(
+    'input_value,expected,expected_fields_set',
+    [
+        ({'a': 1, 'b': None}, {'a': 1, 'b': None}, {'a', 'b'}),
+        ({'a': 1}, {'a': 1, 'b': None}, {'a'}),
+        ({'a': None}, {'a': None, 'b': None}, {'a'}),
+    ],
+)
+def test_null_vs_missing_fields_set(py_and_json: PyAndJson, input_value, expected, expected_fields_set):
+    """
+    Explicit nulls are in `fields_set` while missing fields are filled from their default and left out of it,
+    as required for JSON merge-patch semantics.
+    """
+    v = py_and_json(
+        core_schema.model_fields_schema(
+            {
+                'a': core_schema.model_field(core_schema.nullable_schema(core_schema.int_schema())),
+                'b': core_schema.model_field(
+                    core_schema.with_default_schema(core_schema.nullable_schema(core_schema.int_schema()), default=None)
+                ),
+            }
+        )
+    )
+    model_dict, model_extra, fields_set = v.validate_test(input_value)
+    assert model_dict == expected
+    assert model_extra is None
+    assert fields_set == expected_fields_set
+
+
+def test_from_attributes_safe():
+    calls: List[str] = []
+
+    class Untrusted:
+        __slots__ = 'a', 'b', '__dict__'
+
+        def __init__(self):
+            self.a = 1
+            self.c = 3
+
+        @property
+        def d(self):
+            calls.append('d')
+            return 4
+
+        def __getattr__(self, name):
+            calls.append(name)
+            return 'spoofed'
+
+        def __getattribute__(self, name):
+            calls.append(name)
+            return object.__getattribute__(self, name)
+
+    fields = {
+        'a': core_schema.model_field(core_schema.int_schema()),
+        'b': core_schema.model_field(core_schema.with_default_schema(core_schema.int_schema(), default=0)),
+        'c': core_schema.model_field(core_schema.int_schema()),
+        'd': core_schema.model_field(core_schema.with_default_schema(core_schema.int_schema(), default=0)),
+    }
+    v = SchemaValidator(core_schema.model_fields_schema(fields, from_attributes=True, from_attributes_safe=True))
+    # the unset slot `b` and the property `d` aren't read
+    assert v.validate_python(Untrusted()) == ({'a': 1, 'b': 0, 'c': 3, 'd': 0}, None, {'a', 'c'})
+    assert calls == []
+
+    config = core_schema.CoreConfig(from_attributes=True, from_attributes_safe=True)
+    v = SchemaValidator(core_schema.model_fields_schema(fields), config)
+    assert v.validate_python(Untrusted())[0] == {'a': 1, 'b': 0, 'c': 3, 'd': 0}
+    assert calls == []
+
+    # without safe mode, `__class__` is read by the `Mapping` check and `__getattribute__`, `__getattr__` and the
+    # property are all called
+    v = SchemaValidator(core_schema.model_fields_schema(fields, from_attributes=True))
+    with pytest.raises(ValidationError, match=r'b\n  Input should be a valid integer, unable to parse string'):
+        v.validate_python(Untrusted())
+    assert calls == ['__class__', 'a', 'b', 'b', 'c', 'd', 'd']
+
+
+def test_from_attributes_safe_metaclass():
+    calls: List[str] = []
+
+    class Meta(type):
+        def __getattribute__(cls, name):
+            calls.append(name)
+            return type.__getattribute__(cls, name)
+
+        def __subclasscheck__(cls, subclass):
+            calls.append('__subclasscheck__')
+            return type.__subclasscheck__(cls, subclass)
+
+    class Untrusted(metaclass=Meta):
+        __slots__ = ('a', '__dict__')
+
+        @classmethod
+        def __subclasshook__(cls, subclass):
+            calls.append('__subclasshook__')
+            return NotImplemented
+
+        def __init__(self):
+            self.a = 1
+
+    # `issubclass(Untrusted, Mapping)` would consult the hooks of all `Mapping` subclasses
+    class Hooked(Mapping):
+        @classmethod
+        def __subclasshook__(cls, subclass):
+            calls.append('__subclasshook__')
+            return NotImplemented
+
+    v = SchemaValidator(
+        core_schema.model_fields_schema(
+            {'a': core_schema.model_field(core_schema.int_schema())}, from_attributes=True, from_attributes_safe=True
+        )
+    )
+    # nothing is looked up via the metaclass, and the `Mapping` subclass hooks aren't called
+    assert v.validate_python(Untrusted()) == ({'a': 1}, None, {'a'})
+    assert calls == []
+
+    class RegisteredMapping:
+        def __init__(self, data):
+            self.data = data
+
+        def __getitem__(self, key):
+            return self.data[key]
+
+        def __iter__(self):
+            return iter(self.data)
+
+        def __len__(self):
+            return len(self.data)
+
+        def keys(self):
+            return self.data.keys()
+
+        def items(self):
+            return self.data.items()
+
+    Mapping.register(RegisteredMapping)
+    assert v.validate_python(RegisteredMapping({'a': 2})) == ({'a': 2}, None, {'a'})
+
+
+def test_from_attributes_safe_missing():
+    class Foobar:
+        a = 1
+
+    v = SchemaValidator(
+        core_schema.model_fields_schema(
+            {'a': core_schema.model_field(core_schema.int_schema())}, from_attributes=True, from_attributes_safe=True
+        )
+    )
+    # class attributes aren't stored on the instance
+    with pytest.raises(ValidationError, match=r'a\n  Field required'):
+        v.validate_python(Foobar())
+    foobar = Foobar()
+    foobar.a = 2
+    assert v.validate_python(foobar) == ({'a': 2}, None, {'a'})
+
+
+def test_case_insensitive():
+    v = SchemaValidator(
+        core_schema.model_fields_schema(
+            {'api_key': core_schema.model_field(core_schema.str_schema(), validation_alias='X-Api-Key')},
+            case_insensitive=True,
+        )
+    )
+    assert v.validate_python({'x-api-key': 'secret'}) == ({'api_key': 'secret'}, None, {'api_key'})
+    assert v.validate_json('{"X-API-KEY": "secret"}') == ({'api_key': 'secret'}, None, {'api_key'})
+    with pytest.raises(ValidationError, match='X-Api-Key\n  Field required'):
+        v.validate_python({'x-api-keys': 'secret'})
+
+
+def test_require_all_fields():
+    v = SchemaValidator(
+        core_schema.model_fields_schema(
+            {
+                'a': core_schema.model_field(core_schema.int_schema()),
+                'b': core_schema.model_field(
+                    core_schema.with_default_schema(core_schema.nullable_schema(core_schema.int_schema()), default=1)
+                ),
+            }
+        )
+    )
+    assert v.validate_python({'a': 1}) == ({'a': 1, 'b': 1}, None, {'a'})
+    assert v.validate_python({'a': 1, 'b': None}, require_all_fields=True) == ({'a': 1, 'b': None}, None, {'a', 'b'})
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python({'a': 1}, require_all_fields=True)
+    assert exc_info.value.errors(include_url=False) == [
+        {'type': 'missing', 'loc': ('b',), 'msg': 'Field required', 'input': {'a': 1}}
+    ]
+
+
+def test_conditional_required():
+    v = SchemaValidator(
+        core_schema.model_fields_schema(
+            {
+                'status': core_schema.model_field(core_schema.str_schema()),
+                'reason': core_schema.model_field(
+                    core_schema.with_default_schema(core_schema.nullable_schema(core_schema.str_schema()), default=None)
+                ),
+                'reviewer': core_schema.model_field(core_schema.str_schema(), validation_alias='Reviewer'),
+            },
+            conditional_required=[core_schema.conditional_required('status', 'rejected', ['reason', 'reviewer'])],
+        )
+    )
+    assert v.validate_python({'status': 'approved', 'Reviewer': 'bob'}) == (
+        {'status': 'approved', 'reason': None, 'reviewer': 'bob'},
+        None,
+        {'status', 'reviewer'},
+    )
+    assert v.validate_json('{"status": "rejected", "reason": "spam", "Reviewer": "bob"}') == (
+        {'status': 'rejected', 'reason': 'spam', 'reviewer': 'bob'},
+        None,
+        {'status', 'reason', 'reviewer'},
+    )
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python({'status': 'rejected'})
+    # fields without a default are only reported missing once
+    assert exc_info.value.errors(include_url=False) == [
+        {'type': 'missing', 'loc': ('Reviewer',), 'msg': 'Field required', 'input': {'status': 'rejected'}},
+        {'type': 'missing', 'loc': ('reason',), 'msg': 'Field required', 'input': {'status': 'rejected'}},
+    ]
+
+
+def test_conditional_required_unknown_field():
+    with pytest.raises(SchemaError, match='conditional_required: unknown field "nope"'):
+        SchemaValidator(
+            core_schema.model_fields_schema(
+                {'a': core_schema.model_field(core_schema.int_schema())},
+                conditional_required=[core_schema.conditional_required('a', 1, ['nope'])],
+            )
+        )
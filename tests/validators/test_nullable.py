@@ -1,10 +1,13 @@
 import gc
 import platform
+import re
 import weakref
 
 import pytest
 
-from pydantic_core import SchemaValidator, ValidationError, core_schema
+from pydantic_core import SchemaError, SchemaValidator, ValidationError, core_schema
+
+from ..conftest import Err, PyAndJson
 
 
 def test_nullable():
@@ -68,3 +71,40 @@ def test_leak_nullable():
     gc.collect()
 
     assert ref() is None
+
+
+@pytest.mark.parametrize(
+    'empty_str_as_none,input_value,expected',
+    [
+        (True, '', None),
+        (True, '123', 123),
+        (True, None, None),
+        (True, ' ', Err('Input should be a valid integer, unable to parse string as an integer')),
+        ('whitespace', ' \t\n', None),
+        ('whitespace', '', None),
+        ('whitespace', ' 1 ', 1),
+        (False, '', Err('Input should be a valid integer, unable to parse string as an integer')),
+    ],
+)
+def test_empty_str_as_none(py_and_json: PyAndJson, empty_str_as_none, input_value, expected):
+    v = py_and_json(core_schema.nullable_schema(core_schema.int_schema(), empty_str_as_none=empty_str_as_none))
+    if isinstance(expected, Err):
+        with pytest.raises(ValidationError, match=re.escape(expected.message)):
+            v.validate_test(input_value)
+    else:
+        assert v.validate_test(input_value) == expected
+
+
+def test_empty_str_as_none_strict():
+    v = SchemaValidator(core_schema.nullable_schema(core_schema.str_schema(), empty_str_as_none=True))
+    assert v.validate_python('') is None
+    assert v.validate_python('', strict=True) == ''
+    assert v.validate_python(b'') == ''
+
+    v = SchemaValidator(core_schema.nullable_schema(core_schema.str_schema(), strict=True, empty_str_as_none=True))
+    assert v.validate_python('') == ''
+
+
+def test_empty_str_as_none_invalid():
+    with pytest.raises(SchemaError, match="Invalid empty_str_as_none \"all\", should be a bool or 'whitespace'"):
+        SchemaValidator({'type': 'nullable', 'schema': {'type': 'int'}, 'empty_str_as_none': 'all'})
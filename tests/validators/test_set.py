@@ -4,7 +4,7 @@ from typing import Any, Dict
 
 import pytest
 
-from pydantic_core import SchemaValidator, ValidationError
+from pydantic_core import SchemaValidator, ValidationError, core_schema
 
 from ..conftest import Err, PyAndJson, infinite_generator
 
@@ -277,3 +277,25 @@ def test_set_any(input_value, expected):
     output = v.validate_python(input_value)
     assert output == expected
     assert isinstance(output, set)
+
+
+def test_allow_subclass():
+    class MySet(set):
+        pass
+
+    v = SchemaValidator({'type': 'set', 'strict': True, 'allow_subclass': False})
+    assert v.validate_python({1, 2}) == {1, 2}
+    with pytest.raises(ValidationError, match='Input should be a valid set'):
+        v.validate_python(MySet({1, 2}))
+    assert SchemaValidator({'type': 'set', 'strict': True}).validate_python(MySet({1, 2})) == {1, 2}
+
+
+@pytest.mark.parametrize('schema_func', [core_schema.set_schema, core_schema.frozenset_schema])
+def test_contains(schema_func):
+    v = SchemaValidator(schema_func(core_schema.int_schema(), contains=core_schema.int_schema(ge=10), max_contains=1))
+    assert v.validate_python({1, '10'}) == {1, 10}
+    field_type = 'Set' if schema_func is core_schema.set_schema else 'Frozenset'
+    with pytest.raises(ValidationError, match=f'{field_type} should contain at least 1 item matching `contains`, not 0'):
+        v.validate_python({1, 2})
+    with pytest.raises(ValidationError, match=f'{field_type} should contain at most 1 item matching `contains`, not 2'):
+        v.validate_python([10, 11])
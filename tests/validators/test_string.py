@@ -212,7 +212,7 @@ def test_default_validator():
     v = SchemaValidator(core_schema.str_schema(strict=True, to_lower=False), {'str_strip_whitespace': False})
     assert (
         plain_repr(v)
-        == 'SchemaValidator(title="str",validator=Str(StrValidator{strict:true,coerce_numbers_to_str:false}),definitions=[],cache_strings=True)'
+        == 'SchemaValidator(title="str",validator=Str(StrValidator{strict:true,explain_strict:false,coerce_numbers_to_str:false}),definitions=[],cache_strings=True)'
     )
 
 
@@ -264,6 +264,22 @@ def test_lax_subclass_plain_enum(kwargs):
     assert repr(p) == "'one'"
 
 
+def test_plain_enum_value_type() -> None:
+    from enum import Enum
+
+    class PlainEnum(Enum):
+        ONE = 'one'
+        TWO = 2
+
+    v = SchemaValidator(core_schema.str_schema())
+    assert v.validate_python(PlainEnum.ONE) == 'one'
+    # only values which are strings are used
+    with pytest.raises(ValidationError, match='Input should be a valid string'):
+        v.validate_python(PlainEnum.TWO)
+    with pytest.raises(ValidationError, match='Input should be a valid string'):
+        v.validate_python(PlainEnum.ONE, strict=True)
+
+
 def test_subclass_preserved() -> None:
     class StrSubclass(str):
         pass
@@ -398,3 +414,122 @@ def test_coerce_numbers_to_str_schema_with_strict_mode(number: int):
         v.validate_python(number)
     with pytest.raises(ValidationError):
         v.validate_json(str(number))
+
+
+def test_explain_strict():
+    v = SchemaValidator(core_schema.str_schema(max_length=5), core_schema.CoreConfig(strict=True, explain_strict=True))
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python(b'foo')
+    assert exc_info.value.errors(include_url=False) == [
+        {
+            'type': 'string_type',
+            'loc': (),
+            'msg': 'Input should be a valid string',
+            'input': b'foo',
+            'ctx': {
+                'explanation': (
+                    'Input of type `bytes` was provided but strict mode requires `str`, '
+                    'use lax mode or pass a value of type `str`'
+                )
+            },
+        }
+    ]
+
+
+@pytest.mark.parametrize(
+    'form,input_value,expected',
+    [
+        ('NFC', 'e\u0301', '\u00e9'),
+        ('NFC', 'abc', 'abc'),
+        ('NFD', '\u00e9', 'e\u0301'),
+        ('NFKC', '\ufb01', 'fi'),
+        ('NFKD', '\u00e9\ufb01', 'e\u0301fi'),
+    ],
+)
+def test_unicode_normalize(py_and_json: PyAndJson, form, input_value, expected):
+    v = py_and_json(core_schema.str_schema(unicode_normalize=form))
+    assert v.validate_test(input_value) == expected
+
+
+def test_unicode_normalize_length():
+    v = SchemaValidator(core_schema.str_schema(unicode_normalize='NFC', max_length=1))
+    assert v.validate_python('e\u0301') == '\u00e9'
+
+    v = SchemaValidator(core_schema.str_schema(unicode_normalize='NFD', max_length=1))
+    with pytest.raises(ValidationError, match='String should have at most 1 character'):
+        v.validate_python('\u00e9')
+
+
+def test_unicode_normalize_invalid():
+    with pytest.raises(SchemaError, match='Invalid unicode_normalize form `nfc`, expected `NFC`, `NFD`, `NFKC` or `NFKD`'):
+        SchemaValidator(core_schema.str_schema(unicode_normalize='nfc'))
+
+
+def test_max_length_multibyte_json():
+    v = SchemaValidator(core_schema.str_schema(max_length=3))
+    # 3 chars but 12 bytes of UTF-8
+    assert v.validate_json('"\U0001f600\U0001f600\U0001f600"') == '\U0001f600\U0001f600\U0001f600'
+    with pytest.raises(ValidationError, match='String should have at most 3 characters'):
+        v.validate_json('"\U0001f600\U0001f600\U0001f600\U0001f600"')
+    with pytest.raises(ValidationError, match='String should have at most 3 characters'):
+        v.validate_json('"' + 'x' * 1_000_000 + '"')
+
+    v = SchemaValidator(core_schema.str_schema(max_length=3, strip_whitespace=True))
+    assert v.validate_json('"' + ' ' * 100 + 'abc"') == 'abc'
+
+
+def test_cache_validations():
+    v = SchemaValidator(core_schema.str_schema(to_lower=True, max_length=5, cache_validations=True))
+    first = v.validate_python('ABC')
+    assert first == 'abc'
+    assert v.validate_python('ABC') is first
+    for _ in range(2):
+        with pytest.raises(ValidationError) as exc_info:
+            v.validate_python('ABCDEF')
+        assert exc_info.value.errors(include_url=False)[0]['type'] == 'string_too_long'
+    # more distinct values than the cache holds are still validated correctly
+    for i in range(1000):
+        assert v.validate_python(str(i % 300)) == str(i % 300)
+    assert v.validate_json('"XYZ"') == 'xyz'
+
+    class MyStr(str):
+        pass
+
+    assert v.validate_python(MyStr('DEF')) == 'def'
+    with pytest.raises(ValidationError, match='Input should be a valid string'):
+        v.validate_python(123)
+
+
+def test_cache_validations_least_recently_used():
+    # without cache_strings each validation creates a new string, unless it's cached
+    v = SchemaValidator(core_schema.str_schema(to_lower=True, cache_validations=True), {'cache_strings': False})
+    kept = v.validate_python('KEPT')
+    evicted = v.validate_python('EVICTED')
+    for i in range(1000):
+        v.validate_python(f'X{i}')
+        # using an entry keeps it in the cache
+        assert v.validate_python('KEPT') is kept
+    assert v.validate_python('EVICTED') == 'evicted'
+    assert v.validate_python('EVICTED') is not evicted
+
+
+def test_cache_validations_size():
+    v = SchemaValidator(core_schema.str_schema(to_lower=True, cache_validations=True), {'cache_strings': False})
+    small = v.validate_python('SMALL')
+    # inputs larger than the cache aren't cached
+    large = 'X' * 100_000
+    assert v.validate_python(large) is not v.validate_python(large)
+    assert v.validate_python('SMALL') is small
+    # entries are evicted to stay within the size limit
+    medium = [str(i) * 20_000 for i in range(4)]
+    outputs = [v.validate_python(s) for s in medium]
+    assert v.validate_python(medium[-1]) is outputs[-1]
+    assert v.validate_python(medium[0]) is not outputs[0]
+
+
+def test_cache_validations_profile():
+    v = SchemaValidator(core_schema.str_schema(cache_validations=True))
+    _, profile = v.validate_python('abc', profile=True)
+    assert {name: entry['calls'] for name, entry in profile.items()} == {'str': 1}
+    _, profile = v.validate_python('abc', profile=True)
+    assert {name: entry['calls'] for name, entry in profile.items()} == {'str': 1, 'str:cache-hit': 1}
@@ -1,9 +1,10 @@
 from enum import Enum
+from types import MappingProxyType
 
 import pytest
 from dirty_equals import IsAnyStr
 
-from pydantic_core import CoreConfig, SchemaValidator, ValidationError, core_schema
+from pydantic_core import CoreConfig, SchemaError, SchemaValidator, ValidationError, core_schema
 
 from ..conftest import Err, PyAndJson
 from .test_typed_dict import Cls
@@ -493,6 +494,47 @@ def test_from_attributes():
     assert v.validate_python(Cls(foobar='banana', c='banana', d='31')) == ({'c': 'banana', 'd': 31}, None, {'c', 'd'})
 
 
+def test_from_attributes_safe():
+    calls = []
+
+    class Fruit:
+        def __init__(self, **kwargs):
+            self.__dict__.update(kwargs)
+
+        @property
+        def kind(self):
+            calls.append('kind')
+            return 'apple'
+
+    v = SchemaValidator(
+        core_schema.tagged_union_schema(
+            discriminator='kind',
+            choices={
+                'apple': core_schema.model_fields_schema({'a': core_schema.model_field(core_schema.int_schema())}),
+            },
+            from_attributes_safe=True,
+        ),
+        CoreConfig(from_attributes=True),
+    )
+    # the property isn't called to find the tag
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python(Fruit(a=1))
+    assert exc_info.value.errors(include_url=False)[0]['type'] == 'union_tag_not_found'
+    assert calls == []
+
+    v = SchemaValidator(
+        core_schema.tagged_union_schema(
+            discriminator='kind',
+            choices={
+                'apple': core_schema.model_fields_schema({'a': core_schema.model_field(core_schema.int_schema())}),
+            },
+        ),
+        CoreConfig(from_attributes=True),
+    )
+    assert v.validate_python(Fruit(a=1)) == ({'a': 1}, None, {'a'})
+    assert calls == ['kind']
+
+
 def test_use_ref():
     v = SchemaValidator(
         core_schema.definitions_schema(
@@ -620,3 +662,105 @@ def test_custom_error_type():
             'input': {'foo': 'other', 'bar': 'Bar'},
         }
     ]
+
+
+def test_report_union_variant():
+    v = SchemaValidator(
+        core_schema.tagged_union_schema(
+            discriminator='foo',
+            choices={
+                'apple': core_schema.typed_dict_schema(
+                    {
+                        'foo': core_schema.typed_dict_field(core_schema.str_schema()),
+                        'bar': core_schema.typed_dict_field(core_schema.int_schema()),
+                    }
+                ),
+                123: core_schema.typed_dict_schema(
+                    {
+                        'foo': core_schema.typed_dict_field(core_schema.int_schema()),
+                        'spam': core_schema.typed_dict_field(core_schema.str_schema()),
+                    }
+                ),
+            },
+        )
+    )
+    report = v.validate_python({'foo': 'apple', 'bar': '1'}, report_union_variant=True)
+    assert (report.value, report.union_variant) == (
+        {'foo': 'apple', 'bar': 1},
+        'apple',
+    )
+    report = v.validate_python({'foo': 123, 'spam': 'x'}, report_union_variant=True)
+    assert (report.value, report.union_variant) == ({'foo': 123, 'spam': 'x'}, 123)
+
+
+def test_strict_runtime_override():
+    v = SchemaValidator(
+        core_schema.tagged_union_schema(
+            discriminator='foo',
+            choices={
+                'apple': core_schema.typed_dict_schema(
+                    {
+                        'foo': core_schema.typed_dict_field(core_schema.str_schema()),
+                        'bar': core_schema.typed_dict_field(core_schema.int_schema()),
+                    }
+                ),
+            },
+        )
+    )
+    assert v.validate_python({'foo': 'apple', 'bar': '1'}) == {'foo': 'apple', 'bar': 1}
+    assert v.validate_python(MappingProxyType({'foo': 'apple', 'bar': 1})) == {'foo': 'apple', 'bar': 1}
+
+    # `strict=True` at runtime applies to finding the tag as well as to the choices
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python(MappingProxyType({'foo': 'apple', 'bar': 1}), strict=True)
+    assert exc_info.value.errors(include_url=False)[0]['type'] == 'model_attributes_type'
+    with pytest.raises(ValidationError, match='Input should be a valid integer'):
+        v.validate_python({'foo': 'apple', 'bar': '1'}, strict=True)
+
+
+def test_fallback_tag():
+    v = SchemaValidator(
+        core_schema.tagged_union_schema(
+            discriminator='kind',
+            choices={
+                'apple': core_schema.typed_dict_schema(
+                    {
+                        'kind': core_schema.typed_dict_field(core_schema.str_schema()),
+                        'bar': core_schema.typed_dict_field(core_schema.int_schema()),
+                    }
+                ),
+                'unknown': core_schema.typed_dict_schema(
+                    {'kind': core_schema.typed_dict_field(core_schema.str_schema())}, extra_behavior='allow'
+                ),
+            },
+            fallback_tag='unknown',
+        )
+    )
+    assert v.validate_python({'kind': 'apple', 'bar': '1'}) == {'kind': 'apple', 'bar': 1}
+    assert v.validate_python({'kind': 'pear', 'size': 3}) == {'kind': 'pear', 'size': 3}
+    report = v.validate_python({'kind': 'pear'}, report_union_variant=True)
+    assert (report.value, report.union_variant) == ({'kind': 'pear'}, 'unknown')
+
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python({'kind': 123})
+    assert exc_info.value.errors(include_url=False) == [
+        {
+            'type': 'string_type',
+            'loc': ('unknown', 'kind'),
+            'msg': 'Input should be a valid string',
+            'input': 123,
+        }
+    ]
+
+    # a missing tag still raises
+    with pytest.raises(ValidationError, match="Unable to extract tag using discriminator 'kind'"):
+        v.validate_python({'bar': 1})
+
+
+def test_fallback_tag_invalid():
+    with pytest.raises(SchemaError, match="`fallback_tag` 'pear' is not one of the choices"):
+        SchemaValidator(
+            core_schema.tagged_union_schema(
+                discriminator='kind', choices={'apple': core_schema.int_schema()}, fallback_tag='pear'
+            )
+        )
@@ -294,3 +294,76 @@ def test_tz_constraint_too_high():
 def test_tz_constraint_wrong():
     with pytest.raises(SchemaError, match="Input should be 'aware' or 'naive"):
         validate_core_schema(core_schema.time_schema(tz_constraint='wrong'))
+
+
+@pytest.mark.parametrize(
+    'input_value,expected',
+    [
+        ('2:30 PM', time(14, 30)),
+        ('2:30pm', time(14, 30)),
+        ('02:30:15 am', time(2, 30, 15)),
+        ('11:05:30.5 PM', time(23, 5, 30, 500_000)),
+        ('12 AM', time(0)),
+        ('12:00 PM', time(12)),
+        ('7 pm', time(19)),
+        ('13:00 PM', Err('Input should be in a valid time format, invalid 12-hour time, expected a time like `2:30 PM`')),
+        ('0:30 AM', Err('invalid 12-hour time')),
+        ('2:60 PM', Err('invalid 12-hour time')),
+        ('2:3 PM', Err('invalid 12-hour time')),
+        ('14:30', Err('invalid 12-hour time')),
+    ],
+)
+def test_time_format_12h(py_and_json: PyAndJson, input_value, expected):
+    v = py_and_json(core_schema.time_schema(time_format='12h'))
+    if isinstance(expected, Err):
+        with pytest.raises(ValidationError, match=re.escape(expected.message)):
+            v.validate_test(input_value)
+    else:
+        assert v.validate_test(input_value) == expected
+
+
+@pytest.mark.parametrize(
+    'input_value,expected',
+    [
+        ('14:30', time(14, 30)),
+        ('2:30 PM', time(14, 30)),
+        ('13:00 PM', Err('invalid 12-hour time')),
+        ('25:00', Err('Input should be in a valid time format, hour value is outside expected range of 0-23')),
+    ],
+)
+def test_time_format_any(py_and_json: PyAndJson, input_value, expected):
+    v = py_and_json(core_schema.time_schema(time_format='any'))
+    if isinstance(expected, Err):
+        with pytest.raises(ValidationError, match=re.escape(expected.message)):
+            v.validate_test(input_value)
+    else:
+        assert v.validate_test(input_value) == expected
+
+
+def test_time_format_12h_strict():
+    v = SchemaValidator(core_schema.time_schema(time_format='12h', strict=True))
+    with pytest.raises(ValidationError, match='Input should be a valid time'):
+        v.validate_python('2:30 PM')
+    assert v.validate_json('"2:30 PM"') == time(14, 30)
+    with pytest.raises(ValidationError, match='Input should be in a valid time format'):
+        v.validate_json('"14:30"')
+    assert v.validate_strings('2:30 PM') == time(14, 30)
+
+
+def test_time_format_12h_strict_override():
+    v = SchemaValidator(core_schema.time_schema(time_format='12h'))
+    assert v.validate_json('"2:30 PM"', strict=True) == time(14, 30)
+    assert v.validate_strings('2:30 PM', strict=True) == time(14, 30)
+    with pytest.raises(ValidationError, match='Input should be a valid time'):
+        v.validate_python('2:30 PM', strict=True)
+
+
+def test_time_format_iso():
+    v = SchemaValidator(core_schema.time_schema())
+    with pytest.raises(ValidationError, match='Input should be in a valid time format'):
+        v.validate_python('2:30 PM')
+
+
+def test_time_format_invalid():
+    with pytest.raises(SchemaError, match="Input should be 'iso', '12h' or 'any'"):
+        validate_core_schema(core_schema.time_schema(time_format='24h'))
@@ -289,3 +289,55 @@ def test_pandas():
         v.validate_python(one_55)
     with pytest.raises(ValidationError, match=msg):
         v.validate_python(one_55.to_pytimedelta())
+
+
+@pytest.mark.parametrize(
+    'duration_format,input_value,expected',
+    [
+        ('iso8601', 'P3DT4H', timedelta(days=3, hours=4)),
+        ('iso8601', 'P1W', timedelta(weeks=1)),
+        ('iso8601', 'PT1.5S', timedelta(seconds=1, microseconds=500_000)),
+        ('iso8601', 'P1.5D', timedelta(days=1, hours=12)),
+        ('iso8601', '-PT30M', timedelta(minutes=-30)),
+        ('iso8601', 'PT2M', timedelta(minutes=2)),
+        ('iso8601', 123, timedelta(seconds=123)),
+        ('iso8601', '12:34', Err('expected an ISO 8601 duration')),
+        ('iso8601', 'P1Y', Err('years and months are ambiguous')),
+        ('iso8601', 'P2MT1H', Err('years and months are ambiguous')),
+        ('any', 'P3DT4H', timedelta(days=3, hours=4)),
+        ('any', '1 day, 12:34', timedelta(days=1, hours=12, minutes=34)),
+        ('any', 'P1Y', Err('years and months are ambiguous')),
+        ('speedate', 'P1Y', timedelta(days=365)),
+    ],
+)
+def test_duration_format(py_and_json: PyAndJson, duration_format, input_value, expected):
+    v = py_and_json({'type': 'timedelta', 'duration_format': duration_format})
+    if isinstance(expected, Err):
+        with pytest.raises(ValidationError, match=re.escape(expected.message)):
+            v.validate_test(input_value)
+    else:
+        assert v.validate_test(input_value) == expected
+
+
+@pytest.mark.parametrize(
+    'duration_format,input_value,expected',
+    [
+        ('iso8601', b'P3DT4H', timedelta(days=3, hours=4)),
+        ('iso8601', b'12:34', Err('expected an ISO 8601 duration')),
+        ('iso8601', b'P1Y', Err('years and months are ambiguous')),
+        ('any', b'P1Y', Err('years and months are ambiguous')),
+        ('speedate', b'P1Y', timedelta(days=365)),
+    ],
+)
+def test_duration_format_bytes(duration_format, input_value, expected):
+    v = SchemaValidator({'type': 'timedelta', 'duration_format': duration_format})
+    if isinstance(expected, Err):
+        with pytest.raises(ValidationError, match=re.escape(expected.message)):
+            v.validate_python(input_value)
+    else:
+        assert v.validate_python(input_value) == expected
+
+
+def test_duration_format_invalid():
+    with pytest.raises(SchemaError, match='Invalid duration_format "foobar"'):
+        SchemaValidator({'type': 'timedelta', 'duration_format': 'foobar'})
@@ -1,11 +1,11 @@
 import re
-from collections import deque
-from typing import Any, Dict, Type
+from collections import deque, namedtuple
+from typing import Any, Dict, NamedTuple, Type
 
 import pytest
 from dirty_equals import IsNonNegative, IsTuple
 
-from pydantic_core import SchemaValidator, ValidationError, core_schema
+from pydantic_core import SchemaError, SchemaValidator, ValidationError, core_schema
 
 from ..conftest import Err, PyAndJson, infinite_generator
 
@@ -493,3 +493,64 @@ def test_length_constraints_omit(input_value, expected):
             v.validate_python(input_value)
     else:
         assert v.validate_python(input_value) == expected
+
+
+class Point(NamedTuple):
+    x: int
+    y: float
+
+
+def test_namedtuple_cls(py_and_json: PyAndJson):
+    v = py_and_json(
+        core_schema.tuple_schema([core_schema.int_schema(), core_schema.float_schema()], namedtuple_cls=Point)
+    )
+    output = v.validate_test(['1', 2])
+    assert output == Point(x=1, y=2.0)
+    assert type(output) is Point
+    assert output.x == output[0] == 1
+
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_test([1, 'x'])
+    assert exc_info.value.errors(include_url=False) == [
+        {
+            'type': 'float_parsing',
+            'loc': (1,),
+            'msg': 'Input should be a valid number, unable to parse string as a number',
+            'input': 'x',
+        }
+    ]
+
+
+def test_namedtuple_cls_collections():
+    point = namedtuple('point', ['x', 'y'])
+    v = SchemaValidator(
+        core_schema.tuple_schema([core_schema.int_schema(), core_schema.int_schema()], namedtuple_cls=point)
+    )
+    assert v.validate_python(('1', 2)) == point(1, 2)
+
+
+def test_namedtuple_cls_invalid():
+    with pytest.raises(SchemaError, match='`namedtuple_cls` has 2 fields but `items_schema` has 1 items'):
+        SchemaValidator(core_schema.tuple_schema([core_schema.int_schema()], namedtuple_cls=Point))
+
+    with pytest.raises(SchemaError, match='`namedtuple_cls` can only be used with tuples without a variadic item'):
+        SchemaValidator(
+            core_schema.tuple_schema(
+                [core_schema.int_schema(), core_schema.float_schema()], variadic_item_index=1, namedtuple_cls=Point
+            )
+        )
+
+
+def test_allow_subclass():
+    Point = namedtuple('Point', 'x y')
+
+    v = SchemaValidator(core_schema.tuple_schema([core_schema.int_schema()], variadic_item_index=0, strict=True))
+    assert v.validate_python(Point(1, 2)) == (1, 2)
+
+    v = SchemaValidator(
+        core_schema.tuple_schema([core_schema.int_schema()], variadic_item_index=0, strict=True, allow_subclass=False)
+    )
+    assert v.validate_python((1, 2)) == (1, 2)
+    with pytest.raises(ValidationError, match='Input should be a valid tuple'):
+        v.validate_python(Point(1, 2))
+    assert v.validate_python(Point(1, 2), strict=False) == (1, 2)
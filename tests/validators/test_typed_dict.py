@@ -2,6 +2,7 @@ import gc
 import math
 import platform
 import re
+import warnings
 import weakref
 from typing import Any, Dict, Mapping, Union
 
@@ -1196,3 +1197,124 @@ def test_leak_typed_dict():
     gc.collect()
 
     assert ref() is None
+
+
+def test_deprecated_field():
+    v = SchemaValidator(
+        core_schema.typed_dict_schema(
+            {
+                'a': core_schema.typed_dict_field(core_schema.int_schema()),
+                'b': core_schema.typed_dict_field(core_schema.int_schema(), required=False, deprecated=True),
+                'c': core_schema.typed_dict_field(core_schema.int_schema(), required=False, deprecated=False),
+            }
+        )
+    )
+    with warnings.catch_warnings():
+        warnings.simplefilter('error')
+        assert v.validate_python({'a': 1, 'c': 3}) == {'a': 1, 'c': 3}
+
+    with pytest.warns(DeprecationWarning, match='Field `b` is deprecated'):
+        assert v.validate_python({'a': 1, 'b': 2}) == {'a': 1, 'b': 2}
+    with pytest.warns(DeprecationWarning, match='Field `b` is deprecated'):
+        assert v.validate_json('{"a": 1, "b": 2}') == {'a': 1, 'b': 2}
+
+
+def test_case_insensitive():
+    v = SchemaValidator(
+        core_schema.typed_dict_schema(
+            {
+                'content_type': core_schema.typed_dict_field(core_schema.str_schema(), validation_alias='Content-Type'),
+                'length': core_schema.typed_dict_field(core_schema.int_schema(), required=False),
+            },
+            case_insensitive=True,
+            extra_behavior='forbid',
+        )
+    )
+    assert v.validate_python({'content-type': 'text/plain'}) == {'content_type': 'text/plain'}
+    assert v.validate_python({'CONTENT-TYPE': 'text/plain', 'Length': 4}) == {'content_type': 'text/plain', 'length': 4}
+    assert v.validate_json('{"content-TYPE": "text/html"}') == {'content_type': 'text/html'}
+    # the exact key is preferred, other spellings aren't treated as extra
+    assert v.validate_python({'content-type': 'a', 'Content-Type': 'b'}) == {'content_type': 'b'}
+
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python({'content-type': 'a', 'other': 1})
+    assert exc_info.value.errors(include_url=False) == [
+        {'type': 'extra_forbidden', 'loc': ('other',), 'msg': 'Extra inputs are not permitted', 'input': 1}
+    ]
+
+
+def test_case_insensitive_path():
+    v = SchemaValidator(
+        core_schema.typed_dict_schema(
+            {
+                'content_type': core_schema.typed_dict_field(
+                    core_schema.str_schema(), validation_alias=[['Content-Type'], ['headers', 'Content-Type']]
+                ),
+            },
+            case_insensitive=True,
+            extra_behavior='forbid',
+        )
+    )
+    assert v.validate_python({'content-type': 'a'}) == {'content_type': 'a'}
+    assert v.validate_json('{"CONTENT-TYPE": "a"}') == {'content_type': 'a'}
+    # only the first item of the path is matched case-insensitively
+    assert v.validate_python({'Headers': {'Content-Type': 'b'}}) == {'content_type': 'b'}
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python({'Headers': {'content-type': 'b'}})
+    assert [(e['type'], e['loc']) for e in exc_info.value.errors()] == [
+        ('missing', ('Content-Type',)),
+        ('extra_forbidden', ('Headers',)),
+    ]
+
+
+def test_case_insensitive_collision():
+    v = SchemaValidator(
+        core_schema.typed_dict_schema(
+            {'name': core_schema.typed_dict_field(core_schema.str_schema())},
+            case_insensitive=True,
+            case_collision='error',
+        )
+    )
+    assert v.validate_python({'NAME': 'x'}) == {'name': 'x'}
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python({'Name': 'a', 'name': 'b'})
+    assert exc_info.value.errors(include_url=False) == [
+        {
+            'type': 'case_collision',
+            'loc': ('name',),
+            'msg': "Keys 'Name', 'name' only differ in case",
+            'input': {'Name': 'a', 'name': 'b'},
+            'ctx': {'keys': "'Name', 'name'"},
+        }
+    ]
+
+    with pytest.raises(SchemaError, match='Invalid case_collision value: first'):
+        SchemaValidator(
+            {
+                'type': 'typed-dict',
+                'fields': {'name': core_schema.typed_dict_field(core_schema.str_schema())},
+                'case_insensitive': True,
+                'case_collision': 'first',
+            }
+        )
+
+
+def test_require_all_fields():
+    v = SchemaValidator(
+        core_schema.typed_dict_schema(
+            {
+                'a': core_schema.typed_dict_field(
+                    core_schema.with_default_schema(core_schema.int_schema(), default_factory=lambda: 1)
+                ),
+                'b': core_schema.typed_dict_field(core_schema.int_schema(), required=False),
+            }
+        )
+    )
+    assert v.validate_python({}) == {'a': 1}
+    assert v.validate_python({'a': 2, 'b': 3}, require_all_fields=True) == {'a': 2, 'b': 3}
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python({}, require_all_fields=True)
+    assert exc_info.value.errors(include_url=False) == [
+        {'type': 'missing', 'loc': ('a',), 'msg': 'Field required', 'input': {}},
+        {'type': 'missing', 'loc': ('b',), 'msg': 'Field required', 'input': {}},
+    ]
@@ -264,7 +264,7 @@ def test_one_choice():
     v = SchemaValidator({'type': 'union', 'choices': [{'type': 'str'}]})
     assert (
         plain_repr(v)
-        == 'SchemaValidator(title="str",validator=Str(StrValidator{strict:false,coerce_numbers_to_str:false}),definitions=[],cache_strings=True)'
+        == 'SchemaValidator(title="str",validator=Str(StrValidator{strict:false,explain_strict:false,coerce_numbers_to_str:false}),definitions=[],cache_strings=True)'
     )
     assert v.validate_python('hello') == 'hello'
 
@@ -803,3 +803,50 @@ def test_model_and_literal_union() -> None:
     assert isinstance(m, ModelA)
     assert m.a == 42
     assert validator.validate_python(True) is True
+
+
+def test_report_union_variant():
+    v = SchemaValidator(core_schema.union_schema([core_schema.int_schema(), core_schema.str_schema()]))
+    report = v.validate_python(1, report_union_variant=True)
+    assert (report.value, report.union_variant) == (1, 0)
+    # smart mode picks the exact match over the first lax match
+    report = v.validate_python('1', report_union_variant=True)
+    assert (report.value, report.union_variant) == ('1', 1)
+    report = v.validate_python('1', report_union_variant=True, with_hash=True)
+    assert (report.value, report.union_variant, report.exactness) == ('1', 1, None)
+    assert repr(report) == f"ValidationReport(value='1', union_variant=1, hash={report.hash})"
+    assert v.validate_python('1') == '1'
+
+    v = SchemaValidator(
+        core_schema.union_schema([core_schema.int_schema(), core_schema.str_schema()], mode='left_to_right')
+    )
+    report = v.validate_python('1', report_union_variant=True)
+    assert (report.value, report.union_variant) == (1, 0)
+    report = v.validate_python('a', report_union_variant=True)
+    assert (report.value, report.union_variant) == ('a', 1)
+
+
+def test_report_union_variant_nested():
+    v = SchemaValidator(
+        core_schema.union_schema(
+            [
+                core_schema.bool_schema(strict=True),
+                core_schema.union_schema([core_schema.int_schema(strict=True), core_schema.str_schema()]),
+            ]
+        )
+    )
+    # the variant of the outermost union is reported
+    report = v.validate_python('a', report_union_variant=True)
+    assert (report.value, report.union_variant) == ('a', 1)
+    report = v.validate_python(True, report_union_variant=True)
+    assert (report.value, report.union_variant) == (True, 0)
+
+
+def test_report_union_variant_not_union():
+    v = SchemaValidator(core_schema.int_schema())
+    with pytest.raises(TypeError, match='`report_union_variant` can only be used with a `union` or `tagged-union`'):
+        v.validate_python(1, report_union_variant=True)
+
+    v = SchemaValidator(core_schema.union_schema([core_schema.int_schema(), core_schema.str_schema()]))
+    with pytest.raises(ValueError, match="`on_error='collect'` cannot be combined with"):
+        v.validate_python(1, report_union_variant=True, on_error='collect')